@@ -0,0 +1,84 @@
+// Grass clump shader (ExtendedMaterial<StandardMaterial, GrassExtension>, see grass.rs).
+// Vertex: standard mesh transform + wind sway that grows with blade height (uv.y = 0 root, 1 tip).
+// Fragment: root -> tip color gradient fed into the regular PBR lighting.
+
+#import bevy_pbr::{
+    mesh_functions,
+    view_transformations::position_world_to_clip,
+    pbr_fragment::pbr_input_from_standard_material,
+    pbr_functions::{alpha_discard, apply_pbr_lighting, main_pass_post_lighting_processing},
+    forward_io::{Vertex, VertexOutput, FragmentOutput},
+}
+
+// Must match GrassUniform in grass.rs.
+struct GrassMaterial {
+    base_color: vec4<f32>,
+    tip_color: vec4<f32>,
+    wind: vec4<f32>, // xy = direction, z = strength (m), w = frequency
+    time: f32,
+};
+
+@group(2) @binding(100)
+var<uniform> grass_material: GrassMaterial;
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+    let world_from_local = mesh_functions::get_world_from_local(vertex.instance_index);
+    var world_position = mesh_functions::mesh_position_local_to_world(world_from_local, vec4<f32>(vertex.position, 1.0));
+
+#ifdef VERTEX_UVS_A
+    let tip = clamp(vertex.uv.y, 0.0, 1.0);
+#else
+    let tip = 0.0;
+#endif
+    // Per-clump phase from its world origin so neighbouring clumps do not move in lockstep.
+    let origin = world_from_local[3].xyz;
+    let phase = dot(origin.xz, vec2<f32>(0.37, 0.21));
+    let w = grass_material.wind;
+    let t = grass_material.time * w.w;
+    let gust = 0.6 + 0.4 * sin(t + phase) + 0.25 * sin(t * 2.3 + phase * 1.7);
+    let bend = tip * tip * w.z * gust;
+    world_position = vec4<f32>(
+        world_position.x + w.x * bend,
+        world_position.y - abs(bend) * 0.25,
+        world_position.z + w.y * bend,
+        world_position.w,
+    );
+
+    out.world_position = world_position;
+    out.position = position_world_to_clip(world_position.xyz);
+#ifdef VERTEX_NORMALS
+    out.world_normal = mesh_functions::mesh_normal_local_to_world(vertex.normal, vertex.instance_index);
+#endif
+#ifdef VERTEX_UVS_A
+    out.uv = vertex.uv;
+#endif
+#ifdef VERTEX_OUTPUT_INSTANCE_INDEX
+    out.instance_index = vertex.instance_index;
+#endif
+    return out;
+}
+
+@fragment
+fn fragment(
+    in: VertexOutput,
+    @builtin(front_facing) is_front: bool,
+) -> FragmentOutput {
+    var pbr_input = pbr_input_from_standard_material(in, is_front);
+
+#ifdef VERTEX_UVS_A
+    let tip = clamp(in.uv.y, 0.0, 1.0);
+#else
+    let tip = 0.5;
+#endif
+    // Darker roots read as self-shadowing inside the clump.
+    let col = mix(grass_material.base_color.rgb, grass_material.tip_color.rgb, tip) * mix(0.6, 1.0, tip);
+    pbr_input.material.base_color = vec4<f32>(col, 1.0);
+    pbr_input.material.base_color = alpha_discard(pbr_input.material, pbr_input.material.base_color);
+
+    var out: FragmentOutput;
+    out.color = apply_pbr_lighting(pbr_input);
+    out.color = main_pass_post_lighting_processing(pbr_input, out.color);
+    return out;
+}
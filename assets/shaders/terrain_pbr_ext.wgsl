@@ -48,10 +48,17 @@ struct RealTerrainExtendedMaterial {
     macro_scale: f32,
     micro_scale: f32,
     animation_speed: f32,
+    normal_map_strength: f32,
+    normal_map_world_size: f32,
+    biome_colors: array<vec4<f32>, 16u>, // [biome * 4 + slot], biomes: meadow, forest, highland, wetland
 };
 
 @group(2) @binding(100)
 var<uniform> realterrain_extended_material: RealTerrainExtendedMaterial;
+@group(2) @binding(101)
+var normal_map_texture: texture_2d<f32>;
+@group(2) @binding(102)
+var normal_map_sampler: sampler;
 
 // Tiny hash / noise retained only for subtle grass/lowland breakup (very mild).
 fn hash(p: vec2<f32>) -> f32 {
@@ -75,6 +82,14 @@ fn renorm4(w: vec4<f32>) -> vec4<f32> {
     return w / s;
 }
 
+// Palette slot (0 lowland, 1 grass, 2 rock, 3 high rock) blended across biomes.
+fn biome_color(slot: u32, bw: vec4<f32>) -> vec3<f32> {
+    return realterrain_extended_material.biome_colors[slot].rgb * bw.x +
+           realterrain_extended_material.biome_colors[4u + slot].rgb * bw.y +
+           realterrain_extended_material.biome_colors[8u + slot].rgb * bw.z +
+           realterrain_extended_material.biome_colors[12u + slot].rgb * bw.w;
+}
+
 @fragment
 fn fragment(
     in: VertexOutput,
@@ -86,8 +101,17 @@ fn fragment(
     let h_denom = max(0.0001, realterrain_extended_material.max_height - realterrain_extended_material.min_height);
     let h_norm = clamp((in.world_position.y - realterrain_extended_material.min_height) / h_denom, 0.0, 1.0);
 
+    // Baked heightmap normal (world space) so coarse LOD meshes keep full lighting detail.
+    // Sampled unconditionally (uniform control flow); strength 0 keeps the mesh normal.
+    let nm_uv = in.world_position.xz / max(1.0, realterrain_extended_material.normal_map_world_size) + vec2<f32>(0.5);
+    let baked_n = textureSample(normal_map_texture, normal_map_sampler, nm_uv).xyz * 2.0 - vec3<f32>(1.0);
+    let nm_strength = clamp(realterrain_extended_material.normal_map_strength, 0.0, 1.0);
+    let world_n = normalize(mix(normalize(in.world_normal), normalize(baked_n), nm_strength));
+    pbr_input.N = world_n;
+    pbr_input.world_normal = world_n;
+
     // Slope (0 flat, 1 steep)
-    let slope = clamp(1.0 - clamp(in.world_normal.y, 0.0, 1.0), 0.0, 1.0);
+    let slope = clamp(1.0 - clamp(world_n.y, 0.0, 1.0), 0.0, 1.0);
 
     // Snow (height band)
     let snow_w = smoothstep(realterrain_extended_material.snow_height_start,
@@ -118,11 +142,20 @@ fn fragment(
     var weights = vec4<f32>(lowland_p, grass_p, rock_w, snow_w);
     weights = renorm4(weights);
 
-    // Palette
+    // Palette: biome blend weights come from the mesh vertex colors (see biome.rs);
+    // meshes without them fall back to the base palette.
+#ifdef VERTEX_COLORS
+    let bw = renorm4(max(in.color, vec4<f32>(0.0)));
+    let c_low  = biome_color(0u, bw);
+    let c_grass= biome_color(1u, bw);
+    let c_rock = biome_color(2u, bw);
+    let c_snow = biome_color(3u, bw);
+#else
     let c_low  = realterrain_extended_material.colors[0u].rgb;
     let c_grass= realterrain_extended_material.colors[1u].rgb;
     let c_rock = realterrain_extended_material.colors[2u].rgb;
     let c_snow = realterrain_extended_material.colors[3u].rgb;
+#endif
 
     var base_col = c_low * weights.x +
                    c_grass * weights.y +
@@ -0,0 +1,105 @@
+// GPU-instanced vegetation (see src/plugins/tree_instancing.rs).
+// One draw per (mesh, material) batch; vertex buffer 1 carries a world matrix + tint per tree.
+// Lighting, fog and tonemapping go through the regular PBR functions so instanced trees match
+// the StandardMaterial path (minus shadow casting). Wind sway matches shaders/tree_wind.wgsl.
+
+#import bevy_pbr::{
+    mesh_view_bindings::view,
+    view_transformations::position_world_to_clip,
+    pbr_types::{pbr_input_new, STANDARD_MATERIAL_FLAGS_FOG_ENABLED_BIT},
+    pbr_functions::{apply_pbr_lighting, main_pass_post_lighting_processing, calculate_view},
+    mesh_types::MESH_FLAGS_SHADOW_RECEIVER_BIT,
+}
+
+@group(1) @binding(0) var base_color_texture: texture_2d<f32>;
+@group(1) @binding(1) var base_color_sampler: sampler;
+
+// Must match TreeWindUniform in tree_wind.rs.
+struct TreeWind {
+    wind: vec4<f32>,   // xy = direction, z = strength, w = frequency
+    params: vec4<f32>, // x = time, y = sway height (m), z = amplitude (m), w = flutter (m)
+};
+
+@group(1) @binding(2) var<uniform> tree_wind: TreeWind;
+
+// Same function as in tree_wind.wgsl; keep the two in sync.
+fn tree_sway(world_position: vec3<f32>, origin: vec3<f32>, w: TreeWind) -> vec3<f32> {
+    let h = max(world_position.y - origin.y, 0.0) / w.params.y;
+    let bend = min(h * h, 1.5);
+    // Per-tree phase from its origin so a forest does not move in lockstep.
+    let phase = dot(origin.xz, vec2<f32>(0.13, 0.29));
+    let t = w.params.x * w.wind.w;
+    let sway = 0.65 * sin(t + phase) + 0.35 * sin(t * 2.1 + phase * 1.3);
+    let lean = w.wind.z * w.params.z * bend * (0.55 + 0.45 * sway);
+    let flutter = w.wind.z * w.params.w * bend * sin(t * 7.0 + dot(world_position, vec3<f32>(1.7, 2.3, 1.1)));
+    return vec3<f32>(
+        world_position.x + w.wind.x * lean + flutter,
+        world_position.y - abs(lean) * 0.1 + flutter * 0.5,
+        world_position.z + w.wind.y * lean - flutter,
+    );
+}
+
+struct Vertex {
+    @location(0) position: vec3<f32>,
+#ifdef VERTEX_NORMALS
+    @location(1) normal: vec3<f32>,
+#endif
+#ifdef VERTEX_UVS_A
+    @location(2) uv: vec2<f32>,
+#endif
+    // Instance attributes (INSTANCE_LOCATION = 8).
+    @location(8) i_model_0: vec4<f32>,
+    @location(9) i_model_1: vec4<f32>,
+    @location(10) i_model_2: vec4<f32>,
+    @location(11) i_model_3: vec4<f32>,
+    @location(12) i_color: vec4<f32>,
+};
+
+struct TreeVertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) world_position: vec4<f32>,
+    @location(1) world_normal: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+    @location(3) color: vec4<f32>,
+};
+
+@vertex
+fn vertex(vertex: Vertex) -> TreeVertexOutput {
+    let model = mat4x4<f32>(vertex.i_model_0, vertex.i_model_1, vertex.i_model_2, vertex.i_model_3);
+    var out: TreeVertexOutput;
+    let world_position = (model * vec4<f32>(vertex.position, 1.0)).xyz;
+    out.world_position = vec4<f32>(tree_sway(world_position, model[3].xyz, tree_wind), 1.0);
+    out.clip_position = position_world_to_clip(out.world_position.xyz);
+#ifdef VERTEX_NORMALS
+    // Tree scales are near-uniform, so the model matrix is fine for normals.
+    out.world_normal = normalize((model * vec4<f32>(vertex.normal, 0.0)).xyz);
+#else
+    out.world_normal = vec3<f32>(0.0, 1.0, 0.0);
+#endif
+#ifdef VERTEX_UVS_A
+    out.uv = vertex.uv;
+#else
+    out.uv = vec2<f32>(0.0);
+#endif
+    out.color = vertex.i_color;
+    return out;
+}
+
+@fragment
+fn fragment(in: TreeVertexOutput) -> @location(0) vec4<f32> {
+    var pbr_input = pbr_input_new();
+    pbr_input.material.base_color = textureSample(base_color_texture, base_color_sampler, in.uv) * in.color;
+    pbr_input.material.perceptual_roughness = 0.9;
+    pbr_input.material.flags |= STANDARD_MATERIAL_FLAGS_FOG_ENABLED_BIT;
+    pbr_input.frag_coord = in.clip_position;
+    pbr_input.world_position = in.world_position;
+    let n = normalize(in.world_normal);
+    pbr_input.world_normal = n;
+    pbr_input.N = n;
+    pbr_input.is_orthographic = view.clip_from_world[3].w == 1.0;
+    pbr_input.V = calculate_view(in.world_position, pbr_input.is_orthographic);
+    pbr_input.flags = MESH_FLAGS_SHADOW_RECEIVER_BIT;
+
+    let lit = apply_pbr_lighting(pbr_input);
+    return main_pass_post_lighting_processing(pbr_input, lit);
+}
@@ -0,0 +1,65 @@
+// Tree wind sway (ExtendedMaterial<StandardMaterial, TreeWindExtension>, see tree_wind.rs).
+// Vertex only: standard mesh transform, then bend by height above the tree origin.
+// The fragment stage is the regular StandardMaterial one.
+
+#import bevy_pbr::{
+    mesh_functions,
+    view_transformations::position_world_to_clip,
+    forward_io::{Vertex, VertexOutput},
+}
+
+// Must match TreeWindUniform in tree_wind.rs.
+struct TreeWind {
+    wind: vec4<f32>,   // xy = direction, z = strength, w = frequency
+    params: vec4<f32>, // x = time, y = sway height (m), z = amplitude (m), w = flutter (m)
+};
+
+@group(2) @binding(100)
+var<uniform> tree_wind: TreeWind;
+
+// Same function as in tree_instancing.wgsl; keep the two in sync.
+fn tree_sway(world_position: vec3<f32>, origin: vec3<f32>, w: TreeWind) -> vec3<f32> {
+    let h = max(world_position.y - origin.y, 0.0) / w.params.y;
+    let bend = min(h * h, 1.5);
+    // Per-tree phase from its origin so a forest does not move in lockstep.
+    let phase = dot(origin.xz, vec2<f32>(0.13, 0.29));
+    let t = w.params.x * w.wind.w;
+    let sway = 0.65 * sin(t + phase) + 0.35 * sin(t * 2.1 + phase * 1.3);
+    let lean = w.wind.z * w.params.z * bend * (0.55 + 0.45 * sway);
+    let flutter = w.wind.z * w.params.w * bend * sin(t * 7.0 + dot(world_position, vec3<f32>(1.7, 2.3, 1.1)));
+    return vec3<f32>(
+        world_position.x + w.wind.x * lean + flutter,
+        world_position.y - abs(lean) * 0.1 + flutter * 0.5,
+        world_position.z + w.wind.y * lean - flutter,
+    );
+}
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+    let world_from_local = mesh_functions::get_world_from_local(vertex.instance_index);
+    let world_position = mesh_functions::mesh_position_local_to_world(world_from_local, vec4<f32>(vertex.position, 1.0));
+    let swayed = tree_sway(world_position.xyz, world_from_local[3].xyz, tree_wind);
+
+    out.world_position = vec4<f32>(swayed, 1.0);
+    out.position = position_world_to_clip(swayed);
+#ifdef VERTEX_NORMALS
+    out.world_normal = mesh_functions::mesh_normal_local_to_world(vertex.normal, vertex.instance_index);
+#endif
+#ifdef VERTEX_UVS_A
+    out.uv = vertex.uv;
+#endif
+#ifdef VERTEX_UVS_B
+    out.uv_b = vertex.uv_b;
+#endif
+#ifdef VERTEX_TANGENTS
+    out.world_tangent = mesh_functions::mesh_tangent_local_to_world(world_from_local, vertex.tangent, vertex.instance_index);
+#endif
+#ifdef VERTEX_COLORS
+    out.color = vertex.color;
+#endif
+#ifdef VERTEX_OUTPUT_INSTANCE_INDEX
+    out.instance_index = vertex.instance_index;
+#endif
+    return out;
+}
@@ -5,8 +5,8 @@
 // NOTE: This replaces the previous custom vertex/fragment pair that caused a
 // pipeline mismatch. By using the standard PBR vertex stage we avoid IO mismatches.
 //
-// Rust side: WaterMaterial uniform at @group(2) @binding(0)
-// (auto layout produced by AsBindGroup with #[uniform(0)] on params).
+// Rust side: WaterExtension uniform at @group(2) @binding(100) of
+// ExtendedMaterial<StandardMaterial, WaterExtension> (see water_material.rs).
 
 #import bevy_pbr::{
     pbr_fragment::pbr_input_from_standard_material,
@@ -14,7 +14,7 @@
     forward_io::{VertexOutput, FragmentOutput},
 }
 
-// Must match WaterParams / WaterMaterial uniform layout in Rust (see terrain.rs).
+// Must match the WaterUniform layout in Rust (see water_material.rs).
 struct WaterMaterial {
     time: f32,
     wave_amp: f32,
@@ -25,7 +25,7 @@ struct WaterMaterial {
     color_shallow: vec4<f32>,
 };
 
-@group(2) @binding(0)
+@group(2) @binding(100)
 var<uniform> water_material: WaterMaterial;
 
 // Simple 2-direction blended sine waves (height only used for color modulation here).
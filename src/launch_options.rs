@@ -0,0 +1,169 @@
+// Command line options.
+// `LaunchOptions::parse` turns the arguments into one resource that main.rs builds the app from
+// and plugins may read (level.rs picks the start level, terrain.rs the quality preset). Options
+// take `--name value` or `--name=value`; a single leading dash works too (the older `-runtime 30`).
+// Unknown options and bad values are errors (main.rs prints them with the usage text).
+
+use std::fmt;
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+use crate::plugins::terrain_presets::TerrainPreset;
+
+pub const USAGE: &str = "\
+Usage: vibe_golf [OPTIONS]
+
+Options:
+  --level <id>             Start on this level (manifest id) instead of the last one played
+  --seed <n>               Seed the simulation's randomness (target relocation) for repeatable runs
+  --autoplay               Let a bot play the level (logs each completed hole)
+  --benchmark              Fly the benchmark camera route and write benchmark.json / .csv
+  --headless               Run without a window or GPU (with --autoplay / --runtime for checks)
+  --window-size <WxH>      Initial window size, e.g. 1280x720
+  --quality <preset>       Terrain and shadow quality for this run: low, medium, high or ultra
+  --low-power              Cap the frame rate, reduce motion and particles for this run
+  --ghost <path or URL>    Race a ghost: a .ghost.ron track or replay file, or an http(s) URL
+  --host <port>            Host a networked game on this port (builds with the net feature)
+  --join <url>             Join a networked game, e.g. ws://192.168.1.20:7777 (net feature)
+  --challenge <code>       Play a friend's challenge code (same level, mode and course)
+  --spectate               Spectator overlay for recording or casting: no controls, big scoreboard
+  --runtime <seconds>      Exit after this much simulated time (writes run_summary.json)
+  --screenshot             Capture the first and last frame
+  --screenshot-every <t>   Also capture a numbered frame every t of simulated time (2s, 500ms)
+  --screenshot-scale <f>   Downscale those frames by f (0.1..1, default 1)
+  --data-dir <path>        Where saves, settings, caches and screenshots go
+  -h, --help               Print this help";
+
+const OPTIONS: [&str; 19] = [
+    "help", "level", "seed", "autoplay", "benchmark", "headless", "window-size", "quality", "low-power", "ghost",
+    "host", "join", "challenge", "spectate", "runtime", "screenshot", "screenshot-every", "screenshot-scale", "data-dir",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgError {
+    Unknown(String),
+    MissingValue(&'static str),
+    BadValue { option: &'static str, value: String },
+    UnexpectedValue(&'static str),
+}
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown(arg) => write!(f, "unknown option '{arg}'"),
+            Self::MissingValue(option) => write!(f, "--{option} needs a value"),
+            Self::BadValue { option, value } => write!(f, "invalid value '{value}' for --{option}"),
+            Self::UnexpectedValue(option) => write!(f, "--{option} takes no value"),
+        }
+    }
+}
+
+impl std::error::Error for ArgError {}
+
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct LaunchOptions {
+    pub level: Option<String>,
+    pub seed: Option<u64>,
+    pub autoplay: bool,
+    pub benchmark: bool,
+    pub headless: bool,
+    pub window_size: Option<(u32, u32)>,
+    pub quality: Option<TerrainPreset>,
+    pub low_power: bool,
+    pub ghost: Option<String>, // ghost track file or URL
+    pub host: Option<u16>,     // net session port (net.rs)
+    pub join: Option<String>,  // net session URL
+    pub challenge: Option<String>, // challenge code (challenge.rs)
+    pub spectate: bool,            // spectator overlay (spectate.rs)
+    pub runtime: Option<f32>, // s; also turns on the auto-exit
+    pub screenshot: bool,
+    pub screenshot_every: Option<f32>, // s between sequence frames
+    pub screenshot_scale: Option<f32>,
+    pub data_dir: Option<PathBuf>,
+    pub help: bool,
+}
+
+/// `1280x720` -> (1280, 720).
+pub fn parse_window_size(s: &str) -> Option<(u32, u32)> {
+    let (w, h) = s.trim().split_once(['x', 'X'])?;
+    let (w, h) = (w.parse().ok()?, h.parse().ok()?);
+    (w > 0 && h > 0).then_some((w, h))
+}
+
+/// `2s`, `500ms` or a bare number of seconds; must be positive.
+pub fn parse_interval(s: &str) -> Option<f32> {
+    let s = s.trim();
+    let seconds = match s.strip_suffix("ms") {
+        Some(ms) => ms.trim().parse::<f32>().ok()? / 1000.0,
+        None => s.strip_suffix('s').unwrap_or(s).trim().parse::<f32>().ok()?,
+    };
+    (seconds > 0.0).then_some(seconds)
+}
+
+impl LaunchOptions {
+    /// Any screenshot capture asked for (first / last frame or a sequence).
+    pub fn screenshots_enabled(&self) -> bool {
+        self.screenshot || self.screenshot_every.is_some()
+    }
+
+    /// Parse the arguments after the program name.
+    pub fn parse<S: AsRef<str>>(args: impl IntoIterator<Item = S>) -> Result<Self, ArgError> {
+        let mut options = Self::default();
+        let mut args = args.into_iter().map(|a| a.as_ref().to_string());
+        while let Some(arg) = args.next() {
+            let Some(stripped) = arg.strip_prefix("--").or_else(|| arg.strip_prefix('-')) else {
+                return Err(ArgError::Unknown(arg));
+            };
+            let (name, inline) = match stripped.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (stripped, None),
+            };
+            let name = if name == "h" { "help" } else { name };
+            let Some(option) = OPTIONS.into_iter().find(|o| *o == name) else {
+                return Err(ArgError::Unknown(arg));
+            };
+            let flag = |on: &mut bool| {
+                *on = true;
+                match inline {
+                    Some(_) => Err(ArgError::UnexpectedValue(option)),
+                    None => Ok(()),
+                }
+            };
+            match option {
+                "help" => flag(&mut options.help)?,
+                "autoplay" => flag(&mut options.autoplay)?,
+                "benchmark" => flag(&mut options.benchmark)?,
+                "headless" => flag(&mut options.headless)?,
+                "low-power" => flag(&mut options.low_power)?,
+                "screenshot" => flag(&mut options.screenshot)?,
+                "spectate" => flag(&mut options.spectate)?,
+                _ => {
+                    let value = inline.or_else(|| args.next()).ok_or(ArgError::MissingValue(option))?;
+                    let bad = || ArgError::BadValue { option, value: value.clone() };
+                    match option {
+                        "level" => options.level = Some(value.clone()),
+                        "ghost" => options.ghost = Some(value.clone()),
+                        "host" => options.host = Some(value.parse().map_err(|_| bad())?),
+                        "join" => options.join = Some(value.clone()),
+                        "challenge" => options.challenge = Some(value.clone()),
+                        "seed" => options.seed = Some(value.parse().map_err(|_| bad())?),
+                        "window-size" => options.window_size = Some(parse_window_size(&value).ok_or_else(bad)?),
+                        "quality" => options.quality = Some(TerrainPreset::from_name(&value).ok_or_else(bad)?),
+                        "runtime" => {
+                            let seconds = value.parse::<f32>().ok().filter(|s| *s > 0.0).ok_or_else(bad)?;
+                            options.runtime = Some(seconds);
+                        }
+                        "screenshot-every" => options.screenshot_every = Some(parse_interval(&value).ok_or_else(bad)?),
+                        "screenshot-scale" => {
+                            let scale = value.parse::<f32>().ok().filter(|f| (0.1..=1.0).contains(f)).ok_or_else(bad)?;
+                            options.screenshot_scale = Some(scale);
+                        }
+                        _ => options.data_dir = Some(PathBuf::from(&value)),
+                    }
+                }
+            }
+        }
+        Ok(options)
+    }
+}
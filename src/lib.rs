@@ -3,23 +3,104 @@
 
 pub mod plugins {
     pub mod core_sim;
+    pub mod headless;
     pub mod game_state;
     pub mod level;
+    pub mod level_validation;
+    pub mod level_props;
+    pub mod campaign;
+    pub mod level_script;
+    pub mod daily;
+    pub mod save_game;
+    pub mod replay;
+    pub mod game_mode;
+    pub mod storage;
     pub mod ball;
     pub mod target;
+    pub mod target_relocation;
+    pub mod target_proximity;
+    pub mod cup_target;
+    pub mod difficulty;
+    pub mod special_targets;
+    pub mod gates;
     pub mod shooting;
+    pub mod landing_reticle;
     pub mod autoplay;
+    pub mod benchmark;
+    pub mod run_summary;
     pub mod hud;
+    pub mod hud_layout;
+    pub mod ui_theme;
+    pub mod target_label;
+    pub mod ui_toast;
+    pub mod touch_controls;
+    pub mod hit_indicator;
+    pub mod tutorial;
+    pub mod event_log;
+    pub mod scoreboard;
+    pub mod game_over;
+    pub mod pause_menu;
+    pub mod minimap;
     pub mod camera;
+    pub mod ball_cam;
+    pub mod camera_cinematic;
+    pub mod camera_obstruction;
+    pub mod overview_camera;
+    pub mod photo_mode;
     pub mod terrain;
+    pub mod terrain_deform;
+    pub mod biome;
+    pub mod terrain_presets;
+    pub mod shadow_settings;
     pub mod particles;
     pub mod game_audio;
     pub mod contour_material;
     pub mod terrain_material;
     pub mod terrain_graph;
     pub mod vegetation;
+    pub mod vegetation_species;
+    pub mod vegetation_cache;
+    pub mod vegetation_exclusion;
+    pub mod vegetation_mask;
+    pub mod vegetation_props;
+    pub mod tree_instancing;
+    pub mod tree_wind;
+    pub mod wind;
+    pub mod tree_impact;
+    pub mod spatial_grid;
+    pub mod grass;
+    pub mod localization;
+    pub mod user_settings;
+    pub mod profile;
+    pub mod achievements;
     pub mod main_menu;
     pub mod performance_menu;
+    pub mod perf_overlay;
+    pub mod debug_gizmos;
+    pub mod memory_inspector;
+    pub mod spawn_view;
+    pub mod loading_state;
+    pub mod texture_pipeline;
+    pub mod low_power;
+    pub mod app_state;
+    pub mod players;
+    pub mod ghost_race;
+    pub mod challenge;
+    pub mod spectate;
+    pub mod day_night;
+    pub mod weather;
+    pub mod water_material;
+    pub mod rivers;
+    pub mod birds;
+    pub mod hole_in_one;
+    pub mod trick_shots;
+    pub mod powerups;
+    pub mod obstacles;
+    pub mod vibe_meter;
+    #[cfg(feature = "net")]
+    pub mod net;
 }
 pub mod screenshot;
+pub mod launch_options;
+pub mod visual_regression;
 pub mod prelude;
@@ -3,51 +3,147 @@
 /// R1/P1: Modularization – systems split into focused plugins under src/plugins/ (core_sim, level, ball, target, shooting, autoplay, hud, camera, terrain, particles, audio, vegetation, etc).
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
-use bevy::diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin};
+use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
 use bevy::asset::{AssetPlugin, AssetMode};
+use bevy::app::ScheduleRunnerPlugin;
+use bevy::render::settings::WgpuSettings;
+use bevy::render::RenderPlugin;
+use bevy::window::{ExitCondition, WindowResolution};
+use bevy::winit::WinitPlugin;
+use std::time::Duration;
 
 use vibe_golf::plugins::{
-    core_sim::{CoreSimPlugin, AutoConfig},
+    core_sim::{CoreSimPlugin, AutoConfig, SimRng},
+    autoplay::AutoplayPlugin,
+    benchmark::BenchmarkPlugin,
+    run_summary::RunSummaryPlugin,
+    app_state::AppStatePlugin,
     game_state::GameStatePlugin,
     level::LevelPlugin,
+    level_validation::LevelValidationPlugin,
+    level_props::LevelPropsPlugin,
+    campaign::CampaignPlugin,
+    level_script::LevelScriptPlugin,
+    save_game::SaveGamePlugin,
+    replay::ReplayPlugin,
+    storage::StoragePaths,
+    game_mode::GameModePlugin,
     ball::BallPlugin,
     target::TargetPlugin,
+    special_targets::SpecialTargetsPlugin,
+    gates::GatePlugin,
+    target_proximity::TargetProximityPlugin,
+    cup_target::CupTargetPlugin,
     shooting::ShootingPlugin,
+    landing_reticle::LandingReticlePlugin,
     hud::HudPlugin,
+    hud_layout::HudLayoutPlugin,
+    ui_theme::UiThemePlugin,
+    target_label::TargetLabelPlugin,
+    ui_toast::UiToastPlugin,
+    touch_controls::TouchControlsPlugin,
+    hit_indicator::HitIndicatorPlugin,
+    tutorial::TutorialPlugin,
+    event_log::EventLogPlugin,
+    scoreboard::ScoreboardPlugin,
+    game_over::GameOverPlugin,
+    pause_menu::PauseMenuPlugin,
+    minimap::MinimapPlugin,
     camera::CameraPlugin,
+    overview_camera::OverviewCameraPlugin,
+    photo_mode::PhotoModePlugin,
+    ball_cam::BallCamPlugin,
+    spectate::SpectatePlugin,
     terrain::TerrainPlugin,
+    shadow_settings::ShadowSettingsPlugin,
+    terrain_deform::TerrainDeformPlugin,
     vegetation::VegetationPlugin,
+    tree_instancing::TreeInstancingPlugin,
+    tree_wind::TreeWindPlugin,
+    wind::WindPlugin,
+    day_night::DayNightPlugin,
+    weather::WeatherPlugin,
+    water_material::WaterMaterialPlugin,
+    rivers::RiversPlugin,
+    birds::BirdsPlugin,
+    hole_in_one::HoleInOnePlugin,
+    trick_shots::TrickShotsPlugin,
+    powerups::PowerupsPlugin,
+    obstacles::ObstaclesPlugin,
+    vibe_meter::VibeMeterPlugin,
+    tree_impact::TreeImpactPlugin,
+    grass::GrassPlugin,
     particles::ParticlePlugin,
     game_audio::GameAudioPlugin,
     terrain_material::TerrainMaterialPlugin,
+    localization::LocalizationPlugin,
+    profile::ProfilePlugin,
+    achievements::AchievementsPlugin,
+    loading_state::LoadingStatePlugin,
+    texture_pipeline::TexturePipelinePlugin,
+    low_power::LowPowerPlugin,
+    players::PlayersPlugin,
+    ghost_race::GhostRacePlugin,
+    challenge::ChallengePlugin,
     main_menu::MainMenuPlugin,
     performance_menu::PerformanceMenuPlugin,
+    perf_overlay::PerfOverlayPlugin,
+    debug_gizmos::DebugGizmosPlugin,
+    memory_inspector::MemoryInspectorPlugin,
 };
 
 use vibe_golf::screenshot::{ScreenshotPlugin, ScreenshotConfig};
+use vibe_golf::launch_options::{LaunchOptions, USAGE};
 
 fn main() {
     // Better panic messages in the browser console when running under WebAssembly.
     #[cfg(target_arch = "wasm32")]
     console_error_panic_hook::set_once();
 
-    let args: Vec<String> = std::env::args().collect();
-    // Screenshots now opt-in: enable only if --screenshot flag is present.
-    let screenshot_enabled = args.iter().any(|a| a == "--screenshot");
-    // Parse -runtime / --runtime flags (supports -runtime 30, --runtime 30, -runtime=30, --runtime=30)
-    // Also detect whether the flag was supplied to enable auto-exit behavior.
-    let mut runtime_flag: Option<f32> = None;
-    for (i, a) in args.iter().enumerate() {
-        if a == "-runtime" || a == "--runtime" {
-            if let Some(val) = args.get(i + 1) {
-                if let Ok(f) = val.parse::<f32>() { runtime_flag = Some(f); }
-            }
-        } else if let Some(stripped) = a.strip_prefix("-runtime=").or_else(|| a.strip_prefix("--runtime=")) {
-            if let Ok(f) = stripped.parse::<f32>() { runtime_flag = Some(f); }
+    let options = match LaunchOptions::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("vibe_golf: {e}\n\n{USAGE}");
+            std::process::exit(2);
         }
+    };
+    if options.help {
+        println!("{USAGE}");
+        return;
+    }
+    // --runtime also turns on the auto-exit.
+    let exit_enabled = options.runtime.is_some();
+    let runtime_seconds = options.runtime.unwrap_or(20.0);
+    // Saves, settings, caches and screenshots: --data-dir <path>, else see StoragePaths::resolve.
+    let storage = StoragePaths::resolve(options.data_dir.clone());
+
+    let mut window = Window {
+        title: "Vibe Golf".into(),
+        #[cfg(target_arch = "wasm32")]
+        canvas: Some("#bevy-canvas".into()),
+        ..default()
+    };
+    if let Some((width, height)) = options.window_size {
+        window.resolution = WindowResolution::new(width as f32, height as f32);
+    }
+    let mut default_plugins = DefaultPlugins
+        .set(WindowPlugin {
+            primary_window: (!options.headless).then_some(window),
+            exit_condition: if options.headless { ExitCondition::DontExit } else { ExitCondition::OnPrimaryClosed },
+            ..default()
+        })
+        .set(AssetPlugin {
+            // On web we don't ship processed .meta files; use unprocessed mode to avoid 404s.
+            mode: AssetMode::Unprocessed,
+            file_path: "assets".into(),
+            ..default()
+        });
+    if options.headless {
+        // No window, no GPU: the schedule runs on a plain 60 Hz loop and nothing is rendered.
+        default_plugins = default_plugins
+            .disable::<WinitPlugin>()
+            .set(RenderPlugin { render_creation: WgpuSettings { backends: None, ..default() }.into(), ..default() });
     }
-    let exit_enabled = runtime_flag.is_some();
-    let runtime_seconds = runtime_flag.unwrap_or(20.0);
 
     // Build the app in stages to allow cfg-gated plugin insertion without illegal attributes in method chains.
     let mut app = App::new();
@@ -58,49 +154,117 @@ fn main() {
             color: Color::srgb(0.55, 0.55, 0.60),
             brightness: 800.0,
         })
-        .insert_resource(ScreenshotConfig::new(screenshot_enabled))
-        .add_plugins(
-            DefaultPlugins
-                .set(WindowPlugin {
-                    primary_window: Some(Window {
-                        title: "Vibe Golf".into(),
-                        #[cfg(target_arch = "wasm32")]
-                        canvas: Some("#bevy-canvas".into()),
-                        ..default()
-                    }),
-                    ..default()
-                })
-                .set(AssetPlugin {
-                    // On web we don't ship processed .meta files; use unprocessed mode to avoid 404s.
-                    mode: AssetMode::Unprocessed,
-                    file_path: "assets".into(),
-                    ..default()
-                })
-        );
-
+        .insert_resource(
+            ScreenshotConfig::new(options.screenshots_enabled(), &storage.screenshots_dir())
+                .with_sequence(options.screenshot_every, options.screenshot_scale.unwrap_or(1.0)),
+        )
+        .insert_resource(storage)
+        .insert_resource(options.clone())
+        .add_plugins(default_plugins);
+    if options.headless {
+        app.add_plugins(ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(1.0 / 60.0)));
+    }
+    if let Some(seed) = options.seed {
+        app.insert_resource(SimRng::seeded(seed));
+    }
 
     app.add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
         // Gameplay & rendering plugins (order preserved)
+        .add_plugins(AppStatePlugin)        // Loading / Menu / Playing / Paused / GameOver / Replay states
         .add_plugins(CoreSimPlugin)         // timing + shared resources
+        .add_plugins(RunSummaryPlugin)      // run_summary.json on exit (with --runtime)
         .add_plugins(TerrainMaterialPlugin) // realistic terrain material (shader)
         .add_plugins(TerrainPlugin)         // procedural terrain
+        .add_plugins(TerrainDeformPlugin)   // runtime terrain stamps (cup greens)
+        .add_plugins(ShadowSettingsPlugin)  // sun shadow quality (follows the quality preset)
+        .add_plugins(LowPowerPlugin)        // FPS cap, reduced motion and particles (settings / --low-power)
         .add_plugins(VegetationPlugin)      // procedural vegetation (trees)
+        .add_plugins(TreeInstancingPlugin)  // GPU instance batches for vegetation
+        .add_plugins(WindPlugin)            // global wind state (gusts)
+        .add_plugins(DayNightPlugin)        // level day/night cycle: sun, sky tint, night target glow
+        .add_plugins(WeatherPlugin)         // level weather: wind, rain, storms; ball friction/bounce/drift
+        .add_plugins(WaterMaterialPlugin)   // animated water surface (shader)
+        .add_plugins(RiversPlugin)          // rivers & waterfalls traced on the heightmap (hazards)
+        .add_plugins(BirdsPlugin)           // bird flocks circling the woods, scattering from the ball
+        .add_plugins(HoleInOnePlugin)       // hole-in-one fanfare, burst and slow-motion replay
+        .add_plugins(TrickShotsPlugin)      // bank / water skim / bob apex trick bonuses
+        .add_plugins(PowerupsPlugin)        // course pickups: extra power, sticky, bullet time, magnet (U)
+        .add_plugins(ObstaclesPlugin)       // breakable crates / ice panels, target guards
+        .add_plugins(VibeMeterPlugin)       // vibe meter ring: perfect aim / double power strokes (Z)
+        .add_plugins(TreeWindPlugin)        // tree wind sway (material extension + instanced)
+        .add_plugins(GrassPlugin)           // instanced grass clumps near the camera
         .add_plugins(ParticlePlugin)        // particle & FX systems
         .add_plugins(GameAudioPlugin)       // game audio (music + sfx)
         .add_plugins(GameStatePlugin)       // shot state, scoring
+        .add_plugins(ProfilePlugin)         // profile.json (best records, lifetime stats, settings)
+        .add_plugins(AchievementsPlugin)    // milestone unlocks + menu achievements panel
+        .add_plugins(LocalizationPlugin)    // UI strings from assets/i18n (language selector in settings)
+        .add_plugins(TexturePipelinePlugin) // compressed KTX2 texture variants + mip generation
+        .add_plugins(LoadingStatePlugin)    // startup asset preload + loading screen, then the menu
         .add_plugins(MainMenuPlugin)        // main menu (Play/Quit/High Score)
         .add_plugins(LevelPlugin)           // level loading & world entities
+        .add_plugins(LevelValidationPlugin) // level sanity checks (log + F4 issue list)
+        .add_plugins(LevelPropsPlugin)      // level-placed props (ball vs prop colliders)
+        .add_plugins(CampaignPlugin)        // completed levels, medals, level unlocks
+        .add_plugins(LevelScriptPlugin)     // level-defined triggers (hole start, hits, timers)
+        .add_plugins(SaveGamePlugin)        // unfinished round save slot + menu Continue
+        .add_plugins(ReplayPlugin)          // round recording, playback from the menu, export/import
+        .add_plugins(GhostRacePlugin)       // race an imported run's ghost with a lead/behind delta
+        .add_plugins(ChallengePlugin)       // challenge codes: seeded course, head-to-head records
+        .add_plugins(GameModePlugin)        // rule variants: time attack, stroke play, hard par
+        .add_plugins(PlayersPlugin)         // local hot-seat players: turns, per-player scores, ball colors
         .add_plugins(BallPlugin)            // ball physics
+        .add_plugins(TreeImpactPlugin)      // ball vs tree trunks (crack, wobble, felling)
         .add_plugins(TargetPlugin)          // target motion + hit detection
+        .add_plugins(SpecialTargetsPlugin)  // decoys + golden bonus targets
+        .add_plugins(GatePlugin)            // ring gates to fly through before the target counts
+        .add_plugins(TargetProximityPlugin) // hot/cold beacon + beeps after each shot
+        .add_plugins(CupTargetPlugin)       // ground cup + flag targets
         .add_plugins(ShootingPlugin)        // shooting input & trajectory UI
-        // .add_plugins(AutoplayPlugin)     // optional automated swings
+        .add_plugins(LandingReticlePlugin)  // predicted touchdown reticle on the terrain while charging
         .add_plugins(HudPlugin)             // HUD (score/time)
+        .add_plugins(HudLayoutPlugin)       // UI scale + safe-area insets for screen-edge HUD
+        .add_plugins(UiThemePlugin)         // colorblind palettes + high-contrast HUD (settings > Accessibility)
+        .add_plugins(TargetLabelPlugin)     // distance label floating above the target
+        .add_plugins(UiToastPlugin)         // queued toasts (hole progress, shots, new best)
+        .add_plugins(TouchControlsPlugin)   // on-screen FIRE / stick / nudge / reset (after first touch)
+        .add_plugins(HitIndicatorPlugin)    // flash + screen-edge marker for far / off-screen hits
+        .add_plugins(TutorialPlugin)        // first-run tutorial (assets/tutorial.ron), skippable
+        .add_plugins(EventLogPlugin)        // L: collapsible log of recent shots / hits / penalties / records
+        .add_plugins(ScoreboardPlugin)      // per-hole stats table (Tab)
+        .add_plugins(GameOverPlugin)        // game-over panel (breakdown, Restart / Main Menu)
+        .add_plugins(PauseMenuPlugin)       // Escape: pause menu (Resume / Settings / Restart / Quit)
+        .add_plugins(MinimapPlugin)         // heightmap minimap (ball/target markers, click-to-ping)
         .add_plugins(CameraPlugin)          // camera follow/orbit
+        .add_plugins(OverviewCameraPlugin)  // picture-in-picture ball + target overview
+        .add_plugins(PhotoModePlugin)       // P: paused free camera, DoF, photo capture
+        .add_plugins(BallCamPlugin)         // B: first-person camera riding on the ball
+        .add_plugins(SpectatePlugin)        // --spectate: stream overlay, last-shot banner, camera cuts
         .add_plugins(PerformanceMenuPlugin) // realtime performance menu (gear icon)
         .add_plugins(FrameTimeDiagnosticsPlugin)
-        .add_plugins(LogDiagnosticsPlugin::default());
+        .add_plugins(PerfOverlayPlugin)     // F3: frame-time graph + counters (replaces console diagnostics)
+        .add_plugins(DebugGizmosPlugin)     // F5: velocity / normal / collider / chunk / camera-spring gizmos
+        .add_plugins(MemoryInspectorPlugin); // F6: asset counts / bytes + entities per feature (leak check)
+
+    if options.autoplay {
+        // The game plays itself (aims at the target, logs holes; exits at round end with -runtime).
+        app.add_plugins(AutoplayPlugin);
+    }
+
+    if options.benchmark {
+        // Fixed camera route + frame-time / chunk / entity metrics (benchmark.json / .csv), then exit.
+        app.add_plugins(BenchmarkPlugin);
+    }
+
+    // Shared-world play with friends (--host / --join).
+    #[cfg(feature = "net")]
+    app.add_plugins(vibe_golf::plugins::net::NetPlugin);
+    #[cfg(not(feature = "net"))]
+    if options.host.is_some() || options.join.is_some() {
+        warn!("--host / --join need a build with the net feature (cargo run --features net)");
+    }
 
-    if screenshot_enabled {
+    if options.screenshots_enabled() {
         // Add screenshot capture plugin only when flag is provided.
         app.add_plugins(ScreenshotPlugin);
     }
@@ -0,0 +1,241 @@
+// Achievements.
+// One-time milestones unlocked from the existing gameplay events and lifetime stats (profile.rs):
+// a first hole in one, a 300 m drive, finishing a round in under two minutes and hitting a target
+// while the ball is in the air. An unlock shows a toast and an event log line and is kept in the
+// profile (by id, so renaming or reordering entries keeps them). The main menu's Achievements
+// button opens a panel listing every achievement with its state (Escape or the button closes it).
+
+use bevy::prelude::*;
+
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::event_log::{EventLog, LogKind};
+use crate::plugins::game_state::Score;
+use crate::plugins::localization::{Localization, LocalizedText};
+use crate::plugins::app_state::AppState;
+use crate::plugins::particles::{GameOverEvent, TargetHitEvent};
+use crate::plugins::profile::{record_lifetime_stats, LifetimeStats, Profile};
+use crate::plugins::target::TargetKind;
+use crate::plugins::terrain::TerrainSampler;
+use crate::plugins::ui_toast::{ToastKind, ToastQueue};
+
+pub const LONG_DRIVE_M: f32 = 300.0;
+pub const QUICK_FINISH_S: f32 = 120.0;
+pub const MID_AIR_CLEARANCE: f32 = 1.0; // m between the ball's underside and the ground
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Achievement {
+    HoleInOne,
+    LongDrive,
+    QuickFinish,
+    MidAirHit,
+}
+
+impl Achievement {
+    pub const ALL: [Achievement; 4] = [Self::HoleInOne, Self::LongDrive, Self::QuickFinish, Self::MidAirHit];
+
+    /// Stable id stored in the profile.
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::HoleInOne => "hole_in_one",
+            Self::LongDrive => "long_drive",
+            Self::QuickFinish => "quick_finish",
+            Self::MidAirHit => "mid_air_hit",
+        }
+    }
+
+    pub fn name_key(self) -> &'static str {
+        match self {
+            Self::HoleInOne => "achievement.hole_in_one",
+            Self::LongDrive => "achievement.long_drive",
+            Self::QuickFinish => "achievement.quick_finish",
+            Self::MidAirHit => "achievement.mid_air_hit",
+        }
+    }
+
+    pub fn description_key(self) -> &'static str {
+        match self {
+            Self::HoleInOne => "achievement.hole_in_one.desc",
+            Self::LongDrive => "achievement.long_drive.desc",
+            Self::QuickFinish => "achievement.quick_finish.desc",
+            Self::MidAirHit => "achievement.mid_air_hit.desc",
+        }
+    }
+}
+
+/// Achievements the lifetime stats already qualify for.
+pub fn earned_by_stats(stats: &LifetimeStats) -> Vec<Achievement> {
+    let mut earned = Vec::new();
+    if stats.holes_in_one > 0 {
+        earned.push(Achievement::HoleInOne);
+    }
+    if stats.longest_drive >= LONG_DRIVE_M {
+        earned.push(Achievement::LongDrive);
+    }
+    earned
+}
+
+/// Whether a finished round earns QuickFinish.
+pub fn is_quick_finish(final_time: f32) -> bool {
+    final_time < QUICK_FINISH_S
+}
+
+/// Menu button opening the achievements panel (spawned by main_menu.rs).
+#[derive(Component)]
+pub struct AchievementsButton;
+
+#[derive(Component)]
+struct AchievementsPanel;
+#[derive(Component)]
+struct AchievementRow(usize); // index into Achievement::ALL
+
+#[derive(Resource, Debug, Default)]
+pub struct AchievementsPanelOpen(pub bool);
+
+pub struct AchievementsPlugin;
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AchievementsPanelOpen>()
+            .add_systems(Startup, spawn_achievements_panel)
+            .add_systems(Update, check_achievements.after(record_lifetime_stats))
+            .add_systems(Update, (toggle_achievements_panel, update_achievements_panel).chain());
+    }
+}
+
+fn check_achievements(
+    score: Res<Score>,
+    loc: Res<Localization>,
+    sampler: Option<Res<TerrainSampler>>,
+    mut profile: ResMut<Profile>,
+    mut toasts: ResMut<ToastQueue>,
+    mut log: Option<ResMut<EventLog>>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+    mut ev_game_over: EventReader<GameOverEvent>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+) {
+    let mut earned = if profile.is_changed() { earned_by_stats(&profile.stats) } else { Vec::new() };
+    if ev_game_over.read().count() > 0 && is_quick_finish(score.final_time) {
+        earned.push(Achievement::QuickFinish);
+    }
+    let standard_hit = ev_hit.read().any(|e| e.kind == TargetKind::Standard);
+    if let (true, Some(sampler), Ok((t, kin))) = (standard_hit, sampler, q_ball.get_single()) {
+        let clearance = t.translation.y - kin.collider_radius - sampler.height(t.translation.x, t.translation.z);
+        if clearance > MID_AIR_CLEARANCE {
+            earned.push(Achievement::MidAirHit);
+        }
+    }
+    for achievement in earned {
+        if profile.is_unlocked(achievement.id()) {
+            continue;
+        }
+        profile.unlock(achievement.id());
+        let text = loc.tf("achievement.unlocked", &[("name", loc.t(achievement.name_key()).to_string())]);
+        toasts.push_for(text.clone(), ToastKind::Success, 3.0);
+        if let Some(log) = log.as_mut() {
+            log.push(LogKind::Record, text);
+        }
+    }
+}
+
+fn spawn_achievements_panel(mut commands: Commands, assets: Res<AssetServer>) {
+    let font = assets.load("fonts/FiraSans-Bold.ttf");
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
+                z_index: ZIndex::Global(50),
+                ..default()
+            },
+            AchievementsPanel,
+        ))
+        .with_children(|root| {
+            root.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(10.0),
+                    padding: UiRect::all(Val::Px(20.0)),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(0.05, 0.06, 0.10, 0.95)),
+                ..default()
+            })
+            .with_children(|panel| {
+                panel.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle { font: font.clone(), font_size: 32.0, color: Color::srgb(0.95, 0.95, 1.0) },
+                    ),
+                    LocalizedText::new("achievements.title"),
+                ));
+                for i in 0..Achievement::ALL.len() {
+                    panel.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle { font: font.clone(), font_size: 20.0, color: Color::srgb(0.85, 0.85, 0.90) },
+                        ),
+                        AchievementRow(i),
+                    ));
+                }
+                panel.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle { font: font.clone(), font_size: 16.0, color: Color::srgb(0.55, 0.55, 0.60) },
+                    ),
+                    LocalizedText::new("achievements.close_hint"),
+                ));
+            });
+        });
+}
+
+fn toggle_achievements_panel(
+    keys: Res<ButtonInput<KeyCode>>,
+    phase: Res<State<AppState>>,
+    mut open: ResMut<AchievementsPanelOpen>,
+    q_button: Query<&Interaction, (Changed<Interaction>, With<AchievementsButton>)>,
+) {
+    if *phase.get() != AppState::Menu {
+        if open.0 {
+            open.0 = false;
+        }
+        return;
+    }
+    if q_button.iter().any(|i| *i == Interaction::Pressed) {
+        open.0 = !open.0;
+    } else if open.0 && keys.just_pressed(KeyCode::Escape) {
+        open.0 = false;
+    }
+}
+
+fn update_achievements_panel(
+    open: Res<AchievementsPanelOpen>,
+    profile: Res<Profile>,
+    loc: Res<Localization>,
+    mut q_panel: Query<&mut Visibility, With<AchievementsPanel>>,
+    mut q_rows: Query<(&mut Text, &AchievementRow)>,
+) {
+    if !(open.is_changed() || profile.is_changed() || loc.is_changed()) {
+        return;
+    }
+    for mut vis in &mut q_panel {
+        *vis = if open.0 { Visibility::Inherited } else { Visibility::Hidden };
+    }
+    for (mut text, row) in &mut q_rows {
+        let achievement = Achievement::ALL[row.0];
+        let unlocked = profile.is_unlocked(achievement.id());
+        let key = if unlocked { "achievements.row_unlocked" } else { "achievements.row_locked" };
+        let args = [
+            ("name", loc.t(achievement.name_key()).to_string()),
+            ("description", loc.t(achievement.description_key()).to_string()),
+        ];
+        text.sections[0].value = loc.tf(key, &args);
+        text.sections[0].style.color =
+            if unlocked { Color::srgb(0.95, 0.85, 0.45) } else { Color::srgb(0.6, 0.6, 0.65) };
+    }
+}
@@ -0,0 +1,61 @@
+// Top-level application state.
+// `AppState` is a Bevy `States` type: systems that only make sense in some states gate on it with
+// `run_if(in_state(..))` (or the shared `gameplay_running` / `in_game` conditions below) instead
+// of checking a resource themselves, one-off work hangs off `OnEnter` / `OnExit` (the main menu
+// spawns on `OnEnter(Menu)`), and transitions are requested through `NextState<AppState>`; Bevy
+// applies them in the StateTransition schedule right after PreUpdate of the next frame.
+// Flow: Loading -> Menu -> Playing <-> Paused, Playing -> GameOver -> Playing (restart) or Menu,
+// Menu -> Replay -> Menu.
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AppState {
+    #[default]
+    Loading, // preloading startup assets behind the loading screen (loading_state.rs)
+    Menu,
+    Playing,
+    Paused,   // Escape during play (pause_menu.rs)
+    GameOver, // the round is over, the game-over panel is up (game_over.rs)
+    Replay,   // watching a recorded round (replay.rs)
+}
+
+impl AppState {
+    /// Playing, paused or just finished: the world of the current run is live.
+    pub fn in_game(self) -> bool {
+        matches!(self, Self::Playing | Self::Paused | Self::GameOver)
+    }
+
+    /// Playing or looking over the finished round: the round is on screen and not paused.
+    pub fn round_in_view(self) -> bool {
+        matches!(self, Self::Playing | Self::GameOver)
+    }
+}
+
+/// Run condition for gameplay systems (simulation tick, ball physics, target motion, shooting):
+/// false while the pause menu is open or a replay drives the world. Apps without the state
+/// (headless tools) always run them.
+pub fn gameplay_running(state: Option<Res<State<AppState>>>) -> bool {
+    !matches!(state.map(|s| *s.get()), Some(AppState::Paused | AppState::Replay))
+}
+
+/// Run condition: the current run's world is live (see `AppState::in_game`).
+pub fn in_game(state: Option<Res<State<AppState>>>) -> bool {
+    state.is_some_and(|s| s.get().in_game())
+}
+
+/// Run condition: see `AppState::round_in_view` (orbit camera, photo mode).
+pub fn round_in_view(state: Option<Res<State<AppState>>>) -> bool {
+    state.is_some_and(|s| s.get().round_in_view())
+}
+
+pub struct AppStatePlugin;
+impl Plugin for AppStatePlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<StatesPlugin>() {
+            app.add_plugins(StatesPlugin);
+        }
+        app.init_state::<AppState>();
+    }
+}
@@ -1,54 +1,268 @@
+// Autoplay bot (--autoplay).
+// A simple AI that plays the round by itself, for end-to-end checks of levels: it starts the
+// selected level from the main menu, waits for the ball to settle, then aims at the focused
+// target (TargetFocus, the nearest standard target). The bearing is straight at the target and the
+// power comes from projectile math at the fixed launch angle; when that arc is out of range or
+// runs into the terrain (TerrainSampler) it lays up instead, picking the power whose predicted
+// touchdown lands closest to the target. Shots go through `fire_shot` like the player's, so
+// scoring, stats and replays see them as ordinary shots. Every completed hole and the end of the
+// round are logged ("AUTOPLAY ...") and kept in `AutoplayReport`; with -runtime the game exits
+// once the round is over.
+
 use bevy::prelude::*;
-use bevy_rapier3d::prelude::*;
 
-use crate::plugins::core_sim::{SimState, AutoConfig, AutoRuntime, LogState};
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::core_sim::{AutoConfig, AutoRuntime, GameSet, LogState, SimState};
+use crate::plugins::game_state::{Score, ShotConfig, ShotMode, ShotState};
+use crate::plugins::landing_reticle::predict_landing;
+use crate::plugins::app_state::{gameplay_running, AppState};
+use crate::plugins::main_menu::MenuRoot;
+use crate::plugins::particles::{GameOverEvent, ShotFiredEvent, TargetHitEvent};
+use crate::plugins::save_game::RoundSlot;
+use crate::plugins::shooting::{fire_shot, shot_direction, shot_power_scale};
+use crate::plugins::target::{TargetFocus, TargetKind};
+use crate::plugins::terrain::TerrainSampler;
 use crate::screenshot::{ScreenshotConfig, ScreenshotState};
-use crate::plugins::ball::Ball;
+
+const G: f32 = 9.81;
+/// Speed below which a grounded ball counts as settled.
+pub const SETTLED_SPEED: f32 = 0.1;
+/// A ball still creeping down a slope after this long is played where it is.
+const MAX_SETTLE_SECONDS: f32 = 12.0;
+/// Candidate powers tried for a lay-up.
+const LAYUP_STEPS: usize = 40;
+const MAX_FLIGHT_SECONDS: f32 = 20.0;
+
+/// A shot the bot decided on: horizontal `heading` (x / z, unit length) and charge `power` (0..1).
+/// `direct` is false for a lay-up (the target is not reachable on the fly).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShotPlan {
+    pub heading: Vec2,
+    pub power: f32,
+    pub direct: bool,
+}
+
+/// Launch velocity of a shot along `heading` with `power` at the configured launch angle.
+pub fn launch_velocity(heading: Vec2, power: f32, cfg: &ShotConfig) -> Vec3 {
+    let behind = -Vec3::new(heading.x, 0.0, heading.y);
+    shot_direction(Vec3::ZERO, behind, None, cfg.up_angle_deg) * cfg.base_impulse * shot_power_scale(power)
+}
+
+/// Charge (0..1) whose arc from `ball` passes through `target`, ignoring the terrain; None when no
+/// charge reaches it.
+pub fn direct_power(ball: Vec3, target: Vec3, cfg: &ShotConfig) -> Option<f32> {
+    let d = (target - ball).xz().length();
+    let dy = target.y - ball.y;
+    let angle = cfg.up_angle_deg.to_radians();
+    // y(d) = d tan(a) - g d^2 / (2 v^2 cos^2(a))  solved for v.
+    let denom = 2.0 * angle.cos().powi(2) * (d * angle.tan() - dy);
+    if denom <= 0.0 {
+        return None;
+    }
+    let scale = (G * d * d / denom).sqrt() / cfg.base_impulse;
+    let power = (scale - shot_power_scale(0.0)) / (shot_power_scale(1.0) - shot_power_scale(0.0));
+    (0.0..=1.0).contains(&power).then_some(power)
+}
+
+/// Whether a ball launched from `ball` with `vel` covers the horizontal distance to `target`
+/// without touching the ground first.
+fn arc_clears_terrain(ball: Vec3, vel: Vec3, target: Vec3, radius: f32, sampler: &TerrainSampler) -> bool {
+    let reach = (target - ball).xz().length();
+    let dt = 1.0 / 60.0;
+    let (mut p, mut v) = (ball, vel);
+    while (p - ball).xz().length() < reach {
+        v.y -= G * dt;
+        p += v * dt;
+        if v.y < 0.0 && p.y < target.y - radius * 2.0 && p.y <= sampler.height(p.x, p.z) + radius {
+            return false;
+        }
+        if (p - ball).xz().length() > reach * 2.0 + 100.0 {
+            break;
+        }
+    }
+    true
+}
+
+/// Pick the shot from `ball` toward `target`; None when the ball already sits under it.
+pub fn plan_shot(ball: Vec3, target: Vec3, radius: f32, cfg: &ShotConfig, sampler: &TerrainSampler) -> Option<ShotPlan> {
+    let heading = (target - ball).xz().try_normalize()?;
+    if let Some(power) = direct_power(ball, target, cfg) {
+        if arc_clears_terrain(ball, launch_velocity(heading, power, cfg), target, radius, sampler) {
+            return Some(ShotPlan { heading, power, direct: true });
+        }
+    }
+    let landing_gap = |power: f32| {
+        predict_landing(ball, launch_velocity(heading, power, cfg), radius, MAX_FLIGHT_SECONDS, |x, z| {
+            sampler.height(x, z)
+        })
+        .map_or(f32::INFINITY, |p| p.xz().distance(target.xz()))
+    };
+    let (power, _) = (0..=LAYUP_STEPS)
+        .map(|i| i as f32 / LAYUP_STEPS as f32)
+        .map(|power| (power, landing_gap(power)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap_or((1.0, 0.0));
+    Some(ShotPlan { heading, power, direct: false })
+}
+
+/// One hole the bot finished.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HoleReport {
+    pub hole: u32,
+    pub shots: u32,
+    pub seconds: f32, // sim time spent on the hole
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct AutoplayReport {
+    pub shots: u32,
+    pub holes: Vec<HoleReport>,
+    pub finished: bool, // round over (all holes played)
+    hole_shots: u32,
+    hole_start: f32,
+}
+
+impl AutoplayReport {
+    pub fn holes_completed(&self) -> usize {
+        self.holes.len()
+    }
+}
 
 pub struct AutoplayPlugin;
 impl Plugin for AutoplayPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(FixedUpdate, (scripted_autoplay, debug_log_each_second, exit_on_duration));
+        app.init_resource::<AutoplayReport>()
+            .add_systems(Update, start_round)
+            .add_systems(OnTransition { exited: AppState::Menu, entered: AppState::Playing }, start_hole_clock)
+            .add_systems(FixedUpdate, autoplay_shot.in_set(GameSet::Input).run_if(gameplay_running))
+            .add_systems(FixedUpdate, (debug_log_each_second, exit_on_duration))
+            .add_systems(Update, report_progress);
     }
 }
 
-fn scripted_autoplay(
+// Press Play for the bot.
+fn start_round(
+    mut commands: Commands,
+    phase: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut slot: Option<ResMut<RoundSlot>>,
+    report: Res<AutoplayReport>,
+    q_menu: Query<Entity, With<MenuRoot>>,
+) {
+    // One round per run: back in the menu after it, the bot leaves the game to the player.
+    if *phase.get() != AppState::Menu || report.finished {
+        return;
+    }
+    if let Some(slot) = slot.as_mut() {
+        slot.clear();
+    }
+    next_state.set(AppState::Playing);
+    for root in &q_menu {
+        commands.entity(root).despawn_recursive();
+    }
+    info!("AUTOPLAY round started");
+}
+
+// Hole 1 is timed from when play begins, not from app start (menu, loading).
+fn start_hole_clock(sim: Res<SimState>, mut report: ResMut<AutoplayReport>) {
+    report.hole_start = sim.elapsed_seconds;
+}
+
+fn autoplay_shot(
     sim: Res<SimState>,
-    mut runtime: ResMut<AutoRuntime>,
     cfg: Res<AutoConfig>,
-    mut commands: Commands,
-    q_ball: Query<(Entity, &Transform), With<Ball>>,
+    shot_cfg: Res<ShotConfig>,
+    state: Res<ShotState>,
+    score: Res<Score>,
+    focus: Res<TargetFocus>,
+    sampler: Res<TerrainSampler>,
+    mut runtime: ResMut<AutoRuntime>,
+    mut q_ball: Query<(&Transform, &mut BallKinematic), With<Ball>>,
+    q_targets: Query<&Transform, Without<Ball>>,
+    mut ev_shot: EventWriter<ShotFiredEvent>,
+    mut settling_since: Local<Option<u64>>,
+) {
+    if score.game_over || state.mode != ShotMode::Idle || sim.tick < runtime.next_swing_tick {
+        return;
+    }
+    let Ok((ball_t, mut kin)) = q_ball.get_single_mut() else { return; };
+    let Some(target) = focus.entity.and_then(|e| q_targets.get(e).ok()) else { return; };
+    let pos = ball_t.translation;
+    let grounded = pos.y <= sampler.height(pos.x, pos.z) + kin.collider_radius + 1e-3;
+    let since = *settling_since.get_or_insert(sim.tick);
+    let timed_out = (sim.tick - since) as f32 / 60.0 > MAX_SETTLE_SECONDS;
+    let settled = grounded && kin.vel.length() < SETTLED_SPEED;
+    if !(settled || timed_out) {
+        return;
+    }
+    let Some(plan) = plan_shot(pos, target.translation, kin.collider_radius, &shot_cfg, &sampler) else { return; };
+    let dir = shot_direction(pos, pos - Vec3::new(plan.heading.x, 0.0, plan.heading.y), None, shot_cfg.up_angle_deg);
+    fire_shot(pos, &mut kin, dir, plan.power, &shot_cfg, &mut ev_shot);
+    *settling_since = None;
+    runtime.next_swing_tick = sim.tick + (cfg.swing_interval_seconds * 60.0) as u64;
+    info!(
+        "AUTOPLAY swing t={:.2}s pos=({:.1},{:.1},{:.1}) target=({:.1},{:.1},{:.1}) power={:.2} {}",
+        sim.elapsed_seconds,
+        pos.x, pos.y, pos.z,
+        target.translation.x, target.translation.y, target.translation.z,
+        plan.power,
+        if plan.direct { "direct" } else { "layup" }
+    );
+}
+
+fn report_progress(
+    sim: Res<SimState>,
+    score: Res<Score>,
+    auto: Res<AutoConfig>,
+    mut report: ResMut<AutoplayReport>,
+    mut ev_shot: EventReader<ShotFiredEvent>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+    mut ev_game_over: EventReader<GameOverEvent>,
+    mut exit: EventWriter<AppExit>,
 ) {
-    if sim.tick < runtime.next_swing_tick { return; }
-    let interval_ticks = (cfg.swing_interval_seconds * 60.0) as u64;
-    if let Ok((entity, transform)) = q_ball.get_single() {
-        let swings_done = if runtime.next_swing_tick == 0 { 0 } else { runtime.next_swing_tick / interval_ticks.max(1) };
-        let angle = (swings_done as f32 * 13.0).to_radians();
-        let dir_flat = Vec3::new(angle.cos(), 0.0, angle.sin()).normalize();
-        let impulse = dir_flat * cfg.base_impulse + Vec3::Y * (cfg.base_impulse * cfg.upward_factor);
-        commands.entity(entity).insert(ExternalImpulse { impulse, torque_impulse: Vec3::ZERO });
-        info!("AUTOPLAY swing t={:.2}s tick={} swing={} pos=({:.2},{:.2},{:.2}) impulse=({:.2},{:.2},{:.2})",
-            sim.elapsed_seconds, sim.tick, swings_done,
-            transform.translation.x, transform.translation.y, transform.translation.z,
-            impulse.x, impulse.y, impulse.z);
+    let shots = ev_shot.read().count() as u32;
+    report.shots += shots;
+    report.hole_shots += shots;
+    for _ in ev_hit.read().filter(|e| e.kind == TargetKind::Standard) {
+        let hole = HoleReport {
+            hole: report.holes.len() as u32 + 1,
+            shots: report.hole_shots,
+            seconds: sim.elapsed_seconds - report.hole_start,
+        };
+        info!("AUTOPLAY hole {} done in {} shots ({:.1}s)", hole.hole, hole.shots, hole.seconds);
+        report.holes.push(hole);
+        report.hole_shots = 0;
+        report.hole_start = sim.elapsed_seconds;
+    }
+    if ev_game_over.read().count() > 0 && !report.finished {
+        report.finished = true;
+        info!(
+            "AUTOPLAY_SUMMARY holes={} shots={} time={:.1}s points={}",
+            report.holes_completed(),
+            report.shots,
+            score.final_time,
+            score.points
+        );
+        if auto.exit_enabled {
+            exit.send(AppExit::Success);
+        }
     }
-    runtime.next_swing_tick += interval_ticks.max(1);
 }
 
 fn debug_log_each_second(
     sim: Res<SimState>,
     mut log_state: ResMut<LogState>,
-    q_ball: Query<(&Transform, &Velocity), With<Ball>>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
 ) {
-    if sim.tick == 0 || sim.tick % 60 != 0 { return; }
+    if sim.tick == 0 || !sim.tick.is_multiple_of(60) { return; }
     let current_second = sim.tick / 60;
     if current_second == 0 || current_second == log_state.last_logged_second { return; }
     log_state.last_logged_second = current_second;
-    if let Ok((t, vel)) = q_ball.get_single() {
+    if let Ok((t, kin)) = q_ball.get_single() {
         info!("T+{}s tick={} ball=({:.2},{:.2},{:.2}) speed={:.2}",
             current_second, sim.tick,
             t.translation.x, t.translation.y, t.translation.z,
-            vel.linvel.length());
+            kin.vel.length());
     }
 }
 
@@ -59,6 +273,7 @@ fn exit_on_duration(
     screenshot_state: Option<Res<ScreenshotState>>,
     mut exit: EventWriter<AppExit>,
 ) {
+    if !cfg.exit_enabled { return; }
     let target_ticks = (cfg.run_duration_seconds * 60.0) as u64;
     if sim.tick < target_ticks { return; }
     if let (Some(c), Some(state)) = (screenshot_cfg, screenshot_state) {
@@ -1,7 +1,10 @@
 // Ball components & simple custom kinematic physics (terrain + world bounds).
 use bevy::prelude::*;
+use crate::plugins::core_sim::GameSet;
 use crate::plugins::terrain::TerrainSampler;
-use crate::plugins::particles::BallGroundImpactEvent;
+use crate::plugins::particles::{BallGroundImpactEvent, BOUNCE_EFFECT_INTENSITY_MIN};
+use crate::plugins::app_state::gameplay_running;
+use crate::plugins::game_state::HoleState;
 
 #[derive(Component)]
 pub struct Ball;
@@ -14,16 +17,49 @@ pub struct BallKinematic {
     pub angular_vel: Vec3,
 }
 
+/// Weather-dependent ball physics (weather.rs); neutral unless the level has weather.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct BallConditions {
+    pub friction: f32,   // rolling friction multiplier
+    pub bounce: f32,     // restitution multiplier for bounces off props and trees
+    pub wind_push: Vec3, // m/s² acceleration while the ball is in the air
+}
+impl Default for BallConditions {
+    fn default() -> Self {
+        Self { friction: 1.0, bounce: 1.0, wind_push: Vec3::ZERO }
+    }
+}
+
+/// Effects of the powerup spent on the shot in play (powerups.rs); neutral when none is.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct BallModifiers {
+    pub sticky: bool, // the next landing stops the ball dead
+    pub pull: Vec3,   // m/s² extra acceleration, in the air and rolling (magnet)
+}
+
+/// Height of the lake water plane (terrain.rs); a ball below it goes back to the tee.
+pub const WATER_LEVEL: f32 = 25.0;
+
 pub struct BallPlugin;
 impl Plugin for BallPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(FixedUpdate, ball_physics);
+        app.init_resource::<BallConditions>().init_resource::<BallModifiers>().add_systems(FixedUpdate, ball_physics.in_set(GameSet::Simulate).run_if(gameplay_running));
     }
 }
 
-fn ball_physics(
+/// Put the ball at rest on the ground at `pos` (x / z).
+pub fn place_ball(t: &mut Transform, kin: &mut BallKinematic, sampler: &TerrainSampler, pos: Vec2) {
+    t.translation = Vec3::new(pos.x, sampler.height(pos.x, pos.y) + kin.collider_radius, pos.y);
+    kin.vel = Vec3::ZERO;
+    kin.angular_vel = Vec3::ZERO;
+}
+
+pub fn ball_physics(
     mut q: Query<(&mut Transform, &mut BallKinematic), With<Ball>>,
     sampler: Res<TerrainSampler>,
+    conditions: Res<BallConditions>,
+    mut modifiers: ResMut<BallModifiers>,
+    hole: Option<Res<HoleState>>,
     mut ev_impact: EventWriter<BallGroundImpactEvent>,
 ) {
     let Ok((mut t, mut kin)) = q.get_single_mut() else { return; };
@@ -31,17 +67,13 @@ fn ball_physics(
     let g = -9.81;
 
     kin.vel.y += g * dt;
+    kin.vel += modifiers.pull * dt;
     t.translation += kin.vel * dt;
 
-    // Water respawn: if ball falls below water plane (y = 25), reset to origin.
-    const WATER_LEVEL: f32 = 25.0;
+    // Water respawn: if ball falls below water plane (y = 25), back to the hole's tee.
     if t.translation.y < WATER_LEVEL {
-        t.translation.x = 0.0;
-        t.translation.z = 0.0;
-        let ground = sampler.height(0.0, 0.0);
-        t.translation.y = ground + kin.collider_radius;
-        kin.vel = Vec3::ZERO;
-        kin.angular_vel = Vec3::ZERO;
+        let tee = hole.map_or(Vec2::ZERO, |h| h.tee);
+        place_ball(&mut t, &mut kin, &sampler, tee);
         return;
     }
 
@@ -51,7 +83,9 @@ fn ball_physics(
     let h = sampler.height(t.translation.x, t.translation.z);
     let surface_y = h + kin.collider_radius;
 
-    if t.translation.y <= surface_y {
+    if t.translation.y > surface_y {
+        kin.vel += conditions.wind_push * dt;
+    } else {
         t.translation.y = surface_y;
 
         let n = sampler.normal(t.translation.x, t.translation.z);
@@ -65,6 +99,13 @@ fn ball_physics(
                     intensity: impact_intensity,
                 });
             }
+            // Sticky landing: a real impact (not the ball settling) kills all motion.
+            if modifiers.sticky && impact_intensity >= BOUNCE_EFFECT_INTENSITY_MIN {
+                modifiers.sticky = false;
+                kin.vel = Vec3::ZERO;
+                kin.angular_vel = Vec3::ZERO;
+                return;
+            }
             kin.vel -= vn * n;
         }
 
@@ -75,7 +116,7 @@ fn ball_physics(
         let mut tangential = kin.vel - n * kin.vel.dot(n);
         let speed = tangential.length();
         if speed > 1e-5 {
-            let friction_coeff = 0.25;
+            let friction_coeff = 0.25 * conditions.friction;
             let decel = friction_coeff * -g;
             let drop = decel * dt;
             if drop >= speed {
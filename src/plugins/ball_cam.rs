@@ -0,0 +1,116 @@
+// First-person ball cam (B).
+// The camera sits just above the ball and tumbles with `BallKinematic::angular_vel`. With horizon
+// stabilization on (H toggles) it instead looks along the ball's direction of travel with the
+// horizon kept level, which is a lot easier to watch. No shots while it is active; B (or any state
+// change) hands the view back to the orbit camera.
+
+use bevy::prelude::*;
+
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::camera::{CameraActual, CameraMode, OrbitCamera};
+use crate::plugins::app_state::AppState;
+
+#[derive(Resource, Debug, Clone)]
+pub struct BallCamConfig {
+    pub height: f32,          // m above the ball surface
+    pub stabilize: bool,      // level horizon, look along travel
+    pub heading_speed: f32,   // m/s above which the travel direction updates the heading
+    pub heading_rate: f32,    // 1/s smoothing of the stabilized heading
+}
+impl Default for BallCamConfig {
+    fn default() -> Self {
+        Self { height: 0.35, stabilize: true, heading_speed: 0.5, heading_rate: 6.0 }
+    }
+}
+
+#[derive(Resource, Debug)]
+pub struct BallCamState {
+    pub orientation: Quat, // tumbling orientation (unstabilized)
+    pub heading: Vec3,     // horizontal look direction (stabilized)
+}
+impl Default for BallCamState {
+    fn default() -> Self {
+        Self { orientation: Quat::IDENTITY, heading: Vec3::NEG_Z }
+    }
+}
+
+/// Advance the tumbling orientation by the ball's world-space angular velocity.
+pub fn integrate_tumble(orientation: Quat, angular_vel: Vec3, dt: f32) -> Quat {
+    (Quat::from_scaled_axis(angular_vel * dt) * orientation).normalize()
+}
+
+/// Ease the stabilized heading toward the horizontal travel direction (unchanged when slow).
+pub fn update_heading(cfg: &BallCamConfig, heading: Vec3, vel: Vec3, dt: f32) -> Vec3 {
+    let flat = Vec3::new(vel.x, 0.0, vel.z);
+    if flat.length() < cfg.heading_speed {
+        return heading;
+    }
+    let a = 1.0 - (-cfg.heading_rate * dt).exp();
+    heading.lerp(flat.normalize(), a).try_normalize().unwrap_or(heading)
+}
+
+pub struct BallCamPlugin;
+impl Plugin for BallCamPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BallCamConfig>()
+            .init_resource::<BallCamState>()
+            .add_systems(Update, (toggle_ball_cam, ball_cam_follow).chain());
+    }
+}
+
+fn toggle_ball_cam(
+    keys: Res<ButtonInput<KeyCode>>,
+    phase: Option<Res<State<AppState>>>,
+    mut cfg: ResMut<BallCamConfig>,
+    mut mode: ResMut<CameraMode>,
+    mut state: ResMut<BallCamState>,
+    mut actual: ResMut<CameraActual>,
+    q_cam: Query<&Transform, With<OrbitCamera>>,
+) {
+    if *mode == CameraMode::BallCam && keys.just_pressed(KeyCode::KeyH) {
+        cfg.stabilize = !cfg.stabilize;
+    }
+    if !keys.just_pressed(KeyCode::KeyB) || !phase.is_some_and(|p| p.get().round_in_view()) {
+        return;
+    }
+    match *mode {
+        CameraMode::Orbit => {
+            // Start out looking the way the orbit camera did.
+            let forward = q_cam.get_single().map(|t| *t.forward()).unwrap_or(Vec3::NEG_Z);
+            let flat = Vec3::new(forward.x, 0.0, forward.z).try_normalize().unwrap_or(Vec3::NEG_Z);
+            state.heading = flat;
+            state.orientation = Transform::IDENTITY.looking_to(flat, Vec3::Y).rotation;
+            *mode = CameraMode::BallCam;
+        }
+        CameraMode::BallCam => {
+            // Glide back out like the free camera does.
+            actual.initialized = false;
+            *mode = CameraMode::Orbit;
+        }
+        _ => return,
+    }
+    info!("Camera mode: {:?}", *mode);
+}
+
+fn ball_cam_follow(
+    time: Res<Time>,
+    cfg: Res<BallCamConfig>,
+    mode: Res<CameraMode>,
+    mut state: ResMut<BallCamState>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+    mut q_cam: Query<&mut Transform, (With<OrbitCamera>, Without<Ball>)>,
+) {
+    if *mode != CameraMode::BallCam {
+        return;
+    }
+    let (Ok((ball_t, kin)), Ok(mut cam_t)) = (q_ball.get_single(), q_cam.get_single_mut()) else { return; };
+    let dt = time.delta_seconds();
+    state.orientation = integrate_tumble(state.orientation, kin.angular_vel, dt);
+    state.heading = update_heading(&cfg, state.heading, kin.vel, dt);
+    cam_t.translation = ball_t.translation + Vec3::Y * (kin.visual_radius + cfg.height);
+    cam_t.rotation = if cfg.stabilize {
+        Transform::IDENTITY.looking_to(state.heading, Vec3::Y).rotation
+    } else {
+        state.orientation
+    };
+}
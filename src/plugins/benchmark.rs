@@ -0,0 +1,304 @@
+// Benchmark mode (--benchmark).
+// Starts the selected level and, after a short warm-up, flies the camera along a fixed route: a
+// figure eight across the island, timed on the real clock, so every run sees the same views at
+// the same moments whatever the frame rate. Terrain streams around the camera instead of the ball
+// (TerrainStreamFocus), which makes chunk building part of the measurement. Every frame time is
+// recorded; once per second the frames, chunk builds (TerrainChunkReady), loaded chunks and
+// entities are sampled. When the route ends (or the window is closed early) the report is written
+// to the data directory as benchmark.json (summary with frame-time percentiles + per-second rows)
+// and benchmark.csv (the per-second rows), then the game exits. Comparing reports of two builds
+// shows performance regressions.
+
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::plugins::camera::{CameraMode, OrbitCamera};
+use crate::plugins::level::LevelManifest;
+use crate::plugins::app_state::AppState;
+use crate::plugins::main_menu::MenuRoot;
+use crate::plugins::storage::StoragePaths;
+use crate::plugins::terrain::{LoadedChunks, TerrainChunkReady, TerrainSampler, TerrainStreamFocus};
+
+pub const BENCHMARK_JSON: &str = "benchmark.json";
+pub const BENCHMARK_CSV: &str = "benchmark.csv";
+const WATER_LEVEL: f32 = 25.0;
+const LOOK_AHEAD_S: f32 = 2.0;
+
+#[derive(Resource, Debug, Clone)]
+pub struct BenchmarkConfig {
+    pub warmup: f32,   // s after start before the route and the measurement begin
+    pub duration: f32, // s for the route
+    pub radius: f32,   // route size, fraction of the world size
+    pub altitude: f32, // m above the ground (or water)
+}
+impl Default for BenchmarkConfig {
+    fn default() -> Self {
+        Self { warmup: 3.0, duration: 60.0, radius: 0.35, altitude: 60.0 }
+    }
+}
+
+/// Ground track (x / z) of the route `t` seconds in: a figure eight through the island centre.
+pub fn route_point(cfg: &BenchmarkConfig, world_size: f32, t: f32) -> Vec2 {
+    let a = std::f32::consts::TAU * (t / cfg.duration);
+    let r = cfg.radius * world_size;
+    Vec2::new(r * a.sin(), r * a.sin() * a.cos())
+}
+
+/// Camera transform `t` seconds into the route, looking at the ground a little ahead.
+pub fn route_pose(cfg: &BenchmarkConfig, sampler: &TerrainSampler, t: f32) -> Transform {
+    let size = sampler.cfg.heightmap_world_size;
+    let ground = |p: Vec2| sampler.height(p.x, p.y).max(WATER_LEVEL);
+    let p = route_point(cfg, size, t);
+    let ahead = route_point(cfg, size, t + LOOK_AHEAD_S);
+    let eye = Vec3::new(p.x, ground(p) + cfg.altitude, p.y);
+    Transform::from_translation(eye).looking_at(Vec3::new(ahead.x, ground(ahead), ahead.y), Vec3::Y)
+}
+
+/// Nearest-rank percentile (`p` in 0..=100) of ascending `sorted` values; 0 when empty.
+pub fn percentile(sorted: &[f32], p: f32) -> f32 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = ((p / 100.0) * sorted.len() as f32).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FrameStats {
+    pub avg_ms: f32,
+    pub p50_ms: f32,
+    pub p90_ms: f32,
+    pub p95_ms: f32,
+    pub p99_ms: f32,
+    pub max_ms: f32,
+}
+
+impl FrameStats {
+    pub fn from_frame_times(ms: &[f32]) -> Self {
+        let mut sorted = ms.to_vec();
+        sorted.sort_by(f32::total_cmp);
+        let avg_ms = if ms.is_empty() { 0.0 } else { ms.iter().sum::<f32>() / ms.len() as f32 };
+        Self {
+            avg_ms,
+            p50_ms: percentile(&sorted, 50.0),
+            p90_ms: percentile(&sorted, 90.0),
+            p95_ms: percentile(&sorted, 95.0),
+            p99_ms: percentile(&sorted, 99.0),
+            max_ms: sorted.last().copied().unwrap_or(0.0),
+        }
+    }
+}
+
+/// One second of the route.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SecondSample {
+    pub second: u32,
+    pub frames: u32,
+    pub avg_ms: f32,
+    pub p95_ms: f32,
+    pub max_ms: f32,
+    pub chunks_built: u32,
+    pub loaded_chunks: usize,
+    pub entities: usize,
+}
+
+impl SecondSample {
+    pub const CSV_HEADER: &'static str = "second,frames,avg_ms,p95_ms,max_ms,chunks_built,loaded_chunks,entities";
+
+    pub fn csv_row(&self) -> String {
+        format!(
+            "{},{},{:.3},{:.3},{:.3},{},{},{}",
+            self.second,
+            self.frames,
+            self.avg_ms,
+            self.p95_ms,
+            self.max_ms,
+            self.chunks_built,
+            self.loaded_chunks,
+            self.entities
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BenchmarkReport {
+    pub version: String, // game version (Cargo.toml)
+    pub level: String,
+    pub completed: bool, // false when the run was cut short
+    pub seconds: f32,    // measured time
+    pub frames: usize,
+    pub avg_fps: f32,
+    pub frame_time: FrameStats,
+    pub chunks_built: u32,
+    pub per_second: Vec<SecondSample>,
+}
+
+impl BenchmarkReport {
+    pub fn new(level: &str, completed: bool, frame_ms: &[f32], per_second: Vec<SecondSample>) -> Self {
+        let seconds = frame_ms.iter().sum::<f32>() / 1000.0;
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            level: level.to_string(),
+            completed,
+            seconds,
+            frames: frame_ms.len(),
+            avg_fps: if seconds > 0.0 { frame_ms.len() as f32 / seconds } else { 0.0 },
+            frame_time: FrameStats::from_frame_times(frame_ms),
+            chunks_built: per_second.iter().map(|s| s.chunks_built).sum(),
+            per_second,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(SecondSample::CSV_HEADER);
+        for s in &self.per_second {
+            csv.push('\n');
+            csv.push_str(&s.csv_row());
+        }
+        csv.push('\n');
+        csv
+    }
+}
+
+/// Measurement state of the running benchmark.
+#[derive(Resource, Debug, Default)]
+pub struct BenchmarkRun {
+    pub elapsed: f32, // s since start (warm-up included)
+    pub frame_ms: Vec<f32>,
+    pub per_second: Vec<SecondSample>,
+    second_ms: Vec<f32>,
+    second_chunks: u32,
+    written: bool,
+}
+
+impl BenchmarkRun {
+    fn route_time(&self, cfg: &BenchmarkConfig) -> Option<f32> {
+        (self.elapsed >= cfg.warmup).then_some(self.elapsed - cfg.warmup)
+    }
+
+    fn close_second(&mut self, loaded_chunks: usize, entities: usize) {
+        let stats = FrameStats::from_frame_times(&self.second_ms);
+        self.per_second.push(SecondSample {
+            second: self.per_second.len() as u32 + 1,
+            frames: self.second_ms.len() as u32,
+            avg_ms: stats.avg_ms,
+            p95_ms: stats.p95_ms,
+            max_ms: stats.max_ms,
+            chunks_built: self.second_chunks,
+            loaded_chunks,
+            entities,
+        });
+        self.second_ms.clear();
+        self.second_chunks = 0;
+    }
+}
+
+pub struct BenchmarkPlugin;
+impl Plugin for BenchmarkPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BenchmarkConfig>()
+            .init_resource::<BenchmarkRun>()
+            .add_systems(Update, (start_benchmark, fly_route, record_frame).chain())
+            .add_systems(Last, write_report_on_exit);
+    }
+}
+
+// Press Play; the camera belongs to the route for the whole run.
+fn start_benchmark(
+    mut commands: Commands,
+    phase: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut mode: ResMut<CameraMode>,
+    q_menu: Query<Entity, With<MenuRoot>>,
+) {
+    if !phase.get().in_game() {
+        next_state.set(AppState::Playing);
+        for root in &q_menu {
+            commands.entity(root).despawn_recursive();
+        }
+        info!("BENCHMARK started");
+    }
+    if *mode != CameraMode::Cinematic {
+        *mode = CameraMode::Cinematic;
+    }
+}
+
+fn fly_route(
+    time: Res<Time<Real>>,
+    cfg: Res<BenchmarkConfig>,
+    sampler: Option<Res<TerrainSampler>>,
+    mut run: ResMut<BenchmarkRun>,
+    mut focus: ResMut<TerrainStreamFocus>,
+    mut q_cam: Query<&mut Transform, With<OrbitCamera>>,
+) {
+    run.elapsed += time.delta_seconds();
+    let Some(sampler) = sampler else { return; };
+    let pose = route_pose(&cfg, &sampler, run.route_time(&cfg).unwrap_or(0.0));
+    focus.pos = Some(pose.translation);
+    if let Ok(mut cam_t) = q_cam.get_single_mut() {
+        *cam_t = pose;
+    }
+}
+
+fn record_frame(
+    time: Res<Time<Real>>,
+    cfg: Res<BenchmarkConfig>,
+    loaded: Option<Res<LoadedChunks>>,
+    manifest: Option<Res<LevelManifest>>,
+    paths: Res<StoragePaths>,
+    mut run: ResMut<BenchmarkRun>,
+    mut ev_chunk: EventReader<TerrainChunkReady>,
+    mut exit: EventWriter<AppExit>,
+    q_entities: Query<Entity>,
+) {
+    let chunks = ev_chunk.read().count() as u32;
+    let Some(t) = run.route_time(&cfg) else { return; };
+    if run.written {
+        return;
+    }
+    run.frame_ms.push(time.delta_seconds() * 1000.0);
+    run.second_ms.push(time.delta_seconds() * 1000.0);
+    run.second_chunks += chunks;
+    let loaded_chunks = loaded.map_or(0, |l| l.map.len());
+    if t >= (run.per_second.len() + 1) as f32 {
+        run.close_second(loaded_chunks, q_entities.iter().count());
+    }
+    if t >= cfg.duration {
+        write_report(&mut run, manifest.as_deref(), &paths, true);
+        exit.send(AppExit::Success);
+    }
+}
+
+fn write_report_on_exit(
+    manifest: Option<Res<LevelManifest>>,
+    paths: Res<StoragePaths>,
+    mut run: ResMut<BenchmarkRun>,
+    mut ev_exit: EventReader<AppExit>,
+) {
+    if ev_exit.read().count() > 0 && !run.written {
+        write_report(&mut run, manifest.as_deref(), &paths, false);
+    }
+}
+
+fn write_report(run: &mut BenchmarkRun, manifest: Option<&LevelManifest>, paths: &StoragePaths, completed: bool) {
+    let level = manifest.and_then(|m| m.levels.get(m.loaded)).map_or("", |l| l.id.as_str());
+    let report = BenchmarkReport::new(level, completed, &run.frame_ms, std::mem::take(&mut run.per_second));
+    paths.save_text(BENCHMARK_JSON, &report.to_json());
+    paths.save_text(BENCHMARK_CSV, &report.to_csv());
+    run.written = true;
+    info!(
+        "BENCHMARK frames={} avg_fps={:.1} p50={:.2}ms p95={:.2}ms p99={:.2}ms chunks_built={} -> {}",
+        report.frames,
+        report.avg_fps,
+        report.frame_time.p50_ms,
+        report.frame_time.p95_ms,
+        report.frame_time.p99_ms,
+        report.chunks_built,
+        paths.file(BENCHMARK_JSON).display()
+    );
+}
@@ -0,0 +1,186 @@
+// Biome layer over the heightmap terrain.
+// A low-res grid (one cell ~16 m) is classified once when the TerrainSampler is built,
+// from height, slope and a low-frequency "moisture" noise. Other plugins query it through
+// `TerrainSampler::biome(x, z)` / `TerrainSampler::biome_weights(x, z)`:
+//  - terrain meshes carry the blend weights as vertex colors -> per-biome palette in the shader
+//  - vegetation scales density by biome; species weight themselves per biome (species.ron)
+//  - game audio shifts the ambient (music) volume & speed per region
+
+use bevy::prelude::*;
+use noise::{NoiseFn, Perlin};
+
+use crate::plugins::terrain_material::DEFAULT_TERRAIN_COLORS;
+
+/// Grid resolution of the biome map (cells per side).
+pub const BIOME_MAP_RES: u32 = 128;
+
+/// Height (m) below which terrain counts as wetland (water plane sits at 25 m).
+const WETLAND_MAX_HEIGHT: f32 = 33.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Biome {
+    #[default]
+    Meadow,
+    Forest,
+    Highland,
+    Wetland,
+}
+
+impl Biome {
+    /// Order matches the RGBA channels of the terrain vertex color weights.
+    pub const ALL: [Biome; 4] = [Biome::Meadow, Biome::Forest, Biome::Highland, Biome::Wetland];
+
+    #[inline]
+    pub fn index(self) -> usize {
+        match self {
+            Biome::Meadow => 0,
+            Biome::Forest => 1,
+            Biome::Highland => 2,
+            Biome::Wetland => 3,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Biome::Meadow => "Meadow",
+            Biome::Forest => "Forest",
+            Biome::Highland => "Highland",
+            Biome::Wetland => "Wetland",
+        }
+    }
+
+    pub fn params(self) -> BiomeParams {
+        let base = DEFAULT_TERRAIN_COLORS;
+        match self {
+            Biome::Meadow => BiomeParams {
+                palette: base,
+                vegetation_density: 1.0,
+                ambient_volume: 0.55,
+                ambient_speed: 1.0,
+            },
+            Biome::Forest => BiomeParams {
+                palette: [
+                    Vec4::new(0.08, 0.15, 0.07, 1.0),
+                    Vec4::new(0.16, 0.29, 0.11, 1.0),
+                    base[2],
+                    base[3],
+                ],
+                vegetation_density: 1.6,
+                ambient_volume: 0.45,
+                ambient_speed: 0.97,
+            },
+            Biome::Highland => BiomeParams {
+                palette: [
+                    Vec4::new(0.22, 0.24, 0.16, 1.0),
+                    Vec4::new(0.33, 0.36, 0.24, 1.0),
+                    Vec4::new(0.40, 0.39, 0.37, 1.0),
+                    Vec4::new(0.56, 0.53, 0.48, 1.0),
+                ],
+                vegetation_density: 0.35,
+                ambient_volume: 0.35,
+                ambient_speed: 0.94,
+            },
+            Biome::Wetland => BiomeParams {
+                palette: [
+                    Vec4::new(0.14, 0.15, 0.08, 1.0),
+                    Vec4::new(0.20, 0.30, 0.14, 1.0),
+                    Vec4::new(0.30, 0.29, 0.25, 1.0),
+                    base[3],
+                ],
+                vegetation_density: 0.6,
+                ambient_volume: 0.50,
+                ambient_speed: 1.03,
+            },
+        }
+    }
+}
+
+/// Per-biome tuning consumed by terrain shading, vegetation and audio.
+#[derive(Debug, Clone, Copy)]
+pub struct BiomeParams {
+    pub palette: [Vec4; 4], // lowland, grass, rock, high rock (same slots as RealTerrainUniform::colors)
+    pub vegetation_density: f32, // multiplier on vegetation spawn density
+    pub ambient_volume: f32,
+    pub ambient_speed: f32,
+}
+
+/// Low-res biome grid covering the heightmap world (row-major, row 0 = -Z edge).
+#[derive(Debug, Clone)]
+pub struct BiomeMap {
+    res: u32,
+    world_size: f32,
+    cells: Vec<Biome>,
+}
+
+impl BiomeMap {
+    /// Classify each cell from a height function. `seed` drives the moisture noise.
+    pub fn generate(res: u32, world_size: f32, max_height: f32, seed: u32, height: impl Fn(f32, f32) -> f32) -> Self {
+        let res = res.max(2);
+        let moisture = Perlin::new(seed.wrapping_add(51_929));
+        let cell = world_size / res as f32;
+        let mut cells = Vec::with_capacity((res * res) as usize);
+        for j in 0..res {
+            for i in 0..res {
+                let x = (i as f32 + 0.5) * cell - world_size * 0.5;
+                let z = (j as f32 + 0.5) * cell - world_size * 0.5;
+                let h = height(x, z);
+                let d = cell * 0.5;
+                let grad = Vec2::new(height(x + d, z) - height(x - d, z), height(x, z + d) - height(x, z - d)) / (2.0 * d);
+                let m = moisture.get([x as f64 * 0.0025, z as f64 * 0.0025]) as f32;
+                cells.push(Self::classify(h, grad.length(), m, max_height));
+            }
+        }
+        Self { res, world_size, cells }
+    }
+
+    /// Single-biome map (placeholder before classification, or for tooling).
+    pub fn uniform(biome: Biome) -> Self {
+        Self { res: 2, world_size: 1.0, cells: vec![biome; 4] }
+    }
+
+    fn classify(h: f32, slope: f32, moisture: f32, max_height: f32) -> Biome {
+        if h < WETLAND_MAX_HEIGHT {
+            Biome::Wetland
+        } else if h > max_height * 0.45 || slope > 0.8 {
+            Biome::Highland
+        } else if moisture > 0.1 {
+            Biome::Forest
+        } else {
+            Biome::Meadow
+        }
+    }
+
+    #[inline]
+    fn cell_coords(&self, x: f32, z: f32) -> Vec2 {
+        // Continuous cell-space coordinates (cell centers at integer + 0.5).
+        Vec2::new(
+            (x / self.world_size + 0.5) * self.res as f32,
+            (z / self.world_size + 0.5) * self.res as f32,
+        )
+    }
+
+    #[inline]
+    fn cell(&self, i: i32, j: i32) -> Biome {
+        let max = self.res as i32 - 1;
+        self.cells[(j.clamp(0, max) * self.res as i32 + i.clamp(0, max)) as usize]
+    }
+
+    /// Dominant biome at world (x, z) (nearest cell, clamped at the map edge).
+    pub fn biome(&self, x: f32, z: f32) -> Biome {
+        let c = self.cell_coords(x, z);
+        self.cell(c.x.floor() as i32, c.y.floor() as i32)
+    }
+
+    /// Bilinear blend weights (indexed by `Biome::index`) for smooth transitions.
+    pub fn weights(&self, x: f32, z: f32) -> [f32; 4] {
+        let c = self.cell_coords(x, z) - Vec2::splat(0.5);
+        let (i0, j0) = (c.x.floor() as i32, c.y.floor() as i32);
+        let (tx, tz) = (c.x - i0 as f32, c.y - j0 as f32);
+        let mut w = [0.0; 4];
+        w[self.cell(i0, j0).index()] += (1.0 - tx) * (1.0 - tz);
+        w[self.cell(i0 + 1, j0).index()] += tx * (1.0 - tz);
+        w[self.cell(i0, j0 + 1).index()] += (1.0 - tx) * tz;
+        w[self.cell(i0 + 1, j0 + 1).index()] += tx * tz;
+        w
+    }
+}
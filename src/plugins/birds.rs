@@ -0,0 +1,310 @@
+// Birds: small flocks circling over the woods.
+// Each flock picks a home over dense forest near the ball (trees from the vegetation grid, forest
+// biome ground where trees are not streamed in) and its birds circle it as boids: they keep apart,
+// match their neighbours' heading, stay together and are pulled round the home. When the ball
+// comes close they scatter away from it with a squawk, then settle over another stretch of forest.
+// Purely atmospheric. How many birds fly follows the vegetation perf tuner (`density_scale`) and
+// the particle quality (low power mode).
+
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+use rand::{thread_rng, Rng};
+
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::biome::Biome;
+use crate::plugins::particles::{BirdsScatteredEvent, ParticleQuality};
+use crate::plugins::terrain::TerrainSampler;
+use crate::plugins::vegetation::VegetationGrid;
+
+#[derive(Resource, Debug, Clone)]
+pub struct BirdConfig {
+    pub enabled: bool,
+    pub flocks: usize,
+    pub birds_per_flock: usize,
+    pub density_scale: f32, // 0..1 share of each flock in the air, driven by the perf tuner
+    pub min_density_scale: f32,
+    pub roam_radius: f32,     // m around the ball homes are searched in
+    pub circle_radius: f32,   // m
+    pub altitude: f32,        // m above the ground at the home
+    pub speed: f32,           // m/s cruising
+    pub scare_radius: f32,    // m; the ball this close scatters the flock
+    pub scatter_time: f32,    // s before a scattered flock settles again
+    pub neighbour_radius: f32, // m
+    pub separation: f32,      // boid rule weights
+    pub alignment: f32,
+    pub cohesion: f32,
+    pub homing: f32,
+}
+impl Default for BirdConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            flocks: 3,
+            birds_per_flock: 12,
+            density_scale: 1.0,
+            min_density_scale: 0.3,
+            roam_radius: 220.0,
+            circle_radius: 30.0,
+            altitude: 32.0,
+            speed: 9.0,
+            scare_radius: 20.0,
+            scatter_time: 6.0,
+            neighbour_radius: 8.0,
+            separation: 2.0,
+            alignment: 0.6,
+            cohesion: 0.5,
+            homing: 1.2,
+        }
+    }
+}
+
+/// Position and velocity of one bird.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Boid {
+    pub pos: Vec3,
+    pub vel: Vec3,
+}
+
+/// Steering acceleration for `me` from the boid rules; `others` is the rest of the flock, `home`
+/// the point it circles (counter-clockwise seen from above at `radius`).
+pub fn steer(me: Boid, others: &[Boid], home: Vec3, radius: f32, cfg: &BirdConfig) -> Vec3 {
+    let mut separation = Vec3::ZERO;
+    let (mut heading, mut center, mut n) = (Vec3::ZERO, Vec3::ZERO, 0.0);
+    for o in others {
+        let d = me.pos - o.pos;
+        let dist = d.length();
+        if dist <= 1e-4 || dist > cfg.neighbour_radius {
+            continue;
+        }
+        separation += d / (dist * dist);
+        heading += o.vel;
+        center += o.pos;
+        n += 1.0;
+    }
+    let mut acc = separation * cfg.separation * cfg.neighbour_radius;
+    if n > 0.0 {
+        acc += (heading / n - me.vel) * cfg.alignment;
+        acc += (center / n - me.pos) * cfg.cohesion * 0.1;
+    }
+    // Orbit: head for the point a little ahead on the circle round home.
+    let offset = (me.pos - home) * Vec3::new(1.0, 0.0, 1.0);
+    let radial = offset.try_normalize().unwrap_or(Vec3::X);
+    let tangent = Vec3::new(-radial.z, 0.0, radial.x);
+    let goal = home + radial * radius + tangent * radius * 0.5;
+    acc + (goal - me.pos) * cfg.homing * 0.1
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FlockMood {
+    Circling,
+    Scattering(f32), // s left
+}
+
+struct Flock {
+    home: Option<Vec3>,
+    placed: bool, // the birds have been put in the sky
+    mood: FlockMood,
+    boids: Vec<Boid>,
+}
+
+#[derive(Resource, Default)]
+struct Flocks(Vec<Flock>);
+
+#[derive(Component)]
+struct Bird {
+    flock: usize,
+    index: usize,
+    flap: f32, // wing beat phase
+}
+
+pub struct BirdsPlugin;
+impl Plugin for BirdsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BirdConfig>()
+            .init_resource::<Flocks>()
+            .add_systems(Startup, spawn_birds)
+            .add_systems(Update, (pick_homes, scatter_from_ball, fly_birds).chain());
+    }
+}
+
+/// Two wing triangles meeting at the body; scaling Y flaps them.
+fn bird_mesh() -> Mesh {
+    let positions: Vec<[f32; 3]> = vec![
+        [0.0, 0.0, 0.25],
+        [0.0, 0.0, -0.2],
+        [-0.6, 0.18, -0.3],
+        [0.6, 0.18, -0.3],
+    ];
+    let normals = vec![[0.0, 1.0, 0.0]; 4];
+    let indices: Vec<u32> = vec![0, 2, 1, 0, 1, 3];
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
+
+fn spawn_birds(
+    mut commands: Commands,
+    cfg: Res<BirdConfig>,
+    mut flocks: ResMut<Flocks>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mesh = meshes.add(bird_mesh());
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.08, 0.08, 0.1),
+        unlit: true,
+        cull_mode: None,
+        double_sided: true,
+        ..default()
+    });
+    let mut rng = thread_rng();
+    for f in 0..cfg.flocks {
+        let boids = vec![Boid { pos: Vec3::ZERO, vel: Vec3::X }; cfg.birds_per_flock];
+        flocks.0.push(Flock { home: None, placed: false, mood: FlockMood::Circling, boids });
+        for index in 0..cfg.birds_per_flock {
+            commands.spawn((
+                PbrBundle { mesh: mesh.clone(), material: material.clone(), visibility: Visibility::Hidden, ..default() },
+                Bird { flock: f, index, flap: rng.gen_range(0.0..std::f32::consts::TAU) },
+            ));
+        }
+    }
+}
+
+// Forest score of a spot: trees around it, or forest ground where no trees are streamed in.
+fn forest_score(p: Vec2, grid: Option<&VegetationGrid>, sampler: &TerrainSampler) -> f32 {
+    let trees = grid.map_or(0, |g| g.trees_within(p, 25.0).len()) as f32;
+    trees + 10.0 * sampler.biome_weights(p.x, p.y)[Biome::Forest.index()]
+}
+
+fn pick_homes(
+    cfg: Res<BirdConfig>,
+    (sampler, grid): (Res<TerrainSampler>, Option<Res<VegetationGrid>>),
+    mut flocks: ResMut<Flocks>,
+    q_ball: Query<&Transform, With<Ball>>,
+) {
+    let Ok(ball) = q_ball.get_single() else { return; };
+    if !cfg.enabled || sampler.is_placeholder() {
+        return;
+    }
+    let ball = ball.translation.xz();
+    let mut rng = thread_rng();
+    let taken: Vec<Vec2> = flocks.0.iter().filter_map(|f| f.home.map(|h| h.xz())).collect();
+    for flock in flocks.0.iter_mut() {
+        let stale = flock.home.is_some_and(|h| h.xz().distance(ball) > cfg.roam_radius * 1.5);
+        if (flock.home.is_some() && !stale) || flock.mood != FlockMood::Circling {
+            continue;
+        }
+        // Best of a handful of random spots, away from the other flocks.
+        let best = (0..12)
+            .map(|_| ball + Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * rng.gen_range(40.0..cfg.roam_radius))
+            .filter(|p| taken.iter().all(|t| t.distance(*p) > cfg.circle_radius * 3.0))
+            .map(|p| (forest_score(p, grid.as_deref(), &sampler), p))
+            .filter(|(score, _)| *score >= 5.0)
+            .max_by(|a, b| a.0.total_cmp(&b.0));
+        let Some((_, p)) = best else { continue; };
+        let home = Vec3::new(p.x, sampler.height(p.x, p.y) + cfg.altitude, p.y);
+        // New flocks (and ones left far behind) appear on their circle; scattered ones fly over.
+        if !flock.placed || stale {
+            flock.placed = true;
+            for b in flock.boids.iter_mut() {
+                let a = rng.gen_range(0.0..std::f32::consts::TAU);
+                let radial = Vec3::new(a.cos(), 0.0, a.sin());
+                b.pos = home + radial * cfg.circle_radius * rng.gen_range(0.7..1.1) + Vec3::Y * rng.gen_range(-3.0..3.0);
+                b.vel = Vec3::new(-radial.z, 0.0, radial.x) * cfg.speed;
+            }
+        }
+        flock.home = Some(home);
+    }
+}
+
+fn scatter_from_ball(
+    cfg: Res<BirdConfig>,
+    mut flocks: ResMut<Flocks>,
+    mut ev_scatter: EventWriter<BirdsScatteredEvent>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+) {
+    // Only a ball on the move frightens them.
+    let Ok((ball, kin)) = q_ball.get_single() else { return; };
+    if kin.vel.length() < 2.0 {
+        return;
+    }
+    let ball = ball.translation;
+    for flock in flocks.0.iter_mut() {
+        if flock.home.is_none() || flock.mood != FlockMood::Circling {
+            continue;
+        }
+        let Some(near) = flock.boids.iter().map(|b| b.pos).find(|p| p.distance(ball) < cfg.scare_radius) else { continue; };
+        flock.mood = FlockMood::Scattering(cfg.scatter_time);
+        for b in flock.boids.iter_mut() {
+            let away = (b.pos - ball).normalize_or_zero() + Vec3::Y * 0.6;
+            b.vel = away.normalize_or_zero() * cfg.speed * 2.0;
+        }
+        ev_scatter.send(BirdsScatteredEvent { pos: near });
+    }
+}
+
+fn fly_birds(
+    time: Res<Time>,
+    (cfg, quality): (Res<BirdConfig>, Res<ParticleQuality>),
+    mut flocks: ResMut<Flocks>,
+    mut q_birds: Query<(&mut Bird, &mut Transform, &mut Visibility)>,
+) {
+    let dt = time.delta_seconds().min(0.1);
+    let flying = quality.scaled((cfg.birds_per_flock as f32 * cfg.density_scale) as usize);
+    for flock in flocks.0.iter_mut() {
+        let Some(home) = flock.home else {
+            // Between homes: keep flying until a new one is found.
+            for b in flock.boids.iter_mut() {
+                b.pos += b.vel * dt;
+            }
+            continue;
+        };
+        match flock.mood {
+            FlockMood::Scattering(left) if left > dt => {
+                flock.mood = FlockMood::Scattering(left - dt);
+                for b in flock.boids.iter_mut() {
+                    b.pos += b.vel * dt;
+                }
+                continue;
+            }
+            // Settle elsewhere once the fright is over.
+            FlockMood::Scattering(_) => {
+                flock.mood = FlockMood::Circling;
+                flock.home = None;
+                continue;
+            }
+            FlockMood::Circling => {}
+        }
+        let snapshot = flock.boids[..flying.min(flock.boids.len())].to_vec();
+        for b in flock.boids.iter_mut().take(flying) {
+            let acc = steer(*b, &snapshot, home, cfg.circle_radius, &cfg);
+            b.vel += acc * dt;
+            b.vel = b.vel.clamp_length(cfg.speed * 0.6, cfg.speed * 1.3);
+            b.pos += b.vel * dt;
+        }
+    }
+    for (mut bird, mut t, mut vis) in &mut q_birds {
+        let flock = &flocks.0[bird.flock];
+        let shown = cfg.enabled && flock.placed && bird.index < flying;
+        let want = if shown { Visibility::Visible } else { Visibility::Hidden };
+        if *vis != want {
+            *vis = want;
+        }
+        if !shown {
+            continue;
+        }
+        let b = flock.boids[bird.index];
+        // Beat harder when fleeing.
+        let rate = if matches!(flock.mood, FlockMood::Scattering(_)) { 16.0 } else { 9.0 };
+        bird.flap = (bird.flap + rate * dt) % std::f32::consts::TAU;
+        t.translation = b.pos;
+        if let Some(dir) = b.vel.try_normalize() {
+            t.look_to(-dir, Vec3::Y);
+        }
+        let beat = bird.flap.sin();
+        t.scale = Vec3::new(1.0, if beat.abs() < 0.05 { 0.05 } else { beat }, 1.0);
+    }
+}
@@ -3,14 +3,60 @@ use bevy::input::touch::TouchInput;
 use bevy::prelude::*;
 use bevy::window::{CursorGrabMode, PrimaryWindow};
 
-use crate::plugins::ball::Ball;
-use crate::plugins::main_menu::GamePhase;
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::camera_cinematic::{play_hole_intro, queue_hole_intro, start_hole_intro, CameraCinematic, CinematicConfig};
+use crate::plugins::camera_obstruction::{update_camera_obstruction, CameraObstruction, CameraObstructionConfig};
+use crate::plugins::core_sim::GameSet;
+use crate::plugins::low_power::LowPowerMode;
+use crate::plugins::app_state::{round_in_view, AppState};
+use crate::plugins::particles::ShotFiredEvent;
+use crate::plugins::target::{Target, TargetFocus};
 use crate::plugins::terrain::TerrainSampler;
+use crate::plugins::touch_controls::TouchControls;
 
 /// Marker component for the single orbit camera.
 #[derive(Component)]
 pub struct OrbitCamera;
 
+/// Which controller drives the gameplay camera.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CameraMode {
+    #[default]
+    Orbit,     // follows the ball (yaw / pitch / zoom)
+    Free,      // WASD + mouse fly camera for inspecting the course (F toggles); no shots
+    Cinematic, // driven by scripted camera sequences
+    Photo,     // photo mode (simulation paused, see photo_mode.rs)
+    BallCam,   // first-person view riding on the ball (B toggles, see ball_cam.rs); no shots
+}
+
+/// Free (spectator) camera tuning.
+#[derive(Resource)]
+pub struct FreeCameraConfig {
+    pub speed: f32,           // m/s
+    pub fast_multiplier: f32, // while Shift is held
+    pub sensitivity: f32,     // radians per mouse count
+    pub min_clearance: f32,   // m above terrain
+}
+
+impl Default for FreeCameraConfig {
+    fn default() -> Self {
+        Self { speed: 30.0, fast_multiplier: 5.0, sensitivity: 0.003, min_clearance: 2.0 }
+    }
+}
+
+/// Free camera heading (taken over from the current view when entering free mode).
+#[derive(Resource, Default)]
+pub struct FreeCameraState {
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+/// Unit direction for fly input (`x` right, `y` world up, `z` forward along the view).
+pub fn fly_direction(yaw: f32, pitch: f32, input: Vec3) -> Vec3 {
+    let rot = Quat::from_euler(EulerRot::YXZ, yaw, pitch, 0.0);
+    (rot * Vec3::NEG_Z * input.z + rot * Vec3::X * input.x + Vec3::Y * input.y).normalize_or_zero()
+}
+
 /// Runtime mutable orbit state (user-controlled angles & zoom during gameplay).
 #[derive(Resource)]
 pub struct OrbitCameraState {
@@ -31,6 +77,42 @@ impl Default for OrbitCameraState {
     }
 }
 
+/// Named spring presets for the orbit camera (`OrbitCameraConfig::set_smoothing`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CameraSmoothing {
+    Tight,
+    #[default]
+    Default,
+    Cinematic,
+}
+
+impl CameraSmoothing {
+    /// (follow_spring, camera_spring)
+    pub fn springs(self) -> (f32, f32) {
+        match self {
+            Self::Tight => (120.0, 14.0),
+            Self::Default => (60.0, 6.0),
+            Self::Cinematic => (12.0, 2.5),
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Tight => Self::Default,
+            Self::Default => Self::Cinematic,
+            Self::Cinematic => Self::Tight,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Tight => "Tight",
+            Self::Default => "Default",
+            Self::Cinematic => "Cinematic",
+        }
+    }
+}
+
 /// Configuration constants for orbit behavior & constraints.
 #[derive(Resource)]
 pub struct OrbitCameraConfig {
@@ -41,11 +123,25 @@ pub struct OrbitCameraConfig {
     pub zoom_speed: f32,
     pub sens_yaw: f32,
     pub sens_pitch: f32,
+    // Keyboard orbit (arrows / Q E, +/- zoom)
+    pub key_yaw_speed: f32,   // rad/s
+    pub key_pitch_speed: f32, // rad/s
+    pub key_zoom_speed: f32,  // m/s
     pub target_height_offset: f32,
     pub min_clearance: f32,
-    // Spring constants (higher = snappier)
+    pub align_speed: f32, // 1/s, yaw tween rate of the align-to-target key
+    // FOV kick while the ball flies fast (0 degrees disables it)
+    pub fov_kick_deg: f32,
+    pub fov_kick_full_speed: f32, // m/s at which the full kick applies
+    pub fov_kick_rise: f32,       // 1/s easing toward a wider view
+    pub fov_kick_fall: f32,       // 1/s easing back as the ball slows
+    // Spring constants (higher = snappier), normally set from a `CameraSmoothing` profile
+    pub smoothing: CameraSmoothing,
     pub follow_spring: f32,
     pub camera_spring: f32,
+    // Jump straight to the ball when it teleports (restart, respawn) instead of springing there
+    pub snap_on_teleport: bool,
+    pub teleport_distance: f32, // m the ball has to move in one frame to count as a teleport
     // Legacy speed limits (still available, unused in spring mode)
     pub cam_max_speed: f32,
     pub target_max_speed: f32, // should be >= cam_max_speed (spec: 2x)
@@ -61,18 +157,91 @@ impl Default for OrbitCameraConfig {
             zoom_speed: 1.0,
             sens_yaw: 0.005,
             sens_pitch: 0.005,
+            key_yaw_speed: 1.8,
+            key_pitch_speed: 1.2,
+            key_zoom_speed: 30.0,
             // Raise follow point a bit so even low pitches keep camera higher
             target_height_offset: 1.0,
             min_clearance: 1.0,
+            align_speed: 6.0,
+            fov_kick_deg: 10.0,
+            fov_kick_full_speed: 35.0,
+            fov_kick_rise: 4.0,
+            fov_kick_fall: 1.5,
             // Increased for tighter, faster convergence
+            smoothing: CameraSmoothing::Default,
             follow_spring: 60.0,
             camera_spring: 6.0,
+            snap_on_teleport: true,
+            teleport_distance: 25.0,
             cam_max_speed: 20.0,
             target_max_speed: 40.0,
         }
     }
 }
 
+impl OrbitCameraConfig {
+    pub fn set_smoothing(&mut self, profile: CameraSmoothing) {
+        self.smoothing = profile;
+        (self.follow_spring, self.camera_spring) = profile.springs();
+    }
+
+    /// Keyboard orbit step: axes are -1..1 (yaw: +1 = orbit left, pitch: +1 = raise camera,
+    /// zoom: +1 = closer). Returns (yaw, pitch, radius) deltas for `dt` seconds.
+    pub fn keyboard_orbit_delta(&self, yaw_axis: f32, pitch_axis: f32, zoom_axis: f32, dt: f32) -> (f32, f32, f32) {
+        (
+            yaw_axis * self.key_yaw_speed * dt,
+            pitch_axis * self.key_pitch_speed * dt,
+            -zoom_axis * self.key_zoom_speed * dt,
+        )
+    }
+
+    /// Did the follow target move from `prev` to `next` too far for one frame of flight?
+    pub fn is_teleport(&self, prev: Vec3, next: Vec3) -> bool {
+        self.snap_on_teleport && prev.distance_squared(next) > self.teleport_distance * self.teleport_distance
+    }
+}
+
+/// Unit vector from the follow target toward the orbit camera (positive pitch raises the camera).
+pub fn orbit_direction(yaw: f32, pitch: f32) -> Vec3 {
+    Vec3::new(pitch.cos() * yaw.sin(), pitch.sin(), pitch.cos() * yaw.cos())
+}
+
+/// Orbit yaw that puts the camera behind `ball` looking at `target` (same forward convention as
+/// the HUD compass: forward = (-sin yaw, -cos yaw) on XZ). None when they share a spot.
+pub fn yaw_behind(ball: Vec3, target: Vec3) -> Option<f32> {
+    let d = (target - ball).xz();
+    (d.length_squared() > 1e-6).then(|| (-d.x).atan2(-d.y))
+}
+
+/// Signed shortest rotation from yaw `from` to yaw `to`, in (-PI, PI].
+pub fn yaw_delta(from: f32, to: f32) -> f32 {
+    use std::f32::consts::{PI, TAU};
+    let d = (to - from).rem_euclid(TAU);
+    if d > PI { d - TAU } else { d }
+}
+
+/// Extra FOV (degrees) for a ball flying at `speed` after a shot of `power` (launch scale, 0.25..2):
+/// grows with speed up to `fov_kick_full_speed`, weaker shots kick half as much.
+pub fn fov_kick_deg(cfg: &OrbitCameraConfig, speed: f32, power: f32) -> f32 {
+    let speed_k = (speed / cfg.fov_kick_full_speed.max(0.01)).clamp(0.0, 1.0);
+    let power_k = 0.5 + 0.5 * (power / 2.0).clamp(0.0, 1.0);
+    cfg.fov_kick_deg.max(0.0) * speed_k * power_k
+}
+
+/// Current FOV kick (degrees) and the power of the last shot.
+#[derive(Resource, Default)]
+pub struct CameraFovKick {
+    pub kick_deg: f32,
+    pub shot_power: f32,
+}
+
+/// Pending align-to-target tween (T key).
+#[derive(Resource, Default)]
+pub struct CameraAlign {
+    pub target_yaw: Option<f32>,
+}
+
 /// Tracks smoothed follow target for camera (speed limited).
 #[derive(Resource)]
 pub struct CameraFollow {
@@ -153,15 +322,48 @@ impl Plugin for CameraPlugin {
             .insert_resource(MenuCameraFlight::default())
             .insert_resource(TouchOrbit::default())
             .insert_resource(PinchZoom::default())
+            .init_resource::<CameraMode>()
+            .init_resource::<FreeCameraConfig>()
+            .init_resource::<FreeCameraState>()
+            .init_resource::<CameraAlign>()
+            .init_resource::<CameraFovKick>()
+            .init_resource::<CinematicConfig>()
+            .init_resource::<CameraCinematic>()
+            .init_resource::<CameraObstructionConfig>()
+            .init_resource::<CameraObstruction>()
             .add_systems(
                 Update,
                 (
-                    orbit_camera_capture,
-                    orbit_camera_input,
-                    menu_camera_flight,
+                    orbit_camera_capture.run_if(round_in_view),
+                    toggle_free_camera.run_if(round_in_view),
+                    orbit_camera_input.run_if(round_in_view),
+                )
+                    .in_set(GameSet::Input),
+            )
+            // Menus and the replay overlay need the cursor back.
+            .add_systems(OnExit(AppState::Playing), release_orbit_capture)
+            .add_systems(OnExit(AppState::GameOver), release_orbit_capture)
+            .add_systems(
+                Update,
+                (
+                    align_camera_to_target.run_if(round_in_view).before(orbit_camera_apply),
+                    // Also behind the loading screen, so the menu opens mid-flight.
+                    menu_camera_flight.run_if(in_state(AppState::Loading).or_else(in_state(AppState::Menu))),
                     camera_phase_transition,
-                    orbit_camera_apply,
-                ),
+                    update_camera_obstruction.before(orbit_camera_apply),
+                    orbit_camera_apply.run_if(round_in_view),
+                    free_camera_fly,
+                    apply_fov_kick,
+                )
+                    .in_set(GameSet::Present),
+            )
+            .add_systems(
+                Update,
+                (queue_hole_intro, start_hole_intro, play_hole_intro)
+                    .chain()
+                    .after(camera_phase_transition)
+                    .before(orbit_camera_apply)
+                    .in_set(GameSet::Present),
             );
     }
 }
@@ -170,20 +372,7 @@ fn orbit_camera_capture(
     buttons: Res<ButtonInput<MouseButton>>,
     mut windows: Query<&mut Window, With<PrimaryWindow>>,
     mut cap: ResMut<OrbitCaptureState>,
-    phase: Option<Res<GamePhase>>,
 ) {
-    // Disable capture in menu.
-    if matches!(phase.map(|p| *p), Some(GamePhase::Menu)) {
-        if cap.captured {
-            if let Ok(mut win) = windows.get_single_mut() {
-                win.cursor.visible = true;
-                win.cursor.grab_mode = CursorGrabMode::None;
-            }
-            cap.captured = false;
-        }
-        return;
-    }
-
     if let Ok(mut win) = windows.get_single_mut() {
         let want = buttons.pressed(MouseButton::Right);
         if want && !cap.captured {
@@ -198,19 +387,99 @@ fn orbit_camera_capture(
     }
 }
 
-/// Process mouse input to update orbit state (yaw, pitch, radius) only in gameplay.
+fn release_orbit_capture(mut windows: Query<&mut Window, With<PrimaryWindow>>, mut cap: ResMut<OrbitCaptureState>) {
+    if cap.captured {
+        if let Ok(mut win) = windows.get_single_mut() {
+            win.cursor.visible = true;
+            win.cursor.grab_mode = CursorGrabMode::None;
+        }
+        cap.captured = false;
+    }
+}
+
+/// F switches between the orbit camera and the free fly camera during gameplay.
+fn toggle_free_camera(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut mode: ResMut<CameraMode>,
+    mut free: ResMut<FreeCameraState>,
+    mut actual: ResMut<CameraActual>,
+    q_cam: Query<&Transform, With<OrbitCamera>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+    match *mode {
+        CameraMode::Orbit => {
+            if let Ok(t) = q_cam.get_single() {
+                let (yaw, pitch, _) = t.rotation.to_euler(EulerRot::YXZ);
+                free.yaw = yaw;
+                free.pitch = pitch;
+            }
+            *mode = CameraMode::Free;
+        }
+        CameraMode::Free => {
+            // Glide back from wherever the free camera ended up.
+            actual.initialized = false;
+            *mode = CameraMode::Orbit;
+        }
+        CameraMode::Cinematic | CameraMode::Photo | CameraMode::BallCam => return,
+    }
+    info!("Camera mode: {:?}", *mode);
+}
+
+/// WASD (+ E / Q for up / down, Shift for speed) and right-drag mouse look.
+fn free_camera_fly(
+    time: Res<Time>,
+    mode: Res<CameraMode>,
+    cfg: Res<FreeCameraConfig>,
+    keys: Res<ButtonInput<KeyCode>>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    sampler: Option<Res<TerrainSampler>>,
+    mut free: ResMut<FreeCameraState>,
+    mut ev_motion: EventReader<MouseMotion>,
+    mut q_cam: Query<&mut Transform, With<OrbitCamera>>,
+) {
+    if *mode != CameraMode::Free {
+        return;
+    }
+    let Ok(mut t) = q_cam.get_single_mut() else { return; };
+    if buttons.pressed(MouseButton::Right) {
+        for m in ev_motion.read() {
+            free.yaw -= m.delta.x * cfg.sensitivity;
+            free.pitch = (free.pitch - m.delta.y * cfg.sensitivity).clamp(-1.54, 1.54);
+        }
+    }
+    let axis = |pos: KeyCode, neg: KeyCode| keys.pressed(pos) as i32 as f32 - keys.pressed(neg) as i32 as f32;
+    let input = Vec3::new(
+        axis(KeyCode::KeyD, KeyCode::KeyA),
+        axis(KeyCode::KeyE, KeyCode::KeyQ),
+        axis(KeyCode::KeyW, KeyCode::KeyS),
+    );
+    let fast = if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) { cfg.fast_multiplier } else { 1.0 };
+    t.translation += fly_direction(free.yaw, free.pitch, input) * cfg.speed * fast * time.delta_seconds();
+    if let Some(s) = &sampler {
+        let ground = s.height(t.translation.x, t.translation.z);
+        t.translation.y = t.translation.y.max(ground + cfg.min_clearance);
+    }
+    t.rotation = Quat::from_euler(EulerRot::YXZ, free.yaw, free.pitch, 0.0);
+}
+
+/// Process mouse / touch / keyboard input to update orbit state (yaw, pitch, radius) only in gameplay.
 fn orbit_camera_input(
+    time: Res<Time>,
     mut state: ResMut<OrbitCameraState>,
     cfg: Res<OrbitCameraConfig>,
+    keys: Res<ButtonInput<KeyCode>>,
     buttons: Res<ButtonInput<MouseButton>>,
     mut ev_motion: EventReader<MouseMotion>,
     mut ev_wheel: EventReader<MouseWheel>,
     mut ev_touch: EventReader<TouchInput>,
     mut touch_orbit: ResMut<TouchOrbit>,
     mut pinch: ResMut<PinchZoom>,
-    phase: Option<Res<GamePhase>>,
+    touch_controls: Option<Res<TouchControls>>,
+    mode: Res<CameraMode>,
 ) {
-    if matches!(phase.map(|p| *p), Some(GamePhase::Menu)) {
+    if *mode != CameraMode::Orbit {
         return;
     }
 
@@ -222,6 +491,10 @@ fn orbit_camera_input(
 
     // Touch processing (swipe to look, pinch to zoom)
     for ev in ev_touch.read() {
+        // Touches on the on-screen controls (touch_controls.rs) are theirs.
+        if touch_controls.as_ref().is_some_and(|c| c.owner(ev.id).is_some()) {
+            continue;
+        }
         match ev.phase {
             bevy::input::touch::TouchPhase::Started => {
                 // Pinch setup
@@ -284,6 +557,18 @@ fn orbit_camera_input(
         }
     }
 
+    // Keyboard: arrows / Q E orbit, +/- zoom (mouse-free play)
+    let held = |codes: &[KeyCode]| codes.iter().any(|k| keys.pressed(*k)) as i32 as f32;
+    let yaw_axis = held(&[KeyCode::ArrowLeft, KeyCode::KeyQ]) - held(&[KeyCode::ArrowRight, KeyCode::KeyE]);
+    let pitch_axis = held(&[KeyCode::ArrowUp]) - held(&[KeyCode::ArrowDown]);
+    let zoom_axis = held(&[KeyCode::Equal, KeyCode::NumpadAdd]) - held(&[KeyCode::Minus, KeyCode::NumpadSubtract]);
+    if yaw_axis != 0.0 || pitch_axis != 0.0 || zoom_axis != 0.0 {
+        let (dyaw, dpitch, dradius) = cfg.keyboard_orbit_delta(yaw_axis, pitch_axis, zoom_axis, time.delta_seconds());
+        state.yaw += dyaw;
+        state.pitch = (state.pitch + dpitch).clamp(cfg.pitch_min, cfg.pitch_max);
+        state.radius = (state.radius + dradius).clamp(cfg.radius_min, cfg.radius_max);
+    }
+
     // Right mouse drag to adjust yaw/pitch
     if buttons.pressed(MouseButton::Right) {
         for m in ev_motion.read() {
@@ -295,6 +580,42 @@ fn orbit_camera_input(
     }
 }
 
+/// T tweens the orbit yaw so the camera sits behind the ball facing the focused target. Turning
+/// the view by hand (drag or keys) cancels the tween.
+fn align_camera_to_target(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    cfg: Res<OrbitCameraConfig>,
+    mode: Res<CameraMode>,
+    focus: Option<Res<TargetFocus>>,
+    mut align: ResMut<CameraAlign>,
+    mut state: ResMut<OrbitCameraState>,
+    q_ball: Query<&Transform, With<Ball>>,
+    q_targets: Query<&Transform, (With<Target>, Without<Ball>)>,
+) {
+    let manual_yaw = buttons.pressed(MouseButton::Right)
+        || keys.any_pressed([KeyCode::ArrowLeft, KeyCode::ArrowRight, KeyCode::KeyQ, KeyCode::KeyE]);
+    if *mode != CameraMode::Orbit || manual_yaw {
+        align.target_yaw = None;
+        return;
+    }
+    if keys.just_pressed(KeyCode::KeyT) {
+        let target = focus.and_then(|f| f.entity).and_then(|e| q_targets.get(e).ok());
+        if let (Ok(ball), Some(target)) = (q_ball.get_single(), target) {
+            align.target_yaw = yaw_behind(ball.translation, target.translation);
+        }
+    }
+    let Some(goal) = align.target_yaw else { return; };
+    let delta = yaw_delta(state.yaw, goal);
+    if delta.abs() < 0.002 {
+        state.yaw = goal;
+        align.target_yaw = None;
+        return;
+    }
+    state.yaw += delta * (1.0 - (-cfg.align_speed * time.delta_seconds()).exp());
+}
+
 /// Endless flight while in main menu.
 /// The camera:
 /// - Moves forward with gentle speed variation
@@ -305,19 +626,16 @@ fn orbit_camera_input(
 fn menu_camera_flight(
     time: Res<Time>,
     mut flight: ResMut<MenuCameraFlight>,
-    phase: Option<Res<GamePhase>>,
     sampler: Option<Res<TerrainSampler>>,
+    low_power: Option<Res<LowPowerMode>>,
     mut q_cam: Query<&mut Transform, With<OrbitCamera>>,
 ) {
-    // Only active in menu.
-    if !matches!(phase.map(|p| *p), Some(GamePhase::Menu)) {
-        return;
-    }
     let Ok(mut cam_t) = q_cam.get_single_mut() else {
         return;
     };
 
-    let dt = time.delta_seconds();
+    // Reduced motion: hold the opening vantage point instead of flying.
+    let dt = if LowPowerMode::reduced_motion(low_power.as_deref()) { 0.0 } else { time.delta_seconds() };
     flight.t += dt;
 
     if let Some(s) = &sampler {
@@ -364,15 +682,23 @@ fn menu_camera_flight(
 }
 
 fn camera_phase_transition(
-    phase: Option<Res<GamePhase>>,
-    mut last: Local<Option<GamePhase>>,
+    phase: Option<Res<State<AppState>>>,
+    mut last: Local<Option<AppState>>,
     mut q_cam: Query<&mut Transform, With<OrbitCamera>>,
     mut follow: ResMut<CameraFollow>,
     mut actual: ResMut<CameraActual>,
+    mut mode: ResMut<CameraMode>,
+    mut cine: ResMut<CameraCinematic>,
 ) {
-    let current = phase.map(|p| *p);
+    // Pausing and resuming is not a phase change for the camera.
+    let current = phase.map(|p| if p.in_game() { AppState::Playing } else { *p.get() });
     if current != *last {
-        if matches!(current, Some(GamePhase::Playing)) {
+        // Free flight and flythroughs end with the phase.
+        cine.stop();
+        if *mode != CameraMode::Orbit {
+            *mode = CameraMode::Orbit;
+        }
+        if matches!(current, Some(AppState::Playing)) {
             if let Ok(mut t) = q_cam.get_single_mut() {
                 // High-altitude initial spawn to show whole landscape
                 t.translation = Vec3::new(0.0, 1000.0, 0.0);
@@ -384,20 +710,55 @@ fn camera_phase_transition(
     }
 }
 
+/// Widen the orbit camera FOV while the ball flies fast, easing back as it settles.
+fn apply_fov_kick(
+    time: Res<Time>,
+    cfg: Res<OrbitCameraConfig>,
+    mode: Res<CameraMode>,
+    low_power: Option<Res<LowPowerMode>>,
+    mut kick: ResMut<CameraFovKick>,
+    mut ev_shot: EventReader<ShotFiredEvent>,
+    q_ball: Query<&BallKinematic, With<Ball>>,
+    mut q_cam: Query<&mut Projection, With<OrbitCamera>>,
+    mut base_fov: Local<Option<f32>>,
+) {
+    if let Some(e) = ev_shot.read().last() {
+        kick.shot_power = e.power;
+    }
+    // Photo mode owns the FOV.
+    if *mode == CameraMode::Photo {
+        return;
+    }
+    let Ok(mut projection) = q_cam.get_single_mut() else { return; };
+    let Projection::Perspective(p) = projection.as_mut() else { return; };
+    let base = *base_fov.get_or_insert(p.fov);
+    // Only the orbit view kicks; other modes relax back to the level's FOV.
+    let speed = q_ball.get_single().map(|k| k.vel.length()).unwrap_or(0.0);
+    let kicks = *mode == CameraMode::Orbit && !LowPowerMode::reduced_motion(low_power.as_deref());
+    let want = if kicks { fov_kick_deg(&cfg, speed, kick.shot_power) } else { 0.0 };
+    let rate = if want > kick.kick_deg { cfg.fov_kick_rise } else { cfg.fov_kick_fall };
+    kick.kick_deg += (want - kick.kick_deg) * (1.0 - (-rate * time.delta_seconds()).exp());
+    let fov = base + kick.kick_deg.to_radians();
+    if (p.fov - fov).abs() > 1e-5 {
+        p.fov = fov;
+    }
+}
+
 /// Apply gameplay camera follow with speed limits (position & target smoothing).
 fn orbit_camera_apply(
     time: Res<Time>,
     state: Res<OrbitCameraState>,
     cfg: Res<OrbitCameraConfig>,
     sampler: Option<Res<TerrainSampler>>,
-    phase: Option<Res<GamePhase>>,
+    mode: Res<CameraMode>,
+    obstruction: Res<CameraObstruction>,
     mut follow: ResMut<CameraFollow>,
     mut actual: ResMut<CameraActual>,
     q_ball: Query<&Transform, With<Ball>>,
     mut q_cam: Query<&mut Transform, (With<OrbitCamera>, Without<Ball>)>,
 ) {
-    // Skip if not in gameplay phase.
-    if !matches!(phase.map(|p| *p), Some(GamePhase::Playing)) {
+    // Another camera mode has the camera.
+    if *mode != CameraMode::Orbit {
         return;
     }
 
@@ -409,10 +770,12 @@ fn orbit_camera_apply(
     };
 
     let raw_target = ball_t.translation + Vec3::Y * cfg.target_height_offset;
+    // Teleported ball: skip the springs this frame so the camera does not glide across the map.
+    let snap = follow.initialized && actual.initialized && cfg.is_teleport(follow.target, raw_target);
     follow.target = raw_target;
 
     // Spring smoothing for follow target (magnetically attracted)
-    if !follow.initialized {
+    if !follow.initialized || snap {
         follow.actual = follow.target;
         follow.initialized = true;
     } else {
@@ -426,14 +789,9 @@ fn orbit_camera_apply(
 
     // Desired camera position (spherical from yaw/pitch so positive pitch raises camera)
     // pitch in [0, ~pi/2]: 0 = horizontal, increasing -> higher
-    let yaw = state.yaw;
-    let pitch = state.pitch;
-    let dir = Vec3::new(
-        pitch.cos() * yaw.sin(),
-        pitch.sin(),
-        pitch.cos() * yaw.cos(),
-    );
-    let mut desired_pos = follow.actual + dir * state.radius;
+    let dir = orbit_direction(state.yaw, state.pitch);
+    // Line of sight: hills / trees between ball and camera cap the radius (smoothed spherecast).
+    let mut desired_pos = follow.actual + dir * state.radius.min(obstruction.radius);
 
     // Terrain clearance (optional)
     if let Some(s) = &sampler {
@@ -448,6 +806,8 @@ fn orbit_camera_apply(
     if !actual.initialized {
         actual.actual = cam_t.translation;
         actual.initialized = true;
+    } else if snap {
+        actual.actual = actual.target;
     } else {
         let dt = time.delta_seconds();
         let k = cfg.camera_spring;
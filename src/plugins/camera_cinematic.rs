@@ -0,0 +1,163 @@
+// Hole-intro flythrough.
+// When the target moves to a new spot, the camera waits for the ball to settle and then flies
+// from the new target back to the ball along a Catmull-Rom spline (`CameraMode::Cinematic`),
+// looking from the target toward the ball as it goes. A click skips it. Afterwards the orbit
+// camera springs on from wherever the flight ended, so control returns without a jump.
+
+use bevy::prelude::*;
+
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::camera::{CameraActual, CameraMode, OrbitCamera};
+use crate::plugins::game_state::Score;
+use crate::plugins::particles::TargetHitEvent;
+use crate::plugins::target::{Target, TargetFocus, TargetKind};
+
+#[derive(Resource, Debug, Clone)]
+pub struct CinematicConfig {
+    pub enabled: bool,
+    pub duration: f32,    // s for the whole flight
+    pub start_back: f32,  // m behind the target (away from the ball) the flight starts
+    pub start_rise: f32,  // m above the target
+    pub arc_height: f32,  // extra height of the mid point, fraction of the target-ball distance
+    pub max_arc: f32,     // m cap on that extra height
+    pub rest_speed: f32,  // m/s the ball must be slower than before the flight starts
+}
+impl Default for CinematicConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            duration: 3.5,
+            start_back: 18.0,
+            start_rise: 10.0,
+            arc_height: 0.25,
+            max_arc: 120.0,
+            rest_speed: 0.5,
+        }
+    }
+}
+
+/// Flythrough state: `pending` after a new target placement, `path` while flying.
+#[derive(Resource, Debug, Default)]
+pub struct CameraCinematic {
+    pub pending: bool,
+    pub path: Vec<Vec3>,
+    pub elapsed: f32,
+    pub look_from: Vec3, // the target (start of the look-at blend)
+}
+
+impl CameraCinematic {
+    pub fn active(&self) -> bool {
+        !self.path.is_empty()
+    }
+
+    pub fn stop(&mut self) {
+        self.pending = false;
+        self.path.clear();
+        self.elapsed = 0.0;
+    }
+}
+
+/// Uniform Catmull-Rom spline through `points` (end points repeated), `t` in 0..=1.
+pub fn catmull_rom(points: &[Vec3], t: f32) -> Vec3 {
+    match points.len() {
+        0 => return Vec3::ZERO,
+        1 => return points[0],
+        _ => {}
+    }
+    let segments = points.len() - 1;
+    let x = t.clamp(0.0, 1.0) * segments as f32;
+    let i = (x.floor() as usize).min(segments - 1);
+    let u = x - i as f32;
+    let p = |k: isize| points[(i as isize + k).clamp(0, segments as isize) as usize];
+    let (p0, p1, p2, p3) = (p(-1), p(0), p(1), p(2));
+    0.5 * (2.0 * p1
+        + (p2 - p0) * u
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * u * u
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * u * u * u)
+}
+
+/// Control points from the new target to the camera's current orbit position.
+pub fn intro_path(cfg: &CinematicConfig, target: Vec3, ball: Vec3, camera: Vec3) -> Vec<Vec3> {
+    let away = (target - ball).xz().try_normalize().unwrap_or(Vec2::X);
+    let start = target + Vec3::new(away.x, 0.0, away.y) * cfg.start_back + Vec3::Y * cfg.start_rise;
+    let arc = (target.xz().distance(ball.xz()) * cfg.arc_height).min(cfg.max_arc);
+    let mid = (start + camera) * 0.5 + Vec3::Y * arc;
+    vec![start, mid, camera]
+}
+
+pub(crate) fn queue_hole_intro(
+    cfg: Res<CinematicConfig>,
+    mut cine: ResMut<CameraCinematic>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+) {
+    if ev_hit.read().any(|e| e.kind == TargetKind::Standard) && cfg.enabled {
+        cine.pending = true;
+    }
+}
+
+pub(crate) fn start_hole_intro(
+    cfg: Res<CinematicConfig>,
+    score: Option<Res<Score>>,
+    focus: Res<TargetFocus>,
+    actual: Res<CameraActual>,
+    mut mode: ResMut<CameraMode>,
+    mut cine: ResMut<CameraCinematic>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+    q_targets: Query<&Transform, (With<Target>, Without<Ball>)>,
+) {
+    if !cine.pending || *mode != CameraMode::Orbit || !actual.initialized {
+        return;
+    }
+    if score.is_some_and(|s| s.game_over) {
+        cine.pending = false;
+        return;
+    }
+    let Ok((ball_t, kin)) = q_ball.get_single() else { return; };
+    if kin.vel.length() > cfg.rest_speed {
+        return;
+    }
+    let Some(target_t) = focus.entity.and_then(|e| q_targets.get(e).ok()) else { return; };
+    cine.pending = false;
+    cine.path = intro_path(&cfg, target_t.translation, ball_t.translation, actual.actual);
+    cine.look_from = target_t.translation;
+    cine.elapsed = 0.0;
+    *mode = CameraMode::Cinematic;
+}
+
+pub(crate) fn play_hole_intro(
+    time: Res<Time>,
+    cfg: Res<CinematicConfig>,
+    mut buttons: ResMut<ButtonInput<MouseButton>>,
+    mut mode: ResMut<CameraMode>,
+    mut cine: ResMut<CameraCinematic>,
+    mut actual: ResMut<CameraActual>,
+    q_ball: Query<&Transform, With<Ball>>,
+    mut q_cam: Query<&mut Transform, (With<OrbitCamera>, Without<Ball>)>,
+) {
+    if *mode != CameraMode::Cinematic || !cine.active() {
+        return;
+    }
+    let Ok(mut cam_t) = q_cam.get_single_mut() else { return; };
+    cine.elapsed += time.delta_seconds();
+    let skip = buttons.just_pressed(MouseButton::Left);
+    if skip {
+        // The skip click must not start charging a shot.
+        buttons.clear_just_pressed(MouseButton::Left);
+    }
+    if skip || cine.elapsed >= cfg.duration {
+        cine.stop();
+        actual.actual = cam_t.translation;
+        *mode = CameraMode::Orbit;
+        return;
+    }
+    let k = (cine.elapsed / cfg.duration.max(0.01)).clamp(0.0, 1.0);
+    let eased = k * k * (3.0 - 2.0 * k);
+    cam_t.translation = catmull_rom(&cine.path, eased);
+    let ball = q_ball.get_single().map(|t| t.translation).unwrap_or(cine.look_from);
+    // Look at the target first, then swing round to the ball.
+    let look = cine.look_from.lerp(ball, (eased * 1.4 - 0.2).clamp(0.0, 1.0));
+    if look.distance_squared(cam_t.translation) > 1e-4 {
+        cam_t.look_at(look, Vec3::Y);
+    }
+}
+
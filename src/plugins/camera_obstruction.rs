@@ -0,0 +1,128 @@
+// Keeps the ball visible when a hill or trees sit between camera and ball.
+// Each frame a spherecast (a bundle of parallel terrain raycasts) plus a test against nearby tree
+// cylinders runs from the follow target toward the desired orbit position. The closest hit caps
+// the orbit radius; the cap snaps in quickly when something blocks the view and eases back out
+// once the line is clear, so the camera does not pump in and out over bumpy ground.
+
+use bevy::prelude::*;
+
+use crate::plugins::camera::{orbit_direction, CameraFollow, OrbitCameraConfig, OrbitCameraState};
+use crate::plugins::terrain::TerrainSampler;
+use crate::plugins::vegetation::{Tree, VegetationGrid};
+
+#[derive(Resource, Debug, Clone)]
+pub struct CameraObstructionConfig {
+    pub enabled: bool,
+    pub probe_radius: f32,   // spherecast radius (m)
+    pub trees: bool,         // also test tree canopies
+    pub tree_radius: f32,    // occluding cylinder radius at tree scale 1 (m)
+    pub tree_height: f32,    // occluding cylinder height at tree scale 1 (m)
+    pub pull_in_rate: f32,   // 1/s, how fast the radius shrinks to a new obstruction
+    pub ease_out_rate: f32,  // 1/s, how fast it grows back once clear
+    pub min_radius: f32,     // never closer than this to the follow target
+}
+impl Default for CameraObstructionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            probe_radius: 0.6,
+            trees: true,
+            tree_radius: 1.8,
+            tree_height: 9.0,
+            pull_in_rate: 18.0,
+            ease_out_rate: 2.0,
+            min_radius: 1.5,
+        }
+    }
+}
+
+/// Current orbit radius cap from obstructions (`f32::INFINITY` = unobstructed).
+#[derive(Resource, Debug)]
+pub struct CameraObstruction {
+    pub allowed: f32, // raw cap this frame
+    pub radius: f32,  // smoothed cap applied to the orbit radius
+}
+impl Default for CameraObstruction {
+    fn default() -> Self {
+        Self { allowed: f32::INFINITY, radius: f32::INFINITY }
+    }
+}
+
+/// Sphere swept along `dir` against the terrain, approximated by the centre ray plus four rays
+/// offset by `radius` around it. Returns the closest hit distance.
+pub fn spherecast_terrain(sampler: &TerrainSampler, origin: Vec3, dir: Vec3, max_distance: f32, radius: f32) -> Option<f32> {
+    let dir = dir.normalize_or_zero();
+    if dir == Vec3::ZERO {
+        return None;
+    }
+    let side = dir.cross(Vec3::Y).try_normalize().unwrap_or(Vec3::X);
+    let up = side.cross(dir);
+    [Vec3::ZERO, side, -side, up, -up]
+        .iter()
+        .filter_map(|o| sampler.raycast_max(origin + *o * radius, dir, max_distance).map(|h| h.distance))
+        .min_by(f32::total_cmp)
+}
+
+/// Ray vs vertical cylinder (`base` at the bottom centre). Returns the entry distance.
+pub fn ray_cylinder(origin: Vec3, dir: Vec3, max_distance: f32, base: Vec3, radius: f32, height: f32) -> Option<f32> {
+    let dir = dir.normalize_or_zero();
+    let (o, d) = (origin.xz() - base.xz(), dir.xz());
+    let a = d.length_squared();
+    let (b, c) = (o.dot(d), o.length_squared() - radius * radius);
+    let t = if c <= 0.0 {
+        0.0 // starts inside the circle
+    } else {
+        let disc = b * b - a * c;
+        if a < 1e-8 || disc < 0.0 || b > 0.0 {
+            return None;
+        }
+        (-b - disc.sqrt()) / a
+    };
+    let y = origin.y + dir.y * t;
+    (t <= max_distance && y >= base.y && y <= base.y + height).then_some(t)
+}
+
+/// Smooth the radius cap toward `allowed`: quick pull-in, slow ease-out.
+pub fn smooth_cap(current: f32, allowed: f32, max: f32, cfg: &CameraObstructionConfig, dt: f32) -> f32 {
+    let current = current.min(max);
+    let allowed = allowed.min(max);
+    let rate = if allowed < current { cfg.pull_in_rate } else { cfg.ease_out_rate };
+    current + (allowed - current) * (1.0 - (-rate * dt).exp())
+}
+
+pub(crate) fn update_camera_obstruction(
+    time: Res<Time>,
+    cfg: Res<CameraObstructionConfig>,
+    cam_cfg: Res<OrbitCameraConfig>,
+    state: Res<OrbitCameraState>,
+    follow: Res<CameraFollow>,
+    sampler: Option<Res<TerrainSampler>>,
+    grid: Option<Res<VegetationGrid>>,
+    q_trees: Query<&GlobalTransform, With<Tree>>,
+    mut obstruction: ResMut<CameraObstruction>,
+) {
+    if !cfg.enabled || !follow.initialized {
+        *obstruction = CameraObstruction::default();
+        return;
+    }
+    let origin = follow.actual;
+    let dir = orbit_direction(state.yaw, state.pitch);
+    let max = state.radius;
+    let mut hit = sampler
+        .as_deref()
+        .and_then(|s| spherecast_terrain(s, origin, dir, max, cfg.probe_radius))
+        .unwrap_or(f32::INFINITY);
+    if let (true, Some(grid)) = (cfg.trees, grid) {
+        let mid = origin + dir * (max * 0.5);
+        for (e, _) in grid.trees_within(mid.xz(), max * 0.5 + cfg.tree_radius * 3.0) {
+            let Ok(gt) = q_trees.get(e) else { continue; };
+            let (scale, _, base) = gt.to_scale_rotation_translation();
+            let r = cfg.tree_radius * scale.x + cfg.probe_radius;
+            if let Some(t) = ray_cylinder(origin, dir, max, base, r, cfg.tree_height * scale.y) {
+                hit = hit.min(t);
+            }
+        }
+    }
+    obstruction.allowed = if hit.is_finite() { (hit - cam_cfg.min_clearance).max(cfg.min_radius) } else { f32::INFINITY };
+    obstruction.radius = smooth_cap(obstruction.radius, obstruction.allowed, max, &cfg, time.delta_seconds());
+}
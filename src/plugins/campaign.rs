@@ -0,0 +1,184 @@
+// Campaign progression.
+// `CampaignState` remembers per level id whether the level was finished, its best time and the
+// best medal earned, saved to campaign.ron in the data directory (see storage.rs). Medals come
+// from the time thresholds in the level's `medals`. A manifest level unlocks once the level it
+// `requires` (by default the one before it in the manifest) is completed; the first level is
+// always open. The level selector shows lock state and medals and won't start a locked level.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::plugins::game_state::Score;
+use crate::plugins::level::{LevelDef, LevelManifest};
+use crate::plugins::localization::Localization;
+use crate::plugins::particles::GameOverEvent;
+use crate::plugins::storage::StoragePaths;
+use crate::plugins::ui_toast::{ToastKind, ToastQueue};
+
+const CAMPAIGN_FILE: &str = "campaign.ron";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Medal {
+    Bronze,
+    Silver,
+    Gold,
+}
+
+impl Medal {
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Self::Bronze => "medal.bronze",
+            Self::Silver => "medal.silver",
+            Self::Gold => "medal.gold",
+        }
+    }
+}
+
+/// Finishing times (s, at most) for each medal, from the level RON.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct MedalTimes {
+    pub gold: f32,
+    pub silver: f32,
+    pub bronze: f32,
+}
+
+impl MedalTimes {
+    pub fn medal_for(&self, time: f32) -> Option<Medal> {
+        if time <= self.gold {
+            Some(Medal::Gold)
+        } else if time <= self.silver {
+            Some(Medal::Silver)
+        } else if time <= self.bronze {
+            Some(Medal::Bronze)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LevelProgress {
+    pub completed: bool,
+    pub best_time: Option<f32>,
+    pub medal: Option<Medal>,
+}
+
+/// What a finished run changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FinishOutcome {
+    pub first_completion: bool,
+    pub new_medal: Option<Medal>, // only when better than the medal held before
+}
+
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CampaignState {
+    pub levels: BTreeMap<String, LevelProgress>,
+}
+
+impl CampaignState {
+    pub fn from_ron(data: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str::<Self>(data)
+    }
+
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()).unwrap_or_default()
+    }
+
+    pub fn progress(&self, level_id: &str) -> Option<&LevelProgress> {
+        self.levels.get(level_id)
+    }
+
+    pub fn is_completed(&self, level_id: &str) -> bool {
+        self.progress(level_id).is_some_and(|p| p.completed)
+    }
+
+    pub fn record_finish(&mut self, level_id: &str, time: f32, medals: Option<&MedalTimes>) -> FinishOutcome {
+        let progress = self.levels.entry(level_id.to_string()).or_default();
+        let first_completion = !progress.completed;
+        progress.completed = true;
+        progress.best_time = Some(progress.best_time.map_or(time, |best| best.min(time)));
+        let earned = medals.and_then(|m| m.medal_for(time));
+        let new_medal = earned.filter(|m| progress.medal.is_none_or(|held| *m > held));
+        if new_medal.is_some() {
+            progress.medal = new_medal;
+        }
+        FinishOutcome { first_completion, new_medal }
+    }
+
+    /// Id of the level that has to be completed before manifest level `index` (None: always open,
+    /// like the Daily Run).
+    pub fn requirement(manifest: &LevelManifest, index: usize) -> Option<&str> {
+        let entry = manifest.levels.get(index)?;
+        if entry.daily {
+            return None;
+        }
+        match &entry.requires {
+            Some(id) => Some(id.as_str()),
+            None if index > 0 => manifest.levels.get(index - 1).map(|l| l.id.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn is_unlocked(&self, manifest: &LevelManifest, index: usize) -> bool {
+        Self::requirement(manifest, index).is_none_or(|id| self.is_completed(id))
+    }
+}
+
+fn load_campaign(paths: &StoragePaths) -> CampaignState {
+    let Some(data) = paths.load_text(CAMPAIGN_FILE) else {
+        return CampaignState::default();
+    };
+    CampaignState::from_ron(&data).unwrap_or_else(|e| {
+        warn!("Ignoring unreadable {CAMPAIGN_FILE}: {e}");
+        CampaignState::default()
+    })
+}
+
+pub struct CampaignPlugin;
+impl Plugin for CampaignPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(load_campaign(&StoragePaths::in_world(app.world())))
+            .add_systems(Update, record_campaign_progress)
+            .add_systems(Last, persist_campaign);
+    }
+}
+
+fn record_campaign_progress(
+    mut ev_game_over: EventReader<GameOverEvent>,
+    score: Res<Score>,
+    level: Option<Res<LevelDef>>,
+    manifest: Option<Res<LevelManifest>>,
+    loc: Res<Localization>,
+    mut campaign: ResMut<CampaignState>,
+    mut toasts: ResMut<ToastQueue>,
+) {
+    if ev_game_over.read().count() == 0 {
+        return;
+    }
+    let locked_before: Vec<bool> = manifest
+        .as_ref()
+        .map(|m| (0..m.levels.len()).map(|i| !campaign.is_unlocked(m, i)).collect())
+        .unwrap_or_default();
+    let medals = level.as_ref().and_then(|l| l.medals.as_ref());
+    let outcome = campaign.record_finish(&score.level_id, score.final_time, medals);
+    if let Some(medal) = outcome.new_medal {
+        toasts.push_for(loc.tf("campaign.medal", &[("medal", loc.t(medal.label_key()).to_string())]), ToastKind::Success, 3.0);
+    }
+    let Some(manifest) = manifest else { return; };
+    for (i, was_locked) in locked_before.into_iter().enumerate() {
+        if was_locked && campaign.is_unlocked(&manifest, i) {
+            let name = manifest.levels[i].name.clone();
+            toasts.push_for(loc.tf("campaign.unlocked", &[("name", name)]), ToastKind::Success, 3.0);
+        }
+    }
+}
+
+fn persist_campaign(campaign: Res<CampaignState>, paths: Res<StoragePaths>) {
+    if campaign.is_changed() && !campaign.is_added() {
+        paths.save_text(CAMPAIGN_FILE, &campaign.to_ron());
+    }
+}
@@ -0,0 +1,399 @@
+// Async challenge codes.
+// A `ChallengeCode` ("VG1-<seed>-<mode>-<record>-<level>") names a level, a game mode and a course
+// seed, plus the challenger's best record on it once they have one. With a challenge active (typed
+// into the main menu's Challenge panel, F2 there for a fresh one, or `--challenge <code>`) the
+// level is rebuilt from the seed the way the Daily Run is (tees, targets, pars and per-hole wind,
+// daily.rs; designed courses keep their holes) and every round restarts the simulation RNG from
+// it, so both players get the exact same course. Records go under the challenge's own key; the
+// game-over panel compares your profile's record there with the challenger's and prints a reply
+// code carrying yours.
+
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+
+use crate::launch_options::LaunchOptions;
+use crate::plugins::app_state::AppState;
+use crate::plugins::game_mode::GameMode;
+use crate::plugins::game_state::Score;
+use crate::plugins::particles::GameOverEvent;
+use crate::plugins::level::{LevelEntry, LevelManifest};
+use crate::plugins::localization::{Localization, LocalizedText};
+use crate::plugins::profile::Profile;
+use crate::plugins::ui_toast::{ToastKind, ToastQueue};
+use crate::plugins::user_settings::UserSettings;
+
+pub const CHALLENGE_PREFIX: &str = "VG1";
+const MAX_CODE_LEN: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChallengeCode {
+    pub level: String, // manifest id
+    pub seed: u32,
+    pub mode: GameMode,
+    pub record: Option<f32>, // challenger's best (mode record: s or strokes)
+}
+
+fn mode_letter(mode: GameMode) -> char {
+    match mode {
+        GameMode::TimeAttack => 'T',
+        GameMode::StrokePlay => 'S',
+        GameMode::HardPar => 'H',
+    }
+}
+
+impl ChallengeCode {
+    /// A fresh challenge on `level`: random seed, no record yet.
+    pub fn new(level: &str, mode: GameMode) -> Self {
+        Self { level: level.to_string(), seed: rand::random(), mode, record: None }
+    }
+
+    /// `VG1-9F3A21C4-T-4812-canyon`: record in hundredths, 0 while there is none.
+    pub fn encode(&self) -> String {
+        let record = self.record.map_or(0, |r| (r * 100.0).round().max(1.0) as u64);
+        format!("{CHALLENGE_PREFIX}-{:08X}-{}-{record}-{}", self.seed, mode_letter(self.mode), self.level)
+    }
+
+    /// Codes are read case-insensitively up to the level id; surrounding spaces are ignored.
+    pub fn parse(code: &str) -> Option<Self> {
+        let mut parts = code.trim().splitn(5, '-');
+        if !parts.next()?.eq_ignore_ascii_case(CHALLENGE_PREFIX) {
+            return None;
+        }
+        let seed = u32::from_str_radix(parts.next()?, 16).ok()?;
+        let mode = match parts.next()?.to_ascii_uppercase().as_str() {
+            "T" => GameMode::TimeAttack,
+            "S" => GameMode::StrokePlay,
+            "H" => GameMode::HardPar,
+            _ => return None,
+        };
+        let record = parts.next()?.parse::<u64>().ok()?;
+        let level = parts.next().filter(|l| !l.is_empty())?.to_string();
+        Some(Self { level, seed, mode, record: (record > 0).then_some(record as f32 / 100.0) })
+    }
+
+    /// Best-record key of rounds played on this challenge (before the mode suffix).
+    pub fn score_id(&self) -> String {
+        format!("challenge-{}-{:08x}", self.level, self.seed)
+    }
+
+    /// The same challenge carrying `record` for the other player to beat.
+    pub fn with_record(&self, record: Option<f32>) -> Self {
+        Self { record, ..self.clone() }
+    }
+}
+
+/// Head-to-head line: your record against the challenger's, lower is better.
+pub fn compare_records(mine: Option<f32>, theirs: Option<f32>, format: impl Fn(f32) -> String) -> String {
+    match (mine, theirs) {
+        (Some(m), Some(t)) if m < t => format!("you {} vs {} - you win by {}", format(m), format(t), format(t - m)),
+        (Some(m), Some(t)) if m > t => format!("you {} vs {} - they win by {}", format(m), format(t), format(m - t)),
+        (Some(m), Some(_)) => format!("you {} - a tie", format(m)),
+        (Some(m), None) => format!("you set {}", format(m)),
+        (None, Some(t)) => format!("{} to beat", format(t)),
+        (None, None) => "no result yet".to_string(),
+    }
+}
+
+/// The challenge being played, if any. It applies while its level is the one selected.
+#[derive(Resource, Debug, Default)]
+pub struct ActiveChallenge(pub Option<ChallengeCode>);
+
+impl ActiveChallenge {
+    /// The challenge, when `level_id` is its level.
+    pub fn on_level(&self, level_id: &str) -> Option<&ChallengeCode> {
+        self.0.as_ref().filter(|c| c.level == level_id)
+    }
+
+    /// Best-record key for a round on `entry`: the challenge's while it applies.
+    pub fn score_id(&self, entry: &LevelEntry) -> String {
+        self.on_level(&entry.id).map_or_else(|| entry.score_id(), |c| c.score_id())
+    }
+
+    /// Seed the course of `level_id` is generated from (`None`: no challenge on it).
+    pub fn course_seed(&self, level_id: &str) -> Option<u64> {
+        self.on_level(level_id).map(|c| u64::from(c.seed))
+    }
+
+    /// Game-over line for a round on the challenge: both records and the reply code.
+    pub fn result_line(&self, score: &Score, profile: &Profile, loc: &Localization) -> Option<String> {
+        let code = self.0.as_ref().filter(|c| c.score_id() == score.level_id && c.mode == score.mode)?;
+        let rules = code.mode.rules();
+        let mine = profile.best(&score.record_key());
+        let versus = compare_records(mine, code.record, |v| rules.format_record(loc, v));
+        Some(format!("Challenge: {versus}\nReply code: {}", code.with_record(mine).encode()))
+    }
+
+    /// Seed the simulation RNG restarts from for a round kept under `score_id`.
+    pub fn round_seed(&self, score_id: &str) -> Option<u64> {
+        self.0.as_ref().filter(|c| c.score_id() == score_id).map(|c| u64::from(c.seed))
+    }
+}
+
+/// Main menu button opening the challenge panel (spawned by main_menu.rs).
+#[derive(Component)]
+pub struct ChallengeButton;
+
+#[derive(Component)]
+struct ChallengePanel;
+#[derive(Component)]
+struct ChallengeInputText;
+#[derive(Component)]
+struct ChallengeStatusText;
+
+#[derive(Resource, Debug, Default)]
+struct ChallengeEntry {
+    open: bool,
+    typed: String,
+    error: Option<&'static str>, // localization key
+}
+
+pub struct ChallengePlugin;
+impl Plugin for ChallengePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActiveChallenge>()
+            .init_resource::<ChallengeEntry>()
+            .add_systems(Startup, spawn_challenge_panel)
+            .add_systems(PostStartup, apply_launch_challenge)
+            .add_systems(Update, (toggle_challenge_panel, type_challenge_code, update_challenge_panel).chain())
+            .add_systems(Update, log_reply_code);
+    }
+}
+
+/// Make `code` the active challenge: its level gets selected (and rebuilt from the seed when Play
+/// is pressed), its mode becomes the menu's mode.
+fn activate(
+    code: ChallengeCode,
+    challenge: &mut ActiveChallenge,
+    manifest: &mut LevelManifest,
+    settings: &mut UserSettings,
+) -> Result<(), &'static str> {
+    let index = manifest.index_of(&code.level).ok_or("challenge.unknown_level")?;
+    manifest.selected = index;
+    // Nothing live matches the challenge course yet: have Play switch the level in again.
+    manifest.loaded = usize::MAX;
+    settings.game_mode = code.mode;
+    challenge.0 = Some(code);
+    Ok(())
+}
+
+fn apply_launch_challenge(
+    options: Option<Res<LaunchOptions>>,
+    mut manifest: Option<ResMut<LevelManifest>>,
+    mut settings: ResMut<UserSettings>,
+    mut challenge: ResMut<ActiveChallenge>,
+) {
+    let (Some(code), Some(manifest)) = (options.and_then(|o| o.challenge.clone()), manifest.as_mut()) else { return; };
+    let Some(parsed) = ChallengeCode::parse(&code) else {
+        warn!("Not a challenge code: {code}");
+        return;
+    };
+    match activate(parsed, &mut challenge, manifest, &mut settings) {
+        Ok(()) => info!("Challenge {code} active"),
+        Err(_) => warn!("Challenge {code} is for a level this build does not have"),
+    }
+}
+
+fn spawn_challenge_panel(mut commands: Commands, assets: Res<AssetServer>) {
+    let font = assets.load("fonts/FiraSans-Bold.ttf");
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
+                z_index: ZIndex::Global(50),
+                ..default()
+            },
+            ChallengePanel,
+        ))
+        .with_children(|root| {
+            root.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(10.0),
+                    padding: UiRect::all(Val::Px(20.0)),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(0.05, 0.06, 0.10, 0.95)),
+                ..default()
+            })
+            .with_children(|panel| {
+                panel.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle { font: font.clone(), font_size: 32.0, color: Color::srgb(0.95, 0.95, 1.0) },
+                    ),
+                    LocalizedText::new("challenge.title"),
+                ));
+                panel.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle { font: font.clone(), font_size: 26.0, color: Color::srgb(0.95, 0.85, 0.45) },
+                    ),
+                    ChallengeInputText,
+                ));
+                panel.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle { font: font.clone(), font_size: 20.0, color: Color::srgb(0.85, 0.85, 0.90) },
+                    ),
+                    ChallengeStatusText,
+                ));
+                panel.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle { font: font.clone(), font_size: 16.0, color: Color::srgb(0.55, 0.55, 0.60) },
+                    ),
+                    LocalizedText::new("challenge.hint"),
+                ));
+            });
+        });
+}
+
+fn toggle_challenge_panel(
+    keys: Res<ButtonInput<KeyCode>>,
+    phase: Res<State<AppState>>,
+    mut entry: ResMut<ChallengeEntry>,
+    q_button: Query<&Interaction, (Changed<Interaction>, With<ChallengeButton>)>,
+) {
+    if *phase.get() != AppState::Menu {
+        if entry.open {
+            entry.open = false;
+        }
+        return;
+    }
+    if q_button.iter().any(|i| *i == Interaction::Pressed) {
+        entry.open = !entry.open;
+    } else if entry.open && keys.just_pressed(KeyCode::Escape) {
+        entry.open = false;
+    }
+}
+
+fn type_challenge_code(
+    loc: Res<Localization>,
+    mut entry: ResMut<ChallengeEntry>,
+    mut challenge: ResMut<ActiveChallenge>,
+    mut manifest: Option<ResMut<LevelManifest>>,
+    mut settings: ResMut<UserSettings>,
+    mut toasts: ResMut<ToastQueue>,
+    mut ev_keys: EventReader<KeyboardInput>,
+) {
+    if !entry.open {
+        ev_keys.clear();
+        return;
+    }
+    let Some(manifest) = manifest.as_mut() else { return; };
+    for key in ev_keys.read().filter(|k| k.state == ButtonState::Pressed) {
+        let code = match &key.logical_key {
+            Key::Character(c) => {
+                let c: String = c.chars().filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_').collect();
+                if entry.typed.len() + c.len() <= MAX_CODE_LEN {
+                    entry.typed.push_str(&c);
+                    entry.error = None;
+                }
+                continue;
+            }
+            Key::Backspace => {
+                entry.typed.pop();
+                entry.error = None;
+                continue;
+            }
+            Key::Delete => {
+                // Leave the challenge and clear the field.
+                entry.typed.clear();
+                entry.error = None;
+                if challenge.0.take().is_some() {
+                    manifest.loaded = usize::MAX;
+                }
+                continue;
+            }
+            Key::F2 => {
+                let Some(level) = manifest.selected_entry().map(|e| e.id.clone()) else { continue; };
+                ChallengeCode::new(&level, settings.game_mode)
+            }
+            Key::Enter => match ChallengeCode::parse(&entry.typed) {
+                Some(code) => code,
+                None => {
+                    entry.error = Some("challenge.invalid");
+                    continue;
+                }
+            },
+            _ => continue,
+        };
+        entry.typed = code.encode();
+        let level = manifest.index_of(&code.level).map(|i| manifest.levels[i].name.clone()).unwrap_or_default();
+        match activate(code, &mut challenge, manifest, &mut settings) {
+            Ok(()) => {
+                entry.error = None;
+                toasts.push_for(loc.tf("challenge.accepted", &[("level", level)]), ToastKind::Success, 3.0);
+            }
+            Err(key) => entry.error = Some(key),
+        }
+    }
+}
+
+fn update_challenge_panel(
+    entry: Res<ChallengeEntry>,
+    challenge: Res<ActiveChallenge>,
+    profile: Res<Profile>,
+    loc: Res<Localization>,
+    time: Res<Time>,
+    mut q_panel: Query<&mut Visibility, With<ChallengePanel>>,
+    mut q_input: Query<&mut Text, (With<ChallengeInputText>, Without<ChallengeStatusText>)>,
+    mut q_status: Query<&mut Text, (With<ChallengeStatusText>, Without<ChallengeInputText>)>,
+) {
+    for mut vis in &mut q_panel {
+        let want = if entry.open { Visibility::Inherited } else { Visibility::Hidden };
+        if *vis != want {
+            *vis = want;
+        }
+    }
+    if !entry.open {
+        return;
+    }
+    if let Ok(mut text) = q_input.get_single_mut() {
+        let cursor = if time.elapsed_seconds().fract() < 0.5 { "_" } else { " " };
+        let value = format!("> {}{cursor}", entry.typed);
+        if text.sections[0].value != value {
+            text.sections[0].value = value;
+        }
+    }
+    let status = match (entry.error, challenge.0.as_ref()) {
+        (Some(key), _) => loc.t(key).to_string(),
+        (None, Some(code)) => {
+            let rules = code.mode.rules();
+            let mine = profile.best(&code.mode.record_key(&code.score_id()));
+            let versus = compare_records(mine, code.record, |v| rules.format_record(&loc, v));
+            loc.tf("challenge.active", &[("code", code.encode()), ("mode", loc.t(rules.label_key()).to_string()), ("versus", versus)])
+        }
+        (None, None) => loc.t("challenge.none").to_string(),
+    };
+    if let Ok(mut text) = q_status.get_single_mut() {
+        if text.sections[0].value != status {
+            text.sections[0].value = status;
+        }
+    }
+}
+
+// The reply code also goes to the log, where it can be copied from.
+fn log_reply_code(
+    challenge: Res<ActiveChallenge>,
+    score: Res<Score>,
+    profile: Res<Profile>,
+    loc: Res<Localization>,
+    mut ev_game_over: EventReader<GameOverEvent>,
+) {
+    if ev_game_over.read().count() == 0 {
+        return;
+    }
+    if let Some(line) = challenge.result_line(&score, &profile, &loc) {
+        info!("{}", line.replace('\n', " | "));
+    }
+}
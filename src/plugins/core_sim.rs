@@ -2,11 +2,10 @@ use bevy::prelude::*;
 use bevy::app::AppExit;
 use bevy::time::Fixed;
 use bevy_rapier3d::prelude::{Velocity, RigidBody};
-use bevy::pbr::NotShadowCaster;
-use std::collections::HashSet;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use crate::plugins::game_state::Score;
-use crate::plugins::terrain::{LoadedChunks, TerrainChunk};
-use crate::plugins::vegetation::Tree;
+use crate::plugins::app_state::gameplay_running;
 
 // Core simulation timing & shared gameplay configuration/types.
 #[derive(Resource, Default, Debug)]
@@ -21,15 +20,42 @@ impl SimState {
     }
 }
 
+/// Randomness of the gameplay simulation (target relocation, float phases). Seeded from entropy
+/// in the game; headless runs (headless.rs) seed it so the same inputs give the same run.
+#[derive(Resource)]
+pub struct SimRng(pub StdRng);
+impl Default for SimRng {
+    fn default() -> Self {
+        Self(StdRng::from_entropy())
+    }
+}
+impl SimRng {
+    pub fn seeded(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+/// Gameplay phases, run in this order in both loops. FixedUpdate: Input (the autoplay swing) ->
+/// Simulate (sim clock, ball physics and the ball's collisions) -> React (target hits, gates).
+/// Update: Input (shot, aim and camera input) -> React (target motion and focus, restarts) ->
+/// Present (camera follow, shot indicators and power gauge), so the camera frames where the ball
+/// and targets are this frame. A shot fired in Update is launched on the next fixed tick.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameSet {
+    Input,
+    Simulate,
+    React,
+    Present,
+}
+
 #[derive(Resource)]
 pub struct AutoConfig {
     // If exit_enabled is false the game will not auto-exit (normal interactive play).
     // When set via -runtime flag exit_enabled=true and run_duration_seconds is respected.
     pub exit_enabled: bool,
     pub run_duration_seconds: f32,
+    // Autoplay bot (autoplay.rs): minimum time between its swings.
     pub swing_interval_seconds: f32,
-    pub base_impulse: f32,
-    pub upward_factor: f32,
 }
 impl Default for AutoConfig {
     fn default() -> Self {
@@ -37,8 +63,6 @@ impl Default for AutoConfig {
             exit_enabled: false,
             run_duration_seconds: 20.0,
             swing_interval_seconds: 3.0,
-            base_impulse: 6.0,
-            upward_factor: 0.0,
         }
     }
 }
@@ -55,12 +79,15 @@ pub struct CoreSimPlugin;
 impl Plugin for CoreSimPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(SimState::default())
+            .init_resource::<SimRng>() // respect a pre-inserted seed (headless runs)
             .init_resource::<AutoConfig>() // respect pre-inserted AutoConfig (e.g. from -runtime flag)
             .insert_resource(AutoRuntime::default())
             .insert_resource(LogState::default())
             .insert_resource(ExitState::default())
             .insert_resource(Time::<Fixed>::from_hz(60.0))
-            .add_systems(FixedUpdate, tick_state)
+            .configure_sets(FixedUpdate, (GameSet::Input, GameSet::Simulate, GameSet::React).chain())
+            .configure_sets(Update, (GameSet::Input, GameSet::Simulate, GameSet::React, GameSet::Present).chain())
+            .add_systems(FixedUpdate, tick_state.in_set(GameSet::Simulate).run_if(gameplay_running))
             .add_systems(Update, apply_custom_gravity)
             .add_systems(Update, exit_after_runtime);
     }
@@ -86,51 +113,15 @@ fn apply_custom_gravity(mut q: Query<(&RigidBody, &mut Velocity)>) {
     }
 }
 
+// The run summary (run_summary.rs) is written on the AppExit this sends.
 fn exit_after_runtime(
     sim: Res<SimState>,
     auto: Res<AutoConfig>,
     mut exit_state: ResMut<ExitState>,
     mut ev_exit: EventWriter<AppExit>,
-    loaded_chunks: Option<Res<LoadedChunks>>,
-    q_tree_mesh: Query<(&Handle<Mesh>, &Handle<StandardMaterial>, Option<&NotShadowCaster>, &Visibility), With<Tree>>,
-    q_chunks: Query<&TerrainChunk>,
 ) {
     if exit_state.triggered { return; }
     if auto.exit_enabled && sim.elapsed_seconds >= auto.run_duration_seconds {
-        // OPT instrumentation: one-time final stats summary (chunks, trees, batches)
-        let chunk_count = loaded_chunks.as_ref().map(|lc| lc.map.len()).unwrap_or(0);
-        let mut unique: HashSet<(Handle<Mesh>, Handle<StandardMaterial>, bool)> = HashSet::new();
-        let mut visible_trees = 0usize;
-        for (mesh, mat, shadow_flag, vis) in &q_tree_mesh {
-            if *vis != Visibility::Hidden {
-                visible_trees += 1;
-                unique.insert((mesh.clone(), mat.clone(), shadow_flag.is_none()));
-            }
-        }
-        // LOD distribution stats
-        let mut lod_res_96 = 0usize;
-        let mut lod_res_48 = 0usize;
-        let mut lod_res_24 = 0usize;
-        let mut lod_res_other = 0usize;
-        for tc in &q_chunks {
-            match tc.res {
-                96 => lod_res_96 += 1,
-                48 => lod_res_48 += 1,
-                24 => lod_res_24 += 1,
-                _ => lod_res_other += 1,
-            }
-        }
-        info!(
-            "FINAL_STATS chunks={} visible_trees={} approx_unique_tree_batches={} lod96={} lod48={} lod24={} lodOther={} sim_seconds={}",
-            chunk_count,
-            visible_trees,
-            unique.len(),
-            lod_res_96,
-            lod_res_48,
-            lod_res_24,
-            lod_res_other,
-            sim.elapsed_seconds
-        );
         info!("EXIT runtime reached seconds={}", sim.elapsed_seconds);
         exit_state.triggered = true;
         ev_exit.send(AppExit::Success);
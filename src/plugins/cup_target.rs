@@ -0,0 +1,291 @@
+// Ground cup targets (classic hole + flag) as an alternative to the floating duck.
+// A target's `TargetStyle` is `Floating` (default) or `Cup`; levels set it per target
+// (`TargetDef::style`) and, for the primary target, per hole (`LevelDef::hole_styles`). A cup
+// sits on a flattened green stamped into the terrain (`TerrainDeformations`), with a flagpole to
+// spot it from afar. The ball is captured when it rolls slowly into the cup radius (checked in
+// `detect_target_hits`); the old flag then leans over and is cleared away while the target moves
+// on as usual. Cups ignore flight paths and the float bob.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::plugins::game_state::Score;
+use crate::plugins::level::LevelDef;
+use crate::plugins::target::{Target, TargetFloat, TargetInfo, TargetMotion, TargetParams};
+use crate::plugins::terrain::{TerrainSampler, TerrainStamp};
+use crate::plugins::terrain_deform::TerrainDeformations;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum TargetStyle {
+    #[default]
+    Floating,
+    Cup,
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct CupConfig {
+    pub cup_radius: f32,      // m (generous compared to a real cup)
+    pub capture_speed: f32,   // m/s the ball must be slower than
+    pub capture_height: f32,  // m above the green the ball centre may be
+    pub green_radius: f32,    // flattened, fully level area
+    pub green_falloff: f32,   // blend back to the original terrain
+    pub flag_height: f32,
+    pub lean_angle_deg: f32,
+    pub lean_time: f32,       // s for the flag to lean over after capture
+    pub flag_linger: f32,     // s before the leaning flag is removed
+}
+impl Default for CupConfig {
+    fn default() -> Self {
+        Self {
+            cup_radius: 1.2,
+            capture_speed: 3.0,
+            capture_height: 1.0,
+            green_radius: 9.0,
+            green_falloff: 6.0,
+            flag_height: 6.0,
+            lean_angle_deg: 35.0,
+            lean_time: 0.8,
+            flag_linger: 4.0,
+        }
+    }
+}
+
+impl CupConfig {
+    /// Is a ball at `ball` moving at `speed` captured by the cup at `cup` (green height `ground`)?
+    pub fn captures(&self, ball: Vec3, speed: f32, cup: Vec2, ground: f32) -> bool {
+        ball.xz().distance_squared(cup) <= self.cup_radius * self.cup_radius
+            && speed <= self.capture_speed
+            && ball.y <= ground + self.capture_height
+    }
+
+    pub fn green_stamp(&self, center: Vec2, height: f32) -> TerrainStamp {
+        TerrainStamp { center, radius: self.green_radius, falloff: self.green_falloff, height }
+    }
+}
+
+/// Active cup on a target entity.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CupState {
+    pub pos: Vec2,
+    pub ground: f32,
+    flag: Entity,
+    stamp: u64,
+}
+
+#[derive(Component)]
+struct CupFlag {
+    mast: Entity, // pole + cloth, the part that leans
+    leaning_for: Option<f32>,
+}
+
+#[derive(Resource)]
+struct CupAssets {
+    pole: Handle<Mesh>,
+    cloth: Handle<Mesh>,
+    hole: Handle<Mesh>,
+    green: Handle<Mesh>,
+    pole_mat: Handle<StandardMaterial>,
+    cloth_mat: Handle<StandardMaterial>,
+    hole_mat: Handle<StandardMaterial>,
+    green_mat: Handle<StandardMaterial>,
+}
+
+impl FromWorld for CupAssets {
+    fn from_world(world: &mut World) -> Self {
+        let cfg = world.get_resource::<CupConfig>().cloned().unwrap_or_default();
+        let mut meshes = world.resource_mut::<Assets<Mesh>>();
+        let pole = meshes.add(Cylinder::new(0.06, cfg.flag_height));
+        let cloth = meshes.add(Cuboid::new(1.6, 1.0, 0.04));
+        let hole = meshes.add(Cylinder::new(cfg.cup_radius, 0.04));
+        let green = meshes.add(Cylinder::new(cfg.green_radius, 0.02));
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        Self {
+            pole,
+            cloth,
+            hole,
+            green,
+            pole_mat: materials.add(Color::srgb(0.92, 0.92, 0.92)),
+            cloth_mat: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.95, 0.1, 0.1),
+                emissive: LinearRgba::rgb(0.6, 0.02, 0.02),
+                double_sided: true,
+                cull_mode: None,
+                ..default()
+            }),
+            hole_mat: materials.add(StandardMaterial { base_color: Color::srgb(0.02, 0.02, 0.02), unlit: true, ..default() }),
+            green_mat: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.28, 0.62, 0.22),
+                perceptual_roughness: 0.9,
+                ..default()
+            }),
+        }
+    }
+}
+
+pub struct CupTargetPlugin;
+impl Plugin for CupTargetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CupConfig>()
+            .init_resource::<CupAssets>()
+            .add_systems(Update, (apply_hole_styles, sync_cups, animate_cup_flags).chain());
+    }
+}
+
+// Primary target: style for the current hole (restart included).
+fn apply_hole_styles(
+    level: Option<Res<LevelDef>>,
+    score: Option<Res<Score>>,
+    mut q: Query<(&TargetInfo, &mut TargetStyle), With<Target>>,
+    mut last_hole: Local<Option<u32>>,
+) {
+    let (Some(level), Some(score)) = (level, score) else { return; };
+    if *last_hole == Some(score.hits) && !level.is_changed() {
+        return;
+    }
+    *last_hole = Some(score.hits);
+    let style = level.hole_styles.get(score.hits as usize).copied().unwrap_or(level.target.style);
+    for (info, mut s) in &mut q {
+        if info.index == 0 && *s != style {
+            *s = style;
+        }
+    }
+}
+
+fn sync_cups(
+    mut commands: Commands,
+    cfg: Res<CupConfig>,
+    assets: Res<CupAssets>,
+    sampler: Option<Res<TerrainSampler>>,
+    params: Option<Res<TargetParams>>,
+    mut deform: ResMut<TerrainDeformations>,
+    mut q: Query<
+        (
+            Entity,
+            &TargetStyle,
+            Option<&CupState>,
+            &mut Transform,
+            &mut TargetFloat,
+            &mut Visibility,
+            Option<&TargetInfo>,
+            Option<&TargetMotion>,
+        ),
+        With<Target>,
+    >,
+    mut q_flags: Query<&mut CupFlag>,
+) {
+    let Some(sampler) = sampler else { return; };
+    for (entity, style, cup, mut t, mut float, mut vis, info, motion) in &mut q {
+        let mut lean_old = |flag: Entity| {
+            if let Ok(mut f) = q_flags.get_mut(flag) {
+                f.leaning_for.get_or_insert(0.0);
+            }
+        };
+        match (style, cup) {
+            (TargetStyle::Cup, _) => {
+                let pos = motion.map_or(t.translation.xz(), |m| m.anchor);
+                if cup.is_some_and(|c| c.pos.distance_squared(pos) < 0.25) {
+                    continue;
+                }
+                let ground = sampler.height(pos.x, pos.y);
+                let stamp = cfg.green_stamp(pos, ground);
+                let stamp = match cup {
+                    Some(old) => {
+                        lean_old(old.flag);
+                        deform.replace(old.stamp, stamp)
+                    }
+                    None => deform.add(stamp),
+                };
+                let flag = spawn_flag(&mut commands, &cfg, &assets, Vec3::new(pos.x, ground, pos.y));
+                commands.entity(entity).insert(CupState { pos, ground, flag, stamp });
+                t.translation = Vec3::new(pos.x, ground, pos.y);
+                *float = TargetFloat { ground, base_height: 0.0, amplitude: 0.0, rot_speed: 0.0, ..*float };
+                *vis = Visibility::Hidden;
+            }
+            (TargetStyle::Floating, Some(old)) => {
+                lean_old(old.flag);
+                deform.remove(old.stamp);
+                commands.entity(entity).remove::<CupState>();
+                *vis = Visibility::Inherited;
+                if let Some(p) = params.as_deref() {
+                    float.base_height = info.map_or(p.base_height, |i| i.base_height) + p.visual_offset;
+                    float.amplitude = p.amplitude;
+                    float.rot_speed = p.rot_speed;
+                    float.bounce_freq = p.bob_freq;
+                }
+            }
+            (TargetStyle::Floating, None) => {}
+        }
+    }
+}
+
+/// Remove a cup's flag and green (its target is going away with the level).
+pub(crate) fn clear_cup(commands: &mut Commands, deform: &mut TerrainDeformations, cup: &CupState) {
+    commands.entity(cup.flag).despawn_recursive();
+    deform.remove(cup.stamp);
+}
+
+fn spawn_flag(commands: &mut Commands, cfg: &CupConfig, assets: &CupAssets, base: Vec3) -> Entity {
+    let h = cfg.flag_height;
+    let mast = commands
+        .spawn(SpatialBundle::default())
+        .with_children(|p| {
+            p.spawn(PbrBundle {
+                mesh: assets.pole.clone(),
+                material: assets.pole_mat.clone(),
+                transform: Transform::from_xyz(0.0, h * 0.5, 0.0),
+                ..default()
+            });
+            p.spawn(PbrBundle {
+                mesh: assets.cloth.clone(),
+                material: assets.cloth_mat.clone(),
+                transform: Transform::from_xyz(0.8, h - 0.5, 0.0),
+                ..default()
+            });
+        })
+        .id();
+    commands
+        .spawn((
+            SpatialBundle::from_transform(Transform::from_translation(base)),
+            CupFlag { mast, leaning_for: None },
+            Name::new("CupFlag"),
+        ))
+        .with_children(|p| {
+            p.spawn(PbrBundle {
+                mesh: assets.green.clone(),
+                material: assets.green_mat.clone(),
+                transform: Transform::from_xyz(0.0, 0.02, 0.0),
+                ..default()
+            });
+            p.spawn(PbrBundle {
+                mesh: assets.hole.clone(),
+                material: assets.hole_mat.clone(),
+                transform: Transform::from_xyz(0.0, 0.04, 0.0),
+                ..default()
+            });
+        })
+        .add_child(mast)
+        .id()
+}
+
+// Captured cups: the flag tips over, then the whole cup is cleared.
+fn animate_cup_flags(
+    mut commands: Commands,
+    time: Res<Time>,
+    cfg: Res<CupConfig>,
+    mut q: Query<(Entity, &mut CupFlag)>,
+    mut q_mast: Query<&mut Transform>,
+) {
+    for (e, mut flag) in &mut q {
+        let Some(age) = flag.leaning_for.as_mut() else { continue; };
+        *age += time.delta_seconds();
+        if *age >= cfg.flag_linger {
+            commands.entity(e).despawn_recursive();
+            continue;
+        }
+        let k = (*age / cfg.lean_time.max(0.01)).min(1.0);
+        let eased = 1.0 - (1.0 - k) * (1.0 - k);
+        if let Ok(mut t) = q_mast.get_mut(flag.mast) {
+            t.rotation = Quat::from_rotation_z(-cfg.lean_angle_deg.to_radians() * eased);
+        }
+    }
+}
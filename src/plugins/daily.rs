@@ -0,0 +1,130 @@
+// Daily Run.
+// A manifest entry with `daily: true` plays a course generated from the date (UTC), so every
+// player gets the same layout on the same day: a seed derived from "YYYY-MM-DD" drives a seeded
+// RNG that places the tees and targets on dry ground of the entry's terrain, sets each hole's par
+// from its length and scripts a wind change per hole (level_script.rs). Times are kept under that
+// day's key ("daily-YYYY-MM-DD") so runs on the same layout compare directly.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::plugins::level::{BallPos, HoleDef, HoleTargetDef, LevelDef};
+use crate::plugins::level_script::{ScriptAction, ScriptRule, ScriptTrigger};
+use crate::plugins::level_validation::max_shot_range;
+use crate::plugins::terrain::TerrainSampler;
+
+const DAILY_HOLES: usize = 5;
+const HOLE_LENGTH: (f32, f32) = (80.0, 240.0); // m tee -> target
+const NEXT_TEE_OFFSET: (f32, f32) = (12.0, 30.0); // m from the previous target
+const MIN_GROUND: f32 = 50.0; // like level targets: well above the water
+const PLAY_AREA: f32 = 0.8; // fraction of the heightmap half-size holes stay within
+const WIND_STRENGTH: (f32, f32) = (0.2, 1.5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DailyDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl DailyDate {
+    /// Civil date of day `days` since 1970-01-01.
+    pub fn from_unix_days(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+
+    /// Today in UTC (everyone shares the layout whatever their time zone).
+    pub fn today() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        let secs = js_sys::Date::now() / 1000.0;
+        #[cfg(not(target_arch = "wasm32"))]
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0.0, |d| d.as_secs_f64());
+        Self::from_unix_days((secs / 86_400.0).floor() as i64)
+    }
+
+    /// High score / campaign key of this day's run.
+    pub fn key(&self) -> String {
+        format!("daily-{self}")
+    }
+
+    /// FNV-1a of the "YYYY-MM-DD" text: stable across platforms and builds.
+    pub fn seed(&self) -> u64 {
+        self.to_string().bytes().fold(0xcbf2_9ce4_8422_2325, |h, b| (h ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3))
+    }
+}
+
+impl std::fmt::Display for DailyDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Replace `def`'s course with the one for `seed` on `sampler`'s terrain (same seed and terrain,
+/// same holes). Level-placed gates, extra targets, decoys and props are dropped.
+pub fn generate_daily_course(def: &mut LevelDef, seed: u64, sampler: &TerrainSampler) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let half = sampler.cfg.heightmap_world_size * 0.5 * PLAY_AREA;
+    let dry = |p: (f32, f32)| p.0.abs() <= half && p.1.abs() <= half && sampler.height(p.0, p.1) >= MIN_GROUND;
+    let range = max_shot_range(&def.shot).max(1.0);
+
+    let first_tee = (0..200)
+        .map(|_| (rng.gen_range(-half * 0.5..half * 0.5), rng.gen_range(-half * 0.5..half * 0.5)))
+        .find(|&p| dry(p))
+        .unwrap_or((def.ball.pos.x, def.ball.pos.z));
+    let mut tee = first_tee;
+    let mut holes = Vec::with_capacity(DAILY_HOLES);
+    let mut scripts = Vec::with_capacity(DAILY_HOLES);
+    for n in 1..=DAILY_HOLES {
+        let Some((target, length)) = (0..100).find_map(|_| {
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            let length = rng.gen_range(HOLE_LENGTH.0..HOLE_LENGTH.1);
+            let p = (tee.0 + angle.cos() * length, tee.1 + angle.sin() * length);
+            dry(p).then_some((p, length))
+        }) else {
+            break;
+        };
+        holes.push(HoleDef {
+            tee: BallPos { x: tee.0, z: tee.1 },
+            target: HoleTargetDef { x: target.0, z: target.1, style: None },
+            par: (length / range).ceil() as u32 + 1,
+            props: Vec::new(),
+        });
+        let strength = rng.gen_range(WIND_STRENGTH.0..WIND_STRENGTH.1);
+        let heading_deg = rng.gen_range(0.0..360.0);
+        scripts.push(ScriptRule {
+            when: ScriptTrigger::HoleStart(n as u32),
+            then: vec![ScriptAction::SetWind { strength, heading_deg: Some(heading_deg) }],
+        });
+        tee = (0..50)
+            .map(|_| {
+                let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                let d = rng.gen_range(NEXT_TEE_OFFSET.0..NEXT_TEE_OFFSET.1);
+                (target.0 + angle.cos() * d, target.1 + angle.sin() * d)
+            })
+            .find(|&p| dry(p))
+            .unwrap_or(target);
+    }
+    if holes.is_empty() {
+        return;
+    }
+
+    def.gates.clear();
+    def.extra_targets.clear();
+    def.special_targets.decoys.clear();
+    def.props.clear();
+    def.hole_styles.clear();
+    def.holes = holes;
+    def.scripts.extend(scripts);
+    def.apply_course();
+}
@@ -0,0 +1,306 @@
+// Day / night cycle.
+// Levels opt in with `day_night` in their RON file: the hour the round starts at and how many
+// seconds of play a full 24 h day takes (0 keeps the clock still, for a fixed dusk or night
+// level). The clock runs while a round is played and starts over with each round. From the hour
+// the sun's direction, strength and color are worked out for the level's directional light
+// (the moon takes over, dim and blue, once the sun is down), and a tint darkens and warms the sky
+// dome, the clear color, the ambient light and the terrain material's brightness together. After
+// dark the focused target carries a glowing halo and a point light, the only way to find it on
+// a night hole. Levels without `day_night` keep the fixed midday lighting.
+
+use bevy::pbr::ExtendedMaterial;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::plugins::app_state::AppState;
+use crate::plugins::ball::Ball;
+use crate::plugins::game_state::RestartGameEvent;
+use crate::plugins::level::{LevelDef, SkyDome};
+use crate::plugins::target::{Target, TargetFocus};
+use crate::plugins::terrain_material::{RealTerrainExtension, RealTerrainUniform};
+
+/// Level-defined cycle (RON).
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct DayNightDef {
+    pub start_hour: f32, // 0..24, 12 = noon
+    pub day_length: f32, // s of play per 24 h; 0 = the clock stands still
+}
+impl Default for DayNightDef {
+    fn default() -> Self {
+        Self { start_hour: 12.0, day_length: 0.0 }
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct DayNightConfig {
+    pub sun_lux: f32,            // directional light at full day
+    pub moon_lux: f32,           // directional light at night
+    pub fixed_sun: Vec3,         // light position for levels without a cycle (looks at the origin)
+    pub clear_color: Color,      // daytime clear / ambient colors (main.rs); the tint scales them
+    pub ambient_color: Color,
+    pub dusk_tint: Vec3,         // sky / ambient multiplier with the sun on the horizon
+    pub night_tint: Vec3,        // ... and at night
+    pub sun_colors: (Vec3, Vec3, Vec3), // light color at noon, at the horizon, moonlight
+    pub night_terrain: f32,      // terrain brightness multiplier at night (1 by day)
+    pub glow_radius: f32,        // m, halo around the focused target at 100 m from the ball
+    pub glow_lumens: f32,        // point light at the target at full night
+}
+impl Default for DayNightConfig {
+    fn default() -> Self {
+        Self {
+            sun_lux: 40_000.0,
+            moon_lux: 2_500.0,
+            fixed_sun: Vec3::new(30.0, 60.0, 30.0),
+            clear_color: Color::srgb(0.52, 0.80, 0.92),
+            ambient_color: Color::srgb(0.55, 0.55, 0.60),
+            dusk_tint: Vec3::new(1.0, 0.62, 0.45),
+            night_tint: Vec3::new(0.10, 0.13, 0.26),
+            sun_colors: (Vec3::ONE, Vec3::new(1.0, 0.6, 0.35), Vec3::new(0.6, 0.7, 1.0)),
+            night_terrain: 0.45,
+            glow_radius: 3.0,
+            glow_lumens: 8_000_000.0,
+        }
+    }
+}
+
+/// Unit vector towards the sun: rises in the east (+X) at 6, highest at 12, sets at 18.
+pub fn sun_direction(hour: f32) -> Vec3 {
+    let angle = (hour - 6.0) / 24.0 * std::f32::consts::TAU;
+    Vec3::new(angle.cos(), angle.sin() * 0.9, 0.35).normalize()
+}
+
+/// 0 (night) .. 1 (full day) from the sun's height.
+pub fn daylight(hour: f32) -> f32 {
+    smoothstep(0.0, 0.3, sun_direction(hour).y)
+}
+
+/// Clock after `dt` seconds with a day of `day_length` seconds (0 = stopped), wrapped to 0..24.
+pub fn advance_hour(hour: f32, dt: f32, day_length: f32) -> f32 {
+    if day_length <= 0.0 {
+        return hour.rem_euclid(24.0);
+    }
+    (hour + dt * 24.0 / day_length).rem_euclid(24.0)
+}
+
+fn smoothstep(e0: f32, e1: f32, x: f32) -> f32 {
+    let t = ((x - e0) / (e1 - e0)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Lighting for one hour of the day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SkyLight {
+    pub light_dir: Vec3,   // unit vector towards the sun (or the moon at night)
+    pub illuminance: f32,  // lux
+    pub light_color: Vec3,
+    pub tint: Vec3,        // sky dome / clear color / ambient multiplier
+    pub daylight: f32,
+}
+
+impl DayNightConfig {
+    pub fn lighting(&self, hour: f32) -> SkyLight {
+        let sun = sun_direction(hour);
+        let day = daylight(hour);
+        let dusk = smoothstep(-0.15, 0.05, sun.y);
+        let (noon, horizon, moon) = self.sun_colors;
+        let tint = self.night_tint.lerp(self.dusk_tint, dusk).lerp(Vec3::ONE, day);
+        SkyLight {
+            light_dir: if sun.y >= 0.0 { sun } else { Vec3::new(-sun.x, -sun.y, sun.z) },
+            illuminance: self.moon_lux + (self.sun_lux - self.moon_lux) * day,
+            light_color: if sun.y >= 0.0 { horizon.lerp(noon, day) } else { moon },
+            tint,
+            daylight: day,
+        }
+    }
+
+    /// Lighting for the state's clock and weather.
+    pub fn current(&self, state: &DayNightState) -> SkyLight {
+        let light = match state.cycle {
+            Some(_) => self.lighting(state.hour),
+            None => self.fixed(),
+        };
+        let w = state.weather_light;
+        SkyLight { illuminance: light.illuminance * w, tint: light.tint * w, daylight: (light.daylight * w).min(1.0), ..light }
+    }
+
+    /// Lighting for levels without a cycle (the fixed setup main.rs and level.rs start with).
+    pub fn fixed(&self) -> SkyLight {
+        SkyLight {
+            light_dir: self.fixed_sun.normalize(),
+            illuminance: self.sun_lux,
+            light_color: Vec3::ONE,
+            tint: Vec3::ONE,
+            daylight: 1.0,
+        }
+    }
+}
+
+/// The running clock; `cycle` is the current level's `day_night` (None = fixed lighting).
+/// `weather_light` scales the result (storm clouds, lightning flashes; weather.rs).
+#[derive(Resource, Debug)]
+pub struct DayNightState {
+    pub cycle: Option<DayNightDef>,
+    pub hour: f32,
+    pub weather_light: f32,
+}
+impl Default for DayNightState {
+    fn default() -> Self {
+        Self { cycle: None, hour: 12.0, weather_light: 1.0 }
+    }
+}
+
+#[derive(Component)]
+struct NightGlow;
+
+#[derive(Resource)]
+struct NightGlowMaterial(Handle<StandardMaterial>);
+
+pub struct DayNightPlugin;
+impl Plugin for DayNightPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DayNightConfig>()
+            .init_resource::<DayNightState>()
+            .add_systems(Startup, spawn_night_glow)
+            .add_systems(
+                Update,
+                (
+                    start_level_clock,
+                    advance_clock.run_if(in_state(AppState::Playing)),
+                    apply_lighting,
+                    update_night_glow,
+                )
+                    .chain(),
+            );
+    }
+}
+
+fn spawn_night_glow(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>, mut materials: ResMut<Assets<StandardMaterial>>) {
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgba(1.0, 0.85, 0.4, 0.0),
+        alpha_mode: AlphaMode::Add,
+        unlit: true,
+        ..default()
+    });
+    commands.insert_resource(NightGlowMaterial(material.clone()));
+    commands
+        .spawn((
+            PbrBundle {
+                mesh: meshes.add(Sphere::new(1.0).mesh().ico(2).expect("icosphere subdivisions")),
+                material,
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            NightGlow,
+            Name::new("NightGlow"),
+        ))
+        .with_children(|glow| {
+            glow.spawn(PointLightBundle {
+                point_light: PointLight {
+                    color: Color::srgb(1.0, 0.85, 0.55),
+                    intensity: 0.0,
+                    range: 60.0,
+                    shadows_enabled: false,
+                    ..default()
+                },
+                ..default()
+            });
+        });
+}
+
+fn start_level_clock(
+    level: Option<Res<LevelDef>>,
+    mut state: ResMut<DayNightState>,
+    mut ev_restart: EventReader<RestartGameEvent>,
+) {
+    let Some(level) = level else { return; };
+    let restarted = ev_restart.read().count() > 0;
+    if !(level.is_changed() || restarted) {
+        return;
+    }
+    state.cycle = level.day_night;
+    state.hour = level.day_night.map_or(12.0, |c| c.start_hour.rem_euclid(24.0));
+}
+
+fn advance_clock(time: Res<Time>, mut state: ResMut<DayNightState>) {
+    let Some(cycle) = state.cycle else { return; };
+    if cycle.day_length > 0.0 {
+        state.hour = advance_hour(state.hour, time.delta_seconds(), cycle.day_length);
+    }
+}
+
+fn apply_lighting(
+    cfg: Res<DayNightConfig>,
+    state: Res<DayNightState>,
+    (mut clear, mut ambient): (ResMut<ClearColor>, ResMut<AmbientLight>),
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut terrain_mats: ResMut<Assets<ExtendedMaterial<StandardMaterial, RealTerrainExtension>>>,
+    mut q_lights: Query<(&mut DirectionalLight, &mut Transform)>,
+    q_sky: Query<&Handle<StandardMaterial>, With<SkyDome>>,
+    mut driven: Local<bool>,
+) {
+    // Levels without a cycle or weather get the fixed lighting back once, then are left alone.
+    // Otherwise it is applied every frame (terrain chunks create their material a while after
+    // the start).
+    let drive = state.cycle.is_some() || state.weather_light != 1.0;
+    if !drive && !std::mem::replace(&mut *driven, false) {
+        return;
+    }
+    *driven = drive;
+    let light = cfg.current(&state);
+    for (mut dir_light, mut t) in &mut q_lights {
+        dir_light.illuminance = light.illuminance;
+        dir_light.color = Color::srgb(light.light_color.x, light.light_color.y, light.light_color.z);
+        *t = Transform::from_translation(light.light_dir * 100.0).looking_at(Vec3::ZERO, Vec3::Y);
+    }
+    let tinted = |c: Color| {
+        let s = c.to_srgba();
+        Color::srgb(s.red * light.tint.x, s.green * light.tint.y, s.blue * light.tint.z)
+    };
+    clear.0 = tinted(cfg.clear_color);
+    ambient.color = tinted(cfg.ambient_color);
+    for handle in &q_sky {
+        if let Some(mat) = materials.get_mut(handle) {
+            mat.base_color = tinted(Color::WHITE);
+        }
+    }
+    let brightness = RealTerrainUniform::default().brightness * (cfg.night_terrain + (1.0 - cfg.night_terrain) * light.daylight);
+    for (_, mat) in terrain_mats.iter_mut() {
+        mat.extension.data.brightness = brightness;
+    }
+}
+
+fn update_night_glow(
+    cfg: Res<DayNightConfig>,
+    state: Res<DayNightState>,
+    focus: Res<TargetFocus>,
+    glow_mat: Option<Res<NightGlowMaterial>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    q_ball: Query<&Transform, (With<Ball>, Without<NightGlow>)>,
+    q_targets: Query<&Transform, (With<Target>, Without<NightGlow>, Without<Ball>)>,
+    mut q_glow: Query<(&mut Transform, &mut Visibility, &Children), With<NightGlow>>,
+    mut q_light: Query<&mut PointLight>,
+) {
+    let Ok((mut t, mut vis, children)) = q_glow.get_single_mut() else { return; };
+    let night = state.cycle.map_or(0.0, |_| 1.0 - cfg.lighting(state.hour).daylight);
+    let target_t = focus.entity.and_then(|e| q_targets.get(e).ok());
+    let (Some(target_t), Ok(ball_t), true) = (target_t, q_ball.get_single(), night > 0.05) else {
+        if *vis != Visibility::Hidden {
+            *vis = Visibility::Hidden;
+        }
+        return;
+    };
+    // Grows with distance so it stays visible from across the course.
+    let view_scale = (ball_t.translation.distance(target_t.translation) / 100.0).max(1.0);
+    t.translation = target_t.translation;
+    t.scale = Vec3::splat(cfg.glow_radius * view_scale);
+    *vis = Visibility::Visible;
+    if let Some(mat) = glow_mat.and_then(|m| materials.get_mut(&m.0)) {
+        mat.base_color.set_alpha(0.35 * night);
+    }
+    for &child in children {
+        if let Ok(mut light) = q_light.get_mut(child) {
+            light.intensity = cfg.glow_lumens * night;
+        }
+    }
+}
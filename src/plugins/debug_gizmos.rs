@@ -0,0 +1,180 @@
+// Debug gizmos (F5).
+// Line overlays drawn with Bevy gizmos for checking physics and gameplay in the running game:
+// the ball's velocity vector, the terrain normal under the ball, target collider spheres, the
+// borders of the loaded terrain chunks (draped over the ground) and the orbit camera's spring
+// targets (raw / smoothed follow point, desired camera position). Each layer is a bit in
+// `DebugGizmoLayers`; with the overlay on, keys 1-5 toggle the layers in that order. (F4 is the
+// level issue list.)
+
+use bevy::prelude::*;
+
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::camera::{CameraActual, CameraFollow};
+use crate::plugins::target::{Target, TargetInfo};
+use crate::plugins::terrain::{LoadedChunks, TerrainSampler};
+
+/// Seconds of travel the velocity arrow shows.
+const VELOCITY_ARROW_SECONDS: f32 = 0.25;
+const NORMAL_LENGTH: f32 = 3.0;
+/// Line segments per chunk edge (the border follows the ground).
+const BORDER_SEGMENTS: usize = 8;
+const BORDER_LIFT: f32 = 0.3;
+
+/// Which gizmo layers are drawn (bit set).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DebugGizmoLayers(pub u8);
+
+impl DebugGizmoLayers {
+    pub const BALL_VELOCITY: Self = Self(1 << 0);
+    pub const TERRAIN_NORMAL: Self = Self(1 << 1);
+    pub const TARGET_COLLIDERS: Self = Self(1 << 2);
+    pub const CHUNK_BORDERS: Self = Self(1 << 3);
+    pub const CAMERA_SPRINGS: Self = Self(1 << 4);
+    /// In key order (1-5).
+    pub const ALL: [Self; 5] =
+        [Self::BALL_VELOCITY, Self::TERRAIN_NORMAL, Self::TARGET_COLLIDERS, Self::CHUNK_BORDERS, Self::CAMERA_SPRINGS];
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub const fn all() -> Self {
+        Self(0b1_1111)
+    }
+
+    pub fn contains(self, layer: Self) -> bool {
+        self.0 & layer.0 == layer.0
+    }
+
+    pub fn toggle(&mut self, layer: Self) {
+        self.0 ^= layer.0;
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct DebugGizmos {
+    pub enabled: bool,
+    pub layers: DebugGizmoLayers,
+}
+
+impl Default for DebugGizmos {
+    fn default() -> Self {
+        Self { enabled: false, layers: DebugGizmoLayers::all() }
+    }
+}
+
+impl DebugGizmos {
+    pub fn shows(&self, layer: DebugGizmoLayers) -> bool {
+        self.enabled && self.layers.contains(layer)
+    }
+}
+
+/// Outline of chunk `coord` following the ground: a closed loop of `4 * segments + 1` points.
+pub fn chunk_border_points(coord: IVec2, chunk_size: f32, segments: usize, height: impl Fn(f32, f32) -> f32) -> Vec<Vec3> {
+    let min = coord.as_vec2() * chunk_size;
+    let corners = [min, min + Vec2::new(chunk_size, 0.0), min + Vec2::splat(chunk_size), min + Vec2::new(0.0, chunk_size)];
+    let segments = segments.max(1);
+    let mut points: Vec<Vec3> = (0..4)
+        .flat_map(|edge| {
+            let (a, b) = (corners[edge], corners[(edge + 1) % 4]);
+            (0..segments).map(move |i| a.lerp(b, i as f32 / segments as f32))
+        })
+        .map(|p| Vec3::new(p.x, height(p.x, p.y), p.y))
+        .collect();
+    points.push(points[0]);
+    points
+}
+
+pub struct DebugGizmosPlugin;
+impl Plugin for DebugGizmosPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugGizmos>().add_systems(
+            Update,
+            (
+                toggle_debug_gizmos,
+                (draw_ball_gizmos, draw_target_gizmos, draw_chunk_borders, draw_camera_springs)
+                    .run_if(|g: Res<DebugGizmos>| g.enabled),
+            )
+                .chain(),
+        );
+    }
+}
+
+fn toggle_debug_gizmos(keys: Res<ButtonInput<KeyCode>>, mut gizmos: ResMut<DebugGizmos>) {
+    if keys.just_pressed(KeyCode::F5) {
+        gizmos.enabled = !gizmos.enabled;
+    }
+    if !gizmos.enabled {
+        return;
+    }
+    let keys_1_to_5 = [KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4, KeyCode::Digit5];
+    for (key, layer) in keys_1_to_5.into_iter().zip(DebugGizmoLayers::ALL) {
+        if keys.just_pressed(key) {
+            gizmos.layers.toggle(layer);
+        }
+    }
+}
+
+fn draw_ball_gizmos(
+    cfg: Res<DebugGizmos>,
+    sampler: Option<Res<TerrainSampler>>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+    mut gizmos: Gizmos,
+) {
+    let Ok((t, kin)) = q_ball.get_single() else { return; };
+    let pos = t.translation;
+    if cfg.shows(DebugGizmoLayers::BALL_VELOCITY) && kin.vel.length_squared() > 1e-4 {
+        gizmos.arrow(pos, pos + kin.vel * VELOCITY_ARROW_SECONDS, Color::srgb(1.0, 0.9, 0.1));
+    }
+    if let (true, Some(sampler)) = (cfg.shows(DebugGizmoLayers::TERRAIN_NORMAL), sampler) {
+        let ground = Vec3::new(pos.x, sampler.height(pos.x, pos.z), pos.z);
+        gizmos.arrow(ground, ground + sampler.normal(pos.x, pos.z) * NORMAL_LENGTH, Color::srgb(0.2, 0.6, 1.0));
+    }
+}
+
+fn draw_target_gizmos(cfg: Res<DebugGizmos>, q_targets: Query<(&Transform, &TargetInfo), With<Target>>, mut gizmos: Gizmos) {
+    if !cfg.shows(DebugGizmoLayers::TARGET_COLLIDERS) {
+        return;
+    }
+    for (t, info) in &q_targets {
+        gizmos.sphere(t.translation, Quat::IDENTITY, info.collider_radius, Color::srgb(1.0, 0.3, 0.3));
+    }
+}
+
+fn draw_chunk_borders(
+    cfg: Res<DebugGizmos>,
+    sampler: Option<Res<TerrainSampler>>,
+    loaded: Option<Res<LoadedChunks>>,
+    mut gizmos: Gizmos,
+) {
+    let (true, Some(sampler), Some(loaded)) = (cfg.shows(DebugGizmoLayers::CHUNK_BORDERS), sampler, loaded) else {
+        return;
+    };
+    let size = sampler.cfg.chunk_size;
+    for coord in loaded.map.keys() {
+        let border = chunk_border_points(*coord, size, BORDER_SEGMENTS, |x, z| sampler.height(x, z) + BORDER_LIFT);
+        gizmos.linestrip(border, Color::srgba(0.9, 0.9, 0.9, 0.6));
+    }
+}
+
+fn draw_camera_springs(
+    cfg: Res<DebugGizmos>,
+    follow: Option<Res<CameraFollow>>,
+    actual: Option<Res<CameraActual>>,
+    mut gizmos: Gizmos,
+) {
+    if !cfg.shows(DebugGizmoLayers::CAMERA_SPRINGS) {
+        return;
+    }
+    if let Some(follow) = follow.filter(|f| f.initialized) {
+        gizmos.sphere(follow.target, Quat::IDENTITY, 0.3, Color::srgb(1.0, 0.5, 0.0));
+        gizmos.sphere(follow.actual, Quat::IDENTITY, 0.2, Color::srgb(0.2, 1.0, 0.2));
+        gizmos.line(follow.actual, follow.target, Color::srgb(1.0, 0.5, 0.0));
+        if let Some(actual) = actual.filter(|a| a.initialized) {
+            // Desired camera position and the smoothed one springing toward it.
+            gizmos.sphere(actual.target, Quat::IDENTITY, 0.5, Color::srgb(1.0, 0.0, 1.0));
+            gizmos.line(actual.actual, actual.target, Color::srgb(1.0, 0.0, 1.0));
+            gizmos.line(actual.target, follow.actual, Color::srgba(1.0, 0.0, 1.0, 0.4));
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// Per-run difficulty ramp.
+// `DifficultyCurve` maps the number of targets hit so far to a target collider scale, the
+// distance range the next target relocates to, and a speed multiplier for target motion (bob,
+// spin, flight paths). Levels set it with `difficulty` in their RON file; the default is flat
+// (no ramp) so older levels play as before.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+#[derive(Resource, Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct DifficultyCurve {
+    pub ramp_hits: u32,              // hits until the end values are reached
+    pub exponent: f32,               // easing of the ramp: 1 = linear, >1 = gentle start
+    pub collider_scale_end: f32,     // target collider radius multiplier at the end (start = 1)
+    pub distance_start: (f32, f32),  // relocation distance range (m) with no hits
+    pub distance_end: (f32, f32),
+    pub motion_speed_end: f32,       // target motion speed multiplier at the end (start = 1)
+}
+impl Default for DifficultyCurve {
+    fn default() -> Self {
+        Self {
+            ramp_hits: 5,
+            exponent: 1.0,
+            collider_scale_end: 1.0,
+            distance_start: (500.0, 800.0),
+            distance_end: (500.0, 800.0),
+            motion_speed_end: 1.0,
+        }
+    }
+}
+
+/// Difficulty values for one point of the run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Difficulty {
+    pub collider_scale: f32,
+    pub distance: (f32, f32),
+    pub motion_speed: f32,
+}
+
+impl DifficultyCurve {
+    /// Ramp progress (0..1) after `hits` targets.
+    pub fn progress(&self, hits: u32) -> f32 {
+        if self.ramp_hits == 0 {
+            return 1.0;
+        }
+        (hits as f32 / self.ramp_hits as f32).clamp(0.0, 1.0).powf(self.exponent.max(0.01))
+    }
+
+    pub fn at(&self, hits: u32) -> Difficulty {
+        let t = self.progress(hits);
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        let min = lerp(self.distance_start.0, self.distance_end.0).max(0.0);
+        let max = lerp(self.distance_start.1, self.distance_end.1).max(min + 1.0);
+        Difficulty {
+            collider_scale: lerp(1.0, self.collider_scale_end).max(0.05),
+            distance: (min, max),
+            motion_speed: lerp(1.0, self.motion_speed_end).max(0.0),
+        }
+    }
+}
@@ -0,0 +1,291 @@
+// Event log panel.
+// A bounded `EventLog` of recent notable events, each stamped with the run clock ("01:23.4"),
+// shown as a small chat-style list in the bottom-right corner (L or the header to collapse).
+// Gameplay events (shots, hits, penalties, gates, felled trees, records) are logged here; other
+// plugins push their own entries through `EventLog::push` (e.g. photo mode's saved photos).
+// Handy for streaming overlays and for reconstructing what just happened while debugging.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::plugins::core_sim::SimState;
+use crate::plugins::game_state::Score;
+use crate::plugins::hud_layout::HudAnchor;
+use crate::plugins::localization::Localization;
+use crate::plugins::app_state::AppState;
+use crate::plugins::particles::{GameOverEvent, GatePassedEvent, ShotFiredEvent, TargetHitEvent, TreeHitEvent};
+use crate::plugins::target::TargetKind;
+
+const VISIBLE_LINES: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogKind {
+    Info,
+    Shot,
+    Hit,
+    Penalty,
+    Record,
+}
+
+impl LogKind {
+    fn color(self) -> Color {
+        match self {
+            LogKind::Info => Color::srgb(0.80, 0.82, 0.88),
+            LogKind::Shot => Color::srgb(0.65, 0.85, 1.0),
+            LogKind::Hit => Color::srgb(0.55, 0.95, 0.55),
+            LogKind::Penalty => Color::srgb(1.0, 0.45, 0.35),
+            LogKind::Record => Color::srgb(1.0, 0.85, 0.3),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub time: f32, // run clock (s) when pushed
+    pub kind: LogKind,
+    pub text: String,
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct EventLog {
+    entries: VecDeque<LogEntry>,
+    pub capacity: usize,
+    pub now: f32, // run clock used to stamp new entries (kept current by EventLogPlugin)
+    pub collapsed: bool,
+}
+
+impl Default for EventLog {
+    fn default() -> Self {
+        Self { entries: VecDeque::new(), capacity: 50, now: 0.0, collapsed: false }
+    }
+}
+
+impl EventLog {
+    pub fn push(&mut self, kind: LogKind, text: impl Into<String>) {
+        self.entries.push_back(LogEntry { time: self.now, kind, text: text.into() });
+        while self.entries.len() > self.capacity {
+            self.entries.pop_front();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Up to `n` most recent entries, oldest first.
+    pub fn recent(&self, n: usize) -> impl Iterator<Item = &LogEntry> {
+        self.entries.iter().skip(self.entries.len().saturating_sub(n))
+    }
+}
+
+/// "mm:ss.s" timestamp.
+pub fn format_timestamp(secs: f32) -> String {
+    let secs = secs.max(0.0);
+    let minutes = (secs / 60.0).floor() as u32;
+    format!("{:02}:{:04.1}", minutes, secs - minutes as f32 * 60.0)
+}
+
+#[derive(Component)]
+struct EventLogRoot;
+#[derive(Component)]
+struct EventLogHeader;
+#[derive(Component)]
+struct EventLogLines;
+#[derive(Component)]
+struct EventLogLine(usize);
+
+pub struct EventLogPlugin;
+impl Plugin for EventLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EventLog>()
+            .add_systems(Startup, spawn_event_log)
+            .add_systems(Update, (log_gameplay_events, toggle_event_log, draw_event_log).chain());
+    }
+}
+
+fn spawn_event_log(mut commands: Commands, assets: Res<AssetServer>) {
+    let font = assets.load("fonts/FiraSans-Bold.ttf");
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(64.0),
+                    bottom: Val::Px(12.0),
+                    width: Val::Px(280.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(2.0),
+                    padding: UiRect::all(Val::Px(6.0)),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.4)),
+                border_radius: BorderRadius::all(Val::Px(6.0)),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            HudAnchor::bottom_right(64.0, 12.0),
+            EventLogRoot,
+            Name::new("EventLog"),
+        ))
+        .with_children(|root| {
+            root.spawn((ButtonBundle { background_color: BackgroundColor(Color::NONE), ..default() }, EventLogHeader))
+                .with_children(|b| {
+                    b.spawn(TextBundle::from_section(
+                        "",
+                        TextStyle { font: font.clone(), font_size: 14.0, color: Color::srgb(0.7, 0.75, 0.85) },
+                    ));
+                });
+            root.spawn((
+                NodeBundle { style: Style { flex_direction: FlexDirection::Column, ..default() }, ..default() },
+                EventLogLines,
+            ))
+            .with_children(|lines| {
+                for i in 0..VISIBLE_LINES {
+                    lines.spawn((
+                        TextBundle::from_sections([
+                            TextSection::new("", TextStyle { font: font.clone(), font_size: 13.0, color: Color::srgb(0.55, 0.6, 0.7) }),
+                            TextSection::new("", TextStyle { font: font.clone(), font_size: 14.0, color: Color::WHITE }),
+                        ]),
+                        EventLogLine(i),
+                    ));
+                }
+            });
+        });
+}
+
+fn log_gameplay_events(
+    sim: Res<SimState>,
+    score: Res<Score>,
+    loc: Res<Localization>,
+    phase: Option<Res<State<AppState>>>,
+    mut log: ResMut<EventLog>,
+    mut ev_shot: EventReader<ShotFiredEvent>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+    mut ev_gate: EventReader<GatePassedEvent>,
+    mut ev_tree: EventReader<TreeHitEvent>,
+    mut ev_game_over: EventReader<GameOverEvent>,
+    mut last_hits: Local<Option<u32>>,
+    mut run_shots: Local<u32>,
+) {
+    // The clock alone doesn't count as a change (the panel redraws on new entries).
+    log.bypass_change_detection().now =
+        if score.game_over { score.final_time } else { score.run_time(sim.elapsed_seconds) };
+    if phase.is_some_and(|p| !p.in_game()) {
+        *last_hits = None;
+        ev_shot.clear();
+        ev_hit.clear();
+        ev_gate.clear();
+        ev_tree.clear();
+        ev_game_over.clear();
+        return;
+    }
+    // A new run starts with an empty log.
+    if last_hits.is_none() || (score.hits == 0 && last_hits.is_some_and(|h| h > 0)) {
+        log.clear();
+        log.push(LogKind::Info, loc.t("log.run_started"));
+        *run_shots = 0;
+    }
+    *last_hits = Some(score.hits);
+
+    for e in ev_shot.read() {
+        *run_shots += 1;
+        let args = [("shot", run_shots.to_string()), ("pct", format!("{:.0}", e.power * 100.0))];
+        log.push(LogKind::Shot, loc.tf("log.shot", &args));
+    }
+    for e in ev_gate.read() {
+        log.push(LogKind::Info, loc.tf("announce.gate", &[("passed", e.passed.to_string()), ("total", e.total.to_string())]));
+    }
+    for e in ev_hit.read() {
+        let args = [
+            ("secs", format!("{:.1}", e.time_delta)),
+            ("points", e.points.to_string()),
+            ("combo", e.combo.to_string()),
+        ];
+        match e.kind {
+            TargetKind::Decoy => log.push(LogKind::Penalty, loc.tf("log.decoy", &args)),
+            TargetKind::Bonus => log.push(LogKind::Hit, loc.tf("log.golden", &args)),
+            TargetKind::Standard if e.combo >= 2 => log.push(LogKind::Hit, loc.tf("log.hit_combo", &args)),
+            TargetKind::Standard => log.push(LogKind::Hit, loc.tf("log.hit", &args)),
+        }
+    }
+    for e in ev_tree.read() {
+        if e.knocked_down {
+            log.push(LogKind::Info, loc.t("log.tree_felled"));
+        }
+    }
+    if ev_game_over.read().count() > 0 {
+        let rules = score.mode.rules();
+        let args = [("record", rules.format_record(&loc, rules.record(&score)))];
+        if score.is_new_record() {
+            log.push(LogKind::Record, loc.tf("log.record", &args));
+        } else {
+            log.push(LogKind::Info, loc.tf("log.finished", &args));
+        }
+    }
+}
+
+fn toggle_event_log(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut log: ResMut<EventLog>,
+    q_header: Query<&Interaction, (Changed<Interaction>, With<EventLogHeader>)>,
+) {
+    if keys.just_pressed(KeyCode::KeyL) || q_header.iter().any(|i| *i == Interaction::Pressed) {
+        log.collapsed = !log.collapsed;
+    }
+}
+
+fn draw_event_log(
+    log: Res<EventLog>,
+    loc: Res<Localization>,
+    phase: Option<Res<State<AppState>>>,
+    mut q_root: Query<&mut Visibility, With<EventLogRoot>>,
+    q_header: Query<&Children, With<EventLogHeader>>,
+    mut q_lines_root: Query<&mut Style, With<EventLogLines>>,
+    mut q_lines: Query<(&EventLogLine, &mut Text)>,
+    mut q_text: Query<&mut Text, Without<EventLogLine>>,
+) {
+    // Only shown during a run.
+    if let Ok(mut vis) = q_root.get_single_mut() {
+        let want = if phase.is_none_or(|p| p.in_game()) { Visibility::Inherited } else { Visibility::Hidden };
+        if *vis != want {
+            *vis = want;
+        }
+    }
+    if !(log.is_changed() || loc.is_changed()) {
+        return;
+    }
+    if let Ok(children) = q_header.get_single() {
+        for &child in children.iter() {
+            if let Ok(mut text) = q_text.get_mut(child) {
+                let arrow = if log.collapsed { "+" } else { "-" };
+                text.sections[0].value = format!("[{arrow}] {}", loc.tf("log.header", &[("count", log.len().to_string())]));
+            }
+        }
+    }
+    if let Ok(mut style) = q_lines_root.get_single_mut() {
+        style.display = if log.collapsed { Display::None } else { Display::Flex };
+    }
+    let recent: Vec<&LogEntry> = log.recent(VISIBLE_LINES).collect();
+    for (line, mut text) in &mut q_lines {
+        match recent.get(line.0) {
+            Some(entry) => {
+                text.sections[0].value = format!("{} ", format_timestamp(entry.time));
+                text.sections[1].value = entry.text.clone();
+                text.sections[1].style.color = entry.kind.color();
+            }
+            None => {
+                text.sections[0].value.clear();
+                text.sections[1].value.clear();
+            }
+        }
+    }
+}
@@ -1,10 +1,23 @@
 use bevy::prelude::*;
-use bevy::audio::{AudioSource, AudioBundle, PlaybackSettings, PlaybackMode, Volume};
+use bevy::audio::{AudioSource, AudioBundle, AudioSinkPlayback, PlaybackSettings, PlaybackMode, Volume};
+use crate::plugins::ball::Ball;
+use crate::plugins::biome::Biome;
+use crate::plugins::obstacles::ObstacleKind;
+use crate::plugins::target::TargetKind;
+use crate::plugins::terrain::TerrainSampler;
 use crate::plugins::particles::{
     BallGroundImpactEvent,
     TargetHitEvent,
+    BonusTargetSpawnedEvent,
+    GatePassedEvent,
+    ProximityBeepEvent,
     GameOverEvent,
     ShotFiredEvent,
+    TreeHitEvent,
+    ObstacleHitEvent,
+    ThunderEvent,
+    BirdsScatteredEvent,
+    HoleInOneEvent,
     BOUNCE_EFFECT_INTENSITY_MIN,
 };
 
@@ -22,7 +35,9 @@ struct SfxHandles {
 impl Plugin for GameAudioPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, load_audio_assets)
-           .add_systems(Update, (play_event_sfx, ensure_music_loop));
+           .init_resource::<AmbientBiomeState>()
+           .init_resource::<Fanfare>()
+           .add_systems(Update, (play_event_sfx, play_fanfare, ensure_music_loop, biome_ambient_mix));
     }
 }
 
@@ -54,6 +69,40 @@ fn load_audio_assets(mut commands: Commands, assets: Res<AssetServer>) {
 #[derive(Component)]
 struct MusicTag;
 
+// Current region for ambient mixing (music volume / speed follow the ball's biome).
+#[derive(Resource, Default)]
+struct AmbientBiomeState {
+    current: Option<Biome>,
+}
+
+fn biome_ambient_mix(
+    time: Res<Time>,
+    sampler: Option<Res<TerrainSampler>>,
+    mut state: ResMut<AmbientBiomeState>,
+    q_ball: Query<&Transform, With<Ball>>,
+    q_music: Query<&AudioSink, With<MusicTag>>,
+) {
+    let (Some(sampler), Ok(ball)) = (sampler, q_ball.get_single()) else { return; };
+    let biome = sampler.biome(ball.translation.x, ball.translation.z);
+    if state.current != Some(biome) {
+        info!("AMBIENT biome={}", biome.name());
+        state.current = Some(biome);
+    }
+    // Blend targets across neighbouring biomes, then ease toward them (~1.5 s crossfade).
+    let w = sampler.biome_weights(ball.translation.x, ball.translation.z);
+    let (mut vol, mut speed) = (0.0, 0.0);
+    for b in Biome::ALL {
+        let p = b.params();
+        vol += p.ambient_volume * w[b.index()];
+        speed += p.ambient_speed * w[b.index()];
+    }
+    let k = (time.delta_seconds() / 1.5).clamp(0.0, 1.0);
+    for sink in &q_music {
+        sink.set_volume(sink.volume() + (vol - sink.volume()) * k);
+        sink.set_speed(sink.speed() + (speed - sink.speed()) * k);
+    }
+}
+
 fn ensure_music_loop(
     mut commands: Commands,
     q_music: Query<(), With<MusicTag>>,
@@ -76,6 +125,53 @@ fn ensure_music_loop(
     }
 }
 
+// Hole-in-one fanfare: a rising arpeggio of the hit chime, then a chord (no dedicated sample yet).
+// Notes are (s until played, playback speed, volume).
+const FANFARE: [(f32, f32, f32); 7] = [
+    (0.0, 1.0, 0.8),
+    (0.14, 1.26, 0.8),
+    (0.28, 1.5, 0.8),
+    (0.42, 2.0, 0.9),
+    (0.7, 1.0, 0.7),
+    (0.7, 1.5, 0.7),
+    (0.7, 2.0, 0.9),
+];
+
+#[derive(Resource, Default)]
+struct Fanfare {
+    notes: Vec<(f32, f32, f32)>,
+}
+
+fn play_fanfare(
+    time: Res<Time>,
+    sfx: Option<Res<SfxHandles>>,
+    mut commands: Commands,
+    mut fanfare: ResMut<Fanfare>,
+    mut ev_hole_in_one: EventReader<HoleInOneEvent>,
+) {
+    if ev_hole_in_one.read().count() > 0 {
+        fanfare.notes = FANFARE.to_vec();
+    }
+    let Some(sfx) = sfx else { return; };
+    let dt = time.delta_seconds();
+    fanfare.notes.retain_mut(|(wait, speed, volume)| {
+        *wait -= dt;
+        if *wait > 0.0 {
+            return true;
+        }
+        commands.spawn(AudioBundle {
+            source: sfx.hit.clone(),
+            settings: PlaybackSettings {
+                mode: PlaybackMode::Despawn,
+                volume: Volume::new(*volume),
+                speed: *speed,
+                ..default()
+            }
+        });
+        false
+    });
+}
+
 fn play_event_sfx(
     sfx: Option<Res<SfxHandles>>,
     mut commands: Commands,
@@ -83,6 +179,13 @@ fn play_event_sfx(
     mut ev_hit: EventReader<TargetHitEvent>,
     mut ev_game_over: EventReader<GameOverEvent>,
     mut ev_shot: EventReader<ShotFiredEvent>,
+    mut ev_tree: EventReader<TreeHitEvent>,
+    mut ev_obstacle: EventReader<ObstacleHitEvent>,
+    mut ev_bonus: EventReader<BonusTargetSpawnedEvent>,
+    mut ev_gate: EventReader<GatePassedEvent>,
+    mut ev_beep: EventReader<ProximityBeepEvent>,
+    mut ev_thunder: EventReader<ThunderEvent>,
+    mut ev_birds: EventReader<BirdsScatteredEvent>,
 ) {
     let Some(sfx) = sfx else { return; };
 
@@ -102,16 +205,101 @@ fn play_event_sfx(
             }
         });
     }
-    for _ in ev_hit.read() {
+    // Decoys thud (slow), golden targets chime (fast); no dedicated samples yet.
+    for e in ev_hit.read() {
+        // Combo chains climb in pitch.
+        let speed = match e.kind {
+            TargetKind::Standard => 1.0 + 0.08 * e.combo.saturating_sub(1).min(6) as f32,
+            TargetKind::Decoy => 0.55,
+            TargetKind::Bonus => 1.6,
+        };
         commands.spawn(AudioBundle {
             source: sfx.hit.clone(),
             settings: PlaybackSettings {
                 mode: PlaybackMode::Despawn,
                 volume: Volume::new(0.9),
+                speed,
                 ..default()
             }
         });
     }
+    for _ in ev_bonus.read() {
+        commands.spawn(AudioBundle {
+            source: sfx.hit.clone(),
+            settings: PlaybackSettings {
+                mode: PlaybackMode::Despawn,
+                volume: Volume::new(0.5),
+                speed: 1.9,
+                ..default()
+            }
+        });
+    }
+    // Gate chime rises with each gate passed this hole.
+    for e in ev_gate.read() {
+        commands.spawn(AudioBundle {
+            source: sfx.hit.clone(),
+            settings: PlaybackSettings {
+                mode: PlaybackMode::Despawn,
+                volume: Volume::new(0.6),
+                speed: 1.2 + 0.15 * e.passed as f32,
+                ..default()
+            }
+        });
+    }
+    // Proximity tick: short, high and quiet; hotter = higher and louder.
+    for e in ev_beep.read() {
+        commands.spawn(AudioBundle {
+            source: sfx.hit.clone(),
+            settings: PlaybackSettings {
+                mode: PlaybackMode::Despawn,
+                volume: Volume::new(0.12 + 0.25 * e.heat),
+                speed: 2.2 + 0.8 * e.heat,
+                ..default()
+            }
+        });
+    }
+    // Obstacles: crates thump low, ice cracks high; breaking one is louder.
+    for e in ev_obstacle.read() {
+        let speed = match e.kind {
+            ObstacleKind::Crate => 0.7,
+            ObstacleKind::Ice => 1.8,
+        };
+        commands.spawn(AudioBundle {
+            source: sfx.bounce.clone(),
+            settings: PlaybackSettings {
+                mode: PlaybackMode::Despawn,
+                volume: Volume::new(if e.broken { 1.0 } else { 0.6 }),
+                speed: if e.broken { speed * 0.85 } else { speed },
+                ..default()
+            }
+        });
+    }
+    // Thunder: the bounce thud slowed right down into a rumble; farther strikes are lower and quieter.
+    for e in ev_thunder.read() {
+        commands.spawn(AudioBundle {
+            source: sfx.bounce.clone(),
+            settings: PlaybackSettings {
+                mode: PlaybackMode::Despawn,
+                volume: Volume::new(1.0 - 0.6 * e.distance),
+                speed: 0.32 - 0.12 * e.distance,
+                ..default()
+            }
+        });
+    }
+    // Squawks: a few quick, high chirps of the hit sample at slightly different pitches.
+    for _ in ev_birds.read() {
+        for speed in [2.8, 3.3, 2.5] {
+            commands.spawn(AudioBundle {
+                source: sfx.hit.clone(),
+                settings: PlaybackSettings {
+                    mode: PlaybackMode::Despawn,
+                    volume: Volume::new(0.3),
+                    speed,
+                    ..default()
+                }
+            });
+        }
+    }
     for _ in ev_game_over.read() {
         commands.spawn(AudioBundle {
             source: sfx.game_over.clone(),
@@ -133,4 +321,17 @@ fn play_event_sfx(
             }
         });
     }
+    // Tree crack: no dedicated sample yet, so the hit sound pitched down (slower playback).
+    for e in ev_tree.read() {
+        let v = (0.35 + e.speed * 0.06).clamp(0.35, 1.0);
+        commands.spawn(AudioBundle {
+            source: sfx.hit.clone(),
+            settings: PlaybackSettings {
+                mode: PlaybackMode::Despawn,
+                volume: Volume::new(v),
+                speed: if e.knocked_down { 0.45 } else { 0.6 },
+                ..default()
+            }
+        });
+    }
 }
@@ -0,0 +1,226 @@
+// Rule variants (game modes).
+// `GameMode` decides what a round is played for: Time Attack (the original game: lowest run
+// clock), Stroke Play (fewest strokes, the clock is only shown) and Hard Par (lowest run clock,
+// but a hole played over par loses the round). What a mode changes lives behind `GameRules` —
+// the value a finished round is ranked by, how it is shown, what the HUD leads with, when a
+// round is lost and what trick shots take off the clock — so scoring, HUD, menu and game-over
+// code ask the rules instead of matching on the mode, and a new mode is one more impl. The mode
+// is picked in the main menu (kept with the settings, see user_settings.rs), saved with an
+// unfinished round, and records are kept per level and mode.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::plugins::core_sim::SimState;
+use crate::plugins::event_log::{EventLog, LogKind};
+use crate::plugins::game_over::game_over_summary;
+use crate::plugins::game_state::Score;
+use crate::plugins::level::LevelDef;
+use crate::plugins::localization::Localization;
+use crate::plugins::app_state::{gameplay_running, AppState};
+use crate::plugins::profile::Profile;
+use crate::plugins::ui_toast::{ToastKind, ToastQueue};
+use crate::plugins::user_settings::UserSettings;
+
+/// Par of holes the level doesn't design (random target relocation).
+pub const DEFAULT_PAR: u32 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GameMode {
+    #[default]
+    TimeAttack,
+    StrokePlay,
+    HardPar,
+}
+
+impl GameMode {
+    pub const ALL: [GameMode; 3] = [GameMode::TimeAttack, GameMode::StrokePlay, GameMode::HardPar];
+
+    pub fn rules(self) -> &'static dyn GameRules {
+        match self {
+            Self::TimeAttack => &TimeAttackRules,
+            Self::StrokePlay => &StrokePlayRules,
+            Self::HardPar => &HardParRules,
+        }
+    }
+
+    /// Next (`step` 1) or previous (-1) mode, wrapping around.
+    pub fn cycle(self, step: i32) -> Self {
+        let n = Self::ALL.len() as i32;
+        let i = Self::ALL.iter().position(|m| *m == self).unwrap_or(0) as i32;
+        Self::ALL[(i + step).rem_euclid(n) as usize]
+    }
+
+    /// High score key of `level_id` in this mode.
+    pub fn record_key(self, level_id: &str) -> String {
+        match self.rules().record_suffix() {
+            Some(suffix) => format!("{level_id}@{suffix}"),
+            None => level_id.to_string(),
+        }
+    }
+}
+
+/// What a game mode decides. Records are "lower is better".
+pub trait GameRules: Sync {
+    fn label_key(&self) -> &'static str;
+
+    /// Appended to the level id for the high score key (None: the plain id, Time Attack's key
+    /// from before modes existed).
+    fn record_suffix(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The value a finished round is ranked by.
+    fn record(&self, score: &Score) -> f32 {
+        score.final_time
+    }
+
+    fn format_record(&self, _loc: &Localization, value: f32) -> String {
+        format!("{value:.2}s")
+    }
+
+    /// Whether taking `hole_strokes` on a hole of par `par` loses the round.
+    fn hole_failed(&self, _hole_strokes: u32, _par: u32) -> bool {
+        false
+    }
+
+    /// Share of a trick shot's time bonus (trick_shots.rs) taken off the run clock.
+    fn trick_bonus_scale(&self) -> f32 {
+        1.0
+    }
+
+    /// HUD line shown above the status line: the figure this mode is played for (None: the
+    /// status line's leading run clock is enough).
+    fn hud_focus(&self, _loc: &Localization, _score: &Score, _hole_strokes: u32, _par: u32) -> Option<String> {
+        None
+    }
+
+    /// Game-over panel summary of a finished round.
+    fn summary(&self, score: &Score) -> String {
+        game_over_summary(score.final_time, score.previous_best_time)
+    }
+}
+
+pub struct TimeAttackRules;
+impl GameRules for TimeAttackRules {
+    fn label_key(&self) -> &'static str {
+        "mode.time_attack"
+    }
+}
+
+pub struct StrokePlayRules;
+impl GameRules for StrokePlayRules {
+    fn label_key(&self) -> &'static str {
+        "mode.stroke_play"
+    }
+
+    fn record_suffix(&self) -> Option<&'static str> {
+        Some("strokes")
+    }
+
+    fn record(&self, score: &Score) -> f32 {
+        score.shots as f32
+    }
+
+    fn format_record(&self, loc: &Localization, value: f32) -> String {
+        loc.tf("mode.strokes", &[("count", format!("{value:.0}"))])
+    }
+
+    // The clock isn't played for: tricks are called out but earn no time.
+    fn trick_bonus_scale(&self) -> f32 {
+        0.0
+    }
+
+    fn hud_focus(&self, loc: &Localization, score: &Score, hole_strokes: u32, _par: u32) -> Option<String> {
+        Some(loc.tf("hud.mode.strokes", &[("total", score.shots.to_string()), ("hole", hole_strokes.to_string())]))
+    }
+
+    fn summary(&self, score: &Score) -> String {
+        stroke_summary(score.shots, score.previous_best_time)
+    }
+}
+
+pub struct HardParRules;
+impl GameRules for HardParRules {
+    fn label_key(&self) -> &'static str {
+        "mode.hard_par"
+    }
+
+    fn record_suffix(&self) -> Option<&'static str> {
+        Some("hardpar")
+    }
+
+    fn hole_failed(&self, hole_strokes: u32, par: u32) -> bool {
+        hole_strokes > par
+    }
+
+    fn hud_focus(&self, loc: &Localization, _score: &Score, hole_strokes: u32, par: u32) -> Option<String> {
+        let left = par.saturating_sub(hole_strokes);
+        Some(loc.tf("hud.mode.hard_par", &[("left", left.to_string()), ("par", par.to_string())]))
+    }
+}
+
+/// Stroke Play's game-over line, e.g. "Strokes: 12 | New best! (-3)".
+pub fn stroke_summary(strokes: u32, previous_best: Option<f32>) -> String {
+    let delta = match previous_best.map(|best| strokes as i64 - best.round() as i64) {
+        None => "First finish - new best!".to_string(),
+        Some(d) if d < 0 => format!("New best! ({d})"),
+        Some(d) => format!("+{d} vs best ({})", strokes as i64 - d),
+    };
+    format!("Strokes: {strokes} | {delta}")
+}
+
+/// Par of hole `hits + 1`: the course's, else DEFAULT_PAR.
+pub fn hole_par(level: Option<&LevelDef>, hits: u32) -> u32 {
+    level.and_then(|l| l.hole(hits as usize)).map_or(DEFAULT_PAR, |h| h.par)
+}
+
+pub struct GameModePlugin;
+impl Plugin for GameModePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (sync_game_mode, enforce_mode_rules.run_if(gameplay_running)).chain());
+    }
+}
+
+// The menu edits the setting; a round plays with the score's mode (a resumed round brings its
+// own, which the setting then follows).
+fn sync_game_mode(
+    phase: Option<Res<State<AppState>>>,
+    profile: Res<Profile>,
+    mut settings: ResMut<UserSettings>,
+    mut score: ResMut<Score>,
+) {
+    if phase.is_some_and(|p| p.in_game()) {
+        if settings.game_mode != score.mode {
+            settings.game_mode = score.mode;
+        }
+    } else if score.mode != settings.game_mode {
+        score.set_mode(settings.game_mode, &profile);
+    }
+}
+
+fn enforce_mode_rules(
+    sim: Res<SimState>,
+    level: Option<Res<LevelDef>>,
+    loc: Res<Localization>,
+    mut score: ResMut<Score>,
+    mut log: Option<ResMut<EventLog>>,
+    mut toasts: ResMut<ToastQueue>,
+) {
+    if score.game_over {
+        return;
+    }
+    let hole_strokes = score.shots.saturating_sub(score.shots_at_last_hit);
+    let par = hole_par(level.as_deref(), score.hits);
+    if !score.mode.rules().hole_failed(hole_strokes, par) {
+        return;
+    }
+    score.game_over = true;
+    score.failed = true;
+    score.final_time = score.run_time(sim.elapsed_seconds);
+    let text = loc.tf("mode.round_lost", &[("hole", (score.hits + 1).to_string()), ("par", par.to_string())]);
+    toasts.push_for(text.clone(), ToastKind::Warning, 3.0);
+    if let Some(log) = log.as_mut() {
+        log.push(LogKind::Penalty, text);
+    }
+}
@@ -0,0 +1,194 @@
+// Game-over screen.
+// Once the last hole is done, a panel shows the final time, how it compares with the best time
+// from before this run, the per-hole breakdown (`HoleHistory`) and two buttons:
+//   Restart   - same as R: score, ball and targets reset in place and play continues.
+//   Main Menu - the same reset, then back to `AppState::Menu` (main_menu.rs respawns its UI).
+// Hot-seat games (players.rs) add the players' standings under the summary, challenge rounds
+// (challenge.rs) the head-to-head records and a reply code.
+// The panel belongs to `AppState::GameOver`, entered from Playing when `Score::game_over` is set
+// and left again when a restart clears it.
+
+use bevy::prelude::*;
+
+use crate::plugins::challenge::ActiveChallenge;
+use crate::plugins::game_state::{RestartGameEvent, Score};
+use crate::plugins::localization::Localization;
+use crate::plugins::profile::Profile;
+use crate::plugins::app_state::AppState;
+use crate::plugins::main_menu::spawn_button;
+use crate::plugins::players::LocalPlayers;
+use crate::plugins::scoreboard::HoleHistory;
+
+/// Final time minus the previous best (negative = new record); `None` on a first finish.
+pub fn best_time_delta(final_time: f32, previous_best: Option<f32>) -> Option<f32> {
+    previous_best.map(|best| final_time - best)
+}
+
+/// Summary line under the title, e.g. "Time: 84.20s | New best! (-3.10s)".
+pub fn game_over_summary(final_time: f32, previous_best: Option<f32>) -> String {
+    let delta = match best_time_delta(final_time, previous_best) {
+        None => "First finish - new best!".to_string(),
+        Some(d) if d < 0.0 => format!("New best! ({d:.2}s)"),
+        Some(d) => format!("+{d:.2}s vs best ({:.2}s)", final_time - d),
+    };
+    format!("Time: {final_time:.2}s | {delta}")
+}
+
+#[derive(Component)]
+struct GameOverPanel;
+#[derive(Component)]
+struct GameOverSummary;
+#[derive(Component)]
+struct GameOverColumn(usize);
+#[derive(Component)]
+struct RestartButton;
+#[derive(Component)]
+struct MainMenuButton;
+
+pub struct GameOverPlugin;
+impl Plugin for GameOverPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_game_over_panel)
+            .add_systems(
+                Update,
+                (sync_game_over_state, game_over_buttons.run_if(in_state(AppState::GameOver)), update_game_over_panel)
+                    .chain(),
+            );
+    }
+}
+
+fn spawn_game_over_panel(mut commands: Commands, assets: Res<AssetServer>) {
+    let font = assets.load("fonts/FiraSans-Bold.ttf");
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(12.0),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(0.02, 0.02, 0.05, 0.6)),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            GameOverPanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Game Over",
+                TextStyle { font: font.clone(), font_size: 48.0, color: Color::srgb(0.95, 0.95, 1.0) },
+            ));
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle { font: font.clone(), font_size: 24.0, color: Color::srgb(0.90, 0.90, 0.95) },
+                ),
+                GameOverSummary,
+            ));
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(460.0),
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::SpaceBetween,
+                        padding: UiRect::all(Val::Px(14.0)),
+                        ..default()
+                    },
+                    background_color: BackgroundColor(Color::srgba(0.04, 0.05, 0.08, 0.88)),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for i in 0..5 {
+                        row.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle { font: font.clone(), font_size: 18.0, color: Color::srgb(0.85, 0.90, 1.0) },
+                            )
+                            .with_text_justify(if i == 0 { JustifyText::Left } else { JustifyText::Right }),
+                            GameOverColumn(i),
+                        ));
+                    }
+                });
+            spawn_button(parent, &font, "game_over.restart", Color::srgb(0.15, 0.55, 0.25), Some(RestartButton));
+            spawn_button(parent, &font, "game_over.main_menu", Color::srgb(0.25, 0.30, 0.45), Some(MainMenuButton));
+        });
+}
+
+/// Playing -> GameOver once the round ends, and back when it restarts (R or the Restart button,
+/// via `reset_game`). A transition already requested this frame (Main Menu) wins.
+fn sync_game_over_state(score: Res<Score>, phase: Res<State<AppState>>, mut next_state: ResMut<NextState<AppState>>) {
+    if matches!(*next_state, NextState::Pending(_)) {
+        return;
+    }
+    match *phase.get() {
+        AppState::Playing if score.game_over => next_state.set(AppState::GameOver),
+        AppState::GameOver if !score.game_over => next_state.set(AppState::Playing),
+        _ => {}
+    }
+}
+
+fn game_over_buttons(
+    mut next_state: ResMut<NextState<AppState>>,
+    mut buttons: ResMut<ButtonInput<MouseButton>>,
+    mut ev_restart: EventWriter<RestartGameEvent>,
+    q_buttons: Query<(&Interaction, Option<&RestartButton>, Option<&MainMenuButton>), (Changed<Interaction>, With<Button>)>,
+) {
+    for (interaction, restart, menu) in &q_buttons {
+        if *interaction != Interaction::Pressed || (restart.is_none() && menu.is_none()) {
+            continue;
+        }
+        // The click must not start charging a shot once the run is reset.
+        buttons.clear_just_pressed(MouseButton::Left);
+        ev_restart.send(RestartGameEvent);
+        if menu.is_some() {
+            next_state.set(AppState::Menu);
+        }
+    }
+}
+
+fn update_game_over_panel(
+    score: Res<Score>,
+    phase: Res<State<AppState>>,
+    history: Res<HoleHistory>,
+    players: Option<Res<LocalPlayers>>,
+    (challenge, profile, loc): (Option<Res<ActiveChallenge>>, Res<Profile>, Res<Localization>),
+    mut q_panel: Query<&mut Visibility, With<GameOverPanel>>,
+    mut q_summary: Query<&mut Text, (With<GameOverSummary>, Without<GameOverColumn>)>,
+    mut q_cols: Query<(&mut Text, &GameOverColumn), Without<GameOverSummary>>,
+) {
+    let show = *phase.get() == AppState::GameOver;
+    if let Ok(mut vis) = q_panel.get_single_mut() {
+        let want = if show { Visibility::Inherited } else { Visibility::Hidden };
+        if *vis != want {
+            *vis = want;
+        }
+    }
+    if !show || !(score.is_changed() || history.is_changed() || phase.is_changed()) {
+        return;
+    }
+    if let Ok(mut summary) = q_summary.get_single_mut() {
+        let result = if score.failed {
+            format!("Round lost on hole {} | Time: {:.2}s", score.hits + 1, score.final_time)
+        } else {
+            score.mode.rules().summary(&score)
+        };
+        let mut value = match players.as_deref().filter(|p| p.hot_seat()) {
+            Some(players) => format!("{result}\n{}", players.summary()),
+            None => format!("{result} | Best combo: {}", score.best_combo),
+        };
+        if let Some(line) = challenge.and_then(|c| c.result_line(&score, &profile, &loc)) {
+            value = format!("{value}\n{line}");
+        }
+        summary.sections[0].value = value;
+    }
+    let columns = history.table_columns();
+    for (mut text, col) in &mut q_cols {
+        if text.sections[0].value != columns[col.0] {
+            text.sections[0].value = columns[col.0].clone();
+        }
+    }
+}
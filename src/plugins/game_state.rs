@@ -1,15 +1,29 @@
 // Game state & scoring resources, shot charge logic, and reset handling.
+// A finished run restarts on R or on a `RestartGameEvent` (game-over panel buttons).
+// Chain-hit combos: consecutive targets hit with a single stroke each, before the combo timer
+// runs out, multiply the points of the hit (x2, x3, ... up to MAX_COMBO_MULTIPLIER).
+// Best records are kept per level (manifest id) and game mode in the player profile (profile.rs);
+// what a record is (run time, strokes) comes from the mode's rules (game_mode.rs).
+// Checkpoint tees: `HoleState` keeps the tee of the hole being played (where the ball was when
+// the previous target was hit). Water respawns put the ball back there, and Backspace returns it
+// there on demand for one penalty stroke.
 
 use bevy::prelude::*;
+use rand::Rng;
 use serde::Deserialize;
-use std::fs;
-use std::io::Write;
-use std::path::Path;
 
-use crate::plugins::core_sim::SimState;
+use crate::plugins::challenge::ActiveChallenge;
+use crate::plugins::core_sim::{GameSet, SimRng, SimState};
+use crate::plugins::particles::ShotFiredEvent;
+use crate::plugins::game_mode::GameMode;
 use crate::plugins::level::LevelDef;
-use crate::plugins::ball::{Ball, BallKinematic};
-use crate::plugins::target::{Target, TargetFloat, TargetParams};
+use crate::plugins::ball::{place_ball, Ball, BallKinematic};
+use crate::plugins::event_log::{EventLog, LogKind};
+use crate::plugins::localization::Localization;
+use crate::plugins::app_state::{gameplay_running, AppState};
+use crate::plugins::profile::Profile;
+use crate::plugins::save_game::RoundResumedEvent;
+use crate::plugins::target::{Target, TargetBob, TargetFloat, TargetInfo, TargetMotion, TargetParams};
 use crate::plugins::terrain::TerrainSampler;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -36,61 +50,185 @@ pub struct ShotConfig {
     pub osc_speed: f32,    // units per second (triangle wave edge speed)
     pub base_impulse: f32, // base launch velocity scale (multiplied by power scale)
     pub up_angle_deg: f32, // launch elevation angle
+    #[serde(default = "no_boost")]
+    pub power_boost: f32,  // launch speed multiplier (extra power powerup, powerups.rs)
+    #[serde(skip, default = "no_boost")]
+    pub vibe_boost: f32,   // launch speed multiplier of a double-power vibe stroke (vibe_meter.rs)
+    #[serde(skip)]
+    pub assist_power: Option<f32>, // charge fired whatever the gauge shows (vibe aim assist)
+}
+
+impl ShotConfig {
+    /// Launch speed multiplier of every boost in play.
+    pub fn launch_boost(&self) -> f32 {
+        self.power_boost * self.vibe_boost
+    }
+
+    /// Charge the shot goes out with for a gauge reading of `gauge` (0..1).
+    pub fn charge(&self, gauge: f32) -> f32 {
+        self.assist_power.unwrap_or(gauge)
+    }
+}
+
+fn no_boost() -> f32 {
+    1.0
 }
 impl Default for ShotConfig {
     fn default() -> Self {
-        Self { osc_speed: 1.6, base_impulse: 18.0, up_angle_deg: 45.0 }
+        Self { osc_speed: 1.6, base_impulse: 18.0, up_angle_deg: 45.0, power_boost: 1.0, vibe_boost: 1.0, assist_power: None }
     }
 }
 
 #[derive(Resource, Debug)]
 pub struct Score {
     pub hits: u32,
+    pub points: u32, // sum of TargetInfo::points over hits
     pub shots: u32,
     pub max_holes: u32,
     pub game_over: bool,
     pub final_time: f32,
-    pub high_score_time: Option<f32>, // lowest completion time
+    pub high_score_time: Option<f32>, // best record in this mode (lowest time / fewest strokes)
+    pub previous_best_time: Option<f32>, // best record before the latest finish (game-over delta)
+    pub time_adjustment: f32,         // decoy penalties minus bonus-target time (s)
+    pub combo: u32,                   // consecutive single-stroke hits (0 = no chain)
+    pub combo_timer: f32,             // s left to extend the chain
+    pub best_combo: u32,
+    pub shots_at_last_hit: u32,
+    pub level_id: String, // manifest id the high score is kept under
+    pub mode: GameMode,
+    pub failed: bool, // round lost under the mode's rules (game_over is set too)
+}
+
+/// Tee of the hole being played: the level's ball spawn (or a course's designed tee), then the
+/// ball's spot whenever a target is hit.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct HoleState {
+    pub tee: Vec2,            // x / z; the ball is put on the ground there
+    pub hole: u32,            // score.hits when the tee was recorded
+    pub penalty_strokes: u32, // return-to-tee penalties taken on this hole
+}
+
+impl HoleState {
+    pub fn start_run(&mut self, tee: Vec2) {
+        *self = Self { tee, ..default() };
+    }
+
+    pub fn checkpoint(&mut self, hole: u32, tee: Vec2) {
+        *self = Self { tee, hole, penalty_strokes: 0 };
+    }
+
+    /// Charge the return-to-tee penalty (one stroke on the run's shot count).
+    pub fn penalize_return(&mut self, score: &mut Score) {
+        self.penalty_strokes += 1;
+        score.shots += 1;
+    }
 }
+
+pub const RETURN_TO_TEE_KEY: KeyCode = KeyCode::Backspace;
+
+pub const COMBO_TIMEOUT: f32 = 60.0;
+pub const MAX_COMBO_MULTIPLIER: u32 = 5;
 impl Default for Score {
     fn default() -> Self {
         Self {
             hits: 0,
+            points: 0,
             shots: 0,
             max_holes: 1,
             game_over: false,
             final_time: 0.0,
-            high_score_time: load_high_score_time(),
+            high_score_time: None,
+            previous_best_time: None,
+            time_adjustment: 0.0,
+            combo: 0,
+            combo_timer: 0.0,
+            best_combo: 0,
+            shots_at_last_hit: 0,
+            level_id: DEFAULT_LEVEL_ID.to_string(),
+            mode: GameMode::default(),
+            failed: false,
         }
     }
 }
 
-fn high_score_file_path() -> &'static str { "high_score_time.txt" }
+impl Score {
+    /// High score key: the level id in this mode.
+    pub fn record_key(&self) -> String {
+        self.mode.record_key(&self.level_id)
+    }
+
+    /// Switch rule variant; the shown best record becomes that mode's.
+    pub fn set_mode(&mut self, mode: GameMode, profile: &Profile) {
+        self.mode = mode;
+        self.high_score_time = profile.best(&self.record_key());
+    }
+
+    /// Whether the finished round beat the record held before it.
+    pub fn is_new_record(&self) -> bool {
+        let record = self.mode.rules().record(self);
+        self.previous_best_time.is_none_or(|best| record < best)
+    }
 
-fn load_high_score_time() -> Option<f32> {
-    let path = Path::new(high_score_file_path());
-    if let Ok(data) = fs::read_to_string(path) {
-        if let Ok(v) = data.trim().parse::<f32>() {
-            return Some(v);
+    /// Run clock: simulated time plus decoy / bonus adjustments (never negative).
+    pub fn run_time(&self, elapsed_seconds: f32) -> f32 {
+        (elapsed_seconds + self.time_adjustment).max(0.0)
+    }
+
+    /// Strokes taken since the last standard target hit (the hole being played), penalties included.
+    pub fn hole_strokes(&self) -> u32 {
+        self.shots.saturating_sub(self.shots_at_last_hit)
+    }
+
+    /// Record a target hit for the combo chain; returns the points multiplier for it.
+    /// One stroke since the previous hit extends a live chain (or starts a new one), more resets it.
+    pub fn register_combo_hit(&mut self) -> u32 {
+        let strokes = self.hole_strokes();
+        self.shots_at_last_hit = self.shots;
+        if strokes <= 1 {
+            self.combo = if self.combo_timer > 0.0 { self.combo + 1 } else { 1 };
+            self.combo_timer = COMBO_TIMEOUT;
+            self.best_combo = self.best_combo.max(self.combo);
+        } else {
+            self.combo = 0;
+            self.combo_timer = 0.0;
         }
+        self.combo_multiplier()
     }
-    None
-}
 
-fn save_high_score_time(t: f32) {
-    if let Ok(mut f) = fs::File::create(high_score_file_path()) {
-        let _ = writeln!(f, "{t}");
+    pub fn combo_multiplier(&self) -> u32 {
+        self.combo.clamp(1, MAX_COMBO_MULTIPLIER)
+    }
+
+    /// Run the combo timer down; the chain breaks when it expires.
+    pub fn tick_combo(&mut self, dt: f32) {
+        if self.combo_timer > 0.0 {
+            self.combo_timer -= dt;
+            if self.combo_timer <= 0.0 {
+                self.combo_timer = 0.0;
+                self.combo = 0;
+            }
+        }
     }
 }
 
+/// Level id older single-time high score files are credited to.
+pub const DEFAULT_LEVEL_ID: &str = "level1";
+
+/// Reset score, ball and targets for a new run (same as R after game over).
+#[derive(Event)]
+pub struct RestartGameEvent;
+
 pub struct GameStatePlugin;
 impl Plugin for GameStatePlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(ShotState::default())
             .insert_resource(ShotConfig::default())
             .insert_resource(Score::default())
-            .add_systems(Update, update_shot_charge)
-            .add_systems(Update, reset_game.after(crate::plugins::target::detect_target_hits)); // run after hit detection
+            .init_resource::<HoleState>()
+            .add_event::<RestartGameEvent>()
+            .add_systems(Update, ((update_shot_charge, tick_combo_timer, return_to_tee).run_if(gameplay_running), count_shots))
+            .add_systems(Update, reset_game.in_set(GameSet::React)) // after this frame's input, before the camera
+            .add_systems(Update, record_checkpoint_tee.after(reset_game));
     }
 }
 
@@ -121,29 +259,62 @@ fn update_shot_charge(
     }
 }
 
+fn count_shots(mut ev_shot: EventReader<ShotFiredEvent>, mut score: ResMut<Score>) {
+    let fired = ev_shot.read().count() as u32;
+    if fired > 0 && !score.game_over {
+        score.shots += fired;
+    }
+}
+
+fn tick_combo_timer(time: Res<Time>, mut score: ResMut<Score>) {
+    if score.combo_timer > 0.0 && !score.game_over {
+        score.tick_combo(time.delta_seconds());
+    }
+}
+
 // Reset game when finished
-fn reset_game(
+pub(crate) fn reset_game(
     keys: Res<ButtonInput<KeyCode>>,
+    mut ev_restart: EventReader<RestartGameEvent>,
     mut sim: ResMut<SimState>,
+    mut rng: ResMut<SimRng>,
     mut score: ResMut<Score>,
+    mut hole: ResMut<HoleState>,
     mut q_ball: Query<(&mut Transform, &mut BallKinematic), With<Ball>>,
-    mut q_target: Query<(&mut Transform, &mut TargetFloat), (With<Target>, Without<Ball>)>,
+    mut q_target: Query<
+        (&mut Transform, &mut TargetFloat, Option<&TargetInfo>, Option<&mut TargetMotion>),
+        (With<Target>, Without<Ball>),
+    >,
     sampler: Res<TerrainSampler>,
     level: Option<Res<LevelDef>>,
     target_params: Option<Res<TargetParams>>,
+    challenge: Option<Res<ActiveChallenge>>,
 ) {
-    if !(score.game_over && keys.just_pressed(KeyCode::KeyR)) {
+    let requested = ev_restart.read().count() > 0;
+    if !(requested || score.game_over && keys.just_pressed(KeyCode::KeyR)) {
         return;
     }
     sim.tick = 0;
     sim.elapsed_seconds = 0.0;
+    // Challenge rounds replay the same random sequence (challenge.rs).
+    if let Some(seed) = challenge.and_then(|c| c.round_seed(&score.level_id)) {
+        *rng = SimRng::seeded(seed);
+    }
 
     let max_holes = level.as_ref().map(|l| l.scoring.max_holes).unwrap_or(score.max_holes);
     score.hits = 0;
+    score.points = 0;
     score.shots = 0;
     score.max_holes = max_holes;
     score.game_over = false;
+    score.failed = false;
     score.final_time = 0.0;
+    score.time_adjustment = 0.0;
+    score.combo = 0;
+    score.combo_timer = 0.0;
+    score.best_combo = 0;
+    score.shots_at_last_hit = 0;
+    hole.start_run(level.as_ref().map_or(Vec2::ZERO, |l| l.tee(0)));
 
     if let Ok((mut t, mut kin)) = q_ball.get_single_mut() {
         // Spawn position from level or defaults
@@ -161,33 +332,81 @@ fn reset_game(
         kin.vel = Vec3::ZERO;
     }
 
-    if let (Ok((mut tt, mut tf)), Some(level), Some(params)) = (q_target.get_single_mut(), level.as_ref(), target_params) {
-        let target_x = level.target.initial.x;
-        let target_z = level.target.initial.z;
-        let ground = sampler.height(target_x, target_z);
+    let (Some(level), Some(params)) = (level.as_ref(), target_params) else { return; };
+    for (mut tt, mut tf, info, motion) in &mut q_target {
+        let home = info.map_or(Vec2::new(level.target.initial.x, level.target.initial.z), |i| i.home);
+        let base_height = info.map_or(params.base_height, |i| i.base_height);
+        let ground = sampler.height(home.x, home.y);
         tf.ground = ground;
-        tf.phase = rand::random::<f32>() * std::f32::consts::TAU;
-        tf.base_height = params.base_height;
-        tf.amplitude = params.amplitude;
-        tf.bounce_freq = params.bob_freq;
-        tf.rot_speed = params.rot_speed;
-        tt.translation = Vec3::new(
-            target_x,
-            ground + params.base_height + params.amplitude * tf.phase.sin(),
-            target_z,
-        );
+        tf.phase = rng.0.gen::<f32>() * std::f32::consts::TAU;
+        tf.base_height = base_height;
+        info.map_or(TargetBob::from(&*params), |i| i.bob).apply(&mut tf);
+        tt.translation = Vec3::new(home.x, ground + base_height + tf.amplitude * tf.phase.sin(), home.y);
+        if let Some(mut motion) = motion {
+            motion.set_anchor(home, ground);
+        }
+    }
+}
+
+// New tee after each target hit (a course's next designed tee, else where the ball is now).
+fn record_checkpoint_tee(
+    score: Res<Score>,
+    level: Option<Res<LevelDef>>,
+    mut hole: ResMut<HoleState>,
+    q_ball: Query<&Transform, With<Ball>>,
+    mut ev_resumed: EventReader<RoundResumedEvent>,
+    mut last_hits: Local<Option<u32>>,
+) {
+    // A resumed round brings its own tee.
+    if ev_resumed.read().count() > 0 {
+        *last_hits = Some(score.hits);
+        return;
+    }
+    if *last_hits == Some(score.hits) {
+        return;
+    }
+    match *last_hits {
+        // First run of the session (later runs are set up by reset_game).
+        None => hole.start_run(level.as_ref().map_or(Vec2::ZERO, |l| l.tee(0))),
+        Some(last) if score.hits > last => {
+            let tee = match (level.as_ref(), q_ball.get_single()) {
+                (Some(level), _) if level.is_course() => level.tee(score.hits as usize),
+                (_, Ok(t)) => t.translation.xz(),
+                _ => hole.tee,
+            };
+            hole.checkpoint(score.hits, tee);
+        }
+        Some(_) => {}
+    }
+    *last_hits = Some(score.hits);
+}
+
+fn return_to_tee(
+    keys: Res<ButtonInput<KeyCode>>,
+    phase: Option<Res<State<AppState>>>,
+    loc: Res<Localization>,
+    sampler: Res<TerrainSampler>,
+    mut score: ResMut<Score>,
+    mut hole: ResMut<HoleState>,
+    mut log: ResMut<EventLog>,
+    mut q_ball: Query<(&mut Transform, &mut BallKinematic), With<Ball>>,
+) {
+    if !keys.just_pressed(RETURN_TO_TEE_KEY) || score.game_over || phase.is_some_and(|p| *p.get() != AppState::Playing) {
+        return;
     }
+    let Ok((mut t, mut kin)) = q_ball.get_single_mut() else { return; };
+    place_ball(&mut t, &mut kin, &sampler, hole.tee);
+    hole.penalize_return(&mut score);
+    log.push(LogKind::Penalty, loc.t("log.return_to_tee"));
 }
 
 // Public utility for updating high score when finishing game
-pub fn update_high_score(score: &mut Score) {
-    let better = match score.high_score_time {
-        Some(best) => score.final_time < best,
-        None => true,
-    };
-    if better {
-        score.high_score_time = Some(score.final_time);
-        save_high_score_time(score.final_time);
+pub fn update_high_score(score: &mut Score, profile: &mut Profile) {
+    score.previous_best_time = score.high_score_time;
+    if score.is_new_record() {
+        let record = score.mode.rules().record(score);
+        score.high_score_time = Some(record);
+        profile.record_best(&score.record_key(), record);
     }
 }
 
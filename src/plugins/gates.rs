@@ -0,0 +1,244 @@
+// Ring gates: checkpoints the ball has to fly through before the target counts.
+// Levels list them under `gates`; each is a vertical ring (torus) at a height above the ground,
+// turned by `yaw_deg`. A pass is detected by the ball's path crossing the ring's plane inside the
+// opening (either direction), so fast shots cannot tunnel through. The ring tube itself is solid:
+// the ball bounces off it. Progress resets whenever a hole is completed (and on restart), and
+// while gates are outstanding standard targets ignore the ball.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::core_sim::GameSet;
+use crate::plugins::game_state::Score;
+use crate::plugins::level::LevelDef;
+use crate::plugins::app_state::gameplay_running;
+use crate::plugins::particles::GatePassedEvent;
+use crate::plugins::terrain::TerrainSampler;
+
+const TUBE_RADIUS: f32 = 0.35;
+const TUBE_RESTITUTION: f32 = 0.5;
+const MAX_STEP: f32 = 50.0; // longer per-tick moves are teleports (respawn), not flight
+
+fn default_gate_height() -> f32 { 8.0 }
+fn default_gate_radius() -> f32 { 4.0 }
+
+/// Level-defined gate (RON).
+#[derive(Debug, Deserialize, Clone)]
+pub struct GateDef {
+    pub pos: (f32, f32), // XZ
+    #[serde(default = "default_gate_height")]
+    pub height: f32,     // ring centre above the ground (m)
+    #[serde(default = "default_gate_radius")]
+    pub radius: f32,     // opening radius (m)
+    #[serde(default)]
+    pub yaw_deg: f32,    // 0 = ring faces +Z
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Gate {
+    pub index: usize,
+    pub center: Vec3,
+    pub normal: Vec3,
+    pub radius: f32, // ring centre line radius
+}
+
+/// Which gates have been passed on the current hole.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct GateProgress {
+    pub passed: Vec<bool>,
+}
+
+impl GateProgress {
+    pub fn new(count: usize) -> Self {
+        Self { passed: vec![false; count] }
+    }
+
+    pub fn total(&self) -> usize {
+        self.passed.len()
+    }
+
+    pub fn count(&self) -> usize {
+        self.passed.iter().filter(|p| **p).count()
+    }
+
+    /// True when every gate is passed (or the level has none).
+    pub fn complete(&self) -> bool {
+        self.passed.iter().all(|p| *p)
+    }
+
+    /// Mark a gate passed; returns true the first time.
+    pub fn pass(&mut self, index: usize) -> bool {
+        match self.passed.get_mut(index) {
+            Some(p) if !*p => {
+                *p = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.passed.iter_mut().for_each(|p| *p = false);
+    }
+}
+
+/// Does the move `prev -> cur` cross the ring plane within `radius` of its centre?
+pub fn segment_crosses_gate(prev: Vec3, cur: Vec3, center: Vec3, normal: Vec3, radius: f32) -> bool {
+    let d0 = (prev - center).dot(normal);
+    let d1 = (cur - center).dot(normal);
+    if (d0 > 0.0) == (d1 > 0.0) {
+        return false;
+    }
+    let p = prev + (cur - prev) * (d0 / (d0 - d1));
+    p.distance_squared(center) <= radius * radius
+}
+
+/// Contact between a sphere and the ring tube: (push-out normal, penetration depth).
+pub fn torus_contact(p: Vec3, sphere_radius: f32, center: Vec3, normal: Vec3, major: f32, minor: f32) -> Option<(Vec3, f32)> {
+    let v = p - center;
+    let in_plane = v - normal * v.dot(normal);
+    if in_plane.length_squared() < 1e-6 {
+        return None; // dead centre of the opening
+    }
+    let d = p - (center + in_plane.normalize() * major);
+    let dist = d.length();
+    let reach = minor + sphere_radius;
+    (dist < reach && dist > 1e-6).then(|| (d / dist, reach - dist))
+}
+
+#[derive(Resource)]
+struct GateMaterials {
+    mesh_cache: Vec<(f32, Handle<Mesh>)>,
+    pending: Handle<StandardMaterial>,
+    passed: Handle<StandardMaterial>,
+}
+
+impl FromWorld for GateMaterials {
+    fn from_world(world: &mut World) -> Self {
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let pending = materials.add(StandardMaterial {
+            base_color: Color::srgb(0.2, 0.85, 1.0),
+            emissive: LinearRgba::rgb(0.3, 1.4, 2.0),
+            ..default()
+        });
+        let passed = materials.add(StandardMaterial {
+            base_color: Color::srgb(0.3, 1.0, 0.35),
+            emissive: LinearRgba::rgb(0.4, 2.0, 0.5),
+            ..default()
+        });
+        Self { mesh_cache: Vec::new(), pending, passed }
+    }
+}
+
+pub struct GatePlugin;
+impl Plugin for GatePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GateProgress>()
+            .init_resource::<GateMaterials>()
+            .add_systems(Update, (spawn_gates.run_if(resource_exists_and_changed::<LevelDef>), reset_gates_on_new_hole))
+            .add_systems(FixedUpdate, ball_vs_gates.in_set(GameSet::React).run_if(gameplay_running))
+            .add_systems(Update, recolor_gates);
+    }
+}
+
+fn spawn_gates(
+    mut commands: Commands,
+    level: Res<LevelDef>,
+    sampler: Res<TerrainSampler>,
+    mut gate_mats: ResMut<GateMaterials>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut progress: ResMut<GateProgress>,
+    q_old: Query<Entity, With<Gate>>,
+) {
+    for e in &q_old {
+        commands.entity(e).despawn_recursive();
+    }
+    *progress = GateProgress::new(level.gates.len());
+    for (index, def) in level.gates.iter().enumerate() {
+        let (x, z) = def.pos;
+        let center = Vec3::new(x, sampler.height(x, z) + def.height, z);
+        // Torus axis is +Y; tip it onto +Z, then yaw.
+        let rotation = Quat::from_rotation_y(def.yaw_deg.to_radians()) * Quat::from_rotation_x(std::f32::consts::FRAC_PI_2);
+        let major = def.radius + TUBE_RADIUS;
+        let mesh = match gate_mats.mesh_cache.iter().find(|(r, _)| *r == def.radius) {
+            Some((_, m)) => m.clone(),
+            None => {
+                let m = meshes.add(Torus::new(def.radius, def.radius + 2.0 * TUBE_RADIUS));
+                gate_mats.mesh_cache.push((def.radius, m.clone()));
+                m
+            }
+        };
+        commands.spawn((
+            PbrBundle {
+                mesh,
+                material: gate_mats.pending.clone(),
+                transform: Transform::from_translation(center).with_rotation(rotation),
+                ..default()
+            },
+            Gate { index, center, normal: rotation * Vec3::Y, radius: major },
+            Name::new(format!("Gate{index}")),
+        ));
+    }
+}
+
+// Pass detection + tube collision, right after the ball integrates.
+fn ball_vs_gates(
+    mut progress: ResMut<GateProgress>,
+    mut q_ball: Query<(&mut Transform, &mut BallKinematic), With<Ball>>,
+    q_gates: Query<&Gate>,
+    mut prev: Local<Option<Vec3>>,
+    mut ev_passed: EventWriter<GatePassedEvent>,
+) {
+    let Ok((mut t, mut kin)) = q_ball.get_single_mut() else { return; };
+    let cur = t.translation;
+    let last = *prev;
+    if q_gates.is_empty() {
+        return;
+    }
+    if let Some(last) = last.filter(|p| p.distance_squared(cur) < MAX_STEP * MAX_STEP) {
+        for gate in &q_gates {
+            if segment_crosses_gate(last, cur, gate.center, gate.normal, gate.radius) && progress.pass(gate.index) {
+                ev_passed.send(GatePassedEvent {
+                    index: gate.index,
+                    pos: gate.center,
+                    passed: progress.count(),
+                    total: progress.total(),
+                });
+            }
+        }
+    }
+    for gate in &q_gates {
+        if let Some((n, depth)) = torus_contact(t.translation, kin.collider_radius, gate.center, gate.normal, gate.radius, TUBE_RADIUS) {
+            t.translation += n * depth;
+            let vn = kin.vel.dot(n);
+            if vn < 0.0 {
+                kin.vel -= n * vn * (1.0 + TUBE_RESTITUTION);
+            }
+        }
+    }
+    *prev = Some(t.translation);
+}
+
+// New hole (or restart): every gate has to be flown through again.
+fn reset_gates_on_new_hole(score: Option<Res<Score>>, mut progress: ResMut<GateProgress>, mut last_hits: Local<u32>) {
+    let Some(score) = score else { return; };
+    if score.hits != *last_hits {
+        *last_hits = score.hits;
+        progress.reset();
+    }
+}
+
+fn recolor_gates(
+    progress: Res<GateProgress>,
+    gate_mats: Res<GateMaterials>,
+    mut q: Query<(&Gate, &mut Handle<StandardMaterial>)>,
+) {
+    if !progress.is_changed() {
+        return;
+    }
+    for (gate, mut mat) in &mut q {
+        let passed = progress.passed.get(gate.index).copied().unwrap_or(false);
+        *mat = if passed { gate_mats.passed.clone() } else { gate_mats.pending.clone() };
+    }
+}
@@ -0,0 +1,315 @@
+// Ghost racing against someone else's run.
+// A `GhostTrack` is the small interchange form of a finished round: level, mode, player, final
+// time, the time each hole was finished and the ball's path (ten samples per second). Exporting a
+// replay (X in the replay viewer) writes one next to the replay file as `<name>.ghost.ron`. A
+// friend's track comes in by dropping the file onto the menu window (a full replay file works
+// too) or with `--ghost <path or URL>` (URLs are fetched in the background; native builds only).
+// While a round of the track's level is played, a translucent ghost ball follows the recorded
+// path on the run clock and the HUD shows how far ahead or behind you are: the difference at the
+// last finished hole, or, once the ghost has finished the hole you are still on, at least the
+// time since it did.
+
+use bevy::prelude::*;
+use bevy::tasks::Task;
+use futures_lite::future::{block_on, poll_once};
+use serde::{Deserialize, Serialize};
+
+use crate::launch_options::LaunchOptions;
+use crate::plugins::app_state::AppState;
+use crate::plugins::core_sim::{GameSet, SimState};
+use crate::plugins::game_mode::GameMode;
+use crate::plugins::game_state::Score;
+use crate::plugins::localization::Localization;
+use crate::plugins::particles::TargetHitEvent;
+use crate::plugins::replay::{Replay, SNAP_DISTANCE};
+use crate::plugins::target::TargetKind;
+use crate::plugins::ui_toast::{ToastKind, ToastQueue};
+
+pub const GHOST_VERSION: u32 = 1;
+pub const GHOST_EXTENSION: &str = ".ghost.ron";
+const GHOST_RADIUS: f32 = 0.5;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GhostTrack {
+    pub version: u32,
+    pub level: String,    // manifest id
+    pub score_id: String, // the level's record key (the day, for the Daily Run)
+    #[serde(default)]
+    pub mode: GameMode,
+    #[serde(default)]
+    pub player: String,
+    pub final_time: f32,
+    pub hole_times: Vec<f32>,          // s into the run each hole was finished
+    pub path: Vec<(f32, f32, f32, f32)>, // (s, x, y, z) ball samples
+}
+
+impl GhostTrack {
+    pub fn from_replay(replay: &Replay, player: &str) -> Self {
+        let start = replay.frames.first().map_or(0, |f| f.tick);
+        let time_of = |tick: u64| tick.saturating_sub(start) as f32 / 60.0;
+        Self {
+            version: GHOST_VERSION,
+            level: replay.level.clone(),
+            score_id: replay.score_id.clone(),
+            mode: replay.mode,
+            player: player.to_string(),
+            final_time: replay.final_time,
+            hole_times: replay.hole_ticks.iter().map(|t| time_of(*t)).collect(),
+            path: replay.frames.iter().map(|f| (time_of(f.tick), f.ball.0, f.ball.1, f.ball.2)).collect(),
+        }
+    }
+
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()).unwrap_or_default()
+    }
+
+    /// A ghost track, or a full replay file turned into one; unnamed runs are named after
+    /// `fallback_player`.
+    pub fn parse(data: &str, fallback_player: &str) -> Option<Self> {
+        let mut track = ron::from_str::<Self>(data)
+            .ok()
+            .or_else(|| Replay::from_ron(data).ok().map(|r| Self::from_replay(&r, "")))
+            .filter(|g| !g.path.is_empty())?;
+        if track.player.is_empty() {
+            track.player = fallback_player.to_string();
+        }
+        Some(track)
+    }
+
+    /// Ghost ball `t` seconds into the run (interpolated, except across cuts; held at the end).
+    pub fn position_at(&self, t: f32) -> Option<Vec3> {
+        let next = self.path.partition_point(|p| p.0 <= t);
+        let a = self.path.get(next.saturating_sub(1))?;
+        let b = self.path.get(next).unwrap_or(a);
+        let (pa, pb) = (Vec3::new(a.1, a.2, a.3), Vec3::new(b.1, b.2, b.3));
+        if b.0 <= a.0 || pa.distance(pb) > SNAP_DISTANCE {
+            return Some(pa);
+        }
+        Some(pa.lerp(pb, ((t - a.0) / (b.0 - a.0)).clamp(0.0, 1.0)))
+    }
+
+    /// Your lead (negative) or deficit (positive) in seconds, from your hole `splits` so far and
+    /// the run clock; `None` until there is something to compare.
+    pub fn delta(&self, splits: &[f32], elapsed: f32) -> Option<f32> {
+        let done = splits.len();
+        let at_split = done.checked_sub(1).and_then(|i| Some(splits[i] - self.hole_times.get(i)?));
+        // The ghost already finished the hole you are on: you are at least this far behind.
+        let overdue = self.hole_times.get(done).map(|g| elapsed - g).filter(|d| *d > 0.0);
+        match (at_split, overdue) {
+            (Some(s), Some(o)) => Some(s.max(o)),
+            (s, o) => s.or(o),
+        }
+    }
+}
+
+/// `+1.25s` behind / `-0.80s` ahead.
+pub fn format_delta(delta: f32) -> String {
+    if delta < 0.0 { format!("-{:.2}s", -delta) } else { format!("+{delta:.2}s") }
+}
+
+/// The ghost being raced and your hole splits against it.
+#[derive(Resource, Default)]
+pub struct GhostRace {
+    pub track: Option<GhostTrack>,
+    pub splits: Vec<f32>, // run clock at each finished hole of this run
+    download: Option<Task<Result<String, String>>>,
+}
+
+impl GhostRace {
+    /// Whether the ghost races on the level being played (same record key).
+    pub fn races_on(&self, score_id: &str) -> bool {
+        self.track.as_ref().is_some_and(|t| t.score_id == score_id)
+    }
+}
+
+#[derive(Component)]
+struct GhostBall;
+#[derive(Component)]
+struct GhostDeltaText;
+
+pub struct GhostRacePlugin;
+impl Plugin for GhostRacePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GhostRace>()
+            .add_systems(Startup, (spawn_ghost, load_launch_ghost))
+            .add_systems(Update, (finish_ghost_download, import_dropped_ghost))
+            .add_systems(Update, record_splits.in_set(GameSet::React))
+            .add_systems(Update, (update_ghost_ball, update_ghost_delta).in_set(GameSet::Present));
+    }
+}
+
+fn load_launch_ghost(options: Option<Res<LaunchOptions>>, mut race: ResMut<GhostRace>) {
+    if let Some(source) = options.and_then(|o| o.ghost.clone()) {
+        load_ghost_source(&mut race, &source);
+    }
+}
+
+fn load_ghost_source(race: &mut GhostRace, source: &str) {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let url = source.to_string();
+            race.download = Some(bevy::tasks::IoTaskPool::get().spawn(async move {
+                ureq::get(&url).call().map_err(|e| e.to_string())?.into_string().map_err(|e| e.to_string())
+            }));
+        }
+        #[cfg(target_arch = "wasm32")]
+        warn!("Ghost URLs are not supported in the browser build: {source}");
+        return;
+    }
+    match std::fs::read_to_string(source) {
+        Ok(data) => {
+            race.track = GhostTrack::parse(&data, &player_from_path(source));
+            if race.track.is_none() {
+                warn!("Not a ghost or replay file: {source}");
+            }
+        }
+        Err(e) => warn!("Could not read ghost {source}: {e}"),
+    }
+}
+
+/// Player name for an unnamed ghost: the file name (`anna.ghost.ron` -> `anna`).
+fn player_from_path(path: &str) -> String {
+    let name = std::path::Path::new(path).file_name().and_then(|n| n.to_str()).unwrap_or("ghost");
+    name.trim_end_matches(GHOST_EXTENSION).trim_end_matches(".ron").to_string()
+}
+
+fn finish_ghost_download(mut race: ResMut<GhostRace>, loc: Res<Localization>, mut toasts: ResMut<ToastQueue>) {
+    let Some(task) = race.download.as_mut() else { return; };
+    let Some(result) = block_on(poll_once(task)) else { return; };
+    race.download = None;
+    match result.map(|data| GhostTrack::parse(&data, "ghost")) {
+        Ok(Some(track)) => {
+            toasts.push_for(loc.tf("ghost.loaded", &[("player", track.player.clone()), ("level", track.level.clone())]), ToastKind::Info, 3.0);
+            race.track = Some(track);
+        }
+        Ok(None) => toasts.push_for(loc.tf("ghost.load_failed", &[("source", "URL".to_string())]), ToastKind::Warning, 3.0),
+        Err(e) => {
+            warn!("Ghost download failed: {e}");
+            toasts.push_for(loc.tf("ghost.load_failed", &[("source", "URL".to_string())]), ToastKind::Warning, 3.0);
+        }
+    }
+}
+
+// `*.ghost.ron` files dropped onto the menu (replay.rs takes the other drops).
+fn import_dropped_ghost(
+    phase: Res<State<AppState>>,
+    loc: Res<Localization>,
+    mut race: ResMut<GhostRace>,
+    mut toasts: ResMut<ToastQueue>,
+    mut ev_drop: EventReader<FileDragAndDrop>,
+) {
+    for ev in ev_drop.read() {
+        let FileDragAndDrop::DroppedFile { path_buf, .. } = ev else { continue; };
+        let file = path_buf.display().to_string();
+        if *phase.get() != AppState::Menu || !file.ends_with(GHOST_EXTENSION) {
+            continue;
+        }
+        let track = std::fs::read_to_string(path_buf).ok().and_then(|data| GhostTrack::parse(&data, &player_from_path(&file)));
+        match track {
+            Some(track) => {
+                toasts.push_for(loc.tf("ghost.loaded", &[("player", track.player.clone()), ("level", track.level.clone())]), ToastKind::Info, 3.0);
+                race.track = Some(track);
+            }
+            None => toasts.push_for(loc.tf("ghost.load_failed", &[("source", file)]), ToastKind::Warning, 3.0),
+        }
+    }
+}
+
+fn record_splits(sim: Res<SimState>, score: Res<Score>, mut race: ResMut<GhostRace>, mut ev_hit: EventReader<TargetHitEvent>) {
+    for hit in ev_hit.read() {
+        if hit.kind == TargetKind::Standard {
+            race.splits.push(sim.elapsed_seconds);
+        }
+    }
+    // Restarts and resumed rounds.
+    if race.splits.len() > score.hits as usize {
+        let hits = score.hits as usize;
+        race.splits.truncate(hits);
+    }
+}
+
+fn spawn_ghost(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Sphere::new(GHOST_RADIUS)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::srgba(0.7, 0.9, 1.0, 0.35),
+                emissive: LinearRgba::rgb(0.2, 0.4, 0.6),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            }),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        GhostBall,
+        Name::new("GhostBall"),
+    ));
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle { font: assets.load("fonts/FiraSans-Bold.ttf"), font_size: 22.0, color: Color::WHITE },
+        )
+        .with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(72.0), // under the hot-seat turn line
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        }),
+        GhostDeltaText,
+    ));
+}
+
+fn update_ghost_ball(
+    sim: Res<SimState>,
+    score: Res<Score>,
+    race: Res<GhostRace>,
+    phase: Res<State<AppState>>,
+    mut q_ghost: Query<(&mut Transform, &mut Visibility), With<GhostBall>>,
+) {
+    let Ok((mut t, mut vis)) = q_ghost.get_single_mut() else { return; };
+    let racing = phase.get().in_game() && race.races_on(&score.level_id);
+    let pos = race.track.as_ref().filter(|_| racing).and_then(|g| g.position_at(sim.elapsed_seconds));
+    *vis = match pos {
+        Some(pos) => {
+            t.translation = pos;
+            Visibility::Inherited
+        }
+        None => Visibility::Hidden,
+    };
+}
+
+fn update_ghost_delta(
+    sim: Res<SimState>,
+    score: Res<Score>,
+    race: Res<GhostRace>,
+    loc: Res<Localization>,
+    phase: Res<State<AppState>>,
+    mut q_text: Query<(&mut Text, &mut Visibility), With<GhostDeltaText>>,
+) {
+    let Ok((mut text, mut vis)) = q_text.get_single_mut() else { return; };
+    let track = race.track.as_ref().filter(|_| phase.get().round_in_view() && race.races_on(&score.level_id));
+    let Some(track) = track else {
+        *vis = Visibility::Hidden;
+        return;
+    };
+    *vis = Visibility::Inherited;
+    let (delta, color) = match track.delta(&race.splits, sim.elapsed_seconds) {
+        Some(d) if d > 0.0 => (format_delta(d), Color::srgb(1.0, 0.45, 0.4)),
+        Some(d) => (format_delta(d), Color::srgb(0.45, 1.0, 0.5)),
+        None => ("--".to_string(), Color::WHITE),
+    };
+    let value = loc.tf("hud.ghost", &[("player", track.player.clone()), ("delta", delta)]);
+    if text.sections[0].value != value {
+        text.sections[0].value = value;
+    }
+    text.sections[0].style.color = color;
+}
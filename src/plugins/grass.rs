@@ -0,0 +1,281 @@
+// Grass blade layer.
+// Crossed-quad grass clumps scattered on low-slope terrain in tiles around the camera.
+//  - One shared clump mesh + one shared material => Bevy batches all clumps into instanced draws.
+//  - Custom vertex shader (shaders/grass.wgsl) bends blade tips with a wind sway.
+//  - Per-tile clump count falls off with distance; `GrassConfig::density_scale` is lowered /
+//    raised by the vegetation adaptive perf tuner.
+//  - Placement is seeded per tile, so a tile respawned at another density keeps its layout.
+
+use bevy::pbr::{ExtendedMaterial, MaterialExtension, NotShadowCaster, StandardMaterial};
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef, ShaderType};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+
+use crate::plugins::camera::OrbitCamera;
+use crate::plugins::terrain::TerrainSampler;
+
+pub type GrassMaterial = ExtendedMaterial<StandardMaterial, GrassExtension>;
+
+#[derive(Resource, Clone)]
+pub struct GrassConfig {
+    pub enabled: bool,
+    pub tile_size: f32,
+    pub radius: f32,               // grass drawn within this distance of the camera
+    pub clumps_per_tile: u32,      // count at full density (closest ring)
+    pub density_scale: f32,        // 0..1 multiplier, driven by the perf tuner
+    pub min_density_scale: f32,
+    pub min_normal_y: f32,         // steeper ground gets no grass
+    pub min_height: f32,           // no grass at/below the water line
+    pub blade_height: (f32, f32),  // clump height range (m)
+    pub tiles_per_frame: usize,    // spawn budget
+    pub wind_dir: Vec2,
+    pub wind_strength: f32,        // tip displacement (m) at full gust
+    pub wind_frequency: f32,
+}
+impl Default for GrassConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            tile_size: 8.0,
+            radius: 64.0,
+            clumps_per_tile: 28,
+            density_scale: 1.0,
+            min_density_scale: 0.25,
+            min_normal_y: 0.85,
+            min_height: 25.5,
+            blade_height: (0.35, 0.7),
+            tiles_per_frame: 12,
+            wind_dir: Vec2::new(0.8, 0.6),
+            wind_strength: 0.12,
+            wind_frequency: 1.6,
+        }
+    }
+}
+
+/// Matches WGSL struct GrassMaterial in shaders/grass.wgsl.
+#[derive(Clone, Copy, Debug, ShaderType)]
+pub struct GrassUniform {
+    pub base_color: Vec4,
+    pub tip_color: Vec4,
+    pub wind: Vec4, // xy = direction, z = strength, w = frequency
+    pub time: f32,
+}
+impl Default for GrassUniform {
+    fn default() -> Self {
+        Self {
+            base_color: Vec4::new(0.10, 0.20, 0.06, 1.0),
+            tip_color: Vec4::new(0.38, 0.52, 0.20, 1.0),
+            wind: Vec4::new(0.8, 0.6, 0.12, 1.6),
+            time: 0.0,
+        }
+    }
+}
+
+#[derive(Asset, AsBindGroup, TypePath, Debug, Clone, Default)]
+pub struct GrassExtension {
+    #[uniform(100)]
+    pub data: GrassUniform,
+}
+
+impl MaterialExtension for GrassExtension {
+    fn vertex_shader() -> ShaderRef {
+        ShaderRef::Path("shaders/grass.wgsl".into())
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        ShaderRef::Path("shaders/grass.wgsl".into())
+    }
+}
+
+#[derive(Component)]
+pub struct GrassTile {
+    pub coord: IVec2,
+    pub clumps: u32,
+}
+
+#[derive(Resource, Default)]
+struct GrassAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<GrassMaterial>,
+}
+
+#[derive(Resource, Default)]
+struct GrassTiles {
+    map: HashMap<IVec2, (Entity, u32)>,
+}
+
+pub struct GrassPlugin;
+impl Plugin for GrassPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(MaterialPlugin::<GrassMaterial>::default())
+            .insert_resource(GrassConfig::default())
+            .init_resource::<GrassTiles>()
+            .add_systems(Startup, setup_grass_assets)
+            .add_systems(Update, (update_grass_tiles, animate_grass_material));
+    }
+}
+
+/// Three tapered blade cards crossed at 60 degrees; uv.y = 0 at the root, 1 at the tip.
+/// Normals point up so cards shade like the ground they grow from.
+pub fn build_grass_clump_mesh() -> Mesh {
+    let (w_base, w_tip) = (0.16_f32, 0.03_f32);
+    let mut positions: Vec<[f32; 3]> = Vec::with_capacity(12);
+    let mut normals: Vec<[f32; 3]> = Vec::with_capacity(12);
+    let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(12);
+    let mut indices: Vec<u32> = Vec::with_capacity(18);
+    for k in 0..3 {
+        let a = k as f32 * std::f32::consts::PI / 3.0;
+        let (s, c) = a.sin_cos();
+        let side = Vec3::new(c, 0.0, s);
+        let base = positions.len() as u32;
+        for (h, w, v) in [(0.0, -w_base, 0.0), (0.0, w_base, 0.0), (1.0, -w_tip, 1.0), (1.0, w_tip, 1.0)] {
+            let p = side * w + Vec3::Y * h;
+            positions.push([p.x, p.y, p.z]);
+            normals.push([0.0, 1.0, 0.0]);
+            uvs.push([if w < 0.0 { 0.0 } else { 1.0 }, v]);
+        }
+        indices.extend_from_slice(&[base, base + 1, base + 2, base + 1, base + 3, base + 2]);
+    }
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::RENDER_WORLD);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
+
+/// Clumps for a tile at distance `dist` from the camera: full density inside 25% of the
+/// radius, then a quadratic falloff to zero at the radius. Quantized to 4 rings so tiles only
+/// respawn when crossing a ring.
+pub fn grass_tile_clumps(cfg: &GrassConfig, dist: f32) -> u32 {
+    if dist >= cfg.radius {
+        return 0;
+    }
+    let ring = ((dist / cfg.radius) * 4.0).floor().clamp(0.0, 3.0);
+    let falloff = (1.0 - ring / 4.0).powi(2);
+    let scale = (cfg.density_scale * 4.0).round() / 4.0; // quantized for the same reason
+    (cfg.clumps_per_tile as f32 * falloff * scale).round() as u32
+}
+
+fn setup_grass_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<GrassMaterial>>,
+    cfg: Res<GrassConfig>,
+) {
+    let mut ext = GrassExtension::default();
+    ext.data.wind = cfg.wind_dir.normalize_or_zero().extend(cfg.wind_strength).extend(cfg.wind_frequency);
+    let material = materials.add(ExtendedMaterial {
+        base: StandardMaterial {
+            base_color: Color::WHITE,
+            perceptual_roughness: 0.9,
+            double_sided: true,
+            cull_mode: None,
+            ..default()
+        },
+        extension: ext,
+    });
+    commands.insert_resource(GrassAssets { mesh: meshes.add(build_grass_clump_mesh()), material });
+}
+
+fn animate_grass_material(
+    time: Res<Time>,
+    cfg: Res<GrassConfig>,
+    assets: Option<Res<GrassAssets>>,
+    mut materials: ResMut<Assets<GrassMaterial>>,
+) {
+    let Some(assets) = assets else { return; };
+    if let Some(mat) = materials.get_mut(&assets.material) {
+        mat.extension.data.time = time.elapsed_seconds();
+        if cfg.is_changed() {
+            mat.extension.data.wind =
+                cfg.wind_dir.normalize_or_zero().extend(cfg.wind_strength).extend(cfg.wind_frequency);
+        }
+    }
+}
+
+fn update_grass_tiles(
+    mut commands: Commands,
+    cfg: Res<GrassConfig>,
+    sampler: Res<TerrainSampler>,
+    assets: Option<Res<GrassAssets>>,
+    mut tiles: ResMut<GrassTiles>,
+    q_cam: Query<&GlobalTransform, With<OrbitCamera>>,
+) {
+    let Some(assets) = assets else { return; };
+    let Ok(cam) = q_cam.get_single() else { return; };
+    if !cfg.enabled {
+        for (_, (e, _)) in tiles.map.drain() {
+            commands.entity(e).despawn_recursive();
+        }
+        return;
+    }
+    let cam_xz = cam.translation().xz();
+    let tile = cfg.tile_size;
+    let tile_center = |c: IVec2| Vec2::new((c.x as f32 + 0.5) * tile, (c.y as f32 + 0.5) * tile);
+
+    // Drop tiles out of range or whose density ring changed (they are re-queued below).
+    tiles.map.retain(|coord, (e, clumps)| {
+        let want = grass_tile_clumps(&cfg, tile_center(*coord).distance(cam_xz));
+        if want != *clumps {
+            commands.entity(*e).despawn_recursive();
+            false
+        } else {
+            true
+        }
+    });
+
+    let r = (cfg.radius / tile).ceil() as i32;
+    let center = IVec2::new((cam_xz.x / tile).floor() as i32, (cam_xz.y / tile).floor() as i32);
+    let mut wanted: Vec<(f32, IVec2)> = Vec::new();
+    for dz in -r..=r {
+        for dx in -r..=r {
+            let c = center + IVec2::new(dx, dz);
+            if tiles.map.contains_key(&c) {
+                continue;
+            }
+            let d = tile_center(c).distance(cam_xz);
+            if d < cfg.radius {
+                wanted.push((d, c));
+            }
+        }
+    }
+    wanted.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    for (d, coord) in wanted.into_iter().take(cfg.tiles_per_frame) {
+        let clumps = grass_tile_clumps(&cfg, d);
+        let origin = Vec2::new(coord.x as f32 * tile, coord.y as f32 * tile);
+        let seed = (coord.x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ (coord.y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+        let mut rng = StdRng::seed_from_u64(seed ^ sampler.cfg.seed as u64);
+        let e = commands
+            .spawn((SpatialBundle::default(), GrassTile { coord, clumps }))
+            .with_children(|p| {
+                for _ in 0..clumps {
+                    let pos = origin + Vec2::new(rng.gen::<f32>() * tile, rng.gen::<f32>() * tile);
+                    let yaw = rng.gen::<f32>() * std::f32::consts::TAU;
+                    let height = rng.gen_range(cfg.blade_height.0..=cfg.blade_height.1);
+                    let h = sampler.height(pos.x, pos.y);
+                    if h <= cfg.min_height || sampler.normal(pos.x, pos.y).y < cfg.min_normal_y {
+                        continue;
+                    }
+                    p.spawn((
+                        MaterialMeshBundle {
+                            mesh: assets.mesh.clone(),
+                            material: assets.material.clone(),
+                            transform: Transform::from_translation(Vec3::new(pos.x, h, pos.y))
+                                .with_rotation(Quat::from_rotation_y(yaw))
+                                .with_scale(Vec3::new(1.0, height, 1.0)),
+                            ..default()
+                        },
+                        NotShadowCaster,
+                    ));
+                }
+            })
+            .id();
+        tiles.map.insert(coord, (e, clumps));
+    }
+}
@@ -0,0 +1,239 @@
+// Headless simulation (tests and tools).
+// `HeadlessSimPlugin` runs the gameplay core - fixed tick (core_sim.rs), ball physics, target hit
+// detection / relocation and scoring (BallPlugin, TargetPlugin, GameStatePlugin) - without a
+// window, renderer or asset server, on a synthetic flat or fixture heightmap. It supplies what
+// the full game's other plugins would (events, app state, profile, target parameters) with
+// non-persistent defaults. `HeadlessSim` wraps such an app with a step / inspect API: each step is
+// exactly one 60 Hz simulation tick (time advances by a fixed amount, not the wall clock) and
+// relocations draw from a seeded `SimRng`, so the same shots give the same run.
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::plugins::ball::{place_ball, Ball, BallKinematic, BallPlugin};
+use crate::plugins::core_sim::{CoreSimPlugin, SimRng, SimState};
+use crate::plugins::cup_target::CupConfig;
+use crate::plugins::event_log::EventLog;
+use crate::plugins::game_state::{GameStatePlugin, Score, ShotConfig};
+use crate::plugins::localization::Localization;
+use crate::plugins::app_state::AppState;
+use crate::plugins::particles::{BallGroundImpactEvent, GameOverEvent, ShotFiredEvent, TargetHitEvent};
+use crate::plugins::profile::Profile;
+use crate::plugins::save_game::RoundResumedEvent;
+use crate::plugins::shooting::{shot_direction, shot_power_scale};
+use crate::plugins::target::{Target, TargetBob, TargetFloat, TargetInfo, TargetParams, TargetPlugin};
+use crate::plugins::terrain::{TerrainConfig, TerrainSampler};
+
+pub const HEADLESS_WORLD_SIZE: f32 = 2000.0; // m, like the shipped heightmaps
+pub const BALL_RADIUS: f32 = 0.5;
+/// Speed below which the ball counts as at rest.
+pub const REST_SPEED: f32 = 0.05;
+
+/// Ground the headless world is built on.
+#[derive(Debug, Clone)]
+pub enum SyntheticTerrain {
+    Flat { height: f32 },
+    /// Heightmap fixture: red channel, row-major, `width * height` bytes; 255 is `max_height` m.
+    Fixture { width: u32, height: u32, red: Vec<u8>, max_height: f32 },
+}
+
+impl SyntheticTerrain {
+    pub fn sampler(&self) -> TerrainSampler {
+        let cfg = |max_height: f32| TerrainConfig {
+            heightmap_world_size: HEADLESS_WORLD_SIZE,
+            heightmap_max_height: max_height,
+            amplitude: 1.0,
+            ..default()
+        };
+        match self {
+            Self::Flat { height } => TerrainSampler::from_heightmap_data(cfg(*height), 2, 2, vec![255; 4]),
+            Self::Fixture { width, height, red, max_height } => {
+                TerrainSampler::from_heightmap_data(cfg(*max_height), *width, *height, red.clone())
+            }
+        }
+    }
+}
+
+pub struct HeadlessSimPlugin {
+    pub terrain: SyntheticTerrain,
+    pub seed: u64,
+}
+
+impl Default for HeadlessSimPlugin {
+    fn default() -> Self {
+        Self { terrain: SyntheticTerrain::Flat { height: 100.0 }, seed: 0 }
+    }
+}
+
+impl Plugin for HeadlessSimPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<InputPlugin>() {
+            app.add_plugins(InputPlugin);
+        }
+        if !app.is_plugin_added::<StatesPlugin>() {
+            app.add_plugins(StatesPlugin);
+        }
+        let tick = Time::<Fixed>::from_hz(60.0).timestep();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(tick))
+            .insert_resource(SimRng::seeded(self.seed))
+            .insert_resource(self.terrain.sampler())
+            .insert_state(AppState::Playing)
+            .insert_resource(Profile::default())
+            .insert_resource(Localization::from_languages(Vec::new()))
+            .insert_resource(TargetParams {
+                base_height: 3.0,
+                amplitude: 0.0, // no bob: target heights stay exact
+                bob_freq: 0.0,
+                rot_speed: 0.0,
+                collider_radius: 4.5,
+                visual_offset: 0.0,
+            })
+            .init_resource::<EventLog>()
+            .init_resource::<CupConfig>()
+            .add_event::<BallGroundImpactEvent>()
+            .add_event::<TargetHitEvent>()
+            .add_event::<GameOverEvent>()
+            .add_event::<ShotFiredEvent>()
+            .add_event::<RoundResumedEvent>()
+            .add_plugins((CoreSimPlugin, GameStatePlugin, BallPlugin, TargetPlugin));
+    }
+}
+
+/// A headless app plus the calls tests need to drive and inspect it.
+pub struct HeadlessSim {
+    pub app: App,
+    ball: Option<Entity>,
+}
+
+impl HeadlessSim {
+    /// Build the app and run its startup frame (one tick).
+    pub fn new(plugin: HeadlessSimPlugin) -> Self {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, plugin));
+        app.update();
+        Self { app, ball: None }
+    }
+
+    pub fn flat(height: f32) -> Self {
+        Self::new(HeadlessSimPlugin { terrain: SyntheticTerrain::Flat { height }, ..default() })
+    }
+
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    pub fn sampler(&self) -> &TerrainSampler {
+        self.world().resource::<TerrainSampler>()
+    }
+
+    pub fn score(&self) -> &Score {
+        self.world().resource::<Score>()
+    }
+
+    pub fn score_mut(&mut self) -> Mut<'_, Score> {
+        self.world_mut().resource_mut::<Score>()
+    }
+
+    pub fn tick(&self) -> u64 {
+        self.world().resource::<SimState>().tick
+    }
+
+    /// Ball at rest on the ground at `pos` (x / z); replaces any previous ball.
+    pub fn spawn_ball(&mut self, pos: Vec2) -> Entity {
+        if let Some(old) = self.ball.take() {
+            self.world_mut().despawn(old);
+        }
+        let world = self.app.world_mut();
+        let mut kin = BallKinematic { collider_radius: BALL_RADIUS, visual_radius: BALL_RADIUS, vel: Vec3::ZERO, angular_vel: Vec3::ZERO };
+        let mut t = Transform::default();
+        place_ball(&mut t, &mut kin, world.resource::<TerrainSampler>(), pos);
+        let ball = world.spawn((Ball, kin, t)).id();
+        self.ball = Some(ball);
+        ball
+    }
+
+    /// Level target `index` floating `base_height` m above the ground at `pos`.
+    pub fn spawn_target(&mut self, index: usize, pos: Vec2, base_height: f32, radius: f32, points: u32) -> Entity {
+        let world = self.world_mut();
+        let ground = world.resource::<TerrainSampler>().height(pos.x, pos.y);
+        world
+            .spawn((
+                Target,
+                TargetInfo { index, points, collider_radius: radius, base_height, home: pos, bob: TargetBob::default() },
+                TargetFloat { ground, base_height, amplitude: 0.0, phase: 0.0, rot_speed: 0.0, bounce_freq: 0.0 },
+                Transform::from_xyz(pos.x, ground + base_height, pos.y),
+            ))
+            .id()
+    }
+
+    /// Strike the ball toward `heading` (x / z) with a charge of `power` (0..1), like releasing
+    /// the shot button with the camera behind the ball.
+    pub fn shoot(&mut self, heading: Vec2, power: f32) {
+        let cfg = *self.world().resource::<ShotConfig>();
+        let Some(ball) = self.ball else { return; };
+        let pos = self.ball_position();
+        let behind = pos - Vec3::new(heading.x, 0.0, heading.y);
+        let scale = shot_power_scale(power.clamp(0.0, 1.0));
+        let world = self.world_mut();
+        if let Some(mut kin) = world.get_mut::<BallKinematic>(ball) {
+            kin.vel += shot_direction(pos, behind, None, cfg.up_angle_deg) * cfg.base_impulse * scale;
+        }
+        world.send_event(ShotFiredEvent { pos, power: scale });
+    }
+
+    /// One simulation tick.
+    pub fn step(&mut self) {
+        self.app.update();
+    }
+
+    pub fn step_n(&mut self, ticks: u32) {
+        for _ in 0..ticks {
+            self.step();
+        }
+    }
+
+    /// Step until `done` holds (checked after each tick); the ticks taken, None if `max_ticks`
+    /// passed first.
+    pub fn step_until(&mut self, max_ticks: u32, done: impl Fn(&Self) -> bool) -> Option<u32> {
+        (1..=max_ticks).find(|_| {
+            self.step();
+            done(self)
+        })
+    }
+
+    /// Step until the ball is at rest on the ground.
+    pub fn run_until_rest(&mut self, max_ticks: u32) -> Option<u32> {
+        self.step_until(max_ticks, |sim| sim.ball_grounded() && sim.ball_velocity().length() < REST_SPEED)
+    }
+
+    fn ball(&self) -> Option<(&Transform, &BallKinematic)> {
+        let world = self.world();
+        let ball = self.ball?;
+        Some((world.get::<Transform>(ball)?, world.get::<BallKinematic>(ball)?))
+    }
+
+    pub fn ball_position(&self) -> Vec3 {
+        self.ball().map_or(Vec3::ZERO, |(t, _)| t.translation)
+    }
+
+    pub fn ball_velocity(&self) -> Vec3 {
+        self.ball().map_or(Vec3::ZERO, |(_, k)| k.vel)
+    }
+
+    /// Whether the ball touches the ground.
+    pub fn ball_grounded(&self) -> bool {
+        let Some((t, kin)) = self.ball() else { return false; };
+        let p = t.translation;
+        p.y <= self.sampler().height(p.x, p.z) + kin.collider_radius + 1e-3
+    }
+
+    pub fn target_position(&self, target: Entity) -> Option<Vec3> {
+        self.world().get::<Transform>(target).map(|t| t.translation)
+    }
+}
@@ -0,0 +1,196 @@
+// Hit feedback for targets hit far away or off screen.
+// The explosion is easy to miss when the target is a speck in the distance, behind the camera or
+// relocates right away, so such hits flash the screen briefly and put a marker on the screen edge
+// pointing at where the hit happened, with the distance the ball covered from the shot ("Hit!
+// 184 m"). The marker keeps tracking the hit position while the camera moves and fades out.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::plugins::camera::OrbitCamera;
+use crate::plugins::particles::{ShotFiredEvent, TargetHitEvent};
+use crate::plugins::target::TargetKind;
+
+#[derive(Resource, Debug, Clone)]
+pub struct HitIndicatorConfig {
+    pub enabled: bool,
+    pub far_distance: f32, // m from the camera beyond which an on-screen hit still counts as far
+    pub duration: f32,     // s until the marker has faded
+    pub flash_time: f32,   // s of screen flash
+    pub flash_alpha: f32,
+    pub edge_margin: f32,  // px kept between the marker centre and the screen edge
+}
+impl Default for HitIndicatorConfig {
+    fn default() -> Self {
+        Self { enabled: true, far_distance: 120.0, duration: 1.8, flash_time: 0.25, flash_alpha: 0.22, edge_margin: 48.0 }
+    }
+}
+
+/// Point on the rectangle `window` inset by `margin` where a ray from the centre along
+/// `dir` (screen space, y down) leaves it.
+pub fn edge_point(dir: Vec2, window: Vec2, margin: f32) -> Vec2 {
+    let half = (window * 0.5 - Vec2::splat(margin)).max(Vec2::ZERO);
+    let center = window * 0.5;
+    if dir.length_squared() < 1e-8 {
+        return center + Vec2::new(0.0, -half.y);
+    }
+    let sx = if dir.x.abs() > 1e-6 { half.x / dir.x.abs() } else { f32::INFINITY };
+    let sy = if dir.y.abs() > 1e-6 { half.y / dir.y.abs() } else { f32::INFINITY };
+    center + dir * sx.min(sy)
+}
+
+/// Does a hit at viewport position `viewport` (None = behind the camera) need the indicator?
+pub fn needs_indicator(cfg: &HitIndicatorConfig, viewport: Option<Vec2>, window: Vec2, camera_distance: f32) -> bool {
+    let on_screen = viewport.is_some_and(|p| p.x >= 0.0 && p.y >= 0.0 && p.x <= window.x && p.y <= window.y);
+    !on_screen || camera_distance > cfg.far_distance
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct HitIndicatorState {
+    pub hit_pos: Vec3,
+    pub covered: f32, // m from the shot position to the hit
+    pub age: f32,
+    pub active: bool,
+}
+
+#[derive(Component)]
+struct HitFlash;
+#[derive(Component)]
+struct HitEdgeMarker;
+
+pub struct HitIndicatorPlugin;
+impl Plugin for HitIndicatorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HitIndicatorConfig>()
+            .init_resource::<HitIndicatorState>()
+            .add_systems(Startup, spawn_hit_indicator)
+            .add_systems(Update, (trigger_hit_indicator, update_hit_indicator).chain());
+    }
+}
+
+fn spawn_hit_indicator(mut commands: Commands, assets: Res<AssetServer>) {
+    commands.spawn((
+        NodeBundle {
+            style: Style { position_type: PositionType::Absolute, width: Val::Percent(100.0), height: Val::Percent(100.0), ..default() },
+            background_color: BackgroundColor(Color::srgba(1.0, 0.9, 0.6, 0.0)),
+            visibility: Visibility::Hidden,
+            focus_policy: bevy::ui::FocusPolicy::Pass,
+            ..default()
+        },
+        HitFlash,
+    ));
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(0.1, 0.05, 0.02, 0.6)),
+                border_color: BorderColor(Color::srgb(1.0, 0.8, 0.3)),
+                border_radius: BorderRadius::all(Val::Px(12.0)),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            HitEdgeMarker,
+        ))
+        .with_children(|m| {
+            m.spawn(TextBundle::from_section(
+                "",
+                TextStyle { font: assets.load("fonts/FiraSans-Bold.ttf"), font_size: 18.0, color: Color::srgb(1.0, 0.85, 0.3) },
+            ));
+        });
+}
+
+fn trigger_hit_indicator(
+    cfg: Res<HitIndicatorConfig>,
+    mut state: ResMut<HitIndicatorState>,
+    mut ev_shot: EventReader<ShotFiredEvent>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+    mut shot_pos: Local<Option<Vec3>>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_cam: Query<(&Camera, &GlobalTransform), With<OrbitCamera>>,
+) {
+    if let Some(e) = ev_shot.read().last() {
+        *shot_pos = Some(e.pos);
+    }
+    for e in ev_hit.read() {
+        // Decoys get their own (penalty) banner.
+        if !cfg.enabled || e.kind == TargetKind::Decoy {
+            continue;
+        }
+        let (Ok(window), Ok((cam, cam_gt))) = (q_window.get_single(), q_cam.get_single()) else { continue; };
+        let size = Vec2::new(window.width(), window.height());
+        let viewport = cam.world_to_viewport(cam_gt, e.pos);
+        if !needs_indicator(&cfg, viewport, size, cam_gt.translation().distance(e.pos)) {
+            continue;
+        }
+        *state = HitIndicatorState {
+            hit_pos: e.pos,
+            covered: shot_pos.map_or(0.0, |p| p.distance(e.pos)),
+            age: 0.0,
+            active: true,
+        };
+    }
+}
+
+fn update_hit_indicator(
+    time: Res<Time>,
+    cfg: Res<HitIndicatorConfig>,
+    ui_scale: Res<UiScale>,
+    mut state: ResMut<HitIndicatorState>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_cam: Query<&GlobalTransform, With<OrbitCamera>>,
+    mut q_flash: Query<(&mut Visibility, &mut BackgroundColor), (With<HitFlash>, Without<HitEdgeMarker>)>,
+    mut q_marker: Query<
+        (&mut Visibility, &mut Style, &Node, &Children, &mut BackgroundColor, &mut BorderColor),
+        (With<HitEdgeMarker>, Without<HitFlash>),
+    >,
+    mut q_text: Query<&mut Text>,
+) {
+    let (Ok((mut flash_vis, mut flash_bg)), Ok((mut marker_vis, mut style, node, children, mut marker_bg, mut marker_border))) =
+        (q_flash.get_single_mut(), q_marker.get_single_mut())
+    else {
+        return;
+    };
+    if state.active {
+        state.age += time.delta_seconds();
+        state.active = state.age < cfg.duration;
+    }
+    let (Ok(window), Ok(cam_gt), true) = (q_window.get_single(), q_cam.get_single(), state.active) else {
+        *flash_vis = Visibility::Hidden;
+        *marker_vis = Visibility::Hidden;
+        return;
+    };
+
+    let flash = (1.0 - state.age / cfg.flash_time.max(1e-3)).clamp(0.0, 1.0);
+    *flash_vis = if flash > 0.0 { Visibility::Inherited } else { Visibility::Hidden };
+    flash_bg.0.set_alpha(cfg.flash_alpha * flash);
+
+    // Direction to the hit in view space (works for points behind the camera too), y down.
+    let view = cam_gt.compute_matrix().inverse().transform_point3(state.hit_pos);
+    let dir = Vec2::new(view.x, -view.y);
+    let size = Vec2::new(window.width(), window.height());
+    let p = edge_point(dir, size, cfg.edge_margin);
+    let scale = ui_scale.0.max(0.01);
+    // Node size is in window pixels; centre the marker on the edge point.
+    let top_left = (p - node.size() * 0.5) / scale;
+    style.left = Val::Px(top_left.x);
+    style.top = Val::Px(top_left.y);
+    *marker_vis = Visibility::Inherited;
+
+    let fade = (1.0 - (state.age - cfg.duration * 0.6) / (cfg.duration * 0.4)).clamp(0.0, 1.0);
+    marker_bg.0.set_alpha(0.6 * fade);
+    marker_border.0.set_alpha(fade);
+    for &child in children.iter() {
+        if let Ok(mut text) = q_text.get_mut(child) {
+            let value = format!("Hit! {:.0} m", state.covered);
+            if text.sections[0].value != value {
+                text.sections[0].value = value;
+            }
+            text.sections[0].style.color.set_alpha(fade);
+        }
+    }
+}
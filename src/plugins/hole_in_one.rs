@@ -0,0 +1,242 @@
+// Hole-in-one celebration.
+// A standard target hit with the hole's first stroke (penalty strokes count, see
+// `Score::hole_strokes`) sends a `HoleInOneEvent`: a fanfare plays (game_audio.rs), an oversized
+// candy fountain bursts from the target (particles.rs), the lifetime stat goes up (profile.rs,
+// which unlocks the achievement) and a toast and log line announce it. Then, unless the round is
+// over, the shot is shown again in slow motion: a golden ghost ball retraces the end of the ball's
+// flight while the camera tracks it from the side. A click skips the replay; the next hole's
+// flythrough (camera_cinematic.rs) follows it.
+
+use bevy::prelude::*;
+
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::camera::{CameraActual, CameraMode, OrbitCamera};
+use crate::plugins::event_log::{EventLog, LogKind};
+use crate::plugins::game_state::Score;
+use crate::plugins::localization::Localization;
+use crate::plugins::particles::{HoleInOneEvent, ShotFiredEvent, TargetHitEvent};
+use crate::plugins::replay::SNAP_DISTANCE;
+use crate::plugins::target::TargetKind;
+use crate::plugins::ui_toast::{ToastKind, ToastQueue};
+
+/// Whether a hit finished the hole in one stroke.
+pub fn is_hole_in_one(kind: TargetKind, strokes: u32) -> bool {
+    kind == TargetKind::Standard && strokes == 1
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct HoleInOneConfig {
+    pub replay: bool,
+    pub replay_speed: f32,     // playback rate of the slow-motion replay
+    pub replay_window: f32,    // s of flight (up to the hit) it shows
+    pub camera_offset: Vec2,   // m to the side of and above the ghost ball
+    pub camera_follow: f32,    // 1/s camera smoothing
+}
+impl Default for HoleInOneConfig {
+    fn default() -> Self {
+        Self {
+            replay: true,
+            replay_speed: 0.3,
+            replay_window: 4.0,
+            camera_offset: Vec2::new(14.0, 5.0),
+            camera_follow: 4.0,
+        }
+    }
+}
+
+/// Timed ball positions of one shot.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShotPath {
+    points: Vec<(f32, Vec3)>,
+}
+
+impl ShotPath {
+    pub fn push(&mut self, t: f32, pos: Vec3) {
+        self.points.push((t, pos));
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn last(&self) -> Option<Vec3> {
+        self.points.last().map(|p| p.1)
+    }
+
+    pub fn duration(&self) -> f32 {
+        match (self.points.first(), self.points.last()) {
+            (Some(a), Some(b)) => b.0 - a.0,
+            _ => 0.0,
+        }
+    }
+
+    /// The last `window` seconds, re-timed to start at 0.
+    pub fn tail(&self, window: f32) -> Self {
+        let end = self.points.last().map_or(0.0, |p| p.0);
+        let from = self.points.iter().rposition(|p| p.0 <= end - window).unwrap_or(0);
+        let start = self.points.get(from).map_or(0.0, |p| p.0);
+        Self { points: self.points[from..].iter().map(|(t, p)| (t - start, *p)).collect() }
+    }
+
+    /// Position `t` seconds after the first point (interpolated, clamped to the ends).
+    pub fn at(&self, t: f32) -> Option<Vec3> {
+        let t = t + self.points.first()?.0;
+        let i = self.points.partition_point(|p| p.0 <= t);
+        if i == 0 {
+            return self.points.first().map(|p| p.1);
+        }
+        let Some(b) = self.points.get(i) else { return self.last(); };
+        let a = self.points[i - 1];
+        let s = if b.0 > a.0 { (t - a.0) / (b.0 - a.0) } else { 1.0 };
+        Some(a.1.lerp(b.1, s))
+    }
+}
+
+/// Path of the shot in flight; frozen once the ball is moved away (next tee, respawn).
+#[derive(Resource, Debug, Default)]
+struct ShotRecorder {
+    path: ShotPath,
+    frozen: bool,
+}
+
+/// Slow-motion replay in progress.
+#[derive(Resource, Debug, Default)]
+pub struct InstantReplay {
+    pub path: ShotPath,
+    pub elapsed: f32, // s of playback
+    ghost: Option<Entity>,
+}
+
+impl InstantReplay {
+    pub fn active(&self) -> bool {
+        self.ghost.is_some()
+    }
+}
+
+pub struct HoleInOnePlugin;
+impl Plugin for HoleInOnePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HoleInOneConfig>()
+            .init_resource::<ShotRecorder>()
+            .init_resource::<InstantReplay>()
+            .add_systems(Update, (record_shot, celebrate, play_replay).chain());
+    }
+}
+
+fn record_shot(
+    time: Res<Time>,
+    mut recorder: ResMut<ShotRecorder>,
+    mut ev_shot: EventReader<ShotFiredEvent>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+) {
+    if ev_shot.read().count() > 0 {
+        *recorder = ShotRecorder::default();
+    }
+    let Ok((t, kin)) = q_ball.get_single() else { return; };
+    if recorder.frozen || kin.vel.length_squared() < 1e-4 {
+        return;
+    }
+    if recorder.path.last().is_some_and(|p| p.distance(t.translation) > SNAP_DISTANCE) {
+        recorder.frozen = true;
+        return;
+    }
+    // About a minute of flight at 60 FPS is plenty.
+    if recorder.path.len() < 4000 {
+        recorder.path.push(time.elapsed_seconds(), t.translation);
+    }
+}
+
+fn celebrate(
+    (cfg, loc, score): (Res<HoleInOneConfig>, Res<Localization>, Res<Score>),
+    recorder: Res<ShotRecorder>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+    mut ev_hole_in_one: EventWriter<HoleInOneEvent>,
+    (mut toasts, mut log): (ResMut<ToastQueue>, Option<ResMut<EventLog>>),
+    mut replay: ResMut<InstantReplay>,
+    mut commands: Commands,
+    (mut meshes, mut materials): (ResMut<Assets<Mesh>>, ResMut<Assets<StandardMaterial>>),
+    mode: Res<CameraMode>,
+) {
+    let Some(hit) = ev_hit.read().filter(|e| is_hole_in_one(e.kind, e.strokes)).last() else { return; };
+    ev_hole_in_one.send(HoleInOneEvent { pos: hit.pos, hole: score.hits });
+    info!("HOLE_IN_ONE hole={}", score.hits);
+    toasts.push_for(loc.t("hole_in_one.toast"), ToastKind::Success, 4.0);
+    if let Some(log) = log.as_mut() {
+        log.push(LogKind::Record, loc.tf("log.hole_in_one", &[("hole", score.hits.to_string())]));
+    }
+    if !cfg.replay || score.game_over || *mode != CameraMode::Orbit || replay.active() || recorder.path.len() < 2 {
+        return;
+    }
+    let path = recorder.path.tail(cfg.replay_window);
+    let start = path.at(0.0).unwrap_or(hit.pos);
+    let ghost = commands
+        .spawn(PbrBundle {
+            mesh: meshes.add(Sphere::new(0.5)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::srgb(1.0, 0.82, 0.25),
+                emissive: LinearRgba::rgb(2.0, 1.4, 0.3),
+                ..default()
+            }),
+            transform: Transform::from_translation(start),
+            ..default()
+        })
+        .id();
+    *replay = InstantReplay { path, elapsed: 0.0, ghost: Some(ghost) };
+}
+
+fn play_replay(
+    time: Res<Time>,
+    cfg: Res<HoleInOneConfig>,
+    mut buttons: ResMut<ButtonInput<MouseButton>>,
+    mut replay: ResMut<InstantReplay>,
+    mut mode: ResMut<CameraMode>,
+    mut actual: ResMut<CameraActual>,
+    mut commands: Commands,
+    mut q_ghost: Query<&mut Transform, Without<OrbitCamera>>,
+    mut q_cam: Query<&mut Transform, With<OrbitCamera>>,
+) {
+    let Some(ghost) = replay.ghost else { return; };
+    let Ok(mut cam) = q_cam.get_single_mut() else { return; };
+    let starting = replay.elapsed == 0.0;
+    if starting {
+        if *mode != CameraMode::Orbit {
+            replay.ghost = None;
+            commands.entity(ghost).despawn_recursive();
+            return;
+        }
+        *mode = CameraMode::Cinematic;
+    }
+    let dt = time.delta_seconds();
+    replay.elapsed += dt;
+    let skip = buttons.just_pressed(MouseButton::Left);
+    if skip {
+        // The skip click must not start charging a shot.
+        buttons.clear_just_pressed(MouseButton::Left);
+    }
+    let t = replay.elapsed * cfg.replay_speed;
+    if skip || t > replay.path.duration() + 0.3 || *mode != CameraMode::Cinematic {
+        replay.ghost = None;
+        commands.entity(ghost).despawn_recursive();
+        if *mode == CameraMode::Cinematic {
+            actual.actual = cam.translation;
+            *mode = CameraMode::Orbit;
+        }
+        return;
+    }
+    let Some(pos) = replay.path.at(t) else { return; };
+    if let Ok(mut g) = q_ghost.get_mut(ghost) {
+        g.translation = pos;
+    }
+    // Side-on to the shot, rising with the ball.
+    let (from, to) = (replay.path.at(0.0).unwrap_or(pos), replay.path.last().unwrap_or(pos));
+    let dir = (to - from).xz().try_normalize().unwrap_or(Vec2::X);
+    let side = Vec3::new(-dir.y, 0.0, dir.x);
+    let want = pos + side * cfg.camera_offset.x + Vec3::Y * cfg.camera_offset.y;
+    let k = if starting { 1.0 } else { (cfg.camera_follow * dt).min(1.0) };
+    cam.translation = cam.translation.lerp(want, k);
+    cam.look_at(pos, Vec3::Y);
+}
@@ -5,9 +5,17 @@ use bevy::render::render_asset::RenderAssetUsages;
 
 use crate::plugins::core_sim::SimState;
 use crate::plugins::ball::{BallKinematic, Ball};
+use crate::plugins::game_mode::hole_par;
 use crate::plugins::game_state::Score;
-use crate::plugins::target::Target;
-use crate::plugins::camera::OrbitCameraState;
+use crate::plugins::gates::GateProgress;
+use crate::plugins::particles::{BallGroundImpactEvent, BonusTargetSpawnedEvent, GatePassedEvent, ShotFiredEvent, TargetHitEvent, TrickShotEvent};
+use crate::plugins::trick_shots::trick_names;
+use crate::plugins::target::{Target, TargetFocus, TargetKind};
+use crate::plugins::camera::{CameraMode, OrbitCameraState};
+use crate::plugins::hud_layout::HudAnchor;
+use crate::plugins::localization::Localization;
+use crate::plugins::level::LevelDef;
+use crate::plugins::ui_theme::{HighContrastText, UiTheme};
 use bevy::window::PrimaryWindow;
 
 #[derive(Component)]
@@ -16,6 +24,23 @@ pub struct Hud;
 #[derive(Resource, Default)]
 pub struct MobileHudHint(pub bool);
 
+// Centered banner for short gameplay announcements (decoy / golden target).
+#[derive(Component)]
+pub struct HudAnnouncement;
+
+#[derive(Resource, Default)]
+pub struct HudAnnouncementState {
+    pub text: String,
+    pub remaining: f32,
+}
+
+impl HudAnnouncementState {
+    pub fn show(&mut self, text: impl Into<String>, seconds: f32) {
+        self.text = text.into();
+        self.remaining = seconds;
+    }
+}
+
 // ---------------- Compass (graphics) ----------------
 #[derive(Component)]
 pub struct CompassRoot;
@@ -23,21 +48,93 @@ pub struct CompassRoot;
 pub struct CompassTargetMarker;
 #[derive(Component)]
 pub struct CompassDistanceText;
+#[derive(Component)]
+struct CompassRing;
+
+/// Point of interest drawn on the compass ring.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompassMarker {
+    Landing,      // first touchdown after the last shot
+    Tee,          // where the current hole was started from
+    Bonus(usize), // n-th live golden target
+}
+
+pub const COMPASS_RADIUS: f32 = 70.0;
+const COMPASS_BONUS_SLOTS: usize = 3;
+
+/// Ring tuning: points closer than `range` sit inside the ring, farther ones are clamped to it.
+#[derive(Resource, Debug, Clone)]
+pub struct CompassConfig {
+    pub range: f32, // m mapped to the ring edge
+}
+impl Default for CompassConfig {
+    fn default() -> Self {
+        Self { range: 250.0 }
+    }
+}
+
+/// Remembered points shown on the compass besides the target.
+#[derive(Resource, Debug, Default)]
+pub struct CompassPoints {
+    pub landing: Option<Vec3>,
+    pub tee: Option<Vec3>,
+    awaiting_landing: bool,
+}
+
+/// Ground distance (m) from the ball to the target, as shown by the compass.
+pub fn compass_distance(ball: Vec3, target: Vec3) -> f32 {
+    let d = target - ball;
+    Vec2::new(d.x, d.z).length()
+}
+
+/// Compass-local position of `poi` seen from `ball` with the camera looking along `yaw`: straight
+/// ahead is up, clockwise is right. Distances map linearly onto `radius` up to `range`, beyond
+/// which the marker is clamped to the ring edge (`range` 0 = always on the edge). `None` when the point is (almost) on the ball.
+pub fn compass_marker_offset(yaw: f32, ball: Vec3, poi: Vec3, radius: f32, range: f32) -> Option<Vec2> {
+    let dist = compass_distance(ball, poi);
+    if dist < 0.001 {
+        return None;
+    }
+    let to = poi - ball;
+    let dir = Vec3::new(to.x, 0.0, to.z) / dist;
+    // Camera forward in (x,z); yaw rotates the orbit offset, forward is its negation.
+    let forward = Vec3::new(-yaw.sin(), 0.0, -yaw.cos());
+    // Signed relative angle (forward = 0, right = +PI/2)
+    let dot = forward.dot(dir).clamp(-1.0, 1.0);
+    let cross_y = forward.x * dir.z - forward.z * dir.x;
+    let rel_angle = cross_y.atan2(dot);
+    let r = radius * (dist / range.max(0.001)).min(1.0);
+    Some(Vec2::new(rel_angle.sin(), rel_angle.cos()) * r)
+}
 
 pub struct HudPlugin;
 impl Plugin for HudPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(MobileHudHint::default())
+            .init_resource::<HudAnnouncementState>()
+            .init_resource::<CompassConfig>()
+            .init_resource::<CompassPoints>()
             .add_systems(Startup, (spawn_hud_text, spawn_compass_graphics))
-            .add_systems(Update, (detect_mobile_hint, update_hud, update_compass_graphics));
+            .add_systems(
+                Update,
+                (
+                    detect_mobile_hint,
+                    update_hud,
+                    track_compass_points,
+                    update_compass_graphics.after(track_compass_points),
+                    announce_special_targets,
+                    update_hud_announcement.after(announce_special_targets),
+                    apply_compass_theme,
+                ),
+            );
     }
 }
 
-fn spawn_hud_text(mut commands: Commands, assets: Res<AssetServer>) {
+fn spawn_hud_text(mut commands: Commands, assets: Res<AssetServer>, loc: Res<Localization>) {
     let font = assets.load("fonts/FiraSans-Bold.ttf");
     commands.spawn((
         TextBundle::from_section(
-            "Initializing...",
+            loc.t("hud.initializing"),
             TextStyle { font: font.clone(), font_size: 22.0, color: Color::WHITE },
         )
         .with_style(Style {
@@ -46,10 +143,85 @@ fn spawn_hud_text(mut commands: Commands, assets: Res<AssetServer>) {
             top: Val::Px(8.0),
             ..default()
         }),
+        HudAnchor::top_left(12.0, 8.0),
         Hud,
+        HighContrastText,
+    ));
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle { font, font_size: 34.0, color: Color::srgb(1.0, 0.85, 0.3) },
+        )
+        .with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(18.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        }),
+        HudAnnouncement,
     ));
 }
 
+fn announce_special_targets(
+    loc: Res<Localization>,
+    mut state: ResMut<HudAnnouncementState>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+    mut ev_bonus: EventReader<BonusTargetSpawnedEvent>,
+    mut ev_gate: EventReader<GatePassedEvent>,
+    mut ev_trick: EventReader<TrickShotEvent>,
+) {
+    for e in ev_gate.read() {
+        if e.passed == e.total {
+            state.show(loc.t("announce.gates_cleared"), 2.5);
+        } else {
+            state.show(loc.tf("announce.gate", &[("passed", e.passed.to_string()), ("total", e.total.to_string())]), 1.5);
+        }
+    }
+    for e in ev_bonus.read() {
+        state.show(loc.tf("announce.golden_spawned", &[("secs", format!("{:.0}", e.lifetime))]), 2.5);
+    }
+    for e in ev_hit.read() {
+        match e.kind {
+            TargetKind::Decoy => state.show(loc.tf("announce.decoy", &[("secs", format!("{:.1}", e.time_delta))]), 2.0),
+            TargetKind::Bonus => state.show(loc.tf("announce.golden_hit", &[("secs", format!("{:.1}", e.time_delta))]), 2.0),
+            TargetKind::Standard if e.combo >= 2 => state.show(
+                loc.tf("announce.combo", &[("combo", e.combo.to_string()), ("points", e.points.to_string())]),
+                2.0,
+            ),
+            TargetKind::Standard => {}
+        }
+    }
+    // Sent with the hit: the trick callout takes over from the combo one.
+    for e in ev_trick.read() {
+        let tricks = trick_names(&e.tricks, &loc);
+        let text = if e.bonus > 0.0 {
+            loc.tf("announce.trick", &[("tricks", tricks), ("secs", format!("{:.1}", e.bonus))])
+        } else {
+            loc.tf("announce.trick_no_bonus", &[("tricks", tricks)])
+        };
+        state.show(text, 2.5);
+    }
+}
+
+fn update_hud_announcement(
+    time: Res<Time>,
+    mut state: ResMut<HudAnnouncementState>,
+    mut q_text: Query<&mut Text, With<HudAnnouncement>>,
+) {
+    let Ok(mut text) = q_text.get_single_mut() else { return; };
+    if state.remaining > 0.0 {
+        state.remaining -= time.delta_seconds();
+    }
+    let shown = if state.remaining > 0.0 { state.text.as_str() } else { "" };
+    if text.sections[0].value != shown {
+        text.sections[0].value = shown.to_string();
+    }
+    // Fade over the last half second.
+    text.sections[0].style.color.set_alpha((state.remaining * 2.0).clamp(0.0, 1.0));
+}
+
 // Build a simple filled circle (triangle fan)
 fn build_circle_mesh(radius: f32, segments: usize) -> Mesh {
     use bevy::render::mesh::{Indices, PrimitiveTopology};
@@ -91,6 +263,8 @@ fn spawn_compass_graphics(
     q_cam2d: Query<Entity, With<Camera2d>>,
     q_win: Query<&Window, With<PrimaryWindow>>,
     assets: Res<AssetServer>,
+    loc: Res<Localization>,
+    theme: Res<UiTheme>,
 ) {
     // 2D camera overlay (only if none)
     if q_cam2d.iter().next().is_none() {
@@ -107,17 +281,23 @@ fn spawn_compass_graphics(
 
     let Ok(win) = q_win.get_single() else { return; };
 
-    let radius = 70.0;
+    let radius = COMPASS_RADIUS;
     let margin = 90.0;
     // Screen space (0,0) at center for 2D camera; place compass top-left
+    // (hud_layout re-places it for UI scale, safe area and window resizes)
     let screen_x = -win.width() * 0.5 + margin;
     let screen_y = win.height() * 0.5 - margin;
 
     let circle_mesh = meshes.add(build_circle_mesh(radius, 64));
-    let circle_mat = materials.add(Color::srgba(1.0, 1.0, 1.0, 0.07));
+    let colors = theme.compass_colors();
+    let circle_mat = materials.add(colors.ring);
     // removed forward line (not needed)
     let target_mesh = meshes.add(build_circle_mesh(6.0, 24));
-    let target_mat = materials.add(Color::srgb(0.95, 0.2, 0.2));
+    let target_mat = materials.add(colors.target);
+    let poi_mesh = meshes.add(build_circle_mesh(4.5, 20));
+    let landing_mat = materials.add(colors.landing);
+    let tee_mat = materials.add(colors.tee);
+    let bonus_mat = materials.add(colors.bonus);
 
     let root = commands
         .spawn((
@@ -138,6 +318,7 @@ fn spawn_compass_graphics(
                 transform: Transform::from_translation(Vec3::new(0.0, 0.0, 0.0)),
                 ..default()
             },
+            CompassRing,
         ));
         // Target marker (will be positioned each frame)
         p.spawn((
@@ -149,11 +330,27 @@ fn spawn_compass_graphics(
             },
             CompassTargetMarker,
         ));
+        // Secondary points of interest (positioned / hidden each frame), under the target marker
+        let pois = [(CompassMarker::Landing, landing_mat.clone()), (CompassMarker::Tee, tee_mat.clone())]
+            .into_iter()
+            .chain((0..COMPASS_BONUS_SLOTS).map(|i| (CompassMarker::Bonus(i), bonus_mat.clone())));
+        for (marker, material) in pois {
+            p.spawn((
+                MaterialMesh2dBundle {
+                    mesh: poi_mesh.clone().into(),
+                    material,
+                    transform: Transform::from_translation(Vec3::new(0.0, 0.0, 0.8)),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                marker,
+            ));
+        }
         // Distance text (2D)
         p.spawn((
             Text2dBundle {
                 text: Text::from_section(
-                    "Dist: --.-m",
+                    loc.tf("hud.distance", &[("dist", "--.-".to_string())]),
                     TextStyle {
                         font: assets.load("fonts/FiraSans-Bold.ttf"),
                         font_size: 16.0,
@@ -188,95 +385,185 @@ fn detect_mobile_hint(
 fn update_hud(
     sim: Res<SimState>,
     score: Res<Score>,
+    gates: Res<GateProgress>,
+    camera_mode: Option<Res<CameraMode>>,
     hint: Res<MobileHudHint>,
+    loc: Res<Localization>,
+    level: Option<Res<LevelDef>>,
     q_ball: Query<&BallKinematic>,
     mut q_text: Query<&mut Text, With<Hud>>,
 ) {
     if let (Ok(kin), Ok(mut text)) = (q_ball.get_single(), q_text.get_single_mut()) {
         let speed = kin.vel.length();
         if score.game_over {
-            let avg_time = score.final_time / score.hits.max(1) as f32;
-            let avg_shots = score.shots as f32 / score.hits.max(1) as f32;
-            let best = score.high_score_time.map(|v| format!("{:.2}s", v)).unwrap_or_else(|| "--".to_string());
-            let mut base = format!(
-                "GAME OVER | Time: {:.2}s | Best: {best} | Holes: {} | Shots: {} | Avg T/H: {:.2}s | Avg S/H: {:.2} | Press R",
-                score.final_time,
-                score.hits,
-                score.shots,
-                avg_time,
-                avg_shots,
-            );
+            // Breakdown and Restart / Main Menu live in the game-over panel.
+            let key = if score.failed { "hud.round_lost" } else { "hud.game_over" };
+            let mut base = loc.tf(key, &[("time", format!("{:.2}", score.final_time))]);
             if hint.0 {
-                base.push_str("\nMobile: Hold + release to shoot | Swipe to look | Pinch to zoom");
+                base.push('\n');
+                base.push_str(loc.t("hud.mobile_hint"));
             }
             text.sections[0].value = base;
         } else {
             let current_hole = score.hits + 1;
-            let avg_time = if score.hits > 0 { sim.elapsed_seconds / score.hits as f32 } else { 0.0 };
+            let run_time = score.run_time(sim.elapsed_seconds);
+            let avg_time = if score.hits > 0 { run_time / score.hits as f32 } else { 0.0 };
             let avg_shots = if score.hits > 0 { score.shots as f32 / score.hits as f32 } else { 0.0 };
-            let mut base = format!(
-                "Time: {:.2}s | Speed: {:.2} m/s | Hole: {}/{} | Shots: {} | Avg T/H: {:.2}s | Avg S/H: {:.2}",
-                sim.elapsed_seconds,
-                speed,
-                current_hole,
-                score.max_holes,
-                score.shots,
-                avg_time,
-                avg_shots,
+            let mut base = loc.tf(
+                "hud.status",
+                &[
+                    ("time", format!("{run_time:.2}")),
+                    ("speed", format!("{speed:.2}")),
+                    ("hole", current_hole.to_string()),
+                    ("holes", score.max_holes.to_string()),
+                    ("points", score.points.to_string()),
+                    ("shots", score.shots.to_string()),
+                    ("avg_time", format!("{avg_time:.2}")),
+                    ("avg_shots", format!("{avg_shots:.2}")),
+                ],
             );
+            if let Some(hole) = level.as_ref().and_then(|l| l.hole(score.hits as usize)) {
+                base.push_str(" | ");
+                base.push_str(&loc.tf("hud.par", &[("par", hole.par.to_string())]));
+            }
+            // The figure the game mode is played for leads the HUD.
+            let hole_strokes = score.shots.saturating_sub(score.shots_at_last_hit);
+            let par = hole_par(level.as_deref(), score.hits);
+            if let Some(focus) = score.mode.rules().hud_focus(&loc, &score, hole_strokes, par) {
+                base = format!("{focus}\n{base}");
+            }
+            if score.combo >= 2 {
+                base.push_str(" | ");
+                base.push_str(&loc.tf(
+                    "hud.combo",
+                    &[("mult", score.combo_multiplier().to_string()), ("secs", format!("{:.0}", score.combo_timer.ceil()))],
+                ));
+            }
+            let camera_hint = match camera_mode.as_deref() {
+                Some(CameraMode::Free) => Some("hud.free_cam"),
+                Some(CameraMode::Cinematic) => Some("hud.cinematic"),
+                Some(CameraMode::BallCam) => Some("hud.ball_cam"),
+                _ => None,
+            };
+            if let Some(key) = camera_hint {
+                base.push_str(" | ");
+                base.push_str(loc.t(key));
+            }
+            if gates.total() > 0 {
+                let key = if gates.complete() { "hud.gates" } else { "hud.gates_locked" };
+                base.push_str(" | ");
+                base.push_str(&loc.tf(key, &[("count", gates.count().to_string()), ("total", gates.total().to_string())]));
+            }
             if hint.0 {
-                base.push_str("\nMobile: Hold + release to shoot | Swipe to look | Pinch to zoom");
+                base.push('\n');
+                base.push_str(loc.t("hud.mobile_hint"));
             }
             text.sections[0].value = base;
         }
     }
 }
 
+/// Recolor the compass when the palette or contrast setting changes.
+fn apply_compass_theme(
+    theme: Res<UiTheme>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    q: Query<(&Handle<ColorMaterial>, Option<&CompassMarker>, Has<CompassTargetMarker>, Has<CompassRing>)>,
+) {
+    if !theme.is_changed() || theme.is_added() {
+        return;
+    }
+    let colors = theme.compass_colors();
+    for (handle, marker, is_target, is_ring) in &q {
+        let color = match (marker, is_target, is_ring) {
+            (_, _, true) => colors.ring,
+            (_, true, _) => colors.target,
+            (Some(CompassMarker::Landing), ..) => colors.landing,
+            (Some(CompassMarker::Tee), ..) => colors.tee,
+            (Some(CompassMarker::Bonus(_)), ..) => colors.bonus,
+            _ => continue,
+        };
+        if let Some(mat) = materials.get_mut(handle) {
+            mat.color = color;
+        }
+    }
+}
+
+fn track_compass_points(
+    score: Res<Score>,
+    mut points: ResMut<CompassPoints>,
+    mut ev_shot: EventReader<ShotFiredEvent>,
+    mut ev_impact: EventReader<BallGroundImpactEvent>,
+    mut last_hits: Local<Option<u32>>,
+) {
+    if *last_hits != Some(score.hits) {
+        // New hole: the next shot is its tee shot; a new run also forgets the old landing spot.
+        if score.hits == 0 {
+            points.landing = None;
+        }
+        points.tee = None;
+        *last_hits = Some(score.hits);
+    }
+    for e in ev_shot.read() {
+        if points.tee.is_none() {
+            points.tee = Some(e.pos);
+        }
+        points.awaiting_landing = true;
+    }
+    for e in ev_impact.read() {
+        if points.awaiting_landing {
+            points.landing = Some(e.pos);
+            points.awaiting_landing = false;
+        }
+    }
+}
+
 fn update_compass_graphics(
     score: Res<Score>,
+    loc: Res<Localization>,
+    cfg: Res<CompassConfig>,
+    points: Res<CompassPoints>,
     state: Option<Res<OrbitCameraState>>,
+    focus: Res<TargetFocus>,
     q_ball_t: Query<&Transform, With<Ball>>,
-    q_target_t: Query<&Transform, (With<Target>, Without<Ball>, Without<CompassTargetMarker>)>,
-    mut q_marker: Query<&mut Transform, (With<CompassTargetMarker>, Without<Target>, Without<Ball>)>,
+    q_target_t: Query<(&Transform, Option<&TargetKind>), (With<Target>, Without<Ball>, Without<CompassTargetMarker>, Without<CompassMarker>)>,
+    mut q_marker: Query<&mut Transform, (With<CompassTargetMarker>, Without<Target>, Without<Ball>, Without<CompassMarker>)>,
+    mut q_pois: Query<(&CompassMarker, &mut Transform, &mut Visibility), (Without<Target>, Without<Ball>)>,
     mut q_dist_text: Query<&mut Text, With<CompassDistanceText>>,
 ) {
     if score.game_over {
         return;
     }
-    let (Some(state), Ok(ball_t), Ok(target_t)) =
-        (state, q_ball_t.get_single(), q_target_t.get_single()) else { return; };
-
-    let Ok(mut marker_t) = q_marker.get_single_mut() else { return; };
-    let Ok(mut dist_text) = q_dist_text.get_single_mut() else { return; };
+    let (Some(state), Ok(ball_t)) = (state, q_ball_t.get_single()) else { return; };
+    let ball = ball_t.translation;
+    let place = |poi: Vec3| compass_marker_offset(state.yaw, ball, poi, COMPASS_RADIUS, cfg.range);
 
-    let to_target = target_t.translation - ball_t.translation;
-    let horiz = Vec3::new(to_target.x, 0.0, to_target.z);
-    let dist = horiz.length();
-    if dist < 0.001 {
-        dist_text.sections[0].value = "Dist: 0.0m".to_string();
-        marker_t.translation = Vec3::new(0.0, 0.0, marker_t.translation.z);
-        return;
+    let bonus: Vec<Vec3> = q_target_t
+        .iter()
+        .filter(|(_, kind)| kind.is_some_and(|k| *k == TargetKind::Bonus))
+        .map(|(t, _)| t.translation)
+        .collect();
+    for (marker, mut t, mut vis) in &mut q_pois {
+        let poi = match *marker {
+            CompassMarker::Landing => points.landing,
+            CompassMarker::Tee => points.tee,
+            CompassMarker::Bonus(i) => bonus.get(i).copied(),
+        };
+        match poi.and_then(place) {
+            Some(p) => {
+                t.translation = p.extend(t.translation.z);
+                *vis = Visibility::Inherited;
+            }
+            None => *vis = Visibility::Hidden,
+        }
     }
-    let dir = horiz / dist;
-
-    // Camera forward vector (see orbit implementation: position offset uses yaw around Y then pitch; yaw rotation means offset Z component is cos(yaw) along +Z, but forward is -sin(yaw), -cos(yaw) in (x,z))
-    let forward = Vec3::new(-state.yaw.sin(), 0.0, -state.yaw.cos()).normalize();
-
-    // Signed relative angle (forward = 0, right = +PI/2)
-    let dot = forward.dot(dir).clamp(-1.0, 1.0);
-    let cross_y = forward.x * dir.z - forward.z * dir.x;
-    let rel_angle = cross_y.atan2(dot); // (-PI, PI]
-
-    // Radius derived from circle child (root has children at local (0,0))
-    // We stored forward marker line length ~0.9R; just reuse distance between root and forward marker tip:
-    let radius = 70.0;
 
-    // Map angle to circle coordinates: 0 at top, positive clockwise
-    // Using x = sin(angle), y = cos(angle) as earlier reasoning
-    let x = rel_angle.sin() * radius;
-    let y = rel_angle.cos() * radius;
-    marker_t.translation = Vec3::new(x, y, marker_t.translation.z);
-
-    dist_text.sections[0].value = format!("Dist: {:.1}m", dist);
+    let Some((target_t, _)) = focus.entity.and_then(|e| q_target_t.get(e).ok()) else { return; };
+    let Ok(mut marker_t) = q_marker.get_single_mut() else { return; };
+    let Ok(mut dist_text) = q_dist_text.get_single_mut() else { return; };
 
+    // The target always sits on the ring so its bearing stays readable.
+    let dist = compass_distance(ball, target_t.translation);
+    let p = compass_marker_offset(state.yaw, ball, target_t.translation, COMPASS_RADIUS, 0.0).unwrap_or(Vec2::ZERO);
+    marker_t.translation = p.extend(marker_t.translation.z);
+    dist_text.sections[0].value = loc.tf("hud.distance", &[("dist", format!("{dist:.1}"))]);
 }
@@ -0,0 +1,145 @@
+// HUD scaling and safe-area layout.
+// Bevy's `UiScale` multiplies every `Val::Px` in the UI tree; it is set from the user HUD scale
+// (settings panel slider) times an automatic factor from the window's logical size, so the HUD
+// keeps its proportions from phones up to 4k. Screen-edge elements carry a `HudAnchor` with their
+// unscaled edge offsets; the layout pass re-applies them plus the safe-area insets (notches,
+// rounded corners, TV overscan). Insets are in window pixels, so they are divided by the UI scale
+// before landing in `Val::Px`. The 2D compass is not part of the UI tree and is placed here too.
+
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowResized};
+
+use crate::plugins::hud::CompassRoot;
+
+/// Insets from each window edge kept clear of HUD elements (logical window pixels).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SafeAreaInsets {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct HudLayoutConfig {
+    pub scale: f32,                // user HUD scale (settings slider)
+    pub scale_range: (f32, f32),
+    pub auto_scale: bool,          // also scale with the window size
+    pub reference_size: Vec2,      // logical window size the HUD was laid out for
+    pub auto_range: (f32, f32),    // clamp of the automatic factor
+    pub safe_area: SafeAreaInsets,
+    pub compass_margin: f32,       // px from the top-left corner to the compass centre (unscaled)
+    pub compass_zoom: f32,         // extra compass size on top of the HUD scale (spectate.rs)
+}
+impl Default for HudLayoutConfig {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            scale_range: (0.5, 2.5),
+            auto_scale: true,
+            reference_size: Vec2::new(1920.0, 1080.0),
+            auto_range: (0.6, 2.0),
+            safe_area: SafeAreaInsets::default(),
+            compass_margin: 90.0,
+            compass_zoom: 1.0,
+        }
+    }
+}
+
+impl HudLayoutConfig {
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale.clamp(self.scale_range.0, self.scale_range.1);
+    }
+}
+
+/// Final `UiScale` for a window of `logical` size.
+pub fn effective_ui_scale(cfg: &HudLayoutConfig, logical: Vec2) -> f32 {
+    let auto = if cfg.auto_scale && logical.x > 0.0 && logical.y > 0.0 {
+        let fit = logical / cfg.reference_size;
+        fit.x.min(fit.y).clamp(cfg.auto_range.0, cfg.auto_range.1)
+    } else {
+        1.0
+    };
+    cfg.scale * auto
+}
+
+/// `Val::Px` for an element `base` px (unscaled) from an edge with a safe-area `inset` in window
+/// pixels, under UI scale `scale`.
+pub fn anchored_offset(base: f32, inset: f32, scale: f32) -> Val {
+    Val::Px(base + inset / scale.max(0.01))
+}
+
+/// Unscaled offsets of a screen-edge HUD element (`None` = edge not anchored).
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct HudAnchor {
+    pub top: Option<f32>,
+    pub right: Option<f32>,
+    pub bottom: Option<f32>,
+    pub left: Option<f32>,
+}
+
+impl HudAnchor {
+    pub fn top_left(left: f32, top: f32) -> Self {
+        Self { top: Some(top), left: Some(left), ..default() }
+    }
+    pub fn top_right(right: f32, top: f32) -> Self {
+        Self { top: Some(top), right: Some(right), ..default() }
+    }
+    pub fn bottom_left(left: f32, bottom: f32) -> Self {
+        Self { bottom: Some(bottom), left: Some(left), ..default() }
+    }
+    pub fn bottom_right(right: f32, bottom: f32) -> Self {
+        Self { bottom: Some(bottom), right: Some(right), ..default() }
+    }
+}
+
+pub struct HudLayoutPlugin;
+impl Plugin for HudLayoutPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HudLayoutConfig>().add_systems(Update, apply_hud_layout);
+    }
+}
+
+fn apply_hud_layout(
+    cfg: Res<HudLayoutConfig>,
+    mut ui_scale: ResMut<UiScale>,
+    mut ev_resize: EventReader<WindowResized>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    mut q_anchored: Query<(Ref<HudAnchor>, &mut Style)>,
+    mut q_compass: Query<&mut Transform, With<CompassRoot>>,
+) {
+    let resized = ev_resize.read().count() > 0;
+    let new_elements = q_anchored.iter().any(|(a, _)| a.is_added());
+    let Ok(window) = q_window.get_single() else { return; };
+    if !(resized || new_elements || cfg.is_changed()) {
+        return;
+    }
+    let size = Vec2::new(window.width(), window.height());
+    let scale = effective_ui_scale(&cfg, size);
+    if (ui_scale.0 - scale).abs() > 1e-4 {
+        ui_scale.0 = scale;
+    }
+    let inset = cfg.safe_area;
+    for (anchor, mut style) in &mut q_anchored {
+        if let Some(v) = anchor.top {
+            style.top = anchored_offset(v, inset.top, scale);
+        }
+        if let Some(v) = anchor.right {
+            style.right = anchored_offset(v, inset.right, scale);
+        }
+        if let Some(v) = anchor.bottom {
+            style.bottom = anchored_offset(v, inset.bottom, scale);
+        }
+        if let Some(v) = anchor.left {
+            style.left = anchored_offset(v, inset.left, scale);
+        }
+    }
+    // Compass: 2D world space, origin at the window centre, in logical pixels.
+    let compass_scale = scale * cfg.compass_zoom;
+    for mut t in &mut q_compass {
+        let margin = cfg.compass_margin * compass_scale;
+        t.translation.x = -size.x * 0.5 + margin + inset.left;
+        t.translation.y = size.y * 0.5 - margin - inset.top;
+        t.scale = Vec3::new(compass_scale, compass_scale, 1.0);
+    }
+}
@@ -0,0 +1,208 @@
+// Landing reticle.
+// While a shot is charging, the flight is simulated with the ball's own integrator (same step,
+// gravity and contact test as `ball_physics`) against the terrain heightfield, and a reticle is
+// laid on the ground where the ball would first touch down. It sits in the slope's plane and is
+// stretched along the fall line on steep ground (where the ball will also run off further), and
+// its color tells the surface: meadow, forest, highland, wetland or water (= respawn).
+
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::biome::Biome;
+use crate::plugins::camera::OrbitCamera;
+use crate::plugins::game_state::{ShotConfig, ShotMode, ShotState};
+use crate::plugins::shooting::{shot_direction, shot_power_scale, AimPoint};
+use crate::plugins::terrain::TerrainSampler;
+
+const WATER_LEVEL: f32 = 25.0; // same plane ball.rs respawns below
+const SIM_DT: f32 = 1.0 / 60.0;
+
+#[derive(Resource, Debug, Clone)]
+pub struct LandingReticleConfig {
+    pub enabled: bool,
+    pub radius: f32,        // m on flat ground
+    pub max_stretch: f32,   // cap on the slope elongation
+    pub max_flight: f32,    // s simulated before giving up
+    pub lift: f32,          // m above the surface (avoids z-fighting)
+}
+impl Default for LandingReticleConfig {
+    fn default() -> Self {
+        Self { enabled: true, radius: 1.6, max_stretch: 2.5, max_flight: 12.0, lift: 0.06 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LandingSurface {
+    Meadow,
+    Forest,
+    Highland,
+    Wetland,
+    Water,
+}
+
+impl LandingSurface {
+    pub fn classify(ground_height: f32, biome: Biome) -> Self {
+        if ground_height < WATER_LEVEL {
+            return Self::Water;
+        }
+        match biome {
+            Biome::Meadow => Self::Meadow,
+            Biome::Forest => Self::Forest,
+            Biome::Highland => Self::Highland,
+            Biome::Wetland => Self::Wetland,
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            Self::Meadow => Color::srgb(0.45, 1.0, 0.45),
+            Self::Forest => Color::srgb(1.0, 0.7, 0.25),
+            Self::Highland => Color::srgb(0.9, 0.9, 1.0),
+            Self::Wetland => Color::srgb(0.3, 0.9, 0.85),
+            Self::Water => Color::srgb(1.0, 0.25, 0.25),
+        }
+    }
+}
+
+/// First touchdown of a ball launched from `origin` with `vel`, stepping like `ball_physics`
+/// (gravity, then position, contact when the ball centre is within `radius` of the ground on
+/// the way down). Returns the contact point on the ground.
+pub fn predict_landing(
+    origin: Vec3,
+    vel: Vec3,
+    radius: f32,
+    max_time: f32,
+    height: impl Fn(f32, f32) -> f32,
+) -> Option<Vec3> {
+    let g = -9.81;
+    let (mut p, mut v) = (origin, vel);
+    let steps = (max_time / SIM_DT).ceil() as usize;
+    for _ in 0..steps {
+        let prev = p;
+        v.y += g * SIM_DT;
+        p += v * SIM_DT;
+        let ground = height(p.x, p.z);
+        if v.y < 0.0 && p.y <= ground + radius {
+            // Interpolate within the step to where the ball crossed the surface.
+            let prev_gap = prev.y - (height(prev.x, prev.z) + radius);
+            let gap = p.y - (ground + radius);
+            let t = if prev_gap > gap { (prev_gap / (prev_gap - gap)).clamp(0.0, 1.0) } else { 1.0 };
+            let hit = prev.lerp(p, t);
+            return Some(Vec3::new(hit.x, height(hit.x, hit.z), hit.z));
+        }
+    }
+    None
+}
+
+/// Elongation along the fall line for a surface with normal `n` (1 on flat ground).
+pub fn slope_stretch(n: Vec3, max_stretch: f32) -> f32 {
+    (1.0 / n.y.max(1e-3)).min(max_stretch.max(1.0))
+}
+
+/// Rotation laying the reticle (modelled in the XZ plane) in the plane with normal `n`, local X
+/// pointing up the fall line.
+fn slope_rotation(n: Vec3) -> Quat {
+    let uphill = Vec3::Y.reject_from(n).normalize_or_zero();
+    let x = if uphill == Vec3::ZERO { Vec3::X } else { uphill };
+    let z = x.cross(n).normalize_or_zero();
+    Quat::from_mat3(&Mat3::from_cols(x, n, z))
+}
+
+#[derive(Component)]
+struct LandingReticle;
+
+pub struct LandingReticlePlugin;
+impl Plugin for LandingReticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LandingReticleConfig>()
+            .add_systems(Startup, spawn_landing_reticle)
+            .add_systems(Update, update_landing_reticle);
+    }
+}
+
+fn spawn_landing_reticle(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let flat = Quat::from_rotation_x(-FRAC_PI_2); // XY-plane primitives -> XZ plane
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgba(1.0, 1.0, 1.0, 0.85),
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        double_sided: true,
+        cull_mode: None,
+        ..default()
+    });
+    commands
+        .spawn((
+            PbrBundle {
+                mesh: meshes.add(Mesh::from(Annulus::new(0.82, 1.0)).rotated_by(flat)),
+                material: material.clone(),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            bevy::pbr::NotShadowCaster,
+            LandingReticle,
+            Name::new("LandingReticle"),
+        ))
+        .with_children(|r| {
+            r.spawn((
+                PbrBundle {
+                    mesh: meshes.add(Mesh::from(Circle::new(0.14)).rotated_by(flat)),
+                    material,
+                    ..default()
+                },
+                bevy::pbr::NotShadowCaster,
+            ));
+        });
+}
+
+fn update_landing_reticle(
+    time: Res<Time>,
+    cfg: Res<LandingReticleConfig>,
+    state: Res<ShotState>,
+    shot_cfg: Res<ShotConfig>,
+    aim: Res<AimPoint>,
+    sampler: Option<Res<TerrainSampler>>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+    q_cam: Query<&Transform, (With<OrbitCamera>, Without<Ball>)>,
+    mut q_reticle: Query<
+        (&mut Transform, &mut Visibility, &Handle<StandardMaterial>),
+        (With<LandingReticle>, Without<Ball>, Without<OrbitCamera>),
+    >,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Ok((mut t, mut vis, mat)) = q_reticle.get_single_mut() else { return; };
+    let charging = cfg.enabled && state.mode == ShotMode::Charging;
+    let (Some(sampler), Ok((ball_t, kin)), Ok(cam_t), true) = (sampler, q_ball.get_single(), q_cam.get_single(), charging)
+    else {
+        *vis = Visibility::Hidden;
+        return;
+    };
+
+    // Same launch as handle_shot_input.
+    let dir = shot_direction(ball_t.translation, cam_t.translation, aim.pos, shot_cfg.up_angle_deg);
+    let power_scale = shot_power_scale(shot_cfg.charge(state.power));
+    let vel = kin.vel + dir * (shot_cfg.base_impulse * power_scale * shot_cfg.launch_boost());
+    let Some(hit) = predict_landing(ball_t.translation, vel, kin.collider_radius, cfg.max_flight, |x, z| sampler.height(x, z))
+    else {
+        *vis = Visibility::Hidden;
+        return;
+    };
+
+    let n = sampler.normal(hit.x, hit.z);
+    let stretch = slope_stretch(n, cfg.max_stretch);
+    t.translation = hit + n * cfg.lift;
+    t.rotation = slope_rotation(n);
+    t.scale = Vec3::new(cfg.radius * stretch, 1.0, cfg.radius);
+    *vis = Visibility::Visible;
+
+    let surface = LandingSurface::classify(sampler.height(hit.x, hit.z), sampler.biome(hit.x, hit.z));
+    if let Some(m) = materials.get_mut(mat) {
+        let pulse = 0.7 + 0.25 * (time.elapsed_seconds() * 6.0).sin();
+        m.base_color = surface.color().with_alpha(pulse);
+    }
+}
@@ -1,4 +1,7 @@
 // Level loading & world setup (camera, sky, walls, ball, target).
+// A level may define a course (`holes`): ordered tee / target / par entries, played in turn.
+// Levels are listed in levels/manifest.ron; the main menu picks one (remembered in the profile)
+// and Play swaps it in, heightmap included.
 use bevy::prelude::*;
 use bevy::render::mesh::{Indices, PrimitiveTopology};
 use bevy::render::render_asset::RenderAssetUsages;
@@ -8,11 +11,32 @@ use std::fs;
 use rand::Rng;
 
 use crate::plugins::camera::OrbitCamera;
+use crate::plugins::difficulty::DifficultyCurve;
+use crate::plugins::special_targets::SpecialTargetConfig;
 use crate::plugins::ball::{Ball, BallKinematic};
-use crate::plugins::main_menu::GamePhase;
-use crate::plugins::target::{Target, TargetFloat, TargetParams};
-use crate::plugins::game_state::{ShotConfig, Score};
-use crate::plugins::terrain::TerrainSampler;
+use crate::plugins::app_state::AppState;
+use crate::plugins::target::{Target, TargetBob, TargetFloat, TargetInfo, TargetMotion, TargetParams, TargetPath};
+use crate::plugins::game_state::{RestartGameEvent, ShotConfig, Score, DEFAULT_LEVEL_ID};
+use crate::plugins::terrain::{init_sampler, TerrainConfig, TerrainSampler};
+use crate::plugins::terrain_deform::TerrainDeformations;
+use crate::plugins::profile::Profile;
+use crate::plugins::user_settings::UserSettings;
+use crate::plugins::vegetation_exclusion::VegetationExclusionDef;
+use crate::plugins::gates::GateDef;
+use crate::plugins::campaign::MedalTimes;
+use crate::plugins::level_script::ScriptRule;
+use crate::plugins::daily::{generate_daily_course, DailyDate};
+use crate::plugins::save_game::RoundResumedEvent;
+use crate::plugins::cup_target::{clear_cup, CupState, TargetStyle};
+use crate::plugins::level_props::{spawn_prop, PropCollider};
+use crate::plugins::texture_pipeline::TexturePipeline;
+use crate::launch_options::LaunchOptions;
+use crate::plugins::challenge::ActiveChallenge;
+use crate::plugins::day_night::DayNightDef;
+use crate::plugins::obstacles::{ObstacleDef, TargetGuardDef};
+use crate::plugins::powerups::PowerupDef;
+use crate::plugins::weather::WeatherDef;
+use crate::plugins::rivers::RiverDef;
 
 // ----------------------- Level Definition (RON) -----------------------
 
@@ -49,11 +73,21 @@ pub struct FloatParams {
     pub collider_radius: f32,
 }
 
+fn one_point() -> u32 { 1 }
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct TargetDef {
     pub model: String,
     pub initial: TargetInitial,
     pub float: FloatParams,
+    #[serde(default = "one_point")]
+    pub points: u32, // score for hitting this target
+    // Optional flight path around the spawn point (waypoints / orbit / figure-eight).
+    #[serde(default)]
+    pub motion: Option<TargetPath>,
+    // Floating duck (default) or ground cup with flag.
+    #[serde(default)]
+    pub style: TargetStyle,
 }
 #[derive(Debug, Deserialize, Clone, Copy)]
 pub struct TargetInitial { pub x: f32, pub z: f32 }
@@ -79,6 +113,61 @@ pub struct Scoring {
     pub max_holes: u32,
 }
 
+fn default_par() -> u32 { 3 }
+fn unit_scale() -> f32 { 1.0 }
+
+/// Static model placed on the terrain (ground height + `height`), see level_props.rs.
+#[derive(Debug, Deserialize, Clone)]
+pub struct PropDef {
+    // Scene to show; empty: the collider shape is drawn instead (ramps, bounce pads).
+    #[serde(default)]
+    pub model: String,
+    pub x: f32,
+    pub z: f32,
+    #[serde(default)]
+    pub height: f32, // m above ground
+    #[serde(default)]
+    pub yaw_deg: f32,
+    #[serde(default)]
+    pub pitch_deg: f32,
+    #[serde(default)]
+    pub roll_deg: f32,
+    #[serde(default = "unit_scale")]
+    pub scale: f32,
+    // Ball collision shape in model units (none: purely decorative).
+    #[serde(default)]
+    pub collider: Option<PropCollider>,
+    // Bounce off the collider: restitution override and a kick along the contact normal (m/s).
+    #[serde(default)]
+    pub restitution: Option<f32>,
+    #[serde(default)]
+    pub boost: f32,
+    // Free-form label other systems can look for (`PropTag`).
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+/// Where a course hole's primary target sits (style falls back to `target.style`).
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct HoleTargetDef {
+    pub x: f32,
+    pub z: f32,
+    #[serde(default)]
+    pub style: Option<TargetStyle>,
+}
+
+/// One hole of a designed course: the ball starts at `tee`, the primary target waits at `target`.
+#[derive(Debug, Deserialize, Clone)]
+pub struct HoleDef {
+    pub tee: BallPos,
+    pub target: HoleTargetDef,
+    #[serde(default = "default_par")]
+    pub par: u32,
+    // Only present while this hole is being played.
+    #[serde(default)]
+    pub props: Vec<PropDef>,
+}
+
 #[derive(Debug, Deserialize, Resource)]
 pub struct LevelDef {
     pub camera_start: Vec3Def,
@@ -86,9 +175,253 @@ pub struct LevelDef {
     pub sky: SkyDef,
     pub ball: BallSpawn,
     pub target: TargetDef,
+    // Further targets live at the same time as `target` (compass follows TargetFocus).
+    #[serde(default)]
+    pub extra_targets: Vec<TargetDef>,
     pub world: WorldBounds,
     pub shot: ShotConfigDef,
     pub scoring: Scoring,
+    // Optional grayscale vegetation density mask over the heightmap square (path under assets/).
+    #[serde(default)]
+    pub vegetation_mask: Option<String>,
+    // Extra areas kept free of vegetation (ball spawn and targets are always excluded).
+    #[serde(default)]
+    pub vegetation_exclusions: Vec<VegetationExclusionDef>,
+    // How targets get harder as the run goes on (flat when omitted).
+    #[serde(default)]
+    pub difficulty: DifficultyCurve,
+    // Decoys and golden bonus targets (bonus targets on, no decoys when omitted).
+    #[serde(default)]
+    pub special_targets: SpecialTargetConfig,
+    // Ring gates the ball must fly through before the target counts (per hole).
+    #[serde(default)]
+    pub gates: Vec<GateDef>,
+    // Primary target style per hole (hole 1 first); holes past the list use `target.style`.
+    #[serde(default)]
+    pub hole_styles: Vec<TargetStyle>,
+    // Designed course, played in order. When present it replaces random target relocation:
+    // after each hole the ball moves to the next tee and the target to the next hole.
+    #[serde(default)]
+    pub holes: Vec<HoleDef>,
+    // Static models for the whole level (bridges, arches, windmills, decorations).
+    #[serde(default)]
+    pub props: Vec<PropDef>,
+    // Campaign medal finishing times (no medals when omitted).
+    #[serde(default)]
+    pub medals: Option<MedalTimes>,
+    // Scripted events: trigger -> actions (level_script.rs).
+    #[serde(default)]
+    pub scripts: Vec<ScriptRule>,
+    // Day / night cycle: start hour and day length (fixed midday lighting when omitted).
+    #[serde(default)]
+    pub day_night: Option<DayNightDef>,
+    // Weather: start weather and timed changes on the run clock (always clear when omitted).
+    #[serde(default)]
+    pub weather: Option<WeatherDef>,
+    // Rivers traced downhill from the high ground, with waterfalls (no rivers when omitted).
+    #[serde(default)]
+    pub rivers: Option<RiverDef>,
+    // Powerup pickups (kind and spot); a few are scattered along each hole when omitted.
+    #[serde(default)]
+    pub powerups: Vec<PowerupDef>,
+    // Breakable crates / ice panels (kind and spot).
+    #[serde(default)]
+    pub obstacles: Vec<ObstacleDef>,
+    // A row of breakable obstacles across the line from each hole's tee to its target (none when omitted).
+    #[serde(default)]
+    pub target_guard: Option<TargetGuardDef>,
+    // Daily Run seed: the course is generated once the terrain is sampled (daily.rs).
+    #[serde(skip)]
+    pub daily_seed: Option<u64>,
+}
+
+impl LevelDef {
+    pub fn is_course(&self) -> bool {
+        !self.holes.is_empty()
+    }
+
+    /// Hole `index` (0 = first) of the course, if any.
+    pub fn hole(&self, index: usize) -> Option<&HoleDef> {
+        self.holes.get(index)
+    }
+
+    /// Tee for hole `index`: the course tee, or the level's ball spawn.
+    pub fn tee(&self, index: usize) -> Vec2 {
+        let pos = self.hole(index).map_or(self.ball.pos, |h| h.tee);
+        Vec2::new(pos.x, pos.z)
+    }
+
+    /// Fold the course into the single-target fields the rest of the game reads: hole 1's tee and
+    /// target become the ball spawn and target home, the hole count the scoring limit, per-hole
+    /// styles `hole_styles`, and later tees are kept clear of vegetation.
+    pub fn apply_course(&mut self) {
+        let Some(first) = self.holes.first().cloned() else { return; };
+        self.ball.pos = first.tee;
+        self.target.initial = TargetInitial { x: first.target.x, z: first.target.z };
+        self.scoring.max_holes = self.holes.len() as u32;
+        self.hole_styles = self.holes.iter().map(|h| h.target.style.unwrap_or(self.target.style)).collect();
+        let tees: Vec<VegetationExclusionDef> = self
+            .holes
+            .iter()
+            .skip(1)
+            .map(|h| VegetationExclusionDef { points: vec![(h.tee.x, h.tee.z)], radius: COURSE_TEE_CLEARANCE })
+            .collect();
+        self.vegetation_exclusions.extend(tees);
+    }
+}
+
+const COURSE_TEE_CLEARANCE: f32 = 12.0; // m, like the ball spawn
+
+// ----------------------- Level Manifest (RON) -----------------------
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LevelDifficulty {
+    #[default]
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl LevelDifficulty {
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Self::Easy => "menu.difficulty.easy",
+            Self::Medium => "menu.difficulty.medium",
+            Self::Hard => "menu.difficulty.hard",
+        }
+    }
+}
+
+/// One selectable level. Paths are relative to assets/.
+#[derive(Debug, Deserialize, Clone)]
+pub struct LevelEntry {
+    pub id: String, // high scores and the remembered selection are keyed by this
+    pub name: String,
+    pub file: String,
+    #[serde(default)]
+    pub thumbnail: Option<String>,
+    pub heightmap: String,
+    #[serde(default)]
+    pub difficulty: LevelDifficulty,
+    // Level id that must be completed to unlock this one (default: the previous manifest entry).
+    #[serde(default)]
+    pub requires: Option<String>,
+    // Daily Run: a course generated from today's date on this entry's level / terrain.
+    #[serde(default)]
+    pub daily: bool,
+}
+
+impl LevelEntry {
+    /// Key best times and campaign progress are kept under (per day for the Daily Run).
+    pub fn score_id(&self) -> String {
+        if self.daily { DailyDate::today().key() } else { self.id.clone() }
+    }
+}
+
+/// levels/manifest.ron: the levels offered in the main menu, in menu order.
+#[derive(Debug, Deserialize, Clone, Resource)]
+pub struct LevelManifest {
+    pub levels: Vec<LevelEntry>,
+    #[serde(skip)]
+    pub selected: usize, // menu choice
+    #[serde(skip)]
+    pub loaded: usize, // level whose LevelDef is live (switched to `selected` on Play)
+}
+
+impl LevelManifest {
+    pub fn from_ron(data: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str::<Self>(data)
+    }
+
+    /// The bundled level alone (used when the manifest is missing or empty).
+    pub fn fallback() -> Self {
+        let level = LevelEntry {
+            id: DEFAULT_LEVEL_ID.to_string(),
+            name: "Level 1".to_string(),
+            file: "levels/level1.ron".to_string(),
+            thumbnail: None,
+            heightmap: "heightmaps/level1.png".to_string(),
+            difficulty: LevelDifficulty::Easy,
+            requires: None,
+            daily: false,
+        };
+        Self { levels: vec![level], selected: 0, loaded: 0 }
+    }
+
+    pub fn index_of(&self, id: &str) -> Option<usize> {
+        self.levels.iter().position(|l| l.id == id)
+    }
+
+    pub fn selected_entry(&self) -> Option<&LevelEntry> {
+        self.levels.get(self.selected)
+    }
+
+    /// Move the menu selection by `step`, wrapping around.
+    pub fn cycle(&mut self, step: i32) {
+        let n = self.levels.len() as i32;
+        if n > 0 {
+            self.selected = (self.selected as i32 + step).rem_euclid(n) as usize;
+        }
+    }
+}
+
+const MANIFEST_PATH: &str = "levels/manifest.ron";
+
+/// Text asset by path under assets/. The web build has no filesystem, so the shipped level files
+/// are embedded.
+fn read_asset_text(path: &str) -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let data = match path {
+            "levels/manifest.ron" => include_str!("../../assets/levels/manifest.ron"),
+            "levels/level1.ron" => include_str!("../../assets/levels/level1.ron"),
+            "levels/course1.ron" => include_str!("../../assets/levels/course1.ron"),
+            _ => return None,
+        };
+        Some(data.to_string())
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        fs::read_to_string(format!("assets/{path}")).ok()
+    }
+}
+
+pub fn load_manifest() -> LevelManifest {
+    match read_asset_text(MANIFEST_PATH).map(|data| LevelManifest::from_ron(&data)) {
+        Some(Ok(manifest)) if !manifest.levels.is_empty() => manifest,
+        Some(Ok(_)) => {
+            warn!("{MANIFEST_PATH} lists no levels; using the bundled level");
+            LevelManifest::fallback()
+        }
+        Some(Err(e)) => {
+            error!("Failed to parse {MANIFEST_PATH}: {e}");
+            LevelManifest::fallback()
+        }
+        None => {
+            warn!("Failed to read {MANIFEST_PATH}; using the bundled level");
+            LevelManifest::fallback()
+        }
+    }
+}
+
+fn read_level(entry: &LevelEntry) -> Option<LevelDef> {
+    let Some(data) = read_asset_text(&entry.file) else {
+        error!("Failed to read level file {}", entry.file);
+        return None;
+    };
+    match ron::from_str::<LevelDef>(&data) {
+        Ok(mut def) => {
+            def.apply_course();
+            if entry.daily {
+                def.daily_seed = Some(DailyDate::today().seed());
+            }
+            Some(def)
+        }
+        Err(e) => {
+            error!("Failed to parse {}: {e}", entry.file);
+            None
+        }
+    }
 }
 
 // ----------------------- Components / Resources -----------------------
@@ -99,57 +432,83 @@ pub struct LevelDef {
 pub struct LevelPlugin;
 
 #[derive(Component)]
-struct SkyDome;
+pub struct SkyDome;
+
+/// Prop from `LevelDef::props` (replaced when another level is switched in).
+#[derive(Component)]
+pub struct LevelProp;
+
+/// Prop belonging to the course hole currently being played.
+#[derive(Component)]
+pub struct HoleProp;
 
 impl Plugin for LevelPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, load_level)
-            .add_systems(Startup, spawn_level.after(load_level))
-            .add_systems(Update, (spawn_runtime_ball, track_sky_dome));
+        app.add_systems(PreStartup, (load_level.before(init_sampler), prepare_daily_level.after(init_sampler)))
+            .add_systems(Startup, spawn_level)
+            .add_systems(
+                Update,
+                (
+                    switch_selected_level.run_if(in_state(AppState::Playing).or_else(in_state(AppState::Replay))),
+                    prepare_daily_level,
+                    respawn_switched_level,
+                    spawn_runtime_ball.run_if(in_state(AppState::Playing).or_else(in_state(AppState::Replay))),
+                    track_sky_dome,
+                    advance_course_hole,
+                )
+                    .chain(),
+            );
     }
 }
 
 // ----------------------- Systems -----------------------
 
-fn load_level(mut commands: Commands) {
-    // Hard-coded single level for now.
-    #[cfg(target_arch = "wasm32")]
-    {
-        // Embed the level definition at compile time for web (no filesystem access in browser).
-        let data = include_str!("../../assets/levels/level1.ron");
-        match ron::from_str::<LevelDef>(data) {
-            Ok(def) => commands.insert_resource(def),
-            Err(e) => error!("Failed to parse embedded level: {e}"),
+// Runs before the terrain sampler is built so the chosen level's heightmap is the first one loaded.
+fn load_level(
+    mut commands: Commands,
+    settings: Option<Res<UserSettings>>,
+    profile: Option<Res<Profile>>,
+    mut terrain: ResMut<TerrainConfig>,
+    mut score: Option<ResMut<Score>>,
+    options: Option<Res<LaunchOptions>>,
+) {
+    let mut manifest = load_manifest();
+    // --level wins over the level remembered from the last session.
+    let requested = options.and_then(|o| o.level.clone()).and_then(|id| {
+        let index = manifest.index_of(&id);
+        if index.is_none() {
+            warn!("--level {id}: no such level in {MANIFEST_PATH}");
         }
-        return;
-    }
-
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        let path = "assets/levels/level1.ron";
-        if let Ok(data) = fs::read_to_string(path) {
-            match ron::from_str::<LevelDef>(&data) {
-                Ok(def) => {
-                    commands.insert_resource(def);
-                }
-                Err(e) => {
-                    error!("Failed to parse {path}: {e}");
-                }
-            }
-        } else {
-            error!("Failed to read level file {path}");
+        index
+    });
+    let remembered = settings.and_then(|s| s.last_level.clone());
+    let index = requested.or_else(|| remembered.and_then(|id| manifest.index_of(&id))).unwrap_or(0);
+    manifest.selected = index;
+    manifest.loaded = index;
+    if let Some(entry) = manifest.levels.get(index) {
+        terrain.heightmap_path = format!("assets/{}", entry.heightmap);
+        if let Some(def) = read_level(entry) {
+            commands.insert_resource(def);
+        }
+        if let Some(ref mut s) = score {
+            set_score_level(s, profile.as_deref(), &entry.score_id());
         }
     }
+    commands.insert_resource(manifest);
+}
+
+fn set_score_level(score: &mut Score, profile: Option<&Profile>, id: &str) {
+    score.level_id = id.to_string();
+    score.high_score_time = profile.and_then(|p| p.best(&score.record_key()));
 }
 
 fn spawn_level(
     mut commands: Commands,
     level: Option<Res<LevelDef>>,
-    sampler: Res<TerrainSampler>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut mats: ResMut<Assets<StandardMaterial>>,
     assets: Res<AssetServer>,
-    mut score: Option<ResMut<Score>>,
+    pipeline: Option<ResMut<TexturePipeline>>,
 ) {
     let Some(level) = level else { return; };
 
@@ -170,7 +529,10 @@ fn spawn_level(
     ));
 
     // Sky
-    let sky_tex = assets.load(level.sky.texture.clone());
+    let sky_tex = match pipeline {
+        Some(mut pipeline) => pipeline.load_image(&assets, &level.sky.texture),
+        None => assets.load(level.sky.texture.clone()),
+    };
     let sky_mesh = generate_inverted_sphere(level.sky.longitudes, level.sky.latitudes, level.sky.radius);
     commands.spawn((
         PbrBundle {
@@ -197,32 +559,19 @@ fn spawn_level(
         ..default()
     });
 
-    // Ball is spawned lazily when entering gameplay phase (see spawn_runtime_ball).
+    // Ball is spawned lazily when entering gameplay phase (see spawn_runtime_ball); targets and
+    // props once the heightmap has decoded (respawn_switched_level).
+}
 
-    // Target spawn + params resource
-    const MIN_TARGET_GROUND: f32 = 50.0;
-    let mut t_x = level.target.initial.x;
-    let mut t_z = level.target.initial.z;
-    let mut t_ground = sampler.height(t_x, t_z);
-    if t_ground < MIN_TARGET_GROUND {
-        let mut rng = rand::thread_rng();
-        for _ in 0..80 {
-            let dist = rng.gen_range(500.0..800.0);
-            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
-            let cand_x = t_x + dist * angle.cos();
-            let cand_z = t_z + dist * angle.sin();
-            let g = sampler.height(cand_x, cand_z);
-            if g >= MIN_TARGET_GROUND {
-                t_x = cand_x;
-                t_z = cand_z;
-                t_ground = g;
-                break;
-            }
-        }
-        // If still below, leave position (will be below threshold but unavoidable); do not force floating
-    }
-    let phase = rand::random::<f32>() * std::f32::consts::TAU;
-    let initial_y = t_ground + level.target.float.base_height + level.target.float.amplitude * phase.sin();
+// Targets, props and the level's gameplay settings (also re-run when another level is switched in).
+fn spawn_level_gameplay(
+    commands: &mut Commands,
+    assets: &AssetServer,
+    sampler: &TerrainSampler,
+    level: &LevelDef,
+    score: Option<&mut Score>,
+) {
+    // Targets + params resource (TargetParams mirrors the primary target)
     commands.insert_resource(TargetParams {
         base_height: level.target.float.base_height,
         amplitude: level.target.float.amplitude,
@@ -231,22 +580,16 @@ fn spawn_level(
         collider_radius: level.target.float.collider_radius,
         visual_offset: 3.6, // increased (200% more) lift to keep model clearly above ground
     });
-    commands.spawn((
-        SceneBundle {
-            scene: assets.load(level.target.model.clone()),
-            transform: Transform::from_xyz(t_x, initial_y, t_z),
-            ..default()
-        },
-        Target,
-        TargetFloat {
-            ground: t_ground,
-            base_height: level.target.float.base_height,
-            amplitude: level.target.float.amplitude,
-            phase,
-            rot_speed: level.target.float.rot_speed,
-            bounce_freq: level.target.float.bob_freq,
-        },
-    ));
+    for (index, def) in std::iter::once(&level.target).chain(&level.extra_targets).enumerate() {
+        spawn_target(commands, assets, sampler, def, index);
+    }
+    for def in &level.props {
+        let prop = spawn_prop(commands, assets, sampler, def);
+        commands.entity(prop).insert(LevelProp);
+    }
+
+    commands.insert_resource(level.difficulty.clone());
+    commands.insert_resource(level.special_targets.clone());
 
     // Open world: removed enclosing walls
 
@@ -255,12 +598,100 @@ fn spawn_level(
         osc_speed: level.shot.osc_speed,
         base_impulse: level.shot.base_impulse,
         up_angle_deg: level.shot.up_angle_deg,
+        ..default()
     });
-    if let Some(ref mut s) = score {
+    if let Some(s) = score {
         s.max_holes = level.scoring.max_holes;
     }
 }
 
+// Play pressed with another level selected in the menu: swap the level in. The old targets and
+// ball go now; the new ones are spawned by `respawn_switched_level` once the terrain caught up.
+// The startup level goes through the same wait (its heightmap decodes in the background).
+fn switch_selected_level(
+    mut commands: Commands,
+    manifest: Option<ResMut<LevelManifest>>,
+    mut terrain: ResMut<TerrainConfig>,
+    mut settings: Option<ResMut<UserSettings>>,
+    mut deform: ResMut<TerrainDeformations>,
+    challenge: Option<Res<ActiveChallenge>>,
+    q_world: Query<(Entity, Option<&CupState>), Or<(With<Target>, With<Ball>, With<LevelProp>)>>,
+) {
+    let Some(mut manifest) = manifest else { return; };
+    if manifest.selected == manifest.loaded {
+        return;
+    }
+    manifest.loaded = manifest.selected;
+    let Some(entry) = manifest.selected_entry().cloned() else { return; };
+    let Some(mut def) = read_level(&entry) else { return; };
+    // A challenge (challenge.rs) plays a course generated from its seed, like the Daily Run.
+    if let Some(seed) = challenge.as_ref().and_then(|c| c.course_seed(&entry.id)) {
+        def.daily_seed = Some(seed);
+    }
+    info!("Switching to level {} ({})", entry.name, entry.id);
+
+    let heightmap = format!("assets/{}", entry.heightmap);
+    if terrain.heightmap_path != heightmap {
+        terrain.heightmap_path = heightmap;
+    }
+    for (e, cup) in &q_world {
+        if let Some(cup) = cup {
+            clear_cup(&mut commands, &mut deform, cup);
+        }
+        commands.entity(e).despawn_recursive();
+    }
+    commands.insert_resource(def);
+    if let Some(ref mut s) = settings {
+        s.last_level = Some(entry.id);
+    }
+}
+
+fn respawn_switched_level(
+    mut commands: Commands,
+    level: Option<Res<LevelDef>>,
+    manifest: Option<Res<LevelManifest>>,
+    terrain: Res<TerrainConfig>,
+    sampler: Res<TerrainSampler>,
+    assets: Res<AssetServer>,
+    profile: Option<Res<Profile>>,
+    challenge: Option<Res<ActiveChallenge>>,
+    mut score: ResMut<Score>,
+    mut ev_restart: EventWriter<RestartGameEvent>,
+    mut pending: Local<bool>,
+    mut started: Local<bool>,
+) {
+    let Some(level) = level else { return; };
+    *pending |= level.is_changed();
+    // A new heightmap is only sampled once the terrain plugin rebuilt (and decoded) the sampler.
+    if !*pending || sampler.is_placeholder() || sampler.cfg.heightmap_path != terrain.heightmap_path {
+        return;
+    }
+    *pending = false;
+    spawn_level_gameplay(&mut commands, &assets, &sampler, &level, Some(&mut score));
+    if !*started {
+        // The startup level: load_level already set up the score, nothing to restart.
+        *started = true;
+        return;
+    }
+    if let Some(entry) = manifest.as_ref().and_then(|m| m.levels.get(m.loaded)) {
+        let id = challenge.as_ref().map_or_else(|| entry.score_id(), |c| c.score_id(entry));
+        set_score_level(&mut score, profile.as_deref(), &id);
+    }
+    ev_restart.send(RestartGameEvent);
+}
+
+// Daily Run and challenge (challenge.rs) levels get their course once the sampler of their
+// heightmap is in (at startup and after a switch, before the gameplay is spawned).
+fn prepare_daily_level(level: Option<ResMut<LevelDef>>, terrain: Res<TerrainConfig>, sampler: Option<Res<TerrainSampler>>) {
+    let (Some(mut level), Some(sampler)) = (level, sampler) else { return; };
+    let Some(seed) = level.daily_seed.filter(|_| !level.is_course()) else { return; };
+    if sampler.is_placeholder() || sampler.cfg.heightmap_path != terrain.heightmap_path {
+        return;
+    }
+    generate_daily_course(&mut level, seed, &sampler);
+    info!("Seeded course generated ({} holes)", level.holes.len());
+}
+
 fn track_sky_dome(
     q_cam: Query<&Transform, (With<OrbitCamera>, Without<SkyDome>)>,
     mut q_sky: Query<&mut Transform, (With<SkyDome>, Without<OrbitCamera>)>,
@@ -273,15 +704,14 @@ fn track_sky_dome(
 
 fn spawn_runtime_ball(
     mut commands: Commands,
-    phase: Option<Res<GamePhase>>,
     level: Option<Res<LevelDef>>,
     sampler: Option<Res<TerrainSampler>>,
     assets: Res<AssetServer>,
     q_ball: Query<Entity, With<Ball>>,
 ) {
-    if !matches!(phase.map(|p| *p), Some(GamePhase::Playing)) { return; }
     if q_ball.get_single().is_ok() { return; }
     let (Some(level), Some(sampler)) = (level, sampler) else { return; };
+    if sampler.is_placeholder() { return; }
 
     let ball_pos = Vec3::new(level.ball.pos.x, 0.0, level.ball.pos.z);
     let ground_h = sampler.height(ball_pos.x, ball_pos.z);
@@ -304,9 +734,109 @@ fn spawn_runtime_ball(
     ));
 }
 
+// Course levels: on each new hole swap in its props and move the ball to its tee (hole 1 is the
+// level's ball spawn, handled by spawn / reset).
+fn advance_course_hole(
+    mut commands: Commands,
+    level: Option<Res<LevelDef>>,
+    score: Option<Res<Score>>,
+    sampler: Option<Res<TerrainSampler>>,
+    assets: Res<AssetServer>,
+    mut last_hole: Local<Option<u32>>,
+    mut q_ball: Query<(&mut Transform, &mut BallKinematic), With<Ball>>,
+    q_props: Query<Entity, With<HoleProp>>,
+    mut ev_resumed: EventReader<RoundResumedEvent>,
+) {
+    let resumed = ev_resumed.read().count() > 0;
+    let (Some(level), Some(score), Some(sampler)) = (level, score, sampler) else { return; };
+    if !level.is_course() || *last_hole == Some(score.hits) {
+        return;
+    }
+    // A resumed round jumps to its hole with the ball where it was left.
+    let advanced = !resumed && last_hole.is_some_and(|h| score.hits > h);
+    *last_hole = Some(score.hits);
+
+    for e in &q_props {
+        commands.entity(e).despawn_recursive();
+    }
+    if score.game_over {
+        return;
+    }
+    let Some(hole) = level.hole(score.hits as usize) else { return; };
+    for def in &hole.props {
+        let prop = spawn_prop(&mut commands, &assets, &sampler, def);
+        commands.entity(prop).insert(HoleProp);
+    }
+
+    if advanced {
+        if let Ok((mut t, mut kin)) = q_ball.get_single_mut() {
+            let tee = level.tee(score.hits as usize);
+            let ground = sampler.height(tee.x, tee.y);
+            t.translation = Vec3::new(tee.x, ground + kin.collider_radius + level.ball.spawn_height_offset, tee.y);
+            t.rotation = Quat::IDENTITY;
+            kin.vel = Vec3::ZERO;
+            kin.angular_vel = Vec3::ZERO;
+        }
+    }
+}
 
 // ----------------------- Utilities -----------------------
 
+fn spawn_target(commands: &mut Commands, assets: &AssetServer, sampler: &TerrainSampler, def: &TargetDef, index: usize) {
+    const MIN_TARGET_GROUND: f32 = 50.0;
+    let mut t_x = def.initial.x;
+    let mut t_z = def.initial.z;
+    let mut t_ground = sampler.height(t_x, t_z);
+    if t_ground < MIN_TARGET_GROUND {
+        let mut rng = rand::thread_rng();
+        for _ in 0..80 {
+            let dist = rng.gen_range(500.0..800.0);
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            let cand_x = t_x + dist * angle.cos();
+            let cand_z = t_z + dist * angle.sin();
+            let g = sampler.height(cand_x, cand_z);
+            if g >= MIN_TARGET_GROUND {
+                t_x = cand_x;
+                t_z = cand_z;
+                t_ground = g;
+                break;
+            }
+        }
+        // If still below, leave position (will be below threshold but unavoidable); do not force floating
+    }
+    let phase = rand::random::<f32>() * std::f32::consts::TAU;
+    let initial_y = t_ground + def.float.base_height + def.float.amplitude * phase.sin();
+    let mut target = commands.spawn((
+        SceneBundle {
+            scene: assets.load(def.model.clone()),
+            transform: Transform::from_xyz(t_x, initial_y, t_z),
+            ..default()
+        },
+        Target,
+        TargetFloat {
+            ground: t_ground,
+            base_height: def.float.base_height,
+            amplitude: def.float.amplitude,
+            phase,
+            rot_speed: def.float.rot_speed,
+            bounce_freq: def.float.bob_freq,
+        },
+        TargetInfo {
+            index,
+            points: def.points,
+            collider_radius: def.float.collider_radius,
+            base_height: def.float.base_height,
+            home: Vec2::new(def.initial.x, def.initial.z),
+            bob: TargetBob { amplitude: def.float.amplitude, bob_freq: def.float.bob_freq, rot_speed: def.float.rot_speed },
+        },
+        def.style,
+        Name::new(format!("Target{index}")),
+    ));
+    if let Some(path) = def.motion.clone() {
+        target.insert(TargetMotion::new(path, Vec2::new(t_x, t_z), t_ground));
+    }
+}
+
 fn generate_inverted_sphere(longitudes: u32, latitudes: u32, radius: f32) -> Mesh {
     let longs = longitudes.max(3);
     let lats = latitudes.max(2);
@@ -0,0 +1,331 @@
+// Level props.
+// Static models a level places through `LevelDef::props` (whole level) or a course hole's
+// `props` (only while that hole is played): bridges, arches, windmills, decorations. A prop may
+// carry a simple collider (sphere, box or upright cylinder, in model units before the prop's
+// scale) that the ball bounces off and rolls over, and a free-form tag other systems can query
+// through `PropTag`.
+// Skate-park pieces use the curved colliders: a `HalfPipe` (the inside of a lying half cylinder)
+// and a `Ramp` (a concave kicker curving up towards +z), both resolved analytically against the
+// ball rather than through the heightfield. `restitution` overrides the bounce of a prop and
+// `boost` kicks the ball off it along the contact normal (bounce pads; once per pad per shot, so
+// a ball can't bounce on one forever). A prop without a `model` is drawn as its collider shape.
+
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+use serde::Deserialize;
+
+use crate::plugins::ball::{ball_physics, Ball, BallConditions, BallKinematic};
+use crate::plugins::core_sim::GameSet;
+use crate::plugins::game_state::Score;
+use crate::plugins::level::PropDef;
+use crate::plugins::app_state::gameplay_running;
+use crate::plugins::terrain::TerrainSampler;
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+pub enum PropCollider {
+    Sphere { radius: f32 },                           // centred on the prop origin
+    Box { half_extents: (f32, f32, f32) },            // centred on the prop origin, turns with the prop
+    Cylinder { radius: f32, height: f32 },            // standing on the prop origin, along its up axis
+    HalfPipe { radius: f32, length: f32 },            // bottom on the prop origin, running along its x axis
+    Ramp { radius: f32, width: f32, angle_deg: f32 }, // foot on the prop origin, curving up towards +z
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct PropCollisionConfig {
+    pub restitution: f32,     // fraction of the normal velocity kept on a bounce
+    pub friction: f32,        // fraction of the tangential velocity lost per contact step
+    pub boost_min_speed: f32, // m/s into a pad for its boost to kick in (not when rolling on)
+}
+impl Default for PropCollisionConfig {
+    fn default() -> Self {
+        Self { restitution: 0.3, friction: 0.02, boost_min_speed: 2.0 }
+    }
+}
+
+/// Bounce of a prop that overrides the default (level `restitution` / `boost`).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct PropBounce {
+    pub restitution: Option<f32>,
+    pub boost: f32, // m/s added along the contact normal
+}
+
+/// Collider (model units) a prop without a model is drawn as.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PropShape(pub PropCollider);
+
+/// Collider of a spawned prop (already scaled to world units).
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PropCollision(pub PropCollider);
+
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct PropTag(pub String);
+
+impl PropCollider {
+    pub fn scaled(self, s: f32) -> Self {
+        match self {
+            Self::Sphere { radius } => Self::Sphere { radius: radius * s },
+            Self::Box { half_extents: (x, y, z) } => Self::Box { half_extents: (x * s, y * s, z * s) },
+            Self::Cylinder { radius, height } => Self::Cylinder { radius: radius * s, height: height * s },
+            Self::HalfPipe { radius, length } => Self::HalfPipe { radius: radius * s, length: length * s },
+            Self::Ramp { radius, width, angle_deg } => Self::Ramp { radius: radius * s, width: width * s, angle_deg },
+        }
+    }
+
+    /// Rough size for broad-phase culling (m).
+    pub fn bounding_radius(self) -> f32 {
+        match self {
+            Self::Sphere { radius } => radius,
+            Self::Box { half_extents: (x, y, z) } => Vec3::new(x, y, z).length(),
+            Self::Cylinder { radius, height } => radius.hypot(height),
+            Self::HalfPipe { radius, length: w } | Self::Ramp { radius, width: w, .. } => {
+                Vec3::new(w * 0.5, radius, radius).length()
+            }
+        }
+    }
+
+    // Curved shell: half length along x, radius and the arc (radians from straight down, + towards +z).
+    fn shell(self) -> Option<(f32, f32, (f32, f32))> {
+        match self {
+            Self::HalfPipe { radius, length } => Some((length * 0.5, radius, (-FRAC_PI_2, FRAC_PI_2))),
+            Self::Ramp { radius, width, angle_deg } => Some((width * 0.5, radius, (0.0, angle_deg.clamp(0.0, 90.0).to_radians()))),
+            _ => None,
+        }
+    }
+}
+
+/// Ball sphere vs the thin shell of a lying cylinder with its axis along x at height `radius`,
+/// between `arc` angles (from straight down, + towards +z). Local frame; normal away from the
+/// shell on the ball's side.
+fn shell_contact(local: Vec3, ball_radius: f32, half_len: f32, radius: f32, arc: (f32, f32)) -> Option<(Vec3, f32)> {
+    if local.x.abs() > half_len {
+        return None;
+    }
+    // Offset from the axis in the (z, y) plane.
+    let d = Vec2::new(local.z, local.y - radius);
+    let angle = d.x.atan2(-d.y);
+    let slack = ball_radius / radius;
+    if angle < arc.0 - slack || angle > arc.1 + slack {
+        return None;
+    }
+    let dist = d.length();
+    if (dist - radius).abs() >= ball_radius || dist < 1e-5 {
+        return None;
+    }
+    let out = Vec3::new(0.0, d.y, d.x) / dist;
+    Some(if dist < radius { (-out, dist + ball_radius - radius) } else { (out, radius + ball_radius - dist) })
+}
+
+/// Ball velocity after bouncing off a surface with normal `n`: the normal part reflected with
+/// `restitution`, plus `boost` m/s along `n`.
+pub fn bounce_velocity(vel: Vec3, n: Vec3, restitution: f32, boost: f32) -> Vec3 {
+    let vn = vel.dot(n);
+    if vn >= 0.0 {
+        return vel;
+    }
+    vel - (1.0 + restitution) * vn * n + n * boost
+}
+
+/// Ball sphere vs a prop collider placed at `pose` (translation + rotation; scale already in the
+/// collider). Returns the world push-out normal (prop -> ball) and penetration depth.
+pub fn prop_contact(ball: Vec3, ball_radius: f32, collider: PropCollider, pose: &Transform) -> Option<(Vec3, f32)> {
+    let local = pose.rotation.inverse() * (ball - pose.translation);
+    let (n, depth) = match collider {
+        PropCollider::Sphere { radius } => {
+            let d = local.length();
+            if d >= radius + ball_radius {
+                return None;
+            }
+            (if d > 1e-5 { local / d } else { Vec3::Y }, radius + ball_radius - d)
+        }
+        PropCollider::Box { half_extents: (hx, hy, hz) } => {
+            let half = Vec3::new(hx, hy, hz);
+            let closest = local.clamp(-half, half);
+            let d = local - closest;
+            let dist = d.length();
+            if dist > 1e-5 {
+                if dist >= ball_radius {
+                    return None;
+                }
+                (d / dist, ball_radius - dist)
+            } else {
+                // Centre inside the box: leave through the nearest face.
+                let gap = half - local.abs();
+                let axis = if gap.x <= gap.y && gap.x <= gap.z {
+                    Vec3::X
+                } else if gap.y <= gap.z {
+                    Vec3::Y
+                } else {
+                    Vec3::Z
+                };
+                let sign = if local.dot(axis) < 0.0 { -1.0 } else { 1.0 };
+                (axis * sign, gap.dot(axis) + ball_radius)
+            }
+        }
+        PropCollider::Cylinder { radius, height } => {
+            if local.y + ball_radius < 0.0 || local.y - ball_radius > height {
+                return None;
+            }
+            let d = local.xz();
+            let side = radius + ball_radius - d.length();
+            if side <= 0.0 {
+                return None;
+            }
+            // Resting on (or dropping onto) the top cap.
+            let top = height + ball_radius - local.y;
+            if local.y > height && top < side {
+                (Vec3::Y, top)
+            } else {
+                let h = d.normalize_or(Vec2::X);
+                (Vec3::new(h.x, 0.0, h.y), side)
+            }
+        }
+        PropCollider::HalfPipe { .. } | PropCollider::Ramp { .. } => {
+            let (half_len, radius, arc) = collider.shell()?;
+            shell_contact(local, ball_radius, half_len, radius, arc)?
+        }
+    };
+    Some((pose.rotation * n, depth))
+}
+
+/// Spawn a prop on the terrain; returns the entity so callers can add their own markers.
+pub fn spawn_prop(commands: &mut Commands, assets: &AssetServer, sampler: &TerrainSampler, def: &PropDef) -> Entity {
+    let ground = sampler.height(def.x, def.z);
+    let rotation = Quat::from_euler(
+        EulerRot::YXZ,
+        def.yaw_deg.to_radians(),
+        def.pitch_deg.to_radians(),
+        def.roll_deg.to_radians(),
+    );
+    let transform = Transform::from_xyz(def.x, ground + def.height, def.z)
+        .with_rotation(rotation)
+        .with_scale(Vec3::splat(def.scale));
+    let mut prop = commands.spawn((
+        SpatialBundle::from_transform(transform),
+        Name::new(def.tag.clone().unwrap_or_else(|| "Prop".to_string())),
+    ));
+    if def.model.is_empty() {
+        if let Some(collider) = def.collider {
+            prop.insert(PropShape(collider));
+        }
+    } else {
+        prop.insert(assets.load::<Scene>(def.model.clone()));
+    }
+    if let Some(collider) = def.collider {
+        prop.insert(PropCollision(collider.scaled(def.scale)));
+    }
+    if def.restitution.is_some() || def.boost != 0.0 {
+        prop.insert(PropBounce { restitution: def.restitution, boost: def.boost });
+    }
+    if let Some(tag) = &def.tag {
+        prop.insert(PropTag(tag.clone()));
+    }
+    prop.id()
+}
+
+pub struct LevelPropsPlugin;
+impl Plugin for LevelPropsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PropCollisionConfig>()
+            .add_systems(Update, build_prop_shapes)
+            .add_systems(FixedUpdate, ball_prop_collision.after(ball_physics).in_set(GameSet::Simulate).run_if(gameplay_running));
+    }
+}
+
+/// Mesh of a collider shape (model units; cylinders stand on the origin like their collider).
+pub fn collider_mesh(collider: PropCollider) -> Mesh {
+    match collider {
+        PropCollider::Sphere { radius } => Sphere::new(radius).mesh().build(),
+        PropCollider::Box { half_extents: (x, y, z) } => Cuboid::new(x * 2.0, y * 2.0, z * 2.0).into(),
+        PropCollider::Cylinder { radius, height } => Mesh::from(Cylinder::new(radius, height)).translated_by(Vec3::Y * height * 0.5),
+        PropCollider::HalfPipe { .. } | PropCollider::Ramp { .. } => shell_mesh(collider.shell().unwrap_or_default()),
+    }
+}
+
+// Strip along the arc, one quad per segment across the full length.
+fn shell_mesh((half_len, radius, (from, to)): (f32, f32, (f32, f32))) -> Mesh {
+    const SEGMENTS: u32 = 24;
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs = Vec::new();
+    for i in 0..=SEGMENTS {
+        let t = i as f32 / SEGMENTS as f32;
+        let angle = from + (to - from) * t;
+        let (s, c) = angle.sin_cos();
+        for x in [-half_len, half_len] {
+            positions.push([x, radius - radius * c, radius * s]);
+            normals.push([0.0, c, -s]); // towards the axis: the riding side
+            uvs.push([(x / half_len + 1.0) * 0.5, t]);
+        }
+    }
+    let mut indices = Vec::new();
+    for i in 0..SEGMENTS {
+        let a = i * 2;
+        indices.extend_from_slice(&[a, a + 2, a + 1, a + 1, a + 2, a + 3]);
+    }
+    Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+        .with_inserted_indices(Indices::U32(indices))
+}
+
+// Props without a model: draw the collider (bounce pads candy pink, the rest pale concrete).
+fn build_prop_shapes(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    q_new: Query<(Entity, &PropShape, Option<&PropBounce>), Added<PropShape>>,
+) {
+    for (e, shape, bounce) in &q_new {
+        let pad = bounce.is_some_and(|b| b.boost > 0.0);
+        let material = materials.add(StandardMaterial {
+            base_color: if pad { Color::srgb(0.95, 0.45, 0.7) } else { Color::srgb(0.78, 0.8, 0.84) },
+            perceptual_roughness: if pad { 0.4 } else { 0.8 },
+            double_sided: true,
+            cull_mode: None,
+            ..default()
+        });
+        commands.entity(e).insert((meshes.add(collider_mesh(shape.0)), material));
+    }
+}
+
+pub fn ball_prop_collision(
+    cfg: Res<PropCollisionConfig>,
+    conditions: Res<BallConditions>,
+    score: Option<Res<Score>>,
+    mut q_ball: Query<(&mut Transform, &mut BallKinematic), With<Ball>>,
+    q_props: Query<(Entity, &Transform, &PropCollision, Option<&PropBounce>), Without<Ball>>,
+    mut boosted: Local<(u32, Vec<Entity>)>, // pads that have kicked the ball during this shot
+) {
+    let Ok((mut ball_t, mut kin)) = q_ball.get_single_mut() else { return; };
+    let shots = score.map_or(0, |s| s.shots);
+    if boosted.0 != shots {
+        *boosted = (shots, Vec::new());
+    }
+    for (e, pose, collision, bounce) in &q_props {
+        let reach = collision.0.bounding_radius() + kin.collider_radius;
+        if pose.translation.distance_squared(ball_t.translation) > reach * reach {
+            continue;
+        }
+        let Some((n, depth)) = prop_contact(ball_t.translation, kin.collider_radius, collision.0, pose) else {
+            continue;
+        };
+        ball_t.translation += n * depth;
+        let vn = kin.vel.dot(n);
+        if vn < 0.0 {
+            let restitution = bounce.and_then(|b| b.restitution).unwrap_or(cfg.restitution);
+            let mut boost = bounce.map_or(0.0, |b| b.boost);
+            if boost > 0.0 && (-vn < cfg.boost_min_speed || boosted.1.contains(&e)) {
+                boost = 0.0;
+            } else if boost > 0.0 {
+                boosted.1.push(e);
+            }
+            kin.vel = bounce_velocity(kin.vel, n, restitution * conditions.bounce, boost);
+            let tangential = kin.vel - kin.vel.dot(n) * n;
+            kin.vel -= tangential * cfg.friction;
+        }
+    }
+}
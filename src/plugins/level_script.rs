@@ -0,0 +1,153 @@
+// Level scripting.
+// A level can list declarative rules (`LevelDef::scripts`): a trigger and the actions it runs,
+// e.g. "after 60 s spawn a golden bonus target" or "when hole 3 starts, set a strong wind". The
+// triggers are hole starts, target hits and run-clock timers (once or repeating); actions poke
+// existing systems (bonus schedule, wind, toasts, run clock), so designers get dynamic events
+// without Rust changes. Rules restart with every run and when another level is loaded.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::plugins::core_sim::SimState;
+use crate::plugins::event_log::{EventLog, LogKind};
+use crate::plugins::game_state::Score;
+use crate::plugins::level::LevelDef;
+use crate::plugins::app_state::{gameplay_running, AppState};
+use crate::plugins::particles::TargetHitEvent;
+use crate::plugins::save_game::RoundResumedEvent;
+use crate::plugins::special_targets::BonusSchedule;
+use crate::plugins::ui_toast::{ToastKind, ToastQueue};
+use crate::plugins::wind::WindState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum ScriptTrigger {
+    HoleStart(u32), // hole number (1 = start of the run)
+    Hit,            // every target hit
+    After(f32),     // once, when the run clock reaches this many seconds
+    Every(f32),     // each time another period of the run clock has passed
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum ScriptAction {
+    SpawnBonus, // golden bonus target near the ball (even with bonus_enabled: false)
+    SetWind { strength: f32, heading_deg: Option<f32> }, // WindState units; heading from +X towards +Z
+    Message(String), // toast + event log line
+    AddTime(f32),    // seconds added to the run clock (negative takes time off)
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ScriptRule {
+    pub when: ScriptTrigger,
+    pub then: Vec<ScriptAction>,
+}
+
+/// How far the level's rules got in the current run.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct LevelScriptState {
+    fired: Vec<u32>, // times each rule fired this run
+}
+
+impl LevelScriptState {
+    pub fn reset(&mut self) {
+        self.fired.clear();
+    }
+
+    /// Mark what already happened before run time `time` / hole `hits + 1` as fired without
+    /// running it (a resumed round keeps its effects); the current hole's start still fires.
+    pub fn skip_to(&mut self, rules: &[ScriptRule], time: f32, hits: u32) {
+        self.fired = rules
+            .iter()
+            .map(|rule| match rule.when {
+                ScriptTrigger::HoleStart(hole) => u32::from(hole <= hits),
+                ScriptTrigger::Hit => 0,
+                ScriptTrigger::After(secs) => u32::from(time >= secs),
+                ScriptTrigger::Every(period) if period > 0.0 => (time / period).floor() as u32,
+                ScriptTrigger::Every(_) => 0,
+            })
+            .collect();
+    }
+
+    /// Indices of the rules due at run time `time` with `hits` targets hit so far and `new_hits`
+    /// of them since the previous step (a rule due several times is listed that often).
+    pub fn step(&mut self, rules: &[ScriptRule], time: f32, hits: u32, new_hits: u32) -> Vec<usize> {
+        self.fired.resize(rules.len(), 0);
+        let mut due = Vec::new();
+        for (i, rule) in rules.iter().enumerate() {
+            let times = match rule.when {
+                ScriptTrigger::HoleStart(hole) => u32::from(self.fired[i] == 0 && hits + 1 == hole),
+                ScriptTrigger::Hit => new_hits,
+                ScriptTrigger::After(secs) => u32::from(self.fired[i] == 0 && time >= secs),
+                ScriptTrigger::Every(period) if period > 0.0 => ((time / period).floor() as u32).saturating_sub(self.fired[i]),
+                ScriptTrigger::Every(_) => 0,
+            };
+            self.fired[i] += times;
+            due.extend(std::iter::repeat_n(i, times as usize));
+        }
+        due
+    }
+}
+
+pub struct LevelScriptPlugin;
+impl Plugin for LevelScriptPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelScriptState>()
+            .add_systems(Update, run_level_scripts.run_if(gameplay_running));
+    }
+}
+
+fn run_level_scripts(
+    sim: Res<SimState>,
+    phase: Option<Res<State<AppState>>>,
+    level: Option<Res<LevelDef>>,
+    mut log: Option<ResMut<EventLog>>,
+    mut score: ResMut<Score>,
+    mut state: ResMut<LevelScriptState>,
+    mut bonus: ResMut<BonusSchedule>,
+    mut wind: ResMut<WindState>,
+    mut toasts: ResMut<ToastQueue>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+    mut ev_resumed: EventReader<RoundResumedEvent>,
+    mut last: Local<Option<(f32, u32)>>, // sim clock and hits at the previous step
+) {
+    let new_hits = ev_hit.read().count() as u32;
+    let resumed = ev_resumed.read().count() > 0;
+    let Some(level) = level else { return; };
+    if phase.is_some_and(|p| !p.in_game()) {
+        *last = None;
+        return;
+    }
+    // A restarted run (clock or hits went back) or a newly loaded level starts the rules over.
+    let elapsed = sim.elapsed_seconds;
+    if level.is_changed() || last.is_none_or(|(t, h)| elapsed < t || score.hits < h) {
+        state.reset();
+    }
+    *last = Some((elapsed, score.hits));
+    if score.game_over || level.scripts.is_empty() {
+        return;
+    }
+
+    let time = score.run_time(elapsed);
+    if resumed {
+        state.skip_to(&level.scripts, time, score.hits);
+    }
+    for i in state.step(&level.scripts, time, score.hits, new_hits) {
+        for action in &level.scripts[i].then {
+            match action {
+                ScriptAction::SpawnBonus => bonus.spawn_now(),
+                ScriptAction::SetWind { strength, heading_deg } => {
+                    wind.base_strength = strength.max(0.0);
+                    if let Some(deg) = heading_deg {
+                        wind.direction = Vec2::from_angle(deg.to_radians());
+                    }
+                }
+                ScriptAction::Message(text) => {
+                    toasts.push_for(text.clone(), ToastKind::Info, 3.0);
+                    if let Some(log) = log.as_mut() {
+                        log.push(LogKind::Info, text.clone());
+                    }
+                }
+                ScriptAction::AddTime(secs) => score.time_adjustment += secs,
+            }
+        }
+    }
+}
@@ -0,0 +1,280 @@
+// Level validation.
+// `validate_level` checks a loaded `LevelDef` against the terrain it will be played on: tees,
+// targets, gates and decoys inside the heightmap and above the sea, every hole reachable in its
+// par with full-power shots (flat-ground range from `base_impulse` / `up_angle_deg`), and every
+// referenced model / texture present under assets/. Issues are logged when a level is loaded
+// (or switched in) and listed in a debug panel (F4; opens by itself when a level has issues).
+
+use std::path::Path;
+
+use bevy::prelude::*;
+
+use crate::plugins::hud_layout::HudAnchor;
+use crate::plugins::level::{LevelDef, ShotConfigDef};
+use crate::plugins::terrain::{TerrainConfig, TerrainSampler};
+
+const WATER_LEVEL: f32 = 25.0; // ball respawns below this (ball.rs)
+const MIN_TARGET_GROUND: f32 = 50.0; // lower targets get moved at spawn (level.rs)
+const MAX_POWER_SCALE: f32 = 2.0; // shooting.rs power scale at full charge
+const SHOT_BUDGET: u32 = 5; // shots allowed to reach the target of a level without a course
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IssueSeverity {
+    Warning, // playable, but probably not what the designer meant
+    Error,   // unplayable or broken (missing asset, unreachable, underwater)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelIssue {
+    pub severity: IssueSeverity,
+    pub subject: String, // what the issue is about, e.g. "hole 2 tee"
+    pub message: String, // what is wrong and how to fix it
+}
+
+impl LevelIssue {
+    fn error(subject: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { severity: IssueSeverity::Error, subject: subject.into(), message: message.into() }
+    }
+
+    fn warning(subject: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { severity: IssueSeverity::Warning, subject: subject.into(), message: message.into() }
+    }
+}
+
+impl std::fmt::Display for LevelIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.subject, self.message)
+    }
+}
+
+/// Carry of a full-power shot over flat ground (m).
+pub fn max_shot_range(shot: &ShotConfigDef) -> f32 {
+    let v = shot.base_impulse * MAX_POWER_SCALE;
+    v * v * (2.0 * shot.up_angle_deg.to_radians()).sin().abs() / 9.81
+}
+
+/// All problems found in `def` when played on `sampler`'s terrain, errors first.
+pub fn validate_level(def: &LevelDef, sampler: &TerrainSampler) -> Vec<LevelIssue> {
+    let mut issues = Vec::new();
+    let half = sampler.cfg.heightmap_world_size * 0.5;
+
+    let check_spot = |issues: &mut Vec<LevelIssue>, subject: &str, x: f32, z: f32, min_ground: Option<f32>| {
+        if x.abs() > half || z.abs() > half {
+            issues.push(LevelIssue::error(
+                subject,
+                format!("({x:.0}, {z:.0}) is outside the terrain (|x|, |z| must be <= {half:.0})"),
+            ));
+            return;
+        }
+        let ground = sampler.height(x, z);
+        if ground < WATER_LEVEL {
+            issues.push(LevelIssue::error(
+                subject,
+                format!("({x:.0}, {z:.0}) is under water (ground {ground:.1} m < {WATER_LEVEL} m); move it onto land"),
+            ));
+        } else if let Some(min) = min_ground.filter(|m| ground < *m) {
+            issues.push(LevelIssue::warning(
+                subject,
+                format!("ground {ground:.1} m is below {min} m, so the target is moved to a random spot at spawn"),
+            ));
+        }
+    };
+
+    // Tees and targets.
+    let range = max_shot_range(&def.shot);
+    let check_reach = |issues: &mut Vec<LevelIssue>, subject: &str, tee: Vec2, target: Vec2, shots: u32| {
+        let distance = tee.distance(target);
+        let reach = range * shots as f32;
+        if distance > reach {
+            issues.push(LevelIssue::error(
+                subject,
+                format!(
+                    "target is {distance:.0} m from the tee but {shots} full shots carry {reach:.0} m \
+                     ({range:.0} m each); move them closer, raise par or shot.base_impulse"
+                ),
+            ));
+        }
+    };
+    if def.holes.is_empty() {
+        check_spot(&mut issues, "ball spawn", def.ball.pos.x, def.ball.pos.z, None);
+        check_spot(&mut issues, "target", def.target.initial.x, def.target.initial.z, Some(MIN_TARGET_GROUND));
+        let tee = Vec2::new(def.ball.pos.x, def.ball.pos.z);
+        let target = Vec2::new(def.target.initial.x, def.target.initial.z);
+        check_reach(&mut issues, "target", tee, target, SHOT_BUDGET);
+    }
+    for (i, hole) in def.holes.iter().enumerate() {
+        let n = i + 1;
+        check_spot(&mut issues, &format!("hole {n} tee"), hole.tee.x, hole.tee.z, None);
+        check_spot(&mut issues, &format!("hole {n} target"), hole.target.x, hole.target.z, Some(MIN_TARGET_GROUND));
+        if hole.par == 0 {
+            issues.push(LevelIssue::warning(format!("hole {n}"), "par is 0; use at least 1"));
+        }
+        let tee = Vec2::new(hole.tee.x, hole.tee.z);
+        let target = Vec2::new(hole.target.x, hole.target.z);
+        check_reach(&mut issues, &format!("hole {n}"), tee, target, hole.par.max(1));
+    }
+    for (i, extra) in def.extra_targets.iter().enumerate() {
+        check_spot(&mut issues, &format!("extra target {}", i + 1), extra.initial.x, extra.initial.z, Some(MIN_TARGET_GROUND));
+    }
+    for (i, gate) in def.gates.iter().enumerate() {
+        check_spot(&mut issues, &format!("gate {}", i + 1), gate.pos.0, gate.pos.1, None);
+    }
+    for (i, &(x, z)) in def.special_targets.decoys.iter().enumerate() {
+        check_spot(&mut issues, &format!("decoy {}", i + 1), x, z, None);
+    }
+    for (i, prop) in def.props.iter().enumerate() {
+        let subject = format!("prop {}", i + 1);
+        if prop.x.abs() > half || prop.z.abs() > half {
+            issues.push(LevelIssue::warning(subject.clone(), format!("({:.0}, {:.0}) is outside the terrain", prop.x, prop.z)));
+        }
+        if prop.model.is_empty() && prop.collider.is_none() {
+            issues.push(LevelIssue::warning(subject, "has neither a model nor a collider; nothing to show"));
+        }
+    }
+
+    // Referenced assets.
+    let mut assets: Vec<(String, &str)> = vec![
+        ("ball.model".into(), &def.ball.model),
+        ("target.model".into(), &def.target.model),
+        ("sky.texture".into(), &def.sky.texture),
+    ];
+    assets.extend(def.extra_targets.iter().enumerate().map(|(i, t)| (format!("extra target {} model", i + 1), t.model.as_str())));
+    if !def.special_targets.decoys.is_empty() {
+        assets.push(("special_targets.decoy_model".into(), &def.special_targets.decoy_model));
+    }
+    if let Some(mask) = &def.vegetation_mask {
+        assets.push(("vegetation_mask".into(), mask));
+    }
+    assets.extend(def.props.iter().enumerate().filter(|(_, p)| !p.model.is_empty()).map(|(i, p)| (format!("prop {}", i + 1), p.model.as_str())));
+    for (i, hole) in def.holes.iter().enumerate() {
+        assets.extend(hole.props.iter().enumerate().filter(|(_, p)| !p.model.is_empty()).map(|(j, p)| (format!("hole {} prop {}", i + 1, j + 1), p.model.as_str())));
+    }
+    for (subject, path) in assets {
+        if !asset_exists(path) {
+            issues.push(LevelIssue::error(subject, format!("asset \"{path}\" not found under assets/")));
+        }
+    }
+
+    issues.sort_by_key(|i| std::cmp::Reverse(i.severity));
+    issues
+}
+
+/// Does `path` (relative to assets/, optionally with a "#Label" suffix) exist? Always true on the
+/// web build, which can't look.
+pub fn asset_exists(path: &str) -> bool {
+    if cfg!(target_arch = "wasm32") {
+        return true;
+    }
+    let file = path.split('#').next().unwrap_or(path);
+    Path::new("assets").join(file).is_file()
+}
+
+/// Issues of the level currently loaded.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct LevelIssues {
+    pub issues: Vec<LevelIssue>,
+    pub panel_open: bool,
+}
+
+#[derive(Component)]
+struct LevelIssuesPanel;
+#[derive(Component)]
+struct LevelIssuesText;
+
+pub struct LevelValidationPlugin;
+impl Plugin for LevelValidationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelIssues>()
+            .add_systems(Startup, spawn_level_issues_panel)
+            .add_systems(Update, (validate_loaded_level, toggle_level_issues, draw_level_issues).chain());
+    }
+}
+
+fn validate_loaded_level(
+    level: Option<Res<LevelDef>>,
+    terrain: Res<TerrainConfig>,
+    sampler: Option<Res<TerrainSampler>>,
+    mut issues: ResMut<LevelIssues>,
+    mut pending: Local<bool>,
+) {
+    let (Some(level), Some(sampler)) = (level, sampler) else { return; };
+    *pending |= level.is_changed();
+    // Wait for the decoded sampler of a (switched-in) heightmap.
+    if !*pending || sampler.is_placeholder() || sampler.cfg.heightmap_path != terrain.heightmap_path {
+        return;
+    }
+    *pending = false;
+    issues.issues = validate_level(&level, &sampler);
+    issues.panel_open = !issues.issues.is_empty();
+    if issues.issues.is_empty() {
+        info!("Level validation: no issues");
+    }
+    for issue in &issues.issues {
+        match issue.severity {
+            IssueSeverity::Error => error!("Level validation: {issue}"),
+            IssueSeverity::Warning => warn!("Level validation: {issue}"),
+        }
+    }
+}
+
+fn spawn_level_issues_panel(mut commands: Commands, assets: Res<AssetServer>) {
+    let font = assets.load("fonts/FiraSans-Bold.ttf");
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(12.0),
+                    top: Val::Px(140.0),
+                    max_width: Val::Px(520.0),
+                    padding: UiRect::all(Val::Px(6.0)),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(0.15, 0.0, 0.0, 0.6)),
+                border_radius: BorderRadius::all(Val::Px(6.0)),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            HudAnchor::top_left(12.0, 140.0),
+            LevelIssuesPanel,
+            Name::new("LevelIssues"),
+        ))
+        .with_children(|p| {
+            p.spawn((
+                TextBundle::from_section("", TextStyle { font, font_size: 14.0, color: Color::WHITE }),
+                LevelIssuesText,
+            ));
+        });
+}
+
+fn toggle_level_issues(keys: Res<ButtonInput<KeyCode>>, mut issues: ResMut<LevelIssues>) {
+    if keys.just_pressed(KeyCode::F4) {
+        issues.panel_open = !issues.panel_open;
+    }
+}
+
+fn draw_level_issues(
+    issues: Res<LevelIssues>,
+    mut q_panel: Query<&mut Visibility, With<LevelIssuesPanel>>,
+    mut q_text: Query<&mut Text, With<LevelIssuesText>>,
+) {
+    if !issues.is_changed() {
+        return;
+    }
+    if let Ok(mut vis) = q_panel.get_single_mut() {
+        *vis = if issues.panel_open { Visibility::Inherited } else { Visibility::Hidden };
+    }
+    let Ok(mut text) = q_text.get_single_mut() else { return; };
+    let mut lines = vec![format!("Level issues: {} (F4 to close)", issues.issues.len())];
+    if issues.issues.is_empty() {
+        lines.push("none".to_string());
+    }
+    lines.extend(issues.issues.iter().map(|i| {
+        let tag = match i.severity {
+            IssueSeverity::Error => "ERROR",
+            IssueSeverity::Warning => "warn",
+        };
+        format!("[{tag}] {i}")
+    }));
+    text.sections[0].value = lines.join("\n");
+}
@@ -0,0 +1,256 @@
+// Loading screen.
+// The game starts in `AppState::Loading`: before the main menu appears, everything the first
+// seconds of play need is preloaded - the level's ball, target and prop models and sky texture,
+// the tree species, the particle models, sound effects and music and the UI font - while a
+// progress bar shows the share of those assets that finished loading (the heightmap, decoded in
+// the background by terrain.rs, counts as one more step). The asset server hands out the same
+// handle for a path, so the features loading these assets later find them ready instead of
+// popping in. Failed assets count as done (logged once); after LOADING_TIMEOUT_S the menu opens
+// regardless, so a missing file can never hang startup. Without a window (--headless) there is
+// nothing to pop in, so only the heightmap is waited for.
+
+use bevy::asset::{LoadState, UntypedAssetId};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::plugins::level::LevelDef;
+use crate::plugins::localization::LocalizedText;
+use crate::plugins::app_state::AppState;
+use crate::plugins::terrain::TerrainSampler;
+use crate::plugins::texture_pipeline::TexturePipeline;
+use crate::plugins::vegetation_species::VegetationSpeciesList;
+
+pub const LOADING_TIMEOUT_S: f32 = 30.0;
+const UI_FONT: &str = "fonts/FiraSans-Bold.ttf";
+const SOUNDS: [&str; 5] = ["audio/bounce.mp3", "audio/hit.mp3", "audio/game_over.mp3", "audio/launch.mp3", "audio/music.mp3"];
+const PARTICLE_MODELS: [&str; 3] = ["models/snowflake.glb#Scene0", "models/candy_1.glb#Scene0", "models/candy_2.glb#Scene0"];
+const BAR_WIDTH_PX: f32 = 420.0;
+
+/// Finished share of the preload list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadProgress {
+    pub loaded: usize,
+    pub failed: usize,
+    pub total: usize,
+}
+
+impl LoadProgress {
+    pub fn from_states(states: impl IntoIterator<Item = LoadState>) -> Self {
+        let mut progress = Self::default();
+        for state in states {
+            progress.total += 1;
+            match state {
+                LoadState::Loaded => progress.loaded += 1,
+                LoadState::Failed(_) => progress.failed += 1,
+                _ => {}
+            }
+        }
+        progress
+    }
+
+    /// 0..=1; an empty list is complete.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 { 1.0 } else { (self.loaded + self.failed) as f32 / self.total as f32 }
+    }
+
+    pub fn finished(&self) -> bool {
+        self.loaded + self.failed >= self.total
+    }
+}
+
+/// Asset paths to preload for a level (deduplicated, in first-use order).
+pub fn preload_paths(level: Option<&LevelDef>, species: Option<&VegetationSpeciesList>) -> (Vec<String>, Vec<String>) {
+    let mut scenes: Vec<String> = Vec::new();
+    let mut images = Vec::new();
+    let add = |list: &mut Vec<String>, path: &str| {
+        if !path.is_empty() && !list.iter().any(|p| p == path) {
+            list.push(path.to_string());
+        }
+    };
+    if let Some(level) = level {
+        add(&mut scenes, &level.ball.model);
+        for target in std::iter::once(&level.target).chain(&level.extra_targets) {
+            add(&mut scenes, &target.model);
+        }
+        for prop in &level.props {
+            add(&mut scenes, &prop.model);
+        }
+        add(&mut images, &level.sky.texture);
+    }
+    for s in species.map(|s| s.species.as_slice()).unwrap_or_default() {
+        add(&mut scenes, &s.model);
+    }
+    for path in PARTICLE_MODELS {
+        add(&mut scenes, path);
+    }
+    (scenes, images)
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct Preload {
+    pub handles: Vec<(String, UntypedHandle)>, // kept alive so nothing unloads before first use
+    pub progress: LoadProgress,
+    pub elapsed: f32,
+    reported: Vec<UntypedAssetId>,
+}
+
+#[derive(Component)]
+struct LoadingScreen;
+#[derive(Component)]
+struct LoadingBarFill;
+#[derive(Component)]
+struct LoadingPercentText;
+
+pub struct LoadingStatePlugin;
+impl Plugin for LoadingStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Preload>()
+            .add_systems(PostStartup, (start_preload, spawn_loading_screen))
+            .add_systems(Update, (track_preload, update_loading_screen).chain());
+    }
+}
+
+// PostStartup: the level and the species list are known by then.
+fn start_preload(
+    mut preload: ResMut<Preload>,
+    assets: Res<AssetServer>,
+    level: Option<Res<LevelDef>>,
+    species: Option<Res<VegetationSpeciesList>>,
+    pipeline: Option<Res<TexturePipeline>>,
+) {
+    let (scenes, images) = preload_paths(level.as_deref(), species.as_deref());
+    // The variant the sky will actually use (spawn_level loads through the pipeline too).
+    let images = images.into_iter().map(|p| pipeline.as_ref().map_or(p.clone(), |tp| tp.resolve(&p)));
+    let mut handles: Vec<(String, UntypedHandle)> = Vec::new();
+    handles.push((UI_FONT.into(), assets.load::<Font>(UI_FONT).untyped()));
+    handles.extend(SOUNDS.iter().map(|p| (p.to_string(), assets.load::<AudioSource>(*p).untyped())));
+    handles.extend(images.map(|p| (p.clone(), assets.load::<Image>(p).untyped())));
+    handles.extend(scenes.into_iter().map(|p| (p.clone(), assets.load::<Scene>(p).untyped())));
+    info!("Loading: preloading {} assets", handles.len());
+    preload.handles = handles;
+}
+
+fn track_preload(
+    mut preload: ResMut<Preload>,
+    phase: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    time: Res<Time<Real>>,
+    assets: Res<AssetServer>,
+    sampler: Option<Res<TerrainSampler>>,
+    q_window: Query<(), With<PrimaryWindow>>,
+) {
+    if *phase.get() != AppState::Loading {
+        return;
+    }
+    let heightmap_ready = sampler.is_some_and(|s| !s.is_placeholder());
+    if q_window.is_empty() {
+        if heightmap_ready {
+            next_state.set(AppState::Menu);
+        }
+        return;
+    }
+    preload.elapsed += time.delta_seconds();
+    let mut states = Vec::with_capacity(preload.handles.len() + 1);
+    let mut newly_failed = Vec::new();
+    for (path, handle) in &preload.handles {
+        let state = assets.get_load_state(handle.id()).unwrap_or(LoadState::NotLoaded);
+        if matches!(state, LoadState::Failed(_)) && !preload.reported.contains(&handle.id()) {
+            warn!("Loading: {path} failed to load");
+            newly_failed.push(handle.id());
+        }
+        states.push(state);
+    }
+    preload.reported.extend(newly_failed);
+    states.push(if heightmap_ready { LoadState::Loaded } else { LoadState::NotLoaded });
+    preload.progress = LoadProgress::from_states(states);
+
+    let timed_out = preload.elapsed >= LOADING_TIMEOUT_S;
+    if preload.progress.finished() || timed_out {
+        if timed_out && !preload.progress.finished() {
+            warn!("Loading: timed out after {LOADING_TIMEOUT_S}s ({:?})", preload.progress);
+        }
+        info!("Loading: done in {:.1}s ({} failed)", preload.elapsed, preload.progress.failed);
+        next_state.set(AppState::Menu);
+    }
+}
+
+fn spawn_loading_screen(mut commands: Commands, assets: Res<AssetServer>) {
+    let font = assets.load(UI_FONT);
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(14.0),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgb(0.02, 0.02, 0.05)),
+                z_index: ZIndex::Global(100),
+                ..default()
+            },
+            LoadingScreen,
+            Name::new("LoadingScreen"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle { font: font.clone(), font_size: 32.0, color: Color::srgb(0.95, 0.95, 1.0) },
+                ),
+                LocalizedText::new("loading.title"),
+            ));
+            parent
+                .spawn(NodeBundle {
+                    style: Style { width: Val::Px(BAR_WIDTH_PX), height: Val::Px(14.0), ..default() },
+                    background_color: BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                    ..default()
+                })
+                .with_children(|bar| {
+                    bar.spawn((
+                        NodeBundle {
+                            style: Style { width: Val::Percent(0.0), height: Val::Percent(100.0), ..default() },
+                            background_color: BackgroundColor(Color::srgb(0.15, 0.55, 0.25)),
+                            ..default()
+                        },
+                        LoadingBarFill,
+                    ));
+                });
+            parent.spawn((
+                TextBundle::from_section(
+                    "0%",
+                    TextStyle { font, font_size: 18.0, color: Color::srgb(0.70, 0.70, 0.75) },
+                ),
+                LoadingPercentText,
+            ));
+        });
+}
+
+fn update_loading_screen(
+    mut commands: Commands,
+    preload: Res<Preload>,
+    phase: Res<State<AppState>>,
+    q_screen: Query<Entity, With<LoadingScreen>>,
+    mut q_fill: Query<&mut Style, With<LoadingBarFill>>,
+    mut q_text: Query<&mut Text, With<LoadingPercentText>>,
+) {
+    if *phase.get() != AppState::Loading {
+        for e in &q_screen {
+            commands.entity(e).despawn_recursive();
+        }
+        return;
+    }
+    if !preload.is_changed() {
+        return;
+    }
+    let percent = preload.progress.fraction() * 100.0;
+    for mut style in &mut q_fill {
+        style.width = Val::Percent(percent);
+    }
+    for mut text in &mut q_text {
+        text.sections[0].value = format!("{percent:.0}%");
+    }
+}
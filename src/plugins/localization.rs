@@ -0,0 +1,245 @@
+// UI string localization.
+// Every language is a RON file under assets/i18n/ (`LanguageFile`): a display name, an optional
+// font and a key -> string table. Strings may contain `{name}` placeholders filled by `tf`.
+// Lookups fall back to English and then to the key itself, so a missing entry is visible but
+// harmless. Static labels carry a `LocalizedText` and are rewritten when the language changes;
+// dynamic texts (HUD line, power gauge) call `t` / `tf` as they format.
+// Fonts: FiraSans only covers Latin scripts. A language written in another script names its own
+// font in its file; on a language switch every UI text using the previous language's font is
+// moved to the new one (and new texts pick it up as they spawn).
+
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+pub const DEFAULT_FONT: &str = "fonts/FiraSans-Bold.ttf";
+pub const DEFAULT_LANGUAGE: &str = "en";
+/// Language codes shipped with the game, in selector order (files: assets/i18n/<code>.ron).
+pub const LANGUAGES: &[&str] = &["en", "de", "es"];
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct LanguageFile {
+    pub name: String,
+    #[serde(default)]
+    pub font: Option<String>, // asset path; Latin-script languages use DEFAULT_FONT
+    pub strings: HashMap<String, String>,
+}
+
+impl LanguageFile {
+    pub fn from_ron(data: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str::<Self>(data)
+    }
+
+    /// Read a shipped language file (embedded on wasm).
+    fn load(code: &str) -> Option<Self> {
+        #[cfg(target_arch = "wasm32")]
+        let data: Option<String> = match code {
+            "en" => Some(include_str!("../../assets/i18n/en.ron").to_string()),
+            "de" => Some(include_str!("../../assets/i18n/de.ron").to_string()),
+            "es" => Some(include_str!("../../assets/i18n/es.ron").to_string()),
+            _ => None,
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        let data = fs::read_to_string(format!("assets/i18n/{code}.ron")).ok();
+
+        let Some(data) = data else {
+            warn!("Failed to read language file for '{code}'");
+            return None;
+        };
+        match Self::from_ron(&data) {
+            Ok(file) => Some(file),
+            Err(e) => {
+                error!("Failed to parse language file for '{code}': {e}");
+                None
+            }
+        }
+    }
+}
+
+/// True if `text` has characters FiraSans can't draw (anything beyond Latin, Latin-1,
+/// Latin Extended, general punctuation and the few symbols the UI uses).
+pub fn needs_fallback_font(text: &str) -> bool {
+    text.chars().any(|c| {
+        !matches!(c as u32,
+            0x0000..=0x024F   // Basic Latin .. Latin Extended-B
+            | 0x2000..=0x206F // General Punctuation
+            | 0x20A0..=0x20CF // Currency
+            | 0x2190..=0x21FF // Arrows
+            | 0x2600..=0x26FF // Misc symbols (gear)
+        )
+    })
+}
+
+/// Fill `{name}` placeholders.
+pub fn fill_placeholders(template: &str, args: &[(&str, String)]) -> String {
+    let mut out = template.to_string();
+    for (name, value) in args {
+        out = out.replace(&format!("{{{name}}}"), value);
+    }
+    out
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct Localization {
+    languages: Vec<(String, LanguageFile)>,
+    current: usize,
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        let languages: Vec<(String, LanguageFile)> = LANGUAGES
+            .iter()
+            .filter_map(|code| LanguageFile::load(code).map(|f| (code.to_string(), f)))
+            .collect();
+        Self::from_languages(languages)
+    }
+}
+
+impl Localization {
+    pub fn from_languages(languages: Vec<(String, LanguageFile)>) -> Self {
+        for (code, file) in &languages {
+            if file.font.is_none() && file.strings.values().any(|s| needs_fallback_font(s)) {
+                warn!("Language '{code}' uses non-Latin text but names no font; glyphs will be missing");
+            }
+        }
+        let current = languages.iter().position(|(c, _)| c == DEFAULT_LANGUAGE).unwrap_or(0);
+        Self { languages, current }
+    }
+
+    pub fn code(&self) -> &str {
+        self.languages.get(self.current).map_or(DEFAULT_LANGUAGE, |(c, _)| c.as_str())
+    }
+
+    pub fn language_name(&self) -> &str {
+        self.languages.get(self.current).map_or("English", |(_, f)| f.name.as_str())
+    }
+
+    pub fn set_language(&mut self, code: &str) -> bool {
+        match self.languages.iter().position(|(c, _)| c == code) {
+            Some(i) => {
+                self.current = i;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Switch to the next available language (settings selector).
+    pub fn cycle(&mut self) {
+        if !self.languages.is_empty() {
+            self.current = (self.current + 1) % self.languages.len();
+        }
+    }
+
+    /// Font asset path for the current language.
+    pub fn font_path(&self) -> &str {
+        self.languages.get(self.current).and_then(|(_, f)| f.font.as_deref()).unwrap_or(DEFAULT_FONT)
+    }
+
+    /// String for `key` in the current language, else English, else the key itself.
+    pub fn t<'a>(&'a self, key: &'a str) -> &'a str {
+        let lookup = |i: usize| self.languages.get(i).and_then(|(_, f)| f.strings.get(key));
+        let english = self.languages.iter().position(|(c, _)| c == DEFAULT_LANGUAGE);
+        lookup(self.current)
+            .or_else(|| english.and_then(lookup))
+            .map_or(key, |s| s.as_str())
+    }
+
+    /// `t` with `{name}` placeholders filled.
+    pub fn tf(&self, key: &str, args: &[(&str, String)]) -> String {
+        fill_placeholders(self.t(key), args)
+    }
+}
+
+/// Static label: its first text section is set from `key` (with `args`) in the current language.
+#[derive(Component, Debug, Clone)]
+pub struct LocalizedText {
+    pub key: &'static str,
+    pub args: Vec<(&'static str, String)>,
+}
+
+impl LocalizedText {
+    pub fn new(key: &'static str) -> Self {
+        Self { key, args: Vec::new() }
+    }
+
+    pub fn with_arg(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.args.push((name, value.into()));
+        self
+    }
+}
+
+/// Font handle currently applied to UI text.
+#[derive(Resource, Debug, Default)]
+struct LocalizedFont {
+    path: String,
+    handle: Handle<Font>,
+}
+
+pub struct LocalizationPlugin;
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Localization>()
+            .init_resource::<LocalizedFont>()
+            .add_systems(PostUpdate, (apply_language_font, apply_localized_texts).before(bevy::ui::UiSystem::Layout));
+    }
+}
+
+fn apply_localized_texts(
+    loc: Res<Localization>,
+    mut q: Query<(Ref<LocalizedText>, &mut Text)>,
+) {
+    for (label, mut text) in &mut q {
+        if !(loc.is_changed() || label.is_changed()) {
+            continue;
+        }
+        let args: Vec<(&str, String)> = label.args.iter().map(|(k, v)| (*k, v.clone())).collect();
+        let value = loc.tf(label.key, &args);
+        if let Some(section) = text.sections.first_mut() {
+            if section.value != value {
+                section.value = value;
+            }
+        }
+    }
+}
+
+fn apply_language_font(
+    loc: Res<Localization>,
+    assets: Res<AssetServer>,
+    mut font: ResMut<LocalizedFont>,
+    mut q_text: Query<&mut Text>,
+) {
+    if font.path.is_empty() {
+        font.path = DEFAULT_FONT.to_string();
+        font.handle = assets.load(DEFAULT_FONT);
+    }
+    if loc.is_changed() && font.path != loc.font_path() {
+        // Move every text on the old language font over to the new one.
+        let old = std::mem::replace(&mut font.handle, assets.load(loc.font_path().to_string()));
+        font.path = loc.font_path().to_string();
+        let default = assets.load(DEFAULT_FONT);
+        for mut text in &mut q_text {
+            for s in text.sections.iter_mut() {
+                if s.style.font == old || s.style.font == default {
+                    s.style.font = font.handle.clone();
+                }
+            }
+        }
+        return;
+    }
+    if font.path == DEFAULT_FONT {
+        return;
+    }
+    // New texts are spawned with the default font.
+    let default = assets.load(DEFAULT_FONT);
+    for mut text in q_text.iter_mut().filter(|t| t.is_added()) {
+        for s in text.sections.iter_mut() {
+            if s.style.font == default {
+                s.style.font = font.handle.clone();
+            }
+        }
+    }
+}
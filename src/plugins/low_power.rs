@@ -0,0 +1,92 @@
+// Low-power / reduced-motion mode for laptops on battery and phones.
+// One switch that caps the frame rate (`FrameLimiter`, held by sleeping at the end of each frame
+// on native builds; the browser paces wasm), stops the motion nobody needs to play: the camera's
+// FOV kick on fast shots, the targets' idle bob and spin (moving targets still follow their
+// paths) and the main menu's flythrough, and scales particle bursts and atmospheric dust down
+// (`ParticleQuality`). It is a toggle in the settings panel's Accessibility group, saved in
+// low_power.txt in the data directory; `--low-power` turns it on for one run without saving.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::Instant;
+
+use crate::launch_options::LaunchOptions;
+use crate::plugins::particles::ParticleQuality;
+use crate::plugins::storage::StoragePaths;
+
+pub const LOW_POWER_FPS: f32 = 30.0;
+/// Share of particles spawned in low-power mode.
+pub const LOW_POWER_PARTICLE_SCALE: f32 = 0.35;
+const LOW_POWER_FILE: &str = "low_power.txt";
+
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LowPowerMode {
+    pub enabled: bool,
+}
+
+impl LowPowerMode {
+    /// Skip purely decorative motion (systems check this instead of the raw flag).
+    pub fn reduced_motion(mode: Option<&Self>) -> bool {
+        mode.is_some_and(|m| m.enabled)
+    }
+}
+
+/// Frame rate cap (`None` = uncapped).
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct FrameLimiter {
+    pub max_fps: Option<f32>,
+}
+
+impl FrameLimiter {
+    /// How long to wait after a frame that took `frame_time` to hold the cap.
+    pub fn wait_for(&self, frame_time: Duration) -> Duration {
+        match self.max_fps.filter(|fps| *fps > 0.0) {
+            Some(fps) => Duration::from_secs_f32(1.0 / fps).saturating_sub(frame_time),
+            None => Duration::ZERO,
+        }
+    }
+}
+
+pub fn load_saved_low_power(paths: &StoragePaths) -> bool {
+    paths.load_text(LOW_POWER_FILE).is_some_and(|s| s.trim() == "on")
+}
+
+pub fn save_low_power(paths: &StoragePaths, enabled: bool) {
+    paths.save_text(LOW_POWER_FILE, if enabled { "on\n" } else { "off\n" });
+}
+
+pub struct LowPowerPlugin;
+impl Plugin for LowPowerPlugin {
+    fn build(&self, app: &mut App) {
+        let cli = app.world().get_resource::<LaunchOptions>().is_some_and(|o| o.low_power);
+        let enabled = cli || load_saved_low_power(&StoragePaths::in_world(app.world()));
+        app.insert_resource(LowPowerMode { enabled })
+            .init_resource::<FrameLimiter>()
+            .init_resource::<ParticleQuality>()
+            .add_systems(Update, apply_low_power)
+            .add_systems(Last, limit_frame_rate);
+    }
+}
+
+fn apply_low_power(mode: Res<LowPowerMode>, mut limiter: ResMut<FrameLimiter>, mut particles: ResMut<ParticleQuality>) {
+    if !mode.is_changed() {
+        return;
+    }
+    limiter.max_fps = mode.enabled.then_some(LOW_POWER_FPS);
+    particles.scale = if mode.enabled { LOW_POWER_PARTICLE_SCALE } else { 1.0 };
+    info!("Low power mode: {}", if mode.enabled { "on" } else { "off" });
+}
+
+// Last in the frame: sleep away what is left of the frame budget.
+fn limit_frame_rate(limiter: Res<FrameLimiter>, mut frame_start: Local<Option<Instant>>) {
+    let now = Instant::now();
+    let wait = frame_start.map_or(Duration::ZERO, |start| limiter.wait_for(now - start));
+    #[cfg(not(target_arch = "wasm32"))]
+    if !wait.is_zero() {
+        std::thread::sleep(wait);
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = wait;
+    *frame_start = Some(Instant::now());
+}
@@ -1,66 +1,74 @@
-// Main menu plugin: displays a simple UI with Play, Level (selector placeholder),
-// High Score (read-only), and Quit. Hides itself once Play is pressed.
+// Main menu plugin: displays a simple UI with Continue (only while an unfinished round is saved,
+// see save_game.rs), Play, a level selector (levels/manifest.ron: name,
+// difficulty, thumbnail, that level's best record and campaign status), the game mode (click to
+// cycle, see game_mode.rs), the number of local hot-seat players (players.rs), Watch Replay (only while the last round's replay can be shown, see
+// replay.rs), Achievements (panel in achievements.rs), Challenge (code entry, challenge.rs) and Quit. Play is disabled
+// while the selected level is still locked (campaign.rs). Hides itself once Play is pressed
+// (which also switches to the selected level, see level.rs) and comes back whenever the state
+// returns to `AppState::Menu` (game-over panel "Main Menu"), including the first time, when the
+// loading screen (loading_state.rs) finishes.
 
 use bevy::prelude::*;
-use crate::plugins::game_state::Score;
-use crate::plugins::ball::Ball;
-
-#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub enum GamePhase {
-    #[default]
-    Menu,
-    Playing,
-}
+use crate::plugins::app_state::AppState;
+use crate::plugins::achievements::AchievementsButton;
+use crate::plugins::campaign::CampaignState;
+use crate::plugins::challenge::ChallengeButton;
+use crate::plugins::level::LevelManifest;
+use crate::plugins::localization::{Localization, LocalizedText};
+use crate::plugins::players::{PlayersButton, PlayersText};
+use crate::plugins::profile::Profile;
+use crate::plugins::replay::WatchReplayButton;
+use crate::plugins::save_game::RoundSlot;
+use crate::plugins::user_settings::UserSettings;
 
 #[derive(Component)]
-struct MenuRoot;
+pub(crate) struct MenuRoot;
+#[derive(Component)]
+struct ContinueButton;
 #[derive(Component)]
 struct PlayButton;
 #[derive(Component)]
 struct QuitButton;
+#[derive(Component)]
+struct LevelStepButton(i32); // -1 previous, +1 next
+#[derive(Component)]
+struct LevelNameText;
+#[derive(Component)]
+struct LevelThumbnail;
+#[derive(Component)]
+struct ModeButton;
+#[derive(Component)]
+struct ModeText;
+#[derive(Component)]
+struct BestTimeText;
+#[derive(Component)]
+struct LevelStatusText;
+
+const PLAY_COLOR: Color = Color::srgb(0.15, 0.55, 0.25);
+const LOCKED_COLOR: Color = Color::srgb(0.3, 0.3, 0.33);
 
 pub struct MainMenuPlugin;
 impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(GamePhase::default())
-            .add_systems(Startup, spawn_main_menu)
-            .add_systems(Update, (menu_button_system, monitor_game_over));
+        app.add_systems(OnEnter(AppState::Menu), respawn_menu_on_return).add_systems(
+            Update,
+            (menu_button_system.run_if(in_state(AppState::Menu)), update_level_selector, update_continue_button).chain(),
+        );
     }
 }
 
-fn monitor_game_over(
-    mut commands: Commands,
-    mut phase: ResMut<GamePhase>,
-    score: Res<Score>,
-    assets: Res<AssetServer>,
-    q_ball: Query<Entity, With<Ball>>,
-    q_menu: Query<Entity, With<MenuRoot>>,
-) {
-    if *phase == GamePhase::Playing && score.game_over {
-        // Despawn any existing balls.
-        for e in q_ball.iter() {
-            commands.entity(e).despawn_recursive();
-        }
-        *phase = GamePhase::Menu;
-        // Re-create menu if it was removed.
-        if q_menu.get_single().is_err() {
-            spawn_main_menu(commands, assets, Some(score));
-        }
+fn respawn_menu_on_return(commands: Commands, assets: Res<AssetServer>, q_menu: Query<Entity, With<MenuRoot>>) {
+    if q_menu.is_empty() {
+        spawn_main_menu(commands, assets);
     }
 }
 
 fn spawn_main_menu(
     mut commands: Commands,
     assets: Res<AssetServer>,
-    score: Option<Res<Score>>,
 ) {
     // Root node (full screen overlay)
     let font = assets.load("fonts/FiraSans-Bold.ttf");
-    let high_score = score
-        .as_ref()
-        .and_then(|s| s.high_score_time)
-        .map(|v| format!("{:.2}s", v))
-        .unwrap_or_else(|| "--".to_string());
 
     commands
         .spawn((
@@ -81,73 +89,196 @@ fn spawn_main_menu(
         ))
         .with_children(|parent| {
             // Title
-            parent.spawn(TextBundle::from_section(
-                "Vibe Golf",
-                TextStyle { font: font.clone(), font_size: 56.0, color: Color::srgb(0.95, 0.95, 1.0) },
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle { font: font.clone(), font_size: 56.0, color: Color::srgb(0.95, 0.95, 1.0) },
+                ),
+                LocalizedText::new("menu.title"),
             ));
             // Tutorial tagline
-            parent.spawn(
+            parent.spawn((
                 TextBundle::from_section(
-                    "Find the ducks as fast as you can",
+                    "",
                     TextStyle { font: font.clone(), font_size: 28.0, color: Color::srgb(0.90, 0.90, 0.95) },
                 )
                 .with_style(Style { margin: UiRect::all(Val::Px(6.0)), ..default() }),
-            );
+                LocalizedText::new("menu.tagline"),
+            ));
             // Basic controls
-            parent.spawn(
+            parent.spawn((
                 TextBundle::from_section(
-                    "Left Click: Hold + release to shoot\nRight Click: Hold to orbit camera\nScroll Wheel: Zoom\nR: Restart after game over\nMobile: Hold + release to shoot | Swipe to look | Pinch to zoom",
+                    "",
                     TextStyle { font: font.clone(), font_size: 18.0, color: Color::srgb(0.70, 0.70, 0.75) },
                 )
                 .with_style(Style { margin: UiRect::all(Val::Px(4.0)), ..default() }),
+                LocalizedText::new("menu.controls"),
+            ));
+            // Continue Button (shown by update_continue_button)
+            spawn_button(
+                parent,
+                &font,
+                "menu.continue",
+                Color::srgb(0.15, 0.4, 0.6),
+                Some(ContinueButton),
             );
             // Play Button
             spawn_button(
                 parent,
                 &font,
-                "Play",
-                Color::srgb(0.15, 0.55, 0.25),
+                "menu.play",
+                PLAY_COLOR,
                 Some(PlayButton),
             );
-            // Level selector placeholder (disabled look)
-            parent.spawn(
+            // Level selector: < [thumbnail + name] >  (filled in by update_level_selector)
+            parent
+                .spawn(NodeBundle {
+                    style: Style { align_items: AlignItems::Center, column_gap: Val::Px(12.0), ..default() },
+                    ..default()
+                })
+                .with_children(|row| {
+                    spawn_step_button(row, &font, "<", -1);
+                    row.spawn(NodeBundle {
+                        style: Style { flex_direction: FlexDirection::Column, align_items: AlignItems::Center, row_gap: Val::Px(4.0), ..default() },
+                        ..default()
+                    })
+                    .with_children(|col| {
+                        col.spawn((
+                            ImageBundle {
+                                style: Style { width: Val::Px(96.0), height: Val::Px(96.0), ..default() },
+                                visibility: Visibility::Hidden,
+                                ..default()
+                            },
+                            LevelThumbnail,
+                        ));
+                        col.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle { font: font.clone(), font_size: 28.0, color: Color::srgb(0.85, 0.85, 0.92) },
+                            ),
+                            LocalizedText::new("menu.level"),
+                            LevelNameText,
+                        ));
+                    });
+                    spawn_step_button(row, &font, ">", 1);
+                });
+            // Game mode (click to cycle; filled in by update_level_selector)
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            padding: UiRect::axes(Val::Px(16.0), Val::Px(6.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        background_color: BackgroundColor(Color::srgb(0.2, 0.22, 0.3)),
+                        ..default()
+                    },
+                    ModeButton,
+                ))
+                .with_children(|b| {
+                    b.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle { font: font.clone(), font_size: 24.0, color: Color::srgb(0.95, 0.95, 1.0) },
+                        ),
+                        LocalizedText::new("menu.mode").with_arg("mode", ""),
+                        ModeText,
+                    ));
+                });
+            // Local hot-seat player count (click to cycle; handled by players.rs)
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            padding: UiRect::axes(Val::Px(16.0), Val::Px(6.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        background_color: BackgroundColor(Color::srgb(0.2, 0.22, 0.3)),
+                        ..default()
+                    },
+                    PlayersButton,
+                ))
+                .with_children(|b| {
+                    b.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle { font: font.clone(), font_size: 24.0, color: Color::srgb(0.95, 0.95, 1.0) },
+                        ),
+                        PlayersText,
+                    ));
+                });
+            // Best record of the selected level in that mode
+            parent.spawn((
                 TextBundle::from_section(
-                    "Level: 1 / 1",
-                    TextStyle { font: font.clone(), font_size: 28.0, color: Color::srgb(0.75, 0.75, 0.80) },
+                    "",
+                    TextStyle { font: font.clone(), font_size: 24.0, color: Color::srgb(0.85, 0.85, 0.90) },
                 )
-                .with_style(Style { margin: UiRect::all(Val::Px(4.0)), ..default() }),
-            );
-            // High score display
-            parent.spawn(
+                .with_style(Style { margin: UiRect::all(Val::Px(2.0)), ..default() }),
+                LocalizedText::new("menu.best").with_arg("record", "--"),
+                BestTimeText,
+            ));
+            // Campaign status: locked / medal / completed
+            parent.spawn((
                 TextBundle::from_section(
-                    format!("Best Time: {high_score}"),
-                    TextStyle { font: font.clone(), font_size: 24.0, color: Color::srgb(0.85, 0.85, 0.90) },
+                    "",
+                    TextStyle { font: font.clone(), font_size: 20.0, color: Color::srgb(0.95, 0.85, 0.45) },
                 )
                 .with_style(Style { margin: UiRect::all(Val::Px(2.0)), ..default() }),
+                LocalizedText::new("menu.not_completed"),
+                LevelStatusText,
+            ));
+            // Watch Replay Button (shown and handled by replay.rs)
+            spawn_button(
+                parent,
+                &font,
+                "menu.watch_replay",
+                Color::srgb(0.25, 0.3, 0.5),
+                Some(WatchReplayButton),
+            );
+            // Achievements Button (opens the panel, see achievements.rs)
+            spawn_button(
+                parent,
+                &font,
+                "menu.achievements",
+                Color::srgb(0.45, 0.35, 0.1),
+                Some(AchievementsButton),
+            );
+            // Challenge Button (code entry panel, see challenge.rs)
+            spawn_button(
+                parent,
+                &font,
+                "menu.challenge",
+                Color::srgb(0.35, 0.2, 0.45),
+                Some(ChallengeButton),
             );
             // Quit Button
             spawn_button(
                 parent,
                 &font,
-                "Quit",
+                "menu.quit",
                 Color::srgb(0.55, 0.15, 0.15),
                 Some(QuitButton),
             );
             // Footer hint
-            parent.spawn(
+            parent.spawn((
                 TextBundle::from_section(
-                    "© 2025 Vibe Golf",
+                    "",
                     TextStyle { font: font.clone(), font_size: 16.0, color: Color::srgb(0.55, 0.55, 0.60) },
                 )
                 .with_style(Style { position_type: PositionType::Absolute, bottom: Val::Px(10.0), right: Val::Px(12.0), ..default() }),
-            );
+                LocalizedText::new("menu.footer"),
+            ));
         });
 }
 
-fn spawn_button<T: Component>(
+pub(crate) fn spawn_button<T: Component>(
     parent: &mut ChildBuilder,
     font: &Handle<Font>,
-    label: &str,
+    label_key: &'static str, // localization key
     base_color: Color,
     marker: Option<T>,
 ) {
@@ -166,31 +297,189 @@ fn spawn_button<T: Component>(
         ec.insert(m);
     }
     ec.with_children(|b| {
-        b.spawn(TextBundle::from_section(
-            label,
-            TextStyle {
-                font: font.clone(),
-                font_size: 30.0,
-                color: Color::srgb(0.95, 0.95, 1.0),
-            },
+        b.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 30.0,
+                    color: Color::srgb(0.95, 0.95, 1.0),
+                },
+            ),
+            LocalizedText::new(label_key),
         ));
     });
 }
 
+fn spawn_step_button(parent: &mut ChildBuilder, font: &Handle<Font>, label: &str, step: i32) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(44.0),
+                    height: Val::Px(44.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgb(0.2, 0.22, 0.3)),
+                ..default()
+            },
+            LevelStepButton(step),
+        ))
+        .with_children(|b| {
+            b.spawn(TextBundle::from_section(
+                label,
+                TextStyle { font: font.clone(), font_size: 30.0, color: Color::srgb(0.95, 0.95, 1.0) },
+            ));
+        });
+}
+
+fn update_level_selector(
+    manifest: Option<Res<LevelManifest>>,
+    campaign: Option<Res<CampaignState>>,
+    settings: Option<Res<UserSettings>>,
+    profile: Option<Res<Profile>>,
+    loc: Res<Localization>,
+    assets: Res<AssetServer>,
+    q_added: Query<(), Added<LevelNameText>>,
+    mut q_name: Query<&mut LocalizedText, (With<LevelNameText>, Without<BestTimeText>, Without<LevelStatusText>, Without<ModeText>)>,
+    mut q_mode: Query<&mut LocalizedText, (With<ModeText>, Without<LevelNameText>, Without<BestTimeText>, Without<LevelStatusText>)>,
+    mut q_best: Query<&mut LocalizedText, (With<BestTimeText>, Without<LevelNameText>, Without<LevelStatusText>, Without<ModeText>)>,
+    mut q_status: Query<&mut LocalizedText, (With<LevelStatusText>, Without<LevelNameText>, Without<BestTimeText>, Without<ModeText>)>,
+    mut q_play: Query<&mut BackgroundColor, With<PlayButton>>,
+    mut q_thumb: Query<(&mut UiImage, &mut Visibility), With<LevelThumbnail>>,
+) {
+    let Some(manifest) = manifest else { return; };
+    let campaign_changed = campaign.as_ref().is_some_and(|c| c.is_changed());
+    let settings_changed = settings.as_ref().is_some_and(|s| s.is_changed());
+    let profile_changed = profile.as_ref().is_some_and(|p| p.is_changed());
+    if !(manifest.is_changed() || campaign_changed || settings_changed || profile_changed || loc.is_changed() || !q_added.is_empty())
+    {
+        return;
+    }
+    let Some(entry) = manifest.selected_entry() else { return; };
+    for mut label in &mut q_name {
+        label.args = vec![
+            ("level", (manifest.selected + 1).to_string()),
+            ("levels", manifest.levels.len().to_string()),
+            ("name", entry.name.clone()),
+            ("difficulty", loc.t(entry.difficulty.label_key()).to_string()),
+        ];
+    }
+    let mode = settings.as_ref().map(|s| s.game_mode).unwrap_or_default();
+    for mut label in &mut q_mode {
+        label.args = vec![("mode", loc.t(mode.rules().label_key()).to_string())];
+    }
+    let score_id = entry.score_id();
+    let best = profile
+        .as_ref()
+        .and_then(|p| p.best(&mode.record_key(&score_id)))
+        .map_or_else(|| "--".to_string(), |r| mode.rules().format_record(&loc, r));
+    for mut label in &mut q_best {
+        label.args = vec![("record", best.clone())];
+    }
+    let unlocked = campaign.as_ref().is_none_or(|c| c.is_unlocked(&manifest, manifest.selected));
+    let progress = campaign.as_ref().and_then(|c| c.progress(&score_id)).filter(|p| p.completed);
+    let (key, args) = if !unlocked {
+        let required = CampaignState::requirement(&manifest, manifest.selected).unwrap_or_default();
+        let name = manifest.index_of(required).map_or(required.to_string(), |i| manifest.levels[i].name.clone());
+        ("menu.locked", vec![("level", name)])
+    } else {
+        match progress.map(|p| p.medal) {
+            Some(Some(medal)) => ("menu.medal", vec![("medal", loc.t(medal.label_key()).to_string())]),
+            Some(None) => ("menu.completed", Vec::new()),
+            None => ("menu.not_completed", Vec::new()),
+        }
+    };
+    for mut label in &mut q_status {
+        label.key = key;
+        label.args = args.clone();
+    }
+    for mut color in &mut q_play {
+        color.0 = if unlocked { PLAY_COLOR } else { LOCKED_COLOR };
+    }
+    for (mut image, mut vis) in &mut q_thumb {
+        match &entry.thumbnail {
+            Some(path) => {
+                image.texture = assets.load(path.clone());
+                *vis = Visibility::Inherited;
+            }
+            None => *vis = Visibility::Hidden,
+        }
+    }
+}
+
+fn update_continue_button(
+    slot: Option<Res<RoundSlot>>,
+    manifest: Option<Res<LevelManifest>>,
+    mut q_button: Query<&mut Style, With<ContinueButton>>,
+) {
+    let resumable = match (slot, manifest) {
+        (Some(slot), Some(manifest)) => slot.saved.as_ref().is_some_and(|s| s.resume_index(&manifest).is_some()),
+        _ => false,
+    };
+    let display = if resumable { Display::Flex } else { Display::None };
+    for mut style in &mut q_button {
+        if style.display != display {
+            style.display = display;
+        }
+    }
+}
+
 fn menu_button_system(
     mut commands: Commands,
-    mut phase: ResMut<GamePhase>,
+    mut next_state: ResMut<NextState<AppState>>,
     mut exit: EventWriter<AppExit>,
-    q_buttons: Query<(&Interaction, Entity, Option<&PlayButton>, Option<&QuitButton>), (Changed<Interaction>, With<Button>)>,
+    mut manifest: Option<ResMut<LevelManifest>>,
+    campaign: Option<Res<CampaignState>>,
+    mut slot: Option<ResMut<RoundSlot>>,
+    mut settings: Option<ResMut<UserSettings>>,
+    q_buttons: Query<
+        (
+            &Interaction,
+            Option<&PlayButton>,
+            Option<&ContinueButton>,
+            Option<&QuitButton>,
+            Option<&LevelStepButton>,
+            Option<&ModeButton>,
+        ),
+        (Changed<Interaction>, With<Button>),
+    >,
     q_root: Query<Entity, With<MenuRoot>>,
 ) {
-    if *phase != GamePhase::Menu {
-        return;
-    }
-    for (interaction, _entity, play, quit) in &q_buttons {
+    for (interaction, play, resume, quit, step, mode) in &q_buttons {
         if *interaction == Interaction::Pressed {
-            if play.is_some() {
-                *phase = GamePhase::Playing;
+            if let Some(step) = step {
+                if let Some(m) = manifest.as_mut() {
+                    m.cycle(step.0);
+                }
+            } else if mode.is_some() {
+                if let Some(settings) = settings.as_mut() {
+                    settings.game_mode = settings.game_mode.cycle(1);
+                }
+            } else if resume.is_some() {
+                // Select the saved level (switched in like Play) and let save_game apply the round.
+                let (Some(m), Some(slot)) = (manifest.as_mut(), slot.as_mut()) else { continue; };
+                let Some(index) = slot.saved.as_ref().and_then(|s| s.resume_index(m)) else { continue; };
+                m.selected = index;
+                slot.pending = true;
+                next_state.set(AppState::Playing);
+                if let Ok(root) = q_root.get_single() {
+                    commands.entity(root).despawn_recursive();
+                }
+            } else if play.is_some() {
+                let locked = match (manifest.as_deref(), campaign.as_deref()) {
+                    (Some(m), Some(c)) => !c.is_unlocked(m, m.selected),
+                    _ => false,
+                };
+                if locked {
+                    continue;
+                }
+                if let Some(slot) = slot.as_mut() {
+                    slot.clear();
+                }
+                next_state.set(AppState::Playing);
                 if let Ok(root) = q_root.get_single() {
                     commands.entity(root).despawn_recursive();
                 }
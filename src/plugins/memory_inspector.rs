@@ -0,0 +1,255 @@
+// Memory inspector (F6).
+// Once per second the asset collections are measured - count and approximate CPU-side bytes of
+// meshes (vertex + index buffers), images (pixel data), standard and terrain materials (struct
+// size) - and the entities are counted per owning feature (terrain chunks, trees, grass,
+// particles, targets, props, UI, ...). Assets only kept in the render world are not counted.
+// Each asset kind keeps a short history: a count that keeps rising over the whole window is
+// flagged as growing, which is how leaks like terrain chunk meshes surviving their chunk show up.
+// `MemoryReport` is sampled whether or not the panel is shown, so other tooling (and the perf
+// tuner's settings) can be judged against it.
+
+use std::collections::VecDeque;
+
+use bevy::pbr::ExtendedMaterial;
+use bevy::prelude::*;
+use bevy::render::mesh::Indices;
+
+use crate::plugins::ball::Ball;
+use crate::plugins::grass::GrassTile;
+use crate::plugins::hud_layout::HudAnchor;
+use crate::plugins::level_props::PropTag;
+use crate::plugins::particles::Particle;
+use crate::plugins::target::Target;
+use crate::plugins::terrain::TerrainChunk;
+use crate::plugins::terrain_material::RealTerrainExtension;
+use crate::plugins::vegetation::Tree;
+
+/// Samples kept per asset kind (one per second).
+pub const HISTORY_SAMPLES: usize = 10;
+/// Rise over the whole history that counts as growing.
+pub const GROWTH_THRESHOLD: usize = 20;
+const SAMPLE_INTERVAL_S: f32 = 1.0;
+
+type TerrainMaterial = ExtendedMaterial<StandardMaterial, RealTerrainExtension>;
+
+/// CPU-side bytes of a mesh: every vertex attribute plus the index buffer.
+pub fn mesh_bytes(mesh: &Mesh) -> usize {
+    let vertices = mesh.count_vertices() * mesh.get_vertex_size() as usize;
+    let indices = match mesh.indices() {
+        Some(Indices::U16(i)) => i.len() * 2,
+        Some(Indices::U32(i)) => i.len() * 4,
+        None => 0,
+    };
+    vertices + indices
+}
+
+/// `1536` -> "1.5 KB".
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 { format!("{bytes} B") } else { format!("{value:.1} {}", UNITS[unit]) }
+}
+
+/// Whether a full history never shrinks and rose by at least `threshold` overall.
+pub fn steady_growth(history: &VecDeque<usize>, threshold: usize) -> bool {
+    history.len() >= HISTORY_SAMPLES
+        && history.iter().zip(history.iter().skip(1)).all(|(a, b)| b >= a)
+        && history.back().zip(history.front()).is_some_and(|(last, first)| last - first >= threshold)
+}
+
+/// One asset collection.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AssetUsage {
+    pub name: &'static str,
+    pub count: usize,
+    pub bytes: usize,
+    pub history: VecDeque<usize>, // counts, oldest first
+}
+
+impl AssetUsage {
+    pub fn record(&mut self, count: usize, bytes: usize) {
+        self.count = count;
+        self.bytes = bytes;
+        if self.history.len() == HISTORY_SAMPLES {
+            self.history.pop_front();
+        }
+        self.history.push_back(count);
+    }
+
+    pub fn growing(&self) -> bool {
+        steady_growth(&self.history, GROWTH_THRESHOLD)
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct MemoryReport {
+    pub meshes: AssetUsage,
+    pub images: AssetUsage,
+    pub materials: AssetUsage,
+    pub terrain_materials: AssetUsage,
+    pub entities: Vec<(&'static str, usize)>, // per feature; "other" is the rest
+    pub total_entities: usize,
+}
+
+impl Default for MemoryReport {
+    fn default() -> Self {
+        let usage = |name| AssetUsage { name, ..default() };
+        Self {
+            meshes: usage("meshes"),
+            images: usage("images"),
+            materials: usage("materials"),
+            terrain_materials: usage("terrain materials"),
+            entities: Vec::new(),
+            total_entities: 0,
+        }
+    }
+}
+
+impl MemoryReport {
+    pub fn assets(&self) -> [&AssetUsage; 4] {
+        [&self.meshes, &self.images, &self.materials, &self.terrain_materials]
+    }
+
+    pub fn asset_bytes(&self) -> usize {
+        self.assets().iter().map(|a| a.bytes).sum()
+    }
+
+    /// Panel text.
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![format!("Memory (F6)  assets ~{}", format_bytes(self.asset_bytes()))];
+        for a in self.assets() {
+            let flag = if a.growing() { "  GROWING" } else { "" };
+            lines.push(format!("{:<18}{:>7}  {:>9}{flag}", a.name, a.count, format_bytes(a.bytes)));
+        }
+        lines.push(format!("entities {}", self.total_entities));
+        lines.extend(self.entities.iter().map(|(name, n)| format!("  {name:<16}{n:>7}")));
+        lines
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct MemoryInspector {
+    pub visible: bool,
+}
+
+#[derive(Component)]
+struct MemoryPanel;
+#[derive(Component)]
+struct MemoryPanelText;
+
+pub struct MemoryInspectorPlugin;
+impl Plugin for MemoryInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MemoryInspector>()
+            .init_resource::<MemoryReport>()
+            .add_systems(Startup, spawn_memory_panel)
+            .add_systems(Update, (toggle_memory_panel, sample_memory, update_memory_panel).chain());
+    }
+}
+
+fn spawn_memory_panel(mut commands: Commands, assets: Res<AssetServer>) {
+    let font = assets.load("fonts/FiraSans-Bold.ttf");
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(12.0),
+                    top: Val::Px(260.0),
+                    padding: UiRect::all(Val::Px(6.0)),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.55)),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            HudAnchor::top_right(12.0, 260.0),
+            MemoryPanel,
+            Name::new("MemoryInspector"),
+        ))
+        .with_children(|p| {
+            p.spawn((
+                TextBundle::from_section("", TextStyle { font, font_size: 14.0, color: Color::WHITE }),
+                MemoryPanelText,
+            ));
+        });
+}
+
+fn toggle_memory_panel(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut inspector: ResMut<MemoryInspector>,
+    mut q_panel: Query<&mut Visibility, With<MemoryPanel>>,
+) {
+    if keys.just_pressed(KeyCode::F6) {
+        inspector.visible = !inspector.visible;
+    }
+    if let Ok(mut vis) = q_panel.get_single_mut() {
+        let want = if inspector.visible { Visibility::Inherited } else { Visibility::Hidden };
+        if *vis != want {
+            *vis = want;
+        }
+    }
+}
+
+fn count<F: bevy::ecs::query::QueryFilter>(world: &mut World) -> usize {
+    world.query_filtered::<(), F>().iter(world).count()
+}
+
+// Exclusive: the entity counts need one query per feature.
+fn sample_memory(world: &mut World, mut since_sample: Local<f32>) {
+    *since_sample += world.resource::<Time>().delta_seconds();
+    if *since_sample < SAMPLE_INTERVAL_S {
+        return;
+    }
+    *since_sample = 0.0;
+    let meshes = world
+        .get_resource::<Assets<Mesh>>()
+        .map_or((0, 0), |a| (a.len(), a.iter().map(|(_, m)| mesh_bytes(m)).sum()));
+    let images = world
+        .get_resource::<Assets<Image>>()
+        .map_or((0, 0), |a| (a.len(), a.iter().map(|(_, i)| i.data.len()).sum()));
+    let materials = world
+        .get_resource::<Assets<StandardMaterial>>()
+        .map_or((0, 0), |a| (a.len(), a.len() * std::mem::size_of::<StandardMaterial>()));
+    let terrain_materials = world
+        .get_resource::<Assets<TerrainMaterial>>()
+        .map_or((0, 0), |a| (a.len(), a.len() * std::mem::size_of::<TerrainMaterial>()));
+    let entities = vec![
+        ("terrain chunks", count::<With<TerrainChunk>>(world)),
+        ("trees", count::<With<Tree>>(world)),
+        ("grass tiles", count::<With<GrassTile>>(world)),
+        ("particles", count::<With<Particle>>(world)),
+        ("targets", count::<With<Target>>(world)),
+        ("props", count::<With<PropTag>>(world)),
+        ("ball", count::<With<Ball>>(world)),
+        ("ui nodes", count::<With<Node>>(world)),
+    ];
+    let total = world.entities().len() as usize;
+    let mut report = world.resource_mut::<MemoryReport>();
+    report.meshes.record(meshes.0, meshes.1);
+    report.images.record(images.0, images.1);
+    report.materials.record(materials.0, materials.1);
+    report.terrain_materials.record(terrain_materials.0, terrain_materials.1);
+    let counted: usize = entities.iter().map(|(_, n)| n).sum();
+    report.entities = entities;
+    report.entities.push(("other", total.saturating_sub(counted)));
+    report.total_entities = total;
+}
+
+fn update_memory_panel(
+    inspector: Res<MemoryInspector>,
+    report: Res<MemoryReport>,
+    mut q_text: Query<&mut Text, With<MemoryPanelText>>,
+) {
+    if !inspector.visible || !report.is_changed() {
+        return;
+    }
+    if let Ok(mut text) = q_text.get_single_mut() {
+        text.sections[0].value = report.lines().join("\n");
+    }
+}
@@ -0,0 +1,237 @@
+// Minimap: top-down color-mapped heightmap image rendered once at load, shown in the
+// bottom-left HUD corner with ball / target markers. Clicking the map drops a ping.
+// Pairs with the compass in hud.rs (compass = bearing, minimap = overview).
+
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::ui::RelativeCursorPosition;
+
+use crate::plugins::ball::Ball;
+use crate::plugins::hud_layout::HudAnchor;
+use crate::plugins::target::{Target, TargetFocus};
+use crate::plugins::terrain::TerrainSampler;
+use crate::plugins::terrain_material::{terrain_palette_color, RealTerrainUniform};
+
+const MINIMAP_RES: u32 = 256;
+const MINIMAP_PX: f32 = 180.0;
+const WATER_LEVEL: f32 = 25.0;
+
+#[derive(Resource, Clone)]
+pub struct MinimapConfig {
+    pub visible: bool,
+    pub ping_duration: f32, // seconds a ping stays on the map
+}
+impl Default for MinimapConfig {
+    fn default() -> Self {
+        Self { visible: true, ping_duration: 4.0 }
+    }
+}
+
+/// Sent when the player clicks the minimap (world position on the terrain surface).
+#[derive(Event, Debug, Clone, Copy)]
+pub struct MinimapPingEvent {
+    pub pos: Vec3,
+}
+
+#[derive(Resource, Default)]
+struct MinimapPing {
+    pos: Option<Vec3>,
+    remaining: f32,
+}
+
+#[derive(Component)]
+struct MinimapRoot;
+#[derive(Component)]
+struct MinimapBallMarker;
+#[derive(Component)]
+struct MinimapTargetMarker;
+#[derive(Component)]
+struct MinimapPingMarker;
+
+pub struct MinimapPlugin;
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(MinimapConfig::default())
+            .init_resource::<MinimapPing>()
+            .add_event::<MinimapPingEvent>()
+            .add_systems(Startup, spawn_minimap)
+            .add_systems(Update, (minimap_click_ping, update_minimap_markers.after(minimap_click_ping)));
+    }
+}
+
+/// Bake a color-mapped top-down image of the heightmap (row 0 = -Z edge).
+fn bake_minimap_image(sampler: &TerrainSampler, res: u32) -> Image {
+    let world = sampler.cfg.heightmap_world_size;
+    let mut heights = Vec::with_capacity((res * res) as usize);
+    for j in 0..res {
+        for i in 0..res {
+            let x = (i as f32 / (res - 1) as f32 - 0.5) * world;
+            let z = (j as f32 / (res - 1) as f32 - 0.5) * world;
+            heights.push(sampler.height(x, z));
+        }
+    }
+    let (min_h, max_h) = heights.iter().fold((f32::MAX, f32::MIN), |(mn, mx), &h| (mn.min(h), mx.max(h)));
+    let span = (max_h - min_h).max(1e-3);
+    let palette = RealTerrainUniform::default();
+    let mut data = Vec::with_capacity((res * res * 4) as usize);
+    for j in 0..res {
+        for i in 0..res {
+            let h = heights[(j * res + i) as usize];
+            let col = if h < WATER_LEVEL {
+                Vec3::new(0.05, 0.25, 0.6)
+            } else {
+                let x = (i as f32 / (res - 1) as f32 - 0.5) * world;
+                let z = (j as f32 / (res - 1) as f32 - 0.5) * world;
+                let slope = 1.0 - sampler.normal(x, z).y.clamp(0.0, 1.0);
+                // Light hillshade so relief reads at a glance.
+                let shade = 0.75 + 0.25 * ((h - min_h) / span);
+                let biome = sampler.biome(x, z).params().palette;
+                terrain_palette_color(&palette, biome, (h - min_h) / span, slope) * shade
+            };
+            data.extend_from_slice(&[
+                (col.x * 255.0) as u8,
+                (col.y * 255.0) as u8,
+                (col.z * 255.0) as u8,
+                230,
+            ]);
+        }
+    }
+    Image::new(
+        Extent3d { width: res, height: res, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+fn spawn_minimap(
+    mut commands: Commands,
+    sampler: Res<TerrainSampler>,
+    cfg: Res<MinimapConfig>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let image = images.add(bake_minimap_image(&sampler, MINIMAP_RES));
+    info!("Minimap baked ({}x{})", MINIMAP_RES, MINIMAP_RES);
+
+    let marker = |color: Color, size: f32| NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            width: Val::Px(size),
+            height: Val::Px(size),
+            margin: UiRect { left: Val::Px(-size * 0.5), top: Val::Px(-size * 0.5), ..default() },
+            ..default()
+        },
+        background_color: BackgroundColor(color),
+        visibility: Visibility::Hidden,
+        ..default()
+    };
+
+    commands
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(12.0),
+                    bottom: Val::Px(12.0),
+                    width: Val::Px(MINIMAP_PX),
+                    height: Val::Px(MINIMAP_PX),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                border_color: BorderColor(Color::srgba(1.0, 1.0, 1.0, 0.35)),
+                image: UiImage::new(image),
+                visibility: if cfg.visible { Visibility::Inherited } else { Visibility::Hidden },
+                ..default()
+            },
+            RelativeCursorPosition::default(),
+            HudAnchor::bottom_left(12.0, 12.0),
+            MinimapRoot,
+        ))
+        .with_children(|p| {
+            p.spawn((marker(Color::srgb(1.0, 0.85, 0.1), 14.0), MinimapPingMarker));
+            p.spawn((marker(Color::srgb(0.95, 0.2, 0.2), 9.0), MinimapTargetMarker));
+            p.spawn((marker(Color::WHITE, 7.0), MinimapBallMarker));
+        });
+}
+
+/// Minimap UV (0..1, top-left = -x/-z corner) of world position `xz` on a heightmap square of
+/// `world_size` m; `None` off the map.
+#[inline]
+pub fn world_to_map(world_size: f32, xz: Vec2) -> Option<Vec2> {
+    let uv = xz / world_size + Vec2::splat(0.5);
+    if (0.0..=1.0).contains(&uv.x) && (0.0..=1.0).contains(&uv.y) { Some(uv) } else { None }
+}
+
+/// World x/z under minimap UV `uv` (the inverse of `world_to_map`).
+#[inline]
+pub fn map_to_world(world_size: f32, uv: Vec2) -> Vec2 {
+    (uv - Vec2::splat(0.5)) * world_size
+}
+
+fn minimap_click_ping(
+    cfg: Res<MinimapConfig>,
+    sampler: Res<TerrainSampler>,
+    mut ping: ResMut<MinimapPing>,
+    mut ev_ping: EventWriter<MinimapPingEvent>,
+    q_root: Query<(&Interaction, &RelativeCursorPosition), (Changed<Interaction>, With<MinimapRoot>)>,
+) {
+    if !cfg.visible {
+        return;
+    }
+    for (interaction, rel) in &q_root {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(uv) = rel.normalized else { continue; };
+        let xz = map_to_world(sampler.cfg.heightmap_world_size, uv);
+        let pos = Vec3::new(xz.x, sampler.height(xz.x, xz.y), xz.y);
+        ping.pos = Some(pos);
+        ping.remaining = cfg.ping_duration;
+        ev_ping.send(MinimapPingEvent { pos });
+        info!("MINIMAP ping=({:.1},{:.1},{:.1})", pos.x, pos.y, pos.z);
+    }
+}
+
+fn update_minimap_markers(
+    time: Res<Time>,
+    sampler: Res<TerrainSampler>,
+    mut ping: ResMut<MinimapPing>,
+    focus: Res<TargetFocus>,
+    q_ball: Query<&Transform, With<Ball>>,
+    q_target: Query<&Transform, (With<Target>, Without<Ball>)>,
+    mut q_markers: Query<
+        (&mut Style, &mut Visibility, Option<&MinimapBallMarker>, Option<&MinimapTargetMarker>),
+        Or<(With<MinimapBallMarker>, With<MinimapTargetMarker>, With<MinimapPingMarker>)>,
+    >,
+) {
+    if ping.pos.is_some() {
+        ping.remaining -= time.delta_seconds();
+        if ping.remaining <= 0.0 {
+            ping.pos = None;
+        }
+    }
+    // Ping blinks at 4 Hz while active.
+    let ping_on = ping.pos.is_some() && (ping.remaining * 4.0).fract() > 0.3;
+
+    for (mut style, mut vis, ball, target) in &mut q_markers {
+        let world_pos = if ball.is_some() {
+            q_ball.get_single().ok().map(|t| t.translation)
+        } else if target.is_some() {
+            focus.entity.and_then(|e| q_target.get(e).ok()).map(|t| t.translation)
+        } else if ping_on {
+            ping.pos
+        } else {
+            None
+        };
+        match world_pos.and_then(|p| world_to_map(sampler.cfg.heightmap_world_size, p.xz())) {
+            Some(uv) => {
+                style.left = Val::Percent(uv.x * 100.0);
+                style.top = Val::Percent(uv.y * 100.0);
+                *vis = Visibility::Inherited;
+            }
+            None => *vis = Visibility::Hidden,
+        }
+    }
+}
@@ -0,0 +1,736 @@
+// Real-time networked multiplayer (cargo feature `net`): friends play the same course at the same
+// time. One peer hosts (`--host <port>`, native builds) and is the authority; the others join over
+// a WebSocket (`--join ws://<host>:<port>`, the browser build too). Peers exchange JSON
+// `NetMessage`s: everyone streams their ball (10 Hz) and shots, the host relays them and streams
+// its target positions, which joined peers on the same hole adopt. Holes are decided by the host
+// (`HoleReferee`): a joined peer's target hit is only a claim, awarded if nobody won that hole yet
+// and the reported ball is near the host's target. Claims on a hole the host has not reached are
+// held until it gets there and its targets for that hole are known. Remote balls show as spheres
+// in the player colors (players.rs); a HUD line lists holes won and shots per peer.
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::launch_options::LaunchOptions;
+use crate::plugins::app_state::AppState;
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::core_sim::GameSet;
+use crate::plugins::game_state::Score;
+use crate::plugins::level::LevelManifest;
+use crate::plugins::localization::Localization;
+use crate::plugins::particles::{ShotFiredEvent, TargetHitEvent};
+use crate::plugins::players::{player_color, MAX_PLAYERS};
+use crate::plugins::target::{Target, TargetFloat, TargetInfo, TargetKind, TargetMotion};
+use crate::plugins::terrain::TerrainSampler;
+use crate::plugins::ui_toast::{ToastKind, ToastQueue};
+
+pub type PeerId = u8;
+pub const HOST_ID: PeerId = 0;
+/// A claimed hit counts if the claimant's ball was this close to one of the host's targets (m);
+/// leaves room for latency and moving targets.
+pub const CLAIM_RADIUS: f32 = 15.0;
+const BALL_INTERVAL: f32 = 0.1; // s between ball updates
+const TARGET_INTERVAL: f32 = 0.5; // s between host target updates
+const ADOPT_DISTANCE: f32 = 1.0; // m a joined peer's target may differ from the host's before it moves
+
+type Point = (f32, f32, f32);
+
+fn point(v: Vec3) -> Point {
+    (v.x, v.y, v.z)
+}
+
+fn vec3(p: Point) -> Vec3 {
+    Vec3::new(p.0, p.1, p.2)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NetMessage {
+    /// Joiner -> host on connect, with the level it has selected.
+    Hello { level: String },
+    /// Host -> joiner: its peer id and the level the host plays.
+    Welcome { id: PeerId, level: String },
+    /// Ball of peer `id`, playing hole `hole` (holes finished so far).
+    Ball { id: PeerId, pos: Point, hole: u32 },
+    Shot { id: PeerId, pos: Point, power: f32 },
+    /// Host -> all: the host's targets on its hole `hole`, in `TargetInfo::index` order.
+    Targets { hole: u32, positions: Vec<Point> },
+    /// Joiner -> host: the sender's ball reached the target of hole `hole` at `pos`.
+    Claim { hole: u32, pos: Point },
+    /// Host -> all: hole `hole` goes to `winner`.
+    Awarded { hole: u32, winner: PeerId },
+    Left { id: PeerId },
+}
+
+impl NetMessage {
+    pub fn encode(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn decode(text: &str) -> Option<Self> {
+        serde_json::from_str(text).ok()
+    }
+}
+
+/// Host-side hole decisions: the first valid claim on a hole wins it.
+#[derive(Debug, Clone, Default)]
+pub struct HoleReferee {
+    pub winners: HashMap<u32, PeerId>,
+    targets: HashMap<u32, Vec<Vec3>>, // host's latest targets per hole, once the host got there
+    held: HashMap<u32, Vec<(PeerId, Vec3)>>, // claims on holes without targets yet, oldest first
+}
+
+impl HoleReferee {
+    /// The host's targets on `hole`. Held claims on it are decided now, in the order they came
+    /// in; returns the peer the hole goes to, if one of them wins it.
+    pub fn record_targets(&mut self, hole: u32, positions: Vec<Vec3>) -> Option<PeerId> {
+        self.targets.insert(hole, positions);
+        let held = self.held.remove(&hole)?;
+        held.into_iter().find(|(peer, ball)| self.claim(hole, *peer, *ball)).map(|(peer, _)| peer)
+    }
+
+    /// Decide `peer`'s claim on `hole` with its ball at `ball`; `true` if the hole is awarded.
+    /// Holes the host has not reached yet cannot be checked: the claim is held (one per peer)
+    /// until `record_targets` brings that hole's targets.
+    pub fn claim(&mut self, hole: u32, peer: PeerId, ball: Vec3) -> bool {
+        if self.winners.contains_key(&hole) {
+            return false;
+        }
+        let Some(targets) = self.targets.get(&hole) else {
+            let held = self.held.entry(hole).or_default();
+            if held.iter().all(|(p, _)| *p != peer) {
+                held.push((peer, ball));
+            }
+            return false;
+        };
+        let near = targets.iter().any(|t| t.distance(ball) <= CLAIM_RADIUS);
+        if near {
+            self.winners.insert(hole, peer);
+        }
+        near
+    }
+
+    pub fn wins(&self, peer: PeerId) -> usize {
+        self.winners.values().filter(|w| **w == peer).count()
+    }
+}
+
+/// Messages in from the sockets (tagged with the sender) and, natively, the socket writers.
+#[derive(Resource, Clone, Default)]
+pub struct NetLink {
+    inbox: Arc<Mutex<VecDeque<(PeerId, NetMessage)>>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    writers: Arc<Mutex<HashMap<PeerId, std::sync::mpsc::Sender<String>>>>,
+}
+
+impl NetLink {
+    fn push(&self, from: PeerId, msg: NetMessage) {
+        if let Ok(mut inbox) = self.inbox.lock() {
+            inbox.push_back((from, msg));
+        }
+    }
+
+    /// Everything received since the last call, oldest first.
+    pub fn drain(&self) -> Vec<(PeerId, NetMessage)> {
+        self.inbox.lock().map(|mut inbox| inbox.drain(..).collect()).unwrap_or_default()
+    }
+
+    /// Accept joiners on `port` (ids 1.. up to the player limit).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn host(&self, port: u16) -> std::io::Result<()> {
+        native::host(self.clone(), port)
+    }
+
+    /// Connect to a host at `url` (`ws://host:port`); messages sent meanwhile go out once connected.
+    pub fn join(&self, url: &str) {
+        #[cfg(not(target_arch = "wasm32"))]
+        native::join(self.clone(), url.to_string());
+        #[cfg(target_arch = "wasm32")]
+        web::join(self.clone(), url);
+    }
+
+    pub fn send_to(&self, peer: PeerId, msg: &NetMessage) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(writer) = self.writers.lock().ok().and_then(|w| w.get(&peer).cloned()) {
+            let _ = writer.send(msg.encode());
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = peer; // the browser only ever talks to the host
+            web::send(msg.encode());
+        }
+    }
+
+    /// Send to every connected peer except `skip`.
+    pub fn broadcast(&self, msg: &NetMessage, skip: Option<PeerId>) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Ok(writers) = self.writers.lock() {
+            let text = msg.encode();
+            for (_, writer) in writers.iter().filter(|(id, _)| Some(**id) != skip) {
+                let _ = writer.send(text.clone());
+            }
+        }
+        #[cfg(target_arch = "wasm32")]
+        if skip != Some(HOST_ID) {
+            web::send(msg.encode());
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use std::io::{ErrorKind, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{channel, Receiver, TryRecvError};
+    use std::time::Duration;
+
+    use bevy::log::warn;
+    use tungstenite::stream::MaybeTlsStream;
+    use tungstenite::{Message, WebSocket};
+
+    use super::{NetLink, NetMessage, PeerId, HOST_ID, MAX_PLAYERS};
+
+    pub fn host(link: NetLink, port: u16) -> std::io::Result<()> {
+        let listener = TcpListener::bind(("0.0.0.0", port))?;
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let taken = |id: &PeerId| link.writers.lock().is_ok_and(|w| w.contains_key(id));
+                let Some(id) = (1..MAX_PLAYERS as PeerId).find(|id| !taken(id)) else { continue; }; // full
+                let ws = match tungstenite::accept(stream) {
+                    Ok(ws) => ws,
+                    Err(e) => {
+                        warn!("Net handshake failed: {e}");
+                        continue;
+                    }
+                };
+                let _ = ws.get_ref().set_nonblocking(true);
+                let (tx, rx) = channel();
+                if let Ok(mut writers) = link.writers.lock() {
+                    writers.insert(id, tx);
+                }
+                let link = link.clone();
+                std::thread::spawn(move || run_socket(ws, id, rx, link));
+            }
+        });
+        Ok(())
+    }
+
+    pub fn join(link: NetLink, url: String) {
+        let (tx, rx) = channel();
+        if let Ok(mut writers) = link.writers.lock() {
+            writers.insert(HOST_ID, tx);
+        }
+        std::thread::spawn(move || match tungstenite::connect(url.as_str()) {
+            Ok((ws, _)) => {
+                if let MaybeTlsStream::Plain(stream) = ws.get_ref() {
+                    let _ = stream.set_nonblocking(true);
+                }
+                run_socket(ws, HOST_ID, rx, link);
+            }
+            Err(e) => {
+                warn!("Could not join {url}: {e}");
+                closed(&link, HOST_ID);
+            }
+        });
+    }
+
+    fn would_block(e: &tungstenite::Error) -> bool {
+        matches!(e, tungstenite::Error::Io(io) if io.kind() == ErrorKind::WouldBlock)
+    }
+
+    // Pumps one connection until it closes: queued messages out, received messages into the inbox.
+    fn run_socket<S: Read + Write>(mut ws: WebSocket<S>, id: PeerId, outbox: Receiver<String>, link: NetLink) {
+        'connection: loop {
+            loop {
+                match outbox.try_recv() {
+                    Ok(text) => match ws.write(Message::Text(text)) {
+                        Err(e) if !would_block(&e) => break 'connection,
+                        _ => {}
+                    },
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => break 'connection,
+                }
+            }
+            match ws.flush() {
+                Err(e) if !would_block(&e) => break,
+                _ => {}
+            }
+            match ws.read() {
+                Ok(Message::Text(text)) => {
+                    if let Some(msg) = NetMessage::decode(&text) {
+                        link.push(id, msg);
+                    }
+                }
+                Ok(Message::Close(_)) => break,
+                Ok(_) => {}
+                Err(e) if would_block(&e) => std::thread::sleep(Duration::from_millis(5)),
+                Err(_) => break,
+            }
+        }
+        closed(&link, id);
+    }
+
+    fn closed(link: &NetLink, id: PeerId) {
+        if let Ok(mut writers) = link.writers.lock() {
+            writers.remove(&id);
+        }
+        link.push(id, NetMessage::Left { id });
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use std::cell::RefCell;
+
+    use bevy::log::warn;
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen::JsCast;
+    use web_sys::{CloseEvent, MessageEvent, WebSocket};
+
+    use super::{NetLink, NetMessage, HOST_ID};
+
+    thread_local! {
+        static SOCKET: RefCell<Option<WebSocket>> = const { RefCell::new(None) };
+        static PENDING: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) }; // sent before the socket opened
+    }
+
+    pub fn join(link: NetLink, url: &str) {
+        let ws = match WebSocket::new(url) {
+            Ok(ws) => ws,
+            Err(e) => {
+                warn!("Could not join {url}: {e:?}");
+                link.push(HOST_ID, NetMessage::Left { id: HOST_ID });
+                return;
+            }
+        };
+        let inbox = link.clone();
+        let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |e: MessageEvent| {
+            if let Some(msg) = e.data().as_string().and_then(|text| NetMessage::decode(&text)) {
+                inbox.push(HOST_ID, msg);
+            }
+        });
+        let on_open = Closure::<dyn FnMut()>::new(|| {
+            for text in PENDING.with(|p| std::mem::take(&mut *p.borrow_mut())) {
+                send(text);
+            }
+        });
+        let on_close = Closure::<dyn FnMut(CloseEvent)>::new(move |_: CloseEvent| {
+            SOCKET.with(|s| s.borrow_mut().take());
+            link.push(HOST_ID, NetMessage::Left { id: HOST_ID });
+        });
+        ws.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        ws.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        ws.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+        // The socket lives for the rest of the session.
+        on_message.forget();
+        on_open.forget();
+        on_close.forget();
+        SOCKET.with(|s| *s.borrow_mut() = Some(ws));
+    }
+
+    pub fn send(text: String) {
+        SOCKET.with(|s| match s.borrow().as_ref() {
+            Some(ws) if ws.ready_state() == WebSocket::OPEN => {
+                let _ = ws.send_with_str(&text);
+            }
+            Some(_) => PENDING.with(|p| p.borrow_mut().push(text)),
+            None => {}
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NetRole {
+    #[default]
+    Offline,
+    Host,
+    Joined,
+}
+
+/// What this peer knows about another one.
+#[derive(Debug, Clone, Default)]
+pub struct RemotePeer {
+    pub ball: Option<Vec3>,
+    pub hole: u32,
+    pub shots: u32,
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct NetSession {
+    pub role: NetRole,
+    pub id: PeerId,
+    pub peers: HashMap<PeerId, RemotePeer>,
+    pub referee: HoleReferee, // decides on the host, mirrors the host's awards elsewhere
+    shots: u32,
+}
+
+impl NetSession {
+    pub fn online(&self) -> bool {
+        self.role != NetRole::Offline
+    }
+
+    /// `P1 2 holes, 5 shots | P2 (you) 1 hole, 4 shots`.
+    pub fn standings(&self) -> String {
+        let mut ids: Vec<PeerId> = self.peers.keys().copied().chain([self.id]).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        let parts: Vec<String> = ids
+            .into_iter()
+            .map(|id| {
+                let you = if id == self.id { " (you)" } else { "" };
+                let shots = if id == self.id { self.shots } else { self.peers[&id].shots };
+                let wins = self.referee.wins(id);
+                let holes = if wins == 1 { "hole" } else { "holes" };
+                format!("P{}{you} {wins} {holes}, {shots} shots", id + 1)
+            })
+            .collect();
+        parts.join(" | ")
+    }
+}
+
+#[derive(Component)]
+struct RemoteBall(PeerId);
+#[derive(Component)]
+struct NetStatusText;
+
+pub struct NetPlugin;
+
+impl Plugin for NetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NetSession>()
+            .init_resource::<NetLink>()
+            .add_systems(Startup, (start_session, spawn_net_status))
+            .add_systems(Update, receive_messages.in_set(GameSet::Input))
+            .add_systems(Update, (claim_holes, send_shots).in_set(GameSet::React))
+            .add_systems(
+                Update,
+                (send_ball_state, send_targets, update_remote_balls, update_net_status).in_set(GameSet::Present),
+            );
+    }
+}
+
+fn start_session(
+    options: Option<Res<LaunchOptions>>,
+    manifest: Option<Res<LevelManifest>>,
+    link: Res<NetLink>,
+    mut session: ResMut<NetSession>,
+) {
+    let Some(options) = options else { return; };
+    if let Some(port) = options.host {
+        #[cfg(not(target_arch = "wasm32"))]
+        match link.host(port) {
+            Ok(()) => {
+                info!("Hosting a net session on port {port}");
+                session.role = NetRole::Host;
+                session.id = HOST_ID;
+            }
+            Err(e) => warn!("Could not host on port {port}: {e}"),
+        }
+        #[cfg(target_arch = "wasm32")]
+        warn!("The browser build cannot host a net session (port {port}); join one instead");
+    } else if let Some(url) = options.join.as_deref() {
+        info!("Joining the net session at {url}");
+        link.join(url);
+        session.role = NetRole::Joined;
+        let level = manifest.and_then(|m| m.selected_entry().map(|e| e.id.clone())).unwrap_or_default();
+        link.send_to(HOST_ID, &NetMessage::Hello { level });
+    }
+}
+
+fn receive_messages(
+    link: Res<NetLink>,
+    loc: Res<Localization>,
+    phase: Res<State<AppState>>,
+    sampler: Option<Res<TerrainSampler>>,
+    score: Option<Res<Score>>,
+    mut manifest: Option<ResMut<LevelManifest>>,
+    mut session: ResMut<NetSession>,
+    mut toasts: ResMut<ToastQueue>,
+    mut q_targets: Query<(&mut Transform, &mut TargetFloat, &TargetInfo, Option<&mut TargetMotion>, Option<&TargetKind>), With<Target>>,
+) {
+    let host = session.role == NetRole::Host;
+    for (from, msg) in link.drain() {
+        match msg {
+            NetMessage::Hello { level } => {
+                if !host {
+                    continue;
+                }
+                let ours = manifest.as_ref().and_then(|m| m.selected_entry().map(|e| e.id.clone())).unwrap_or(level);
+                link.send_to(from, &NetMessage::Welcome { id: from, level: ours });
+                session.peers.entry(from).or_default();
+                toasts.push_for(loc.tf("net.peer_joined", &[("player", format!("P{}", from + 1))]), ToastKind::Info, 3.0);
+            }
+            NetMessage::Welcome { id, level } => {
+                session.id = id;
+                session.peers.entry(HOST_ID).or_default();
+                // Pick the host's course; it loads when Play is pressed.
+                if let Some(m) = manifest.as_mut().filter(|_| *phase.get() == AppState::Menu) {
+                    if let Some(index) = m.index_of(&level) {
+                        m.selected = index;
+                    }
+                }
+                toasts.push_for(loc.tf("net.joined", &[("player", format!("P{}", id + 1)), ("level", level)]), ToastKind::Success, 3.0);
+            }
+            NetMessage::Ball { id, pos, hole } => {
+                let id = if host { from } else { id };
+                if host {
+                    link.broadcast(&NetMessage::Ball { id, pos, hole }, Some(from));
+                }
+                if id != session.id {
+                    let peer = session.peers.entry(id).or_default();
+                    peer.ball = Some(vec3(pos));
+                    peer.hole = hole;
+                }
+            }
+            NetMessage::Shot { id, pos, power } => {
+                let id = if host { from } else { id };
+                if host {
+                    link.broadcast(&NetMessage::Shot { id, pos, power }, Some(from));
+                }
+                if id != session.id {
+                    session.peers.entry(id).or_default().shots += 1;
+                }
+            }
+            NetMessage::Targets { hole, positions } => {
+                if host || score.as_ref().is_none_or(|s| s.hits != hole) {
+                    continue;
+                }
+                let Some(sampler) = sampler.as_ref() else { continue; };
+                for (mut t, mut float, info, motion, kind) in &mut q_targets {
+                    let Some(p) = positions.get(info.index).filter(|_| kind.is_none_or(|k| *k == TargetKind::Standard)) else {
+                        continue;
+                    };
+                    let anchor = motion.as_ref().map_or(t.translation.xz(), |m| m.anchor);
+                    if anchor.distance(Vec2::new(p.0, p.2)) <= ADOPT_DISTANCE {
+                        continue;
+                    }
+                    let ground = sampler.height(p.0, p.2);
+                    float.ground = ground;
+                    t.translation = Vec3::new(p.0, ground + float.base_height, p.2);
+                    if let Some(mut motion) = motion {
+                        motion.set_anchor(Vec2::new(p.0, p.2), ground);
+                    }
+                }
+            }
+            NetMessage::Claim { hole, pos } => {
+                if host && session.referee.claim(hole, from, vec3(pos)) {
+                    link.broadcast(&NetMessage::Awarded { hole, winner: from }, None);
+                    award_toast(&loc, &mut toasts, &session, hole, from);
+                } else if let Some(winner) = session.referee.winners.get(&hole).filter(|_| host) {
+                    // Too late or too far: tell the claimant who has it.
+                    link.send_to(from, &NetMessage::Awarded { hole, winner: *winner });
+                }
+            }
+            NetMessage::Awarded { hole, winner } => {
+                if host || session.referee.winners.get(&hole) == Some(&winner) {
+                    continue;
+                }
+                session.referee.winners.insert(hole, winner);
+                award_toast(&loc, &mut toasts, &session, hole, winner);
+            }
+            NetMessage::Left { id } => {
+                let id = if host { from } else { id };
+                session.peers.remove(&id);
+                if host {
+                    link.broadcast(&NetMessage::Left { id }, None);
+                } else if id == HOST_ID {
+                    session.role = NetRole::Offline;
+                    session.peers.clear();
+                }
+                let key = if id == HOST_ID && !host { "net.disconnected" } else { "net.peer_left" };
+                toasts.push_for(loc.tf(key, &[("player", format!("P{}", id + 1))]), ToastKind::Warning, 3.0);
+            }
+        }
+    }
+}
+
+fn award_toast(loc: &Localization, toasts: &mut ToastQueue, session: &NetSession, hole: u32, winner: PeerId) {
+    let player = if winner == session.id { loc.tf("net.you", &[]) } else { format!("P{}", winner + 1) };
+    toasts.push_for(loc.tf("net.hole_awarded", &[("player", player), ("hole", (hole + 1).to_string())]), ToastKind::Info, 3.0);
+}
+
+// Local target hits: the host decides its own straight away, joined peers ask the host.
+fn claim_holes(
+    link: Res<NetLink>,
+    loc: Res<Localization>,
+    score: Res<Score>,
+    mut session: ResMut<NetSession>,
+    mut toasts: ResMut<ToastQueue>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+    q_ball: Query<&Transform, With<Ball>>,
+) {
+    for hit in ev_hit.read() {
+        if hit.kind != TargetKind::Standard || !session.online() {
+            continue;
+        }
+        let hole = score.hits.saturating_sub(1);
+        let ball = q_ball.get_single().map_or(hit.pos, |t| t.translation);
+        match session.role {
+            NetRole::Host => {
+                let id = session.id;
+                if session.referee.claim(hole, id, ball) {
+                    link.broadcast(&NetMessage::Awarded { hole, winner: id }, None);
+                    award_toast(&loc, &mut toasts, &session, hole, id);
+                }
+            }
+            NetRole::Joined => link.send_to(HOST_ID, &NetMessage::Claim { hole, pos: point(ball) }),
+            NetRole::Offline => {}
+        }
+    }
+}
+
+fn send_shots(link: Res<NetLink>, mut session: ResMut<NetSession>, mut ev_shot: EventReader<ShotFiredEvent>) {
+    for shot in ev_shot.read() {
+        if !session.online() {
+            continue;
+        }
+        session.shots += 1;
+        let msg = NetMessage::Shot { id: session.id, pos: point(shot.pos), power: shot.power };
+        match session.role {
+            NetRole::Host => link.broadcast(&msg, None),
+            _ => link.send_to(HOST_ID, &msg),
+        }
+    }
+}
+
+fn send_ball_state(
+    time: Res<Time>,
+    link: Res<NetLink>,
+    session: Res<NetSession>,
+    score: Res<Score>,
+    phase: Res<State<AppState>>,
+    q_ball: Query<&Transform, With<Ball>>,
+    mut since: Local<f32>,
+) {
+    *since += time.delta_seconds();
+    if !session.online() || !phase.get().in_game() || *since < BALL_INTERVAL {
+        return;
+    }
+    *since = 0.0;
+    let Ok(ball) = q_ball.get_single() else { return; };
+    let msg = NetMessage::Ball { id: session.id, pos: point(ball.translation), hole: score.hits };
+    match session.role {
+        NetRole::Host => link.broadcast(&msg, None),
+        _ => link.send_to(HOST_ID, &msg),
+    }
+}
+
+// Host: where its targets are on its current hole (anchors for moving ones). Sent as soon as the
+// host reaches a hole, so its own hit there can always be checked, and every interval after.
+fn send_targets(
+    time: Res<Time>,
+    link: Res<NetLink>,
+    loc: Res<Localization>,
+    score: Res<Score>,
+    mut session: ResMut<NetSession>,
+    mut toasts: ResMut<ToastQueue>,
+    q_targets: Query<(&Transform, &TargetInfo, Option<&TargetMotion>, Option<&TargetKind>), With<Target>>,
+    mut since: Local<f32>,
+    mut sent_hole: Local<Option<u32>>,
+) {
+    *since += time.delta_seconds();
+    if session.role != NetRole::Host || (*since < TARGET_INTERVAL && *sent_hole == Some(score.hits)) {
+        return;
+    }
+    *since = 0.0;
+    *sent_hole = Some(score.hits);
+    let mut targets: Vec<(usize, Vec3)> = q_targets
+        .iter()
+        .filter(|(.., kind)| kind.is_none_or(|k| *k == TargetKind::Standard))
+        .map(|(t, info, motion, _)| {
+            let xz = motion.map_or(t.translation.xz(), |m| m.anchor);
+            (info.index, Vec3::new(xz.x, t.translation.y, xz.y))
+        })
+        .collect();
+    targets.sort_by_key(|(index, _)| *index);
+    let positions: Vec<Vec3> = targets.into_iter().map(|(_, p)| p).collect();
+    link.broadcast(&NetMessage::Targets { hole: score.hits, positions: positions.iter().copied().map(point).collect() }, None);
+    if let Some(winner) = session.referee.record_targets(score.hits, positions) {
+        link.broadcast(&NetMessage::Awarded { hole: score.hits, winner }, None);
+        award_toast(&loc, &mut toasts, &session, score.hits, winner);
+    }
+}
+
+fn update_remote_balls(
+    mut commands: Commands,
+    session: Res<NetSession>,
+    phase: Res<State<AppState>>,
+    q_ball: Query<&BallKinematic, With<Ball>>,
+    mut q_remote: Query<(&RemoteBall, &mut Transform, &mut Visibility)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Ok(kin) = q_ball.get_single() else { return; };
+    if q_remote.is_empty() {
+        let mesh = meshes.add(Sphere::new(kin.visual_radius));
+        for id in 0..MAX_PLAYERS as PeerId {
+            commands.spawn((
+                PbrBundle {
+                    mesh: mesh.clone(),
+                    material: materials.add(StandardMaterial {
+                        base_color: player_color(id as usize),
+                        perceptual_roughness: 0.4,
+                        ..default()
+                    }),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                RemoteBall(id),
+                Name::new(format!("RemoteBall{}", id + 1)),
+            ));
+        }
+        return;
+    }
+    let shown = session.online() && phase.get().round_in_view();
+    for (remote, mut t, mut vis) in &mut q_remote {
+        let pos = session.peers.get(&remote.0).and_then(|p| p.ball).filter(|_| shown && remote.0 != session.id);
+        *vis = match pos {
+            Some(pos) => {
+                // Smooth over the 10 Hz updates.
+                t.translation = t.translation.lerp(pos, 0.3);
+                if t.translation.distance(pos) > 30.0 {
+                    t.translation = pos;
+                }
+                Visibility::Inherited
+            }
+            None => Visibility::Hidden,
+        };
+    }
+}
+
+fn spawn_net_status(mut commands: Commands, assets: Res<AssetServer>) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle { font: assets.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::WHITE },
+        )
+        .with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(98.0), // under the ghost delta
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        }),
+        NetStatusText,
+    ));
+}
+
+fn update_net_status(
+    loc: Res<Localization>,
+    session: Res<NetSession>,
+    phase: Res<State<AppState>>,
+    mut q_text: Query<(&mut Text, &mut Visibility), With<NetStatusText>>,
+) {
+    let Ok((mut text, mut vis)) = q_text.get_single_mut() else { return; };
+    if !session.online() || !phase.get().round_in_view() {
+        *vis = Visibility::Hidden;
+        return;
+    }
+    *vis = Visibility::Inherited;
+    let value = loc.tf("hud.net", &[("standings", session.standings())]);
+    if text.sections[0].value != value {
+        text.sections[0].value = value;
+    }
+}
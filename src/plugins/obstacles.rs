@@ -0,0 +1,297 @@
+// Destructible obstacles: wooden crates and ice panels.
+// Levels place them with `obstacles` in their RON file (`(kind: Crate, x: 40.0, z: 12.0)`), and
+// `target_guard` stands a row of them across the direct line from each hole's tee to its target,
+// so the hole is played by smashing through or by routing around it. They are solid boxes (a
+// `PropCollision`, so the ball bounces off them like off level props) with `Health`: every hit
+// faster than `min_damage_speed` (along the contact normal) takes that much off. Ice is brittle,
+// a crate takes a couple of good hits; a damaged obstacle darkens (crate) or clears up (ice).
+// When the hit points run out it shatters into candy debris (particles.rs) and the ball carries
+// on through with some of its speed. Every damaging hit sends an `ObstacleHitEvent` (debris,
+// sound). A restarted round or a new level puts everything back.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::plugins::app_state::gameplay_running;
+use crate::plugins::ball::{ball_physics, Ball, BallKinematic, WATER_LEVEL};
+use crate::plugins::core_sim::GameSet;
+use crate::plugins::game_state::{HoleState, RestartGameEvent};
+use crate::plugins::level::LevelDef;
+use crate::plugins::level_props::{ball_prop_collision, prop_contact, PropCollider, PropCollision};
+use crate::plugins::particles::ObstacleHitEvent;
+use crate::plugins::target::{Target, TargetInfo, TargetKind};
+use crate::plugins::terrain::TerrainSampler;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum ObstacleKind {
+    #[default]
+    Crate,
+    Ice,
+}
+
+impl ObstacleKind {
+    pub fn hit_points(self) -> f32 {
+        match self {
+            Self::Crate => 30.0,
+            Self::Ice => 12.0,
+        }
+    }
+
+    /// Half extents (m): a cube of a crate, a tall thin ice panel (thin along local z).
+    pub fn half_extents(self) -> Vec3 {
+        match self {
+            Self::Crate => Vec3::splat(1.25),
+            Self::Ice => Vec3::new(1.6, 2.2, 0.25),
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Self::Crate => Color::srgb(0.62, 0.42, 0.22),
+            Self::Ice => Color::srgba(0.72, 0.9, 1.0, 0.75),
+        }
+    }
+
+    /// Look of the obstacle with `health` (0..1) of its hit points left.
+    fn tint(self, health: f32) -> Color {
+        let base = self.color().to_srgba();
+        match self {
+            Self::Crate => (base * (0.45 + 0.55 * health)).with_alpha(1.0).into(),
+            Self::Ice => base.with_alpha(0.35 + 0.4 * health).into(),
+        }
+    }
+}
+
+/// Level-placed obstacle (RON).
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct ObstacleDef {
+    #[serde(default)]
+    pub kind: ObstacleKind,
+    pub x: f32,
+    pub z: f32,
+    #[serde(default)]
+    pub yaw_deg: f32,
+}
+
+/// A row of obstacles across the direct line to each hole's target (RON `target_guard`).
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct TargetGuardDef {
+    pub kind: ObstacleKind,
+    pub distance: f32, // m in front of the target, towards the tee
+    pub pieces: usize,
+}
+impl Default for TargetGuardDef {
+    fn default() -> Self {
+        Self { kind: ObstacleKind::Ice, distance: 14.0, pieces: 3 }
+    }
+}
+
+/// Spots and yaws of a guard row of `pieces` obstacles `width` m wide, standing `distance` m
+/// from `target` towards `tee` (x / z) and facing along the line.
+pub fn guard_layout(tee: Vec2, target: Vec2, distance: f32, pieces: usize, width: f32) -> Vec<(Vec2, f32)> {
+    let Some(back) = (tee - target).try_normalize() else { return Vec::new(); };
+    let distance = distance.min(tee.distance(target) * 0.5);
+    let center = target + back * distance;
+    let side = back.perp();
+    // Local z (the thin side of a panel) along the line.
+    let yaw = back.x.atan2(back.y);
+    (0..pieces)
+        .map(|i| (center + side * (i as f32 - (pieces as f32 - 1.0) * 0.5) * width, yaw))
+        .collect()
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct ObstacleConfig {
+    pub min_damage_speed: f32,  // m/s along the contact normal; softer knocks do nothing
+    pub pass_through_keep: f32, // share of the ball's velocity kept when it smashes through
+}
+impl Default for ObstacleConfig {
+    fn default() -> Self {
+        Self { min_damage_speed: 4.0, pass_through_keep: 0.6 }
+    }
+}
+
+/// Hit points of a destructible.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Health {
+    pub hp: f32,
+    pub max: f32,
+}
+
+impl Health {
+    pub fn new(max: f32) -> Self {
+        Self { hp: max, max }
+    }
+
+    /// Take `damage`; true when this breaks it.
+    pub fn damage(&mut self, damage: f32) -> bool {
+        let was_standing = self.hp > 0.0;
+        self.hp = (self.hp - damage).max(0.0);
+        was_standing && self.hp <= 0.0
+    }
+
+    pub fn fraction(&self) -> f32 {
+        if self.max > 0.0 { self.hp / self.max } else { 0.0 }
+    }
+}
+
+/// Damage of a hit at `speed` m/s along the contact normal.
+pub fn impact_damage(speed: f32, cfg: &ObstacleConfig) -> f32 {
+    if speed >= cfg.min_damage_speed { speed } else { 0.0 }
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Obstacle {
+    pub kind: ObstacleKind,
+    guard: bool, // part of the current hole's target guard
+}
+
+// What has been laid out: the level's obstacles, and the hole (by its tee) the guard is for.
+#[derive(Resource, Default)]
+struct ObstacleLayout {
+    placed: bool,
+    guard_for: Option<(u32, Vec2)>,
+}
+
+pub struct ObstaclesPlugin;
+impl Plugin for ObstaclesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ObstacleConfig>()
+            .init_resource::<ObstacleLayout>()
+            .add_systems(Update, (place_obstacles, place_target_guard).chain())
+            .add_systems(
+                FixedUpdate,
+                ball_obstacle_hits
+                    .after(ball_physics)
+                    .before(ball_prop_collision)
+                    .in_set(GameSet::Simulate)
+                    .run_if(gameplay_running),
+            );
+    }
+}
+
+fn spawn_obstacle(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    sampler: &TerrainSampler,
+    (kind, pos, yaw, guard): (ObstacleKind, Vec2, f32, bool),
+) {
+    let half = kind.half_extents();
+    let color = kind.color();
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Cuboid::from_size(half * 2.0)),
+            material: materials.add(StandardMaterial {
+                base_color: color,
+                alpha_mode: if kind == ObstacleKind::Ice { AlphaMode::Blend } else { AlphaMode::Opaque },
+                perceptual_roughness: if kind == ObstacleKind::Ice { 0.1 } else { 0.9 },
+                ..default()
+            }),
+            // Sunk a little so it stands on slopes without gaps.
+            transform: Transform::from_xyz(pos.x, sampler.height(pos.x, pos.y) + half.y - 0.2, pos.y)
+                .with_rotation(Quat::from_rotation_y(yaw)),
+            ..default()
+        },
+        Obstacle { kind, guard },
+        Health::new(kind.hit_points()),
+        PropCollision(PropCollider::Box { half_extents: (half.x, half.y, half.z) }),
+    ));
+}
+
+fn place_obstacles(
+    mut commands: Commands,
+    (level, sampler): (Option<Res<LevelDef>>, Res<TerrainSampler>),
+    mut layout: ResMut<ObstacleLayout>,
+    (mut meshes, mut materials): (ResMut<Assets<Mesh>>, ResMut<Assets<StandardMaterial>>),
+    mut ev_restart: EventReader<RestartGameEvent>,
+    q_obstacles: Query<Entity, With<Obstacle>>,
+) {
+    let Some(level) = level else { return; };
+    if ev_restart.read().count() > 0 || level.is_changed() {
+        for e in &q_obstacles {
+            commands.entity(e).despawn_recursive();
+        }
+        *layout = ObstacleLayout::default();
+    }
+    if layout.placed || sampler.is_placeholder() {
+        return;
+    }
+    layout.placed = true;
+    for def in &level.obstacles {
+        let obstacle = (def.kind, Vec2::new(def.x, def.z), def.yaw_deg.to_radians(), false);
+        spawn_obstacle(&mut commands, &mut meshes, &mut materials, &sampler, obstacle);
+    }
+}
+
+fn place_target_guard(
+    mut commands: Commands,
+    (level, sampler, hole): (Option<Res<LevelDef>>, Res<TerrainSampler>, Res<HoleState>),
+    mut layout: ResMut<ObstacleLayout>,
+    (mut meshes, mut materials): (ResMut<Assets<Mesh>>, ResMut<Assets<StandardMaterial>>),
+    q_obstacles: Query<(Entity, &Obstacle)>,
+    q_targets: Query<(&Transform, Option<&TargetInfo>, Option<&TargetKind>), With<Target>>,
+) {
+    let Some(guard) = level.as_ref().and_then(|l| l.target_guard) else { return; };
+    if !layout.placed || layout.guard_for == Some((hole.hole, hole.tee)) {
+        return;
+    }
+    // The primary target of the hole being played.
+    let Some(target) = q_targets
+        .iter()
+        .find(|(_, info, kind)| info.is_none_or(|i| i.index == 0) && kind.is_none_or(|k| *k == TargetKind::Standard))
+        .map(|(t, ..)| t.translation.xz())
+    else {
+        return;
+    };
+    layout.guard_for = Some((hole.hole, hole.tee));
+    for (e, o) in &q_obstacles {
+        if o.guard {
+            commands.entity(e).despawn_recursive();
+        }
+    }
+    let width = guard.kind.half_extents().x * 2.0 + 0.2;
+    let mut placed = 0;
+    for (pos, yaw) in guard_layout(hole.tee, target, guard.distance, guard.pieces, width) {
+        if sampler.height(pos.x, pos.y) > WATER_LEVEL {
+            spawn_obstacle(&mut commands, &mut meshes, &mut materials, &sampler, (guard.kind, pos, yaw, true));
+            placed += 1;
+        }
+    }
+    info!("OBSTACLE guard hole={} placed={placed}", hole.hole);
+}
+
+fn ball_obstacle_hits(
+    mut commands: Commands,
+    cfg: Res<ObstacleConfig>,
+    mut q_ball: Query<&mut BallKinematic, With<Ball>>,
+    q_ball_t: Query<&Transform, With<Ball>>,
+    mut q_obstacles: Query<(Entity, &Transform, &PropCollision, &Obstacle, &mut Health, &Handle<StandardMaterial>), Without<Ball>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut ev_hit: EventWriter<ObstacleHitEvent>,
+) {
+    let (Ok(mut kin), Ok(ball_t)) = (q_ball.get_single_mut(), q_ball_t.get_single()) else { return; };
+    for (e, pose, collision, obstacle, mut health, material) in &mut q_obstacles {
+        let reach = collision.0.bounding_radius() + kin.collider_radius;
+        if pose.translation.distance_squared(ball_t.translation) > reach * reach {
+            continue;
+        }
+        let Some((n, _)) = prop_contact(ball_t.translation, kin.collider_radius, collision.0, pose) else { continue; };
+        let speed = -kin.vel.dot(n);
+        let damage = impact_damage(speed, &cfg);
+        if damage <= 0.0 {
+            continue; // a resting or glancing ball just bounces (ball_prop_collision)
+        }
+        let broken = health.damage(damage);
+        ev_hit.send(ObstacleHitEvent { pos: ball_t.translation - n * kin.collider_radius, kind: obstacle.kind, broken });
+        if broken {
+            // Gone before the prop collision runs: the ball smashes through.
+            commands.entity(e).despawn_recursive();
+            kin.vel *= cfg.pass_through_keep;
+            info!("OBSTACLE broken kind={:?} speed={speed:.1}", obstacle.kind);
+        } else if let Some(mat) = materials.get_mut(material) {
+            mat.base_color = obstacle.kind.tint(health.fraction());
+        }
+    }
+}
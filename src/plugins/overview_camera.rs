@@ -0,0 +1,233 @@
+// Picture-in-picture overview camera.
+// A second 3D camera renders into an image shown in the top-right corner. It frames the ball and
+// the focused target together, either straight down (`TopDown`) or from the side at an angle
+// (`Wide`), so long shots can be judged while the ball is in the air. It is shown while the ball
+// flies (and briefly after it lands); toggle and framing live in the settings panel.
+
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_resource::{Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages};
+
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::hud_layout::HudAnchor;
+use crate::plugins::app_state::AppState;
+use crate::plugins::target::{Target, TargetFocus};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverviewFraming {
+    #[default]
+    TopDown,
+    Wide,
+}
+
+impl OverviewFraming {
+    pub fn next(self) -> Self {
+        match self {
+            Self::TopDown => Self::Wide,
+            Self::Wide => Self::TopDown,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::TopDown => "Top-down",
+            Self::Wide => "Wide",
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct OverviewCameraConfig {
+    pub enabled: bool,
+    pub framing: OverviewFraming,
+    pub always_visible: bool, // otherwise only while the ball flies
+    pub size: UVec2,          // render target / panel size (px)
+    pub fov_deg: f32,         // vertical
+    pub padding: f32,         // extra fraction of the ball-target span kept around both
+    pub min_span: f32,        // m, never frame tighter than this
+    pub wide_pitch_deg: f32,  // elevation of the wide view
+    pub flight_speed: f32,    // m/s above which the ball counts as flying
+    pub linger: f32,          // s the panel stays up after the ball slows down
+    pub follow_rate: f32,     // 1/s smoothing of the camera transform
+}
+impl Default for OverviewCameraConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            framing: OverviewFraming::TopDown,
+            always_visible: false,
+            size: UVec2::new(320, 200),
+            fov_deg: 50.0,
+            padding: 0.25,
+            min_span: 40.0,
+            wide_pitch_deg: 35.0,
+            flight_speed: 1.0,
+            linger: 1.5,
+            follow_rate: 5.0,
+        }
+    }
+}
+
+impl OverviewCameraConfig {
+    pub fn aspect(&self) -> f32 {
+        self.size.x.max(1) as f32 / self.size.y.max(1) as f32
+    }
+}
+
+/// Camera transform framing `ball` and `target`: the ball-target line runs across the (wider)
+/// horizontal axis of the view.
+pub fn frame_overview(cfg: &OverviewCameraConfig, ball: Vec3, target: Vec3) -> Transform {
+    let half_fov_y = (cfg.fov_deg.to_radians() * 0.5).tan();
+    let half_fov_x = half_fov_y * cfg.aspect();
+    let d = (target - ball).xz();
+    let along = d.try_normalize().unwrap_or(Vec2::X);
+    let span = (d.length() * (1.0 + cfg.padding)).max(cfg.min_span);
+    let mid = (ball + target) * 0.5;
+    let top = ball.y.max(target.y);
+    match cfg.framing {
+        OverviewFraming::TopDown => {
+            // Seen from above only the horizontal span has to fit.
+            let h = span * 0.5 / half_fov_x;
+            let center = Vec3::new(mid.x, top, mid.z);
+            let up = Vec3::new(-along.y, 0.0, along.x);
+            Transform::from_translation(center + Vec3::Y * h).looking_at(center, up)
+        }
+        OverviewFraming::Wide => {
+            let pitch = cfg.wide_pitch_deg.to_radians();
+            // Also fit the height difference vertically (plus the ball's arc above the line).
+            let rise = (ball.y - target.y).abs() + span * 0.15;
+            let dist = (span * 0.5 / half_fov_x).max(rise / half_fov_y) * 1.1;
+            let side = Vec3::new(along.y, 0.0, -along.x);
+            let eye = mid + (side * pitch.cos() + Vec3::Y * pitch.sin()) * dist;
+            Transform::from_translation(eye).looking_at(mid, Vec3::Y)
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct OverviewCamera;
+
+#[derive(Component)]
+struct OverviewPanel;
+
+/// Seconds the panel has left (while the ball is slow).
+#[derive(Resource, Default)]
+struct OverviewVisibility {
+    remaining: f32,
+}
+
+pub struct OverviewCameraPlugin;
+impl Plugin for OverviewCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<OverviewCameraConfig>()
+            .init_resource::<OverviewVisibility>()
+            .add_systems(Startup, spawn_overview_camera)
+            .add_systems(Update, update_overview_camera);
+    }
+}
+
+fn spawn_overview_camera(mut commands: Commands, cfg: Res<OverviewCameraConfig>, images: Option<ResMut<Assets<Image>>>) {
+    let Some(mut images) = images else { return; };
+    let size = Extent3d { width: cfg.size.x.max(1), height: cfg.size.y.max(1), depth_or_array_layers: 1 };
+    let mut image = Image {
+        texture_descriptor: TextureDescriptor {
+            label: Some("overview_camera_target"),
+            size,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Bgra8UnormSrgb,
+            mip_level_count: 1,
+            sample_count: 1,
+            usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        },
+        ..default()
+    };
+    image.resize(size);
+    let image = images.add(image);
+
+    commands.spawn((
+        Camera3dBundle {
+            camera: Camera {
+                target: RenderTarget::Image(image.clone()),
+                order: -1, // before the main view
+                is_active: false,
+                clear_color: ClearColorConfig::Custom(Color::srgb(0.55, 0.7, 0.9)),
+                ..default()
+            },
+            projection: PerspectiveProjection {
+                fov: cfg.fov_deg.to_radians(),
+                near: 0.5,
+                far: 12000.0,
+                ..default()
+            }
+            .into(),
+            ..default()
+        },
+        OverviewCamera,
+        Name::new("OverviewCamera"),
+    ));
+    commands.spawn((
+        ImageBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(12.0),
+                top: Val::Px(64.0), // below the power gauge
+                width: Val::Px(cfg.size.x as f32),
+                height: Val::Px(cfg.size.y as f32),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            image: UiImage::new(image),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        BorderColor(Color::srgba(1.0, 1.0, 1.0, 0.35)),
+        HudAnchor::top_right(12.0, 64.0),
+        OverviewPanel,
+    ));
+}
+
+fn update_overview_camera(
+    time: Res<Time>,
+    cfg: Res<OverviewCameraConfig>,
+    phase: Option<Res<State<AppState>>>,
+    focus: Res<TargetFocus>,
+    mut shown: ResMut<OverviewVisibility>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+    q_targets: Query<&Transform, (With<Target>, Without<Ball>)>,
+    mut q_cam: Query<(&mut Camera, &mut Transform), (With<OverviewCamera>, Without<Ball>, Without<Target>)>,
+    mut q_panel: Query<&mut Visibility, With<OverviewPanel>>,
+) {
+    let Ok((mut camera, mut cam_t)) = q_cam.get_single_mut() else { return; };
+    let playing = phase.is_some_and(|p| p.get().round_in_view());
+    let ball = q_ball.get_single().ok();
+    let target_t = focus.entity.and_then(|e| q_targets.get(e).ok());
+
+    let flying = ball.is_some_and(|(_, k)| k.vel.length() > cfg.flight_speed);
+    shown.remaining = if flying { cfg.linger } else { (shown.remaining - time.delta_seconds()).max(0.0) };
+    let active = cfg.enabled && playing && (cfg.always_visible || shown.remaining > 0.0);
+    let frame = match (ball, target_t, active) {
+        (Some((ball_t, _)), Some(target_t), true) => Some(frame_overview(&cfg, ball_t.translation, target_t.translation)),
+        _ => None,
+    };
+
+    let was_active = camera.is_active;
+    if was_active != frame.is_some() {
+        camera.is_active = frame.is_some();
+    }
+    if let Ok(mut vis) = q_panel.get_single_mut() {
+        let want = if frame.is_some() { Visibility::Inherited } else { Visibility::Hidden };
+        if *vis != want {
+            *vis = want;
+        }
+    }
+    let Some(frame) = frame else { return; };
+    if was_active {
+        let a = 1.0 - (-cfg.follow_rate * time.delta_seconds()).exp();
+        cam_t.translation = cam_t.translation.lerp(frame.translation, a);
+        cam_t.rotation = cam_t.rotation.slerp(frame.rotation, a);
+    } else {
+        // Just switched on: jump straight to the framing.
+        *cam_t = frame;
+    }
+}
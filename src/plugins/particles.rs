@@ -1,7 +1,13 @@
 // Particle & FX systems now using candy_1 / candy_2 glb models for burst/explosion/confetti effects.
 use bevy::prelude::*;
 use rand::prelude::*;
+use bevy::render::primitives::Frustum;
 use crate::plugins::ball::Ball;
+use crate::plugins::camera::OrbitCamera;
+use crate::plugins::obstacles::ObstacleKind;
+use crate::plugins::spawn_view::SpawnView;
+use crate::plugins::target::TargetKind;
+use crate::plugins::trick_shots::TrickKind;
 
 pub struct ParticlePlugin;
 
@@ -15,6 +21,47 @@ pub struct BallGroundImpactEvent {
 #[derive(Event)]
 pub struct TargetHitEvent {
     pub pos: Vec3,
+    pub points: u32,
+    pub kind: TargetKind,
+    pub time_delta: f32, // seconds added to the run clock (decoy > 0, bonus < 0)
+    pub combo: u32,      // chain length after this hit (0 = none); scales effects
+    pub strokes: u32,    // strokes taken on the hole (standard targets; 0 otherwise)
+    pub bob: f32,        // where the target was in its bob: -1 bottom .. 1 top (0 when it doesn't bob)
+}
+
+// A hole finished with a single stroke (see hole_in_one.rs).
+#[derive(Event)]
+pub struct HoleInOneEvent {
+    pub pos: Vec3,
+    pub hole: u32, // holes completed, this one included
+}
+
+// A scoring hit was a trick shot (see trick_shots.rs); `bonus` s came off the run clock.
+#[derive(Event)]
+pub struct TrickShotEvent {
+    pub pos: Vec3,
+    pub tricks: Vec<TrickKind>,
+    pub bonus: f32,
+}
+
+#[derive(Event)]
+pub struct GatePassedEvent {
+    pub index: usize,
+    pub pos: Vec3,
+    pub passed: usize, // gates passed so far this hole
+    pub total: usize,
+}
+
+// Geiger-style proximity tick after the ball settles (see target_proximity.rs).
+#[derive(Event)]
+pub struct ProximityBeepEvent {
+    pub heat: f32, // 0 = far .. 1 = close
+}
+
+#[derive(Event)]
+pub struct BonusTargetSpawnedEvent {
+    pub pos: Vec3,
+    pub lifetime: f32,
 }
 
 #[derive(Event)]
@@ -28,6 +75,35 @@ pub struct ShotFiredEvent {
     pub power: f32,
 }
 
+// Ball struck a tree trunk hard enough to crack it (see tree_impact.rs).
+#[derive(Event)]
+pub struct TreeHitEvent {
+    pub tree: Entity,
+    pub pos: Vec3,
+    pub speed: f32,         // impact speed along the contact normal
+    pub knocked_down: bool, // tree was felled (despawned into debris)
+}
+
+// Ball struck a breakable obstacle hard enough to damage it (see obstacles.rs).
+#[derive(Event)]
+pub struct ObstacleHitEvent {
+    pub pos: Vec3,
+    pub kind: ObstacleKind,
+    pub broken: bool, // hit points ran out (despawned into debris)
+}
+
+// Lightning struck during a storm (see weather.rs); `distance` 0 = overhead .. 1 = far off.
+#[derive(Event)]
+pub struct ThunderEvent {
+    pub distance: f32,
+}
+
+// A flock of birds took fright at the ball (see birds.rs).
+#[derive(Event)]
+pub struct BirdsScatteredEvent {
+    pub pos: Vec3,
+}
+
 // Minimum impact intensity required to spawn bounce dust & play bounce SFX.
 pub const BOUNCE_EFFECT_INTENSITY_MIN: f32 = 2.0;
 
@@ -39,10 +115,12 @@ enum ParticleKind {
     ShotBlast,      // burst when player launches the ball
     Explosion,      // bright fast particles (target hit)
     Confetti,       // game-over candy rain (candy models)
+    Leaf,           // leaves shaken loose by a tree hit
+    Debris,         // branch chunks from a felled tree, planks / shards from an obstacle
 }
 
 #[derive(Component)]
-struct Particle {
+pub(crate) struct Particle {
     lifetime: f32,
     age: f32,
     gravity: f32,
@@ -72,6 +150,23 @@ impl Default for AtmosDustConfig {
     }
 }
 
+/// Share of each particle effect actually spawned (low_power.rs lowers it).
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct ParticleQuality {
+    pub scale: f32,
+}
+impl Default for ParticleQuality {
+    fn default() -> Self {
+        Self { scale: 1.0 }
+    }
+}
+impl ParticleQuality {
+    /// `count` scaled down, keeping at least one particle of a non-empty effect.
+    pub fn scaled(&self, count: usize) -> usize {
+        if count == 0 { 0 } else { ((count as f32 * self.scale).round() as usize).clamp(1, count) }
+    }
+}
+
 #[derive(Resource)]
 pub struct ParticleMaterials {
     _dust: Handle<StandardMaterial>, // reserved (was atmospheric dust material)
@@ -104,6 +199,67 @@ impl FromWorld for SnowflakeModel {
     }
 }
 
+// Leaf / branch pieces for tree hits (primitive cuboids, shared by all particles)
+#[derive(Resource)]
+pub struct TreeDebrisAssets {
+    leaf_mesh: Handle<Mesh>,
+    branch_mesh: Handle<Mesh>,
+    leaf: Handle<StandardMaterial>,
+    bark: Handle<StandardMaterial>,
+}
+impl FromWorld for TreeDebrisAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mut meshes = world.resource_mut::<Assets<Mesh>>();
+        let leaf_mesh = meshes.add(Cuboid::new(0.22, 0.03, 0.16));
+        let branch_mesh = meshes.add(Cuboid::new(0.09, 0.09, 0.7));
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let leaf = materials.add(StandardMaterial {
+            base_color: Color::srgb(0.28, 0.52, 0.18),
+            perceptual_roughness: 0.9,
+            double_sided: true,
+            cull_mode: None,
+            ..default()
+        });
+        let bark = materials.add(StandardMaterial {
+            base_color: Color::srgb(0.36, 0.25, 0.15),
+            perceptual_roughness: 1.0,
+            ..default()
+        });
+        Self { leaf_mesh, branch_mesh, leaf, bark }
+    }
+}
+
+// Plank / shard pieces for obstacle hits (primitive cuboids, shared by all particles)
+#[derive(Resource)]
+pub struct ObstacleDebrisAssets {
+    plank_mesh: Handle<Mesh>,
+    shard_mesh: Handle<Mesh>,
+    wood: Handle<StandardMaterial>,
+    ice: Handle<StandardMaterial>,
+}
+impl FromWorld for ObstacleDebrisAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mut meshes = world.resource_mut::<Assets<Mesh>>();
+        let plank_mesh = meshes.add(Cuboid::new(0.5, 0.06, 0.16));
+        let shard_mesh = meshes.add(Tetrahedron::default());
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let wood = materials.add(StandardMaterial {
+            base_color: Color::srgb(0.62, 0.42, 0.22),
+            perceptual_roughness: 0.9,
+            ..default()
+        });
+        let ice = materials.add(StandardMaterial {
+            base_color: Color::srgba(0.75, 0.92, 1.0, 0.7),
+            alpha_mode: AlphaMode::Blend,
+            perceptual_roughness: 0.1,
+            double_sided: true,
+            cull_mode: None,
+            ..default()
+        });
+        Self { plank_mesh, shard_mesh, wood, ice }
+    }
+}
+
 // Candy model handles
 #[derive(Resource)]
 pub struct CandyModels {
@@ -188,40 +344,95 @@ fn extract_candy_variants(
 impl Plugin for ParticlePlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(AtmosDustConfig::default())
-.init_resource::<ParticleMaterials>()
+            .init_resource::<ParticleQuality>()
+            .init_resource::<ParticleMaterials>()
             .init_resource::<SnowflakeModel>()
             .init_resource::<CandyModels>()
+            .init_resource::<TreeDebrisAssets>()
+            .init_resource::<ObstacleDebrisAssets>()
             .insert_resource(CandyMeshVariants::default())
             .add_event::<BallGroundImpactEvent>()
             .add_event::<TargetHitEvent>()
+            .add_event::<BonusTargetSpawnedEvent>()
+            .add_event::<GatePassedEvent>()
+            .add_event::<ProximityBeepEvent>()
             .add_event::<GameOverEvent>()
             .add_event::<ShotFiredEvent>()
-            .add_systems(Startup, (setup_atmospheric_dust, spawn_candy_templates))
+            .add_event::<TreeHitEvent>()
+            .add_event::<ObstacleHitEvent>()
+            .add_event::<ThunderEvent>()
+            .add_event::<BirdsScatteredEvent>()
+            .add_event::<HoleInOneEvent>()
+            .add_event::<TrickShotEvent>()
+            .add_systems(Startup, spawn_candy_templates)
             .add_systems(Update, (
+                spawn_atmospheric_dust,
                 extract_candy_variants.before(recycle_atmospheric_dust),
                 recycle_atmospheric_dust,
                 spawn_dust_on_impact,
                 spawn_shot_blast,
                 spawn_explosion_on_hit,
+                spawn_hole_in_one_burst,
                 spawn_confetti_on_game_over,
+                spawn_leaves_on_tree_hit,
+                spawn_debris_on_obstacle_hit,
                 update_particles,
             ));
     }
 }
 
 // -------- Atmospheric Dust (persistent primitive spheres) --------
-fn setup_atmospheric_dust(
+// Spawned progressively (a few per frame, up to `AtmosDustConfig::count`) so it does not compete
+// with chunk building at startup; each flake prefers a spot the camera sees, so the visible
+// wedge fills first and the distribution evens out as flakes recycle.
+const DUST_SPAWNS_PER_FRAME: usize = 12;
+const DUST_VIEW_TRIES: usize = 6;
+
+/// Random dust position around `center`, retried a few times for one inside the view.
+fn dust_spawn_point(center: Vec3, cfg: &AtmosDustConfig, view: Option<&SpawnView>, rng: &mut impl Rng) -> Vec3 {
+    let mut candidate = || {
+        center + Vec3::new(
+            rng.gen_range(-cfg.half_extent..=cfg.half_extent),
+            rng.gen_range(cfg.min_y..cfg.max_y),
+            rng.gen_range(-cfg.half_extent..=cfg.half_extent),
+        )
+    };
+    let mut p = candidate();
+    if let Some(view) = view {
+        for _ in 1..DUST_VIEW_TRIES {
+            if view.sees(p, 0.0) {
+                break;
+            }
+            p = candidate();
+        }
+    }
+    p
+}
+
+fn spawn_atmospheric_dust(
     mut commands: Commands,
     cfg: Res<AtmosDustConfig>,
+    quality: Res<ParticleQuality>,
     snow: Res<SnowflakeModel>,
     q_ball: Query<&Transform, With<Ball>>,
+    q_cam: Query<(&GlobalTransform, &Frustum), With<OrbitCamera>>,
+    q_dust: Query<(Entity, &ParticleKind)>,
 ) {
+    let dust: Vec<Entity> = q_dust.iter().filter(|(_, k)| matches!(k, ParticleKind::DustAtmos)).map(|(e, _)| e).collect();
+    let live = dust.len();
+    let wanted = quality.scaled(cfg.count);
+    if live >= wanted {
+        // Recycled flakes never expire, so a lower budget (low-power mode) removes the excess.
+        for e in &dust[wanted..] {
+            commands.entity(*e).despawn_recursive();
+        }
+        return;
+    }
+    let view = SpawnView::from_camera(&q_cam);
     let center = q_ball.get_single().map(|t| t.translation).unwrap_or(Vec3::ZERO);
     let mut rng = thread_rng();
-    for _ in 0..cfg.count {
-        let x = center.x + rng.gen_range(-cfg.half_extent..=cfg.half_extent);
-        let y = rng.gen_range(cfg.min_y..cfg.max_y);
-        let z = center.z + rng.gen_range(-cfg.half_extent..=cfg.half_extent);
+    for _ in 0..(wanted - live).min(DUST_SPAWNS_PER_FRAME) {
+        let pos = dust_spawn_point(center, &cfg, view.as_ref(), &mut rng);
         let lifetime = rng.gen_range(15.0..30.0);
         let age = rng.gen_range(0.0..lifetime);
         let max_scale = rng.gen_range(1.0..4.0); // quarter previous size
@@ -238,7 +449,7 @@ fn setup_atmospheric_dust(
         commands.spawn((
             SceneBundle {
                 scene: snow.handle.clone(),
-                transform: Transform::from_translation(Vec3::new(x, y, z))
+                transform: Transform::from_translation(pos)
                     .with_scale(Vec3::splat(0.0)),
                 ..default()
             },
@@ -286,10 +497,11 @@ fn spawn_dust_on_impact(
     mut commands: Commands,
     candy_models: Res<CandyModels>,
     variants: Res<CandyMeshVariants>,
+    quality: Res<ParticleQuality>,
 ) {
     for e in ev.read() {
         if e.intensity < BOUNCE_EFFECT_INTENSITY_MIN { continue; }
-        let count = (6.0 + e.intensity * 4.0).clamp(6.0, 40.0) as usize;
+        let count = quality.scaled((6.0 + e.intensity * 4.0).clamp(6.0, 40.0) as usize);
         let mut rng = thread_rng();
         for _ in 0..count {
             // random outward hemisphere direction
@@ -364,11 +576,12 @@ fn spawn_shot_blast(
     mut commands: Commands,
     candy_models: Res<CandyModels>,
     variants: Res<CandyMeshVariants>,
+    quality: Res<ParticleQuality>,
 ) {
     for e in ev.read() {
         let mut rng = thread_rng();
         // Scale count with shot power (power 0..1)
-        let count = (14.0 + e.power * 40.0).round() as usize;
+        let count = quality.scaled((14.0 + e.power * 40.0).round() as usize);
         for _ in 0..count {
             // Sample direction in upper hemisphere biased slightly upward.
             let dir = {
@@ -443,10 +656,17 @@ fn spawn_explosion_on_hit(
     mut commands: Commands,
     candy_models: Res<CandyModels>,
     variants: Res<CandyMeshVariants>,
+    quality: Res<ParticleQuality>,
 ) {
     for e in ev.read() {
         let mut rng = thread_rng();
-        let count = 60;
+        // Decoys fizzle, golden targets burst.
+        let count = match e.kind {
+            TargetKind::Standard => 60,
+            TargetKind::Decoy => 20,
+            TargetKind::Bonus => 100,
+        } * (4 + e.combo.min(8)) / 4;
+        let count = quality.scaled(count as usize);
         for _ in 0..count {
             let dir = {
                 let mut d;
@@ -506,16 +726,66 @@ fn spawn_explosion_on_hit(
     }
 }
 
+// -------- Hole-in-One Burst (oversized, slow-falling candy fountain) --------
+fn spawn_hole_in_one_burst(
+    mut ev: EventReader<HoleInOneEvent>,
+    mut commands: Commands,
+    candy_models: Res<CandyModels>,
+    variants: Res<CandyMeshVariants>,
+    quality: Res<ParticleQuality>,
+) {
+    for e in ev.read() {
+        let mut rng = thread_rng();
+        for _ in 0..quality.scaled(220) {
+            // Mostly upwards, like a fountain.
+            let dir = Vec3::new(rng.gen_range(-1.0..1.0), rng.gen_range(0.4..1.6), rng.gen_range(-1.0..1.0)).normalize();
+            let scale = rng.gen_range(0.5..0.9);
+            let transform = Transform::from_translation(e.pos)
+                .with_scale(Vec3::splat(scale))
+                .with_rotation(Quat::from_euler(
+                    EulerRot::XYZ,
+                    rng.gen_range(0.0..std::f32::consts::TAU),
+                    rng.gen_range(0.0..std::f32::consts::TAU),
+                    rng.gen_range(0.0..std::f32::consts::TAU),
+                ));
+            let particle = Particle {
+                lifetime: rng.gen_range(1.4..2.6),
+                age: 0.0,
+                gravity: -6.0,
+                vel: dir * rng.gen_range(10.0..26.0),
+                angular_vel: Vec3::new(rng.gen_range(-5.0..5.0), rng.gen_range(-5.0..5.0), rng.gen_range(-5.0..5.0)),
+                start_scale: Vec3::splat(scale),
+                end_scale: Vec3::splat(scale * 0.4),
+            };
+            if variants.ready && !variants.variants.is_empty() {
+                let (mesh, material) = &variants.variants[rng.gen_range(0..variants.variants.len())];
+                commands.spawn((
+                    PbrBundle { mesh: mesh.clone(), material: material.clone(), transform, ..default() },
+                    ParticleKind::Explosion,
+                    particle,
+                ));
+            } else {
+                commands.spawn((
+                    SceneBundle { scene: random_candy(&mut rng, &candy_models.candy), transform, ..default() },
+                    ParticleKind::Explosion,
+                    particle,
+                ));
+            }
+        }
+    }
+}
+
 // -------- Game Over Confetti (candy rain) --------
 fn spawn_confetti_on_game_over(
     mut ev: EventReader<GameOverEvent>,
     mut commands: Commands,
     candy_models: Res<CandyModels>,
     variants: Res<CandyMeshVariants>,
+    quality: Res<ParticleQuality>,
 ) {
     for e in ev.read() {
         let mut rng = thread_rng();
-        let count = 300;
+        let count = quality.scaled(300);
         for _ in 0..count {
             let pos = e.pos + Vec3::new(
                 rng.gen_range(-8.0..8.0),
@@ -576,6 +846,160 @@ fn spawn_confetti_on_game_over(
     }
 }
 
+// -------- Tree Hit (leaves + branch debris) --------
+fn spawn_leaves_on_tree_hit(
+    mut ev: EventReader<TreeHitEvent>,
+    mut commands: Commands,
+    assets: Res<TreeDebrisAssets>,
+) {
+    let mut rng = thread_rng();
+    for e in ev.read() {
+        // Leaves flutter down from the canopy; felled trees also throw branches out from the trunk.
+        let leaves = (8.0 + e.speed * 3.0).clamp(8.0, 50.0) as usize;
+        for _ in 0..leaves {
+            let offset = Vec3::new(rng.gen_range(-1.2..1.2), rng.gen_range(1.5..4.0), rng.gen_range(-1.2..1.2));
+            let vel = Vec3::new(rng.gen_range(-1.0..1.0), rng.gen_range(-0.2..1.2), rng.gen_range(-1.0..1.0));
+            let scale = rng.gen_range(0.7..1.3);
+            commands.spawn((
+                PbrBundle {
+                    mesh: assets.leaf_mesh.clone(),
+                    material: assets.leaf.clone(),
+                    transform: Transform::from_translation(e.pos + offset)
+                        .with_rotation(Quat::from_euler(
+                            EulerRot::XYZ,
+                            rng.gen_range(0.0..std::f32::consts::TAU),
+                            rng.gen_range(0.0..std::f32::consts::TAU),
+                            0.0,
+                        ))
+                        .with_scale(Vec3::splat(scale)),
+                    ..default()
+                },
+                ParticleKind::Leaf,
+                Particle {
+                    lifetime: rng.gen_range(2.5..4.5),
+                    age: 0.0,
+                    gravity: -1.2, // drag-limited fall
+                    vel,
+                    angular_vel: Vec3::new(rng.gen_range(-3.0..3.0), rng.gen_range(-3.0..3.0), rng.gen_range(-3.0..3.0)),
+                    start_scale: Vec3::splat(scale),
+                    end_scale: Vec3::ZERO,
+                },
+            ));
+        }
+        if !e.knocked_down {
+            continue;
+        }
+        let branches = (6.0 + e.speed).clamp(6.0, 20.0) as usize;
+        for _ in 0..branches {
+            let dir = Vec3::new(rng.gen_range(-1.0..1.0), rng.gen_range(0.3..1.0), rng.gen_range(-1.0..1.0)).normalize();
+            let speed = rng.gen_range(1.5..3.5) + e.speed * 0.2;
+            let scale = rng.gen_range(0.6..1.4);
+            commands.spawn((
+                PbrBundle {
+                    mesh: assets.branch_mesh.clone(),
+                    material: assets.bark.clone(),
+                    transform: Transform::from_translation(e.pos + Vec3::Y * rng.gen_range(0.2..2.5))
+                        .with_rotation(Quat::from_rotation_arc(Vec3::Z, dir))
+                        .with_scale(Vec3::splat(scale)),
+                    ..default()
+                },
+                ParticleKind::Debris,
+                Particle {
+                    lifetime: rng.gen_range(1.8..3.0),
+                    age: 0.0,
+                    gravity: -9.8,
+                    vel: dir * speed,
+                    angular_vel: Vec3::new(rng.gen_range(-5.0..5.0), rng.gen_range(-2.0..2.0), rng.gen_range(-5.0..5.0)),
+                    start_scale: Vec3::splat(scale),
+                    end_scale: Vec3::splat(scale * 0.3),
+                },
+            ));
+        }
+    }
+}
+
+// -------- Obstacle Debris (planks / ice shards, and candy from inside a broken one) --------
+fn spawn_debris_on_obstacle_hit(
+    mut ev: EventReader<ObstacleHitEvent>,
+    mut commands: Commands,
+    assets: Res<ObstacleDebrisAssets>,
+    (candy_models, variants, quality): (Res<CandyModels>, Res<CandyMeshVariants>, Res<ParticleQuality>),
+) {
+    let mut rng = thread_rng();
+    for e in ev.read() {
+        // A knock chips a few pieces off; a break throws out the lot.
+        let (mesh, material) = match e.kind {
+            ObstacleKind::Crate => (&assets.plank_mesh, &assets.wood),
+            ObstacleKind::Ice => (&assets.shard_mesh, &assets.ice),
+        };
+        let pieces = quality.scaled(if e.broken { 36 } else { 8 });
+        let spread = if e.broken { 1.2 } else { 0.3 };
+        for _ in 0..pieces {
+            let dir = Vec3::new(rng.gen_range(-1.0..1.0), rng.gen_range(0.2..1.2), rng.gen_range(-1.0..1.0)).normalize();
+            let offset = Vec3::new(rng.gen_range(-spread..spread), rng.gen_range(-spread..spread), rng.gen_range(-spread..spread));
+            let scale = rng.gen_range(0.5..1.3);
+            commands.spawn((
+                PbrBundle {
+                    mesh: mesh.clone(),
+                    material: material.clone(),
+                    transform: Transform::from_translation(e.pos + offset)
+                        .with_rotation(Quat::from_rotation_arc(Vec3::Z, dir))
+                        .with_scale(Vec3::splat(scale)),
+                    ..default()
+                },
+                ParticleKind::Debris,
+                Particle {
+                    lifetime: rng.gen_range(1.2..2.4),
+                    age: 0.0,
+                    gravity: -9.8,
+                    vel: dir * rng.gen_range(2.0..7.0),
+                    angular_vel: Vec3::new(rng.gen_range(-8.0..8.0), rng.gen_range(-8.0..8.0), rng.gen_range(-8.0..8.0)),
+                    start_scale: Vec3::splat(scale),
+                    end_scale: Vec3::splat(scale * 0.3),
+                },
+            ));
+        }
+        if !e.broken {
+            continue;
+        }
+        for _ in 0..quality.scaled(40) {
+            let dir = Vec3::new(rng.gen_range(-1.0..1.0), rng.gen_range(0.3..1.4), rng.gen_range(-1.0..1.0)).normalize();
+            let scale = rng.gen_range(0.3..0.6);
+            let transform = Transform::from_translation(e.pos)
+                .with_scale(Vec3::splat(scale))
+                .with_rotation(Quat::from_euler(
+                    EulerRot::XYZ,
+                    rng.gen_range(0.0..std::f32::consts::TAU),
+                    rng.gen_range(0.0..std::f32::consts::TAU),
+                    rng.gen_range(0.0..std::f32::consts::TAU),
+                ));
+            let particle = Particle {
+                lifetime: rng.gen_range(1.0..1.8),
+                age: 0.0,
+                gravity: -9.8,
+                vel: dir * rng.gen_range(4.0..10.0),
+                angular_vel: Vec3::new(rng.gen_range(-5.0..5.0), rng.gen_range(-5.0..5.0), rng.gen_range(-5.0..5.0)),
+                start_scale: Vec3::splat(scale),
+                end_scale: Vec3::splat(scale * 0.2),
+            };
+            if variants.ready && !variants.variants.is_empty() {
+                let (mesh, material) = &variants.variants[rng.gen_range(0..variants.variants.len())];
+                commands.spawn((
+                    PbrBundle { mesh: mesh.clone(), material: material.clone(), transform, ..default() },
+                    ParticleKind::Debris,
+                    particle,
+                ));
+            } else {
+                commands.spawn((
+                    SceneBundle { scene: random_candy(&mut rng, &candy_models.candy), transform, ..default() },
+                    ParticleKind::Debris,
+                    particle,
+                ));
+            }
+        }
+    }
+}
+
 // -------- Particle Update --------
 fn update_particles(
     mut commands: Commands,
@@ -0,0 +1,154 @@
+// Pause menu (Escape).
+// `AppState::Paused` stops the gameplay systems through the `gameplay_running` run condition
+// (simulation tick, ball physics, collisions, target motion, shot charge, combo timer), dims the
+// screen and offers:
+//   Resume       - back to play (Escape does the same).
+//   Settings     - opens the performance / settings panel in place of the pause menu.
+//   Restart      - `RestartGameEvent`, then play on from the tee.
+//   Quit to Menu - the same reset, then `AppState::Menu` (main_menu.rs respawns its UI).
+// No pausing after game over (the game-over panel has its own buttons) or in photo mode.
+
+use bevy::prelude::*;
+
+use crate::plugins::camera::CameraMode;
+use crate::plugins::game_state::{RestartGameEvent, Score};
+use crate::plugins::app_state::AppState;
+use crate::plugins::main_menu::spawn_button;
+use crate::plugins::performance_menu::PerfMenuState;
+
+/// State after Escape: pauses a run that `can_pause`, resumes a paused one.
+pub fn escape_transition(phase: AppState, can_pause: bool) -> AppState {
+    match phase {
+        AppState::Playing if can_pause => AppState::Paused,
+        AppState::Paused => AppState::Playing,
+        other => other,
+    }
+}
+
+#[derive(Component)]
+struct PauseMenuRoot;
+#[derive(Component, Clone, Copy)]
+enum PauseButton {
+    Resume,
+    Settings,
+    Restart,
+    Quit,
+}
+
+pub struct PauseMenuPlugin;
+impl Plugin for PauseMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (toggle_pause, pause_menu_buttons, sync_pause_menu).chain());
+    }
+}
+
+fn toggle_pause(
+    keys: Res<ButtonInput<KeyCode>>,
+    score: Res<Score>,
+    mode: Res<CameraMode>,
+    phase: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut settings: ResMut<PerfMenuState>,
+) {
+    if !keys.just_pressed(KeyCode::Escape) {
+        return;
+    }
+    if *phase.get() == AppState::Paused && settings.open {
+        // Escape backs out of the settings panel first.
+        settings.open = false;
+        return;
+    }
+    let next = escape_transition(*phase.get(), !score.game_over && *mode != CameraMode::Photo);
+    if next != *phase.get() {
+        next_state.set(next);
+        info!("Game phase: {:?}", next);
+    }
+}
+
+fn pause_menu_buttons(
+    phase: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut settings: ResMut<PerfMenuState>,
+    mut buttons: ResMut<ButtonInput<MouseButton>>,
+    mut ev_restart: EventWriter<RestartGameEvent>,
+    q_buttons: Query<(&Interaction, &PauseButton), Changed<Interaction>>,
+) {
+    if *phase.get() != AppState::Paused {
+        return;
+    }
+    for (interaction, button) in &q_buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        // The click must not start charging a shot once play resumes.
+        buttons.clear_just_pressed(MouseButton::Left);
+        match button {
+            PauseButton::Resume => next_state.set(AppState::Playing),
+            PauseButton::Settings => settings.open = true,
+            PauseButton::Restart => {
+                ev_restart.send(RestartGameEvent);
+                next_state.set(AppState::Playing);
+            }
+            PauseButton::Quit => {
+                ev_restart.send(RestartGameEvent);
+                next_state.set(AppState::Menu);
+            }
+        }
+    }
+}
+
+fn sync_pause_menu(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    phase: Res<State<AppState>>,
+    settings: Res<PerfMenuState>,
+    mut q_root: Query<(Entity, &mut Visibility), With<PauseMenuRoot>>,
+) {
+    let paused = *phase.get() == AppState::Paused;
+    match q_root.get_single_mut() {
+        Ok((root, mut vis)) => {
+            if !paused {
+                commands.entity(root).despawn_recursive();
+                return;
+            }
+            // The settings panel takes the pause menu's place while open.
+            let want = if settings.open { Visibility::Hidden } else { Visibility::Inherited };
+            if *vis != want {
+                *vis = want;
+            }
+        }
+        Err(_) if paused => spawn_pause_menu(&mut commands, &assets),
+        Err(_) => {}
+    }
+}
+
+fn spawn_pause_menu(commands: &mut Commands, assets: &AssetServer) {
+    let font = assets.load("fonts/FiraSans-Bold.ttf");
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(14.0),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(0.02, 0.02, 0.05, 0.6)),
+                ..default()
+            },
+            PauseMenuRoot,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Paused",
+                TextStyle { font: font.clone(), font_size: 48.0, color: Color::srgb(0.95, 0.95, 1.0) },
+            ));
+            spawn_button(parent, &font, "pause.resume", Color::srgb(0.15, 0.55, 0.25), Some(PauseButton::Resume));
+            spawn_button(parent, &font, "pause.settings", Color::srgb(0.25, 0.30, 0.45), Some(PauseButton::Settings));
+            spawn_button(parent, &font, "pause.restart", Color::srgb(0.45, 0.40, 0.15), Some(PauseButton::Restart));
+            spawn_button(parent, &font, "pause.quit", Color::srgb(0.55, 0.15, 0.15), Some(PauseButton::Quit));
+        });
+}
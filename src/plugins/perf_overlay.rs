@@ -0,0 +1,235 @@
+// Performance overlay (F3).
+// A scrolling frame-time graph (one bar per frame, green / yellow / red against 60 / 30 fps) and
+// a few counters, all read from `DiagnosticsStore`: FPS and frame time from
+// `FrameTimeDiagnosticsPlugin`, entity count from `EntityCountDiagnosticsPlugin`, and this
+// game's own diagnostics (terrain chunks, visible trees, live particles) registered here.
+// The game counters are only measured while the overlay is open.
+
+use std::collections::VecDeque;
+
+use bevy::diagnostic::{
+    Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin,
+    RegisterDiagnostic,
+};
+use bevy::prelude::*;
+
+use crate::plugins::hud_layout::HudAnchor;
+use crate::plugins::particles::Particle;
+use crate::plugins::terrain::LoadedChunks;
+use crate::plugins::vegetation::{Tree, TreeCulled};
+
+pub const TERRAIN_CHUNKS: DiagnosticPath = DiagnosticPath::const_new("vibe_golf/terrain_chunks");
+pub const VISIBLE_TREES: DiagnosticPath = DiagnosticPath::const_new("vibe_golf/visible_trees");
+pub const PARTICLES: DiagnosticPath = DiagnosticPath::const_new("vibe_golf/particles");
+
+const GRAPH_BARS: usize = 120;
+const BAR_WIDTH: f32 = 2.0;
+const GRAPH_HEIGHT: f32 = 60.0;
+
+#[derive(Resource, Debug, Clone)]
+pub struct PerfOverlayConfig {
+    pub visible: bool,
+    pub full_scale_ms: f32, // frame time drawn at the full graph height
+}
+impl Default for PerfOverlayConfig {
+    fn default() -> Self {
+        Self { visible: false, full_scale_ms: 50.0 }
+    }
+}
+
+/// Last `cap` frame times (ms), oldest first.
+#[derive(Resource, Debug, Clone)]
+pub struct FrameTimeHistory {
+    pub samples: VecDeque<f32>,
+    pub cap: usize,
+}
+impl Default for FrameTimeHistory {
+    fn default() -> Self {
+        Self { samples: VecDeque::with_capacity(GRAPH_BARS), cap: GRAPH_BARS }
+    }
+}
+
+impl FrameTimeHistory {
+    pub fn push(&mut self, ms: f32) {
+        if self.samples.len() == self.cap {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(ms);
+    }
+
+    /// (average, worst) frame time in ms over the history.
+    pub fn stats(&self) -> (f32, f32) {
+        if self.samples.is_empty() {
+            return (0.0, 0.0);
+        }
+        let sum: f32 = self.samples.iter().sum();
+        let max = self.samples.iter().copied().fold(0.0, f32::max);
+        (sum / self.samples.len() as f32, max)
+    }
+}
+
+/// Bar color for a frame time: green up to 60 fps, yellow up to 30 fps, red beyond.
+pub fn frame_time_color(ms: f32) -> Color {
+    if ms <= 1000.0 / 60.0 + 0.5 {
+        Color::srgb(0.3, 0.9, 0.4)
+    } else if ms <= 1000.0 / 30.0 + 0.5 {
+        Color::srgb(0.95, 0.8, 0.25)
+    } else {
+        Color::srgb(0.95, 0.3, 0.25)
+    }
+}
+
+#[derive(Component)]
+struct PerfOverlayRoot;
+#[derive(Component)]
+struct PerfOverlayText;
+#[derive(Component)]
+struct PerfGraphBar(usize);
+
+pub struct PerfOverlayPlugin;
+impl Plugin for PerfOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin);
+        }
+        if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+            app.add_plugins(EntityCountDiagnosticsPlugin);
+        }
+        app.init_resource::<PerfOverlayConfig>()
+            .init_resource::<FrameTimeHistory>()
+            .register_diagnostic(Diagnostic::new(TERRAIN_CHUNKS))
+            .register_diagnostic(Diagnostic::new(VISIBLE_TREES))
+            .register_diagnostic(Diagnostic::new(PARTICLES))
+            .add_systems(Startup, spawn_perf_overlay)
+            .add_systems(
+                Update,
+                (
+                    toggle_perf_overlay,
+                    measure_game_counters.run_if(overlay_visible),
+                    update_perf_overlay,
+                )
+                    .chain(),
+            );
+    }
+}
+
+fn overlay_visible(cfg: Res<PerfOverlayConfig>) -> bool {
+    cfg.visible
+}
+
+fn spawn_perf_overlay(mut commands: Commands, assets: Res<AssetServer>) {
+    let font = assets.load("fonts/FiraSans-Bold.ttf");
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(204.0),
+                    bottom: Val::Px(12.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    padding: UiRect::all(Val::Px(6.0)),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.55)),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            HudAnchor::bottom_left(204.0, 12.0),
+            PerfOverlayRoot,
+            Name::new("PerfOverlay"),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                TextBundle::from_section("", TextStyle { font, font_size: 14.0, color: Color::WHITE }),
+                PerfOverlayText,
+            ));
+            // Graph: bars grow up from the bottom edge, newest on the right.
+            root.spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(GRAPH_BARS as f32 * BAR_WIDTH),
+                    height: Val::Px(GRAPH_HEIGHT),
+                    align_items: AlignItems::FlexEnd,
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.05)),
+                ..default()
+            })
+            .with_children(|graph| {
+                for i in 0..GRAPH_BARS {
+                    graph.spawn((
+                        NodeBundle {
+                            style: Style { width: Val::Px(BAR_WIDTH), height: Val::Px(0.0), ..default() },
+                            ..default()
+                        },
+                        PerfGraphBar(i),
+                    ));
+                }
+            });
+        });
+}
+
+fn toggle_perf_overlay(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut cfg: ResMut<PerfOverlayConfig>,
+    mut q_root: Query<&mut Visibility, With<PerfOverlayRoot>>,
+) {
+    if keys.just_pressed(KeyCode::F3) {
+        cfg.visible = !cfg.visible;
+    }
+    if let Ok(mut vis) = q_root.get_single_mut() {
+        let want = if cfg.visible { Visibility::Inherited } else { Visibility::Hidden };
+        if *vis != want {
+            *vis = want;
+        }
+    }
+}
+
+fn measure_game_counters(
+    mut diagnostics: Diagnostics,
+    chunks: Option<Res<LoadedChunks>>,
+    q_trees: Query<&TreeCulled, With<Tree>>,
+    q_particles: Query<(), With<Particle>>,
+) {
+    diagnostics.add_measurement(&TERRAIN_CHUNKS, || chunks.as_ref().map_or(0, |c| c.map.len()) as f64);
+    diagnostics.add_measurement(&VISIBLE_TREES, || q_trees.iter().filter(|c| !c.0).count() as f64);
+    diagnostics.add_measurement(&PARTICLES, || q_particles.iter().count() as f64);
+}
+
+fn update_perf_overlay(
+    cfg: Res<PerfOverlayConfig>,
+    store: Res<DiagnosticsStore>,
+    mut history: ResMut<FrameTimeHistory>,
+    mut q_text: Query<&mut Text, With<PerfOverlayText>>,
+    mut q_bars: Query<(&PerfGraphBar, &mut Style, &mut BackgroundColor)>,
+) {
+    let latest = |path: &DiagnosticPath| store.get(path).and_then(|d| d.value());
+    // Keep recording while hidden so the graph is already full when opened.
+    if let Some(ms) = latest(&FrameTimeDiagnosticsPlugin::FRAME_TIME) {
+        history.push(ms as f32);
+    }
+    if !cfg.visible {
+        return;
+    }
+
+    let (avg, worst) = history.stats();
+    let fps = store.get(&FrameTimeDiagnosticsPlugin::FPS).and_then(|d| d.smoothed()).unwrap_or(0.0);
+    let count = |path: &DiagnosticPath| latest(path).map_or("-".to_string(), |v| format!("{v:.0}"));
+    if let Ok(mut text) = q_text.get_single_mut() {
+        text.sections[0].value = format!(
+            "FPS {fps:.0} | {avg:.1} ms avg | {worst:.1} ms max\nEntities {} | Chunks {} | Trees {} | Particles {}",
+            count(&EntityCountDiagnosticsPlugin::ENTITY_COUNT),
+            count(&TERRAIN_CHUNKS),
+            count(&VISIBLE_TREES),
+            count(&PARTICLES),
+        );
+    }
+
+    // Right-align the history in the bars (fewer samples than bars right after startup).
+    let offset = GRAPH_BARS.saturating_sub(history.samples.len());
+    for (bar, mut style, mut bg) in &mut q_bars {
+        let ms = bar.0.checked_sub(offset).and_then(|i| history.samples.get(i)).copied().unwrap_or(0.0);
+        style.height = Val::Px((ms / cfg.full_scale_ms.max(1.0)).min(1.0) * GRAPH_HEIGHT);
+        bg.0 = frame_time_color(ms);
+    }
+}
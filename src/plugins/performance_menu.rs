@@ -1,12 +1,22 @@
 use bevy::prelude::*;
 
+use crate::plugins::storage::StoragePaths;
 use crate::plugins::terrain::TerrainConfig;
+use crate::plugins::terrain_presets::{select_preset, ActiveTerrainPreset, TerrainPreset};
+use crate::plugins::shadow_settings::ShadowSettings;
 use crate::plugins::vegetation::{VegetationConfig, VegetationCullingConfig, VegetationLodConfig};
 use crate::plugins::particles::AtmosDustConfig;
+use crate::plugins::overview_camera::OverviewCameraConfig;
+use crate::plugins::camera::OrbitCameraConfig;
+use crate::plugins::hud_layout::{HudAnchor, HudLayoutConfig};
+use crate::plugins::localization::{Localization, LocalizedText};
+use crate::plugins::low_power::{save_low_power, LowPowerMode};
+use crate::plugins::spectate::InteractiveUi;
+use crate::plugins::ui_theme::UiTheme;
 
 #[derive(Resource, Default)]
-struct PerfMenuState {
-    open: bool,
+pub(crate) struct PerfMenuState {
+    pub(crate) open: bool, // also opened from the pause menu's Settings button
 }
 
 #[derive(Component)]
@@ -35,6 +45,7 @@ struct CloseButton;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum ParamKind {
+    TerrainPreset,
     TerrainAmplitude,
     TerrainViewRadius,
     VegetationMaxInstances,
@@ -46,8 +57,24 @@ enum ParamKind {
     VegetationShadowOn,
     VegetationShadowOff,
     AmbientBrightness,
+    SunShadowsToggle,
+    ShadowResolution,
+    ShadowDistance,
+    ShadowCascades,
     AtmosDustCount,
     AtmosDustRiseSpeed,
+    OverviewToggle,
+    OverviewFramingToggle,
+    CameraSmoothingToggle,
+    CameraSnapToggle,
+    HudScale,
+    HudAutoScaleToggle,
+    SafeAreaVertical,   // top + bottom insets
+    SafeAreaHorizontal, // left + right insets
+    Language,
+    ColorPalette,
+    HighContrastToggle,
+    LowPowerToggle,
 }
 
 pub struct PerformanceMenuPlugin;
@@ -87,6 +114,7 @@ fn spawn_perf_menu_ui(
             ..default()
         },
         PerfMenuRoot,
+        InteractiveUi,
     )).with_children(|root| {
         // Gear button (bottom-right)
         root.spawn((
@@ -101,6 +129,7 @@ fn spawn_perf_menu_ui(
                 background_color: BackgroundColor(Color::srgb(0.12, 0.12, 0.18)),
                 ..default()
             },
+            HudAnchor::bottom_right(12.0, 12.0),
             GearButton,
         )).with_children(|b| {
             b.spawn(TextBundle::from_section(
@@ -117,7 +146,7 @@ fn spawn_perf_menu_ui(
                     bottom: Val::Px(60.0),
                     right: Val::Px(12.0),
                     width: Val::Px(360.0),
-                    max_height: Val::Px(640.0),
+                    max_height: Val::Vh(80.0), // viewport-relative: fits whatever the HUD scale
                     flex_direction: FlexDirection::Column,
                     overflow: Overflow::clip_y(),
                     row_gap: Val::Px(4.0),
@@ -128,58 +157,80 @@ fn spawn_perf_menu_ui(
                 visibility: Visibility::Hidden,
                 ..default()
             },
+            HudAnchor::bottom_right(12.0, 60.0),
             PerfMenuPanel,
         )).with_children(|panel| {
             // Header
-            panel.spawn(TextBundle::from_section(
-                "Performance / Tweaks",
-                TextStyle { font: font.clone(), font_size: 22.0, color: Color::srgb(0.95,0.95,1.0) }
+            panel.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle { font: font.clone(), font_size: 22.0, color: Color::srgb(0.95,0.95,1.0) }
+                ),
+                LocalizedText::new("settings.title"),
             ));
 
             spawn_close_button(panel, &font);
 
-            panel.spawn(TextBundle::from_section(
-                "Terrain",
-                TextStyle { font: font.clone(), font_size: 18.0, color: Color::srgb(0.80,0.90,1.0) }
-            ));
+            spawn_section_header(panel, &font, "settings.section.terrain");
 
-            spawn_param_row(panel, &font, "Amplitude", ParamKind::TerrainAmplitude, 0.25, -0.25, 0.25);
-            spawn_param_row(panel, &font, "View Radius (chunks)", ParamKind::TerrainViewRadius, 1.0, -1.0, 1.0);
+            spawn_toggle_row(panel, &font, "settings.quality_preset", ParamKind::TerrainPreset);
+            spawn_param_row(panel, &font, "settings.amplitude", ParamKind::TerrainAmplitude, 0.25, -0.25, 0.25);
+            spawn_param_row(panel, &font, "settings.view_radius", ParamKind::TerrainViewRadius, 1.0, -1.0, 1.0);
 
-            panel.spawn(TextBundle::from_section(
-                "Vegetation",
-                TextStyle { font: font.clone(), font_size: 18.0, color: Color::srgb(0.80,0.90,1.0) }
-            ));
-            spawn_toggle_row(panel, &font, "Instanced Mode", ParamKind::VegetationInstancedToggle);
-            spawn_toggle_row(panel, &font, "DrawCall Debug", ParamKind::VegetationDrawCallDebugToggle);
-            spawn_param_row(panel, &font, "Max Instances", ParamKind::VegetationMaxInstances, 500.0, -500.0, 500.0);
-            spawn_param_row(panel, &font, "Samples / Frame", ParamKind::VegetationSamplesPerFrame, 100.0, -100.0, 100.0);
+            spawn_section_header(panel, &font, "settings.section.vegetation");
+            spawn_toggle_row(panel, &font, "settings.instanced", ParamKind::VegetationInstancedToggle);
+            spawn_toggle_row(panel, &font, "settings.draw_call_debug", ParamKind::VegetationDrawCallDebugToggle);
+            spawn_param_row(panel, &font, "settings.max_instances", ParamKind::VegetationMaxInstances, 500.0, -500.0, 500.0);
+            spawn_param_row(panel, &font, "settings.samples_per_frame", ParamKind::VegetationSamplesPerFrame, 100.0, -100.0, 100.0);
 
-            panel.spawn(TextBundle::from_section(
-                "Culling & Shadows",
-                TextStyle { font: font.clone(), font_size: 18.0, color: Color::srgb(0.80,0.90,1.0) }
-            ));
-            spawn_toggle_row(panel, &font, "Distance Culling", ParamKind::VegetationCullingEnableToggle);
-            spawn_param_row(panel, &font, "Cull Distance", ParamKind::VegetationCullingMaxDistance, 50.0, -50.0, 50.0);
-            spawn_param_row(panel, &font, "Shadow On Dist", ParamKind::VegetationShadowOn, 5.0, -5.0, 5.0);
-            spawn_param_row(panel, &font, "Shadow Off Dist", ParamKind::VegetationShadowOff, 5.0, -5.0, 5.0);
+            spawn_section_header(panel, &font, "settings.section.culling");
+            spawn_toggle_row(panel, &font, "settings.distance_culling", ParamKind::VegetationCullingEnableToggle);
+            spawn_param_row(panel, &font, "settings.cull_distance", ParamKind::VegetationCullingMaxDistance, 50.0, -50.0, 50.0);
+            spawn_param_row(panel, &font, "settings.shadow_on", ParamKind::VegetationShadowOn, 5.0, -5.0, 5.0);
+            spawn_param_row(panel, &font, "settings.shadow_off", ParamKind::VegetationShadowOff, 5.0, -5.0, 5.0);
 
-            panel.spawn(TextBundle::from_section(
-                "Lighting",
-                TextStyle { font: font.clone(), font_size: 18.0, color: Color::srgb(0.80,0.90,1.0) }
-            ));
-            spawn_param_row(panel, &font, "Ambient Bright", ParamKind::AmbientBrightness, 50.0, -50.0, 50.0);
+            spawn_section_header(panel, &font, "settings.section.lighting");
+            spawn_param_row(panel, &font, "settings.ambient", ParamKind::AmbientBrightness, 50.0, -50.0, 50.0);
+            spawn_toggle_row(panel, &font, "settings.sun_shadows", ParamKind::SunShadowsToggle);
+            spawn_toggle_row(panel, &font, "settings.shadow_resolution", ParamKind::ShadowResolution);
+            spawn_param_row(panel, &font, "settings.shadow_distance", ParamKind::ShadowDistance, 50.0, -50.0, 50.0);
+            spawn_param_row(panel, &font, "settings.shadow_cascades", ParamKind::ShadowCascades, 1.0, -1.0, 1.0);
 
-            panel.spawn(TextBundle::from_section(
-                "Particles",
-                TextStyle { font: font.clone(), font_size: 18.0, color: Color::srgb(0.80,0.90,1.0) }
-            ));
-            spawn_param_row(panel, &font, "Dust Count", ParamKind::AtmosDustCount, 20.0, -20.0, 20.0);
-            spawn_param_row(panel, &font, "Dust Rise Speed", ParamKind::AtmosDustRiseSpeed, 0.02, -0.02, 0.02);
+            spawn_section_header(panel, &font, "settings.section.particles");
+            spawn_param_row(panel, &font, "settings.dust_count", ParamKind::AtmosDustCount, 20.0, -20.0, 20.0);
+            spawn_param_row(panel, &font, "settings.dust_rise", ParamKind::AtmosDustRiseSpeed, 0.02, -0.02, 0.02);
+
+            spawn_section_header(panel, &font, "settings.section.camera");
+            spawn_toggle_row(panel, &font, "settings.overview", ParamKind::OverviewToggle);
+            spawn_toggle_row(panel, &font, "settings.overview_framing", ParamKind::OverviewFramingToggle);
+            spawn_toggle_row(panel, &font, "settings.smoothing", ParamKind::CameraSmoothingToggle);
+            spawn_toggle_row(panel, &font, "settings.snap", ParamKind::CameraSnapToggle);
+
+            spawn_section_header(panel, &font, "settings.section.interface");
+            spawn_param_row(panel, &font, "settings.hud_scale", ParamKind::HudScale, 0.1, -0.1, 0.1);
+            spawn_toggle_row(panel, &font, "settings.auto_scale", ParamKind::HudAutoScaleToggle);
+            spawn_param_row(panel, &font, "settings.safe_vertical", ParamKind::SafeAreaVertical, 4.0, -4.0, 4.0);
+            spawn_param_row(panel, &font, "settings.safe_horizontal", ParamKind::SafeAreaHorizontal, 4.0, -4.0, 4.0);
+            spawn_toggle_row(panel, &font, "settings.language", ParamKind::Language);
+
+            spawn_section_header(panel, &font, "settings.section.accessibility");
+            spawn_toggle_row(panel, &font, "settings.color_palette", ParamKind::ColorPalette);
+            spawn_toggle_row(panel, &font, "settings.high_contrast", ParamKind::HighContrastToggle);
+            spawn_toggle_row(panel, &font, "settings.low_power", ParamKind::LowPowerToggle);
         });
     });
 }
 
+fn spawn_section_header(parent: &mut ChildBuilder, font: &Handle<Font>, key: &'static str) {
+    parent.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle { font: font.clone(), font_size: 18.0, color: Color::srgb(0.80,0.90,1.0) }
+        ),
+        LocalizedText::new(key),
+    ));
+}
+
 fn spawn_close_button(parent: &mut ChildBuilder, font: &Handle<Font>) {
     parent.spawn((
         ButtonBundle {
@@ -194,9 +245,12 @@ fn spawn_close_button(parent: &mut ChildBuilder, font: &Handle<Font>) {
         },
         CloseButton,
     )).with_children(|b| {
-        b.spawn(TextBundle::from_section(
-            "Close",
-            TextStyle { font: font.clone(), font_size: 16.0, color: Color::WHITE }
+        b.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle { font: font.clone(), font_size: 16.0, color: Color::WHITE }
+            ),
+            LocalizedText::new("settings.close"),
         ));
     });
 }
@@ -204,7 +258,7 @@ fn spawn_close_button(parent: &mut ChildBuilder, font: &Handle<Font>) {
 fn spawn_param_row(
     parent: &mut ChildBuilder,
     font: &Handle<Font>,
-    label: &str,
+    label_key: &'static str,
     kind: ParamKind,
     step_pos: f32,
     step_neg: f32,
@@ -223,9 +277,12 @@ fn spawn_param_row(
         },
         ParamRow,
     )).with_children(|row| {
-        row.spawn(TextBundle::from_section(
-            label,
-            TextStyle { font: font.clone(), font_size: 14.0, color: Color::srgb(0.85,0.90,1.0) }
+        row.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle { font: font.clone(), font_size: 14.0, color: Color::srgb(0.85,0.90,1.0) }
+            ),
+            LocalizedText::new(label_key),
         ));
         // minus
         row.spawn((
@@ -275,7 +332,7 @@ fn spawn_param_row(
 fn spawn_toggle_row(
     parent: &mut ChildBuilder,
     font: &Handle<Font>,
-    label: &str,
+    label_key: &'static str,
     kind: ParamKind,
 ) {
     parent.spawn((
@@ -291,9 +348,12 @@ fn spawn_toggle_row(
         },
         ParamRow,
     )).with_children(|row| {
-        row.spawn(TextBundle::from_section(
-            label,
-            TextStyle { font: font.clone(), font_size: 14.0, color: Color::srgb(0.85,0.90,1.0) }
+        row.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle { font: font.clone(), font_size: 14.0, color: Color::srgb(0.85,0.90,1.0) }
+            ),
+            LocalizedText::new(label_key),
         ));
         row.spawn((
             ButtonBundle {
@@ -308,9 +368,10 @@ fn spawn_toggle_row(
         )).with_children(|b| {
             b.spawn((
                 TextBundle::from_section(
-                    "Toggle",
+                    "",
                     TextStyle { font: font.clone(), font_size: 14.0, color: Color::WHITE }
                 ),
+                LocalizedText::new("settings.toggle"),
             ));
         });
         row.spawn((
@@ -358,11 +419,14 @@ fn sync_panel_visibility(
 fn param_adjust_buttons(
     mut q_buttons: Query<(&Interaction, &ParamAdjustButton), (Changed<Interaction>, With<Button>)>,
     mut terrain_cfg: Option<ResMut<TerrainConfig>>,
+    mut active_preset: Option<ResMut<ActiveTerrainPreset>>,
     mut veg_cfg: Option<ResMut<VegetationConfig>>,
     mut cull_cfg: Option<ResMut<VegetationCullingConfig>>,
     mut lod_cfg: Option<ResMut<VegetationLodConfig>>,
     mut ambient: ResMut<AmbientLight>,
     mut atmos: Option<ResMut<AtmosDustConfig>>,
+    mut hud_layout: Option<ResMut<HudLayoutConfig>>,
+    mut shadows: Option<ResMut<ShadowSettings>>,
 ) {
     for (interaction, btn) in q_buttons.iter_mut() {
         if *interaction != Interaction::Pressed { continue; }
@@ -371,6 +435,7 @@ fn param_adjust_buttons(
                 if let Some(ref mut c) = terrain_cfg {
                     c.amplitude = (c.amplitude + btn.delta).clamp(0.25, 12.0);
                 }
+                // Not a preset knob: the preset stays selected.
             }
             ParamKind::TerrainViewRadius => {
                 if let Some(ref mut c) = terrain_cfg {
@@ -378,6 +443,13 @@ fn param_adjust_buttons(
                     v = v.clamp(2.0, 12.0);
                     c.view_radius_chunks = v.round() as i32;
                 }
+                // Manual tweak -> Custom, unless it is back on the preset's value.
+                if let (Some(ref c), Some(ref mut a)) = (&terrain_cfg, &mut active_preset) {
+                    let kept = a.0.filter(|p| p.matches(c));
+                    if a.0 != kept {
+                        a.0 = kept;
+                    }
+                }
             }
             ParamKind::VegetationMaxInstances => {
                 if let Some(ref mut c) = veg_cfg {
@@ -420,6 +492,25 @@ fn param_adjust_buttons(
             ParamKind::AmbientBrightness => {
                 ambient.brightness = (ambient.brightness + btn.delta).clamp(50.0, 2000.0);
             }
+            ParamKind::ShadowDistance => {
+                if let Some(ref mut c) = shadows {
+                    let v = c.max_distance + btn.delta;
+                    c.set_max_distance(v);
+                }
+                // Shadows are part of the quality preset.
+                if let Some(ref mut a) = active_preset {
+                    a.0 = None;
+                }
+            }
+            ParamKind::ShadowCascades => {
+                if let Some(ref mut c) = shadows {
+                    let v = c.cascades as f32 + btn.delta;
+                    c.set_cascades(v.max(1.0) as usize);
+                }
+                if let Some(ref mut a) = active_preset {
+                    a.0 = None;
+                }
+            }
             ParamKind::AtmosDustCount => {
                 if let Some(ref mut c) = atmos {
                     let mut v = c.count as f32 + btn.delta;
@@ -432,6 +523,26 @@ fn param_adjust_buttons(
                     c.rise_speed = (c.rise_speed + btn.delta).clamp(0.0, 2.0);
                 }
             }
+            ParamKind::HudScale => {
+                if let Some(ref mut c) = hud_layout {
+                    let v = ((c.scale + btn.delta) * 10.0).round() / 10.0;
+                    c.set_scale(v);
+                }
+            }
+            ParamKind::SafeAreaVertical => {
+                if let Some(ref mut c) = hud_layout {
+                    let v = (c.safe_area.top + btn.delta).clamp(0.0, 120.0);
+                    c.safe_area.top = v;
+                    c.safe_area.bottom = v;
+                }
+            }
+            ParamKind::SafeAreaHorizontal => {
+                if let Some(ref mut c) = hud_layout {
+                    let v = (c.safe_area.left + btn.delta).clamp(0.0, 120.0);
+                    c.safe_area.left = v;
+                    c.safe_area.right = v;
+                }
+            }
             _ => {}
         }
     }
@@ -439,12 +550,37 @@ fn param_adjust_buttons(
 
 fn toggle_buttons(
     mut q_buttons: Query<(&Interaction, &ToggleButton), (Changed<Interaction>, With<Button>)>,
+    mut terrain_cfg: Option<ResMut<TerrainConfig>>,
+    mut active_preset: Option<ResMut<ActiveTerrainPreset>>,
     mut veg_cfg: Option<ResMut<VegetationConfig>>,
     mut cull_cfg: Option<ResMut<VegetationCullingConfig>>,
+    mut overview_cfg: Option<ResMut<OverviewCameraConfig>>,
+    mut orbit_cfg: Option<ResMut<OrbitCameraConfig>>,
+    mut hud_layout: Option<ResMut<HudLayoutConfig>>,
+    mut loc: Option<ResMut<Localization>>,
+    mut theme: Option<ResMut<UiTheme>>,
+    mut shadows: Option<ResMut<ShadowSettings>>,
+    mut low_power: Option<ResMut<LowPowerMode>>,
+    paths: Res<StoragePaths>,
 ) {
     for (interaction, btn) in q_buttons.iter_mut() {
         if *interaction != Interaction::Pressed { continue; }
         match btn.kind {
+            ParamKind::TerrainPreset => {
+                if let (Some(ref mut c), Some(ref mut a)) = (&mut terrain_cfg, &mut active_preset) {
+                    // Custom -> start the cycle from Low.
+                    let next = a.0.map(|p| p.next()).unwrap_or(TerrainPreset::Low);
+                    select_preset(next, c, a, &paths);
+                }
+            }
+            ParamKind::SunShadowsToggle => {
+                if let Some(ref mut c) = shadows { c.enabled = !c.enabled; }
+                if let Some(ref mut a) = active_preset { a.0 = None; }
+            }
+            ParamKind::ShadowResolution => {
+                if let Some(ref mut c) = shadows { c.map_resolution = c.next_resolution(); }
+                if let Some(ref mut a) = active_preset { a.0 = None; }
+            }
             ParamKind::VegetationInstancedToggle => {
                 if let Some(ref mut c) = veg_cfg { c.use_instanced = !c.use_instanced; }
             }
@@ -454,6 +590,36 @@ fn toggle_buttons(
             ParamKind::VegetationCullingEnableToggle => {
                 if let Some(ref mut c) = cull_cfg { c.enable_distance = !c.enable_distance; }
             }
+            ParamKind::OverviewToggle => {
+                if let Some(ref mut c) = overview_cfg { c.enabled = !c.enabled; }
+            }
+            ParamKind::OverviewFramingToggle => {
+                if let Some(ref mut c) = overview_cfg { c.framing = c.framing.next(); }
+            }
+            ParamKind::CameraSmoothingToggle => {
+                if let Some(ref mut c) = orbit_cfg { let next = c.smoothing.next(); c.set_smoothing(next); }
+            }
+            ParamKind::CameraSnapToggle => {
+                if let Some(ref mut c) = orbit_cfg { c.snap_on_teleport = !c.snap_on_teleport; }
+            }
+            ParamKind::HudAutoScaleToggle => {
+                if let Some(ref mut c) = hud_layout { c.auto_scale = !c.auto_scale; }
+            }
+            ParamKind::Language => {
+                if let Some(ref mut l) = loc { l.cycle(); }
+            }
+            ParamKind::ColorPalette => {
+                if let Some(ref mut t) = theme { t.palette = t.palette.next(); }
+            }
+            ParamKind::HighContrastToggle => {
+                if let Some(ref mut t) = theme { t.high_contrast = !t.high_contrast; }
+            }
+            ParamKind::LowPowerToggle => {
+                if let Some(ref mut m) = low_power {
+                    m.enabled = !m.enabled;
+                    save_low_power(&paths, m.enabled);
+                }
+            }
             _ => {}
         }
     }
@@ -461,28 +627,60 @@ fn toggle_buttons(
 
 fn refresh_param_texts(
     terrain_cfg: Option<Res<TerrainConfig>>,
+    active_preset: Option<Res<ActiveTerrainPreset>>,
     veg_cfg: Option<Res<VegetationConfig>>,
     cull_cfg: Option<Res<VegetationCullingConfig>>,
     lod_cfg: Option<Res<VegetationLodConfig>>,
     ambient: Option<Res<AmbientLight>>,
     atmos: Option<Res<AtmosDustConfig>>,
+    overview_cfg: Option<Res<OverviewCameraConfig>>,
+    orbit_cfg: Option<Res<OrbitCameraConfig>>,
+    hud_layout: Option<Res<HudLayoutConfig>>,
+    loc: Option<Res<Localization>>,
+    theme: Option<Res<UiTheme>>,
+    shadows: Option<Res<ShadowSettings>>,
+    low_power: Option<Res<LowPowerMode>>,
     mut q_values: Query<(&mut Text, &ParamValueText)>,
 ) {
+    let on_off = |on: bool| {
+        let key = if on { "settings.on" } else { "settings.off" };
+        loc.as_ref().map_or(key, |l| l.t(key)).to_string()
+    };
     for (mut text, tag) in &mut q_values {
         let v = match tag.kind {
+            ParamKind::TerrainPreset => active_preset.as_ref().map(|a| a.0.map(|p| p.name()).unwrap_or_else(|| loc.as_ref().map_or("Custom", |l| l.t("settings.custom"))).to_string()),
             ParamKind::TerrainAmplitude => terrain_cfg.as_ref().map(|c| format!("{:.2}", c.amplitude)),
             ParamKind::TerrainViewRadius => terrain_cfg.as_ref().map(|c| format!("{}", c.view_radius_chunks)),
             ParamKind::VegetationMaxInstances => veg_cfg.as_ref().map(|c| format!("{}", c.max_instances)),
             ParamKind::VegetationSamplesPerFrame => veg_cfg.as_ref().map(|c| format!("{}", c.samples_per_frame)),
-            ParamKind::VegetationInstancedToggle => veg_cfg.as_ref().map(|c| if c.use_instanced { "On".into() } else { "Off".into() }),
-            ParamKind::VegetationDrawCallDebugToggle => veg_cfg.as_ref().map(|c| if c.debug_draw_calls { "On".into() } else { "Off".into() }),
-            ParamKind::VegetationCullingEnableToggle => cull_cfg.as_ref().map(|c| if c.enable_distance { "On".into() } else { "Off".into() }),
+            ParamKind::VegetationInstancedToggle => veg_cfg.as_ref().map(|c| on_off(c.use_instanced)),
+            ParamKind::VegetationDrawCallDebugToggle => veg_cfg.as_ref().map(|c| on_off(c.debug_draw_calls)),
+            ParamKind::VegetationCullingEnableToggle => cull_cfg.as_ref().map(|c| on_off(c.enable_distance)),
             ParamKind::VegetationCullingMaxDistance => cull_cfg.as_ref().map(|c| format!("{:.0}", c.max_distance)),
             ParamKind::VegetationShadowOn => lod_cfg.as_ref().map(|c| format!("{:.0}", c.shadows_full_on)),
             ParamKind::VegetationShadowOff => lod_cfg.as_ref().map(|c| format!("{:.0}", c.shadows_full_off)),
             ParamKind::AmbientBrightness => ambient.as_ref().map(|c| format!("{:.0}", c.brightness)),
+            ParamKind::SunShadowsToggle => shadows.as_ref().map(|c| on_off(c.enabled)),
+            ParamKind::ShadowResolution => shadows.as_ref().map(|c| format!("{}", c.map_resolution)),
+            ParamKind::ShadowDistance => shadows.as_ref().map(|c| format!("{:.0}m", c.max_distance)),
+            ParamKind::ShadowCascades => shadows.as_ref().map(|c| format!("{}", c.cascades)),
             ParamKind::AtmosDustCount => atmos.as_ref().map(|c| format!("{}", c.count)),
             ParamKind::AtmosDustRiseSpeed => atmos.as_ref().map(|c| format!("{:.3}", c.rise_speed)),
+            ParamKind::OverviewToggle => overview_cfg.as_ref().map(|c| on_off(c.enabled)),
+            ParamKind::OverviewFramingToggle => overview_cfg.as_ref().map(|c| c.framing.name().to_string()),
+            ParamKind::CameraSmoothingToggle => orbit_cfg.as_ref().map(|c| c.smoothing.name().to_string()),
+            ParamKind::CameraSnapToggle => orbit_cfg.as_ref().map(|c| on_off(c.snap_on_teleport)),
+            ParamKind::HudScale => hud_layout.as_ref().map(|c| format!("{:.1}x", c.scale)),
+            ParamKind::HudAutoScaleToggle => hud_layout.as_ref().map(|c| on_off(c.auto_scale)),
+            ParamKind::SafeAreaVertical => hud_layout.as_ref().map(|c| format!("{:.0}px", c.safe_area.top)),
+            ParamKind::SafeAreaHorizontal => hud_layout.as_ref().map(|c| format!("{:.0}px", c.safe_area.left)),
+            ParamKind::Language => loc.as_ref().map(|l| l.language_name().to_string()),
+            ParamKind::ColorPalette => theme.as_ref().map(|t| {
+                let key = t.palette.label_key();
+                loc.as_ref().map_or(key, |l| l.t(key)).to_string()
+            }),
+            ParamKind::HighContrastToggle => theme.as_ref().map(|t| on_off(t.high_contrast)),
+            ParamKind::LowPowerToggle => low_power.as_ref().map(|m| on_off(m.enabled)),
         };
         if let Some(s) = v {
             if text.sections[0].value != s {
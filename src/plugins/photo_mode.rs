@@ -0,0 +1,280 @@
+// Photo mode (P).
+// Pauses virtual time (so the fixed simulation, particles and targets freeze), hands the gameplay
+// camera to a free-flying photo controller and hides the HUD overlay camera. Controls:
+//   WASD / E / Q move (Shift faster), right-drag look, Z / C roll, mouse wheel FOV,
+//   G toggles depth of field, [ / ] focus distance, , / . aperture, Space / Enter saves a photo.
+// Photos go through `ScreenshotManager` to `screenshots/photos/photo_<timestamp>.png` in the data
+// directory (see storage.rs). The manager only reads back window surfaces, so photos are taken at
+// the window's physical resolution (fullscreen / HiDPI for bigger shots); the window itself is
+// never resized for a shot. Leaving photo mode restores camera, FOV and time.
+
+use bevy::core_pipeline::dof::{DepthOfFieldMode, DepthOfFieldSettings};
+use bevy::input::mouse::{MouseMotion, MouseWheel};
+use bevy::prelude::*;
+use bevy::render::view::screenshot::ScreenshotManager;
+use bevy::window::PrimaryWindow;
+
+use crate::plugins::camera::{fly_direction, CameraMode, OrbitCamera};
+use crate::plugins::event_log::{EventLog, LogKind};
+use crate::plugins::localization::Localization;
+use crate::plugins::app_state::AppState;
+use crate::plugins::storage::StoragePaths;
+
+#[derive(Resource, Debug, Clone)]
+pub struct PhotoModeConfig {
+    pub speed: f32,           // m/s
+    pub fast_multiplier: f32,
+    pub sensitivity: f32,     // rad per pixel of mouse motion
+    pub roll_speed: f32,      // rad/s
+    pub fov_range_deg: (f32, f32),
+    pub fov_step_deg: f32,    // per wheel notch
+    pub focus_range: (f32, f32),
+    pub aperture_range: (f32, f32), // f-stops
+    pub directory: String,
+}
+impl Default for PhotoModeConfig {
+    fn default() -> Self {
+        Self {
+            speed: 20.0,
+            fast_multiplier: 5.0,
+            sensitivity: 0.003,
+            roll_speed: 0.8,
+            fov_range_deg: (10.0, 100.0),
+            fov_step_deg: 2.0,
+            focus_range: (0.5, 2000.0),
+            aperture_range: (0.5, 32.0),
+            directory: "screenshots/photos".into(),
+        }
+    }
+}
+
+/// Photo controller state (`active` while in photo mode).
+#[derive(Resource, Debug)]
+pub struct PhotoMode {
+    pub active: bool,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub roll: f32,
+    pub fov_deg: f32,
+    pub dof: bool,
+    pub focus_distance: f32,
+    pub aperture_f_stops: f32,
+    pub photos_taken: u32,
+    restore: Option<(CameraMode, Transform, f32)>, // mode, camera transform, FOV (rad)
+}
+impl Default for PhotoMode {
+    fn default() -> Self {
+        Self {
+            active: false,
+            yaw: 0.0,
+            pitch: 0.0,
+            roll: 0.0,
+            fov_deg: 45.0,
+            dof: false,
+            focus_distance: 30.0,
+            aperture_f_stops: 2.8,
+            photos_taken: 0,
+            restore: None,
+        }
+    }
+}
+
+/// Camera rotation for the photo controller (yaw about Y, pitch, then roll about the view axis).
+pub fn photo_rotation(yaw: f32, pitch: f32, roll: f32) -> Quat {
+    Quat::from_euler(EulerRot::YXZ, yaw, pitch, roll)
+}
+
+/// `photo_YYYYMMDD_HHMMSS.png` (UTC) for a unix timestamp in seconds.
+pub fn photo_filename(unix_secs: u64) -> String {
+    let days = (unix_secs / 86_400) as i64;
+    let secs = unix_secs % 86_400;
+    // Days since 1970-01-01 to a civil date (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "photo_{year:04}{month:02}{day:02}_{:02}{:02}{:02}.png",
+        secs / 3600,
+        (secs / 60) % 60,
+        secs % 60
+    )
+}
+
+pub struct PhotoModePlugin;
+impl Plugin for PhotoModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhotoModeConfig>()
+            .init_resource::<PhotoMode>()
+            .add_systems(Update, (toggle_photo_mode, photo_camera_controls, photo_capture).chain());
+    }
+}
+
+fn toggle_photo_mode(
+    keys: Res<ButtonInput<KeyCode>>,
+    phase: Option<Res<State<AppState>>>,
+    mut mode: ResMut<CameraMode>,
+    mut photo: ResMut<PhotoMode>,
+    mut time: ResMut<Time<Virtual>>,
+    mut commands: Commands,
+    mut q_cam: Query<(Entity, &mut Transform, &mut Projection), With<OrbitCamera>>,
+    mut q_overlay: Query<&mut Camera, (With<Camera2d>, Without<OrbitCamera>)>,
+) {
+    let playing = phase.is_some_and(|p| p.get().round_in_view());
+    let enter = keys.just_pressed(KeyCode::KeyP) && playing && !photo.active && matches!(*mode, CameraMode::Orbit | CameraMode::Free | CameraMode::BallCam);
+    // Leaving the game (menu, phase change) also ends photo mode.
+    let exit = photo.active && (keys.just_pressed(KeyCode::KeyP) || !playing);
+    if !enter && !exit {
+        return;
+    }
+    let Ok((cam, mut t, mut projection)) = q_cam.get_single_mut() else { return; };
+    if enter {
+        let fov = match projection.as_ref() {
+            Projection::Perspective(p) => p.fov,
+            _ => 45f32.to_radians(),
+        };
+        let (yaw, pitch, _) = t.rotation.to_euler(EulerRot::YXZ);
+        photo.restore = Some((*mode, *t, fov));
+        photo.active = true;
+        (photo.yaw, photo.pitch, photo.roll) = (yaw, pitch, 0.0);
+        photo.fov_deg = fov.to_degrees();
+        photo.dof = false;
+        *mode = CameraMode::Photo;
+        time.pause();
+    } else {
+        if let Some((prev, saved_t, fov)) = photo.restore.take() {
+            *t = saved_t;
+            if let Projection::Perspective(p) = projection.as_mut() {
+                p.fov = fov;
+            }
+            *mode = if playing { prev } else { CameraMode::Orbit };
+        }
+        commands.entity(cam).remove::<DepthOfFieldSettings>();
+        photo.active = false;
+        time.unpause();
+    }
+    for mut overlay in &mut q_overlay {
+        overlay.is_active = !photo.active;
+    }
+    info!("Photo mode: {}", if photo.active { "on" } else { "off" });
+}
+
+fn photo_camera_controls(
+    real: Res<Time<Real>>,
+    cfg: Res<PhotoModeConfig>,
+    keys: Res<ButtonInput<KeyCode>>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    mut ev_motion: EventReader<MouseMotion>,
+    mut ev_wheel: EventReader<MouseWheel>,
+    mut photo: ResMut<PhotoMode>,
+    mut commands: Commands,
+    mut q_cam: Query<(Entity, &mut Transform, &mut Projection), With<OrbitCamera>>,
+) {
+    if !photo.active {
+        ev_motion.clear();
+        ev_wheel.clear();
+        return;
+    }
+    let Ok((cam, mut t, mut projection)) = q_cam.get_single_mut() else { return; };
+    let dt = real.delta_seconds();
+
+    if buttons.pressed(MouseButton::Right) {
+        for m in ev_motion.read() {
+            photo.yaw -= m.delta.x * cfg.sensitivity;
+            photo.pitch = (photo.pitch - m.delta.y * cfg.sensitivity).clamp(-1.55, 1.55);
+        }
+    } else {
+        ev_motion.clear();
+    }
+    let roll_input = keys.pressed(KeyCode::KeyZ) as i32 - keys.pressed(KeyCode::KeyC) as i32;
+    photo.roll += roll_input as f32 * cfg.roll_speed * dt;
+    for w in ev_wheel.read() {
+        photo.fov_deg = (photo.fov_deg - w.y.signum() * cfg.fov_step_deg).clamp(cfg.fov_range_deg.0, cfg.fov_range_deg.1);
+    }
+
+    let axis = |pos: KeyCode, neg: KeyCode| keys.pressed(pos) as i32 as f32 - keys.pressed(neg) as i32 as f32;
+    let input = Vec3::new(axis(KeyCode::KeyD, KeyCode::KeyA), axis(KeyCode::KeyE, KeyCode::KeyQ), axis(KeyCode::KeyW, KeyCode::KeyS));
+    let fast = if keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight) { cfg.fast_multiplier } else { 1.0 };
+    t.translation += fly_direction(photo.yaw, photo.pitch, input) * cfg.speed * fast * dt;
+    t.rotation = photo_rotation(photo.yaw, photo.pitch, photo.roll);
+    if let Projection::Perspective(p) = projection.as_mut() {
+        p.fov = photo.fov_deg.to_radians();
+    }
+
+    // Depth of field.
+    if keys.just_pressed(KeyCode::KeyG) {
+        photo.dof = !photo.dof;
+        if !photo.dof {
+            commands.entity(cam).remove::<DepthOfFieldSettings>();
+        }
+    }
+    let focus_step = 1.0 + dt * 1.5; // exponential so near and far focus are equally quick
+    if keys.pressed(KeyCode::BracketRight) {
+        photo.focus_distance *= focus_step;
+    }
+    if keys.pressed(KeyCode::BracketLeft) {
+        photo.focus_distance /= focus_step;
+    }
+    if keys.pressed(KeyCode::Period) {
+        photo.aperture_f_stops *= focus_step;
+    }
+    if keys.pressed(KeyCode::Comma) {
+        photo.aperture_f_stops /= focus_step;
+    }
+    photo.focus_distance = photo.focus_distance.clamp(cfg.focus_range.0, cfg.focus_range.1);
+    photo.aperture_f_stops = photo.aperture_f_stops.clamp(cfg.aperture_range.0, cfg.aperture_range.1);
+    if photo.dof {
+        commands.entity(cam).insert(DepthOfFieldSettings {
+            mode: DepthOfFieldMode::Gaussian,
+            focal_distance: photo.focus_distance,
+            aperture_f_stops: photo.aperture_f_stops,
+            ..default()
+        });
+    }
+}
+
+fn photo_capture(
+    cfg: Res<PhotoModeConfig>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut photo: ResMut<PhotoMode>,
+    mut screenshot_manager: Option<ResMut<ScreenshotManager>>,
+    mut event_log: Option<ResMut<EventLog>>,
+    loc: Option<Res<Localization>>,
+    paths: Res<StoragePaths>,
+    q_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    if !photo.active || !(keys.just_pressed(KeyCode::Space) || keys.just_pressed(KeyCode::Enter)) {
+        return;
+    }
+    let (Some(manager), Ok(window)) = (screenshot_manager.as_deref_mut(), q_window.get_single()) else { return; };
+    let unix = bevy::utils::SystemTime::now()
+        .duration_since(bevy::utils::SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let mut name = photo_filename(unix);
+    if photo.photos_taken > 0 {
+        // Several photos within one second keep distinct names.
+        name = name.replace(".png", &format!("_{}.png", photo.photos_taken));
+    }
+    let dir = paths.file(&cfg.directory);
+    let path = dir.join(name).to_string_lossy().into_owned();
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Err(e) = std::fs::create_dir_all(&dir) {
+        warn!("PHOTO dir create failed error={}", e);
+    }
+    match manager.save_screenshot_to_disk(window, path.clone()) {
+        Ok(()) => {
+            photo.photos_taken += 1;
+            info!("PHOTO saved path={}", path);
+            if let (Some(log), Some(loc)) = (event_log.as_deref_mut(), loc.as_deref()) {
+                log.push(LogKind::Info, loc.tf("log.photo_saved", &[("path", path.clone())]));
+            }
+        }
+        Err(e) => warn!("PHOTO capture failed error={}", e),
+    }
+}
@@ -0,0 +1,359 @@
+// Local hot-seat multiplayer (2-4 players on one machine, picked on the main menu).
+// Players take alternating strokes at their own balls. There is one physical `Ball`: the active
+// player's. When it comes to rest after a stroke the turn passes on, the ball's spot is parked
+// for that player (shown as a `ParkedBall` sphere in their color) and the ball moves to the next
+// player's spot, wearing that player's color. Each hole is a race: whoever hits the target first
+// wins it (its points count for them), then everybody plays the next hole from the new tee.
+// Strokes, holes and points are kept per player (`PlayerScore`); the shared `Score` still drives
+// the run (holes left, game over). The game-over panel ranks the players by holes won, then
+// points, then fewest strokes. With one player the plugin stays out of the way.
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::plugins::app_state::AppState;
+use crate::plugins::ball::{place_ball, Ball, BallKinematic};
+use crate::plugins::core_sim::GameSet;
+use crate::plugins::game_state::{HoleState, Score};
+use crate::plugins::localization::Localization;
+use crate::plugins::particles::{ShotFiredEvent, TargetHitEvent};
+use crate::plugins::target::TargetKind;
+use crate::plugins::terrain::TerrainSampler;
+
+pub const MAX_PLAYERS: usize = 4;
+pub const PLAYER_COLORS: [Color; MAX_PLAYERS] = [
+    Color::srgb(0.95, 0.30, 0.25),
+    Color::srgb(0.25, 0.55, 1.00),
+    Color::srgb(1.00, 0.85, 0.20),
+    Color::srgb(0.30, 0.85, 0.40),
+];
+/// m/s below which the ball counts as resting (the turn passes after `REST_TIME` of it).
+const REST_SPEED: f32 = 0.4;
+const REST_TIME: f32 = 0.6;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlayerScore {
+    pub strokes: u32,
+    pub holes: u32,  // holes won (first to the target)
+    pub points: u32, // TargetHitEvent points of those holes
+    pub ball: Option<Vec2>, // parked ball (x / z); None = on the hole's tee
+}
+
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct LocalPlayers {
+    pub players: Vec<PlayerScore>,
+    pub active: usize,
+    pub awaiting_rest: bool, // a stroke is in flight; the turn passes once the ball settles
+    pub hole_done: bool,     // the active player won the hole with that stroke
+}
+
+impl Default for LocalPlayers {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl LocalPlayers {
+    pub fn new(count: usize) -> Self {
+        Self {
+            players: vec![PlayerScore::default(); count.clamp(1, MAX_PLAYERS)],
+            active: 0,
+            awaiting_rest: false,
+            hole_done: false,
+        }
+    }
+
+    /// More than one player: turns, parked balls and the turn HUD are on.
+    pub fn hot_seat(&self) -> bool {
+        self.players.len() > 1
+    }
+
+    /// Next player count for the menu button (1 -> 2 -> 3 -> 4 -> 1).
+    pub fn cycled_count(&self) -> usize {
+        self.players.len() % MAX_PLAYERS + 1
+    }
+
+    /// Fresh scores, everyone on the tee, player 1 to play.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.players.len());
+    }
+
+    pub fn credit_stroke(&mut self) {
+        self.players[self.active].strokes += 1;
+        self.awaiting_rest = true;
+    }
+
+    /// The active player hit the target first: the hole is theirs.
+    pub fn credit_hole(&mut self, points: u32) {
+        let player = &mut self.players[self.active];
+        player.holes += 1;
+        player.points += points;
+        self.hole_done = true;
+    }
+
+    /// The active ball settled at `ball`: park it and hand over. Returns where the next player's
+    /// ball goes (`None` = the tee; after a won hole everybody starts the next one from there).
+    pub fn end_turn(&mut self, ball: Vec2) -> Option<Vec2> {
+        if self.hole_done {
+            for player in &mut self.players {
+                player.ball = None;
+            }
+        } else {
+            self.players[self.active].ball = Some(ball);
+        }
+        self.awaiting_rest = false;
+        self.hole_done = false;
+        self.active = (self.active + 1) % self.players.len();
+        self.players[self.active].ball
+    }
+
+    /// Player indices, best first: holes won, then points, then fewest strokes.
+    pub fn standings(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.players.len()).collect();
+        order.sort_by(|&a, &b| {
+            let (pa, pb) = (&self.players[a], &self.players[b]);
+            pb.holes.cmp(&pa.holes).then(pb.points.cmp(&pa.points)).then(pa.strokes.cmp(&pb.strokes))
+        });
+        order
+    }
+
+    /// Game-over line, e.g. "Winner: Player 2 | P2 2 holes, 7 strokes | P1 1 hole, 9 strokes".
+    pub fn summary(&self) -> String {
+        let order = self.standings();
+        let mut parts = vec![format!("Winner: Player {}", order[0] + 1)];
+        for i in order {
+            let p = &self.players[i];
+            let holes = if p.holes == 1 { "hole" } else { "holes" };
+            parts.push(format!("P{} {} {holes}, {} strokes", i + 1, p.holes, p.strokes));
+        }
+        parts.join(" | ")
+    }
+}
+
+pub fn player_color(index: usize) -> Color {
+    PLAYER_COLORS[index % MAX_PLAYERS]
+}
+
+/// Main menu player count button (spawned by main_menu.rs, handled here).
+#[derive(Component)]
+pub(crate) struct PlayersButton;
+#[derive(Component)]
+pub(crate) struct PlayersText;
+
+#[derive(Component)]
+struct ParkedBall(usize);
+#[derive(Component)]
+struct TurnIndicator;
+/// The ball scene's own material, kept on each of its meshes while a player color is worn.
+#[derive(Component)]
+struct BallBaseMaterial(Handle<StandardMaterial>);
+
+pub struct PlayersPlugin;
+impl Plugin for PlayersPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LocalPlayers>()
+            .add_systems(Startup, spawn_turn_indicator)
+            .add_systems(Update, players_menu_button.run_if(in_state(AppState::Menu)))
+            .add_systems(
+                Update,
+                (reset_players, credit_strokes, credit_holes, pass_turn).chain().in_set(GameSet::React),
+            )
+            .add_systems(Update, (tint_active_ball, update_parked_balls, update_turn_indicator).in_set(GameSet::Present));
+    }
+}
+
+fn players_menu_button(
+    loc: Res<Localization>,
+    mut players: ResMut<LocalPlayers>,
+    q_button: Query<&Interaction, (Changed<Interaction>, With<PlayersButton>)>,
+    mut q_text: Query<&mut Text, With<PlayersText>>,
+) {
+    if q_button.iter().any(|i| *i == Interaction::Pressed) {
+        *players = LocalPlayers::new(players.cycled_count());
+    }
+    let count = players.players.len().to_string();
+    let label = loc.tf("menu.players", &[("count", count)]);
+    for mut text in &mut q_text {
+        if text.sections[0].value != label {
+            text.sections[0].value = label.clone();
+        }
+    }
+}
+
+// New run (R, restart buttons, another level): scores cleared, player 1 on the tee.
+fn reset_players(mut players: ResMut<LocalPlayers>, score: Res<Score>) {
+    if score.is_changed() && score.shots == 0 && players.players.iter().any(|p| p.strokes > 0) {
+        players.reset();
+    }
+}
+
+fn credit_strokes(mut players: ResMut<LocalPlayers>, mut ev_shot: EventReader<ShotFiredEvent>) {
+    for _ in ev_shot.read() {
+        if players.hot_seat() {
+            players.credit_stroke();
+        }
+    }
+}
+
+fn credit_holes(mut players: ResMut<LocalPlayers>, mut ev_hit: EventReader<TargetHitEvent>) {
+    for hit in ev_hit.read() {
+        if players.hot_seat() && hit.kind == TargetKind::Standard {
+            players.credit_hole(hit.points);
+        }
+    }
+}
+
+fn pass_turn(
+    time: Res<Time>,
+    score: Res<Score>,
+    hole: Res<HoleState>,
+    sampler: Option<Res<TerrainSampler>>,
+    mut players: ResMut<LocalPlayers>,
+    mut q_ball: Query<(&mut Transform, &mut BallKinematic), With<Ball>>,
+    mut rest_for: Local<f32>,
+) {
+    if !players.hot_seat() || !players.awaiting_rest || score.game_over {
+        *rest_for = 0.0;
+        return;
+    }
+    let (Ok((mut t, mut kin)), Some(sampler)) = (q_ball.get_single_mut(), sampler) else { return; };
+    if kin.vel.length() > REST_SPEED {
+        *rest_for = 0.0;
+        return;
+    }
+    *rest_for += time.delta_seconds();
+    if *rest_for < REST_TIME {
+        return;
+    }
+    *rest_for = 0.0;
+    let next = players.end_turn(t.translation.xz()).unwrap_or(hole.tee);
+    place_ball(&mut t, &mut kin, &sampler, next);
+    info!("Player {}'s turn", players.active + 1);
+}
+
+// The ball wears the active player's color (a tinted copy of each of its scene materials).
+fn tint_active_ball(
+    mut commands: Commands,
+    players: Res<LocalPlayers>,
+    q_ball: Query<Entity, With<Ball>>,
+    q_children: Query<&Children>,
+    mut q_mats: Query<(&mut Handle<StandardMaterial>, Option<&BallBaseMaterial>)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut tinted: Local<HashMap<(AssetId<StandardMaterial>, usize), Handle<StandardMaterial>>>,
+) {
+    let Ok(ball) = q_ball.get_single() else { return; };
+    let fresh_meshes = q_children.iter_descendants(ball).any(|e| q_mats.get_mut(e).is_ok_and(|(h, _)| h.is_added()));
+    if !(players.is_changed() || fresh_meshes) {
+        return;
+    }
+    for entity in q_children.iter_descendants(ball) {
+        let Ok((mut handle, base)) = q_mats.get_mut(entity) else { continue; };
+        let base = match base {
+            Some(base) => base.0.clone(),
+            None => {
+                commands.entity(entity).insert(BallBaseMaterial(handle.clone()));
+                handle.clone()
+            }
+        };
+        let want = if players.hot_seat() {
+            let key = (base.id(), players.active);
+            if !tinted.contains_key(&key) {
+                let Some(mut material) = materials.get(&base).cloned() else { continue; };
+                material.base_color = player_color(players.active);
+                tinted.insert(key, materials.add(material));
+            }
+            tinted[&key].clone()
+        } else {
+            base
+        };
+        if *handle != want {
+            *handle = want;
+        }
+    }
+}
+
+// Spheres in the players' colors where the waiting players' balls lie.
+fn update_parked_balls(
+    mut commands: Commands,
+    players: Res<LocalPlayers>,
+    phase: Res<State<AppState>>,
+    sampler: Option<Res<TerrainSampler>>,
+    q_ball: Query<&BallKinematic, With<Ball>>,
+    mut q_parked: Query<(&ParkedBall, &mut Transform, &mut Visibility)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let (Ok(kin), Some(sampler)) = (q_ball.get_single(), sampler) else { return; };
+    if q_parked.is_empty() {
+        let mesh = meshes.add(Sphere::new(kin.visual_radius));
+        for i in 0..MAX_PLAYERS {
+            commands.spawn((
+                PbrBundle {
+                    mesh: mesh.clone(),
+                    material: materials.add(StandardMaterial { base_color: player_color(i), perceptual_roughness: 0.4, ..default() }),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                ParkedBall(i),
+                Name::new(format!("ParkedBall{}", i + 1)),
+            ));
+        }
+        return;
+    }
+    if !(players.is_changed() || phase.is_changed()) {
+        return;
+    }
+    let shown = players.hot_seat() && phase.get().round_in_view();
+    for (parked, mut t, mut vis) in &mut q_parked {
+        let spot = players.players.get(parked.0).filter(|_| shown && parked.0 != players.active).and_then(|p| p.ball);
+        *vis = match spot {
+            Some(pos) => {
+                t.translation = Vec3::new(pos.x, sampler.height(pos.x, pos.y) + kin.visual_radius, pos.y);
+                Visibility::Inherited
+            }
+            None => Visibility::Hidden,
+        };
+    }
+}
+
+fn spawn_turn_indicator(mut commands: Commands, assets: Res<AssetServer>) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle { font: assets.load("fonts/FiraSans-Bold.ttf"), font_size: 26.0, color: Color::WHITE },
+        )
+        .with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(44.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        }),
+        TurnIndicator,
+    ));
+}
+
+fn update_turn_indicator(
+    loc: Res<Localization>,
+    players: Res<LocalPlayers>,
+    phase: Res<State<AppState>>,
+    mut q_text: Query<(&mut Text, &mut Visibility), With<TurnIndicator>>,
+) {
+    let Ok((mut text, mut vis)) = q_text.get_single_mut() else { return; };
+    let show = players.hot_seat() && *phase.get() == AppState::Playing;
+    *vis = if show { Visibility::Inherited } else { Visibility::Hidden };
+    if !show || !(players.is_changed() || phase.is_changed() || loc.is_changed()) {
+        return;
+    }
+    let active = &players.players[players.active];
+    text.sections[0].value = loc.tf(
+        "hud.turn",
+        &[
+            ("player", (players.active + 1).to_string()),
+            ("strokes", active.strokes.to_string()),
+            ("holes", active.holes.to_string()),
+        ],
+    );
+    text.sections[0].style.color = player_color(players.active);
+}
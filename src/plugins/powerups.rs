@@ -0,0 +1,402 @@
+// Powerup pickups.
+// Levels place them with `powerups` in their RON file (`(kind: Magnet, x: 60.0, z: 50.0)`); a
+// level without any gets a few scattered (SimRng) on dry ground along each hole, tee to target.
+// The ball collects one by rolling (or flying low) through it into a small inventory, shown above
+// the minimap. U arms the next one for the coming shot (press again to arm the one after, then
+// none); the armed powerup is spent when the shot is fired:
+//   Extra power  - the shot launches harder (`ShotConfig::power_boost`, the aim preview follows).
+//   Sticky       - the first landing stops the ball dead (`BallModifiers::sticky`, ball_physics).
+//   Bullet time  - the world slows down while the shot is charged (virtual time), so the release
+//                  can be timed against the power gauge and moving targets.
+//   Magnet       - in flight and rolling the ball is pulled towards a standard target close by
+//                  (`BallModifiers::pull`) until it comes to rest.
+// Restarting the round or switching levels empties the inventory and lays the pickups out again.
+
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::plugins::app_state::{gameplay_running, AppState};
+use crate::plugins::ball::{Ball, BallKinematic, BallModifiers, WATER_LEVEL};
+use crate::plugins::camera::CameraMode;
+use crate::plugins::core_sim::{GameSet, SimRng};
+use crate::plugins::event_log::{EventLog, LogKind};
+use crate::plugins::game_state::{RestartGameEvent, Score, ShotConfig, ShotMode, ShotState};
+use crate::plugins::hud_layout::HudAnchor;
+use crate::plugins::level::LevelDef;
+use crate::plugins::localization::Localization;
+use crate::plugins::particles::ShotFiredEvent;
+use crate::plugins::target::{Target, TargetKind};
+use crate::plugins::terrain::TerrainSampler;
+use crate::plugins::ui_theme::HighContrastText;
+use crate::plugins::ui_toast::{ToastKind, ToastQueue};
+
+pub const USE_POWERUP_KEY: KeyCode = KeyCode::KeyU;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum PowerupKind {
+    ExtraPower,
+    Sticky,
+    BulletTime,
+    Magnet,
+}
+
+impl PowerupKind {
+    pub const ALL: [PowerupKind; 4] = [Self::ExtraPower, Self::Sticky, Self::BulletTime, Self::Magnet];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::ExtraPower => "powerup.extra_power",
+            Self::Sticky => "powerup.sticky",
+            Self::BulletTime => "powerup.bullet_time",
+            Self::Magnet => "powerup.magnet",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Self::ExtraPower => Color::srgb(1.0, 0.35, 0.2),
+            Self::Sticky => Color::srgb(0.4, 0.9, 0.3),
+            Self::BulletTime => Color::srgb(0.55, 0.45, 1.0),
+            Self::Magnet => Color::srgb(0.25, 0.7, 1.0),
+        }
+    }
+}
+
+/// Level-placed pickup (RON).
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct PowerupDef {
+    pub kind: PowerupKind,
+    pub x: f32,
+    pub z: f32,
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct PowerupConfig {
+    pub enabled: bool,
+    pub capacity: usize,          // inventory slots
+    pub scatter_per_hole: usize,  // pickups scattered per hole on levels that place none
+    pub scatter_spread: f32,      // m either side of the tee-target line
+    pub pickup_radius: f32,       // m
+    pub hover: f32,               // m above the ground
+    pub power_boost: f32,         // launch speed multiplier of Extra power
+    pub bullet_time: f32,         // world speed while a Bullet time shot is charged
+    pub magnet_radius: f32,       // m; targets further off don't pull
+    pub magnet_accel: f32,        // m/s²
+}
+impl Default for PowerupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            capacity: 3,
+            scatter_per_hole: 3,
+            scatter_spread: 35.0,
+            pickup_radius: 2.5,
+            hover: 1.2,
+            power_boost: 1.4,
+            bullet_time: 0.35,
+            magnet_radius: 35.0,
+            magnet_accel: 7.0,
+        }
+    }
+}
+
+/// Spots `count` pickups may go on along the way from `from` to `to` (x / z): evenly spread over
+/// the middle of the line, each pushed up to `spread` m to one side.
+pub fn scatter_spots(from: Vec2, to: Vec2, count: usize, spread: f32, rng: &mut impl Rng) -> Vec<Vec2> {
+    let along = to - from;
+    let side = along.perp().normalize_or_zero();
+    (0..count)
+        .map(|i| {
+            let t = 0.2 + 0.6 * (i as f32 + rng.gen_range(0.25..0.75)) / count as f32;
+            from + along * t + side * rng.gen_range(-spread..=spread)
+        })
+        .collect()
+}
+
+/// Collected powerups and the one armed for the next shot.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct PowerupInventory {
+    pub items: Vec<PowerupKind>,
+    pub armed: Option<usize>,        // index into `items`
+    pub active: Option<PowerupKind>, // spent on the shot in play
+}
+
+impl PowerupInventory {
+    /// Stow a pickup; false when every slot is taken.
+    pub fn add(&mut self, kind: PowerupKind, capacity: usize) -> bool {
+        if self.items.len() >= capacity {
+            return false;
+        }
+        self.items.push(kind);
+        true
+    }
+
+    /// Arm the next item (none armed -> first -> ... -> last -> none).
+    pub fn cycle_armed(&mut self) {
+        self.armed = match self.armed {
+            None if !self.items.is_empty() => Some(0),
+            Some(i) if i + 1 < self.items.len() => Some(i + 1),
+            _ => None,
+        };
+    }
+
+    pub fn armed_kind(&self) -> Option<PowerupKind> {
+        self.armed.and_then(|i| self.items.get(i).copied())
+    }
+
+    /// A shot was fired: the armed item leaves the inventory and becomes the active one.
+    pub fn spend_armed(&mut self) -> Option<PowerupKind> {
+        let kind = self.armed_kind();
+        if let Some(i) = self.armed.take().filter(|i| *i < self.items.len()) {
+            self.items.remove(i);
+        }
+        self.active = kind;
+        kind
+    }
+}
+
+#[derive(Component)]
+struct Pickup {
+    kind: PowerupKind,
+    spin: f32, // phase
+}
+
+#[derive(Component)]
+struct PowerupHud;
+
+// Whether the pickups of the current level / round are out.
+#[derive(Resource, Default)]
+struct PickupsPlaced(bool);
+
+pub struct PowerupsPlugin;
+impl Plugin for PowerupsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PowerupConfig>()
+            .init_resource::<PowerupInventory>()
+            .init_resource::<PickupsPlaced>()
+            .add_systems(Startup, spawn_powerup_hud)
+            .add_systems(Update, arm_powerup.in_set(GameSet::Input).run_if(gameplay_running))
+            .add_systems(
+                Update,
+                (
+                    place_pickups,
+                    (collect_pickups, spend_powerup, magnet_pull).run_if(gameplay_running),
+                    (bullet_time, animate_pickups, update_powerup_hud),
+                )
+                    .chain()
+                    .in_set(GameSet::React),
+            );
+    }
+}
+
+fn spawn_powerup_hud(mut commands: Commands, assets: Res<AssetServer>) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle { font: assets.load("fonts/FiraSans-Bold.ttf"), font_size: 18.0, color: Color::WHITE },
+        )
+        .with_style(Style { position_type: PositionType::Absolute, left: Val::Px(12.0), bottom: Val::Px(204.0), ..default() }),
+        HudAnchor::bottom_left(12.0, 204.0),
+        PowerupHud,
+        HighContrastText,
+    ));
+}
+
+fn place_pickups(
+    mut commands: Commands,
+    (cfg, level, sampler): (Res<PowerupConfig>, Option<Res<LevelDef>>, Res<TerrainSampler>),
+    mut rng: ResMut<SimRng>,
+    mut placed: ResMut<PickupsPlaced>,
+    (mut inventory, mut modifiers): (ResMut<PowerupInventory>, ResMut<BallModifiers>),
+    (mut meshes, mut materials): (ResMut<Assets<Mesh>>, ResMut<Assets<StandardMaterial>>),
+    mut ev_restart: EventReader<RestartGameEvent>,
+    q_pickups: Query<Entity, With<Pickup>>,
+) {
+    let Some(level) = level else { return; };
+    if ev_restart.read().count() > 0 || level.is_changed() {
+        for e in &q_pickups {
+            commands.entity(e).despawn_recursive();
+        }
+        *inventory = PowerupInventory::default();
+        *modifiers = BallModifiers::default();
+        placed.0 = false;
+    }
+    if placed.0 || !cfg.enabled || sampler.is_placeholder() {
+        return;
+    }
+    placed.0 = true;
+    let spots: Vec<(PowerupKind, Vec2)> = if level.powerups.is_empty() {
+        let legs: Vec<(Vec2, Vec2)> = if level.is_course() {
+            level.holes.iter().map(|h| (Vec2::new(h.tee.x, h.tee.z), Vec2::new(h.target.x, h.target.z))).collect()
+        } else {
+            vec![(level.tee(0), Vec2::new(level.target.initial.x, level.target.initial.z))]
+        };
+        let spots: Vec<Vec2> = legs
+            .into_iter()
+            .flat_map(|(from, to)| scatter_spots(from, to, cfg.scatter_per_hole, cfg.scatter_spread, &mut rng.0))
+            .filter(|p| sampler.height(p.x, p.y) > WATER_LEVEL + 1.0)
+            .collect();
+        spots.into_iter().map(|p| (PowerupKind::ALL[rng.0.gen_range(0..PowerupKind::ALL.len())], p)).collect()
+    } else {
+        level.powerups.iter().map(|d| (d.kind, Vec2::new(d.x, d.z))).collect()
+    };
+    info!("POWERUPS placed={}", spots.len());
+    let mesh = meshes.add(Cuboid::new(1.0, 1.0, 1.0));
+    for (kind, p) in spots {
+        let color = kind.color();
+        commands.spawn((
+            PbrBundle {
+                mesh: mesh.clone(),
+                material: materials.add(StandardMaterial {
+                    base_color: color,
+                    emissive: LinearRgba::from(color) * 2.0,
+                    ..default()
+                }),
+                transform: Transform::from_xyz(p.x, sampler.height(p.x, p.y) + cfg.hover, p.y)
+                    .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_4)),
+                ..default()
+            },
+            Pickup { kind, spin: rng.0.gen_range(0.0..std::f32::consts::TAU) },
+        ));
+    }
+}
+
+fn collect_pickups(
+    mut commands: Commands,
+    (cfg, loc): (Res<PowerupConfig>, Res<Localization>),
+    mut inventory: ResMut<PowerupInventory>,
+    mut toasts: ResMut<ToastQueue>,
+    mut log: Option<ResMut<EventLog>>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+    q_pickups: Query<(Entity, &Transform, &Pickup), Without<Ball>>,
+) {
+    let Ok((ball, kin)) = q_ball.get_single() else { return; };
+    let reach = cfg.pickup_radius + kin.collider_radius;
+    for (e, t, pickup) in &q_pickups {
+        if t.translation.distance(ball.translation) > reach || !inventory.add(pickup.kind, cfg.capacity) {
+            continue;
+        }
+        commands.entity(e).despawn_recursive();
+        let name = loc.t(pickup.kind.key()).to_string();
+        info!("POWERUP picked={:?}", pickup.kind);
+        toasts.push_for(loc.tf("powerup.picked", &[("name", name.clone())]), ToastKind::Success, 2.5);
+        if let Some(log) = log.as_mut() {
+            log.push(LogKind::Info, loc.tf("log.powerup", &[("name", name)]));
+        }
+    }
+}
+
+fn arm_powerup(
+    keys: Res<ButtonInput<KeyCode>>,
+    (cfg, loc, score): (Res<PowerupConfig>, Res<Localization>, Res<Score>),
+    mode: Res<CameraMode>,
+    mut inventory: ResMut<PowerupInventory>,
+    mut shot_cfg: ResMut<ShotConfig>,
+    mut toasts: ResMut<ToastQueue>,
+) {
+    if keys.just_pressed(USE_POWERUP_KEY) && *mode == CameraMode::Orbit && !score.game_over {
+        inventory.cycle_armed();
+        let text = match inventory.armed_kind() {
+            Some(kind) => loc.tf("powerup.armed", &[("name", loc.t(kind.key()).to_string())]),
+            None if inventory.items.is_empty() => loc.t("powerup.none").to_string(),
+            None => loc.t("powerup.disarmed").to_string(),
+        };
+        toasts.push_for(text, ToastKind::Info, 2.0);
+    }
+    // Extra power goes into the launch (and its preview) while armed.
+    let boost = if inventory.armed_kind() == Some(PowerupKind::ExtraPower) { cfg.power_boost } else { 1.0 };
+    if shot_cfg.power_boost != boost {
+        shot_cfg.power_boost = boost;
+    }
+}
+
+fn spend_powerup(
+    mut inventory: ResMut<PowerupInventory>,
+    (mut modifiers, mut shot_cfg): (ResMut<BallModifiers>, ResMut<ShotConfig>),
+    mut ev_shot: EventReader<ShotFiredEvent>,
+    q_ball: Query<&BallKinematic, With<Ball>>,
+) {
+    if ev_shot.read().count() > 0 {
+        let kind = inventory.spend_armed();
+        if let Some(kind) = kind {
+            info!("POWERUP used={:?}", kind);
+        }
+        *modifiers = BallModifiers { sticky: kind == Some(PowerupKind::Sticky), pull: Vec3::ZERO };
+        shot_cfg.power_boost = 1.0;
+        return;
+    }
+    // The shot is over once the ball is at rest.
+    if inventory.active.is_some() && q_ball.get_single().is_ok_and(|k| k.vel.length_squared() < 1e-4) {
+        inventory.active = None;
+        *modifiers = BallModifiers::default();
+    }
+}
+
+fn magnet_pull(
+    cfg: Res<PowerupConfig>,
+    inventory: Res<PowerupInventory>,
+    mut modifiers: ResMut<BallModifiers>,
+    q_ball: Query<&Transform, With<Ball>>,
+    q_targets: Query<(&Transform, Option<&TargetKind>), (With<Target>, Without<Ball>)>,
+) {
+    let pull = match (inventory.active, q_ball.get_single()) {
+        (Some(PowerupKind::Magnet), Ok(ball)) => q_targets
+            .iter()
+            .filter(|(_, kind)| kind.is_none_or(|k| *k == TargetKind::Standard))
+            .map(|(t, _)| t.translation - ball.translation)
+            .filter(|d| d.length() <= cfg.magnet_radius)
+            .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+            .map_or(Vec3::ZERO, |d| d.normalize_or_zero() * cfg.magnet_accel),
+        _ => Vec3::ZERO,
+    };
+    if modifiers.pull != pull {
+        modifiers.pull = pull;
+    }
+}
+
+fn bullet_time(
+    cfg: Res<PowerupConfig>,
+    phase: Option<Res<State<AppState>>>,
+    (inventory, shot): (Res<PowerupInventory>, Res<ShotState>),
+    mut time: ResMut<Time<Virtual>>,
+) {
+    let slow = phase.is_some_and(|p| *p.get() == AppState::Playing)
+        && shot.mode == ShotMode::Charging
+        && inventory.armed_kind() == Some(PowerupKind::BulletTime);
+    let speed = if slow { cfg.bullet_time } else { 1.0 };
+    if time.relative_speed() != speed {
+        time.set_relative_speed(speed);
+    }
+}
+
+fn animate_pickups(time: Res<Time>, mut q: Query<(&mut Transform, &mut Pickup)>) {
+    let dt = time.delta_seconds();
+    for (mut t, mut p) in &mut q {
+        p.spin += dt;
+        t.rotation = Quat::from_rotation_y(p.spin * 1.5) * Quat::from_rotation_x(std::f32::consts::FRAC_PI_4);
+    }
+}
+
+fn update_powerup_hud(
+    (loc, inventory): (Res<Localization>, Res<PowerupInventory>),
+    phase: Option<Res<State<AppState>>>,
+    mut q_text: Query<&mut Text, With<PowerupHud>>,
+) {
+    let Ok(mut text) = q_text.get_single_mut() else { return; };
+    let shown = if inventory.items.is_empty() || !phase.is_some_and(|p| p.get().round_in_view()) {
+        String::new()
+    } else {
+        let items = inventory
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, k)| if inventory.armed == Some(i) { format!("[{}]", loc.t(k.key())) } else { loc.t(k.key()).to_string() })
+            .collect::<Vec<_>>()
+            .join("  ");
+        loc.tf("hud.powerups", &[("items", items)])
+    };
+    if text.sections[0].value != shown {
+        text.sections[0].value = shown;
+    }
+}
@@ -0,0 +1,189 @@
+// Player profile (profile.json).
+// One versioned JSON document holds everything that outlives a round: the best record per level
+// and game mode (keys from game_mode.rs), lifetime statistics (shots, holes, finished rounds,
+// holes in one, longest drive), unlocked achievements (achievements.rs) and the player settings
+// (user_settings.rs). It lives in the data directory (storage.rs; localStorage on the web). When
+// no profile exists yet, the legacy high_score_time.txt and settings.ron are read once (from the
+// data directory or, where older builds wrote them, the working directory) and carried over.
+// Changes are written back at the end of the frame they happen in.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::plugins::game_state::DEFAULT_LEVEL_ID;
+use crate::plugins::particles::{BallGroundImpactEvent, GameOverEvent, HoleInOneEvent, ShotFiredEvent, TargetHitEvent};
+use crate::plugins::storage::StoragePaths;
+use crate::plugins::target::TargetKind;
+use crate::plugins::user_settings::UserSettings;
+
+pub const PROFILE_FILE: &str = "profile.json";
+/// Written into every saved profile; bump when a field changes meaning (new fields just default).
+pub const PROFILE_VERSION: u32 = 1;
+const LEGACY_HIGH_SCORES: &str = "high_score_time.txt";
+const LEGACY_SETTINGS: &str = "settings.ron";
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LifetimeStats {
+    pub shots: u64,
+    pub holes: u32, // standard targets hit
+    pub rounds_finished: u32,
+    pub holes_in_one: u32,
+    pub longest_drive: f32, // m, shot spot to first bounce (xz)
+}
+
+impl LifetimeStats {
+    pub fn record_drive(&mut self, from: Vec3, to: Vec3) {
+        self.longest_drive = self.longest_drive.max(from.xz().distance(to.xz()));
+    }
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub version: u32,
+    pub best: BTreeMap<String, f32>, // record key -> best record (lower is better)
+    pub stats: LifetimeStats,
+    pub achievements: BTreeSet<String>, // unlocked achievement ids
+    pub settings: UserSettings,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            version: PROFILE_VERSION,
+            best: BTreeMap::new(),
+            stats: LifetimeStats::default(),
+            achievements: BTreeSet::new(),
+            settings: UserSettings::default(),
+        }
+    }
+}
+
+impl Profile {
+    /// Parse a stored profile; older versions load with their missing fields defaulted.
+    pub fn from_json(data: &str) -> Result<Self, serde_json::Error> {
+        let mut profile = serde_json::from_str::<Self>(data)?;
+        profile.version = PROFILE_VERSION;
+        Ok(profile)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Profile carried over from the pre-profile files (either may be missing or unreadable).
+    pub fn from_legacy(high_scores: Option<&str>, settings: Option<&str>) -> Self {
+        Self {
+            best: high_scores.map(parse_high_scores).unwrap_or_default(),
+            settings: settings.and_then(|s| UserSettings::from_ron(s).ok()).unwrap_or_default(),
+            ..default()
+        }
+    }
+
+    pub fn best(&self, key: &str) -> Option<f32> {
+        self.best.get(key).copied()
+    }
+
+    /// Keep `value` as the record for `key` if it beats the stored one; true when it did.
+    pub fn record_best(&mut self, key: &str, value: f32) -> bool {
+        let better = self.best(key).is_none_or(|best| value < best);
+        if better {
+            self.best.insert(key.to_string(), value);
+        }
+        better
+    }
+
+    pub fn is_unlocked(&self, achievement_id: &str) -> bool {
+        self.achievements.contains(achievement_id)
+    }
+
+    /// True when `achievement_id` was not unlocked before.
+    pub fn unlock(&mut self, achievement_id: &str) -> bool {
+        self.achievements.insert(achievement_id.to_string())
+    }
+}
+
+/// Legacy high_score_time.txt: one "<id> <seconds>" line per level. A bare number (the older
+/// single-level format) counts for `DEFAULT_LEVEL_ID`.
+pub fn parse_high_scores(data: &str) -> BTreeMap<String, f32> {
+    let mut times = BTreeMap::new();
+    for line in data.lines() {
+        let mut parts = line.split_whitespace();
+        let (id, value) = match (parts.next(), parts.next()) {
+            (Some(v), None) => (DEFAULT_LEVEL_ID, v),
+            (Some(id), Some(v)) => (id, v),
+            _ => continue,
+        };
+        if let Ok(t) = value.parse::<f32>() {
+            times.insert(id.to_string(), t);
+        }
+    }
+    times
+}
+
+pub fn load_profile(paths: &StoragePaths) -> Profile {
+    if let Some(data) = paths.load_text(PROFILE_FILE) {
+        return Profile::from_json(&data).unwrap_or_else(|e| {
+            warn!("Ignoring unreadable {PROFILE_FILE}: {e}");
+            Profile::default()
+        });
+    }
+    let legacy = |name: &str| paths.load_text(name).or_else(|| StoragePaths::working_dir().load_text(name));
+    let (scores, settings) = (legacy(LEGACY_HIGH_SCORES), legacy(LEGACY_SETTINGS));
+    if scores.is_none() && settings.is_none() {
+        return Profile::default();
+    }
+    let profile = Profile::from_legacy(scores.as_deref(), settings.as_deref());
+    paths.save_text(PROFILE_FILE, &profile.to_json());
+    info!("Migrated {LEGACY_HIGH_SCORES} / {LEGACY_SETTINGS} into {PROFILE_FILE}");
+    profile
+}
+
+pub struct ProfilePlugin;
+impl Plugin for ProfilePlugin {
+    fn build(&self, app: &mut App) {
+        let profile = load_profile(&StoragePaths::in_world(app.world()));
+        app.insert_resource(profile.settings.clone())
+            .insert_resource(profile)
+            .add_systems(Update, record_lifetime_stats)
+            .add_systems(Last, (sync_settings, persist_profile).chain());
+    }
+}
+
+pub(crate) fn record_lifetime_stats(
+    mut profile: ResMut<Profile>,
+    mut ev_shot: EventReader<ShotFiredEvent>,
+    mut ev_impact: EventReader<BallGroundImpactEvent>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+    mut ev_hole_in_one: EventReader<HoleInOneEvent>,
+    mut ev_game_over: EventReader<GameOverEvent>,
+    mut drive_from: Local<Option<Vec3>>, // spot of the last shot until its first bounce
+) {
+    for e in ev_shot.read() {
+        profile.stats.shots += 1;
+        *drive_from = Some(e.pos);
+    }
+    let first_impact = ev_impact.read().fold(None, |first, e| first.or(Some(e.pos)));
+    if let (Some(to), Some(from)) = (first_impact, *drive_from) {
+        profile.stats.record_drive(from, to);
+        *drive_from = None;
+    }
+    profile.stats.holes += ev_hit.read().filter(|e| e.kind == TargetKind::Standard).count() as u32;
+    profile.stats.holes_in_one += ev_hole_in_one.read().count() as u32;
+    profile.stats.rounds_finished += ev_game_over.read().count() as u32;
+}
+
+fn sync_settings(settings: Res<UserSettings>, mut profile: ResMut<Profile>) {
+    if settings.is_changed() && !settings.is_added() {
+        profile.settings = settings.clone();
+    }
+}
+
+fn persist_profile(profile: Res<Profile>, paths: Res<StoragePaths>) {
+    if profile.is_changed() && !profile.is_added() {
+        paths.save_text(PROFILE_FILE, &profile.to_json());
+    }
+}
@@ -0,0 +1,622 @@
+// Round replays.
+// While a round is played the recorder samples the ball, the camera and the level's targets
+// (so target motion and relocations come along) ten times per second of simulation, and notes
+// every shot and finished hole. When the round ends (finished or lost) the recording becomes the
+// last replay, kept as last_replay.ron in the data directory (see storage.rs). The main menu's
+// "Watch Replay" button loads the replay's level and plays the recording back in
+// `AppState::Replay` (gameplay frozen, the replay drives ball, targets and camera) with pause,
+// 2x speed and skip to previous / next hole; Escape or Exit goes back to the menu.
+// Export writes the replay to replays/ in the data directory for sharing; dropping such a file
+// onto the menu window imports it as the last replay. Exports come with a ghost track for racing
+// (ghost_race.rs).
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::camera::OrbitCamera;
+use crate::plugins::core_sim::SimState;
+use crate::plugins::event_log::format_timestamp;
+use crate::plugins::game_mode::GameMode;
+use crate::plugins::game_state::{RestartGameEvent, Score};
+use crate::plugins::ghost_race::{GhostTrack, GHOST_EXTENSION};
+use crate::plugins::level::LevelManifest;
+use crate::plugins::localization::{Localization, LocalizedText};
+use crate::plugins::app_state::AppState;
+use crate::plugins::main_menu::MenuRoot;
+use crate::plugins::particles::{ShotFiredEvent, TargetHitEvent};
+use crate::plugins::save_game::RoundResumedEvent;
+use crate::plugins::storage::StoragePaths;
+use crate::plugins::target::{Target, TargetInfo, TargetKind};
+use crate::plugins::terrain::{TerrainConfig, TerrainSampler};
+use crate::plugins::ui_toast::{ToastKind, ToastQueue};
+
+pub const REPLAY_VERSION: u32 = 1;
+pub const SAMPLE_TICKS: u64 = 6; // sim ticks between frames (10 per second)
+/// Moves longer than this between two frames are cuts (relocation, reset to tee), not motion.
+pub const SNAP_DISTANCE: f32 = 25.0;
+const LAST_REPLAY_FILE: &str = "last_replay.ron";
+const EXPORT_DIR: &str = "replays";
+const SPEEDS: [f32; 2] = [1.0, 2.0];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayFrame {
+    pub tick: u64,
+    pub ball: (f32, f32, f32),
+    pub camera_pos: (f32, f32, f32),
+    pub camera_rot: (f32, f32, f32, f32),
+    pub targets: Vec<(f32, f32, f32)>, // level targets in TargetInfo::index order
+}
+
+impl ReplayFrame {
+    pub fn camera_rotation(&self) -> Quat {
+        let (x, y, z, w) = self.camera_rot;
+        Quat::from_xyzw(x, y, z, w)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayShot {
+    pub tick: u64,
+    pub pos: (f32, f32, f32),
+    pub power: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    pub version: u32,
+    pub level: String,    // manifest id
+    pub score_id: String, // best-time key (the day, for the Daily Run)
+    #[serde(default)]
+    pub mode: GameMode,
+    pub holes: u32, // holes in the round
+    pub final_time: f32,
+    pub failed: bool,
+    pub shots: Vec<ReplayShot>,
+    pub hole_ticks: Vec<u64>, // tick each hole was finished
+    pub frames: Vec<ReplayFrame>,
+}
+
+/// Interpolated state at one point of a replay.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplaySample {
+    pub ball: Vec3,
+    pub camera: Transform,
+    pub targets: Vec<Vec3>,
+}
+
+fn blend(a: Vec3, b: Vec3, s: f32) -> Vec3 {
+    if a.distance(b) > SNAP_DISTANCE { a } else { a.lerp(b, s) }
+}
+
+impl Replay {
+    pub fn new(level: &str, score_id: &str, mode: GameMode, holes: u32) -> Self {
+        Self {
+            version: REPLAY_VERSION,
+            level: level.to_string(),
+            score_id: score_id.to_string(),
+            mode,
+            holes,
+            final_time: 0.0,
+            failed: false,
+            shots: Vec::new(),
+            hole_ticks: Vec::new(),
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn from_ron(data: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str::<Self>(data)
+    }
+
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()).unwrap_or_default()
+    }
+
+    /// Manifest index of the replay's level, if it can be shown (level still listed; same day for
+    /// the Daily Run, whose layout changes daily).
+    pub fn level_index(&self, manifest: &LevelManifest) -> Option<usize> {
+        let index = manifest.index_of(&self.level)?;
+        (manifest.levels[index].score_id() == self.score_id && !self.frames.is_empty()).then_some(index)
+    }
+
+    fn start_tick(&self) -> u64 {
+        self.frames.first().map_or(0, |f| f.tick)
+    }
+
+    fn tick_at(&self, t: f32) -> f32 {
+        self.start_tick() as f32 + t.max(0.0) * 60.0
+    }
+
+    fn time_of(&self, tick: u64) -> f32 {
+        tick.saturating_sub(self.start_tick()) as f32 / 60.0
+    }
+
+    /// Seconds from the first to the last frame.
+    pub fn duration(&self) -> f32 {
+        self.frames.last().map_or(0.0, |f| self.time_of(f.tick))
+    }
+
+    /// State `t` seconds into the replay (between frames: interpolated, except across cuts).
+    pub fn sample(&self, t: f32) -> Option<ReplaySample> {
+        let tick = self.tick_at(t);
+        let next = self.frames.partition_point(|f| f.tick as f32 <= tick);
+        let a = self.frames.get(next.saturating_sub(1))?;
+        let b = self.frames.get(next).unwrap_or(a);
+        let s = if b.tick > a.tick { ((tick - a.tick as f32) / (b.tick - a.tick) as f32).clamp(0.0, 1.0) } else { 0.0 };
+        let camera = Transform::from_translation(blend(a.camera_pos.into(), b.camera_pos.into(), s))
+            .with_rotation(a.camera_rotation().slerp(b.camera_rotation(), s));
+        let targets = a
+            .targets
+            .iter()
+            .enumerate()
+            .map(|(i, p)| blend((*p).into(), b.targets.get(i).copied().unwrap_or(*p).into(), s))
+            .collect();
+        Some(ReplaySample { ball: blend(a.ball.into(), b.ball.into(), s), camera, targets })
+    }
+
+    /// Hole being played `t` seconds in (1-based, at most `holes`).
+    pub fn hole_at(&self, t: f32) -> u32 {
+        let tick = self.tick_at(t);
+        let done = self.hole_ticks.iter().filter(|h| **h as f32 <= tick).count() as u32;
+        (done + 1).min(self.holes.max(1))
+    }
+
+    /// Shots taken up to `t` seconds in.
+    pub fn shots_at(&self, t: f32) -> usize {
+        let tick = self.tick_at(t);
+        self.shots.iter().filter(|s| s.tick as f32 <= tick).count()
+    }
+
+    /// Start of the hole after the one played at `t` (None on the last hole).
+    pub fn next_hole_time(&self, t: f32) -> Option<f32> {
+        let tick = self.tick_at(t);
+        self.hole_ticks.iter().find(|h| **h as f32 > tick).map(|h| self.time_of(*h))
+    }
+
+    /// Start of the hole played at `t`, or of the one before when `t` is within a second of it.
+    pub fn previous_hole_time(&self, t: f32) -> f32 {
+        let tick = self.tick_at(t - 1.0);
+        self.hole_ticks.iter().rev().find(|h| **h as f32 <= tick).map_or(0.0, |h| self.time_of(*h))
+    }
+}
+
+/// The round being recorded (None outside play).
+#[derive(Resource, Debug, Default)]
+pub struct ReplayRecorder {
+    pub recording: Option<Replay>,
+}
+
+/// The most recent finished round, or an imported replay.
+#[derive(Resource, Debug)]
+pub struct LastReplay {
+    pub replay: Option<Replay>,
+    paths: StoragePaths,
+}
+
+impl FromWorld for LastReplay {
+    fn from_world(world: &mut World) -> Self {
+        let paths = StoragePaths::in_world(world);
+        let replay = paths.load_text(LAST_REPLAY_FILE).and_then(|data| {
+            Replay::from_ron(&data)
+                .map_err(|e| warn!("Ignoring unreadable {LAST_REPLAY_FILE}: {e}"))
+                .ok()
+        });
+        Self { replay, paths }
+    }
+}
+
+impl LastReplay {
+    pub fn store(&mut self, replay: Replay) {
+        self.paths.save_text(LAST_REPLAY_FILE, &replay.to_ron());
+        self.replay = Some(replay);
+    }
+
+    /// Write the replay to a new file in replays/, with its ghost track (ghost_race.rs) next to it;
+    /// the replay file name written.
+    pub fn export(&self) -> Option<String> {
+        let replay = self.replay.as_ref()?;
+        let id: String = replay.score_id.chars().map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' }).collect();
+        let name = (1..)
+            .map(|n| format!("{EXPORT_DIR}/{id}_{n}.ron"))
+            .find(|name| self.paths.load_text(name).is_none())?;
+        self.paths.save_text(&name, &replay.to_ron());
+        let ghost = name.trim_end_matches(".ron").to_string() + GHOST_EXTENSION;
+        self.paths.save_text(&ghost, &GhostTrack::from_replay(replay, "").to_ron());
+        Some(self.paths.file(&name).display().to_string())
+    }
+}
+
+/// Replay being watched (present only in `AppState::Replay`).
+#[derive(Resource, Debug)]
+pub struct ReplayPlayback {
+    pub replay: Replay,
+    pub time: f32, // s into the replay
+    pub speed: f32,
+    pub paused: bool,
+    settled: u32, // frames the replay's level has been ready for
+}
+
+impl ReplayPlayback {
+    pub fn new(replay: Replay) -> Self {
+        Self { replay, time: 0.0, speed: SPEEDS[0], paused: false, settled: 0 }
+    }
+
+    pub fn at_end(&self) -> bool {
+        self.time >= self.replay.duration()
+    }
+}
+
+/// Menu button starting the last replay (spawned by main_menu.rs, shown while one is playable).
+#[derive(Component)]
+pub struct WatchReplayButton;
+
+#[derive(Component)]
+struct ReplayOverlay;
+#[derive(Component)]
+struct ReplayStatusText;
+#[derive(Component, Clone, Copy)]
+enum ReplayControl {
+    Pause,
+    Speed,
+    PreviousHole,
+    NextHole,
+    Export,
+    Exit,
+}
+
+pub struct ReplayPlugin;
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReplayRecorder>()
+            .init_resource::<LastReplay>()
+            .add_systems(Startup, spawn_replay_overlay)
+            .add_systems(Update, (update_watch_button, watch_replay_button, import_dropped_replay).chain())
+            .add_systems(Update, (replay_controls, advance_replay, update_replay_overlay).chain())
+            .add_systems(PostUpdate, record_replay);
+    }
+}
+
+fn record_replay(
+    phase: Option<Res<State<AppState>>>,
+    sim: Res<SimState>,
+    score: Res<Score>,
+    manifest: Option<Res<LevelManifest>>,
+    mut recorder: ResMut<ReplayRecorder>,
+    mut last: ResMut<LastReplay>,
+    mut ev_restart: EventReader<RestartGameEvent>,
+    mut ev_resumed: EventReader<RoundResumedEvent>,
+    mut ev_shot: EventReader<ShotFiredEvent>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+    q_ball: Query<&Transform, With<Ball>>,
+    q_cam: Query<&Transform, With<OrbitCamera>>,
+    q_targets: Query<(&Transform, &TargetInfo), With<Target>>,
+) {
+    // A restart or a resumed save starts a new recording.
+    if ev_restart.read().count() + ev_resumed.read().count() > 0 {
+        recorder.recording = None;
+    }
+    let shots: Vec<ReplayShot> =
+        ev_shot.read().map(|e| ReplayShot { tick: sim.tick, pos: e.pos.into(), power: e.power }).collect();
+    let holes_done = ev_hit.read().filter(|e| e.kind == TargetKind::Standard).count();
+    if phase.map(|p| *p.get()) != Some(AppState::Playing) {
+        return;
+    }
+    if recorder.recording.is_none() && !score.game_over {
+        let Some(entry) = manifest.as_ref().and_then(|m| m.levels.get(m.loaded)) else { return; };
+        recorder.recording = Some(Replay::new(&entry.id, &entry.score_id(), score.mode, score.max_holes));
+    }
+    let Some(replay) = recorder.recording.as_mut() else { return; };
+    replay.shots.extend(shots);
+    replay.hole_ticks.extend(std::iter::repeat_n(sim.tick, holes_done));
+    let due = replay.frames.last().is_none_or(|f| sim.tick >= f.tick + SAMPLE_TICKS);
+    if due || score.game_over {
+        if let (Ok(ball), Ok(cam)) = (q_ball.get_single(), q_cam.get_single()) {
+            let mut targets: Vec<_> = q_targets.iter().filter(|(_, info)| info.index != usize::MAX).collect();
+            targets.sort_by_key(|(_, info)| info.index);
+            let r = cam.rotation;
+            replay.frames.push(ReplayFrame {
+                tick: sim.tick,
+                ball: ball.translation.into(),
+                camera_pos: cam.translation.into(),
+                camera_rot: (r.x, r.y, r.z, r.w),
+                targets: targets.iter().map(|(t, _)| t.translation.into()).collect(),
+            });
+        }
+    }
+    if score.game_over {
+        let Some(mut replay) = recorder.recording.take() else { return; };
+        replay.final_time = score.final_time;
+        replay.failed = score.failed;
+        info!("Replay recorded: {} frames, {} shots", replay.frames.len(), replay.shots.len());
+        last.store(replay);
+    }
+}
+
+fn update_watch_button(
+    last: Res<LastReplay>,
+    manifest: Option<Res<LevelManifest>>,
+    mut q_button: Query<&mut Style, With<WatchReplayButton>>,
+) {
+    let playable = match (last.replay.as_ref(), manifest) {
+        (Some(replay), Some(manifest)) => replay.level_index(&manifest).is_some(),
+        _ => false,
+    };
+    let display = if playable { Display::Flex } else { Display::None };
+    for mut style in &mut q_button {
+        if style.display != display {
+            style.display = display;
+        }
+    }
+}
+
+fn watch_replay_button(
+    mut commands: Commands,
+    phase: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    last: Res<LastReplay>,
+    manifest: Option<ResMut<LevelManifest>>,
+    q_button: Query<&Interaction, (Changed<Interaction>, With<WatchReplayButton>)>,
+    q_root: Query<Entity, With<MenuRoot>>,
+) {
+    if *phase.get() != AppState::Menu || !q_button.iter().any(|i| *i == Interaction::Pressed) {
+        return;
+    }
+    let (Some(mut manifest), Some(replay)) = (manifest, last.replay.clone()) else { return; };
+    let Some(index) = replay.level_index(&manifest) else { return; };
+    // The level is switched in like Play does; advance_replay waits for it.
+    manifest.selected = index;
+    commands.insert_resource(ReplayPlayback::new(replay));
+    next_state.set(AppState::Replay);
+    if let Ok(root) = q_root.get_single() {
+        commands.entity(root).despawn_recursive();
+    }
+}
+
+fn import_dropped_replay(
+    phase: Res<State<AppState>>,
+    loc: Res<Localization>,
+    manifest: Option<Res<LevelManifest>>,
+    mut last: ResMut<LastReplay>,
+    mut toasts: ResMut<ToastQueue>,
+    mut ev_drop: EventReader<FileDragAndDrop>,
+) {
+    for ev in ev_drop.read() {
+        let FileDragAndDrop::DroppedFile { path_buf, .. } = ev else { continue; };
+        let file = path_buf.display().to_string();
+        if *phase.get() != AppState::Menu || file.ends_with(GHOST_EXTENSION) {
+            continue;
+        }
+        let replay = std::fs::read_to_string(path_buf).ok().and_then(|data| Replay::from_ron(&data).ok());
+        let Some(replay) = replay else {
+            toasts.push_for(loc.tf("replay.import_failed", &[("file", file)]), ToastKind::Warning, 3.0);
+            continue;
+        };
+        let playable = manifest.as_ref().is_some_and(|m| replay.level_index(m).is_some());
+        let key = if playable { "replay.imported" } else { "replay.level_unavailable" };
+        toasts.push_for(loc.tf(key, &[("level", replay.level.clone())]), ToastKind::Info, 3.0);
+        last.store(replay);
+    }
+}
+
+fn replay_controls(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    loc: Res<Localization>,
+    last: Res<LastReplay>,
+    phase: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    playback: Option<ResMut<ReplayPlayback>>,
+    mut toasts: ResMut<ToastQueue>,
+    mut ev_restart: EventWriter<RestartGameEvent>,
+    q_buttons: Query<(&Interaction, &ReplayControl), Changed<Interaction>>,
+) {
+    if *phase.get() != AppState::Replay {
+        return;
+    }
+    let Some(mut playback) = playback else {
+        next_state.set(AppState::Menu);
+        return;
+    };
+    let pressed = q_buttons.iter().filter(|(i, _)| **i == Interaction::Pressed).map(|(_, c)| *c);
+    let keyed = [
+        (KeyCode::Space, ReplayControl::Pause),
+        (KeyCode::KeyS, ReplayControl::Speed),
+        (KeyCode::ArrowLeft, ReplayControl::PreviousHole),
+        (KeyCode::ArrowRight, ReplayControl::NextHole),
+        (KeyCode::KeyX, ReplayControl::Export),
+        (KeyCode::Escape, ReplayControl::Exit),
+    ]
+    .into_iter()
+    .filter(|(key, _)| keys.just_pressed(*key))
+    .map(|(_, c)| c);
+    let controls: Vec<ReplayControl> = pressed.chain(keyed).collect();
+    for control in controls {
+        match control {
+            ReplayControl::Pause if playback.at_end() => {
+                playback.time = 0.0;
+                playback.paused = false;
+            }
+            ReplayControl::Pause => playback.paused = !playback.paused,
+            ReplayControl::Speed => {
+                let i = SPEEDS.iter().position(|s| *s == playback.speed).unwrap_or(0);
+                playback.speed = SPEEDS[(i + 1) % SPEEDS.len()];
+            }
+            ReplayControl::PreviousHole => playback.time = playback.replay.previous_hole_time(playback.time),
+            ReplayControl::NextHole => {
+                playback.time = playback.replay.next_hole_time(playback.time).unwrap_or(playback.replay.duration());
+            }
+            ReplayControl::Export => match last.export() {
+                Some(path) => toasts.push_for(loc.tf("replay.exported", &[("path", path)]), ToastKind::Success, 4.0),
+                None => toasts.push_for(loc.t("replay.export_failed").to_string(), ToastKind::Warning, 3.0),
+            },
+            ReplayControl::Exit => {
+                // Back to the menu like the pause menu's Quit: reset the run, then the menu.
+                commands.remove_resource::<ReplayPlayback>();
+                ev_restart.send(RestartGameEvent);
+                next_state.set(AppState::Menu);
+                return;
+            }
+        }
+    }
+}
+
+fn advance_replay(
+    time: Res<Time>,
+    phase: Res<State<AppState>>,
+    playback: Option<ResMut<ReplayPlayback>>,
+    manifest: Option<Res<LevelManifest>>,
+    terrain: Res<TerrainConfig>,
+    sampler: Res<TerrainSampler>,
+    mut q_ball: Query<(&mut Transform, &mut BallKinematic), With<Ball>>,
+    mut q_cam: Query<&mut Transform, (With<OrbitCamera>, Without<Ball>)>,
+    mut q_targets: Query<(&mut Transform, &TargetInfo), (With<Target>, Without<Ball>, Without<OrbitCamera>)>,
+) {
+    let (Some(mut playback), Some(manifest)) = (playback, manifest) else { return; };
+    if *phase.get() != AppState::Replay {
+        return;
+    }
+    // Same wait as resuming a save: the level is in and its ball and targets exist two frames in a row.
+    if playback.settled < 2 {
+        let ready = manifest.loaded == manifest.selected
+            && sampler.cfg.heightmap_path == terrain.heightmap_path
+            && !q_ball.is_empty()
+            && q_targets.iter().any(|(_, info)| info.index == 0);
+        playback.settled = if ready { playback.settled + 1 } else { 0 };
+        return;
+    }
+    if !playback.paused {
+        playback.time += time.delta_seconds() * playback.speed;
+    }
+    let duration = playback.replay.duration();
+    if playback.time >= duration {
+        playback.time = duration;
+        playback.paused = true;
+    }
+    let Some(sample) = playback.replay.sample(playback.time) else { return; };
+    if let Ok((mut t, mut kin)) = q_ball.get_single_mut() {
+        t.translation = sample.ball;
+        kin.vel = Vec3::ZERO;
+    }
+    if let Ok(mut cam) = q_cam.get_single_mut() {
+        cam.translation = sample.camera.translation;
+        cam.rotation = sample.camera.rotation;
+    }
+    for (mut t, info) in &mut q_targets {
+        if let Some(pos) = sample.targets.get(info.index) {
+            t.translation = *pos;
+        }
+    }
+}
+
+fn spawn_replay_overlay(mut commands: Commands, assets: Res<AssetServer>) {
+    let font = assets.load("fonts/FiraSans-Bold.ttf");
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(16.0),
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(8.0),
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
+                z_index: ZIndex::Global(40),
+                ..default()
+            },
+            ReplayOverlay,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle { font: font.clone(), font_size: 24.0, color: Color::srgb(0.95, 0.95, 1.0) },
+                ),
+                ReplayStatusText,
+            ));
+            root.spawn(NodeBundle {
+                style: Style { column_gap: Val::Px(8.0), ..default() },
+                ..default()
+            })
+            .with_children(|row| {
+                for (control, key) in [
+                    (ReplayControl::Pause, "replay.pause"),
+                    (ReplayControl::Speed, "replay.speed"),
+                    (ReplayControl::PreviousHole, "replay.previous_hole"),
+                    (ReplayControl::NextHole, "replay.next_hole"),
+                    (ReplayControl::Export, "replay.export"),
+                    (ReplayControl::Exit, "replay.exit"),
+                ] {
+                    row.spawn((
+                        ButtonBundle {
+                            style: Style {
+                                padding: UiRect::axes(Val::Px(14.0), Val::Px(6.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            background_color: BackgroundColor(Color::srgba(0.1, 0.12, 0.18, 0.9)),
+                            ..default()
+                        },
+                        control,
+                    ))
+                    .with_children(|b| {
+                        b.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle { font: font.clone(), font_size: 20.0, color: Color::srgb(0.95, 0.95, 1.0) },
+                            ),
+                            LocalizedText::new(key),
+                        ));
+                    });
+                }
+            });
+            root.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle { font: font.clone(), font_size: 16.0, color: Color::srgb(0.7, 0.7, 0.75) },
+                ),
+                LocalizedText::new("replay.hint"),
+            ));
+        });
+}
+
+fn update_replay_overlay(
+    phase: Res<State<AppState>>,
+    loc: Res<Localization>,
+    playback: Option<Res<ReplayPlayback>>,
+    mut q_overlay: Query<&mut Visibility, With<ReplayOverlay>>,
+    mut q_status: Query<&mut Text, With<ReplayStatusText>>,
+) {
+    let active = playback.as_ref().filter(|_| *phase.get() == AppState::Replay);
+    for mut vis in &mut q_overlay {
+        let want = if active.is_some() { Visibility::Inherited } else { Visibility::Hidden };
+        if *vis != want {
+            *vis = want;
+        }
+    }
+    let Some(playback) = active else { return; };
+    let replay = &playback.replay;
+    let state = match (playback.at_end(), playback.paused) {
+        (true, _) => "replay.state.ended",
+        (false, true) => "replay.state.paused",
+        (false, false) => "replay.state.playing",
+    };
+    let args = [
+        ("level", replay.level.clone()),
+        ("time", format_timestamp(playback.time)),
+        ("duration", format_timestamp(replay.duration())),
+        ("hole", replay.hole_at(playback.time).to_string()),
+        ("holes", replay.holes.to_string()),
+        ("shot", replay.shots_at(playback.time).to_string()),
+        ("shots", replay.shots.len().to_string()),
+        ("speed", format!("{:.0}", playback.speed)),
+        ("state", loc.t(state).to_string()),
+    ];
+    for mut text in &mut q_status {
+        text.sections[0].value = loc.tf("replay.status", &args);
+    }
+}
@@ -0,0 +1,353 @@
+// Rivers and waterfalls.
+// Levels opt in with `rivers` in their RON file. Once the heightmap is loaded a hydrology pass
+// picks sources on the high ground (the highest spots at least `spacing` apart) and lets water
+// run downhill from each, always stepping to the lowest neighbouring point, until it reaches the
+// lake (water plane), pools in a hollow, meets an earlier river or gets close to a tee or target
+// (those stay dry). Each run becomes a ribbon of animated water (water_material.rs); wherever it
+// drops steeply a waterfall emitter throws spray down the fall.
+// Rivers are hazards: a ball that comes to the ground in one goes back to the hole's tee for a
+// penalty stroke, like the return-to-tee key.
+
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+use rand::{thread_rng, Rng};
+use serde::Deserialize;
+
+use crate::plugins::app_state::gameplay_running;
+use crate::plugins::ball::{ball_physics, place_ball, Ball, BallKinematic, WATER_LEVEL};
+use crate::plugins::core_sim::GameSet;
+use crate::plugins::event_log::{EventLog, LogKind};
+use crate::plugins::game_state::{HoleState, Score};
+use crate::plugins::level::LevelDef;
+use crate::plugins::localization::Localization;
+use crate::plugins::particles::ParticleQuality;
+use crate::plugins::terrain::TerrainSampler;
+use crate::plugins::ui_toast::{ToastKind, ToastQueue};
+use crate::plugins::water_material::{water_material, WaterMaterial};
+
+fn default_count() -> usize { 3 }
+fn default_area() -> f32 { 400.0 }
+fn default_spacing() -> f32 { 150.0 }
+fn default_width() -> f32 { 4.0 }
+fn default_min_length() -> f32 { 60.0 }
+fn default_clearance() -> f32 { 20.0 }
+
+/// Level-defined rivers (RON).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RiverDef {
+    #[serde(default = "default_count")]
+    pub count: usize, // at most this many rivers
+    #[serde(default = "default_area")]
+    pub area: f32, // half extent (m) of the square around the origin sources are picked in
+    #[serde(default = "default_spacing")]
+    pub spacing: f32, // m between sources
+    #[serde(default = "default_width")]
+    pub width: f32, // m
+    #[serde(default = "default_min_length")]
+    pub min_length: f32, // m; shorter runs are dropped
+    #[serde(default = "default_clearance")]
+    pub clearance: f32, // m kept dry around tees and targets
+}
+
+impl Default for RiverDef {
+    fn default() -> Self {
+        Self {
+            count: default_count(),
+            area: default_area(),
+            spacing: default_spacing(),
+            width: default_width(),
+            min_length: default_min_length(),
+            clearance: default_clearance(),
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct RiverConfig {
+    pub step: f32,         // m per downhill step
+    pub max_steps: usize,
+    pub surface_lift: f32, // m the ribbon floats above the ground
+    pub fall_slope: f32,   // drop per horizontal m that makes a waterfall
+    pub spray_per_fall: usize,
+    pub spray_life: f32,   // s
+}
+impl Default for RiverConfig {
+    fn default() -> Self {
+        Self { step: 4.0, max_steps: 250, surface_lift: 0.25, fall_slope: 0.9, spray_per_fall: 40, spray_life: 1.4 }
+    }
+}
+
+/// One traced river: ground points from source to mouth, and its width.
+#[derive(Debug, Clone, PartialEq)]
+pub struct River {
+    pub points: Vec<Vec3>,
+    pub width: f32,
+}
+
+impl River {
+    pub fn length(&self) -> f32 {
+        self.points.windows(2).map(|w| w[0].xz().distance(w[1].xz())).sum()
+    }
+
+    /// Whether `p` (x / z) is on the water.
+    pub fn contains(&self, p: Vec2) -> bool {
+        let half = self.width * 0.5;
+        self.points.windows(2).any(|w| distance_to_segment(p, w[0].xz(), w[1].xz()) <= half)
+    }
+
+    /// Indices `i` where the run from point `i` to `i + 1` drops faster than `slope`.
+    pub fn waterfalls(&self, slope: f32) -> Vec<usize> {
+        (0..self.points.len().saturating_sub(1))
+            .filter(|&i| {
+                let (a, b) = (self.points[i], self.points[i + 1]);
+                a.y - b.y > slope * a.xz().distance(b.xz()).max(1e-3)
+            })
+            .collect()
+    }
+}
+
+#[derive(Resource, Debug, Clone, Default)]
+pub struct Rivers {
+    pub rivers: Vec<River>,
+}
+
+impl Rivers {
+    pub fn contains(&self, p: Vec2) -> bool {
+        self.rivers.iter().any(|r| r.contains(p))
+    }
+}
+
+fn distance_to_segment(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let t = if ab.length_squared() > 0.0 { ((p - a).dot(ab) / ab.length_squared()).clamp(0.0, 1.0) } else { 0.0 };
+    p.distance(a + ab * t)
+}
+
+/// Up to `count` source spots in the square of half extent `area`: the highest grid points at
+/// least `spacing` apart.
+pub fn pick_sources(height: impl Fn(f32, f32) -> f32, area: f32, spacing: f32, count: usize) -> Vec<Vec2> {
+    let cell = (spacing * 0.25).max(1.0);
+    let n = (2.0 * area / cell).floor() as i32;
+    let mut candidates: Vec<(f32, Vec2)> = (0..=n)
+        .flat_map(|i| (0..=n).map(move |j| Vec2::new(-area + i as f32 * cell, -area + j as f32 * cell)))
+        .map(|p| (height(p.x, p.y), p))
+        .collect();
+    candidates.sort_by(|a, b| b.0.total_cmp(&a.0));
+    let mut picked: Vec<Vec2> = Vec::new();
+    for (_, p) in candidates {
+        if picked.len() >= count {
+            break;
+        }
+        if picked.iter().all(|q| q.distance(p) >= spacing) {
+            picked.push(p);
+        }
+    }
+    picked
+}
+
+/// Follow the water downhill from `start` in `step` m steps (steepest descent over 16 headings).
+/// Stops below `water_level` (that point included), in a hollow with no lower neighbour, after
+/// `max_steps`, or before a point `stop` rejects.
+pub fn trace_downhill(
+    height: impl Fn(f32, f32) -> f32,
+    start: Vec2,
+    step: f32,
+    max_steps: usize,
+    water_level: f32,
+    stop: impl Fn(Vec2) -> bool,
+) -> Vec<Vec3> {
+    let mut p = start;
+    let mut h = height(p.x, p.y);
+    let mut points = vec![Vec3::new(p.x, h, p.y)];
+    for _ in 0..max_steps {
+        if h < water_level {
+            break;
+        }
+        let lowest = (0..16)
+            .map(|k| {
+                let a = k as f32 * std::f32::consts::TAU / 16.0;
+                let q = p + Vec2::new(a.cos(), a.sin()) * step;
+                (height(q.x, q.y), q)
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0));
+        let Some((qh, q)) = lowest.filter(|(qh, _)| *qh < h) else { break; };
+        if stop(q) {
+            break;
+        }
+        (p, h) = (q, qh);
+        points.push(Vec3::new(p.x, h, p.y));
+    }
+    points
+}
+
+/// Trace the level's rivers on the terrain.
+pub fn trace_rivers(def: &RiverDef, cfg: &RiverConfig, level: &LevelDef, height: impl Fn(f32, f32) -> f32) -> Vec<River> {
+    let mut dry: Vec<Vec2> = vec![Vec2::new(level.ball.pos.x, level.ball.pos.z)];
+    dry.extend(std::iter::once(&level.target).chain(&level.extra_targets).map(|t| Vec2::new(t.initial.x, t.initial.z)));
+    dry.extend(level.holes.iter().flat_map(|h| [Vec2::new(h.tee.x, h.tee.z), Vec2::new(h.target.x, h.target.z)]));
+    let keep_dry = |p: Vec2| dry.iter().any(|d| d.distance(p) < def.clearance + def.width * 0.5);
+    let mut rivers: Vec<River> = Vec::new();
+    for source in pick_sources(&height, def.area, def.spacing, def.count) {
+        if keep_dry(source) {
+            continue;
+        }
+        // Rivers end where they run into dry ground or an earlier river.
+        let joins = |p: Vec2| keep_dry(p) || rivers.iter().any(|r| r.contains(p));
+        let points = trace_downhill(&height, source, cfg.step, cfg.max_steps, WATER_LEVEL, joins);
+        let river = River { points, width: def.width };
+        if river.length() >= def.min_length {
+            rivers.push(river);
+        }
+    }
+    rivers
+}
+
+/// Flat ribbon of width `width` along `points`, lifted `lift` m.
+pub fn river_mesh(points: &[Vec3], width: f32, lift: f32) -> Mesh {
+    let mut positions: Vec<[f32; 3]> = Vec::with_capacity(points.len() * 2);
+    let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(points.len() * 2);
+    let mut along = 0.0;
+    for (i, p) in points.iter().enumerate() {
+        let prev = points[i.saturating_sub(1)];
+        let next = points[(i + 1).min(points.len() - 1)];
+        let dir = (next.xz() - prev.xz()).normalize_or_zero();
+        let side = Vec3::new(-dir.y, 0.0, dir.x) * width * 0.5;
+        if i > 0 {
+            along += prev.xz().distance(p.xz());
+        }
+        let center = *p + Vec3::Y * lift;
+        positions.extend([(center - side).to_array(), (center + side).to_array()]);
+        uvs.extend([[0.0, along / width], [1.0, along / width]]);
+    }
+    let normals = vec![[0.0, 1.0, 0.0]; positions.len()];
+    let indices: Vec<u32> = (0..points.len().saturating_sub(1) as u32)
+        .flat_map(|i| {
+            let (a, b, c, d) = (2 * i, 2 * i + 1, 2 * i + 2, 2 * i + 3);
+            [a, c, b, b, c, d]
+        })
+        .collect();
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
+
+#[derive(Component)]
+struct RiverEntity;
+
+/// Spray droplet falling from `top` to `bottom` of a waterfall, looping.
+#[derive(Component)]
+struct Spray {
+    top: Vec3,
+    bottom: Vec3,
+    spread: Vec3,
+    age: f32,
+}
+
+pub struct RiversPlugin;
+impl Plugin for RiversPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RiverConfig>()
+            .init_resource::<Rivers>()
+            .add_systems(Update, (build_rivers, animate_spray))
+            .add_systems(FixedUpdate, river_hazard.after(ball_physics).in_set(GameSet::Simulate).run_if(gameplay_running));
+    }
+}
+
+fn build_rivers(
+    mut commands: Commands,
+    (level, sampler, cfg): (Option<Res<LevelDef>>, Res<TerrainSampler>, Res<RiverConfig>),
+    quality: Res<ParticleQuality>,
+    mut rivers: ResMut<Rivers>,
+    (mut meshes, mut water, mut materials): (ResMut<Assets<Mesh>>, ResMut<Assets<WaterMaterial>>, ResMut<Assets<StandardMaterial>>),
+    q_old: Query<Entity, With<RiverEntity>>,
+    mut built: Local<bool>,
+) {
+    let Some(level) = level else { return; };
+    // Trace once per level, as soon as the real heightmap is in.
+    if level.is_changed() {
+        *built = false;
+    }
+    if *built || sampler.is_placeholder() {
+        return;
+    }
+    *built = true;
+    for e in &q_old {
+        commands.entity(e).despawn_recursive();
+    }
+    rivers.rivers = level.rivers.as_ref().map_or_else(Vec::new, |def| trace_rivers(def, &cfg, &level, |x, z| sampler.height(x, z)));
+    if rivers.rivers.is_empty() {
+        return;
+    }
+    let material = water.add(water_material());
+    let spray_mesh = meshes.add(Sphere::new(0.12));
+    let spray_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.9, 0.95, 1.0, 0.5),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    });
+    let mut rng = thread_rng();
+    let mut falls = 0;
+    for river in &rivers.rivers {
+        commands.spawn((
+            MaterialMeshBundle {
+                mesh: meshes.add(river_mesh(&river.points, river.width, cfg.surface_lift)),
+                material: material.clone(),
+                ..default()
+            },
+            RiverEntity,
+        ));
+        for i in river.waterfalls(cfg.fall_slope) {
+            falls += 1;
+            let (top, bottom) = (river.points[i] + Vec3::Y * cfg.surface_lift, river.points[i + 1]);
+            for _ in 0..quality.scaled(cfg.spray_per_fall) {
+                let spread = Vec3::new(rng.gen_range(-1.0..1.0), rng.gen_range(0.0..1.0), rng.gen_range(-1.0..1.0)) * river.width * 0.5;
+                commands.spawn((
+                    PbrBundle { mesh: spray_mesh.clone(), material: spray_material.clone(), transform: Transform::from_translation(top), ..default() },
+                    Spray { top, bottom, spread, age: rng.gen_range(0.0..cfg.spray_life) },
+                    RiverEntity,
+                ));
+            }
+        }
+    }
+    info!("Rivers traced: {} ({} waterfalls)", rivers.rivers.len(), falls);
+}
+
+fn animate_spray(time: Res<Time>, cfg: Res<RiverConfig>, mut q: Query<(&mut Spray, &mut Transform)>) {
+    let dt = time.delta_seconds();
+    for (mut s, mut t) in &mut q {
+        s.age = (s.age + dt) % cfg.spray_life;
+        let k = s.age / cfg.spray_life;
+        // Falls faster as it goes, fanning out into mist at the bottom.
+        t.translation = s.top.lerp(s.bottom, k * k) + s.spread * k;
+        t.scale = Vec3::splat(0.6 + 2.4 * k);
+    }
+}
+
+fn river_hazard(
+    (rivers, sampler, loc): (Res<Rivers>, Res<TerrainSampler>, Res<Localization>),
+    mut score: ResMut<Score>,
+    mut hole: ResMut<HoleState>,
+    mut log: ResMut<EventLog>,
+    mut toasts: ResMut<ToastQueue>,
+    mut q_ball: Query<(&mut Transform, &mut BallKinematic), With<Ball>>,
+) {
+    if rivers.rivers.is_empty() || score.game_over {
+        return;
+    }
+    let Ok((mut t, mut kin)) = q_ball.get_single_mut() else { return; };
+    let p = t.translation.xz();
+    let grounded = t.translation.y <= sampler.height(p.x, p.y) + kin.collider_radius + 0.05;
+    if !grounded || !rivers.contains(p) {
+        return;
+    }
+    place_ball(&mut t, &mut kin, &sampler, hole.tee);
+    hole.penalize_return(&mut score);
+    let text = loc.t("log.river");
+    log.push(LogKind::Penalty, text);
+    toasts.push_for(text, ToastKind::Warning, 3.0);
+}
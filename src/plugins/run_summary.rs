@@ -0,0 +1,178 @@
+// Run summary (run_summary.json).
+// With --runtime (AutoConfig::exit_enabled) the game writes one JSON document to the data
+// directory when it exits, whatever ended the run (runtime reached, autoplay round over, window
+// closed): terrain chunks and their LOD distribution, visible trees and tree batches, score and
+// shots, and timings (simulated vs wall-clock time, frames, average FPS). External tooling reads
+// it to track builds; the FINAL_STATS log line keeps the render counts in the log as well.
+
+use std::collections::HashSet;
+
+use bevy::app::AppExit;
+use bevy::core::FrameCount;
+use bevy::pbr::NotShadowCaster;
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::plugins::core_sim::{AutoConfig, SimState};
+use crate::plugins::game_mode::GameMode;
+use crate::plugins::game_state::Score;
+use crate::plugins::storage::StoragePaths;
+use crate::plugins::terrain::{LoadedChunks, TerrainChunk};
+use crate::plugins::vegetation::Tree;
+
+pub const RUN_SUMMARY_JSON: &str = "run_summary.json";
+
+/// Terrain chunks per mesh resolution.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct LodCounts {
+    pub res96: usize,
+    pub res48: usize,
+    pub res24: usize,
+    pub other: usize,
+}
+
+impl LodCounts {
+    pub fn from_resolutions(resolutions: impl IntoIterator<Item = u32>) -> Self {
+        let mut counts = Self::default();
+        for res in resolutions {
+            match res {
+                96 => counts.res96 += 1,
+                48 => counts.res48 += 1,
+                24 => counts.res24 += 1,
+                _ => counts.other += 1,
+            }
+        }
+        counts
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TerrainSummary {
+    pub chunks: usize, // loaded
+    pub lod: LodCounts,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct VegetationSummary {
+    pub visible_trees: usize,
+    pub tree_batches: usize, // approximate: unique mesh / material / shadow combinations
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScoreSummary {
+    pub level: String,
+    pub mode: GameMode,
+    pub hits: u32,
+    pub holes: u32, // holes in the round
+    pub points: u32,
+    pub shots: u32,
+    pub game_over: bool,
+    pub failed: bool,
+    pub final_time: f32, // s; 0 until the round is over
+}
+
+impl ScoreSummary {
+    pub fn from_score(score: &Score) -> Self {
+        Self {
+            level: score.level_id.clone(),
+            mode: score.mode,
+            hits: score.hits,
+            holes: score.max_holes,
+            points: score.points,
+            shots: score.shots,
+            game_over: score.game_over,
+            failed: score.failed,
+            final_time: score.final_time,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Timings {
+    pub sim_seconds: f32,
+    pub ticks: u64,
+    pub wall_seconds: f32,
+    pub frames: u32,
+    pub avg_fps: f32, // frames / wall_seconds
+}
+
+impl Timings {
+    pub fn new(sim_seconds: f32, ticks: u64, wall_seconds: f32, frames: u32) -> Self {
+        let avg_fps = if wall_seconds > 0.0 { frames as f32 / wall_seconds } else { 0.0 };
+        Self { sim_seconds, ticks, wall_seconds, frames, avg_fps }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunSummary {
+    pub version: String, // game version (Cargo.toml)
+    pub terrain: TerrainSummary,
+    pub vegetation: VegetationSummary,
+    pub score: Option<ScoreSummary>,
+    pub timings: Timings,
+}
+
+impl RunSummary {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+pub struct RunSummaryPlugin;
+impl Plugin for RunSummaryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Last, write_run_summary);
+    }
+}
+
+fn write_run_summary(
+    sim: Res<SimState>,
+    auto: Res<AutoConfig>,
+    time: Res<Time<Real>>,
+    frames: Res<FrameCount>,
+    paths: Option<Res<StoragePaths>>,
+    score: Option<Res<Score>>,
+    loaded_chunks: Option<Res<LoadedChunks>>,
+    q_tree_mesh: Query<(&Handle<Mesh>, &Handle<StandardMaterial>, Option<&NotShadowCaster>, &Visibility), With<Tree>>,
+    q_chunks: Query<&TerrainChunk>,
+    mut ev_exit: EventReader<AppExit>,
+    mut written: Local<bool>,
+) {
+    if ev_exit.read().count() == 0 || !auto.exit_enabled || *written {
+        return;
+    }
+    *written = true;
+    let mut batches: HashSet<(Handle<Mesh>, Handle<StandardMaterial>, bool)> = HashSet::new();
+    let mut visible_trees = 0usize;
+    for (mesh, mat, shadow_flag, vis) in &q_tree_mesh {
+        if *vis != Visibility::Hidden {
+            visible_trees += 1;
+            batches.insert((mesh.clone(), mat.clone(), shadow_flag.is_none()));
+        }
+    }
+    let summary = RunSummary {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        terrain: TerrainSummary {
+            chunks: loaded_chunks.map_or(0, |lc| lc.map.len()),
+            lod: LodCounts::from_resolutions(q_chunks.iter().map(|c| c.res)),
+        },
+        vegetation: VegetationSummary { visible_trees, tree_batches: batches.len() },
+        score: score.map(|s| ScoreSummary::from_score(&s)),
+        timings: Timings::new(sim.elapsed_seconds, sim.tick, time.elapsed_seconds(), frames.0),
+    };
+    let lod = &summary.terrain.lod;
+    info!(
+        "FINAL_STATS chunks={} visible_trees={} approx_unique_tree_batches={} lod96={} lod48={} lod24={} lodOther={} sim_seconds={}",
+        summary.terrain.chunks,
+        visible_trees,
+        summary.vegetation.tree_batches,
+        lod.res96,
+        lod.res48,
+        lod.res24,
+        lod.other,
+        sim.elapsed_seconds
+    );
+    let paths = paths.map(|p| p.clone()).unwrap_or_default();
+    paths.save_text(RUN_SUMMARY_JSON, &summary.to_json());
+    info!("RUN_SUMMARY {}", paths.file(RUN_SUMMARY_JSON).display());
+}
@@ -0,0 +1,317 @@
+// Save & resume of an unfinished round.
+// The round in progress (level, run clock, score, ball, target spots, the hole's tee) is kept in
+// one save slot, round_save.ron in the data directory (see storage.rs). It is written every
+// few seconds of play, when the game is paused and when the app exits, so closing the window or
+// the browser tab loses at most a few seconds. The main menu offers "Continue" while a save of a
+// still-available level exists; Play, Restart or finishing the round drop it.
+
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::core_sim::SimState;
+use crate::plugins::game_mode::GameMode;
+use crate::plugins::game_state::{reset_game, HoleState, RestartGameEvent, Score};
+use crate::plugins::level::LevelManifest;
+use crate::plugins::app_state::AppState;
+use crate::plugins::particles::GameOverEvent;
+use crate::plugins::profile::Profile;
+use crate::plugins::storage::StoragePaths;
+use crate::plugins::target::{Target, TargetFloat, TargetInfo, TargetMotion};
+use crate::plugins::terrain::{TerrainConfig, TerrainSampler};
+
+const ROUND_FILE: &str = "round_save.ron";
+const AUTOSAVE_INTERVAL: f32 = 5.0; // s of play between saves
+
+/// Position of one of the level's targets (not decoys / bonus targets).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedTarget {
+    pub index: usize, // TargetInfo::index
+    pub x: f32,
+    pub z: f32,
+    pub base_height: f32, // float height above ground (grows with relocation)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoundSave {
+    pub level: String,    // manifest id
+    pub score_id: String, // best-time key (the day, for the Daily Run)
+    pub elapsed_seconds: f32,
+    pub hits: u32,
+    pub points: u32,
+    pub shots: u32,
+    pub time_adjustment: f32,
+    pub combo: u32,
+    pub combo_timer: f32,
+    pub best_combo: u32,
+    pub shots_at_last_hit: u32,
+    pub ball_pos: (f32, f32, f32),
+    pub ball_vel: (f32, f32, f32),
+    pub tee: (f32, f32),
+    pub penalty_strokes: u32,
+    pub targets: Vec<SavedTarget>,
+    #[serde(default)]
+    pub mode: GameMode, // rule variant the round is played with (game_mode.rs)
+}
+
+impl RoundSave {
+    pub fn from_ron(data: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str::<Self>(data)
+    }
+
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()).unwrap_or_default()
+    }
+
+    /// Manifest index of the saved level, if it can still be resumed (level still listed; same
+    /// day for the Daily Run, whose layout changes daily).
+    pub fn resume_index(&self, manifest: &LevelManifest) -> Option<usize> {
+        let index = manifest.index_of(&self.level)?;
+        (manifest.levels[index].score_id() == self.score_id).then_some(index)
+    }
+
+    /// Copy the saved counters into `score` (the level's hole limit stays).
+    pub fn restore_score(&self, score: &mut Score) {
+        score.hits = self.hits;
+        score.points = self.points;
+        score.shots = self.shots;
+        score.time_adjustment = self.time_adjustment;
+        score.combo = self.combo;
+        score.combo_timer = self.combo_timer;
+        score.best_combo = self.best_combo;
+        score.shots_at_last_hit = self.shots_at_last_hit;
+        score.game_over = false;
+        score.failed = false;
+        score.final_time = 0.0;
+    }
+}
+
+/// The save slot. `pending` is set by Continue until the saved level is in and the round applied.
+#[derive(Resource, Debug)]
+pub struct RoundSlot {
+    pub saved: Option<RoundSave>,
+    pub pending: bool,
+    paths: StoragePaths,
+}
+
+impl FromWorld for RoundSlot {
+    fn from_world(world: &mut World) -> Self {
+        let paths = StoragePaths::in_world(world);
+        let saved = paths.load_text(ROUND_FILE).and_then(|data| {
+            RoundSave::from_ron(&data)
+                .map_err(|e| warn!("Ignoring unreadable {ROUND_FILE}: {e}"))
+                .ok()
+        });
+        Self { saved, pending: false, paths }
+    }
+}
+
+impl RoundSlot {
+    pub fn store(&mut self, save: RoundSave) {
+        self.paths.save_text(ROUND_FILE, &save.to_ron());
+        self.saved = Some(save);
+    }
+
+    pub fn clear(&mut self) {
+        if self.saved.take().is_some() {
+            self.paths.remove_text(ROUND_FILE);
+        }
+        self.pending = false;
+    }
+}
+
+/// Sent when a saved round was applied (hole / checkpoint tracking must not treat the jump in
+/// hits as holes just played).
+#[derive(Event)]
+pub struct RoundResumedEvent;
+
+pub struct SaveGamePlugin;
+impl Plugin for SaveGamePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RoundSlot>()
+            .add_event::<RoundResumedEvent>()
+            .add_systems(Update, (resume_round, drop_finished_round, autosave_round).chain().after(reset_game))
+            .add_systems(Last, save_round_on_exit);
+    }
+}
+
+fn capture_round(
+    manifest: &LevelManifest,
+    sim: &SimState,
+    score: &Score,
+    hole: &HoleState,
+    ball: (&Transform, &BallKinematic),
+    targets: Vec<SavedTarget>,
+) -> Option<RoundSave> {
+    let entry = manifest.levels.get(manifest.loaded)?;
+    let (t, kin) = ball;
+    Some(RoundSave {
+        level: entry.id.clone(),
+        score_id: entry.score_id(),
+        elapsed_seconds: sim.elapsed_seconds,
+        hits: score.hits,
+        points: score.points,
+        shots: score.shots,
+        time_adjustment: score.time_adjustment,
+        combo: score.combo,
+        combo_timer: score.combo_timer,
+        best_combo: score.best_combo,
+        shots_at_last_hit: score.shots_at_last_hit,
+        ball_pos: t.translation.into(),
+        ball_vel: kin.vel.into(),
+        tee: hole.tee.into(),
+        penalty_strokes: hole.penalty_strokes,
+        targets,
+        mode: score.mode,
+    })
+}
+
+fn saved_targets<'a>(q: impl Iterator<Item = (&'a Transform, &'a TargetInfo, &'a TargetFloat)>) -> Vec<SavedTarget> {
+    q.filter(|(_, info, _)| info.index != usize::MAX)
+        .map(|(t, info, float)| SavedTarget {
+            index: info.index,
+            x: t.translation.x,
+            z: t.translation.z,
+            base_height: float.base_height,
+        })
+        .collect()
+}
+
+// Continue pressed: apply the save once the saved level is in and its ball and targets exist for
+// two frames in a row (a level switch despawns the old ones first, then respawns and restarts).
+fn resume_round(
+    mut slot: ResMut<RoundSlot>,
+    manifest: Option<Res<LevelManifest>>,
+    terrain: Res<TerrainConfig>,
+    sampler: Res<TerrainSampler>,
+    profile: Res<Profile>,
+    mut sim: ResMut<SimState>,
+    mut score: ResMut<Score>,
+    mut hole: ResMut<HoleState>,
+    mut q_ball: Query<(&mut Transform, &mut BallKinematic), With<Ball>>,
+    mut q_targets: Query<
+        (&mut Transform, &mut TargetFloat, &TargetInfo, Option<&mut TargetMotion>),
+        (With<Target>, Without<Ball>),
+    >,
+    mut ev_resumed: EventWriter<RoundResumedEvent>,
+    mut settled: Local<u32>,
+) {
+    let Some(manifest) = manifest else { return; };
+    if !slot.pending {
+        *settled = 0;
+        return;
+    }
+    let Some(save) = slot.saved.clone() else {
+        slot.pending = false;
+        return;
+    };
+    let ready = manifest.loaded == manifest.selected
+        && sampler.cfg.heightmap_path == terrain.heightmap_path
+        && !q_ball.is_empty()
+        && q_targets.iter().any(|(_, _, info, _)| info.index == 0);
+    *settled = if ready { *settled + 1 } else { 0 };
+    if *settled < 2 {
+        return;
+    }
+    slot.pending = false;
+    *settled = 0;
+
+    sim.tick = (save.elapsed_seconds * 60.0).round() as u64;
+    sim.elapsed_seconds = sim.tick as f32 / 60.0;
+    save.restore_score(&mut score);
+    if score.mode != save.mode {
+        score.set_mode(save.mode, &profile);
+    }
+    hole.tee = save.tee.into();
+    hole.hole = save.hits;
+    hole.penalty_strokes = save.penalty_strokes;
+    if let Ok((mut t, mut kin)) = q_ball.get_single_mut() {
+        t.translation = save.ball_pos.into();
+        kin.vel = save.ball_vel.into();
+        kin.angular_vel = Vec3::ZERO;
+    }
+    for (mut t, mut float, info, motion) in &mut q_targets {
+        let Some(saved) = save.targets.iter().find(|s| s.index == info.index) else { continue; };
+        let ground = sampler.height(saved.x, saved.z);
+        float.ground = ground;
+        float.base_height = saved.base_height;
+        t.translation = Vec3::new(saved.x, ground + saved.base_height, saved.z);
+        if let Some(mut motion) = motion {
+            motion.set_anchor(Vec2::new(saved.x, saved.z), ground);
+        }
+    }
+    ev_resumed.send(RoundResumedEvent);
+    info!("Resumed round on {} ({} hits, {:.0}s)", save.level, save.hits, save.elapsed_seconds);
+}
+
+// A finished or lost round, or a Restart during play, has nothing left to continue.
+fn drop_finished_round(
+    phase: Option<Res<State<AppState>>>,
+    next_state: Option<Res<NextState<AppState>>>,
+    mut slot: ResMut<RoundSlot>,
+    score: Res<Score>,
+    mut ev_game_over: EventReader<GameOverEvent>,
+    mut ev_restart: EventReader<RestartGameEvent>,
+) {
+    let finished = ev_game_over.read().count() > 0 || score.failed;
+    // Quit to Menu restarts too, but keeps the save (written when the game was paused).
+    // The buttons request their state change in the same frame as the event.
+    let target = match next_state.as_deref() {
+        Some(NextState::Pending(next)) => Some(*next),
+        _ => phase.map(|p| *p.get()),
+    };
+    let restarted = ev_restart.read().count() > 0 && target.is_some_and(|s| s != AppState::Menu);
+    if (finished || restarted) && !slot.pending {
+        slot.clear();
+    }
+}
+
+fn autosave_round(
+    time: Res<Time>,
+    phase: Option<Res<State<AppState>>>,
+    manifest: Option<Res<LevelManifest>>,
+    sim: Res<SimState>,
+    score: Res<Score>,
+    hole: Res<HoleState>,
+    mut slot: ResMut<RoundSlot>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+    q_targets: Query<(&Transform, &TargetInfo, &TargetFloat), (With<Target>, Without<Ball>)>,
+    mut since_save: Local<f32>,
+) {
+    let (Some(phase), Some(manifest)) = (phase, manifest) else { return; };
+    if !phase.get().in_game() || score.game_over || slot.pending {
+        *since_save = 0.0;
+        return;
+    }
+    *since_save += time.delta_seconds();
+    let just_paused = phase.is_changed() && *phase.get() == AppState::Paused;
+    if *since_save < AUTOSAVE_INTERVAL && !just_paused {
+        return;
+    }
+    let Ok(ball) = q_ball.get_single() else { return; };
+    *since_save = 0.0;
+    if let Some(save) = capture_round(&manifest, &sim, &score, &hole, ball, saved_targets(q_targets.iter())) {
+        slot.store(save);
+    }
+}
+
+fn save_round_on_exit(
+    mut ev_exit: EventReader<AppExit>,
+    phase: Option<Res<State<AppState>>>,
+    manifest: Option<Res<LevelManifest>>,
+    sim: Res<SimState>,
+    score: Res<Score>,
+    hole: Res<HoleState>,
+    mut slot: ResMut<RoundSlot>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+    q_targets: Query<(&Transform, &TargetInfo, &TargetFloat), (With<Target>, Without<Ball>)>,
+) {
+    if ev_exit.read().count() == 0 || score.game_over || slot.pending || !phase.is_some_and(|p| p.in_game()) {
+        return;
+    }
+    let (Some(manifest), Ok(ball)) = (manifest, q_ball.get_single()) else { return; };
+    if let Some(save) = capture_round(&manifest, &sim, &score, &hole, ball, saved_targets(q_targets.iter())) {
+        slot.store(save);
+    }
+}
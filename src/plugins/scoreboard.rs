@@ -0,0 +1,286 @@
+// Per-hole scoreboard.
+// Every completed hole (standard target hit) is recorded into `HoleHistory`: strokes, time and
+// the distance the ball travelled along its path. The table is shown as an overlay while Tab
+// toggles it on; once the game is over the game-over panel (game_over.rs) shows it instead.
+// `ScoreboardLayout::Sidebar` moves it under the compass in bigger type for the spectator overlay.
+// The history starts over when a new run begins.
+
+use bevy::prelude::*;
+
+use crate::plugins::ball::Ball;
+use crate::plugins::core_sim::SimState;
+use crate::plugins::game_state::{RestartGameEvent, Score};
+use crate::plugins::particles::TargetHitEvent;
+use crate::plugins::target::TargetKind;
+
+/// Per-frame ball moves longer than this are teleports (restart / respawn), not travel.
+const MAX_TRAVEL_STEP: f32 = 25.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HoleRecord {
+    pub hole: u32,
+    pub strokes: u32,
+    pub time: f32,     // s
+    pub distance: f32, // m travelled by the ball
+    pub points: u32,
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct HoleHistory {
+    pub holes: Vec<HoleRecord>,
+    start_shots: u32,
+    start_time: f32,
+    distance: f32, // current hole so far
+}
+
+impl HoleHistory {
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn add_travel(&mut self, meters: f32) {
+        self.distance += meters;
+    }
+
+    /// Close the current hole at total `shots` / run `time`.
+    pub fn complete_hole(&mut self, shots: u32, time: f32, points: u32) -> HoleRecord {
+        let record = HoleRecord {
+            hole: self.holes.len() as u32 + 1,
+            strokes: shots.saturating_sub(self.start_shots),
+            time: (time - self.start_time).max(0.0),
+            distance: self.distance,
+            points,
+        };
+        self.holes.push(record);
+        self.start_shots = shots;
+        self.start_time = time;
+        self.distance = 0.0;
+        record
+    }
+
+    pub fn totals(&self) -> HoleRecord {
+        self.holes.iter().fold(HoleRecord::default(), |acc, h| HoleRecord {
+            hole: self.holes.len() as u32,
+            strokes: acc.strokes + h.strokes,
+            time: acc.time + h.time,
+            distance: acc.distance + h.distance,
+            points: acc.points + h.points,
+        })
+    }
+
+    /// Table text, one string per column (Hole, Strokes, Time, Distance, Points) with a total row.
+    pub fn table_columns(&self) -> [String; 5] {
+        let mut cols: [Vec<String>; 5] = [
+            vec!["Hole".into()],
+            vec!["Strokes".into()],
+            vec!["Time".into()],
+            vec!["Distance".into()],
+            vec!["Points".into()],
+        ];
+        let mut push = |label: String, h: &HoleRecord| {
+            cols[0].push(label);
+            cols[1].push(h.strokes.to_string());
+            cols[2].push(format!("{:.1}s", h.time));
+            cols[3].push(format!("{:.0}m", h.distance));
+            cols[4].push(h.points.to_string());
+        };
+        for h in &self.holes {
+            push(h.hole.to_string(), h);
+        }
+        if self.holes.is_empty() {
+            push("-".into(), &HoleRecord::default());
+        } else {
+            push("Total".into(), &self.totals());
+        }
+        cols.map(|c| c.join("\n"))
+    }
+}
+
+/// Tab toggles the table during play.
+#[derive(Resource, Debug, Default)]
+pub struct ScoreboardVisible(pub bool);
+
+/// Where the table sits: centred over the course, or as a larger sidebar under the compass
+/// (spectator overlay, see spectate.rs).
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScoreboardLayout {
+    #[default]
+    Centered,
+    Sidebar,
+}
+
+impl ScoreboardLayout {
+    /// Panel width (px) and title / table font sizes.
+    fn metrics(self) -> (f32, f32, f32) {
+        match self {
+            Self::Centered => (460.0, 24.0, 18.0),
+            Self::Sidebar => (560.0, 32.0, 25.0),
+        }
+    }
+}
+
+#[derive(Component)]
+struct ScoreboardPanel;
+#[derive(Component)]
+struct ScoreboardTitle;
+#[derive(Component)]
+struct ScoreboardColumn(usize);
+
+pub struct ScoreboardPlugin;
+impl Plugin for ScoreboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HoleHistory>()
+            .init_resource::<ScoreboardVisible>()
+            .init_resource::<ScoreboardLayout>()
+            .add_systems(Startup, spawn_scoreboard)
+            .add_systems(Update, (track_holes, toggle_scoreboard, apply_scoreboard_layout, update_scoreboard).chain());
+    }
+}
+
+fn spawn_scoreboard(mut commands: Commands, assets: Res<AssetServer>) {
+    let font = assets.load("fonts/FiraSans-Bold.ttf");
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(24.0),
+                    left: Val::Percent(50.0),
+                    margin: UiRect::left(Val::Px(-230.0)),
+                    width: Val::Px(460.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(8.0),
+                    padding: UiRect::all(Val::Px(14.0)),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(0.04, 0.05, 0.08, 0.88)),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            ScoreboardPanel,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                TextBundle::from_section(
+                    "Scoreboard",
+                    TextStyle { font: font.clone(), font_size: 24.0, color: Color::srgb(0.95, 0.95, 1.0) },
+                ),
+                ScoreboardTitle,
+            ));
+            panel
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::SpaceBetween,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|row| {
+                    for i in 0..5 {
+                        row.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle { font: font.clone(), font_size: 18.0, color: Color::srgb(0.85, 0.90, 1.0) },
+                            )
+                            .with_text_justify(if i == 0 { JustifyText::Left } else { JustifyText::Right }),
+                            ScoreboardColumn(i),
+                        ));
+                    }
+                });
+        });
+}
+
+fn track_holes(
+    sim: Res<SimState>,
+    score: Res<Score>,
+    mut history: ResMut<HoleHistory>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+    mut ev_restart: EventReader<RestartGameEvent>,
+    q_ball: Query<&Transform, With<Ball>>,
+    mut last_pos: Local<Option<Vec3>>,
+) {
+    // A restart zeroes the score: start a fresh history.
+    if ev_restart.read().count() > 0 || score.hits < history.holes.len() as u32 {
+        history.reset();
+    }
+    if let Ok(t) = q_ball.get_single() {
+        if let Some(prev) = *last_pos {
+            let step = prev.distance(t.translation);
+            if step < MAX_TRAVEL_STEP && !score.game_over {
+                history.add_travel(step);
+            }
+        }
+        *last_pos = Some(t.translation);
+    }
+    for e in ev_hit.read() {
+        if e.kind == TargetKind::Standard {
+            let time = if score.game_over { score.final_time } else { score.run_time(sim.elapsed_seconds) };
+            history.complete_hole(score.shots, time, e.points);
+        }
+    }
+}
+
+fn toggle_scoreboard(keys: Res<ButtonInput<KeyCode>>, mut visible: ResMut<ScoreboardVisible>) {
+    if keys.just_pressed(KeyCode::Tab) {
+        visible.0 = !visible.0;
+    }
+}
+
+fn apply_scoreboard_layout(
+    layout: Res<ScoreboardLayout>,
+    mut q_panel: Query<&mut Style, With<ScoreboardPanel>>,
+    mut q_text: Query<(&mut Text, Has<ScoreboardTitle>), Or<(With<ScoreboardTitle>, With<ScoreboardColumn>)>>,
+) {
+    if !layout.is_changed() {
+        return;
+    }
+    let (width, title_size, table_size) = layout.metrics();
+    if let Ok(mut style) = q_panel.get_single_mut() {
+        style.width = Val::Px(width);
+        match *layout {
+            ScoreboardLayout::Centered => {
+                style.top = Val::Percent(24.0);
+                style.left = Val::Percent(50.0);
+                style.margin = UiRect::left(Val::Px(-width * 0.5));
+            }
+            ScoreboardLayout::Sidebar => {
+                style.top = Val::Px(300.0);
+                style.left = Val::Px(12.0);
+                style.margin = UiRect::DEFAULT;
+            }
+        }
+    }
+    for (mut text, is_title) in &mut q_text {
+        text.sections[0].style.font_size = if is_title { title_size } else { table_size };
+    }
+}
+
+fn update_scoreboard(
+    score: Res<Score>,
+    history: Res<HoleHistory>,
+    visible: Res<ScoreboardVisible>,
+    mut q_panel: Query<&mut Visibility, With<ScoreboardPanel>>,
+    mut q_title: Query<&mut Text, (With<ScoreboardTitle>, Without<ScoreboardColumn>)>,
+    mut q_cols: Query<(&mut Text, &ScoreboardColumn), Without<ScoreboardTitle>>,
+) {
+    let show = visible.0 && !score.game_over;
+    if let Ok(mut vis) = q_panel.get_single_mut() {
+        let want = if show { Visibility::Inherited } else { Visibility::Hidden };
+        if *vis != want {
+            *vis = want;
+        }
+    }
+    if !show || !(history.is_changed() || score.is_changed() || visible.is_changed()) {
+        return;
+    }
+    if let Ok(mut title) = q_title.get_single_mut() {
+        title.sections[0].value = format!("Scoreboard - hole {}/{}", score.hits + 1, score.max_holes);
+    }
+    let columns = history.table_columns();
+    for (mut text, col) in &mut q_cols {
+        if text.sections[0].value != columns[col.0] {
+            text.sections[0].value = columns[col.0].clone();
+        }
+    }
+}
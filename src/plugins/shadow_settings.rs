@@ -0,0 +1,103 @@
+// Shadow quality (graphics settings).
+// The sun (the directional light level.rs spawns) is the biggest GPU cost, so its shadows are
+// tunable: on / off, shadow map resolution, maximum shadow distance and cascade count. The
+// values follow the quality preset (ShadowSettings::for_preset; High keeps Bevy's defaults the
+// game always used) whenever one is selected, and can be changed in the performance menu's
+// lighting section, which makes the preset Custom. Like the other performance knobs only the
+// preset is saved. WebGL supports a single cascade, so the count is capped there.
+
+use bevy::pbr::{CascadeShadowConfig, CascadeShadowConfigBuilder, DirectionalLightShadowMap};
+use bevy::prelude::*;
+
+use crate::plugins::terrain_presets::{ActiveTerrainPreset, TerrainPreset};
+
+/// Shadow map sizes the menu cycles through.
+pub const SHADOW_MAP_SIZES: [usize; 4] = [512, 1024, 2048, 4096];
+pub const MIN_SHADOW_DISTANCE: f32 = 50.0;
+pub const MAX_SHADOW_DISTANCE: f32 = 2000.0;
+/// Cascades the platform supports.
+pub const MAX_CASCADES: usize = if cfg!(target_arch = "wasm32") { 1 } else { 4 };
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct ShadowSettings {
+    pub enabled: bool,
+    pub map_resolution: usize, // texels per side of each cascade's shadow map
+    pub max_distance: f32,     // m from the camera beyond which nothing casts shadows
+    pub cascades: usize,
+}
+
+impl Default for ShadowSettings {
+    fn default() -> Self {
+        Self::for_preset(TerrainPreset::High)
+    }
+}
+
+impl ShadowSettings {
+    pub fn for_preset(preset: TerrainPreset) -> Self {
+        let (enabled, map_resolution, max_distance, cascades) = match preset {
+            TerrainPreset::Low => (false, 1024, 150.0, 1),
+            TerrainPreset::Medium => (true, 1024, 400.0, 2),
+            TerrainPreset::High => (true, 2048, 1000.0, 4),
+            TerrainPreset::Ultra => (true, 4096, 1500.0, 4),
+        };
+        Self { enabled, map_resolution, max_distance, cascades: cascades.min(MAX_CASCADES) }
+    }
+
+    /// Next shadow map size (wraps to the smallest).
+    pub fn next_resolution(&self) -> usize {
+        let i = SHADOW_MAP_SIZES.iter().position(|s| *s == self.map_resolution).unwrap_or(0);
+        SHADOW_MAP_SIZES[(i + 1) % SHADOW_MAP_SIZES.len()]
+    }
+
+    pub fn set_max_distance(&mut self, m: f32) {
+        self.max_distance = m.clamp(MIN_SHADOW_DISTANCE, MAX_SHADOW_DISTANCE);
+    }
+
+    pub fn set_cascades(&mut self, n: usize) {
+        self.cascades = n.clamp(1, MAX_CASCADES);
+    }
+
+    pub fn cascade_config(&self) -> CascadeShadowConfig {
+        CascadeShadowConfigBuilder {
+            num_cascades: self.cascades.clamp(1, MAX_CASCADES),
+            maximum_distance: self.max_distance.max(MIN_SHADOW_DISTANCE),
+            ..default()
+        }
+        .build()
+    }
+}
+
+pub struct ShadowSettingsPlugin;
+impl Plugin for ShadowSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ShadowSettings>()
+            .add_systems(Update, (follow_quality_preset, apply_shadow_settings).chain());
+    }
+}
+
+fn follow_quality_preset(active: Option<Res<ActiveTerrainPreset>>, mut settings: ResMut<ShadowSettings>) {
+    let Some(preset) = active.filter(|a| a.is_changed()).and_then(|a| a.0) else { return; };
+    let wanted = ShadowSettings::for_preset(preset);
+    if *settings != wanted {
+        *settings = wanted;
+    }
+}
+
+// On a settings change, and for lights spawned since (level loads).
+fn apply_shadow_settings(
+    settings: Res<ShadowSettings>,
+    shadow_map: Option<ResMut<DirectionalLightShadowMap>>,
+    mut q_lights: Query<(&mut DirectionalLight, &mut CascadeShadowConfig)>,
+) {
+    if settings.is_changed() {
+        if let Some(mut map) = shadow_map {
+            map.size = settings.map_resolution;
+        }
+    }
+    for (mut light, mut cascades) in &mut q_lights {
+        if settings.is_changed() || light.is_added() {
+            light.shadows_enabled = settings.enabled;
+            *cascades = settings.cascade_config();
+        }
+    }
+}
@@ -13,11 +13,20 @@
 
 use bevy::prelude::*;
 use bevy::input::touch::TouchInput;
+use bevy::window::PrimaryWindow;
 use crate::plugins::ball::{Ball, BallKinematic};
-use crate::plugins::camera::OrbitCamera;
-use crate::plugins::game_state::{ShotState, ShotConfig, ShotMode};
+use crate::plugins::camera::{CameraMode, OrbitCamera};
+use crate::plugins::core_sim::GameSet;
+use crate::plugins::game_state::{Score, ShotState, ShotConfig, ShotMode};
 use crate::plugins::game_state::ShotMode::*;
+use crate::plugins::localization::Localization;
+use crate::plugins::ui_theme::{HighContrastText, UiTheme};
 use crate::plugins::particles::ShotFiredEvent;
+use crate::plugins::terrain::TerrainSampler;
+use crate::plugins::app_state::AppState;
+use crate::plugins::hud_layout::HudAnchor;
+use crate::plugins::spectate::InteractiveUi;
+use crate::plugins::touch_controls::{TouchControl, TouchControls};
 
 /// Trajectory visualization parameters
 const TRAJ_DOT_COUNT: usize = 20;
@@ -33,6 +42,19 @@ pub struct ShotIndicatorDot {
 #[derive(Component)]
 pub struct PowerGauge;
 
+/// Optional terrain aim point picked with the middle mouse button.
+/// When set, shots fly toward it horizontally instead of along the camera heading (cleared after each shot).
+#[derive(Resource, Default)]
+pub struct AimPoint {
+    pub pos: Option<Vec3>,
+}
+
+/// Power bar placement (px from the window's top-right corner) and size; HUD parts drawn around
+/// it (vibe_meter.rs) line up with these.
+pub const POWER_BAR_RIGHT: f32 = 12.0;
+pub const POWER_BAR_TOP: f32 = 36.0;
+pub const POWER_BAR_SIZE: Vec2 = Vec2::new(180.0, 18.0);
+
 #[derive(Component)]
 pub struct PowerBar;
 #[derive(Component)]
@@ -41,13 +63,17 @@ pub struct PowerBarFill;
 pub struct ShootingPlugin;
 impl Plugin for ShootingPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, (spawn_shot_indicators, spawn_power_ui))
+        app.init_resource::<AimPoint>()
+            .add_systems(Startup, (spawn_shot_indicators, spawn_power_ui))
+            .add_systems(Update, (
+                pick_aim_point.before(handle_shot_input),
+                handle_shot_input.run_if(in_state(AppState::Playing)),
+            ).in_set(GameSet::Input))
             .add_systems(Update, (
-                handle_shot_input,
                 update_shot_indicator,
                 update_power_gauge,
                 update_power_bar,
-            ));
+            ).in_set(GameSet::Present));
     }
 }
 
@@ -57,16 +83,16 @@ fn spawn_shot_indicators(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut mats: ResMut<Assets<StandardMaterial>>,
+    theme: Res<UiTheme>,
 ) {
     // Spawn hidden dots at origin (they relocate when charging).
     for i in 0..TRAJ_DOT_COUNT {
-        let tint = 0.3 + (i as f32 / TRAJ_DOT_COUNT as f32) * 0.7;
         commands
             .spawn(PbrBundle {
                 mesh: meshes.add(Mesh::from(bevy::math::primitives::Sphere { radius: 0.18 })),
                 material: mats.add(StandardMaterial {
-                    base_color: Color::srgb(1.0, 0.85 * tint, 0.10 * tint),
-                    emissive: LinearRgba::new(3.0, 2.0, 0.3, 1.0) * 0.2,
+                    base_color: theme.trajectory_color(i as f32 / TRAJ_DOT_COUNT as f32),
+                    emissive: theme.trajectory_emissive() * 0.2,
                     unlit: false,
                     ..default()
                 }),
@@ -95,7 +121,10 @@ fn spawn_power_ui(mut commands: Commands, assets: Res<AssetServer>) {
                 top: Val::Px(8.0),
                 ..default()
             }),
+            HudAnchor::top_right(12.0, 8.0),
             PowerGauge,
+            HighContrastText,
+            InteractiveUi,
         ));
 
     // Power bar container + fill
@@ -104,10 +133,10 @@ fn spawn_power_ui(mut commands: Commands, assets: Res<AssetServer>) {
             NodeBundle {
                 style: Style {
                     position_type: PositionType::Absolute,
-                    right: Val::Px(12.0),
-                    top: Val::Px(36.0),
-                    width: Val::Px(180.0),
-                    height: Val::Px(18.0),
+                    right: Val::Px(POWER_BAR_RIGHT),
+                    top: Val::Px(POWER_BAR_TOP),
+                    width: Val::Px(POWER_BAR_SIZE.x),
+                    height: Val::Px(POWER_BAR_SIZE.y),
                     flex_direction: FlexDirection::Row,
                     align_items: AlignItems::Center,
                     justify_content: JustifyContent::FlexStart,
@@ -117,7 +146,9 @@ fn spawn_power_ui(mut commands: Commands, assets: Res<AssetServer>) {
                 background_color: Color::srgb(0.08, 0.08, 0.10).into(),
                 ..default()
             },
+            HudAnchor::top_right(POWER_BAR_RIGHT, POWER_BAR_TOP),
             PowerBar,
+            InteractiveUi,
         ))
         .with_children(|parent| {
             parent.spawn((
@@ -137,6 +168,60 @@ fn spawn_power_ui(mut commands: Commands, assets: Res<AssetServer>) {
 
 // ---------------- Systems ----------------
 
+/// Launch speed multiplier for a charge of `power` (0..1): 0.25x to 2x `ShotConfig::base_impulse`.
+pub fn shot_power_scale(power: f32) -> f32 {
+    0.25 + power * (2.0 - 0.25)
+}
+
+/// Horizontal heading from camera (or toward the aim point if set), elevated by the launch angle.
+pub fn shot_direction(ball: Vec3, cam: Vec3, aim: Option<Vec3>, up_angle_deg: f32) -> Vec3 {
+    let cam_to_ball = (ball - cam).normalize_or_zero();
+    let mut horiz = Vec3::new(cam_to_ball.x, 0.0, cam_to_ball.z).normalize_or_zero();
+    if let Some(a) = aim {
+        let to_aim = Vec3::new(a.x - ball.x, 0.0, a.z - ball.z).normalize_or_zero();
+        if to_aim != Vec3::ZERO {
+            horiz = to_aim;
+        }
+    }
+    let angle = up_angle_deg.to_radians();
+    (horiz * angle.cos() + Vec3::Y * angle.sin()).normalize_or_zero()
+}
+
+/// Launch the ball at `pos` along `dir` with a charge of `power` (0..1) and announce the shot.
+/// Every shot goes through here (mouse, touch, the autoplay bot).
+pub fn fire_shot(
+    pos: Vec3,
+    kin: &mut BallKinematic,
+    dir: Vec3,
+    power: f32,
+    cfg: &ShotConfig,
+    ev_shot: &mut EventWriter<ShotFiredEvent>,
+) {
+    let power_scale = shot_power_scale(power);
+    kin.vel += dir * cfg.base_impulse * power_scale * cfg.launch_boost();
+    ev_shot.send(ShotFiredEvent { pos, power: power_scale });
+}
+
+// Middle click picks a terrain point under the cursor as the aim point (click the sky to clear).
+fn pick_aim_point(
+    buttons: Res<ButtonInput<MouseButton>>,
+    sampler: Option<Res<TerrainSampler>>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_cam: Query<(&Camera, &GlobalTransform), With<OrbitCamera>>,
+    mut aim: ResMut<AimPoint>,
+) {
+    if !buttons.just_pressed(MouseButton::Middle) { return; }
+    let Some(sampler) = sampler else { return; };
+    let Ok(window) = q_window.get_single() else { return; };
+    let Some(cursor) = window.cursor_position() else { return; };
+    let Ok((camera, cam_gt)) = q_cam.get_single() else { return; };
+    let Some(ray) = camera.viewport_to_world(cam_gt, cursor) else { return; };
+    aim.pos = sampler.raycast(ray.origin, *ray.direction).map(|hit| hit.position);
+    if let Some(p) = aim.pos {
+        info!("AIM point=({:.1},{:.1},{:.1})", p.x, p.y, p.z);
+    }
+}
+
 fn handle_shot_input(
     buttons: Res<ButtonInput<MouseButton>>,
     mut state: ResMut<ShotState>,
@@ -147,7 +232,23 @@ fn handle_shot_input(
     mut ev_shot: EventWriter<ShotFiredEvent>,
     mut ev_touch: EventReader<TouchInput>,
     touch_orbit: Option<Res<crate::plugins::camera::TouchOrbit>>,
+    touch_controls: Option<Res<TouchControls>>,
+    mut aim: ResMut<AimPoint>,
+    camera_mode: Res<CameraMode>,
+    score: Res<Score>,
 ) {
+    // No shots while the free camera or a flythrough has the view, or once the run is over.
+    if *camera_mode != CameraMode::Orbit || score.game_over {
+        if state.mode == Charging {
+            state.mode = Idle;
+            state.power = 0.0;
+            state.touch_id = None;
+            for (_, mut vis, _) in &mut q_indicators {
+                *vis = Visibility::Hidden;
+            }
+        }
+        return;
+    }
     let Ok((ball_t, mut kin)) = q_ball.get_single_mut() else { return; };
     let Ok(cam_t) = q_cam.get_single() else { return; };
 
@@ -155,7 +256,9 @@ fn handle_shot_input(
     for ev in ev_touch.read() {
         match ev.phase {
             bevy::input::touch::TouchPhase::Started => {
-                if state.mode == Idle && state.touch_id.is_none() {
+                // With the on-screen controls only the FIRE button charges.
+                let allowed = touch_controls.as_ref().is_none_or(|c| c.allows_shot_touch(ev.id));
+                if allowed && state.mode == Idle && state.touch_id.is_none() {
                     state.touch_id = Some(ev.id);
                     state.mode = Charging;
                     state.power = 0.0;
@@ -169,7 +272,8 @@ fn handle_shot_input(
             }
             bevy::input::touch::TouchPhase::Moved => {
                 // If this touch became a look (orbit) gesture, cancel charging.
-                if state.touch_id == Some(ev.id) {
+                let on_fire_button = touch_controls.as_ref().is_some_and(|c| c.owner(ev.id) == Some(TouchControl::Fire));
+                if state.touch_id == Some(ev.id) && !on_fire_button {
                     if let Some(to) = touch_orbit.as_ref() {
                         if to.look_active {
                             // Cancel shot charge
@@ -186,17 +290,12 @@ fn handle_shot_input(
             bevy::input::touch::TouchPhase::Ended | bevy::input::touch::TouchPhase::Canceled => {
                 if state.touch_id == Some(ev.id) && state.mode == Charging {
                     // Fire shot (same logic as mouse release)
-                    let cam_to_ball = (ball_t.translation - cam_t.translation).normalize_or_zero();
-                    let horiz = Vec3::new(cam_to_ball.x, 0.0, cam_to_ball.z).normalize_or_zero();
-                    let angle = cfg.up_angle_deg.to_radians();
-                    let dir = (horiz * angle.cos() + Vec3::Y * angle.sin()).normalize_or_zero();
-                    let power_scale = 0.25 + state.power * (2.0 - 0.25);
-                    let impulse = cfg.base_impulse * power_scale;
-                    kin.vel += dir * impulse;
-                    ev_shot.send(ShotFiredEvent { pos: ball_t.translation, power: power_scale });
+                    let dir = shot_direction(ball_t.translation, cam_t.translation, aim.pos, cfg.up_angle_deg);
+                    fire_shot(ball_t.translation, &mut kin, dir, cfg.charge(state.power), &cfg, &mut ev_shot);
                     state.mode = ShotMode::Idle;
                     state.power = 0.0;
                     state.touch_id = None;
+                    aim.pos = None;
                     for (_, mut vis, _) in &mut q_indicators {
                         *vis = Visibility::Hidden;
                     }
@@ -221,18 +320,12 @@ fn handle_shot_input(
     }
 
     if buttons.just_released(MouseButton::Left) && state.mode == Charging {
-        let cam_to_ball = (ball_t.translation - cam_t.translation).normalize_or_zero();
-        let horiz = Vec3::new(cam_to_ball.x, 0.0, cam_to_ball.z).normalize_or_zero();
-        let angle = cfg.up_angle_deg.to_radians();
-        let dir = (horiz * angle.cos() + Vec3::Y * angle.sin()).normalize_or_zero();
-
-        let power_scale = 0.25 + state.power * (2.0 - 0.25);
-        let impulse = cfg.base_impulse * power_scale;
-        kin.vel += dir * impulse;
-        ev_shot.send(ShotFiredEvent { pos: ball_t.translation, power: power_scale });
+        let dir = shot_direction(ball_t.translation, cam_t.translation, aim.pos, cfg.up_angle_deg);
+        fire_shot(ball_t.translation, &mut kin, dir, cfg.charge(state.power), &cfg, &mut ev_shot);
 
         state.mode = Idle;
         state.power = 0.0;
+        aim.pos = None;
         for (_, mut vis, _) in &mut q_indicators {
             *vis = Visibility::Hidden;
         }
@@ -246,6 +339,8 @@ fn update_shot_indicator(
     q_cam: Query<&Transform, (With<OrbitCamera>, Without<Ball>, Without<ShotIndicator>)>,
     mut q_ind: Query<(&mut Transform, &Handle<StandardMaterial>, &mut Visibility, &ShotIndicatorDot), (With<ShotIndicator>, Without<Ball>, Without<OrbitCamera>)>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    aim: Res<AimPoint>,
+    theme: Res<UiTheme>,
 ) {
     if state.mode != ShotMode::Charging {
         return;
@@ -254,13 +349,10 @@ fn update_shot_indicator(
     let Ok(cam_t) = q_cam.get_single() else { return; };
     let ball_pos = ball_t.translation;
 
-    let cam_to_ball = (ball_pos - cam_t.translation).normalize_or_zero();
-    let horiz = Vec3::new(cam_to_ball.x, 0.0, cam_to_ball.z).normalize_or_zero();
-    let angle = cfg.up_angle_deg.to_radians();
-    let dir = (horiz * angle.cos() + Vec3::Y * angle.sin()).normalize_or_zero();
+    let dir = shot_direction(ball_pos, cam_t.translation, aim.pos, cfg.up_angle_deg);
 
-    let power_scale = 0.25 + state.power * (2.0 - 0.25);
-    let v0 = dir * (cfg.base_impulse * power_scale);
+    let power_scale = shot_power_scale(cfg.charge(state.power));
+    let v0 = dir * (cfg.base_impulse * power_scale * cfg.launch_boost());
     let g = -9.81;
     let origin = ball_pos + Vec3::Y * 0.1;
 
@@ -273,26 +365,31 @@ fn update_shot_indicator(
         if let Some(mat) = materials.get_mut(mat_handle) {
             let fade = 1.0 - (dot.index as f32 / TRAJ_DOT_COUNT as f32);
             let intensity = 0.3 + power_scale * 0.4 * fade;
-            mat.emissive = LinearRgba::new(3.0, 2.0, 0.3, 1.0) * intensity;
+            mat.emissive = theme.trajectory_emissive() * intensity;
+            if theme.is_changed() {
+                mat.base_color = theme.trajectory_color(dot.index as f32 / TRAJ_DOT_COUNT as f32);
+            }
         }
     }
 }
 
 fn update_power_gauge(
     state: Res<ShotState>,
+    loc: Res<Localization>,
     mut q: Query<&mut Text, With<PowerGauge>>,
 ) {
-    if !state.is_changed() {
+    if !state.is_changed() && !loc.is_changed() {
         return;
     }
     if let Ok(mut text) = q.get_single_mut() {
         match state.mode {
             Idle => {
-                text.sections[0].value = "Power: --".to_string();
+                text.sections[0].value = loc.t("shot.power_idle").to_string();
             }
             Charging => {
-                let power_scale = 0.25 + state.power * (2.0 - 0.25);
-                text.sections[0].value = format!("Power: {:>3}%", (power_scale * 100.0) as u32);
+                let power_scale = shot_power_scale(state.power);
+                text.sections[0].value =
+                    loc.tf("shot.power", &[("pct", format!("{:>3}", (power_scale * 100.0) as u32))]);
             }
         }
     }
@@ -300,31 +397,17 @@ fn update_power_gauge(
 
 fn update_power_bar(
     state: Res<ShotState>,
+    theme: Res<UiTheme>,
     mut q_fill: Query<(&mut Style, &mut BackgroundColor), With<PowerBarFill>>,
 ) {
-    if !state.is_changed() { return; }
+    if !state.is_changed() && !theme.is_changed() { return; }
     let power = match state.mode {
         Idle => 0.0,
         Charging => state.power,
     };
     if let Ok((mut style, mut color)) = q_fill.get_single_mut() {
         style.width = Val::Percent(power * 100.0);
-        // Gradient green -> yellow -> red (same logic as original)
-        let col = if power < 0.5 {
-            let t = power / 0.5;
-            Color::srgb(
-                0.15 + (0.70 - 0.15) * t,
-                0.60 + (0.85 - 0.60) * t,
-                0.25 + (0.10 - 0.25) * t,
-            )
-        } else {
-            let t = (power - 0.5) / 0.5;
-            Color::srgb(
-                0.70 + (0.90 - 0.70) * t,
-                0.85 + (0.20 - 0.85) * t,
-                0.10 + (0.15 - 0.10) * t,
-            )
-        };
-        *color = col.into();
+        // Gradient low -> mid -> full in the active palette (default green -> yellow -> red)
+        *color = theme.power_color(power).into();
     }
 }
@@ -0,0 +1,125 @@
+// Uniform 2D grid hash over the XZ plane.
+// Used for vegetation spacing rejection while placing a chunk, and (as `VegetationGrid`) as a
+// world-wide index of spawned trees so collision / culling code can ask "what is near here"
+// without scanning every tree. `distance_range_to_box` lets cull / LOD passes decide a whole
+// cell at once.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct SpatialGrid<T> {
+    cell: f32,
+    cells: HashMap<IVec2, Vec<(Vec2, T)>>,
+    len: usize,
+}
+
+impl<T> Default for SpatialGrid<T> {
+    fn default() -> Self {
+        Self::new(8.0)
+    }
+}
+
+impl<T> SpatialGrid<T> {
+    /// `cell` should be about the typical query radius (e.g. the spacing radius).
+    pub fn new(cell: f32) -> Self {
+        Self { cell: cell.max(0.01), cells: HashMap::new(), len: 0 }
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        self.cell
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    pub fn key(&self, p: Vec2) -> IVec2 {
+        (p / self.cell).floor().as_ivec2()
+    }
+
+    pub fn insert(&mut self, p: Vec2, value: T) {
+        let k = self.key(p);
+        self.cells.entry(k).or_default().push((p, value));
+        self.len += 1;
+    }
+
+    /// Remove the first entry stored at `p` matching `pred`; returns it if found.
+    pub fn remove_where(&mut self, p: Vec2, mut pred: impl FnMut(&T) -> bool) -> Option<T> {
+        let k = self.key(p);
+        let list = self.cells.get_mut(&k)?;
+        let i = list.iter().position(|(_, v)| pred(v))?;
+        let (_, v) = list.swap_remove(i);
+        if list.is_empty() {
+            self.cells.remove(&k);
+        }
+        self.len -= 1;
+        Some(v)
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.len = 0;
+    }
+
+    /// Visit every entry within `radius` of `p`.
+    pub fn for_each_within(&self, p: Vec2, radius: f32, mut f: impl FnMut(Vec2, &T)) {
+        if radius <= 0.0 {
+            return;
+        }
+        let range = ((radius / self.cell).ceil() as i32).max(1);
+        let k = self.key(p);
+        let r2 = radius * radius;
+        for dy in -range..=range {
+            for dx in -range..=range {
+                if let Some(list) = self.cells.get(&(k + IVec2::new(dx, dy))) {
+                    for (q, v) in list {
+                        if q.distance_squared(p) < r2 {
+                            f(*q, v);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// True if any entry lies closer than `spacing` to `p`.
+    pub fn too_close(&self, p: Vec2, spacing: f32) -> bool {
+        let mut hit = false;
+        self.for_each_within(p, spacing, |_, _| hit = true);
+        hit
+    }
+
+    /// Closest entry within `radius` of `p`.
+    pub fn nearest(&self, p: Vec2, radius: f32) -> Option<(Vec2, &T)> {
+        let mut best: Option<(f32, Vec2, &T)> = None;
+        let range = ((radius / self.cell).ceil() as i32).max(1);
+        let k = self.key(p);
+        for dy in -range..=range {
+            for dx in -range..=range {
+                if let Some(list) = self.cells.get(&(k + IVec2::new(dx, dy))) {
+                    for (q, v) in list {
+                        let d2 = q.distance_squared(p);
+                        if d2 < radius * radius && !best.is_some_and(|b| d2 >= b.0) {
+                            best = Some((d2, *q, v));
+                        }
+                    }
+                }
+            }
+        }
+        best.map(|(_, q, v)| (q, v))
+    }
+}
+
+/// Nearest and farthest distance from `p` to any point of the box `[min, max]`
+/// (0 nearest when `p` is inside). Used to classify whole grid cells against a radius.
+pub fn distance_range_to_box(p: Vec3, min: Vec3, max: Vec3) -> (f32, f32) {
+    let near = p.clamp(min, max).distance(p);
+    let far_corner = Vec3::select((p - min).cmpgt(max - p), min, max);
+    (near, far_corner.distance(p))
+}
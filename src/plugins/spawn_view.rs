@@ -0,0 +1,60 @@
+// Spawn ordering by camera view.
+// Progressive spawners (per-chunk vegetation, atmospheric dust) fill in what the gameplay camera
+// sees first: `SpawnView` is the orbit camera's position and view frustum, and `priority` ranks
+// a bounding sphere by distance, with everything outside the frustum (and beyond a small radius
+// around the camera, so turning around does not reveal holes) deferred behind all visible work.
+// Without a camera (headless, before the first frame) everything counts as visible, which keeps
+// the spawners' original order.
+
+use bevy::prelude::*;
+use bevy::render::primitives::{Frustum, Sphere};
+
+use crate::plugins::camera::OrbitCamera;
+
+/// m around the camera that always counts as in view.
+pub const NEAR_RADIUS: f32 = 60.0;
+/// Added to the distance of anything out of view, so it sorts after all visible work.
+pub const DEFERRED_PENALTY: f32 = 1.0e6;
+
+#[derive(Debug, Clone, Default)]
+pub struct SpawnView {
+    pub eye: Vec3,
+    pub frustum: Option<Frustum>,
+}
+
+impl SpawnView {
+    pub fn new(eye: Vec3, frustum: Option<Frustum>) -> Self {
+        Self { eye, frustum }
+    }
+
+    /// The orbit camera's view, if it exists yet.
+    pub fn from_camera(q_cam: &Query<(&GlobalTransform, &Frustum), With<OrbitCamera>>) -> Option<Self> {
+        q_cam.get_single().ok().map(|(t, f)| Self::new(t.translation(), Some(*f)))
+    }
+
+    /// Whether a sphere is inside the frustum or close to the camera.
+    pub fn sees(&self, center: Vec3, radius: f32) -> bool {
+        if center.distance(self.eye) <= radius + NEAR_RADIUS {
+            return true;
+        }
+        self.frustum
+            .as_ref()
+            .is_none_or(|f| f.intersects_sphere(&Sphere { center: center.into(), radius }, true))
+    }
+
+    /// Spawn order key, lowest first: visible by distance, then everything else by distance.
+    pub fn priority(&self, center: Vec3, radius: f32) -> f32 {
+        let distance = center.distance(self.eye);
+        if self.sees(center, radius) { distance } else { DEFERRED_PENALTY + distance }
+    }
+}
+
+/// Index of the entry to spawn next (the first of equal keys, so no view keeps FIFO order).
+pub fn next_by_priority<T>(items: impl IntoIterator<Item = T>, key: impl Fn(&T) -> f32) -> Option<usize> {
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(i, item)| (i, key(&item)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i)
+}
@@ -0,0 +1,244 @@
+// Secondary target types (see `TargetKind` in target.rs).
+//  - Decoys: look-alike ducks (smaller, with a red ring) placed by the level; hitting one adds a
+//    time penalty and sends it elsewhere like a regular target.
+//  - Golden bonus targets: pop up now and then near the ball for a few seconds; hitting one takes
+//    seconds off the run clock. They blink before vanishing.
+// Hit handling lives in `detect_target_hits`; this module spawns / expires bonus targets and
+// holds the level's `special_targets` settings.
+
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::plugins::ball::Ball;
+use crate::plugins::game_state::Score;
+use crate::plugins::particles::BonusTargetSpawnedEvent;
+use crate::plugins::target::{Target, TargetBob, TargetFloat, TargetInfo, TargetKind};
+use crate::plugins::terrain::TerrainSampler;
+
+#[derive(Resource, Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SpecialTargetConfig {
+    pub decoys: Vec<(f32, f32)>,      // XZ spawn points
+    pub decoy_model: String,
+    pub decoy_scale: f32,
+    pub decoy_penalty_seconds: f32,
+    pub bonus_enabled: bool,
+    pub bonus_interval: (f32, f32),   // seconds between golden targets (random in range)
+    pub bonus_lifetime: f32,          // seconds a golden target stays up
+    pub bonus_distance: (f32, f32),   // spawn distance from the ball (m)
+    pub bonus_seconds: f32,           // time taken off the clock on hit
+    pub bonus_collider_radius: f32,
+}
+impl Default for SpecialTargetConfig {
+    fn default() -> Self {
+        Self {
+            decoys: Vec::new(),
+            decoy_model: "models/ducky.glb#Scene0".into(),
+            decoy_scale: 0.8,
+            decoy_penalty_seconds: 5.0,
+            bonus_enabled: true,
+            bonus_interval: (35.0, 70.0),
+            bonus_lifetime: 12.0,
+            bonus_distance: (40.0, 160.0),
+            bonus_seconds: 8.0,
+            bonus_collider_radius: 3.0,
+        }
+    }
+}
+
+/// Golden bonus target countdown.
+#[derive(Component)]
+pub struct BonusTarget {
+    pub remaining: f32,
+}
+
+#[derive(Resource)]
+struct SpecialTargetAssets {
+    bonus_mesh: Handle<Mesh>,
+    bonus_material: Handle<StandardMaterial>,
+    ring_mesh: Handle<Mesh>,
+    ring_material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for SpecialTargetAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mut meshes = world.resource_mut::<Assets<Mesh>>();
+        let bonus_mesh = meshes.add(Sphere::new(1.4).mesh().ico(3).expect("icosphere subdivisions"));
+        let ring_mesh = meshes.add(Torus::new(1.6, 1.85));
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let bonus_material = materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.78, 0.18),
+            emissive: LinearRgba::rgb(2.5, 1.6, 0.2),
+            metallic: 0.9,
+            perceptual_roughness: 0.25,
+            ..default()
+        });
+        let ring_material = materials.add(StandardMaterial {
+            base_color: Color::srgb(0.8, 0.05, 0.05),
+            emissive: LinearRgba::rgb(1.5, 0.05, 0.05),
+            unlit: true,
+            ..default()
+        });
+        Self { bonus_mesh, bonus_material, ring_mesh, ring_material }
+    }
+}
+
+// Next bonus spawn (seconds until), re-rolled after each spawn.
+#[derive(Resource, Default)]
+pub struct BonusSchedule {
+    next_in: Option<f32>,
+    forced: bool, // spawn as soon as possible, even with bonus_enabled off (level scripts)
+}
+
+impl BonusSchedule {
+    pub fn spawn_now(&mut self) {
+        self.forced = true;
+    }
+}
+
+pub struct SpecialTargetsPlugin;
+impl Plugin for SpecialTargetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpecialTargetConfig>()
+            .init_resource::<SpecialTargetAssets>()
+            .init_resource::<BonusSchedule>()
+            .add_systems(Update, (spawn_decoys, schedule_bonus_targets, expire_bonus_targets));
+    }
+}
+
+const SPECIAL_TARGET_BOB: TargetBob = TargetBob { amplitude: 0.6, bob_freq: 0.8, rot_speed: 1.2 };
+
+/// Entity bundle parts shared by decoys and bonus targets.
+fn special_target_float(ground: f32, phase: f32) -> TargetFloat {
+    let mut float = TargetFloat { ground, base_height: 1.2, amplitude: 0.0, phase, rot_speed: 0.0, bounce_freq: 0.0 };
+    SPECIAL_TARGET_BOB.apply(&mut float);
+    float
+}
+
+fn special_target_info(collider_radius: f32, home: Vec2) -> TargetInfo {
+    TargetInfo { index: usize::MAX, points: 0, collider_radius, base_height: 1.2, home, bob: SPECIAL_TARGET_BOB }
+}
+
+// Level decoys, once the level's config is in.
+fn spawn_decoys(
+    mut commands: Commands,
+    cfg: Res<SpecialTargetConfig>,
+    special: Res<SpecialTargetAssets>,
+    assets: Res<AssetServer>,
+    sampler: Option<Res<TerrainSampler>>,
+    q_decoys: Query<Entity, (With<Target>, With<DecoySpawned>)>,
+    mut pending: Local<bool>,
+) {
+    *pending |= cfg.is_changed();
+    if !*pending {
+        return;
+    }
+    let Some(sampler) = sampler else { return; };
+    *pending = false;
+    for e in &q_decoys {
+        commands.entity(e).despawn_recursive();
+    }
+    for (i, &(x, z)) in cfg.decoys.iter().enumerate() {
+        let ground = sampler.height(x, z);
+        let phase = rand::random::<f32>() * std::f32::consts::TAU;
+        commands
+            .spawn((
+                SceneBundle {
+                    scene: assets.load(cfg.decoy_model.clone()),
+                    transform: Transform::from_xyz(x, ground + 1.2, z).with_scale(Vec3::splat(cfg.decoy_scale)),
+                    ..default()
+                },
+                Target,
+                TargetKind::Decoy,
+                special_target_float(ground, phase),
+                special_target_info(3.5 * cfg.decoy_scale, Vec2::new(x, z)),
+                DecoySpawned,
+                Name::new(format!("Decoy{i}")),
+            ))
+            .with_children(|p| {
+                p.spawn(PbrBundle {
+                    mesh: special.ring_mesh.clone(),
+                    material: special.ring_material.clone(),
+                    transform: Transform::from_xyz(0.0, 0.2, 0.0),
+                    ..default()
+                });
+            });
+    }
+}
+
+#[derive(Component)]
+struct DecoySpawned;
+
+fn schedule_bonus_targets(
+    mut commands: Commands,
+    time: Res<Time>,
+    cfg: Res<SpecialTargetConfig>,
+    special: Res<SpecialTargetAssets>,
+    score: Option<Res<Score>>,
+    sampler: Option<Res<TerrainSampler>>,
+    mut schedule: ResMut<BonusSchedule>,
+    q_ball: Query<&Transform, With<Ball>>,
+    q_bonus: Query<(), With<BonusTarget>>,
+    mut ev_spawned: EventWriter<BonusTargetSpawnedEvent>,
+) {
+    if !(cfg.bonus_enabled || schedule.forced) || score.is_some_and(|s| s.game_over) || !q_bonus.is_empty() {
+        return;
+    }
+    let (Some(sampler), Ok(ball_t)) = (sampler, q_ball.get_single()) else { return; };
+    let mut rng = rand::thread_rng();
+    let (lo, hi) = (cfg.bonus_interval.0, cfg.bonus_interval.1.max(cfg.bonus_interval.0 + 0.1));
+    let next_in = schedule.next_in.get_or_insert_with(|| rng.gen_range(lo..hi));
+    *next_in -= time.delta_seconds();
+    if *next_in > 0.0 && !schedule.forced {
+        return;
+    }
+    schedule.next_in = Some(rng.gen_range(lo..hi));
+
+    // Random spot around the ball on dry, usable ground (retry a few times, else skip this turn).
+    let (near, far) = (cfg.bonus_distance.0, cfg.bonus_distance.1.max(cfg.bonus_distance.0 + 1.0));
+    let half = sampler.cfg.heightmap_world_size * 0.5;
+    let spot = (0..20).find_map(|_| {
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let p = ball_t.translation.xz() + Vec2::from_angle(angle) * rng.gen_range(near..far);
+        let ground = sampler.height(p.x, p.y);
+        (p.x.abs() < half && p.y.abs() < half && ground >= 20.0).then_some((p, ground))
+    });
+    let Some((p, ground)) = spot else { return; };
+    schedule.forced = false;
+
+    let phase = rng.gen_range(0.0..std::f32::consts::TAU);
+    let pos = Vec3::new(p.x, ground + 1.2, p.y);
+    commands.spawn((
+        PbrBundle {
+            mesh: special.bonus_mesh.clone(),
+            material: special.bonus_material.clone(),
+            transform: Transform::from_translation(pos),
+            ..default()
+        },
+        Target,
+        TargetKind::Bonus,
+        special_target_float(ground, phase),
+        special_target_info(cfg.bonus_collider_radius, p),
+        BonusTarget { remaining: cfg.bonus_lifetime },
+        Name::new("BonusTarget"),
+    ));
+    ev_spawned.send(BonusTargetSpawnedEvent { pos, lifetime: cfg.bonus_lifetime });
+}
+
+// Count down, blink over the last 3 s, despawn when time is up.
+fn expire_bonus_targets(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q: Query<(Entity, &mut BonusTarget, &mut Visibility)>,
+) {
+    for (e, mut bonus, mut vis) in &mut q {
+        bonus.remaining -= time.delta_seconds();
+        if bonus.remaining <= 0.0 {
+            commands.entity(e).despawn_recursive();
+            continue;
+        }
+        let blink_off = bonus.remaining < 3.0 && (bonus.remaining * 6.0).fract() < 0.35;
+        *vis = if blink_off { Visibility::Hidden } else { Visibility::Inherited };
+    }
+}
@@ -0,0 +1,301 @@
+// Spectator / stream overlay (`--spectate`).
+// For capturing footage or casting a session to an audience: the controls a player needs (power
+// gauge and bar, touch controls, settings gear, tutorial; anything tagged `InteractiveUi`) are
+// hidden, the scoreboard is pinned as a sidebar in bigger type, the compass grows, and a banner
+// along the bottom sums up the last shot once the ball settles (distance, apex, time, power).
+// The camera cuts between views on its own: chase, a high wide orbit, the ball cam while the ball
+// flies and the hole flyby from camera_cinematic.rs. N cuts to the next view right away, M turns
+// the automatic cuts off and on.
+
+use bevy::prelude::*;
+use bevy::render::view::VisibilitySystems;
+
+use crate::launch_options::LaunchOptions;
+use crate::plugins::app_state::AppState;
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::ball_cam::BallCamState;
+use crate::plugins::camera::{CameraActual, CameraMode, OrbitCamera, OrbitCameraState};
+use crate::plugins::camera_cinematic::CameraCinematic;
+use crate::plugins::hud_layout::HudLayoutConfig;
+use crate::plugins::localization::Localization;
+use crate::plugins::particles::ShotFiredEvent;
+use crate::plugins::scoreboard::{ScoreboardLayout, ScoreboardVisible};
+use crate::plugins::ui_theme::HighContrastText;
+
+/// Player-facing controls the overlay hides.
+#[derive(Component)]
+pub struct InteractiveUi;
+
+#[derive(Resource, Debug, Clone)]
+pub struct SpectateConfig {
+    pub compass_zoom: f32,
+    pub cut_interval: f32, // s between automatic camera cuts
+    pub chase: (f32, f32), // orbit radius (m) and pitch (rad) of the chase view
+    pub wide: (f32, f32),  // same for the high wide view
+    pub rest_speed: f32,   // m/s below which the ball counts as settled
+    pub rest_time: f32,    // s it has to stay that slow before the shot is summed up
+}
+impl Default for SpectateConfig {
+    fn default() -> Self {
+        Self {
+            compass_zoom: 1.5,
+            cut_interval: 9.0,
+            chase: (45.0, 35f32.to_radians()),
+            wide: (95.0, 68f32.to_radians()),
+            rest_speed: 0.4,
+            rest_time: 0.5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpectateView {
+    #[default]
+    Chase,
+    Wide,
+    BallCam,
+    Flyby,
+}
+
+impl SpectateView {
+    /// The view after this one: the ball cam only while the ball flies, the flyby once it rests.
+    pub fn next(self, ball_moving: bool) -> Self {
+        match self {
+            Self::Chase => Self::Wide,
+            Self::Wide if ball_moving => Self::BallCam,
+            Self::Wide => Self::Flyby,
+            Self::BallCam | Self::Flyby => Self::Chase,
+        }
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct SpectateMode {
+    pub active: bool,
+    pub auto_cut: bool,
+    pub view: SpectateView,
+    pub since_cut: f32, // s
+}
+
+/// Summary of a finished shot.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ShotStats {
+    pub distance: f32, // m, horizontal from where it was hit to where it settled
+    pub apex: f32,     // m above the launch point
+    pub time: f32,     // s until it settled
+    pub power: f32,    // launch speed multiplier (1 = 100%)
+}
+
+/// A shot in progress: launch point, highest point so far and time in motion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShotTracker {
+    start: Vec3,
+    power: f32,
+    top: f32,
+    time: f32,
+}
+
+impl ShotTracker {
+    pub fn new(start: Vec3, power: f32) -> Self {
+        Self { start, power, top: start.y, time: 0.0 }
+    }
+
+    pub fn sample(&mut self, pos: Vec3, dt: f32) {
+        self.top = self.top.max(pos.y);
+        self.time += dt;
+    }
+
+    pub fn finish(&self, rest: Vec3) -> ShotStats {
+        ShotStats {
+            distance: self.start.xz().distance(rest.xz()),
+            apex: (self.top - self.start.y).max(0.0),
+            time: self.time,
+            power: self.power,
+        }
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct LastShot {
+    pub stats: Option<ShotStats>,
+    tracker: Option<ShotTracker>,
+    rest_for: f32,
+}
+
+#[derive(Component)]
+struct LastShotBanner;
+
+pub struct SpectatePlugin;
+impl Plugin for SpectatePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpectateConfig>()
+            .init_resource::<SpectateMode>()
+            .init_resource::<LastShot>()
+            .add_systems(Startup, (enable_from_launch, spawn_banner))
+            .add_systems(Update, (track_last_shot, cut_cameras, update_banner).chain())
+            .add_systems(PostUpdate, hide_interactive_ui.before(VisibilitySystems::VisibilityPropagate));
+    }
+}
+
+fn enable_from_launch(
+    options: Option<Res<LaunchOptions>>,
+    cfg: Res<SpectateConfig>,
+    mut mode: ResMut<SpectateMode>,
+    mut layout: ResMut<HudLayoutConfig>,
+    mut scoreboard: ResMut<ScoreboardLayout>,
+    mut scoreboard_visible: ResMut<ScoreboardVisible>,
+) {
+    if !options.is_some_and(|o| o.spectate) {
+        return;
+    }
+    *mode = SpectateMode { active: true, auto_cut: true, ..default() };
+    layout.compass_zoom = cfg.compass_zoom;
+    *scoreboard = ScoreboardLayout::Sidebar;
+    scoreboard_visible.0 = true;
+    info!("Spectator overlay on (N: next camera, M: automatic cuts)");
+}
+
+fn spawn_banner(mut commands: Commands, assets: Res<AssetServer>) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle { font: assets.load("fonts/FiraSans-Bold.ttf"), font_size: 28.0, color: Color::WHITE },
+        )
+        .with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(28.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        })
+        .with_background_color(Color::srgba(0.04, 0.05, 0.08, 0.7)),
+        LastShotBanner,
+        HighContrastText,
+    ));
+}
+
+fn track_last_shot(
+    time: Res<Time>,
+    cfg: Res<SpectateConfig>,
+    mode: Res<SpectateMode>,
+    mut last: ResMut<LastShot>,
+    mut ev_shot: EventReader<ShotFiredEvent>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+) {
+    let shot = ev_shot.read().last().map(|e| ShotTracker::new(e.pos, e.power));
+    if !mode.active {
+        return;
+    }
+    if shot.is_some() {
+        last.tracker = shot;
+        last.rest_for = 0.0;
+    }
+    let Ok((ball_t, kin)) = q_ball.get_single() else { return; };
+    let dt = time.delta_seconds();
+    let Some(tracker) = last.tracker.as_mut() else { return; };
+    tracker.sample(ball_t.translation, dt);
+    if kin.vel.length() > cfg.rest_speed {
+        last.rest_for = 0.0;
+        return;
+    }
+    last.rest_for += dt;
+    if last.rest_for >= cfg.rest_time {
+        last.stats = last.tracker.take().map(|t| t.finish(ball_t.translation));
+    }
+}
+
+fn cut_cameras(
+    (time, keys, cfg): (Res<Time>, Res<ButtonInput<KeyCode>>, Res<SpectateConfig>),
+    phase: Res<State<AppState>>,
+    mut spectate: ResMut<SpectateMode>,
+    mut camera_mode: ResMut<CameraMode>,
+    (mut orbit, mut ball_cam, mut cine, mut actual): (
+        ResMut<OrbitCameraState>,
+        ResMut<BallCamState>,
+        ResMut<CameraCinematic>,
+        ResMut<CameraActual>,
+    ),
+    q_ball: Query<&BallKinematic, With<Ball>>,
+    q_cam: Query<&Transform, With<OrbitCamera>>,
+) {
+    if !spectate.active || *phase.get() != AppState::Playing {
+        return;
+    }
+    if keys.just_pressed(KeyCode::KeyM) {
+        spectate.auto_cut = !spectate.auto_cut;
+        info!("Spectator automatic camera cuts: {}", if spectate.auto_cut { "on" } else { "off" });
+    }
+    let moving = q_ball.get_single().is_ok_and(|k| k.vel.length() > cfg.rest_speed);
+    spectate.since_cut += time.delta_seconds();
+    // The ball cam is only worth watching while the ball flies.
+    let landed = *camera_mode == CameraMode::BallCam && !moving;
+    let due = spectate.auto_cut && spectate.since_cut >= cfg.cut_interval;
+    if !(keys.just_pressed(KeyCode::KeyN) || due || landed) {
+        return;
+    }
+    // Leave the player's own camera modes and a running flyby alone.
+    if !matches!(*camera_mode, CameraMode::Orbit | CameraMode::BallCam) {
+        return;
+    }
+    let view = if landed { SpectateView::Chase } else { spectate.view.next(moving) };
+    spectate.view = view;
+    spectate.since_cut = 0.0;
+    if *camera_mode == CameraMode::BallCam {
+        actual.initialized = false;
+        *camera_mode = CameraMode::Orbit;
+    }
+    match view {
+        SpectateView::Chase => (orbit.radius, orbit.pitch) = cfg.chase,
+        SpectateView::Wide => (orbit.radius, orbit.pitch) = cfg.wide,
+        SpectateView::BallCam => {
+            let forward = q_cam.get_single().map(|t| *t.forward()).unwrap_or(Vec3::NEG_Z);
+            let flat = Vec3::new(forward.x, 0.0, forward.z).try_normalize().unwrap_or(Vec3::NEG_Z);
+            ball_cam.heading = flat;
+            ball_cam.orientation = Transform::IDENTITY.looking_to(flat, Vec3::Y).rotation;
+            *camera_mode = CameraMode::BallCam;
+        }
+        // Flies in from behind the target once the ball rests (camera_cinematic.rs).
+        SpectateView::Flyby => cine.pending = true,
+    }
+    info!("Spectator camera: {:?}", view);
+}
+
+fn update_banner(
+    mode: Res<SpectateMode>,
+    last: Res<LastShot>,
+    loc: Res<Localization>,
+    phase: Res<State<AppState>>,
+    mut q_banner: Query<(&mut Text, &mut Visibility), With<LastShotBanner>>,
+) {
+    let Ok((mut text, mut vis)) = q_banner.get_single_mut() else { return; };
+    let stats = last.stats.filter(|_| mode.active && phase.get().round_in_view());
+    let want = if stats.is_some() { Visibility::Inherited } else { Visibility::Hidden };
+    if *vis != want {
+        *vis = want;
+    }
+    let Some(s) = stats else { return; };
+    if !(last.is_changed() || loc.is_changed()) {
+        return;
+    }
+    text.sections[0].value = loc.tf(
+        "spectate.last_shot",
+        &[
+            ("dist", format!("{:.0}", s.distance)),
+            ("apex", format!("{:.0}", s.apex)),
+            ("time", format!("{:.1}", s.time)),
+            ("pct", format!("{:.0}", s.power * 100.0)),
+        ],
+    );
+}
+
+fn hide_interactive_ui(mode: Res<SpectateMode>, mut q_ui: Query<&mut Visibility, With<InteractiveUi>>) {
+    if !mode.active {
+        return;
+    }
+    for mut vis in &mut q_ui {
+        if *vis != Visibility::Hidden {
+            *vis = Visibility::Hidden;
+        }
+    }
+}
@@ -0,0 +1,131 @@
+// Where persisted data lives, and a small named text store on top of it.
+// `StoragePaths` holds the directory every save, setting, cache and screenshot is written to:
+//  - dev (debug) builds keep using the working directory, as they always have;
+//  - release builds use the platform's per-user data directory (XDG data home on Linux,
+//    Application Support on macOS, %APPDATA% on Windows), so an install location can be
+//    read-only;
+//  - `--data-dir <path>` overrides both.
+// The web build has no filesystem and keeps named texts in the browser's localStorage instead
+// (keys prefixed "vibe_golf/"), so progress survives closing the tab. Write failures are logged
+// and otherwise ignored: losing a save is not worth interrupting play for.
+
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+
+const APP_DIR: &str = "vibe_golf";
+
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct StoragePaths {
+    pub data_dir: PathBuf,
+}
+
+impl Default for StoragePaths {
+    fn default() -> Self {
+        Self::resolve(None)
+    }
+}
+
+impl StoragePaths {
+    pub fn new(data_dir: impl Into<PathBuf>) -> Self {
+        Self { data_dir: data_dir.into() }
+    }
+
+    /// The working directory (dev builds, and where older builds wrote everything).
+    pub fn working_dir() -> Self {
+        Self::new(".")
+    }
+
+    /// `override_dir` if given, else the working directory in dev builds and the platform data
+    /// directory in release builds (working directory if the platform has none).
+    pub fn resolve(override_dir: Option<PathBuf>) -> Self {
+        if let Some(dir) = override_dir {
+            return Self::new(dir);
+        }
+        if cfg!(debug_assertions) || cfg!(target_arch = "wasm32") {
+            return Self::working_dir();
+        }
+        platform_data_dir(std::env::consts::OS, |name| std::env::var(name).ok()).map_or_else(Self::working_dir, Self::new)
+    }
+
+    /// The paths inserted by the app, or the defaults (tests, tools without main's setup).
+    pub fn in_world(world: &World) -> Self {
+        world.get_resource::<Self>().cloned().unwrap_or_default()
+    }
+
+    pub fn file(&self, name: impl AsRef<Path>) -> PathBuf {
+        self.data_dir.join(name)
+    }
+
+    pub fn screenshots_dir(&self) -> PathBuf {
+        self.file("screenshots")
+    }
+
+    /// Stored text for `name`, if any.
+    pub fn load_text(&self, name: &str) -> Option<String> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            local_storage()?.get_item(&format!("vibe_golf/{name}")).ok()?
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            std::fs::read_to_string(self.file(name)).ok()
+        }
+    }
+
+    pub fn save_text(&self, name: &str, data: &str) {
+        #[cfg(target_arch = "wasm32")]
+        if let Some(storage) = local_storage() {
+            let _ = storage.set_item(&format!("vibe_golf/{name}"), data);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        self.save_bytes(name, data.as_bytes());
+    }
+
+    pub fn remove_text(&self, name: &str) {
+        #[cfg(target_arch = "wasm32")]
+        if let Some(storage) = local_storage() {
+            let _ = storage.remove_item(&format!("vibe_golf/{name}"));
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = std::fs::remove_file(self.file(name));
+        }
+    }
+
+    /// Binary file `name` (native only; caches and the like).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_bytes(&self, name: &str) -> Option<Vec<u8>> {
+        std::fs::read(self.file(name)).ok()
+    }
+
+    /// Write file `name` (may include subdirectories), creating its directory first; false if that
+    /// failed.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_bytes(&self, name: &str, data: &[u8]) -> bool {
+        let path = self.file(name);
+        let dir = path.parent().unwrap_or(&self.data_dir);
+        let written = std::fs::create_dir_all(dir).and_then(|_| std::fs::write(&path, data));
+        if let Err(e) = &written {
+            warn!("Could not write {}: {e}", path.display());
+        }
+        written.is_ok()
+    }
+}
+
+/// Per-user data directory for this game on `os` (`std::env::consts::OS` values), from the
+/// environment variables `var` looks up.
+pub fn platform_data_dir(os: &str, var: impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    let non_empty = |name: &str| var(name).filter(|v| !v.is_empty()).map(PathBuf::from);
+    let base = match os {
+        "windows" => non_empty("APPDATA")?,
+        "macos" => non_empty("HOME")?.join("Library").join("Application Support"),
+        _ => non_empty("XDG_DATA_HOME").or_else(|| non_empty("HOME").map(|h| h.join(".local").join("share")))?,
+    };
+    Some(base.join(APP_DIR))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
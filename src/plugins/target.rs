@@ -1,16 +1,80 @@
 // Target components, motion update, and hit detection / progression logic.
+// A level can field several targets at once (`LevelDef::target` plus `extra_targets`), each with
+// its own float animation, collider and point value. `TargetFocus` picks the one the compass and
+// minimap point at (nearest, or highest value; V switches).
+// Targets may also fly a `TargetMotion` path (waypoint patrol, orbit, figure-eight) around an
+// anchor on top of the usual bob + spin; relocation moves the anchor, the path comes along.
+// The level's `DifficultyCurve` shrinks colliders, spreads relocations and speeds motion up as
+// the run's hit count grows. Decoy and golden bonus targets (`TargetKind`, special_targets.rs)
+// share the hit test but adjust the clock instead of counting as holes.
+// Relocation spots are validated for reachability in target_relocation.rs; cup-style targets
+// (cup_target.rs) capture a slow rolling ball instead of a sphere contact. Levels with a course
+// (`LevelDef::holes`) move the primary target to each next hole instead of relocating it.
 use bevy::prelude::*;
 use rand::Rng;
+use serde::Deserialize;
 
 use crate::plugins::ball::{Ball, BallKinematic};
 use crate::plugins::game_state::{Score, update_high_score};
-use crate::plugins::core_sim::SimState;
+use crate::plugins::profile::Profile;
+use crate::plugins::core_sim::{GameSet, SimRng, SimState};
+use crate::plugins::difficulty::DifficultyCurve;
 use crate::plugins::terrain::TerrainSampler;
 use crate::plugins::particles::{TargetHitEvent, GameOverEvent};
+use crate::plugins::special_targets::SpecialTargetConfig;
+use crate::plugins::gates::GateProgress;
+use crate::plugins::cup_target::{CupConfig, CupState};
+use crate::plugins::target_relocation::{find_relocation, RelocationConfig};
+use crate::plugins::app_state::gameplay_running;
+use crate::plugins::level::LevelDef;
+use crate::plugins::low_power::LowPowerMode;
 
 #[derive(Component)]
 pub struct Target;
 
+/// What hitting a target does. Entities without the component are `Standard`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TargetKind {
+    #[default]
+    Standard, // counts as a hole, relocates
+    Decoy,    // time penalty, relocates
+    Bonus,    // time bonus, disappears
+}
+
+/// Per-target scoring / layout data (index = order in the level's target list, usize::MAX for
+/// decoy / bonus targets).
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TargetInfo {
+    pub index: usize,
+    pub points: u32,
+    pub collider_radius: f32,
+    pub base_height: f32, // level float base height (relocation adds TargetParams::visual_offset)
+    pub home: Vec2,       // initial XZ, restored on reset
+    pub bob: TargetBob,   // own bob / spin, restored on relocation and reset
+}
+
+/// How a target bobs and spins (the `TargetFloat` values that are not per-placement).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TargetBob {
+    pub amplitude: f32,
+    pub bob_freq: f32,
+    pub rot_speed: f32,
+}
+
+impl TargetBob {
+    pub fn apply(self, float: &mut TargetFloat) {
+        float.amplitude = self.amplitude;
+        float.bounce_freq = self.bob_freq;
+        float.rot_speed = self.rot_speed;
+    }
+}
+
+impl From<&TargetParams> for TargetBob {
+    fn from(p: &TargetParams) -> Self {
+        Self { amplitude: p.amplitude, bob_freq: p.bob_freq, rot_speed: p.rot_speed }
+    }
+}
+
 #[derive(Component)]
 pub struct TargetFloat {
     pub ground: f32,
@@ -21,6 +85,95 @@ pub struct TargetFloat {
     pub bounce_freq: f32,
 }
 
+/// Flight path around a target's anchor (XZ offsets in meters; level RON `motion` field).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum TargetPath {
+    /// Patrol through the offsets at `speed` m/s, looping back to the first one.
+    Waypoints { points: Vec<(f32, f32)>, speed: f32 },
+    /// Circle of `radius` centred on the anchor, one lap per `period` seconds.
+    Orbit { radius: f32, period: f32 },
+    /// Figure-eight through the anchor with lobes `radius` long, one cycle per `period` seconds.
+    FigureEight { radius: f32, period: f32 },
+}
+
+impl TargetPath {
+    /// Offset from the anchor at path time `t` (s).
+    pub fn offset_at(&self, t: f32) -> Vec2 {
+        match self {
+            Self::Waypoints { points, speed } => {
+                let pts: Vec<Vec2> = points.iter().map(|&(x, z)| Vec2::new(x, z)).collect();
+                let segment = |i: usize| (pts[i], pts[(i + 1) % pts.len()]);
+                let total: f32 = (0..pts.len()).map(|i| segment(i).0.distance(segment(i).1)).sum();
+                if pts.len() < 2 || total <= 0.0 {
+                    return pts.first().copied().unwrap_or(Vec2::ZERO);
+                }
+                let mut s = (t * speed).rem_euclid(total);
+                for i in 0..pts.len() {
+                    let (a, b) = segment(i);
+                    let len = a.distance(b);
+                    if s <= len && len > 0.0 {
+                        return a.lerp(b, s / len);
+                    }
+                    s -= len;
+                }
+                pts[0]
+            }
+            Self::Orbit { radius, period } => {
+                let a = t / period.max(0.01) * std::f32::consts::TAU;
+                Vec2::new(a.cos(), a.sin()) * *radius
+            }
+            Self::FigureEight { radius, period } => {
+                // Lemniscate of Gerono: passes through the anchor twice per cycle.
+                let a = t / period.max(0.01) * std::f32::consts::TAU;
+                Vec2::new(a.sin(), a.sin() * a.cos()) * *radius
+            }
+        }
+    }
+
+    /// Closed polyline of offsets covering the whole path (for exclusion zones, gizmos).
+    pub fn outline(&self) -> Vec<Vec2> {
+        match self {
+            Self::Waypoints { points, .. } => {
+                let mut out: Vec<Vec2> = points.iter().map(|&(x, z)| Vec2::new(x, z)).collect();
+                if let Some(first) = out.first().copied() {
+                    out.push(first);
+                }
+                out
+            }
+            Self::Orbit { period, .. } | Self::FigureEight { period, .. } => {
+                (0..=32).map(|i| self.offset_at(i as f32 / 32.0 * period)).collect()
+            }
+        }
+    }
+}
+
+/// Moves a target along `path` around `anchor`. Height follows the terrain but never drops
+/// below the anchor's ground, so a patrol glides across valleys instead of diving into them.
+#[derive(Component, Debug, Clone)]
+pub struct TargetMotion {
+    pub path: TargetPath,
+    pub anchor: Vec2,
+    pub anchor_ground: f32,
+    pub time: f32,
+}
+
+impl TargetMotion {
+    pub fn new(path: TargetPath, anchor: Vec2, anchor_ground: f32) -> Self {
+        Self { path, anchor, anchor_ground, time: 0.0 }
+    }
+
+    pub fn position(&self) -> Vec2 {
+        self.anchor + self.path.offset_at(self.time)
+    }
+
+    /// Move the whole path (target relocated / reset).
+    pub fn set_anchor(&mut self, anchor: Vec2, ground: f32) {
+        self.anchor = anchor;
+        self.anchor_ground = ground;
+        self.time = 0.0;
+    }
+}
+
 // Runtime tunable target parameters (collider + animation config)
 #[derive(Resource, Clone, Copy)]
 pub struct TargetParams {
@@ -32,93 +185,236 @@ pub struct TargetParams {
     pub visual_offset: f32, // constant vertical lift to account for model pivot (added)
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TargetFocusMode {
+    #[default]
+    Nearest,
+    HighestValue, // ties go to the nearest
+}
+
+/// Target the HUD compass / minimap point at.
+#[derive(Resource, Default)]
+pub struct TargetFocus {
+    pub mode: TargetFocusMode,
+    pub entity: Option<Entity>,
+}
+
 pub struct TargetPlugin;
 impl Plugin for TargetPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(FixedUpdate, detect_target_hits)
-            .add_systems(Update, update_target_motion);
+        app.init_resource::<TargetFocus>()
+            .init_resource::<DifficultyCurve>()
+            .init_resource::<RelocationConfig>()
+            .add_systems(FixedUpdate, detect_target_hits.in_set(GameSet::React).run_if(gameplay_running))
+            .add_systems(Update, toggle_target_focus_mode.in_set(GameSet::Input))
+            .add_systems(Update, (update_target_motion.run_if(gameplay_running), update_target_focus).chain().in_set(GameSet::React));
+    }
+}
+
+/// Pick the focus among `(entity, position, points)` as seen from `from`.
+pub fn choose_focus(mode: TargetFocusMode, from: Vec3, targets: impl IntoIterator<Item = (Entity, Vec3, u32)>) -> Option<Entity> {
+    let key = |(_, p, pts): &(Entity, Vec3, u32)| {
+        let d = from.xz().distance_squared(p.xz());
+        match mode {
+            TargetFocusMode::Nearest => (0, d),
+            TargetFocusMode::HighestValue => (u32::MAX - pts, d),
+        }
+    };
+    targets
+        .into_iter()
+        .min_by(|a, b| {
+            let (ka, kb) = (key(a), key(b));
+            ka.0.cmp(&kb.0).then(ka.1.total_cmp(&kb.1))
+        })
+        .map(|(e, _, _)| e)
+}
+
+fn toggle_target_focus_mode(keys: Res<ButtonInput<KeyCode>>, mut focus: ResMut<TargetFocus>) {
+    if keys.just_pressed(KeyCode::KeyV) {
+        focus.mode = match focus.mode {
+            TargetFocusMode::Nearest => TargetFocusMode::HighestValue,
+            TargetFocusMode::HighestValue => TargetFocusMode::Nearest,
+        };
+        info!("Target focus: {:?}", focus.mode);
+    }
+}
+
+fn update_target_focus(
+    mut focus: ResMut<TargetFocus>,
+    q_ball: Query<&Transform, With<Ball>>,
+    q_targets: Query<(Entity, &Transform, Option<&TargetInfo>, Option<&TargetKind>), (With<Target>, Without<Ball>)>,
+) {
+    let from = q_ball.get_single().map(|t| t.translation).unwrap_or(Vec3::ZERO);
+    let chosen = choose_focus(
+        focus.mode,
+        from,
+        q_targets
+            .iter()
+            .filter(|(.., kind)| !kind.is_some_and(|k| *k != TargetKind::Standard))
+            .map(|(e, t, info, _)| (e, t.translation, info.map_or(1, |i| i.points))),
+    );
+    if focus.entity != chosen {
+        focus.entity = chosen;
     }
 }
 
 fn update_target_motion(
     time: Res<Time>,
-    mut q: Query<(&mut Transform, &mut TargetFloat), With<Target>>,
+    sampler: Option<Res<TerrainSampler>>,
+    curve: Res<DifficultyCurve>,
+    score: Option<Res<Score>>,
+    low_power: Option<Res<LowPowerMode>>,
+    mut q: Query<(&mut Transform, &mut TargetFloat, Option<&mut TargetMotion>), (With<Target>, Without<CupState>)>,
 ) {
-    let dt = time.delta_seconds();
-    for (mut t, mut f) in &mut q {
-        f.phase += dt * f.bounce_freq * std::f32::consts::TAU;
+    let speed = curve.at(score.map_or(0, |s| s.hits)).motion_speed;
+    let dt = time.delta_seconds() * speed;
+    // Reduced motion: paths still move, the idle bob and spin hold still.
+    let idle_dt = if LowPowerMode::reduced_motion(low_power.as_deref()) { 0.0 } else { dt };
+    for (mut t, mut f, motion) in &mut q {
+        if let (Some(mut motion), Some(sampler)) = (motion, sampler.as_ref()) {
+            motion.time += dt;
+            let p = motion.position();
+            t.translation.x = p.x;
+            t.translation.z = p.y;
+            f.ground = sampler.height(p.x, p.y).max(motion.anchor_ground);
+        }
+        f.phase += idle_dt * f.bounce_freq * std::f32::consts::TAU;
         let y = f.ground + f.base_height + f.amplitude * f.phase.sin();
         t.translation.y = y;
-        t.rotate_local(Quat::from_rotation_y(f.rot_speed * dt));
+        t.rotate_local(Quat::from_rotation_y(f.rot_speed * idle_dt));
     }
 }
 
 pub fn detect_target_hits(
+    mut commands: Commands,
     mut score: ResMut<Score>,
     sim: Res<SimState>,
+    mut rng: ResMut<SimRng>,
     sampler: Res<TerrainSampler>,
     params: Option<Res<TargetParams>>,
-    mut q_target: Query<(&mut Transform, &mut TargetFloat), (With<Target>, Without<Ball>)>,
+    curve: Res<DifficultyCurve>,
+    special: Option<Res<SpecialTargetConfig>>,
+    gates: Option<Res<GateProgress>>,
+    (relocation, cups): (Res<RelocationConfig>, Res<CupConfig>),
+    level: Option<Res<LevelDef>>,
+    mut q_target: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut TargetFloat,
+            Option<&TargetInfo>,
+            Option<&mut TargetMotion>,
+            Option<&TargetKind>,
+            Option<&CupState>,
+        ),
+        (With<Target>, Without<Ball>),
+    >,
     q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
     mut ev_hit: EventWriter<TargetHitEvent>,
     mut ev_game_over: EventWriter<GameOverEvent>,
+    mut profile: ResMut<Profile>,
 ) {
     let Ok((ball_t, kin)) = q_ball.get_single() else { return; };
-    let Ok((mut target_t, mut float)) = q_target.get_single_mut() else { return; };
     let params = match params {
         Some(p) => *p,
         None => return,
     };
 
-    // Collision test
-    let center_dist = (ball_t.translation - target_t.translation).length();
-    if center_dist > params.collider_radius + kin.collider_radius {
-        return;
-    }
+    // Collision test: closest target in contact (at most one hit per tick).
+    // Standard targets stay closed until every gate of the hole is passed.
+    let collider_scale = curve.at(score.hits).collider_scale;
+    let gates_open = gates.is_none_or(|g| g.complete());
+    let hit = q_target
+        .iter_mut()
+        .filter(|(.., kind, _)| gates_open || kind.is_some_and(|k| *k != TargetKind::Standard))
+        .filter_map(|(e, t, float, info, motion, kind, cup)| {
+            let radius = info.map_or(params.collider_radius, |i| i.collider_radius) * collider_scale;
+            let center_dist = (ball_t.translation - t.translation).length();
+            let contact = match cup {
+                Some(c) => cups.captures(ball_t.translation, kin.vel.length(), c.pos, c.ground),
+                None => center_dist <= radius + kin.collider_radius,
+            };
+            contact.then_some((center_dist, e, t, float, info, motion, kind))
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0));
+    let Some((_, entity, mut target_t, mut float, info, motion, kind)) = hit else { return; };
+    let kind = kind.copied().unwrap_or_default();
+    let special = special.map(|s| s.clone()).unwrap_or_default();
 
     // Register hit
-    score.hits += 1;
-    ev_hit.send(TargetHitEvent { pos: target_t.translation });
+    let strokes = if kind == TargetKind::Standard { score.hole_strokes() } else { 0 };
+    let (points, time_delta) = match kind {
+        TargetKind::Standard => (info.map_or(1, |i| i.points) * score.register_combo_hit(), 0.0),
+        TargetKind::Decoy => (0, special.decoy_penalty_seconds),
+        TargetKind::Bonus => (0, -special.bonus_seconds),
+    };
+    score.time_adjustment += time_delta;
+    let bob = if float.amplitude > 0.0 { float.phase.sin() } else { 0.0 };
+    let combo = if kind == TargetKind::Standard { score.combo } else { 0 };
+    ev_hit.send(TargetHitEvent { pos: target_t.translation, points, kind, time_delta, combo, strokes, bob });
+    match kind {
+        TargetKind::Standard => {
+            score.hits += 1;
+            score.points += points;
+        }
+        TargetKind::Decoy => {} // relocates like a regular target below
+        TargetKind::Bonus => {
+            commands.entity(entity).despawn_recursive();
+            return;
+        }
+    }
 
     // Completion check
-    if score.hits >= score.max_holes {
+    if kind == TargetKind::Standard && score.hits >= score.max_holes {
         score.game_over = true;
-        score.final_time = sim.elapsed_seconds;
+        score.final_time = score.run_time(sim.elapsed_seconds);
         ev_game_over.send(GameOverEvent { pos: ball_t.translation });
-        update_high_score(&mut score);
+        update_high_score(&mut score, &mut profile);
         return;
     }
 
-    // Reposition target:
-    // Choose a random direction and distance (500..800) from the LAST target position.
-    let mut rng = rand::thread_rng();
-    float.phase = rng.gen_range(0.0..std::f32::consts::TAU);
-
-    // Reposition target ensuring it does not spawn below minimum ground elevation.
-    const MIN_TARGET_GROUND: f32 = 50.0;
-    let base_x = target_t.translation.x;
-    let base_z = target_t.translation.z;
-    let mut chosen: Option<(f32, f32, f32)> = None;
-    for _ in 0..40 {
-        let dist = rng.gen_range(500.0..800.0);
-        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
-        let cand_x = base_x + dist * angle.cos();
-        let cand_z = base_z + dist * angle.sin();
-        let g = sampler.height(cand_x, cand_z);
-        if g >= MIN_TARGET_GROUND {
-            chosen = Some((cand_x, cand_z, g));
-            break;
+    // Designed course: the primary target moves on to the next hole (the ball to its tee, see
+    // LevelPlugin) instead of relocating at random.
+    let primary = kind == TargetKind::Standard && info.is_none_or(|i| i.index == 0);
+    if let Some(hole) = level.as_ref().filter(|_| primary).and_then(|l| l.hole(score.hits as usize)) {
+        let (x, z) = (hole.target.x, hole.target.z);
+        let ground = sampler.height(x, z);
+        float.ground = ground;
+        float.phase = rng.0.gen_range(0.0..std::f32::consts::TAU);
+        target_t.translation = Vec3::new(x, ground + float.base_height, z);
+        if let Some(mut motion) = motion {
+            motion.set_anchor(Vec2::new(x, z), ground);
         }
+        return;
     }
-    let (new_x, new_z, ground) = chosen.unwrap_or_else(|| {
-        let g = sampler.height(base_x, base_z);
-        (base_x, base_z, g)
-    });
+
+    // Reposition target:
+    // Random direction and distance (difficulty range, 500..800 by default) from the LAST target
+    // position, validated for reachability (see target_relocation); stays put if nothing fits.
+    let distance = curve.at(score.hits).distance;
+    float.phase = rng.0.gen_range(0.0..std::f32::consts::TAU);
+
+    let base = target_t.translation.xz();
+    let target_height = info.map_or(params.base_height, |i| i.base_height) + params.visual_offset;
+    let (new_x, new_z, ground) =
+        match find_relocation(&relocation, &sampler, base, ball_t.translation, distance, target_height, &mut rng.0) {
+            Some((p, g, pass)) => {
+                if pass > 0 {
+                    debug!("Target relocation needed relaxed rules (pass {pass})");
+                }
+                (p.x, p.y, g)
+            }
+            None => {
+                warn!("No valid relocation spot found; target stays in place");
+                (base.x, base.y, sampler.height(base.x, base.y))
+            }
+        };
     float.ground = ground;
-    float.base_height = params.base_height + params.visual_offset;
-    float.amplitude = params.amplitude;
-    float.bounce_freq = params.bob_freq;
-    float.rot_speed = params.rot_speed;
+    float.base_height = target_height;
+    info.map_or(TargetBob::from(&params), |i| i.bob).apply(&mut float);
 
-    target_t.translation = Vec3::new(new_x, ground + params.base_height + params.visual_offset, new_z);
+    target_t.translation = Vec3::new(new_x, ground + target_height, new_z);
+    if let Some(mut motion) = motion {
+        motion.set_anchor(Vec2::new(new_x, new_z), ground);
+    }
 }
@@ -0,0 +1,142 @@
+// Distance label floating above the focused target.
+// A UI text node is projected to a point a few metres above the target each frame and shows the
+// same ground distance as the compass (`hud::compass_distance`), so the reading is right where the
+// player is aiming. It fades out when the ball is close to the target and when the target sits
+// near the screen centre (where the label would cover the aim), and hides off-screen / behind the
+// camera. UI `Val::Px` is multiplied by `UiScale`, so viewport positions are divided by it.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::plugins::ball::Ball;
+use crate::plugins::camera::OrbitCamera;
+use crate::plugins::game_state::Score;
+use crate::plugins::hud::compass_distance;
+use crate::plugins::app_state::AppState;
+use crate::plugins::target::{Target, TargetFocus};
+
+#[derive(Resource, Debug, Clone)]
+pub struct TargetLabelConfig {
+    pub enabled: bool,
+    pub height: f32,               // m above the target centre
+    pub near_fade: (f32, f32),     // ball-target distance (m): hidden at .0, fully shown at .1
+    pub center_fade: (f32, f32),   // offset from screen centre (fraction of half the short side)
+    pub max_alpha: f32,
+    pub fade_rate: f32,            // 1/s approach towards the wanted alpha
+    pub width: f32,                // px (unscaled) of the label box the text is centred in
+}
+impl Default for TargetLabelConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            height: 3.0,
+            near_fade: (12.0, 30.0),
+            center_fade: (0.08, 0.25),
+            max_alpha: 0.9,
+            fade_rate: 8.0,
+            width: 160.0,
+        }
+    }
+}
+
+impl TargetLabelConfig {
+    /// Wanted label opacity for a ball-target `distance` and a screen `center_offset`
+    /// (0 = screen centre, 1 = half the short side away).
+    pub fn alpha(&self, distance: f32, center_offset: f32) -> f32 {
+        let ramp = |(lo, hi): (f32, f32), v: f32| {
+            let t = ((v - lo) / (hi - lo).max(1e-4)).clamp(0.0, 1.0);
+            t * t * (3.0 - 2.0 * t)
+        };
+        self.max_alpha * ramp(self.near_fade, distance) * ramp(self.center_fade, center_offset)
+    }
+}
+
+#[derive(Component, Default)]
+pub struct TargetDistanceLabel {
+    alpha: f32,
+}
+
+pub struct TargetLabelPlugin;
+impl Plugin for TargetLabelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TargetLabelConfig>()
+            .add_systems(Startup, spawn_target_label)
+            .add_systems(Update, update_target_label);
+    }
+}
+
+fn spawn_target_label(mut commands: Commands, assets: Res<AssetServer>, cfg: Res<TargetLabelConfig>) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font: assets.load("fonts/FiraSans-Bold.ttf"),
+                font_size: 20.0,
+                color: Color::srgba(1.0, 1.0, 1.0, 0.0),
+            },
+        )
+        .with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            width: Val::Px(cfg.width),
+            justify_content: JustifyContent::Center,
+            ..default()
+        }),
+        TargetDistanceLabel::default(),
+        Name::new("TargetDistanceLabel"),
+    ));
+}
+
+fn update_target_label(
+    time: Res<Time>,
+    cfg: Res<TargetLabelConfig>,
+    focus: Res<TargetFocus>,
+    ui_scale: Res<UiScale>,
+    score: Option<Res<Score>>,
+    phase: Option<Res<State<AppState>>>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_cam: Query<(&Camera, &GlobalTransform), With<OrbitCamera>>,
+    q_ball: Query<&Transform, With<Ball>>,
+    q_targets: Query<&Transform, (With<Target>, Without<Ball>)>,
+    mut q_label: Query<(&mut Text, &mut Style, &mut Visibility, &mut TargetDistanceLabel)>,
+) {
+    let Ok((mut text, mut style, mut vis, mut label)) = q_label.get_single_mut() else { return; };
+    let active = cfg.enabled
+        && phase.is_none_or(|p| p.in_game())
+        && !score.is_some_and(|s| s.game_over);
+    let target_t = focus.entity.and_then(|e| q_targets.get(e).ok());
+    let (Some(target_t), Ok(ball_t), Ok((cam, cam_gt)), Ok(window), true) =
+        (target_t, q_ball.get_single(), q_cam.get_single(), q_window.get_single(), active)
+    else {
+        label.alpha = 0.0;
+        *vis = Visibility::Hidden;
+        return;
+    };
+
+    let anchor = target_t.translation + Vec3::Y * cfg.height;
+    let dist = compass_distance(ball_t.translation, target_t.translation);
+    let wanted = match cam.world_to_viewport(cam_gt, anchor) {
+        Some(vp) => {
+            let half = Vec2::new(window.width(), window.height()) * 0.5;
+            let center_offset = (vp - half).length() / half.x.min(half.y).max(1.0);
+            let scale = ui_scale.0.max(0.01);
+            style.left = Val::Px(vp.x / scale - cfg.width * 0.5);
+            // Sit the text just above the projected point.
+            style.top = Val::Px(vp.y / scale - 28.0);
+            cfg.alpha(dist, center_offset)
+        }
+        None => 0.0,
+    };
+    let k = 1.0 - (-cfg.fade_rate * time.delta_seconds()).exp();
+    label.alpha += (wanted - label.alpha) * k;
+    if wanted == 0.0 && label.alpha < 0.01 {
+        label.alpha = 0.0;
+    }
+
+    *vis = if label.alpha > 0.0 { Visibility::Visible } else { Visibility::Hidden };
+    let value = format!("{:.0} m", dist);
+    if text.sections[0].value != value {
+        text.sections[0].value = value;
+    }
+    text.sections[0].style.color = Color::srgba(1.0, 1.0, 1.0, label.alpha);
+}
@@ -0,0 +1,196 @@
+// Hot / cold feedback for long approaches.
+// Once the ball comes to rest after a shot, its distance to the focused target is turned into a
+// "heat" value (1 = right next to it, 0 = far away, log scale). Heat drives a glowing beacon over
+// the target (pulsing faster and brighter the closer the ball lies) and a geiger-style beep whose
+// rate rises with heat for a few seconds. Useful at long range where the duck is a few pixels.
+
+use bevy::prelude::*;
+
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::game_state::Score;
+use crate::plugins::particles::{ProximityBeepEvent, ShotFiredEvent};
+use crate::plugins::target::{Target, TargetFocus};
+
+#[derive(Resource, Debug, Clone)]
+pub struct ProximityConfig {
+    pub enabled: bool,
+    pub rest_speed: f32,       // m/s below which the ball counts as resting
+    pub rest_time: f32,        // s it has to stay that slow
+    pub near: f32,             // m at (or under) which heat is 1
+    pub far: f32,              // m at (or over) which heat is 0
+    pub pulse_hz: (f32, f32),  // beacon pulse rate cold .. hot
+    pub beep_interval: (f32, f32), // s between beeps cold .. hot
+    pub beep_duration: f32,    // s of beeping after the ball settles
+    pub beacon_radius: f32,    // m at 100 m from the ball (grows with distance to stay visible)
+}
+impl Default for ProximityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            rest_speed: 0.4,
+            rest_time: 0.5,
+            near: 15.0,
+            far: 900.0,
+            pulse_hz: (0.4, 3.5),
+            beep_interval: (1.4, 0.1),
+            beep_duration: 4.0,
+            beacon_radius: 1.5,
+        }
+    }
+}
+
+impl ProximityConfig {
+    /// Heat (0..1) for a resting distance, log-scaled between `near` and `far`.
+    pub fn heat(&self, distance: f32) -> f32 {
+        let near = self.near.max(0.01);
+        let far = self.far.max(near * 1.01);
+        (1.0 - (distance.max(near) / near).ln() / (far / near).ln()).clamp(0.0, 1.0)
+    }
+
+    pub fn pulse_hz(&self, heat: f32) -> f32 {
+        self.pulse_hz.0 + (self.pulse_hz.1 - self.pulse_hz.0) * heat
+    }
+
+    pub fn beep_interval(&self, heat: f32) -> f32 {
+        self.beep_interval.0 + (self.beep_interval.1 - self.beep_interval.0) * heat
+    }
+}
+
+/// Last resting reading (cleared when the next shot is fired).
+#[derive(Resource, Debug, Default)]
+pub struct TargetProximity {
+    pub distance: Option<f32>,
+    pub heat: f32,
+    awaiting_rest: bool,
+    rest_for: f32,
+    beep_left: f32,
+    beep_timer: f32,
+    pulse_phase: f32,
+}
+
+#[derive(Component)]
+struct ProximityBeacon;
+
+#[derive(Resource)]
+struct BeaconMaterial(Handle<StandardMaterial>);
+
+pub struct TargetProximityPlugin;
+impl Plugin for TargetProximityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProximityConfig>()
+            .init_resource::<TargetProximity>()
+            .add_systems(Startup, spawn_beacon)
+            .add_systems(Update, (measure_resting_distance, update_beacon, emit_beeps).chain());
+    }
+}
+
+fn spawn_beacon(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>, mut materials: ResMut<Assets<StandardMaterial>>) {
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgba(1.0, 0.45, 0.15, 0.35),
+        alpha_mode: AlphaMode::Add,
+        unlit: true,
+        ..default()
+    });
+    commands.insert_resource(BeaconMaterial(material.clone()));
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Sphere::new(1.0).mesh().ico(2).expect("icosphere subdivisions")),
+            material,
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        ProximityBeacon,
+        Name::new("ProximityBeacon"),
+    ));
+}
+
+fn measure_resting_distance(
+    time: Res<Time>,
+    cfg: Res<ProximityConfig>,
+    focus: Res<TargetFocus>,
+    mut prox: ResMut<TargetProximity>,
+    mut ev_shot: EventReader<ShotFiredEvent>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+    q_targets: Query<&Transform, (With<Target>, Without<Ball>)>,
+) {
+    if ev_shot.read().count() > 0 {
+        prox.distance = None;
+        prox.beep_left = 0.0;
+        prox.awaiting_rest = true;
+        prox.rest_for = 0.0;
+    }
+    if !cfg.enabled || !prox.awaiting_rest {
+        return;
+    }
+    let Ok((ball_t, kin)) = q_ball.get_single() else { return; };
+    if kin.vel.length() > cfg.rest_speed {
+        prox.rest_for = 0.0;
+        return;
+    }
+    prox.rest_for += time.delta_seconds();
+    if prox.rest_for < cfg.rest_time {
+        return;
+    }
+    let Some(target_t) = focus.entity.and_then(|e| q_targets.get(e).ok()) else { return; };
+    let distance = ball_t.translation.distance(target_t.translation);
+    prox.awaiting_rest = false;
+    prox.distance = Some(distance);
+    prox.heat = cfg.heat(distance);
+    prox.beep_left = cfg.beep_duration;
+    prox.beep_timer = 0.0;
+}
+
+fn update_beacon(
+    time: Res<Time>,
+    cfg: Res<ProximityConfig>,
+    focus: Res<TargetFocus>,
+    score: Option<Res<Score>>,
+    beacon_mat: Option<Res<BeaconMaterial>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut prox: ResMut<TargetProximity>,
+    q_ball: Query<&Transform, (With<Ball>, Without<ProximityBeacon>)>,
+    q_targets: Query<&Transform, (With<Target>, Without<ProximityBeacon>, Without<Ball>)>,
+    mut q_beacon: Query<(&mut Transform, &mut Visibility), With<ProximityBeacon>>,
+) {
+    let Ok((mut t, mut vis)) = q_beacon.get_single_mut() else { return; };
+    let target_t = focus.entity.and_then(|e| q_targets.get(e).ok());
+    let active = cfg.enabled && prox.distance.is_some() && !score.is_some_and(|s| s.game_over);
+    let (Some(target_t), Ok(ball_t), true) = (target_t, q_ball.get_single(), active) else {
+        *vis = Visibility::Hidden;
+        return;
+    };
+    let heat = prox.heat;
+    prox.pulse_phase = (prox.pulse_phase + time.delta_seconds() * cfg.pulse_hz(heat)).fract();
+    let pulse = 0.5 + 0.5 * (prox.pulse_phase * std::f32::consts::TAU).sin();
+
+    let view_scale = (ball_t.translation.distance(target_t.translation) / 100.0).max(1.0);
+    t.translation = target_t.translation + Vec3::Y * (4.0 + 2.0 * view_scale);
+    t.scale = Vec3::splat(cfg.beacon_radius * view_scale * (1.0 + 0.35 * pulse * (0.3 + heat)));
+    *vis = Visibility::Visible;
+
+    if let Some(mat) = beacon_mat.and_then(|m| materials.get_mut(&m.0)) {
+        let glow = (0.4 + 2.6 * heat) * (0.4 + 0.6 * pulse);
+        // Cold = blue, hot = orange.
+        let cold = LinearRgba::rgb(0.2, 0.5, 2.0);
+        let hot = LinearRgba::rgb(2.0, 0.7, 0.15);
+        mat.base_color = Color::LinearRgba(((cold * (1.0 - heat) + hot * heat) * glow).with_alpha(0.35));
+    }
+}
+
+fn emit_beeps(
+    time: Res<Time>,
+    cfg: Res<ProximityConfig>,
+    mut prox: ResMut<TargetProximity>,
+    mut ev_beep: EventWriter<ProximityBeepEvent>,
+) {
+    if prox.beep_left <= 0.0 {
+        return;
+    }
+    let dt = time.delta_seconds();
+    prox.beep_left -= dt;
+    prox.beep_timer -= dt;
+    if prox.beep_timer <= 0.0 {
+        prox.beep_timer = cfg.beep_interval(prox.heat).max(0.02);
+        ev_beep.send(ProximityBeepEvent { heat: prox.heat });
+    }
+}
@@ -0,0 +1,155 @@
+// Target relocation rules.
+// After a hit the target moves a random distance (difficulty range) from where it was. A
+// candidate spot has to be a fair next hole: inside the heightmap (with a margin), on high enough
+// ground, on a landing area that is not too steep, within reach of the ball, and with no long
+// stretch of sea between ball and target. In the strict pass the target must also be visible from
+// the ball. If nothing qualifies the rules are relaxed pass by pass; only if even the loosest pass
+// fails does the target stay where it is.
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::plugins::terrain::TerrainSampler;
+
+#[derive(Resource, Debug, Clone)]
+pub struct RelocationConfig {
+    pub edge_margin: f32,         // m kept free inside the heightmap border
+    pub min_ground: f32,          // lowest acceptable ground height under the target
+    pub water_level: f32,         // ground below this counts as sea
+    pub max_slope_deg: f32,       // steepest landing area (strict pass)
+    pub landing_radius: f32,      // m around the spot the slope is measured over
+    pub max_ball_distance: f32,   // straight-line XZ distance from the ball (strict pass)
+    pub max_water_gap: f32,       // longest sea crossing on the ball -> target line (strict pass)
+    pub path_step: f32,           // m between samples along that line
+    pub attempts_per_pass: u32,
+}
+impl Default for RelocationConfig {
+    fn default() -> Self {
+        Self {
+            edge_margin: 40.0,
+            min_ground: 50.0,
+            water_level: 25.0,
+            max_slope_deg: 25.0,
+            landing_radius: 6.0,
+            max_ball_distance: 1100.0,
+            max_water_gap: 80.0,
+            path_step: 8.0,
+            attempts_per_pass: 40,
+        }
+    }
+}
+
+/// Constraints for one pass; later passes loosen them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelocationRules {
+    pub max_slope_deg: f32,
+    pub max_ball_distance: f32,
+    pub max_water_gap: f32,
+    pub line_of_sight: bool,
+}
+
+impl RelocationConfig {
+    /// Strict pass first, then: no line of sight; 1.5x slope / 2x sea gap; anything in bounds.
+    pub fn passes(&self) -> [RelocationRules; 4] {
+        let strict = RelocationRules {
+            max_slope_deg: self.max_slope_deg,
+            max_ball_distance: self.max_ball_distance,
+            max_water_gap: self.max_water_gap,
+            line_of_sight: true,
+        };
+        let no_los = RelocationRules { line_of_sight: false, ..strict };
+        let loose = RelocationRules {
+            max_slope_deg: (self.max_slope_deg * 1.5).min(60.0),
+            max_ball_distance: self.max_ball_distance * 1.5,
+            max_water_gap: self.max_water_gap * 2.0,
+            line_of_sight: false,
+        };
+        let any = RelocationRules {
+            max_slope_deg: 90.0,
+            max_ball_distance: f32::INFINITY,
+            max_water_gap: f32::INFINITY,
+            line_of_sight: false,
+        };
+        [strict, no_los, loose, any]
+    }
+
+    fn in_bounds(&self, sampler: &TerrainSampler, p: Vec2) -> bool {
+        let half = sampler.cfg.heightmap_world_size * 0.5 - self.edge_margin;
+        p.x.abs() <= half && p.y.abs() <= half
+    }
+
+    /// Steepest slope (degrees) across the landing area around `p`.
+    pub fn landing_slope_deg(&self, sampler: &TerrainSampler, p: Vec2) -> f32 {
+        let r = self.landing_radius.max(0.5);
+        let h = sampler.height(p.x, p.y);
+        let rise = [Vec2::X, Vec2::NEG_X, Vec2::Y, Vec2::NEG_Y]
+            .iter()
+            .map(|d| (sampler.height(p.x + d.x * r, p.y + d.y * r) - h).abs())
+            .fold(0.0, f32::max);
+        (rise / r).atan().to_degrees()
+    }
+
+    /// Longest run of sea (m) along the straight line from `a` to `b`.
+    pub fn longest_water_gap(&self, sampler: &TerrainSampler, a: Vec2, b: Vec2) -> f32 {
+        let len = a.distance(b);
+        let step = self.path_step.max(0.5);
+        let steps = (len / step).ceil().max(1.0) as u32;
+        let (mut run, mut longest) = (0.0f32, 0.0f32);
+        for i in 0..=steps {
+            let p = a.lerp(b, i as f32 / steps as f32);
+            if sampler.height(p.x, p.y) < self.water_level {
+                run += len / steps as f32;
+                longest = longest.max(run);
+            } else {
+                run = 0.0;
+            }
+        }
+        longest
+    }
+
+    /// Is the point `to` visible from `from` (terrain never rises above the sight line)?
+    pub fn line_of_sight(&self, sampler: &TerrainSampler, from: Vec3, to: Vec3) -> bool {
+        let len = from.xz().distance(to.xz());
+        let steps = (len / self.path_step.max(0.5)).ceil().max(1.0) as u32;
+        (1..steps).all(|i| {
+            let p = from.lerp(to, i as f32 / steps as f32);
+            sampler.height(p.x, p.z) <= p.y
+        })
+    }
+
+    /// Does `p` (ground `ground`) satisfy `rules` as the next target for a ball at `ball`?
+    pub fn accepts(&self, sampler: &TerrainSampler, rules: &RelocationRules, p: Vec2, ground: f32, ball: Vec3, target_height: f32) -> bool {
+        self.in_bounds(sampler, p)
+            && ground >= self.min_ground
+            && ball.xz().distance(p) <= rules.max_ball_distance
+            && self.landing_slope_deg(sampler, p) <= rules.max_slope_deg
+            && self.longest_water_gap(sampler, ball.xz(), p) <= rules.max_water_gap
+            && (!rules.line_of_sight
+                || self.line_of_sight(sampler, ball + Vec3::Y * 1.5, Vec3::new(p.x, ground + target_height, p.y)))
+    }
+}
+
+/// Pick the next target spot `distance` (min, max) away from `from`. Returns the XZ position, its
+/// ground height and the pass (0 = strict) that accepted it.
+pub fn find_relocation(
+    cfg: &RelocationConfig,
+    sampler: &TerrainSampler,
+    from: Vec2,
+    ball: Vec3,
+    distance: (f32, f32),
+    target_height: f32,
+    rng: &mut impl Rng,
+) -> Option<(Vec2, f32, usize)> {
+    let (min_dist, max_dist) = (distance.0, distance.1.max(distance.0 + 1.0));
+    for (pass, rules) in cfg.passes().iter().enumerate() {
+        for _ in 0..cfg.attempts_per_pass {
+            let dist = rng.gen_range(min_dist..max_dist);
+            let p = from + Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * dist;
+            let ground = sampler.height(p.x, p.y);
+            if cfg.accepts(sampler, rules, p, ground, ball, target_height) {
+                return Some((p, ground, pass));
+            }
+        }
+    }
+    None
+}
@@ -1,13 +1,20 @@
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
 use bevy::render::mesh::PrimitiveTopology;
-use bevy::pbr::{ExtendedMaterial, StandardMaterial};
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::render::texture::ImageSampler;
+use bevy::pbr::{ExtendedMaterial, NotShadowCaster, StandardMaterial};
 use bevy::render::alpha::AlphaMode;
 use std::collections::{HashMap, HashSet};
 use bevy::tasks::{AsyncComputeTaskPool, Task};
 use futures_lite::future::{block_on, poll_once};
 use crate::plugins::terrain_material::RealTerrainExtension;
 use crate::plugins::ball::Ball;
+use crate::plugins::biome::{Biome, BiomeMap, BIOME_MAP_RES};
+use crate::plugins::storage::StoragePaths;
+use crate::plugins::terrain_presets::{load_saved_preset, ActiveTerrainPreset, TerrainPreset};
+use crate::launch_options::LaunchOptions;
 use std::sync::Arc;
 
 /// Configuration for terrain. Retains legacy procedural fields for now (unused in heightmap mode).
@@ -50,6 +57,8 @@ pub struct TerrainConfig {
     pub lod_far_distance: f32,
     pub lod_mid_resolution: u32,
     pub lod_far_resolution: u32,
+    // Max heightfield collider resolution per chunk (chunk heights are downsampled to fit).
+    pub collider_resolution: u32,
     // Heightmap specific
     // World size of the heightmap square in meters (2 km x 2 km).
     pub heightmap_world_size: f32,
@@ -57,6 +66,14 @@ pub struct TerrainConfig {
     pub heightmap_max_height: f32,
     // Path to heightmap (red channel = height).
     pub heightmap_path: String,
+    // Baked world-space normal texture resolution (texels per side, 0 = disabled).
+    pub normal_map_resolution: u32,
+    // wasm: max milliseconds per frame spent meshing the in-flight chunk.
+    pub incremental_build_budget_ms: f32,
+    // Far shell: low-poly mesh over the whole heightmap drawn under streamed chunks (0 = disabled).
+    pub far_shell_resolution: u32,
+    // Extra meters the far shell sits below the conservative (min) surface estimate.
+    pub far_shell_drop: f32,
 }
 
 impl Default for TerrainConfig {
@@ -93,10 +110,15 @@ impl Default for TerrainConfig {
             lod_far_distance: 160.0 * 5.0,
             lod_mid_resolution: 48,
             lod_far_resolution: 24,
+            collider_resolution: 96,
             heightmap_world_size: 2000.0, // 2 km
             heightmap_max_height: 200.0,  // meters
             // Use a relative asset path. For wasm we embed the bytes directly (see Heightmap::load).
             heightmap_path: "assets/heightmaps/level1.png".to_string(),
+            normal_map_resolution: 1024, // ~2 m per texel over 2 km
+            incremental_build_budget_ms: 4.0,
+            far_shell_resolution: 128, // ~16 m quads
+            far_shell_drop: 1.5,
         }
     }
 }
@@ -133,9 +155,14 @@ impl Heightmap {
             red.push(raw[i]); // red channel
         }
         info!("Heightmap loaded: {} ({} x {})", path, w, h);
+        Self::from_red(w, h, red)
+    }
+
+    fn from_red(width: u32, height: u32, red: Vec<u8>) -> Self {
+        assert_eq!(red.len(), (width * height) as usize, "heightmap data size mismatch");
         Self {
-            width: w,
-            height: h,
+            width,
+            height,
             data_r: Arc::new(red),
         }
     }
@@ -168,17 +195,158 @@ impl Heightmap {
     }
 }
 
+/// Local terrain edit on top of the heightmap: flattens to `height` inside `radius`, blending
+/// back to the original ground over `falloff`. Managed through `TerrainDeformations`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TerrainStamp {
+    pub center: Vec2,
+    pub radius: f32,
+    pub falloff: f32,
+    pub height: f32,
+}
+
+impl TerrainStamp {
+    /// Outer radius of the stamp's influence.
+    pub fn reach(&self) -> f32 {
+        self.radius + self.falloff.max(0.0)
+    }
+
+    #[inline]
+    pub fn apply(&self, x: f32, z: f32, h: f32) -> f32 {
+        let d = Vec2::new(x, z).distance(self.center);
+        if d >= self.reach() {
+            return h;
+        }
+        let t = if d <= self.radius { 1.0 } else { 1.0 - (d - self.radius) / self.falloff };
+        let w = t * t * (3.0 - 2.0 * t); // smoothstep
+        h + (self.height - h) * w
+    }
+}
+
 /// Heightmap-based sampler.
 #[derive(Resource, Clone)]
 pub struct TerrainSampler {
     pub cfg: TerrainConfig,
     heightmap: Heightmap,
+    biomes: Arc<BiomeMap>,
+    stamps: Arc<Vec<TerrainStamp>>,
+    placeholder: bool,
+}
+
+/// Result of a terrain raycast.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TerrainHit {
+    pub position: Vec3,
+    pub normal: Vec3,
+    pub distance: f32, // along the (normalized) ray direction
 }
 
 impl TerrainSampler {
     pub fn new(cfg: TerrainConfig) -> Self {
         let hm = Heightmap::load(&cfg.heightmap_path);
-        Self { cfg, heightmap: hm }
+        Self::with_biomes(cfg, hm)
+    }
+
+    fn with_biomes(cfg: TerrainConfig, heightmap: Heightmap) -> Self {
+        let mut s = Self::placeholder(cfg);
+        s.heightmap = heightmap;
+        s.placeholder = false;
+        let biomes = BiomeMap::generate(
+            BIOME_MAP_RES,
+            s.cfg.heightmap_world_size,
+            s.cfg.heightmap_max_height * s.cfg.amplitude,
+            s.cfg.seed,
+            |x, z| s.height(x, z),
+        );
+        s.biomes = Arc::new(biomes);
+        s
+    }
+
+    /// Flat stand-in (uniform meadow) installed while the real heightmap decodes off the main
+    /// thread (`HeightmapDecode`). Chunks, vegetation and level gameplay wait for the real one.
+    pub fn placeholder(cfg: TerrainConfig) -> Self {
+        Self {
+            cfg,
+            heightmap: Heightmap::from_red(1, 1, vec![0]),
+            biomes: Arc::new(BiomeMap::uniform(Biome::Meadow)),
+            stamps: Arc::default(),
+            placeholder: true,
+        }
+    }
+
+    pub fn is_placeholder(&self) -> bool {
+        self.placeholder
+    }
+
+    /// Build a sampler from in-memory red channel bytes (row-major, `width * height`).
+    /// Used by tests / tools that need a known heightmap fixture instead of the level PNG.
+    pub fn from_heightmap_data(cfg: TerrainConfig, width: u32, height: u32, red: Vec<u8>) -> Self {
+        Self::with_biomes(cfg, Heightmap::from_red(width, height, red))
+    }
+
+    /// Raw heightmap (width, height, red channel bytes); used to key derived caches.
+    pub fn heightmap_data(&self) -> (u32, u32, &[u8]) {
+        (self.heightmap.width, self.heightmap.height, &self.heightmap.data_r)
+    }
+
+    /// Ray-march against the height function. `dir` need not be normalized.
+    /// Returns the first surface crossing within `heightmap_world_size * 2` meters.
+    pub fn raycast(&self, origin: Vec3, dir: Vec3) -> Option<TerrainHit> {
+        self.raycast_max(origin, dir, self.cfg.heightmap_world_size * 2.0)
+    }
+
+    /// Same as `raycast` but with an explicit maximum distance.
+    pub fn raycast_max(&self, origin: Vec3, dir: Vec3, max_distance: f32) -> Option<TerrainHit> {
+        let dir = dir.normalize_or_zero();
+        if dir == Vec3::ZERO || max_distance <= 0.0 {
+            return None;
+        }
+        // Starting below the surface counts as an immediate hit.
+        let start_h = self.height(origin.x, origin.z);
+        if origin.y <= start_h {
+            return Some(TerrainHit {
+                position: Vec3::new(origin.x, start_h, origin.z),
+                normal: self.normal(origin.x, origin.z),
+                distance: 0.0,
+            });
+        }
+
+        // Marching step bounds derived from heightmap texel size.
+        let texel = self.cfg.heightmap_world_size / (self.heightmap.width.max(2) - 1) as f32;
+        let min_step = (texel * 0.5).max(0.05);
+        let max_step = texel * 64.0;
+        let top = self.cfg.heightmap_max_height * self.cfg.amplitude;
+
+        let mut t_prev = 0.0;
+        let mut t = 0.0;
+        while t < max_distance {
+            let p = origin + dir * t;
+            // Rising above the tallest possible terrain means no hit is possible anymore.
+            if dir.y >= 0.0 && p.y > top {
+                return None;
+            }
+            let above = p.y - self.height(p.x, p.z);
+            if above <= 0.0 {
+                // Bisection refine between last free sample and this one.
+                let (mut lo, mut hi) = (t_prev, t);
+                for _ in 0..16 {
+                    let mid = 0.5 * (lo + hi);
+                    let m = origin + dir * mid;
+                    if m.y - self.height(m.x, m.z) > 0.0 { lo = mid; } else { hi = mid; }
+                }
+                let hit = origin + dir * hi;
+                let h = self.height(hit.x, hit.z);
+                return Some(TerrainHit {
+                    position: Vec3::new(hit.x, h, hit.z),
+                    normal: self.normal(hit.x, hit.z),
+                    distance: hi,
+                });
+            }
+            // Adaptive step: large when far above the surface (assumes slopes below ~60 degrees).
+            t_prev = t;
+            t += (above * 0.4).clamp(min_step, max_step);
+        }
+        None
     }
 
     fn sample_heightmap(&self, x: f32, z: f32) -> f32 {
@@ -196,7 +364,30 @@ impl TerrainSampler {
     }
 
     pub fn height(&self, x: f32, z: f32) -> f32 {
-        self.sample_heightmap(x, z)
+        let h = self.sample_heightmap(x, z);
+        if self.stamps.is_empty() {
+            return h;
+        }
+        self.stamps.iter().fold(h, |h, s| s.apply(x, z, h))
+    }
+
+    /// Terrain edits currently applied on top of the heightmap.
+    pub fn stamps(&self) -> &[TerrainStamp] {
+        &self.stamps
+    }
+
+    pub fn set_stamps(&mut self, stamps: Vec<TerrainStamp>) {
+        self.stamps = Arc::new(stamps);
+    }
+
+    /// Dominant biome at world (x, z).
+    pub fn biome(&self, x: f32, z: f32) -> Biome {
+        self.biomes.biome(x, z)
+    }
+
+    /// Smooth biome blend weights at world (x, z), indexed by `Biome::index`.
+    pub fn biome_weights(&self, x: f32, z: f32) -> [f32; 4] {
+        self.biomes.weights(x, z)
     }
 
     pub fn normal(&self, x: f32, z: f32) -> Vec3 {
@@ -220,17 +411,51 @@ pub fn sample_height_normal(x: f32, z: f32, sampler: &TerrainSampler) -> (f32, V
     (sampler.height(x, z), sampler.normal(x, z))
 }
 
+/// Static low-resolution mesh covering the whole heightmap, always rendered under the
+/// streamed chunks so the horizon never ends at `view_radius_chunks`. Quads lying entirely
+/// on loaded chunks are left out (see `far_shell_indices`).
+#[derive(Component)]
+pub struct FarTerrainShell;
+
+#[derive(Resource, Default)]
+struct FarShellState {
+    mesh: Option<Handle<Mesh>>,
+    entity: Option<Entity>,
+}
+
 #[derive(Component)]
 pub struct TerrainChunk {
     pub coord: IVec2,
     pub res: u32,
 }
 
+/// Sent when a streamed chunk has been built and inserted (also after LOD rebuilds, which
+/// replace the chunk entity). Per-chunk content (vegetation) attaches to `entity`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TerrainChunkReady {
+    pub coord: IVec2,
+    pub entity: Entity,
+}
+
 #[derive(Resource, Default)]
 pub struct LoadedChunks {
     pub map: HashMap<IVec2, Entity>,
 }
 
+/// Heightmap decode (PNG + biome map) running on the async compute pool; `TerrainSampler` is a
+/// placeholder until `finish_heightmap_decode` swaps the result in. A newer decode replaces
+/// (cancels) an older one.
+#[derive(Resource, Default)]
+pub struct HeightmapDecode {
+    task: Option<Task<TerrainSampler>>,
+}
+
+/// Point chunks stream around instead of the ball (the benchmark's camera route).
+#[derive(Resource, Default)]
+pub struct TerrainStreamFocus {
+    pub pos: Option<Vec3>,
+}
+
 #[derive(Resource, Default)]
 pub struct InProgressChunks {
     pub set: HashSet<IVec2>,
@@ -239,20 +464,21 @@ pub struct InProgressChunks {
 #[derive(Resource, Default)]
 struct TerrainGlobalMaterial {
     handle: Option<Handle<ExtendedMaterial<StandardMaterial, RealTerrainExtension>>>,
+    normal_map: Option<Handle<Image>>,
     min_h: f32,
     max_h: f32,
     created_logged: bool,
 }
 
-struct ChunkBuildResult {
-    coord: IVec2,
-    mesh: Mesh,
-    heights: Vec<f32>,
-    min_h: f32,
-    max_h: f32,
-    res: u32,
-    step: f32,
-    create_collider: bool,
+pub struct ChunkBuildResult {
+    pub coord: IVec2,
+    pub mesh: Mesh,
+    pub heights: Vec<f32>,
+    pub min_h: f32,
+    pub max_h: f32,
+    pub res: u32,
+    pub step: f32,
+    pub create_collider: bool,
 }
 
 #[derive(Component)]
@@ -264,12 +490,25 @@ struct ChunkBuildTask {
 pub struct TerrainPlugin;
 impl Plugin for TerrainPlugin {
     fn build(&self, app: &mut App) {
+        // Saved quality preset (if any) is applied before the sampler is built.
+        // --quality overrides it for the run (not saved).
+        let mut cfg = TerrainConfig::default();
+        let cli = app.world().get_resource::<LaunchOptions>().and_then(|o| o.quality);
+        let saved = cli.or(load_saved_preset(&StoragePaths::in_world(app.world())));
+        if let Some(p) = saved {
+            p.apply(&mut cfg);
+        }
         let app = app
-            .insert_resource(TerrainConfig::default())
+            .insert_resource(cfg)
+            .insert_resource(ActiveTerrainPreset(saved.or(Some(TerrainPreset::High))))
             .add_systems(PreStartup, init_sampler)
             .insert_resource(LoadedChunks::default())
+            .init_resource::<HeightmapDecode>()
             .insert_resource(InProgressChunks::default())
+            .init_resource::<TerrainStreamFocus>()
             .insert_resource(TerrainGlobalMaterial::default())
+            .init_resource::<FarShellState>()
+            .add_event::<TerrainChunkReady>()
             .add_systems(Startup, spawn_water);
 
         #[cfg(not(target_arch = "wasm32"))]
@@ -277,8 +516,11 @@ impl Plugin for TerrainPlugin {
             app.add_systems(
                 Update,
                 (
+                    finish_heightmap_decode.before(bake_terrain_normal_map),
+                    bake_terrain_normal_map.before(update_terrain_chunks),
                     update_terrain_chunks,
                     finalize_chunk_tasks.after(update_terrain_chunks),
+                    update_far_shell.after(finalize_chunk_tasks),
                     apply_terrain_config_changes.after(finalize_chunk_tasks),
                 ),
             );
@@ -289,10 +531,15 @@ impl Plugin for TerrainPlugin {
             app.add_systems(
                 Update,
                 (
+                    finish_heightmap_decode.before(bake_terrain_normal_map),
+                    bake_terrain_normal_map.before(update_terrain_chunks),
                     update_terrain_chunks,
-                    apply_terrain_config_changes.after(update_terrain_chunks),
+                    advance_incremental_chunk.after(update_terrain_chunks),
+                    update_far_shell.after(advance_incremental_chunk),
+                    apply_terrain_config_changes.after(advance_incremental_chunk),
                 ),
-            );
+            )
+            .init_resource::<IncrementalChunkBuild>();
         }
     }
 }
@@ -300,8 +547,10 @@ impl Plugin for TerrainPlugin {
 fn apply_terrain_config_changes(
     mut commands: Commands,
     cfg: Res<TerrainConfig>,
-    sampler: Res<TerrainSampler>,
+    mut sampler: ResMut<TerrainSampler>,
     mut loaded: ResMut<LoadedChunks>,
+    #[cfg(target_arch = "wasm32")] mut incremental: ResMut<IncrementalChunkBuild>,
+    #[cfg(target_arch = "wasm32")] mut in_progress: ResMut<InProgressChunks>,
     q_chunks: Query<Entity, With<TerrainChunk>>,
 ) {
     if !cfg.is_changed() {
@@ -313,18 +562,298 @@ fn apply_terrain_config_changes(
         || cfg.heightmap_world_size != sampler.cfg.heightmap_world_size
         || cfg.heightmap_path != sampler.cfg.heightmap_path
         || cfg.heightmap_max_height != sampler.cfg.heightmap_max_height
+        || cfg.normal_map_resolution != sampler.cfg.normal_map_resolution
+        || cfg.far_shell_resolution != sampler.cfg.far_shell_resolution
+        || cfg.far_shell_drop != sampler.cfg.far_shell_drop
     {
         for e in q_chunks.iter() {
             commands.entity(e).despawn_recursive();
         }
         loaded.map.clear();
-        commands.insert_resource(TerrainSampler::new(cfg.as_ref().clone()));
+        // Drop the partially meshed wasm chunk; it was sampled from the old heightmap.
+        #[cfg(target_arch = "wasm32")]
+        if let Some(b) = incremental.active.take() {
+            in_progress.set.remove(&b.coord);
+        }
+        begin_heightmap_decode(&mut commands, cfg.as_ref().clone());
         info!("Terrain config changed (heightmap related) -> clearing & regenerating terrain");
+    } else if cfg.resolution != sampler.cfg.resolution
+        || cfg.lod_mid_distance != sampler.cfg.lod_mid_distance
+        || cfg.lod_far_distance != sampler.cfg.lod_far_distance
+        || cfg.lod_mid_resolution != sampler.cfg.lod_mid_resolution
+        || cfg.lod_far_resolution != sampler.cfg.lod_far_resolution
+        || cfg.collider_resolution != sampler.cfg.collider_resolution
+    {
+        // Meshing-only change (e.g. quality preset): keep heightmap, biomes, normal map & far shell.
+        for e in q_chunks.iter() {
+            commands.entity(e).despawn_recursive();
+        }
+        loaded.map.clear();
+        #[cfg(target_arch = "wasm32")]
+        if let Some(b) = incremental.active.take() {
+            in_progress.set.remove(&b.coord);
+        }
+        sampler.bypass_change_detection().cfg = cfg.as_ref().clone();
+        info!("Terrain meshing config changed -> regenerating chunks");
+    }
+}
+
+/// Pick every `stride`-th sample of a `(res + 1)^2` height grid so the collider has at most
+/// `max_res` quads per side. Returns (heights, quads per side, stride).
+fn downsample_heightfield(heights: Vec<f32>, res: u32, max_res: u32) -> (Vec<f32>, u32, u32) {
+    let max_res = max_res.max(1);
+    // Smallest divisor of res that brings the grid under the limit (falls back to a single quad).
+    let stride = (1..=res).find(|s| res.is_multiple_of(*s) && res / s <= max_res).unwrap_or(res);
+    if stride == 1 {
+        return (heights, res, 1);
+    }
+    let row = res + 1;
+    let out_res = res / stride;
+    let mut out = Vec::with_capacity(((out_res + 1) * (out_res + 1)) as usize);
+    for j in (0..=res).step_by(stride as usize) {
+        for i in (0..=res).step_by(stride as usize) {
+            out.push(heights[(j * row + i) as usize]);
+        }
+    }
+    (out, out_res, stride)
+}
+
+pub(crate) fn init_sampler(mut commands: Commands, cfg: Res<TerrainConfig>) {
+    begin_heightmap_decode(&mut commands, cfg.clone());
+}
+
+// Decoding the full-resolution PNG (and generating biomes from it) on the main thread held up the
+// first frame; it runs as a task instead while a flat placeholder stands in.
+fn begin_heightmap_decode(commands: &mut Commands, cfg: TerrainConfig) {
+    commands.insert_resource(TerrainSampler::placeholder(cfg.clone()));
+    let task = AsyncComputeTaskPool::get().spawn(async move { TerrainSampler::new(cfg) });
+    commands.insert_resource(HeightmapDecode { task: Some(task) });
+}
+
+fn finish_heightmap_decode(
+    mut commands: Commands,
+    mut decode: ResMut<HeightmapDecode>,
+    cfg: Res<TerrainConfig>,
+) {
+    let Some(task) = decode.task.as_mut() else { return; };
+    let Some(mut sampler) = block_on(poll_once(task)) else { return; };
+    decode.task = None;
+    // Meshing-only settings may have changed while it decoded.
+    sampler.cfg = cfg.clone();
+    commands.insert_resource(sampler);
+}
+
+/// Bake a world-space normal texture (xyz * 0.5 + 0.5 in RGB) covering the whole heightmap.
+/// Row 0 is the -Z edge, column 0 the -X edge (uv = world.xz / world_size + 0.5).
+pub fn bake_normal_map(sampler: &TerrainSampler, res: u32) -> Image {
+    let res = res.max(2);
+    let world = sampler.cfg.heightmap_world_size;
+    let texel = world / (res - 1) as f32;
+    let mut data = Vec::with_capacity((res * res * 4) as usize);
+    for j in 0..res {
+        for i in 0..res {
+            let x = i as f32 * texel - world * 0.5;
+            let z = j as f32 * texel - world * 0.5;
+            let h_l = sampler.height(x - texel, z);
+            let h_r = sampler.height(x + texel, z);
+            let h_d = sampler.height(x, z - texel);
+            let h_u = sampler.height(x, z + texel);
+            let n = Vec3::new(h_l - h_r, 2.0 * texel, h_d - h_u).normalize_or(Vec3::Y);
+            let e = n * 0.5 + Vec3::splat(0.5);
+            data.extend_from_slice(&[
+                (e.x * 255.0).round() as u8,
+                (e.y * 255.0).round() as u8,
+                (e.z * 255.0).round() as u8,
+                255,
+            ]);
+        }
+    }
+    let mut image = Image::new(
+        Extent3d { width: res, height: res, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8Unorm, // linear: encodes vectors, not color
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    image.sampler = ImageSampler::linear();
+    image
+}
+
+/// Build the far shell: a `res` x `res` quad grid over the heightmap plus a perimeter skirt
+/// down to y = 0. Each vertex takes the minimum height around it (minus `drop`) so the coarse
+/// surface stays beneath the detailed chunks instead of poking through in valleys.
+/// Vertices: `(res + 1)^2` grid points, then one skirt base per perimeter point (`4 * res`).
+pub fn build_far_shell_mesh(sampler: &TerrainSampler, res: u32, drop: f32) -> Mesh {
+    let res = res.max(2);
+    let world = sampler.cfg.heightmap_world_size;
+    let half = world * 0.5;
+    let step = world / res as f32;
+    let row = res + 1;
+
+    let mut positions: Vec<[f32; 3]> = Vec::with_capacity((row * row + 4 * row) as usize);
+    let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(positions.capacity());
+    let mut colors: Vec<[f32; 4]> = Vec::with_capacity(positions.capacity());
+    for j in 0..=res {
+        for i in 0..=res {
+            let x = i as f32 * step - half;
+            let z = j as f32 * step - half;
+            let mut h = f32::MAX;
+            for dz in -2..=2 {
+                for dx in -2..=2 {
+                    h = h.min(sampler.height(x + dx as f32 * step * 0.5, z + dz as f32 * step * 0.5));
+                }
+            }
+            positions.push([x, h - drop, z]);
+            uvs.push([i as f32 / res as f32, j as f32 / res as f32]);
+            colors.push(sampler.biome_weights(x, z));
+        }
+    }
+    // Skirt: a wall from every perimeter point down to y = 0.
+    for top in far_shell_ring(res) {
+        let p = positions[top as usize];
+        positions.push([p[0], 0.0, p[2]]);
+        uvs.push(uvs[top as usize]);
+        colors.push(colors[top as usize]);
+    }
+    let indices = far_shell_indices(res, world, sampler.cfg.chunk_size, |_| false);
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, Default::default());
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    mesh.insert_indices(bevy::render::mesh::Indices::U32(indices));
+    mesh.compute_smooth_normals();
+    mesh
+}
+
+// Perimeter of the far shell grid, counter-clockwise seen from above.
+fn far_shell_ring(res: u32) -> Vec<u32> {
+    let row = res + 1;
+    let mut ring: Vec<u32> = Vec::with_capacity((res * 4) as usize);
+    ring.extend(0..res);                                       // -Z edge, +X
+    ring.extend((0..res).map(|j| j * row + res));              // +X edge, +Z
+    ring.extend((0..res).map(|i| res * row + (res - i)));      // +Z edge, -X
+    ring.extend((0..res).map(|j| (res - j) * row));            // -X edge, -Z
+    ring
+}
+
+/// Triangles of a `res` x `res` far shell over a `world` m square: the grid quads, except those
+/// lying entirely on chunks for which `covered(chunk coord)` holds, then the skirt.
+pub fn far_shell_indices(res: u32, world: f32, chunk_size: f32, covered: impl Fn(IVec2) -> bool) -> Vec<u32> {
+    let res = res.max(2);
+    let half = world * 0.5;
+    let step = world / res as f32;
+    let row = res + 1;
+    let chunk = |v: f32| (v / chunk_size).floor() as i32;
+    // Nudged inwards so a quad edge on a chunk border does not count the neighbour.
+    let eps = step * 1e-3;
+
+    let mut indices: Vec<u32> = Vec::with_capacity((res * res * 6 + res * 24) as usize);
+    for j in 0..res {
+        for i in 0..res {
+            let (x0, z0) = (i as f32 * step - half, j as f32 * step - half);
+            let (cx0, cx1) = (chunk(x0 + eps), chunk(x0 + step - eps));
+            let (cz0, cz1) = (chunk(z0 + eps), chunk(z0 + step - eps));
+            if (cz0..=cz1).all(|cz| (cx0..=cx1).all(|cx| covered(IVec2::new(cx, cz)))) {
+                continue;
+            }
+            let i0 = j * row + i;
+            let i1 = i0 + 1;
+            let i2 = i0 + row;
+            let i3 = i2 + 1;
+            indices.extend_from_slice(&[i0, i2, i1, i1, i2, i3]);
+        }
+    }
+    let ring = far_shell_ring(res);
+    let base = row * row;
+    let n = ring.len() as u32;
+    for k in 0..n {
+        let (t0, t1) = (ring[k as usize], ring[((k + 1) % n) as usize]);
+        let (b0, b1) = (base + k, base + (k + 1) % n);
+        indices.extend_from_slice(&[t0, t1, b0, t1, b1, b0]);
+    }
+    indices
+}
+
+// Build the far shell once the sampler exists (and again if it is rebuilt); spawn it as soon
+// as the shared terrain material has been created by the first streamed chunk. Whenever the set
+// of loaded chunks changes, the quads under them are cut out so they are not drawn twice.
+fn update_far_shell(
+    mut commands: Commands,
+    sampler: Res<TerrainSampler>,
+    loaded: Res<LoadedChunks>,
+    global_mat: Res<TerrainGlobalMaterial>,
+    mut state: ResMut<FarShellState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    if sampler.is_changed() && !sampler.is_placeholder() {
+        if let Some(e) = state.entity.take() {
+            commands.entity(e).despawn_recursive();
+        }
+        if let Some(old) = state.mesh.take() {
+            meshes.remove(&old);
+        }
+        let res = sampler.cfg.far_shell_resolution;
+        if res > 0 {
+            state.mesh = Some(meshes.add(build_far_shell_mesh(&sampler, res, sampler.cfg.far_shell_drop)));
+            info!("Far terrain shell built ({} x {} quads)", res, res);
+        }
+    }
+    if loaded.is_changed() || sampler.is_changed() {
+        let cfg = &sampler.cfg;
+        if let Some(mesh) = state.mesh.as_ref().and_then(|h| meshes.get_mut(h)) {
+            let indices = far_shell_indices(cfg.far_shell_resolution, cfg.heightmap_world_size, cfg.chunk_size, |c| {
+                loaded.map.contains_key(&c)
+            });
+            mesh.insert_indices(bevy::render::mesh::Indices::U32(indices));
+        }
+    }
+    if state.entity.is_some() {
+        return;
     }
+    let (Some(mesh), Some(material)) = (state.mesh.clone(), global_mat.handle.clone()) else { return; };
+    let e = commands
+        .spawn((
+            MaterialMeshBundle {
+                mesh,
+                material,
+                ..default()
+            },
+            NotShadowCaster,
+            FarTerrainShell,
+        ))
+        .id();
+    state.entity = Some(e);
+}
+
+fn apply_normal_map(ext: &mut RealTerrainExtension, normal_map: Option<Handle<Image>>, world_size: f32) {
+    ext.data.normal_map_strength = if normal_map.is_some() { 1.0 } else { 0.0 };
+    ext.data.normal_map_world_size = world_size;
+    ext.normal_map = normal_map;
 }
 
-fn init_sampler(mut commands: Commands, cfg: Res<TerrainConfig>) {
-    commands.insert_resource(TerrainSampler::new(cfg.clone()));
+// (Re)bake the normal texture whenever the sampler is (re)built so low-LOD chunks keep
+// heightmap-resolution lighting detail.
+fn bake_terrain_normal_map(
+    sampler: Res<TerrainSampler>,
+    mut global_mat: ResMut<TerrainGlobalMaterial>,
+    mut images: ResMut<Assets<Image>>,
+    mut terrain_mats: ResMut<Assets<ExtendedMaterial<StandardMaterial, RealTerrainExtension>>>,
+) {
+    if !sampler.is_changed() || sampler.is_placeholder() {
+        return;
+    }
+    let res = sampler.cfg.normal_map_resolution;
+    if let Some(old) = global_mat.normal_map.take() {
+        images.remove(&old);
+    }
+    if res > 0 {
+        global_mat.normal_map = Some(images.add(bake_normal_map(&sampler, res)));
+        info!("Terrain normal map baked ({} x {})", res, res);
+    }
+    if let Some(mat) = global_mat.handle.as_ref().and_then(|h| terrain_mats.get_mut(h)) {
+        apply_normal_map(&mut mat.extension, global_mat.normal_map.clone(), sampler.cfg.heightmap_world_size);
+    }
 }
 
 // Spawn a very large water plane at a fixed elevation (y = 25).
@@ -373,14 +902,16 @@ fn update_terrain_chunks(
     mut commands: Commands,
     mut loaded: ResMut<LoadedChunks>,
     mut in_progress: ResMut<InProgressChunks>,
-    #[cfg(target_arch = "wasm32")] mut meshes: ResMut<Assets<Mesh>>,
-    #[cfg(target_arch = "wasm32")] mut terrain_mats: ResMut<Assets<ExtendedMaterial<StandardMaterial, RealTerrainExtension>>>,
-    #[cfg(target_arch = "wasm32")] mut global_mat: ResMut<TerrainGlobalMaterial>,
+    #[cfg(target_arch = "wasm32")] mut incremental: ResMut<IncrementalChunkBuild>,
     sampler: Res<TerrainSampler>,
+    focus: Res<TerrainStreamFocus>,
     q_ball: Query<&Transform, With<Ball>>,
 ) {
+    if sampler.is_placeholder() {
+        return;
+    }
     let cfg = &sampler.cfg;
-    let center_pos = q_ball.get_single().map(|t| t.translation).unwrap_or(Vec3::ZERO);
+    let center_pos = focus.pos.or(q_ball.get_single().ok().map(|t| t.translation)).unwrap_or(Vec3::ZERO);
     let center_chunk = IVec2::new(
         (center_pos.x / cfg.chunk_size).floor() as i32,
         (center_pos.z / cfg.chunk_size).floor() as i32,
@@ -428,146 +959,15 @@ fn update_terrain_chunks(
             in_progress.set.insert(*coord);
         }
 
-        // On wasm build chunks synchronously (no AsyncComputeTaskPool multithreading)
+        // On wasm (no AsyncComputeTaskPool threads) a single chunk is built incrementally across
+        // frames by `advance_incremental_chunk` to avoid long main-thread hitches.
         #[cfg(target_arch = "wasm32")]
         {
-            let res = chosen_res;
-            let size = cfg.chunk_size;
-            let step = size / res as f32;
-
-            let verts_count = ((res + 1) * (res + 1)) as usize;
-            let mut positions: Vec<[f32; 3]> = Vec::with_capacity(verts_count);
-            let mut normals: Vec<[f32; 3]> = Vec::with_capacity(verts_count);
-            let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(verts_count);
-            let mut heights: Vec<f32> = Vec::with_capacity(verts_count);
-
-            let origin_x_chunk = coord.x as f32 * size;
-            let origin_z_chunk = coord.y as f32 * size;
-
-            for j in 0..=res {
-                for i in 0..=res {
-                    let world_x = origin_x_chunk + i as f32 * step;
-                    let world_z = origin_z_chunk + j as f32 * step;
-                    heights.push(sampler.height(world_x, world_z));
-                }
-            }
-            let (min_h, max_h) =
-                heights.iter().fold((f32::MAX, f32::MIN), |(mn, mx), &h| (mn.min(h), mx.max(h)));
-
-            for j in 0..=res {
-                for i in 0..=res {
-                    let idx = (j * (res + 1) + i) as usize;
-                    let h = heights[idx];
-
-                    let i_l = if i == 0 { i } else { i - 1 };
-                    let i_r = if i == res { i } else { i + 1 };
-                    let j_d = if j == 0 { j } else { j - 1 };
-                    let j_u = if j == res { j } else { j + 1 };
-                    let h_l = heights[(j * (res + 1) + i_l) as usize];
-                    let h_r = heights[(j * (res + 1) + i_r) as usize];
-                    let h_d = heights[(j_d * (res + 1) + i) as usize];
-                    let h_u = heights[(j_u * (res + 1) + i) as usize];
-                    let dxn = h_l - h_r;
-                    let dzn = h_d - h_u;
-                    let n = Vec3::new(dxn, 2.0 * step, dzn).normalize_or_zero();
-
-                    let local_x = i as f32 * step;
-                    let local_z = j as f32 * step;
-                    positions.push([local_x, h, local_z]);
-                    normals.push([n.x, n.y, n.z]);
-                    uvs.push([i as f32 / res as f32, j as f32 / res as f32]);
-                }
-            }
-
-            let mut indices: Vec<u32> = Vec::with_capacity((res * res * 6) as usize);
-            for j in 0..res {
-                for i in 0..res {
-                    let row = res + 1;
-                    let i0 = j * row + i;
-                    let i1 = i0 + 1;
-                    let i2 = i0 + row;
-                    let i3 = i2 + 1;
-                    indices.extend_from_slice(&[i0, i2, i1, i1, i2, i3]);
-                }
-            }
-
-            // Global material min/max update
-            if global_mat.min_h == 0.0 && global_mat.max_h == 0.0 && global_mat.handle.is_none() {
-                // sentinel
-            }
-            if global_mat.min_h == 0.0 && global_mat.handle.is_none() {
-                global_mat.min_h = f32::MAX;
-                global_mat.max_h = f32::MIN;
-            }
-            global_mat.min_h = global_mat.min_h.min(min_h);
-            global_mat.max_h = global_mat.max_h.max(max_h);
-
-            if global_mat.handle.is_none() {
-                let mut ext = RealTerrainExtension::default();
-                ext.data.min_height = min_h;
-                ext.data.max_height = max_h;
-                let base = StandardMaterial {
-                    base_color: Color::WHITE,
-                    perceptual_roughness: 0.85,
-                    metallic: 0.0,
-                    ..default()
-                };
-                let handle = terrain_mats.add(ExtendedMaterial { base, extension: ext });
-                global_mat.handle = Some(handle.clone());
-                if !global_mat.created_logged {
-                    info!("Terrain realistic material created (heightmap mode, wasm immediate)");
-                    global_mat.created_logged = true;
-                }
-            }
-            if let Some(handle) = &global_mat.handle {
-                if let Some(mat) = terrain_mats.get_mut(handle) {
-                    mat.extension.data.min_height = global_mat.min_h;
-                    mat.extension.data.max_height = global_mat.max_h;
-                }
+            if incremental.active.is_some() {
+                break;
             }
-            let material = global_mat.handle.as_ref().unwrap().clone();
-
-            let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, Default::default());
-            mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-            mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
-            mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
-            mesh.insert_indices(bevy::render::mesh::Indices::U32(indices));
-
-            let mesh_handle = meshes.add(mesh);
-
-            let origin_x = coord.x as f32 * res as f32 * step;
-            let origin_z = coord.y as f32 * res as f32 * step;
-
-            let mut ec = commands.spawn((
-                MaterialMeshBundle {
-                    mesh: mesh_handle,
-                    material,
-                    transform: Transform::from_translation(Vec3::new(origin_x, 0.0, origin_z)),
-                    ..default()
-                },
-                TerrainChunk { coord: *coord, res },
-            ));
-
-            if create_collider {
-                let nrows = (res + 1) as usize;
-                let ncols = (res + 1) as usize;
-                let collider = Collider::heightfield(
-                    heights,
-                    nrows,
-                    ncols,
-                    Vec3::new(step, 1.0, step),
-                );
-                ec.insert((
-                    RigidBody::Fixed,
-                    collider,
-                    Friction {
-                        coefficient: 1.0,
-                        combine_rule: CoefficientCombineRule::Average,
-                    },
-                ));
-            }
-
-            loaded.map.insert(*coord, ec.id());
+            incremental.active = Some(ChunkBuilder::new(*coord, chosen_res, cfg.chunk_size, create_collider));
+            in_progress.set.insert(*coord);
         }
 
         spawned_this_frame += 1;
@@ -586,91 +986,279 @@ fn update_terrain_chunks(
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-fn spawn_chunk_task(commands: &mut Commands, coord: IVec2, sampler: TerrainSampler, override_res: u32, create_collider: bool) {
-    let task_pool = AsyncComputeTaskPool::get();
-    let task = task_pool.spawn(async move {
-        let cfg = &sampler.cfg;
-        let res = override_res;
-        let size = cfg.chunk_size;
-        let step = size / res as f32;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChunkBuildPhase {
+    Heights,
+    Vertices,
+    Indices,
+    Done,
+}
+
+/// Resumable chunk mesher. Work is split into rows so callers can either run it to
+/// completion (native async task) or spread it across frames under a time budget (wasm).
+pub struct ChunkBuilder {
+    coord: IVec2,
+    res: u32,
+    step: f32,
+    origin: Vec2,
+    create_collider: bool,
+    phase: ChunkBuildPhase,
+    row: u32,
+    heights: Vec<f32>,
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    uvs: Vec<[f32; 2]>,
+    colors: Vec<[f32; 4]>, // biome blend weights
+    indices: Vec<u32>,
+}
 
+impl ChunkBuilder {
+    pub fn new(coord: IVec2, res: u32, chunk_size: f32, create_collider: bool) -> Self {
         let verts_count = ((res + 1) * (res + 1)) as usize;
-        let mut positions: Vec<[f32; 3]> = Vec::with_capacity(verts_count);
-        let mut normals: Vec<[f32; 3]> = Vec::with_capacity(verts_count);
-        let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(verts_count);
-        let mut heights: Vec<f32> = Vec::with_capacity(verts_count);
-
-        let origin_x = coord.x as f32 * size;
-        let origin_z = coord.y as f32 * size;
-
-        for j in 0..=res {
-            for i in 0..=res {
-                let world_x = origin_x + i as f32 * step;
-                let world_z = origin_z + j as f32 * step;
-                heights.push(sampler.height(world_x, world_z));
-            }
-        }
-        let (min_h, max_h) =
-            heights.iter().fold((f32::MAX, f32::MIN), |(mn, mx), &h| (mn.min(h), mx.max(h)));
-
-        for j in 0..=res {
-            for i in 0..=res {
-                let idx = (j * (res + 1) + i) as usize;
-                let h = heights[idx];
-
-                let i_l = if i == 0 { i } else { i - 1 };
-                let i_r = if i == res { i } else { i + 1 };
-                let j_d = if j == 0 { j } else { j - 1 };
-                let j_u = if j == res { j } else { j + 1 };
-                let h_l = heights[(j * (res + 1) + i_l) as usize];
-                let h_r = heights[(j * (res + 1) + i_r) as usize];
-                let h_d = heights[(j_d * (res + 1) + i) as usize];
-                let h_u = heights[(j_u * (res + 1) + i) as usize];
-                let dx = h_l - h_r;
-                let dz = h_d - h_u;
-                let n = Vec3::new(dx, 2.0 * step, dz).normalize_or_zero();
-
-                let local_x = i as f32 * step;
-                let local_z = j as f32 * step;
-                positions.push([local_x, h, local_z]);
-                normals.push([n.x, n.y, n.z]);
-                uvs.push([i as f32 / res as f32, j as f32 / res as f32]);
-            }
+        Self {
+            coord,
+            res,
+            step: chunk_size / res as f32,
+            origin: Vec2::new(coord.x as f32 * chunk_size, coord.y as f32 * chunk_size),
+            create_collider,
+            phase: ChunkBuildPhase::Heights,
+            row: 0,
+            heights: Vec::with_capacity(verts_count),
+            positions: Vec::with_capacity(verts_count),
+            normals: Vec::with_capacity(verts_count),
+            uvs: Vec::with_capacity(verts_count),
+            colors: Vec::with_capacity(verts_count),
+            indices: Vec::with_capacity((res * res * 6) as usize),
         }
+    }
 
-        let mut indices: Vec<u32> = Vec::with_capacity((res * res * 6) as usize);
-        for j in 0..res {
-            for i in 0..res {
-                let row = res + 1;
-                let i0 = j * row + i;
-                let i1 = i0 + 1;
-                let i2 = i0 + row;
-                let i3 = i2 + 1;
-                indices.extend_from_slice(&[i0, i2, i1, i1, i2, i3]);
+    pub fn is_done(&self) -> bool {
+        self.phase == ChunkBuildPhase::Done
+    }
+
+    /// Process up to `max_rows` rows of work. Returns true once the chunk is complete.
+    pub fn step_rows(&mut self, sampler: &TerrainSampler, max_rows: u32) -> bool {
+        let res = self.res;
+        let row_len = res + 1;
+        let mut budget = max_rows;
+        while budget > 0 && !self.is_done() {
+            budget -= 1;
+            let j = self.row;
+            match self.phase {
+                ChunkBuildPhase::Heights => {
+                    for i in 0..=res {
+                        let world_x = self.origin.x + i as f32 * self.step;
+                        let world_z = self.origin.y + j as f32 * self.step;
+                        self.heights.push(sampler.height(world_x, world_z));
+                    }
+                }
+                ChunkBuildPhase::Vertices => {
+                    for i in 0..=res {
+                        let h = self.heights[(j * row_len + i) as usize];
+                        let i_l = if i == 0 { i } else { i - 1 };
+                        let i_r = if i == res { i } else { i + 1 };
+                        let j_d = if j == 0 { j } else { j - 1 };
+                        let j_u = if j == res { j } else { j + 1 };
+                        let h_l = self.heights[(j * row_len + i_l) as usize];
+                        let h_r = self.heights[(j * row_len + i_r) as usize];
+                        let h_d = self.heights[(j_d * row_len + i) as usize];
+                        let h_u = self.heights[(j_u * row_len + i) as usize];
+                        let dx = h_l - h_r;
+                        let dz = h_d - h_u;
+                        let n = Vec3::new(dx, 2.0 * self.step, dz).normalize_or_zero();
+
+                        let local_x = i as f32 * self.step;
+                        let local_z = j as f32 * self.step;
+                        self.positions.push([local_x, h, local_z]);
+                        self.normals.push([n.x, n.y, n.z]);
+                        self.uvs.push([i as f32 / res as f32, j as f32 / res as f32]);
+                        self.colors.push(sampler.biome_weights(self.origin.x + local_x, self.origin.y + local_z));
+                    }
+                }
+                ChunkBuildPhase::Indices => {
+                    for i in 0..res {
+                        let i0 = j * row_len + i;
+                        let i1 = i0 + 1;
+                        let i2 = i0 + row_len;
+                        let i3 = i2 + 1;
+                        self.indices.extend_from_slice(&[i0, i2, i1, i1, i2, i3]);
+                    }
+                }
+                ChunkBuildPhase::Done => {}
+            }
+            self.row += 1;
+            let rows_in_phase = if self.phase == ChunkBuildPhase::Indices { res } else { res + 1 };
+            if self.row >= rows_in_phase {
+                self.row = 0;
+                self.phase = match self.phase {
+                    ChunkBuildPhase::Heights => ChunkBuildPhase::Vertices,
+                    ChunkBuildPhase::Vertices => ChunkBuildPhase::Indices,
+                    _ => ChunkBuildPhase::Done,
+                };
             }
         }
+        self.is_done()
+    }
 
-        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, Default::default());
-        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
-        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
-        mesh.insert_indices(bevy::render::mesh::Indices::U32(indices));
+    /// Run all remaining work and return the finished chunk.
+    pub fn build(mut self, sampler: &TerrainSampler) -> ChunkBuildResult {
+        self.step_rows(sampler, u32::MAX);
+        self.finish()
+    }
 
+    /// Consume a completed builder. Panics if called before `is_done`.
+    pub fn finish(self) -> ChunkBuildResult {
+        assert!(self.is_done(), "ChunkBuilder::finish called before completion");
+        let (min_h, max_h) =
+            self.heights.iter().fold((f32::MAX, f32::MIN), |(mn, mx), &h| (mn.min(h), mx.max(h)));
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, Default::default());
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, self.positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, self.normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, self.uvs);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, self.colors);
+        mesh.insert_indices(bevy::render::mesh::Indices::U32(self.indices));
         ChunkBuildResult {
-            coord,
+            coord: self.coord,
             mesh,
-            heights,
+            heights: self.heights,
             min_h,
             max_h,
-            res,
-            step,
-            create_collider,
+            res: self.res,
+            step: self.step,
+            create_collider: self.create_collider,
         }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn spawn_chunk_task(commands: &mut Commands, coord: IVec2, sampler: TerrainSampler, override_res: u32, create_collider: bool) {
+    let task_pool = AsyncComputeTaskPool::get();
+    let task = task_pool.spawn(async move {
+        ChunkBuilder::new(coord, override_res, sampler.cfg.chunk_size, create_collider).build(&sampler)
     });
     commands.spawn(ChunkBuildTask { task });
 }
 
+/// Single in-flight wasm chunk build, advanced a few rows per frame.
+#[cfg(target_arch = "wasm32")]
+#[derive(Resource, Default)]
+struct IncrementalChunkBuild {
+    active: Option<ChunkBuilder>,
+}
+
+#[cfg(target_arch = "wasm32")]
+fn advance_incremental_chunk(
+    mut commands: Commands,
+    mut incremental: ResMut<IncrementalChunkBuild>,
+    mut loaded: ResMut<LoadedChunks>,
+    mut in_progress: ResMut<InProgressChunks>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut terrain_mats: ResMut<Assets<ExtendedMaterial<StandardMaterial, RealTerrainExtension>>>,
+    mut global_mat: ResMut<TerrainGlobalMaterial>,
+    sampler: Res<TerrainSampler>,
+    mut ev_ready: EventWriter<TerrainChunkReady>,
+) {
+    let Some(builder) = incremental.active.as_mut() else { return; };
+    // bevy::utils::Instant is web-time backed on wasm (std::time::Instant panics there).
+    let start = bevy::utils::Instant::now();
+    let budget = sampler.cfg.incremental_build_budget_ms.max(0.1) / 1000.0;
+    // One row at a time until the frame budget is spent (always at least one row of progress).
+    while !builder.step_rows(&sampler, 1) {
+        if start.elapsed().as_secs_f32() >= budget {
+            return;
+        }
+    }
+    let result = incremental.active.take().unwrap().finish();
+    let coord = result.coord;
+    let e = commands.spawn_empty().id();
+    insert_built_chunk(commands.entity(e), result, &mut meshes, &mut terrain_mats, &mut global_mat, &sampler);
+    loaded.map.insert(coord, e);
+    in_progress.set.remove(&coord);
+    ev_ready.send(TerrainChunkReady { coord, entity: e });
+}
+
+// Shared finalize: create/update the global terrain material and attach mesh + collider.
+fn insert_built_chunk(
+    mut ec: bevy::ecs::system::EntityCommands,
+    result: ChunkBuildResult,
+    meshes: &mut Assets<Mesh>,
+    terrain_mats: &mut Assets<ExtendedMaterial<StandardMaterial, RealTerrainExtension>>,
+    global_mat: &mut TerrainGlobalMaterial,
+    sampler: &TerrainSampler,
+) {
+    let coord = result.coord;
+
+    if global_mat.min_h == 0.0 && global_mat.handle.is_none() {
+        global_mat.min_h = f32::MAX;
+        global_mat.max_h = f32::MIN;
+    }
+    global_mat.min_h = global_mat.min_h.min(result.min_h);
+    global_mat.max_h = global_mat.max_h.max(result.max_h);
+
+    if global_mat.handle.is_none() {
+        let mut ext = RealTerrainExtension::default();
+        ext.data.min_height = result.min_h;
+        ext.data.max_height = result.max_h;
+        apply_normal_map(&mut ext, global_mat.normal_map.clone(), sampler.cfg.heightmap_world_size);
+        let base = StandardMaterial {
+            base_color: Color::WHITE,
+            perceptual_roughness: 0.85,
+            metallic: 0.0,
+            ..default()
+        };
+        let handle = terrain_mats.add(ExtendedMaterial { base, extension: ext });
+        global_mat.handle = Some(handle.clone());
+        if !global_mat.created_logged {
+            info!("Terrain realistic material created (heightmap mode)");
+            global_mat.created_logged = true;
+        }
+    }
+    if let Some(handle) = &global_mat.handle {
+        if let Some(mat) = terrain_mats.get_mut(handle) {
+            mat.extension.data.min_height = global_mat.min_h;
+            mat.extension.data.max_height = global_mat.max_h;
+        }
+    }
+
+    let material = global_mat.handle.as_ref().unwrap().clone();
+    let mesh_handle = meshes.add(result.mesh);
+
+    let origin_x = coord.x as f32 * result.res as f32 * result.step;
+    let origin_z = coord.y as f32 * result.res as f32 * result.step;
+
+    ec.insert((
+        MaterialMeshBundle {
+            mesh: mesh_handle,
+            material,
+            transform: Transform::from_translation(Vec3::new(origin_x, 0.0, origin_z)),
+            ..default()
+        },
+        TerrainChunk { coord, res: result.res },
+    ));
+
+    if result.create_collider {
+        let (heights, collider_res, stride) =
+            downsample_heightfield(result.heights, result.res, sampler.cfg.collider_resolution);
+        let nrows = (collider_res + 1) as usize;
+        let ncols = (collider_res + 1) as usize;
+        let cell = result.step * stride as f32;
+        let collider = Collider::heightfield(
+            heights,
+            nrows,
+            ncols,
+            Vec3::new(cell, 1.0, cell),
+        );
+        ec.insert((
+            RigidBody::Fixed,
+            collider,
+            Friction {
+                coefficient: 1.0,
+                combine_rule: CoefficientCombineRule::Average,
+            },
+        ));
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 fn finalize_chunk_tasks(
     mut commands: Commands,
@@ -679,86 +1267,19 @@ fn finalize_chunk_tasks(
     mut meshes: ResMut<Assets<Mesh>>,
     mut terrain_mats: ResMut<Assets<ExtendedMaterial<StandardMaterial, RealTerrainExtension>>>,
     mut global_mat: ResMut<TerrainGlobalMaterial>,
+    sampler: Res<TerrainSampler>,
     mut q_tasks: Query<(Entity, &mut ChunkBuildTask)>,
+    mut ev_ready: EventWriter<TerrainChunkReady>,
 ) {
     for (e, mut build) in q_tasks.iter_mut() {
         if let Some(result) = block_on(poll_once(&mut build.task)) {
             let coord = result.coord;
-
-            if global_mat.min_h == 0.0 && global_mat.max_h == 0.0 && global_mat.handle.is_none() {
-                // sentinel - no action
-            }
-            if global_mat.min_h == 0.0 && global_mat.handle.is_none() {
-                global_mat.min_h = f32::MAX;
-                global_mat.max_h = f32::MIN;
-            }
-            global_mat.min_h = global_mat.min_h.min(result.min_h);
-            global_mat.max_h = global_mat.max_h.max(result.max_h);
-
-            if global_mat.handle.is_none() {
-                let mut ext = RealTerrainExtension::default();
-                ext.data.min_height = result.min_h;
-                ext.data.max_height = result.max_h;
-                let base = StandardMaterial {
-                    base_color: Color::WHITE,
-                    perceptual_roughness: 0.85,
-                    metallic: 0.0,
-                    ..default()
-                };
-                let handle = terrain_mats.add(ExtendedMaterial { base, extension: ext });
-                global_mat.handle = Some(handle.clone());
-                if !global_mat.created_logged {
-                    info!("Terrain realistic material created (heightmap mode)");
-                    global_mat.created_logged = true;
-                }
-            }
-            if let Some(handle) = &global_mat.handle {
-                if let Some(mat) = terrain_mats.get_mut(handle) {
-                    mat.extension.data.min_height = global_mat.min_h;
-                    mat.extension.data.max_height = global_mat.max_h;
-                }
-            }
-
-            let material = global_mat.handle.as_ref().unwrap().clone();
-            let mesh_handle = meshes.add(result.mesh);
-
-            let nrows = (result.res + 1) as usize;
-            let ncols = (result.res + 1) as usize;
-
-            let origin_x = coord.x as f32 * result.res as f32 * result.step;
-            let origin_z = coord.y as f32 * result.res as f32 * result.step;
-
             let mut ec = commands.entity(e);
             ec.remove::<ChunkBuildTask>();
-            ec.insert((
-                MaterialMeshBundle {
-                    mesh: mesh_handle,
-                    material,
-                    transform: Transform::from_translation(Vec3::new(origin_x, 0.0, origin_z)),
-                    ..default()
-                },
-                TerrainChunk { coord, res: result.res },
-            ));
-
-            if result.create_collider {
-                let collider = Collider::heightfield(
-                    result.heights,
-                    nrows,
-                    ncols,
-                    Vec3::new(result.step, 1.0, result.step),
-                );
-                ec.insert((
-                    RigidBody::Fixed,
-                    collider,
-                    Friction {
-                        coefficient: 1.0,
-                        combine_rule: CoefficientCombineRule::Average,
-                    },
-                ));
-            }
-
+            insert_built_chunk(ec, result, &mut meshes, &mut terrain_mats, &mut global_mat, &sampler);
             loaded.map.insert(coord, e);
             in_progress.set.remove(&coord);
+            ev_ready.send(TerrainChunkReady { coord, entity: e });
         }
     }
 }
@@ -0,0 +1,95 @@
+// Runtime terrain deformation.
+// Gameplay code adds / replaces / removes `TerrainStamp`s (flattened patches such as cup greens)
+// through the `TerrainDeformations` resource. The stamps are pushed into the `TerrainSampler`, so
+// physics, placement and newly built chunks see the edited ground, and loaded chunks overlapping
+// an edited area are dropped so the streamer rebuilds them. The baked normal map and far shell
+// are left as they are (edits are small and rebaking them would hitch).
+
+use bevy::prelude::*;
+
+use crate::plugins::terrain::{LoadedChunks, TerrainSampler, TerrainStamp};
+
+#[derive(Resource, Default, Debug)]
+pub struct TerrainDeformations {
+    stamps: Vec<(u64, TerrainStamp)>,
+    next_id: u64,
+    dirty: Vec<(Vec2, f32)>, // areas (centre, radius) whose chunks need rebuilding
+}
+
+impl TerrainDeformations {
+    pub fn add(&mut self, stamp: TerrainStamp) -> u64 {
+        self.next_id += 1;
+        self.stamps.push((self.next_id, stamp));
+        self.dirty.push((stamp.center, stamp.reach()));
+        self.next_id
+    }
+
+    /// Replace stamp `id` (or add it back if it was removed); returns the id in use.
+    pub fn replace(&mut self, id: u64, stamp: TerrainStamp) -> u64 {
+        match self.stamps.iter_mut().find(|(i, _)| *i == id) {
+            Some((_, s)) => {
+                self.dirty.push((s.center, s.reach()));
+                self.dirty.push((stamp.center, stamp.reach()));
+                *s = stamp;
+                id
+            }
+            None => self.add(stamp),
+        }
+    }
+
+    pub fn remove(&mut self, id: u64) -> Option<TerrainStamp> {
+        let i = self.stamps.iter().position(|(i, _)| *i == id)?;
+        let (_, s) = self.stamps.remove(i);
+        self.dirty.push((s.center, s.reach()));
+        Some(s)
+    }
+
+    pub fn stamps(&self) -> impl Iterator<Item = &TerrainStamp> {
+        self.stamps.iter().map(|(_, s)| s)
+    }
+}
+
+/// Does the chunk at `coord` (square of `chunk_size`) overlap the circle?
+pub fn chunk_overlaps(coord: IVec2, chunk_size: f32, center: Vec2, radius: f32) -> bool {
+    let min = coord.as_vec2() * chunk_size;
+    let nearest = center.clamp(min, min + Vec2::splat(chunk_size));
+    nearest.distance_squared(center) <= radius * radius
+}
+
+pub struct TerrainDeformPlugin;
+impl Plugin for TerrainDeformPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TerrainDeformations>()
+            .add_systems(Update, sync_terrain_deformations);
+    }
+}
+
+fn sync_terrain_deformations(
+    mut commands: Commands,
+    mut deform: ResMut<TerrainDeformations>,
+    sampler: Option<ResMut<TerrainSampler>>,
+    mut loaded: ResMut<LoadedChunks>,
+) {
+    let Some(mut sampler) = sampler else { return; };
+    // A rebuilt sampler (terrain config change) starts without stamps.
+    if !deform.is_changed() && !sampler.is_changed() {
+        return;
+    }
+    let wanted: Vec<TerrainStamp> = deform.stamps().copied().collect();
+    if sampler.stamps() != wanted.as_slice() {
+        // No change detection: the normal map / far shell / vegetation cache stay as they are.
+        sampler.bypass_change_detection().set_stamps(wanted);
+    }
+    if deform.dirty.is_empty() {
+        return;
+    }
+    let chunk_size = sampler.cfg.chunk_size;
+    let dirty = std::mem::take(&mut deform.bypass_change_detection().dirty);
+    loaded.map.retain(|coord, e| {
+        let hit = dirty.iter().any(|(c, r)| chunk_overlaps(*coord, chunk_size, *c, *r));
+        if hit {
+            commands.entity(*e).despawn_recursive();
+        }
+        !hit
+    });
+}
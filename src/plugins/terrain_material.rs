@@ -2,6 +2,16 @@ use bevy::prelude::*;
 use bevy::pbr::{ExtendedMaterial, MaterialExtension, StandardMaterial};
 use bevy::render::render_resource::{AsBindGroup, ShaderRef, ShaderType};
 
+use crate::plugins::biome::Biome;
+
+/// Default (meadow) palette: lowland, grass, rock, high rock.
+pub const DEFAULT_TERRAIN_COLORS: [Vec4; 4] = [
+    Vec4::new(0.11, 0.19, 0.09, 1.0), // lowland muddy moss (deep green)
+    Vec4::new(0.24, 0.37, 0.15, 1.0), // richer moss / grassy
+    Vec4::new(0.35, 0.34, 0.32, 1.0), // mid warm grey rock
+    Vec4::new(0.50, 0.47, 0.41, 1.0), // high rocky / sandy grey (replaces snow)
+];
+
 /// Uniform buffer for the realistic terrain extension.
 /// Matches WGSL struct RealTerrainExtendedMaterial.
 #[derive(Clone, Copy, Debug, ShaderType)]
@@ -32,6 +42,12 @@ pub struct RealTerrainUniform {
     pub macro_scale: f32,
     pub micro_scale: f32,
     pub animation_speed: f32,
+    // Baked world-space normal texture (see terrain::bake_normal_map); strength 0 = mesh normals only.
+    pub normal_map_strength: f32,
+    pub normal_map_world_size: f32,
+    // Per-biome palettes (biome * 4 + slot, biome order = Biome::ALL); blended by the
+    // biome weights stored in the terrain mesh vertex colors.
+    pub biome_colors: [Vec4; 16],
 }
 
 impl Default for RealTerrainUniform {
@@ -45,12 +61,7 @@ impl Default for RealTerrainUniform {
             time: 0.0,
             noise_scale: 0.0015,
             _pad1: 0.0,
-            colors: [
-                Vec4::new(0.11, 0.19, 0.09, 1.0), // lowland muddy moss (deep green)
-                Vec4::new(0.24, 0.37, 0.15, 1.0), // richer moss / grassy
-                Vec4::new(0.35, 0.34, 0.32, 1.0), // mid warm grey rock
-                Vec4::new(0.50, 0.47, 0.41, 1.0), // high rocky / sandy grey (replaces snow)
-            ],
+            colors: DEFAULT_TERRAIN_COLORS,
             roughness_lowland: 0.88,
             roughness_grass: 0.75,
             roughness_rock: 0.55,
@@ -67,15 +78,30 @@ impl Default for RealTerrainUniform {
             macro_scale: 0.18,
             micro_scale: 3.5,
             animation_speed: 0.0, // 0 = static (prevents temporal aliasing)
+            normal_map_strength: 0.0,
+            normal_map_world_size: 2000.0,
+            biome_colors: biome_palettes(),
         }
     }
 }
 
+fn biome_palettes() -> [Vec4; 16] {
+    let mut out = [Vec4::ZERO; 16];
+    for b in Biome::ALL {
+        let p = b.params().palette;
+        out[b.index() * 4..b.index() * 4 + 4].copy_from_slice(&p);
+    }
+    out
+}
+
 /// Extension type.
 #[derive(Asset, AsBindGroup, TypePath, Debug, Clone, Default)]
 pub struct RealTerrainExtension {
     #[uniform(100)]
     pub data: RealTerrainUniform,
+    #[texture(101)]
+    #[sampler(102)]
+    pub normal_map: Option<Handle<Image>>,
 }
 
 impl MaterialExtension for RealTerrainExtension {
@@ -107,3 +133,26 @@ fn advance_time(
         mat.extension.data.time = t;
     }
 }
+
+/// CPU approximation of the shader palette blend (lowland / grass / rock / high rock).
+/// `h_norm` is 0..1 height within the terrain range, `slope` is 0 (flat) .. 1 (vertical).
+/// `colors` is a palette in `RealTerrainUniform::colors` order (e.g. a biome palette).
+/// Used by tooling/UI that needs terrain-matching colors (e.g. the minimap).
+pub fn terrain_palette_color(u: &RealTerrainUniform, colors: [Vec4; 4], h_norm: f32, slope: f32) -> Vec3 {
+    let smooth = |e0: f32, e1: f32, x: f32| {
+        let t = ((x - e0) / (e1 - e0)).clamp(0.0, 1.0);
+        t * t * (3.0 - 2.0 * t)
+    };
+    let snow_w = smooth(u.snow_height_start, u.snow_height_end, h_norm);
+    let rock_w = smooth(u.rock_slope_start, u.rock_slope_start + 0.15, slope) * (1.0 - snow_w);
+    let lowland_w = (1.0 - smooth(0.15, 0.35, h_norm)) * (1.0 - snow_w);
+    let grass_w = (1.0 - (lowland_w + rock_w + snow_w)).max(0.0);
+    let sum = (lowland_w + grass_w + rock_w + snow_w).max(1e-4);
+    let c = colors;
+    let col = (c[0].truncate() * lowland_w
+        + c[1].truncate() * grass_w
+        + c[2].truncate() * rock_w
+        + c[3].truncate() * snow_w)
+        / sum;
+    (col * u.brightness).clamp(Vec3::ZERO, Vec3::ONE)
+}
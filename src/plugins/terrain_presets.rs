@@ -0,0 +1,110 @@
+// Named terrain quality presets (Low / Medium / High / Ultra).
+// A preset sets the interacting streaming knobs together (mesh resolution, LOD distances and
+// resolutions, view radius, collider resolution) and is persisted in terrain_preset.txt in the
+// data directory (see storage.rs; localStorage on the web). The sun's shadow quality follows the
+// selected preset too (shadow_settings.rs).
+
+use bevy::prelude::*;
+
+use crate::plugins::storage::StoragePaths;
+use crate::plugins::terrain::TerrainConfig;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerrainPreset {
+    Low,
+    Medium,
+    High,
+    Ultra,
+}
+
+impl TerrainPreset {
+    pub const ALL: [TerrainPreset; 4] = [
+        TerrainPreset::Low,
+        TerrainPreset::Medium,
+        TerrainPreset::High,
+        TerrainPreset::Ultra,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            TerrainPreset::Low => "Low",
+            TerrainPreset::Medium => "Medium",
+            TerrainPreset::High => "High",
+            TerrainPreset::Ultra => "Ultra",
+        }
+    }
+
+    pub fn from_name(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.name().eq_ignore_ascii_case(s.trim()))
+    }
+
+    /// Cycle to the next preset (wraps Ultra -> Low).
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|p| *p == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    /// Write this preset's values into `cfg`. High matches `TerrainConfig::default()`.
+    pub fn apply(self, cfg: &mut TerrainConfig) {
+        let chunk = cfg.chunk_size;
+        let (res, mid_d, far_d, mid_res, far_res, radius, collider) = match self {
+            TerrainPreset::Low => (48, 2.5, 3.5, 24, 12, 4, 24),
+            TerrainPreset::Medium => (64, 3.0, 4.5, 32, 16, 5, 32),
+            TerrainPreset::High => (96, 3.2, 5.0, 48, 24, 6, 96),
+            TerrainPreset::Ultra => (128, 4.0, 6.0, 64, 32, 8, 128),
+        };
+        cfg.resolution = res;
+        cfg.lod_mid_distance = chunk * mid_d;
+        cfg.lod_far_distance = chunk * far_d;
+        cfg.lod_mid_resolution = mid_res;
+        cfg.lod_far_resolution = far_res;
+        cfg.view_radius_chunks = radius;
+        cfg.collider_resolution = collider;
+    }
+
+    /// Whether `cfg` still has this preset's values; other fields (amplitude, ...) don't matter.
+    pub fn matches(self, cfg: &TerrainConfig) -> bool {
+        let mut wanted = cfg.clone();
+        self.apply(&mut wanted);
+        let knobs = |c: &TerrainConfig| {
+            (
+                c.resolution,
+                c.lod_mid_distance,
+                c.lod_far_distance,
+                c.lod_mid_resolution,
+                c.lod_far_resolution,
+                c.view_radius_chunks,
+                c.collider_resolution,
+            )
+        };
+        knobs(cfg) == knobs(&wanted)
+    }
+}
+
+/// Currently selected preset (`None` = custom values tweaked in the performance menu).
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct ActiveTerrainPreset(pub Option<TerrainPreset>);
+
+impl Default for ActiveTerrainPreset {
+    fn default() -> Self {
+        Self(Some(TerrainPreset::High))
+    }
+}
+
+const PRESET_FILE: &str = "terrain_preset.txt";
+
+pub fn load_saved_preset(paths: &StoragePaths) -> Option<TerrainPreset> {
+    paths.load_text(PRESET_FILE).and_then(|s| TerrainPreset::from_name(&s))
+}
+
+pub fn save_preset(paths: &StoragePaths, preset: TerrainPreset) {
+    paths.save_text(PRESET_FILE, &format!("{}\n", preset.name()));
+}
+
+/// Select a preset: update the live config (chunks regenerate) and persist the choice.
+pub fn select_preset(preset: TerrainPreset, cfg: &mut TerrainConfig, active: &mut ActiveTerrainPreset, paths: &StoragePaths) {
+    preset.apply(cfg);
+    active.0 = Some(preset);
+    save_preset(paths, preset);
+    info!("Terrain preset -> {}", preset.name());
+}
@@ -0,0 +1,268 @@
+// Texture pipeline: compressed variants and mip generation.
+// Standalone textures (the HDR sky) can ship pre-compressed next to the original as
+// `<dir>/compressed/<stem>.<format>.ktx2`, listed in assets/textures/compressed.ron. The GPU's
+// supported block formats pick the family (`BlockFormat::pick`: BC on desktop, ASTC then ETC2
+// on mobile / WebGL) and `TexturePipeline::load_image` loads the listed variant, or the original
+// when none is listed; a variant that fails to load is swapped for the original in every
+// material using it. KTX2 (zstd supercompressed) is read by Bevy's default features; .basis /
+// UASTC would need the basis-universal feature, so variants must already be in a GPU block format
+// and carry their own mips.
+// Images loaded from files with a single mip level (the uncompressed sky, textures inside the
+// .glb models) get a box-filtered mip chain when they arrive, which stops distant trees and the
+// sky dome from shimmering. Without a GPU (headless) nothing is compressed or mipped.
+
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use bevy::render::renderer::RenderDevice;
+use bevy::render::texture::CompressedImageFormats;
+use serde::Deserialize;
+
+pub const COMPRESSED_MANIFEST: &str = "assets/textures/compressed.ron";
+/// Smaller images (UI icons, ramps) are left alone.
+const MIN_MIP_SOURCE: u32 = 64;
+
+/// GPU block compression family of a KTX2 variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum BlockFormat {
+    Bc,   // BC6H (HDR) / BC7: desktop GPUs
+    Astc, // mobile, Apple silicon
+    Etc2, // WebGL 2 / older mobile
+}
+
+impl BlockFormat {
+    pub fn suffix(self) -> &'static str {
+        match self {
+            Self::Bc => "bc",
+            Self::Astc => "astc",
+            Self::Etc2 => "etc2",
+        }
+    }
+
+    /// Best family the GPU samples natively.
+    pub fn pick(supported: CompressedImageFormats) -> Option<Self> {
+        [(CompressedImageFormats::BC, Self::Bc), (CompressedImageFormats::ASTC_LDR, Self::Astc), (CompressedImageFormats::ETC2, Self::Etc2)]
+            .into_iter()
+            .find(|(flag, _)| supported.contains(*flag))
+            .map(|(_, format)| format)
+    }
+}
+
+/// assets/textures/compressed.ron: original path (under assets/) -> families with a variant.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CompressedTextures {
+    #[serde(default)]
+    pub textures: HashMap<String, Vec<BlockFormat>>,
+}
+
+impl CompressedTextures {
+    pub fn from_ron(data: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str::<Self>(data)
+    }
+
+    /// Read the manifest (embedded on wasm); no variants on any error.
+    pub fn load() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        let data: Option<String> = Some(include_str!("../../assets/textures/compressed.ron").to_string());
+        #[cfg(not(target_arch = "wasm32"))]
+        let data = fs::read_to_string(COMPRESSED_MANIFEST).ok();
+
+        match data.map(|d| Self::from_ron(&d)) {
+            Some(Ok(list)) => list,
+            Some(Err(e)) => {
+                error!("Failed to parse {COMPRESSED_MANIFEST}: {e}");
+                Self::default()
+            }
+            None => Self::default(),
+        }
+    }
+
+    /// `skymap/sky_1k.hdr` -> `skymap/compressed/sky_1k.bc.ktx2`.
+    pub fn variant_path(path: &str, format: BlockFormat) -> String {
+        let (dir, file) = path.rsplit_once('/').map_or(("", path), |(d, f)| (d, f));
+        let stem = file.rsplit_once('.').map_or(file, |(s, _)| s);
+        let name = format!("compressed/{stem}.{}.ktx2", format.suffix());
+        if dir.is_empty() { name } else { format!("{dir}/{name}") }
+    }
+
+    /// The variant of `path` for `format` if the manifest lists one, else `path`.
+    pub fn resolve(&self, path: &str, format: Option<BlockFormat>) -> String {
+        match format {
+            Some(f) if self.textures.get(path).is_some_and(|fs| fs.contains(&f)) => Self::variant_path(path, f),
+            _ => path.to_string(),
+        }
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct TexturePipeline {
+    pub format: Option<BlockFormat>, // None: originals only (no GPU, or no block formats)
+    pub generate_mips: bool,
+    pub compressed: CompressedTextures,
+    fallbacks: Vec<(Handle<Image>, String)>, // compressed handles still loading -> original path
+}
+
+impl TexturePipeline {
+    pub fn resolve(&self, path: &str) -> String {
+        self.compressed.resolve(path, self.format)
+    }
+
+    /// Load `path` through the pipeline (compressed variant when available).
+    pub fn load_image(&mut self, assets: &AssetServer, path: &str) -> Handle<Image> {
+        let resolved = self.resolve(path);
+        let handle = assets.load(resolved.clone());
+        if resolved != path {
+            self.fallbacks.push((handle.clone(), path.to_string()));
+        }
+        handle
+    }
+}
+
+/// Box-filter one mip level (odd edges repeat the last texel).
+pub fn downsample_rgba(width: u32, height: u32, texels: &[[f32; 4]]) -> (u32, u32, Vec<[f32; 4]>) {
+    let (w, h) = ((width / 2).max(1), (height / 2).max(1));
+    let at = |x: u32, y: u32| texels[(y.min(height - 1) * width + x.min(width - 1)) as usize];
+    let mut out = Vec::with_capacity((w * h) as usize);
+    for y in 0..h {
+        for x in 0..w {
+            let quad = [at(2 * x, 2 * y), at(2 * x + 1, 2 * y), at(2 * x, 2 * y + 1), at(2 * x + 1, 2 * y + 1)];
+            out.push(std::array::from_fn(|c| quad.iter().map(|t| t[c]).sum::<f32>() * 0.25));
+        }
+    }
+    (w, h, out)
+}
+
+fn srgb_to_linear(v: f32) -> f32 {
+    if v <= 0.04045 { v / 12.92 } else { ((v + 0.055) / 1.055).powf(2.4) }
+}
+
+fn linear_to_srgb(v: f32) -> f32 {
+    if v <= 0.0031308 { v * 12.92 } else { 1.055 * v.powf(1.0 / 2.4) - 0.055 }
+}
+
+/// Append a full mip chain to a single-level 2D RGBA8 / RGBA32F image. Returns false (image
+/// untouched) for other formats, arrays and images that already have mips.
+pub fn generate_mips(image: &mut Image) -> bool {
+    let desc = &image.texture_descriptor;
+    let (width, height) = (desc.size.width, desc.size.height);
+    let format = desc.format;
+    if desc.mip_level_count != 1 || desc.size.depth_or_array_layers != 1 || width < 2 && height < 2 {
+        return false;
+    }
+    let texel_count = (width * height) as usize;
+    let texels: Vec<[f32; 4]> = match format {
+        TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb if image.data.len() == texel_count * 4 => {
+            let srgb = format == TextureFormat::Rgba8UnormSrgb;
+            image
+                .data
+                .chunks_exact(4)
+                .map(|p| {
+                    let c = |i: usize| if srgb && i < 3 { srgb_to_linear(p[i] as f32 / 255.0) } else { p[i] as f32 / 255.0 };
+                    [c(0), c(1), c(2), c(3)]
+                })
+                .collect()
+        }
+        TextureFormat::Rgba32Float if image.data.len() == texel_count * 16 => image
+            .data
+            .chunks_exact(16)
+            .map(|p| std::array::from_fn(|i| f32::from_le_bytes([p[4 * i], p[4 * i + 1], p[4 * i + 2], p[4 * i + 3]])))
+            .collect(),
+        _ => return false,
+    };
+    let encode = |out: &mut Vec<u8>, t: &[f32; 4]| match format {
+        TextureFormat::Rgba32Float => t.iter().for_each(|v| out.extend_from_slice(&v.to_le_bytes())),
+        TextureFormat::Rgba8UnormSrgb => out.extend(
+            t.iter().enumerate().map(|(i, v)| ((if i < 3 { linear_to_srgb(*v) } else { *v }) * 255.0).round().clamp(0.0, 255.0) as u8),
+        ),
+        _ => out.extend(t.iter().map(|v| (v * 255.0).round().clamp(0.0, 255.0) as u8)),
+    };
+    let (mut w, mut h, mut level) = (width, height, texels);
+    let mut levels = 1;
+    while w > 1 || h > 1 {
+        (w, h, level) = downsample_rgba(w, h, &level);
+        level.iter().for_each(|t| encode(&mut image.data, t));
+        levels += 1;
+    }
+    image.texture_descriptor.mip_level_count = levels;
+    true
+}
+
+pub struct TexturePipelinePlugin;
+impl Plugin for TexturePipelinePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TexturePipeline { compressed: CompressedTextures::load(), ..default() })
+            .add_systems(Update, (fall_back_to_originals, generate_missing_mips));
+    }
+
+    // The render device (and with it the supported block formats) exists once the renderer is up.
+    fn finish(&self, app: &mut App) {
+        let supported = app.world().get_resource::<RenderDevice>().map(|d| CompressedImageFormats::from_features(d.features()));
+        let mut pipeline = app.world_mut().resource_mut::<TexturePipeline>();
+        pipeline.generate_mips = supported.is_some();
+        pipeline.format = supported.and_then(BlockFormat::pick);
+        info!(
+            "Texture pipeline: {} compressed textures listed, using {:?}",
+            pipeline.compressed.textures.len(),
+            pipeline.format.map(BlockFormat::suffix)
+        );
+    }
+}
+
+fn fall_back_to_originals(
+    mut pipeline: ResMut<TexturePipeline>,
+    assets: Res<AssetServer>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    if pipeline.fallbacks.is_empty() {
+        return;
+    }
+    let mut failed = Vec::new();
+    pipeline.fallbacks.retain(|(handle, original)| match assets.get_load_state(handle) {
+        Some(LoadState::Failed(_)) => {
+            failed.push((handle.clone(), original.clone()));
+            false
+        }
+        Some(LoadState::Loaded) => false,
+        _ => true,
+    });
+    for (handle, original) in failed {
+        warn!("Compressed texture for {original} failed to load; using the original");
+        let replacement: Handle<Image> = assets.load(original);
+        for (_, material) in materials.iter_mut() {
+            if material.base_color_texture.as_ref() == Some(&handle) {
+                material.base_color_texture = Some(replacement.clone());
+            }
+        }
+    }
+}
+
+// Only images read from files: render targets, font atlases and baked maps have no path.
+fn generate_missing_mips(
+    pipeline: Res<TexturePipeline>,
+    assets: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    mut events: EventReader<AssetEvent<Image>>,
+) {
+    if !pipeline.generate_mips {
+        events.clear();
+        return;
+    }
+    let added: Vec<AssetId<Image>> =
+        events.read().filter_map(|e| if let AssetEvent::Added { id } = e { Some(*id) } else { None }).collect();
+    for id in added {
+        let wanted = images.get(id).is_some_and(|i| {
+            let size = i.texture_descriptor.size;
+            i.texture_descriptor.mip_level_count == 1 && size.width.max(size.height) >= MIN_MIP_SOURCE
+        });
+        if !wanted || assets.get_path(id).is_none() {
+            continue;
+        }
+        if let Some(image) = images.get_mut(id) {
+            generate_mips(image);
+        }
+    }
+}
@@ -0,0 +1,377 @@
+// On-screen touch controls.
+// Spawned the first time a touch is seen (`MobileHudHint`): a FIRE button (hold to charge,
+// release to shoot), a camera stick, aim nudge arrows (small yaw steps) and a Reset View button
+// (turns the camera behind the ball towards the target, like T). Free-screen swipes still look
+// around and pinches still zoom, but no longer start a shot.
+// Touches are hit-tested in `PreUpdate` against the same layout the nodes are placed with
+// (`control_rects`), and the touches a control owns are skipped by the swipe / pinch camera and
+// by the shot input (FIRE touches excepted), so the gestures don't fight each other.
+
+use bevy::input::touch::{TouchInput, TouchPhase};
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy::window::PrimaryWindow;
+
+use crate::plugins::ball::Ball;
+use crate::plugins::camera::{yaw_behind, CameraAlign, CameraMode, OrbitCameraConfig, OrbitCameraState};
+use crate::plugins::hud::MobileHudHint;
+use crate::plugins::hud_layout::{HudLayoutConfig, SafeAreaInsets};
+use crate::plugins::app_state::AppState;
+use crate::plugins::spectate::InteractiveUi;
+use crate::plugins::target::{Target, TargetFocus};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TouchControl {
+    Fire,
+    Joystick,
+    NudgeLeft,
+    NudgeRight,
+    ResetView,
+}
+
+impl TouchControl {
+    pub const ALL: [TouchControl; 5] =
+        [TouchControl::Fire, TouchControl::Joystick, TouchControl::NudgeLeft, TouchControl::NudgeRight, TouchControl::ResetView];
+
+    fn label(self) -> &'static str {
+        match self {
+            TouchControl::Fire => "FIRE",
+            TouchControl::Joystick => "",
+            TouchControl::NudgeLeft => "<",
+            TouchControl::NudgeRight => ">",
+            TouchControl::ResetView => "Reset View",
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct TouchControlsConfig {
+    pub enabled: bool,
+    pub fire_size: f32,      // px (unscaled)
+    pub nudge_size: f32,
+    pub stick_size: f32,
+    pub margin: f32,         // px from the screen edges
+    pub stick_bottom: f32,   // px from the bottom (clears the minimap)
+    pub stick_dead_zone: f32,
+    pub stick_yaw_rate: f32,   // rad/s at full deflection
+    pub stick_pitch_rate: f32, // rad/s at full deflection
+    pub nudge_step: f32,       // rad per arrow tap
+}
+impl Default for TouchControlsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            fire_size: 110.0,
+            nudge_size: 56.0,
+            stick_size: 150.0,
+            margin: 24.0,
+            stick_bottom: 216.0,
+            stick_dead_zone: 0.15,
+            stick_yaw_rate: 1.8,
+            stick_pitch_rate: 1.0,
+            nudge_step: 1.5f32.to_radians(),
+        }
+    }
+}
+
+/// Edge placement of a control in unscaled UI px: horizontal offset from the left or right edge,
+/// offset from the bottom edge, and size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ControlPlacement {
+    pub from_left: bool,
+    pub x: f32,
+    pub bottom: f32,
+    pub size: Vec2,
+}
+
+impl TouchControlsConfig {
+    pub fn placement(&self, control: TouchControl) -> ControlPlacement {
+        let fire_bottom = self.margin + 46.0; // above the settings gear
+        let nudge_bottom = fire_bottom + (self.fire_size - self.nudge_size) * 0.5;
+        let nudge_x = self.margin + self.fire_size + 16.0;
+        match control {
+            TouchControl::Fire => ControlPlacement { from_left: false, x: self.margin, bottom: fire_bottom, size: Vec2::splat(self.fire_size) },
+            TouchControl::NudgeRight => ControlPlacement { from_left: false, x: nudge_x, bottom: nudge_bottom, size: Vec2::splat(self.nudge_size) },
+            TouchControl::NudgeLeft => ControlPlacement {
+                from_left: false,
+                x: nudge_x + self.nudge_size + 8.0,
+                bottom: nudge_bottom,
+                size: Vec2::splat(self.nudge_size),
+            },
+            TouchControl::ResetView => ControlPlacement {
+                from_left: false,
+                x: self.margin,
+                bottom: fire_bottom + self.fire_size + 12.0,
+                size: Vec2::new(self.fire_size, 40.0),
+            },
+            TouchControl::Joystick => ControlPlacement { from_left: true, x: 12.0, bottom: self.stick_bottom, size: Vec2::splat(self.stick_size) },
+        }
+    }
+}
+
+/// Screen rects (logical window px, y down) of every control for a window of `window` size under
+/// UI scale `scale`, with the safe-area insets applied the same way as `HudAnchor`.
+pub fn control_rects(cfg: &TouchControlsConfig, window: Vec2, scale: f32, safe: SafeAreaInsets) -> Vec<(TouchControl, Rect)> {
+    TouchControl::ALL
+        .iter()
+        .map(|&c| {
+            let p = cfg.placement(c);
+            let size = p.size * scale;
+            let x = if p.from_left { p.x * scale + safe.left } else { window.x - safe.right - p.x * scale - size.x };
+            let y = window.y - safe.bottom - p.bottom * scale - size.y;
+            (c, Rect::from_corners(Vec2::new(x, y), Vec2::new(x, y) + size))
+        })
+        .collect()
+}
+
+pub fn control_at(rects: &[(TouchControl, Rect)], pos: Vec2) -> Option<TouchControl> {
+    rects.iter().find(|(_, r)| r.contains(pos)).map(|(c, _)| *c)
+}
+
+/// Stick deflection (-1..1 per axis, +y = up) for a touch at `pos` on a stick occupying `rect`.
+pub fn stick_vector(rect: Rect, pos: Vec2, dead_zone: f32) -> Vec2 {
+    let half = rect.half_size().max(Vec2::splat(1.0));
+    let d = (pos - rect.center()) / half;
+    let v = Vec2::new(d.x, -d.y).clamp_length_max(1.0);
+    let len = v.length();
+    if len <= dead_zone {
+        return Vec2::ZERO;
+    }
+    v / len * ((len - dead_zone) / (1.0 - dead_zone).max(1e-3))
+}
+
+/// Live state of the on-screen controls.
+#[derive(Resource, Debug, Default)]
+pub struct TouchControls {
+    pub active: bool, // UI spawned (touch seen)
+    pub stick: Vec2,
+    owners: HashMap<u64, TouchControl>,
+    released: Vec<u64>,
+    pending_nudge: f32,
+    pending_reset: bool,
+}
+
+impl TouchControls {
+    /// Control a touch started on (`None` = free-screen touch).
+    pub fn owner(&self, id: u64) -> Option<TouchControl> {
+        self.owners.get(&id).copied()
+    }
+
+    /// Whether a touch may charge / fire a shot: any touch without the on-screen controls,
+    /// only FIRE touches with them.
+    pub fn allows_shot_touch(&self, id: u64) -> bool {
+        !self.active || self.owner(id) == Some(TouchControl::Fire)
+    }
+}
+
+#[derive(Component)]
+struct TouchControlsRoot;
+#[derive(Component)]
+struct TouchControlNode(TouchControl);
+#[derive(Component)]
+struct StickKnob;
+
+pub struct TouchControlsPlugin;
+impl Plugin for TouchControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TouchControlsConfig>()
+            .init_resource::<TouchControls>()
+            .add_systems(PreUpdate, claim_touches.after(InputSystem))
+            .add_systems(Update, (spawn_touch_controls, place_touch_controls, apply_touch_controls).chain());
+    }
+}
+
+fn claim_touches(
+    cfg: Res<TouchControlsConfig>,
+    layout: Option<Res<HudLayoutConfig>>,
+    ui_scale: Res<UiScale>,
+    mut controls: ResMut<TouchControls>,
+    mut ev_touch: EventReader<TouchInput>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+) {
+    // Ended touches stay owned for one frame so Update systems still see who they belonged to.
+    let released = std::mem::take(&mut controls.released);
+    for id in released {
+        if controls.owners.remove(&id) == Some(TouchControl::Joystick) {
+            controls.stick = Vec2::ZERO;
+        }
+    }
+    if !controls.active {
+        ev_touch.clear();
+        return;
+    }
+    let Ok(window) = q_window.get_single() else { return; };
+    let safe = layout.map(|l| l.safe_area).unwrap_or_default();
+    let rects = control_rects(&cfg, Vec2::new(window.width(), window.height()), ui_scale.0, safe);
+    let stick_rect = rects.iter().find(|(c, _)| *c == TouchControl::Joystick).map(|(_, r)| *r);
+    for ev in ev_touch.read() {
+        match ev.phase {
+            TouchPhase::Started => {
+                let Some(control) = control_at(&rects, ev.position) else { continue; };
+                controls.owners.insert(ev.id, control);
+                match control {
+                    TouchControl::NudgeLeft => controls.pending_nudge += 1.0,
+                    TouchControl::NudgeRight => controls.pending_nudge -= 1.0,
+                    TouchControl::ResetView => controls.pending_reset = true,
+                    TouchControl::Joystick => {
+                        if let Some(r) = stick_rect {
+                            controls.stick = stick_vector(r, ev.position, cfg.stick_dead_zone);
+                        }
+                    }
+                    TouchControl::Fire => {}
+                }
+            }
+            TouchPhase::Moved => {
+                if let (Some(TouchControl::Joystick), Some(r)) = (controls.owner(ev.id), stick_rect) {
+                    controls.stick = stick_vector(r, ev.position, cfg.stick_dead_zone);
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Canceled => {
+                if controls.owners.contains_key(&ev.id) {
+                    controls.released.push(ev.id);
+                }
+            }
+        }
+    }
+}
+
+fn spawn_touch_controls(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    cfg: Res<TouchControlsConfig>,
+    hint: Res<MobileHudHint>,
+    mut controls: ResMut<TouchControls>,
+) {
+    if controls.active || !hint.0 || !cfg.enabled {
+        return;
+    }
+    controls.active = true;
+    let font = assets.load("fonts/FiraSans-Bold.ttf");
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style { position_type: PositionType::Absolute, width: Val::Percent(100.0), height: Val::Percent(100.0), ..default() },
+                // Let touches through to the world (the controls hit-test themselves).
+                focus_policy: bevy::ui::FocusPolicy::Pass,
+                ..default()
+            },
+            TouchControlsRoot,
+            InteractiveUi,
+            Name::new("TouchControls"),
+        ))
+        .with_children(|root| {
+            for control in TouchControl::ALL {
+                let p = cfg.placement(control);
+                let round = if control == TouchControl::ResetView { 10.0 } else { p.size.x * 0.5 };
+                root.spawn((
+                    NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            width: Val::Px(p.size.x),
+                            height: Val::Px(p.size.y),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        background_color: BackgroundColor(match control {
+                            TouchControl::Fire => Color::srgba(0.9, 0.35, 0.2, 0.45),
+                            _ => Color::srgba(0.1, 0.1, 0.14, 0.35),
+                        }),
+                        border_color: BorderColor(Color::srgba(1.0, 1.0, 1.0, 0.4)),
+                        border_radius: BorderRadius::all(Val::Px(round)),
+                        ..default()
+                    },
+                    TouchControlNode(control),
+                ))
+                .with_children(|n| {
+                    if control == TouchControl::Joystick {
+                        n.spawn((
+                            NodeBundle {
+                                style: Style { position_type: PositionType::Absolute, width: Val::Px(52.0), height: Val::Px(52.0), ..default() },
+                                background_color: BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.35)),
+                                border_radius: BorderRadius::all(Val::Px(26.0)),
+                                ..default()
+                            },
+                            StickKnob,
+                        ));
+                    } else {
+                        n.spawn(TextBundle::from_section(
+                            control.label(),
+                            TextStyle { font: font.clone(), font_size: if control == TouchControl::Fire { 26.0 } else { 20.0 }, color: Color::WHITE },
+                        ));
+                    }
+                });
+            }
+        });
+}
+
+fn place_touch_controls(
+    cfg: Res<TouchControlsConfig>,
+    layout: Option<Res<HudLayoutConfig>>,
+    ui_scale: Res<UiScale>,
+    controls: Res<TouchControls>,
+    phase: Option<Res<State<AppState>>>,
+    mode: Res<CameraMode>,
+    mut q_root: Query<&mut Visibility, With<TouchControlsRoot>>,
+    mut q_nodes: Query<(&TouchControlNode, &mut Style), Without<StickKnob>>,
+    mut q_knob: Query<&mut Style, With<StickKnob>>,
+) {
+    let Ok(mut vis) = q_root.get_single_mut() else { return; };
+    let shown = cfg.enabled && matches!(phase.map(|p| *p.get()), Some(AppState::Playing)) && *mode == CameraMode::Orbit;
+    *vis = if shown { Visibility::Inherited } else { Visibility::Hidden };
+    if !shown {
+        return;
+    }
+    let safe = layout.map(|l| l.safe_area).unwrap_or_default();
+    let scale = ui_scale.0.max(0.01);
+    for (node, mut style) in &mut q_nodes {
+        let p = cfg.placement(node.0);
+        let inset = if p.from_left { safe.left } else { safe.right };
+        let x = Val::Px(p.x + inset / scale);
+        if p.from_left {
+            style.left = x;
+        } else {
+            style.right = x;
+        }
+        style.bottom = Val::Px(p.bottom + safe.bottom / scale);
+    }
+    if let Ok(mut knob) = q_knob.get_single_mut() {
+        let travel = (cfg.stick_size - 52.0) * 0.5;
+        knob.left = Val::Px(travel + controls.stick.x * travel);
+        knob.top = Val::Px(travel - controls.stick.y * travel);
+    }
+}
+
+fn apply_touch_controls(
+    time: Res<Time>,
+    cfg: Res<TouchControlsConfig>,
+    orbit_cfg: Res<OrbitCameraConfig>,
+    phase: Option<Res<State<AppState>>>,
+    mode: Res<CameraMode>,
+    focus: Option<Res<TargetFocus>>,
+    mut controls: ResMut<TouchControls>,
+    mut state: ResMut<OrbitCameraState>,
+    mut align: ResMut<CameraAlign>,
+    q_ball: Query<&Transform, With<Ball>>,
+    q_targets: Query<&Transform, (With<Target>, Without<Ball>)>,
+) {
+    let nudge = std::mem::take(&mut controls.pending_nudge);
+    let reset = std::mem::take(&mut controls.pending_reset);
+    if !controls.active || !matches!(phase.map(|p| *p.get()), Some(AppState::Playing)) || *mode != CameraMode::Orbit {
+        return;
+    }
+    let dt = time.delta_seconds();
+    if controls.stick != Vec2::ZERO || nudge != 0.0 {
+        align.target_yaw = None;
+        state.yaw += nudge * cfg.nudge_step - controls.stick.x * cfg.stick_yaw_rate * dt;
+        state.pitch = (state.pitch + controls.stick.y * cfg.stick_pitch_rate * dt).clamp(orbit_cfg.pitch_min, orbit_cfg.pitch_max);
+    }
+    if reset {
+        let target = focus.and_then(|f| f.entity).and_then(|e| q_targets.get(e).ok());
+        if let (Ok(ball), Some(target)) = (q_ball.get_single(), target) {
+            align.target_yaw = yaw_behind(ball.translation, target.translation);
+        }
+    }
+}
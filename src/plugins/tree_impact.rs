@@ -0,0 +1,174 @@
+// Ball vs tree trunk collision, plus knockdown reactions.
+// Candidate trees come from `VegetationGrid`, so a shot only tests the few trunks near the ball.
+// Every contact bounces the ball off the trunk. A contact faster than `crack_speed` sends a
+// `TreeHitEvent` (the crack SFX and leaf particles listen for it, and scoring bonuses can too)
+// and starts a damped spring wobble on the tree. A contact faster than `break_speed` fells the
+// tree instead: it despawns into branch debris and the ball carries on, slowed.
+
+use bevy::prelude::*;
+
+use crate::plugins::ball::{ball_physics, Ball, BallConditions, BallKinematic};
+use crate::plugins::core_sim::GameSet;
+use crate::plugins::app_state::gameplay_running;
+use crate::plugins::particles::TreeHitEvent;
+use crate::plugins::vegetation::{Tree, VegetationGrid};
+
+pub struct TreeImpactPlugin;
+impl Plugin for TreeImpactPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TreeImpactConfig::default())
+            .add_systems(FixedUpdate, ball_tree_collision.after(ball_physics).in_set(GameSet::Simulate).run_if(gameplay_running))
+            .add_systems(Update, animate_tree_wobble);
+    }
+}
+
+#[derive(Resource, Clone)]
+pub struct TreeImpactConfig {
+    pub trunk_radius: f32,    // trunk radius at tree scale 1.0 (m)
+    pub trunk_height: f32,    // collidable trunk height at tree scale 1.0 (m)
+    pub restitution: f32,     // fraction of the normal velocity kept on a bounce
+    pub crack_speed: f32,     // impact speed (m/s) for crack + leaves + wobble
+    pub break_speed: f32,     // impact speed (m/s) that fells the tree
+    pub fell_trees: bool,     // if false, fast hits wobble hard instead of felling
+    pub pass_through_keep: f32, // fraction of ball velocity kept when a tree is felled
+    pub wobble_per_speed: f32,  // initial tilt rate (rad/s) per m/s of impact speed
+    pub wobble_max_angle: f32,  // tilt clamp (rad)
+    pub spring_stiffness: f32,
+    pub spring_damping: f32,
+}
+impl Default for TreeImpactConfig {
+    fn default() -> Self {
+        Self {
+            trunk_radius: 0.35,
+            trunk_height: 4.0,
+            restitution: 0.35,
+            crack_speed: 4.0,
+            break_speed: 9.0,
+            fell_trees: true,
+            pass_through_keep: 0.55,
+            wobble_per_speed: 0.18,
+            wobble_max_angle: 0.45,
+            spring_stiffness: 30.0,
+            spring_damping: 3.5,
+        }
+    }
+}
+
+/// Tilt spring on a struck tree: rotates about a horizontal `axis` through the trunk base.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TreeWobble {
+    pub axis: Vec3,
+    pub angle: f32,
+    pub ang_vel: f32,
+    pub rest: Quat,
+}
+
+impl TreeWobble {
+    /// Advance the damped spring; returns false once it has settled.
+    pub fn step(&mut self, dt: f32, stiffness: f32, damping: f32, max_angle: f32) -> bool {
+        let acc = -stiffness * self.angle - damping * self.ang_vel;
+        self.ang_vel += acc * dt;
+        self.angle = (self.angle + self.ang_vel * dt).clamp(-max_angle, max_angle);
+        self.angle.abs() > 1e-3 || self.ang_vel.abs() > 1e-3
+    }
+
+    pub fn rotation(&self) -> Quat {
+        Quat::from_axis_angle(self.axis, self.angle) * self.rest
+    }
+}
+
+/// Ball sphere vs vertical trunk cylinder standing on `base`.
+/// Returns the horizontal push-out normal (trunk -> ball) and penetration depth.
+pub fn trunk_contact(ball: Vec3, ball_radius: f32, base: Vec3, radius: f32, height: f32) -> Option<(Vec3, f32)> {
+    if ball.y + ball_radius < base.y || ball.y - ball_radius > base.y + height {
+        return None;
+    }
+    let d = Vec2::new(ball.x - base.x, ball.z - base.z);
+    let reach = radius + ball_radius;
+    let dist2 = d.length_squared();
+    if dist2 >= reach * reach {
+        return None;
+    }
+    let dist = dist2.sqrt();
+    let n = if dist > 1e-5 { d / dist } else { Vec2::X };
+    Some((Vec3::new(n.x, 0.0, n.y), reach - dist))
+}
+
+fn ball_tree_collision(
+    mut commands: Commands,
+    cfg: Res<TreeImpactConfig>,
+    conditions: Res<BallConditions>,
+    mut grid: ResMut<VegetationGrid>,
+    mut q_ball: Query<(&mut Transform, &mut BallKinematic), With<Ball>>,
+    q_trees: Query<(&GlobalTransform, &Transform, Option<&TreeWobble>), (With<Tree>, Without<Ball>)>,
+    mut ev_tree: EventWriter<TreeHitEvent>,
+) {
+    let Ok((mut ball_t, mut kin)) = q_ball.get_single_mut() else { return; };
+    let p = ball_t.translation;
+    // Query radius covers the largest trunk (scale ~3 for hero trees) plus the ball.
+    let search = cfg.trunk_radius * 3.0 + kin.collider_radius;
+    for (e, _) in grid.trees_within(p.xz(), search) {
+        let Ok((gt, local, wobble)) = q_trees.get(e) else { continue; };
+        let (scale, _, base) = gt.to_scale_rotation_translation();
+        let Some((n, depth)) = trunk_contact(
+            ball_t.translation,
+            kin.collider_radius,
+            base,
+            cfg.trunk_radius * scale.x,
+            cfg.trunk_height * scale.y,
+        ) else {
+            continue;
+        };
+        let vn = kin.vel.dot(n);
+        if vn >= 0.0 {
+            ball_t.translation += n * depth; // already separating; just resolve overlap
+            continue;
+        }
+        let speed = -vn;
+        let contact = base.with_y(ball_t.translation.y) + n * cfg.trunk_radius * scale.x;
+
+        if cfg.fell_trees && speed >= cfg.break_speed {
+            kin.vel *= cfg.pass_through_keep;
+            grid.remove(e);
+            commands.entity(e).despawn_recursive();
+            ev_tree.send(TreeHitEvent { tree: e, pos: contact, speed, knocked_down: true });
+            continue;
+        }
+
+        ball_t.translation += n * depth;
+        kin.vel -= (1.0 + cfg.restitution * conditions.bounce) * vn * n;
+        if speed < cfg.crack_speed {
+            continue;
+        }
+        // Tilt away from the ball: axis is horizontal, perpendicular to the impact direction.
+        let axis = Vec3::Y.cross(-n).normalize_or_zero();
+        if axis == Vec3::ZERO {
+            continue;
+        }
+        let rest = wobble.map_or(local.rotation, |w| w.rest);
+        commands.entity(e).insert(TreeWobble {
+            axis,
+            angle: wobble.map_or(0.0, |w| w.angle),
+            ang_vel: speed * cfg.wobble_per_speed,
+            rest,
+        });
+        ev_tree.send(TreeHitEvent { tree: e, pos: contact, speed, knocked_down: false });
+    }
+}
+
+fn animate_tree_wobble(
+    mut commands: Commands,
+    time: Res<Time>,
+    cfg: Res<TreeImpactConfig>,
+    mut q: Query<(Entity, &mut Transform, &mut TreeWobble)>,
+) {
+    let dt = time.delta_seconds().min(1.0 / 20.0);
+    for (e, mut t, mut w) in &mut q {
+        if w.step(dt, cfg.spring_stiffness, cfg.spring_damping, cfg.wobble_max_angle) {
+            t.rotation = w.rotation();
+        } else {
+            t.rotation = w.rest;
+            commands.entity(e).remove::<TreeWobble>();
+        }
+    }
+}
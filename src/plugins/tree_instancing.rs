@@ -0,0 +1,453 @@
+// True GPU instancing for vegetation.
+// Trees spawned with `TreeInstanceOf` (instead of a PbrBundle) are grouped per (mesh, material)
+// into `TreeInstanceBatch` entities holding one world matrix + tint per visible tree. The render
+// app uploads each batch as a per-instance vertex buffer and draws it with a single instanced
+// draw in the opaque pass (shaders/tree_instancing.wgsl, lit with the regular PBR functions).
+//  - Tree entities keep their Transform / Visibility, so the vegetation cull, fade and LOD
+//    systems keep working unchanged; batches are rebuilt only when one of them changes.
+//  - Batches are not frustum culled on the CPU (the GPU clips), and do not cast shadows.
+//  - Wind sway reads `CurrentTreeWind` (tree_wind.rs) from one uniform shared by all batches.
+
+use bevy::core_pipeline::core_3d::{Opaque3d, Opaque3dBinKey};
+use bevy::core_pipeline::tonemapping::{DebandDither, Tonemapping};
+use bevy::ecs::query::{QueryItem, ROQueryItem};
+use bevy::ecs::system::{lifetimeless::SRes, SystemParamItem};
+use bevy::pbr::{
+    tonemapping_pipeline_key, MeshPipeline, MeshPipelineKey, SetMeshViewBindGroup, ShadowFilteringMethod,
+};
+use bevy::prelude::*;
+use bevy::render::extract_component::{ExtractComponent, ExtractComponentPlugin};
+use bevy::render::mesh::{GpuBufferInfo, GpuMesh, MeshVertexBufferLayoutRef};
+use bevy::render::render_asset::RenderAssets;
+use bevy::render::render_phase::{
+    AddRenderCommand, BinnedRenderPhaseType, DrawFunctions, PhaseItem, RenderCommand, RenderCommandResult,
+    SetItemPipeline, TrackedRenderPass, ViewBinnedRenderPhases,
+};
+use bevy::render::render_resource::binding_types::{sampler, texture_2d, uniform_buffer};
+use bevy::render::render_resource::{
+    BindGroup, BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, Buffer, BufferInitDescriptor,
+    BufferUsages, PipelineCache, RenderPipelineDescriptor, SamplerBindingType, ShaderStages,
+    SpecializedMeshPipeline, SpecializedMeshPipelineError, SpecializedMeshPipelines, TextureSampleType,
+    UniformBuffer, VertexAttribute, VertexBufferLayout, VertexFormat, VertexStepMode,
+};
+use bevy::render::renderer::{RenderDevice, RenderQueue};
+use bevy::render::texture::{FallbackImage, GpuImage};
+use bevy::render::view::{ExtractedView, VisibilitySystems};
+use bevy::render::{Render, RenderApp, RenderSet};
+use bevy::transform::TransformSystem;
+use bytemuck::{Pod, Zeroable};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::plugins::tree_wind::{CurrentTreeWind, TreeWindUniform};
+
+/// First vertex shader location used by the instance buffer (mesh attributes use 0..=7).
+const INSTANCE_LOCATION: u32 = 8;
+
+/// Per-instance GPU data: world matrix columns + tint (material base color).
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+#[repr(C)]
+pub struct TreeInstanceData {
+    pub model: [[f32; 4]; 4],
+    pub color: [f32; 4],
+}
+
+impl TreeInstanceData {
+    pub fn new(world: Mat4, color: LinearRgba) -> Self {
+        Self { model: world.to_cols_array_2d(), color: color.to_f32_array() }
+    }
+}
+
+/// Marks a tree drawn through a GPU instance batch (mesh/material are not rendered directly).
+#[derive(Component, Clone)]
+pub struct TreeInstanceOf {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+/// One instanced draw: every visible tree sharing a (mesh, material) pair.
+#[derive(Component, Clone)]
+pub struct TreeInstanceBatch {
+    pub mesh: Handle<Mesh>,
+    pub texture: Option<Handle<Image>>,
+    pub instances: Arc<Vec<TreeInstanceData>>,
+    generation: u64,
+}
+
+impl TreeInstanceBatch {
+    pub fn len(&self) -> usize {
+        self.instances.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instances.is_empty()
+    }
+}
+
+/// Render-world copy of a batch (instances shared, not copied).
+#[derive(Component)]
+pub struct ExtractedTreeBatch {
+    mesh: AssetId<Mesh>,
+    texture: Option<AssetId<Image>>,
+    instances: Arc<Vec<TreeInstanceData>>,
+    generation: u64,
+}
+
+impl ExtractComponent for TreeInstanceBatch {
+    type QueryData = &'static TreeInstanceBatch;
+    type QueryFilter = ();
+    type Out = ExtractedTreeBatch;
+
+    fn extract_component(batch: QueryItem<'_, Self::QueryData>) -> Option<ExtractedTreeBatch> {
+        Some(ExtractedTreeBatch {
+            mesh: batch.mesh.id(),
+            texture: batch.texture.as_ref().map(|t| t.id()),
+            instances: batch.instances.clone(),
+            generation: batch.generation,
+        })
+    }
+}
+
+type BatchKey = (AssetId<Mesh>, AssetId<StandardMaterial>);
+
+#[derive(Resource, Default)]
+struct TreeBatchIndex(HashMap<BatchKey, Entity>);
+
+pub struct TreeInstancingPlugin;
+impl Plugin for TreeInstancingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TreeBatchIndex>()
+            .add_plugins(ExtractComponentPlugin::<TreeInstanceBatch>::default())
+            .add_systems(
+                PostUpdate,
+                sync_tree_batches
+                    .after(TransformSystem::TransformPropagate)
+                    .after(VisibilitySystems::VisibilityPropagate),
+            );
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else { return; };
+        render_app
+            .init_resource::<PreparedTreeBatches>()
+            .init_resource::<TreeWindBuffer>()
+            .init_resource::<SpecializedMeshPipelines<TreeInstancingPipeline>>()
+            .add_render_command::<Opaque3d, DrawTreeInstanced>()
+            .add_systems(
+                Render,
+                (
+                    prepare_tree_wind.in_set(RenderSet::PrepareResources),
+                    prepare_tree_batches.in_set(RenderSet::PrepareBindGroups),
+                    queue_tree_batches.in_set(RenderSet::QueueMeshes),
+                ),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app.init_resource::<TreeInstancingPipeline>();
+        }
+    }
+}
+
+// ---------------- Main world: batch building ----------------
+
+fn sync_tree_batches(
+    mut commands: Commands,
+    mut index: ResMut<TreeBatchIndex>,
+    materials: Res<Assets<StandardMaterial>>,
+    q_changed: Query<(), (With<TreeInstanceOf>, Or<(Changed<GlobalTransform>, Changed<InheritedVisibility>)>)>,
+    mut removed: RemovedComponents<TreeInstanceOf>,
+    q_trees: Query<(&TreeInstanceOf, &GlobalTransform, &InheritedVisibility)>,
+    mut q_batches: Query<&mut TreeInstanceBatch>,
+) {
+    let removed_any = removed.read().count() > 0;
+    if q_changed.is_empty() && !removed_any {
+        return;
+    }
+
+    let mut groups: HashMap<BatchKey, (&TreeInstanceOf, LinearRgba, Vec<TreeInstanceData>)> = HashMap::new();
+    for (of, gt, vis) in &q_trees {
+        let (_, color, instances) = groups.entry((of.mesh.id(), of.material.id())).or_insert_with(|| {
+            let color = materials.get(&of.material).map_or(LinearRgba::WHITE, |m| m.base_color.into());
+            (of, color, Vec::new())
+        });
+        if vis.get() {
+            instances.push(TreeInstanceData::new(gt.compute_matrix(), *color));
+        }
+    }
+
+    // Batches whose trees are all gone keep their entity with an empty instance list.
+    for (key, &e) in index.0.iter() {
+        if !groups.contains_key(key) {
+            if let Ok(mut batch) = q_batches.get_mut(e) {
+                if !batch.is_empty() {
+                    batch.instances = Arc::new(Vec::new());
+                    batch.generation += 1;
+                }
+            }
+        }
+    }
+
+    for (key, (of, _, instances)) in groups {
+        if let Some(mut batch) = index.0.get(&key).and_then(|&e| q_batches.get_mut(e).ok()) {
+            batch.instances = Arc::new(instances);
+            batch.generation += 1;
+            continue;
+        }
+        let texture = materials.get(&of.material).and_then(|m| m.base_color_texture.clone());
+        let e = commands
+            .spawn((
+                TreeInstanceBatch { mesh: of.mesh.clone(), texture, instances: Arc::new(instances), generation: 0 },
+                Name::new("TreeInstanceBatch"),
+            ))
+            .id();
+        index.0.insert(key, e);
+    }
+}
+
+// ---------------- Render world ----------------
+
+#[derive(Resource)]
+struct TreeInstancingPipeline {
+    shader: Handle<Shader>,
+    mesh_pipeline: MeshPipeline,
+    material_layout: BindGroupLayout,
+}
+
+impl FromWorld for TreeInstancingPipeline {
+    fn from_world(world: &mut World) -> Self {
+        let material_layout = world.resource::<RenderDevice>().create_bind_group_layout(
+            "tree_instancing_material_layout",
+            &BindGroupLayoutEntries::sequential(
+                ShaderStages::VERTEX_FRAGMENT,
+                (
+                    texture_2d(TextureSampleType::Float { filterable: true }),
+                    sampler(SamplerBindingType::Filtering),
+                    uniform_buffer::<TreeWindUniform>(false),
+                ),
+            ),
+        );
+        Self {
+            shader: world.load_asset("shaders/tree_instancing.wgsl"),
+            mesh_pipeline: world.resource::<MeshPipeline>().clone(),
+            material_layout,
+        }
+    }
+}
+
+impl SpecializedMeshPipeline for TreeInstancingPipeline {
+    type Key = MeshPipelineKey;
+
+    fn specialize(
+        &self,
+        key: Self::Key,
+        layout: &MeshVertexBufferLayoutRef,
+    ) -> Result<RenderPipelineDescriptor, SpecializedMeshPipelineError> {
+        let mut descriptor = self.mesh_pipeline.specialize(key, layout)?;
+        descriptor.label = Some("tree_instancing_pipeline".into());
+        // Group 0 = view bindings; group 1 = base color texture + wind (no per-mesh uniform needed).
+        descriptor.layout.truncate(1);
+        descriptor.layout.push(self.material_layout.clone());
+        descriptor.vertex.shader = self.shader.clone();
+        descriptor.vertex.buffers.push(VertexBufferLayout {
+            array_stride: std::mem::size_of::<TreeInstanceData>() as u64,
+            step_mode: VertexStepMode::Instance,
+            attributes: (0..5u32)
+                .map(|i| VertexAttribute {
+                    format: VertexFormat::Float32x4,
+                    offset: i as u64 * VertexFormat::Float32x4.size(),
+                    shader_location: INSTANCE_LOCATION + i,
+                })
+                .collect(),
+        });
+        if let Some(fragment) = descriptor.fragment.as_mut() {
+            fragment.shader = self.shader.clone();
+        }
+        Ok(descriptor)
+    }
+}
+
+struct PreparedTreeBatch {
+    mesh: AssetId<Mesh>,
+    generation: u64,
+    buffer: Buffer,
+    count: u32,
+    bind_group: BindGroup,
+}
+
+#[derive(Resource, Default)]
+struct PreparedTreeBatches(HashMap<Entity, PreparedTreeBatch>);
+
+// Fixed-size uniform: allocated on the first write and then updated in place, so the cached
+// batch bind groups referencing it stay valid.
+#[derive(Resource, Default)]
+struct TreeWindBuffer(UniformBuffer<TreeWindUniform>);
+
+fn prepare_tree_wind(
+    wind: Option<Res<CurrentTreeWind>>,
+    mut buffer: ResMut<TreeWindBuffer>,
+    device: Res<RenderDevice>,
+    queue: Res<RenderQueue>,
+) {
+    buffer.0.set(wind.map_or_else(TreeWindUniform::default, |w| w.0));
+    buffer.0.write_buffer(&device, &queue);
+}
+
+fn prepare_tree_batches(
+    mut prepared: ResMut<PreparedTreeBatches>,
+    batches: Query<(Entity, &ExtractedTreeBatch)>,
+    device: Res<RenderDevice>,
+    pipeline: Res<TreeInstancingPipeline>,
+    images: Res<RenderAssets<GpuImage>>,
+    fallback: Res<FallbackImage>,
+    wind: Res<TreeWindBuffer>,
+) {
+    prepared.0.retain(|e, _| batches.contains(*e));
+    let Some(wind) = wind.0.binding() else { return; };
+    for (e, batch) in &batches {
+        if batch.instances.is_empty() {
+            prepared.0.remove(&e);
+            continue;
+        }
+        if prepared.0.get(&e).is_some_and(|p| p.generation == batch.generation) {
+            continue;
+        }
+        let image = match batch.texture {
+            Some(id) => match images.get(id) {
+                Some(image) => image,
+                None => continue, // texture not uploaded yet
+            },
+            None => &fallback.d2,
+        };
+        let buffer = device.create_buffer_with_data(&BufferInitDescriptor {
+            label: Some("tree_instance_buffer"),
+            contents: bytemuck::cast_slice(batch.instances.as_slice()),
+            usage: BufferUsages::VERTEX,
+        });
+        let bind_group = device.create_bind_group(
+            "tree_instancing_material",
+            &pipeline.material_layout,
+            &BindGroupEntries::sequential((&image.texture_view, &image.sampler, wind.clone())),
+        );
+        prepared.0.insert(
+            e,
+            PreparedTreeBatch {
+                mesh: batch.mesh,
+                generation: batch.generation,
+                buffer,
+                count: batch.instances.len() as u32,
+                bind_group,
+            },
+        );
+    }
+}
+
+fn queue_tree_batches(
+    draw_functions: Res<DrawFunctions<Opaque3d>>,
+    pipeline: Res<TreeInstancingPipeline>,
+    msaa: Res<Msaa>,
+    mut pipelines: ResMut<SpecializedMeshPipelines<TreeInstancingPipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    meshes: Res<RenderAssets<GpuMesh>>,
+    prepared: Res<PreparedTreeBatches>,
+    mut opaque_phases: ResMut<ViewBinnedRenderPhases<Opaque3d>>,
+    views: Query<(
+        Entity,
+        &ExtractedView,
+        Option<&Projection>,
+        Option<&Tonemapping>,
+        Option<&DebandDither>,
+        Option<&ShadowFilteringMethod>,
+    )>,
+) {
+    if prepared.0.is_empty() {
+        return;
+    }
+    let draw_function = draw_functions.read().id::<DrawTreeInstanced>();
+
+    for (view_entity, view, projection, tonemapping, dither, shadow_filter) in &views {
+        let Some(phase) = opaque_phases.get_mut(&view_entity) else { continue; };
+
+        // Same view key bits the PBR material queue uses for this game's cameras.
+        let mut view_key = MeshPipelineKey::from_msaa_samples(msaa.samples()) | MeshPipelineKey::from_hdr(view.hdr);
+        if let Some(projection) = projection {
+            view_key |= match projection {
+                Projection::Perspective(_) => MeshPipelineKey::VIEW_PROJECTION_PERSPECTIVE,
+                Projection::Orthographic(_) => MeshPipelineKey::VIEW_PROJECTION_ORTHOGRAPHIC,
+            };
+        }
+        view_key |= match shadow_filter.copied().unwrap_or_default() {
+            ShadowFilteringMethod::Hardware2x2 => MeshPipelineKey::SHADOW_FILTER_METHOD_HARDWARE_2X2,
+            ShadowFilteringMethod::Gaussian => MeshPipelineKey::SHADOW_FILTER_METHOD_GAUSSIAN,
+            ShadowFilteringMethod::Temporal => MeshPipelineKey::SHADOW_FILTER_METHOD_TEMPORAL,
+        };
+        if !view.hdr {
+            if let Some(tonemapping) = tonemapping {
+                view_key |= MeshPipelineKey::TONEMAP_IN_SHADER | tonemapping_pipeline_key(*tonemapping);
+            }
+            if let Some(DebandDither::Enabled) = dither {
+                view_key |= MeshPipelineKey::DEBAND_DITHER;
+            }
+        }
+
+        for (&entity, batch) in &prepared.0 {
+            let Some(mesh) = meshes.get(batch.mesh) else { continue; };
+            let key = view_key | MeshPipelineKey::from_primitive_topology(mesh.primitive_topology());
+            let pipeline_id = match pipelines.specialize(&pipeline_cache, &pipeline, key, &mesh.layout) {
+                Ok(id) => id,
+                Err(e) => {
+                    error!("Tree instancing pipeline: {e}");
+                    continue;
+                }
+            };
+            phase.add(
+                Opaque3dBinKey {
+                    pipeline: pipeline_id,
+                    draw_function,
+                    asset_id: batch.mesh.untyped(),
+                    material_bind_group_id: Some(batch.bind_group.id()),
+                    lightmap_image: None,
+                },
+                entity,
+                BinnedRenderPhaseType::NonMesh,
+            );
+        }
+    }
+}
+
+type DrawTreeInstanced = (SetItemPipeline, SetMeshViewBindGroup<0>, DrawTreeBatch);
+
+struct DrawTreeBatch;
+
+impl<P: PhaseItem> RenderCommand<P> for DrawTreeBatch {
+    type Param = (SRes<RenderAssets<GpuMesh>>, SRes<PreparedTreeBatches>);
+    type ViewQuery = ();
+    type ItemQuery = ();
+
+    #[inline]
+    fn render<'w>(
+        item: &P,
+        _view: ROQueryItem<'w, Self::ViewQuery>,
+        _entity: Option<ROQueryItem<'w, Self::ItemQuery>>,
+        (meshes, batches): SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let Some(batch) = batches.into_inner().0.get(&item.entity()) else {
+            return RenderCommandResult::Failure;
+        };
+        let Some(gpu_mesh) = meshes.into_inner().get(batch.mesh) else {
+            return RenderCommandResult::Failure;
+        };
+        pass.set_bind_group(1, &batch.bind_group, &[]);
+        pass.set_vertex_buffer(0, gpu_mesh.vertex_buffer.slice(..));
+        pass.set_vertex_buffer(1, batch.buffer.slice(..));
+        match &gpu_mesh.buffer_info {
+            GpuBufferInfo::Indexed { buffer, index_format, count } => {
+                pass.set_index_buffer(buffer.slice(..), 0, *index_format);
+                pass.draw_indexed(0..*count, 0, 0..batch.count);
+            }
+            GpuBufferInfo::NonIndexed => {
+                pass.draw(0..gpu_mesh.vertex_count, 0..batch.count);
+            }
+        }
+        RenderCommandResult::Success
+    }
+}
@@ -0,0 +1,92 @@
+// Wind sway for trees.
+// Upper vertices bend downwind and flutter, scaled by `WindState::strength`.
+//  - Regular (non GPU-instanced) tree meshes use `TreeWindMaterial`, an extension of their
+//    StandardMaterial with a custom vertex shader (shaders/tree_wind.wgsl). Shadows and the depth
+//    prepass keep the rest pose.
+//  - GPU-instanced batches read the same parameters (`CurrentTreeWind`, extracted to the render
+//    world) in shaders/tree_instancing.wgsl.
+
+use bevy::pbr::{ExtendedMaterial, MaterialExtension, StandardMaterial};
+use bevy::prelude::*;
+use bevy::render::extract_resource::{ExtractResource, ExtractResourcePlugin};
+use bevy::render::render_resource::{AsBindGroup, ShaderRef, ShaderType};
+
+use crate::plugins::wind::WindState;
+
+pub type TreeWindMaterial = ExtendedMaterial<StandardMaterial, TreeWindExtension>;
+
+#[derive(Resource, Clone)]
+pub struct TreeWindConfig {
+    pub enabled: bool,
+    pub sway_height: f32, // height (m, world) over which bending ramps to full
+    pub amplitude: f32,   // downwind tip displacement (m) at wind strength 1
+    pub flutter: f32,     // small high-frequency leaf motion (m) at wind strength 1
+    pub frequency: f32,   // sway speed multiplier
+}
+impl Default for TreeWindConfig {
+    fn default() -> Self {
+        Self { enabled: true, sway_height: 8.0, amplitude: 0.35, flutter: 0.04, frequency: 1.1 }
+    }
+}
+
+/// Matches WGSL struct TreeWind in shaders/tree_wind.wgsl and shaders/tree_instancing.wgsl.
+#[derive(Clone, Copy, Debug, Default, ShaderType)]
+pub struct TreeWindUniform {
+    pub wind: Vec4,   // xy = direction, z = strength, w = frequency
+    pub params: Vec4, // x = time, y = sway height, z = amplitude, w = flutter
+}
+
+impl TreeWindUniform {
+    pub fn new(wind: &WindState, cfg: &TreeWindConfig) -> Self {
+        let strength = if cfg.enabled { wind.strength } else { 0.0 };
+        Self {
+            wind: wind.dir().extend(strength).extend(cfg.frequency),
+            params: Vec4::new(wind.time, cfg.sway_height.max(0.1), cfg.amplitude, cfg.flutter),
+        }
+    }
+}
+
+/// Wind parameters for this frame, shared by every tree material and the instanced batches.
+#[derive(Resource, Clone, Copy, Default, ExtractResource)]
+pub struct CurrentTreeWind(pub TreeWindUniform);
+
+#[derive(Asset, AsBindGroup, TypePath, Debug, Clone, Default)]
+pub struct TreeWindExtension {
+    #[uniform(100)]
+    pub data: TreeWindUniform,
+}
+
+impl MaterialExtension for TreeWindExtension {
+    fn vertex_shader() -> ShaderRef {
+        ShaderRef::Path("shaders/tree_wind.wgsl".into())
+    }
+}
+
+pub struct TreeWindPlugin;
+impl Plugin for TreeWindPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(MaterialPlugin::<TreeWindMaterial>::default())
+            .add_plugins(ExtractResourcePlugin::<CurrentTreeWind>::default())
+            .insert_resource(TreeWindConfig::default())
+            .init_resource::<CurrentTreeWind>()
+            .add_systems(Update, update_tree_wind);
+    }
+}
+
+/// Wrap a tree's StandardMaterial so its mesh sways.
+pub fn tree_wind_material(base: &StandardMaterial) -> TreeWindMaterial {
+    ExtendedMaterial { base: base.clone(), extension: TreeWindExtension::default() }
+}
+
+fn update_tree_wind(
+    wind: Option<Res<WindState>>,
+    cfg: Res<TreeWindConfig>,
+    mut current: ResMut<CurrentTreeWind>,
+    mut materials: ResMut<Assets<TreeWindMaterial>>,
+) {
+    let Some(wind) = wind else { return; };
+    current.0 = TreeWindUniform::new(&wind, &cfg);
+    for (_, mat) in materials.iter_mut() {
+        mat.extension.data = current.0;
+    }
+}
@@ -0,0 +1,195 @@
+// Trick shots.
+// The `TrickDetector` follows each shot through the physics events: a bounce off a tree trunk
+// (`TreeHitEvent`, a felled tree doesn't count) is a bank shot, and flying low and fast over the
+// lake or a river is a water skim. When the shot hits a standard target the tricks it collected
+// are paid out, plus a hit at the top of the target's bob. Each trick takes a few seconds off the
+// run clock — how much of that a mode pays is up to its rules (`GameRules::trick_bonus_scale`,
+// Stroke Play pays none) — and the HUD calls it out (`TrickShotEvent`, hud.rs). A finishing trick
+// takes its bonus off the final time and the round is ranked again.
+
+use bevy::prelude::*;
+
+use crate::plugins::app_state::gameplay_running;
+use crate::plugins::ball::{Ball, BallKinematic, WATER_LEVEL};
+use crate::plugins::core_sim::GameSet;
+use crate::plugins::event_log::{EventLog, LogKind};
+use crate::plugins::game_mode::GameRules;
+use crate::plugins::game_state::{update_high_score, Score};
+use crate::plugins::localization::Localization;
+use crate::plugins::low_power::LowPowerMode;
+use crate::plugins::particles::{TargetHitEvent, TreeHitEvent, TrickShotEvent};
+use crate::plugins::profile::Profile;
+use crate::plugins::rivers::Rivers;
+use crate::plugins::target::{detect_target_hits, TargetKind};
+use crate::plugins::terrain::TerrainSampler;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrickKind {
+    TreeBank,  // bounced off a tree trunk
+    WaterSkip, // skimmed low over water
+    ApexHit,   // hit the target at the top of its bob
+}
+
+impl TrickKind {
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::TreeBank => "trick.tree_bank",
+            Self::WaterSkip => "trick.water_skip",
+            Self::ApexHit => "trick.apex_hit",
+        }
+    }
+
+    /// Seconds off the run clock before the mode's scale.
+    pub fn bonus(self, cfg: &TrickConfig) -> f32 {
+        match self {
+            Self::TreeBank => cfg.tree_bank_bonus,
+            Self::WaterSkip => cfg.water_skip_bonus,
+            Self::ApexHit => cfg.apex_bonus,
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct TrickConfig {
+    pub enabled: bool,
+    pub tree_bank_bonus: f32,  // s
+    pub water_skip_bonus: f32, // s
+    pub apex_bonus: f32,       // s
+    pub skim_height: f32,      // m; the bottom of the ball at most this far above the water
+    pub skim_speed: f32,       // m/s horizontal, at least
+    pub apex: f32,             // bob (sin of the float phase) at least this high
+}
+impl Default for TrickConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            tree_bank_bonus: 3.0,
+            water_skip_bonus: 4.0,
+            apex_bonus: 2.0,
+            skim_height: 2.5,
+            skim_speed: 8.0,
+            apex: 0.92,
+        }
+    }
+}
+
+/// Whether a ball `height` m above the water moving at `speed` m/s (horizontal) skims it.
+pub fn is_skimming(height: f32, speed: f32, cfg: &TrickConfig) -> bool {
+    height > 0.0 && height <= cfg.skim_height && speed >= cfg.skim_speed
+}
+
+/// Seconds all of `tricks` take off the clock under `rules`.
+pub fn trick_bonus(tricks: &[TrickKind], cfg: &TrickConfig, rules: &dyn GameRules) -> f32 {
+    tricks.iter().map(|t| t.bonus(cfg)).sum::<f32>() * rules.trick_bonus_scale()
+}
+
+/// Localized trick names joined for a callout, e.g. "Bank shot + Water skim".
+pub fn trick_names(tricks: &[TrickKind], loc: &Localization) -> String {
+    tricks.iter().map(|t| loc.t(t.key())).collect::<Vec<_>>().join(" + ")
+}
+
+/// Tricks of the shot in play.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct TrickDetector {
+    pub shot: u32, // Score::shots the tricks belong to
+    pub tree_bank: bool,
+    pub water_skip: bool,
+}
+
+impl TrickDetector {
+    /// Follow the stroke count: a new shot (or a penalty stroke) starts without tricks.
+    pub fn track_shot(&mut self, shots: u32) {
+        if shots != self.shot {
+            *self = Self { shot: shots, ..default() };
+        }
+    }
+
+    /// Tricks a standard hit pays out, `bob` being where the target was in its bob (None when
+    /// its bob doesn't count). The shot's tricks are spent.
+    pub fn cash_in(&mut self, bob: Option<f32>, cfg: &TrickConfig) -> Vec<TrickKind> {
+        let mut tricks = Vec::new();
+        if self.tree_bank {
+            tricks.push(TrickKind::TreeBank);
+        }
+        if self.water_skip {
+            tricks.push(TrickKind::WaterSkip);
+        }
+        if bob.is_some_and(|b| b >= cfg.apex) {
+            tricks.push(TrickKind::ApexHit);
+        }
+        self.tree_bank = false;
+        self.water_skip = false;
+        tricks
+    }
+}
+
+pub struct TrickShotsPlugin;
+impl Plugin for TrickShotsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TrickConfig>().init_resource::<TrickDetector>().add_systems(
+            FixedUpdate,
+            detect_tricks.after(detect_target_hits).in_set(GameSet::React).run_if(gameplay_running),
+        );
+    }
+}
+
+// Water under `p`: the lake plane where the ground dips below it, else a river's surface.
+fn water_surface(p: Vec2, sampler: &TerrainSampler, rivers: Option<&Rivers>) -> Option<f32> {
+    let ground = sampler.height(p.x, p.y);
+    if ground < WATER_LEVEL {
+        Some(WATER_LEVEL)
+    } else {
+        rivers.filter(|r| r.contains(p)).map(|_| ground)
+    }
+}
+
+fn detect_tricks(
+    (cfg, loc): (Res<TrickConfig>, Res<Localization>),
+    (sampler, rivers, low_power): (Res<TerrainSampler>, Option<Res<Rivers>>, Option<Res<LowPowerMode>>),
+    mut detector: ResMut<TrickDetector>,
+    (mut score, mut profile): (ResMut<Score>, ResMut<Profile>),
+    mut log: Option<ResMut<EventLog>>,
+    mut ev_tree: EventReader<TreeHitEvent>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+    mut ev_trick: EventWriter<TrickShotEvent>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+) {
+    if !cfg.enabled {
+        ev_tree.clear();
+        ev_hit.clear();
+        return;
+    }
+    detector.track_shot(score.shots);
+    if ev_tree.read().any(|e| !e.knocked_down) {
+        detector.tree_bank = true;
+    }
+    if let Ok((t, kin)) = q_ball.get_single() {
+        let p = t.translation;
+        let skims = water_surface(p.xz(), &sampler, rivers.as_deref())
+            .is_some_and(|water| is_skimming(p.y - kin.collider_radius - water, kin.vel.xz().length(), &cfg));
+        detector.water_skip |= skims;
+    }
+
+    // With the idle bob held still (reduced motion) the top of it is no feat.
+    let bob_counts = !LowPowerMode::reduced_motion(low_power.as_deref());
+    for hit in ev_hit.read().filter(|e| e.kind == TargetKind::Standard) {
+        let tricks = detector.cash_in(bob_counts.then_some(hit.bob), &cfg);
+        if tricks.is_empty() {
+            continue;
+        }
+        let bonus = trick_bonus(&tricks, &cfg, score.mode.rules());
+        score.time_adjustment -= bonus;
+        if score.game_over && !score.failed && bonus > 0.0 {
+            // The finishing hit already stopped the clock and ranked the round.
+            score.final_time = (score.final_time - bonus).max(0.0);
+            score.high_score_time = score.previous_best_time;
+            update_high_score(&mut score, &mut profile);
+        }
+        let names = trick_names(&tricks, &loc);
+        info!("TRICK_SHOT tricks={:?} bonus={:.1}", tricks, bonus);
+        if let Some(log) = log.as_mut() {
+            log.push(LogKind::Record, loc.tf("log.trick", &[("tricks", names), ("secs", format!("{bonus:.1}"))]));
+        }
+        ev_trick.send(TrickShotEvent { pos: hit.pos, tricks, bonus });
+    }
+}
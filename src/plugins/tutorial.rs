@@ -0,0 +1,384 @@
+// First-run tutorial.
+// A short script (assets/tutorial.ron) of steps, each showing a localized hint, optionally
+// framing a HUD element, and waiting for the player to do something: orbit the camera, charge
+// a shot, fire it, hit the target (or just a delay). It starts with the first run when
+// `UserSettings::tutorial_completed` is unset; finishing or pressing Skip records completion in
+// the profile so it doesn't come back. The overlay hides while the game is paused.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use serde::Deserialize;
+
+use crate::plugins::camera::OrbitCameraState;
+use crate::plugins::game_state::{ShotMode, ShotState};
+use crate::plugins::hud::{CompassRoot, Hud, COMPASS_RADIUS};
+use crate::plugins::hud_layout::HudAnchor;
+use crate::plugins::localization::{Localization, LocalizedText};
+use crate::plugins::app_state::AppState;
+use crate::plugins::particles::{ShotFiredEvent, TargetHitEvent};
+use crate::plugins::shooting::{PowerBar, PowerGauge};
+use crate::plugins::spectate::InteractiveUi;
+use crate::plugins::user_settings::UserSettings;
+
+const TUTORIAL_FILE: &str = "assets/tutorial.ron";
+
+/// What a step waits for before moving on.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum TutorialWait {
+    Delay(f32),       // s
+    OrbitCamera(f32), // total yaw turned, radians
+    ChargeShot(f32),  // charge power 0..1 reached while holding
+    FireShot,
+    HitTarget,
+}
+
+/// HUD element framed while a step is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum TutorialHighlight {
+    Hud,
+    Compass,
+    PowerBar,
+    PowerGauge,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TutorialStep {
+    pub text: String, // localization key
+    #[serde(default)]
+    pub highlight: Option<TutorialHighlight>,
+    pub wait: TutorialWait,
+}
+
+#[derive(Resource, Debug, Clone, Deserialize, Default)]
+pub struct TutorialScript {
+    #[serde(default = "default_min_step_time")]
+    pub min_step_time: f32, // s each hint stays up even if its action is already done
+    pub steps: Vec<TutorialStep>,
+}
+
+fn default_min_step_time() -> f32 { 1.0 }
+
+impl TutorialScript {
+    pub fn from_ron(data: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str::<Self>(data)
+    }
+
+    fn load() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        let data = Some(include_str!("../../assets/tutorial.ron").to_string());
+        #[cfg(not(target_arch = "wasm32"))]
+        let data = fs::read_to_string(TUTORIAL_FILE).ok();
+
+        let Some(data) = data else {
+            warn!("Failed to read {TUTORIAL_FILE}; tutorial disabled");
+            return Self::default();
+        };
+        Self::from_ron(&data).unwrap_or_else(|e| {
+            error!("Failed to parse {TUTORIAL_FILE}: {e}");
+            Self::default()
+        })
+    }
+}
+
+/// Player input seen this frame, as far as the tutorial cares.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TutorialSignals {
+    pub yaw_turned: f32,      // |yaw change| this frame, radians
+    pub charge: Option<f32>,  // current charge power while holding a shot
+    pub shot_fired: bool,
+    pub target_hit: bool,
+}
+
+/// Progress through the script.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TutorialRun {
+    pub active: bool,
+    pub step: usize,
+    pub step_time: f32,
+    pub orbit: f32,     // yaw accumulated during this step
+    pub satisfied: bool, // the step's action has happened
+}
+
+impl TutorialRun {
+    pub fn start(&mut self) {
+        *self = Self { active: true, ..default() };
+    }
+
+    /// Advance by `dt` with this frame's `signals`; returns true when the last step completes.
+    pub fn update(&mut self, script: &TutorialScript, signals: TutorialSignals, dt: f32) -> bool {
+        if !self.active {
+            return false;
+        }
+        let Some(step) = script.steps.get(self.step) else {
+            self.active = false;
+            return true;
+        };
+        self.step_time += dt;
+        self.orbit += signals.yaw_turned;
+        self.satisfied |= match step.wait {
+            TutorialWait::Delay(secs) => self.step_time >= secs,
+            TutorialWait::OrbitCamera(angle) => self.orbit >= angle,
+            // Firing means the shot was charged, even if released before the threshold.
+            TutorialWait::ChargeShot(power) => signals.charge.is_some_and(|p| p >= power) || signals.shot_fired,
+            TutorialWait::FireShot => signals.shot_fired,
+            TutorialWait::HitTarget => signals.target_hit,
+        };
+        if self.satisfied && self.step_time >= script.min_step_time {
+            self.step += 1;
+            self.step_time = 0.0;
+            self.orbit = 0.0;
+            self.satisfied = false;
+            if self.step >= script.steps.len() {
+                self.active = false;
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn current<'a>(&self, script: &'a TutorialScript) -> Option<&'a TutorialStep> {
+        if self.active { script.steps.get(self.step) } else { None }
+    }
+}
+
+#[derive(Component)]
+struct TutorialPanel;
+#[derive(Component)]
+struct TutorialText;
+#[derive(Component)]
+struct TutorialStepCounter;
+#[derive(Component)]
+struct TutorialSkipButton;
+#[derive(Component)]
+struct TutorialHighlightFrame;
+
+pub struct TutorialPlugin;
+impl Plugin for TutorialPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TutorialScript::load())
+            .init_resource::<TutorialRun>()
+            .add_systems(Startup, spawn_tutorial_ui)
+            .add_systems(
+                Update,
+                (start_tutorial, skip_tutorial, advance_tutorial, draw_tutorial, place_highlight).chain(),
+            );
+    }
+}
+
+fn spawn_tutorial_ui(mut commands: Commands, assets: Res<AssetServer>) {
+    let font = assets.load("fonts/FiraSans-Bold.ttf");
+    commands.spawn((
+        NodeBundle {
+            style: Style { position_type: PositionType::Absolute, border: UiRect::all(Val::Px(3.0)), ..default() },
+            border_color: BorderColor(Color::srgb(1.0, 0.85, 0.3)),
+            border_radius: BorderRadius::all(Val::Px(8.0)),
+            visibility: Visibility::Hidden,
+            focus_policy: bevy::ui::FocusPolicy::Pass,
+            ..default()
+        },
+        TutorialHighlightFrame,
+        InteractiveUi,
+    ));
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(96.0),
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
+                focus_policy: bevy::ui::FocusPolicy::Pass,
+                ..default()
+            },
+            HudAnchor { bottom: Some(96.0), ..default() },
+            TutorialPanel,
+            InteractiveUi,
+            Name::new("Tutorial"),
+        ))
+        .with_children(|root| {
+            root.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(6.0),
+                    padding: UiRect::axes(Val::Px(18.0), Val::Px(10.0)),
+                    max_width: Val::Px(560.0),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(0.03, 0.04, 0.08, 0.85)),
+                border_radius: BorderRadius::all(Val::Px(10.0)),
+                ..default()
+            })
+            .with_children(|panel| {
+                panel.spawn((
+                    TextBundle::from_section("", TextStyle { font: font.clone(), font_size: 14.0, color: Color::srgb(0.6, 0.7, 0.9) }),
+                    TutorialStepCounter,
+                ));
+                panel.spawn((
+                    TextBundle::from_section("", TextStyle { font: font.clone(), font_size: 22.0, color: Color::WHITE })
+                        .with_text_justify(JustifyText::Center),
+                    TutorialText,
+                ));
+                panel
+                    .spawn((
+                        ButtonBundle {
+                            style: Style { padding: UiRect::axes(Val::Px(10.0), Val::Px(3.0)), ..default() },
+                            background_color: BackgroundColor(Color::srgb(0.25, 0.25, 0.35)),
+                            border_radius: BorderRadius::all(Val::Px(6.0)),
+                            ..default()
+                        },
+                        TutorialSkipButton,
+                    ))
+                    .with_children(|b| {
+                        b.spawn((
+                            TextBundle::from_section("", TextStyle { font: font.clone(), font_size: 16.0, color: Color::WHITE }),
+                            LocalizedText::new("tutorial.skip"),
+                        ));
+                    });
+            });
+        });
+}
+
+/// Start with the first run of a player who hasn't finished (or skipped) it yet.
+fn start_tutorial(
+    phase: Res<State<AppState>>,
+    settings: Res<UserSettings>,
+    script: Res<TutorialScript>,
+    mut run: ResMut<TutorialRun>,
+) {
+    if phase.is_changed() && *phase.get() == AppState::Playing && !run.active && !settings.tutorial_completed && !script.steps.is_empty() {
+        run.start();
+    }
+}
+
+fn skip_tutorial(
+    mut run: ResMut<TutorialRun>,
+    mut settings: ResMut<UserSettings>,
+    q_button: Query<&Interaction, (Changed<Interaction>, With<TutorialSkipButton>)>,
+) {
+    if run.active && q_button.iter().any(|i| *i == Interaction::Pressed) {
+        run.active = false;
+        settings.tutorial_completed = true;
+    }
+}
+
+fn advance_tutorial(
+    time: Res<Time>,
+    phase: Res<State<AppState>>,
+    script: Res<TutorialScript>,
+    shot: Res<ShotState>,
+    orbit: Option<Res<OrbitCameraState>>,
+    mut run: ResMut<TutorialRun>,
+    mut settings: ResMut<UserSettings>,
+    mut ev_shot: EventReader<ShotFiredEvent>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+    mut last_yaw: Local<Option<f32>>,
+) {
+    let yaw = orbit.map(|o| o.yaw);
+    let yaw_turned = match (*last_yaw, yaw) {
+        (Some(a), Some(b)) => (b - a).abs(),
+        _ => 0.0,
+    };
+    *last_yaw = yaw;
+    let signals = TutorialSignals {
+        yaw_turned,
+        charge: (shot.mode == ShotMode::Charging).then_some(shot.power),
+        shot_fired: ev_shot.read().count() > 0,
+        target_hit: ev_hit.read().count() > 0,
+    };
+    if !run.active || *phase.get() != AppState::Playing {
+        return;
+    }
+    if run.update(&script, signals, time.delta_seconds()) {
+        settings.tutorial_completed = true;
+    }
+}
+
+fn draw_tutorial(
+    run: Res<TutorialRun>,
+    phase: Res<State<AppState>>,
+    script: Res<TutorialScript>,
+    loc: Res<Localization>,
+    mut q_panel: Query<&mut Visibility, With<TutorialPanel>>,
+    mut q_text: Query<&mut Text, (With<TutorialText>, Without<TutorialStepCounter>)>,
+    mut q_counter: Query<&mut Text, (With<TutorialStepCounter>, Without<TutorialText>)>,
+) {
+    let step = run.current(&script).filter(|_| *phase.get() == AppState::Playing);
+    if let Ok(mut vis) = q_panel.get_single_mut() {
+        let want = if step.is_some() { Visibility::Inherited } else { Visibility::Hidden };
+        if *vis != want {
+            *vis = want;
+        }
+    }
+    let Some(step) = step else { return; };
+    if !(run.is_changed() || loc.is_changed()) {
+        return;
+    }
+    if let Ok(mut text) = q_text.get_single_mut() {
+        let value = loc.t(&step.text);
+        if text.sections[0].value != value {
+            text.sections[0].value = value.to_string();
+        }
+    }
+    if let Ok(mut text) = q_counter.get_single_mut() {
+        text.sections[0].value =
+            loc.tf("tutorial.step", &[("step", (run.step + 1).to_string()), ("steps", script.steps.len().to_string())]);
+    }
+}
+
+/// (centre, size) of a UI node in window pixels.
+fn node_rect<T: Component>(q: &Query<(&Node, &GlobalTransform), With<T>>) -> Option<(Vec2, Vec2)> {
+    q.get_single().ok().map(|(n, gt)| (gt.translation().truncate(), n.size()))
+}
+
+/// Frame the current step's HUD element (screen rect in window pixels -> UI pixels).
+fn place_highlight(
+    run: Res<TutorialRun>,
+    phase: Res<State<AppState>>,
+    script: Res<TutorialScript>,
+    time: Res<Time>,
+    ui_scale: Res<UiScale>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_hud: Query<(&Node, &GlobalTransform), With<Hud>>,
+    q_bar: Query<(&Node, &GlobalTransform), With<PowerBar>>,
+    q_gauge: Query<(&Node, &GlobalTransform), With<PowerGauge>>,
+    q_compass: Query<&Transform, With<CompassRoot>>,
+    mut q_frame: Query<(&mut Style, &mut Visibility, &mut BorderColor), With<TutorialHighlightFrame>>,
+) {
+    let Ok((mut style, mut vis, mut border)) = q_frame.get_single_mut() else { return; };
+    let highlight = run.current(&script).filter(|_| *phase.get() == AppState::Playing).and_then(|s| s.highlight);
+    let rect = match highlight {
+        Some(TutorialHighlight::Hud) => node_rect(&q_hud),
+        Some(TutorialHighlight::PowerBar) => node_rect(&q_bar),
+        Some(TutorialHighlight::PowerGauge) => node_rect(&q_gauge),
+        Some(TutorialHighlight::Compass) => match (q_compass.get_single(), q_window.get_single()) {
+            // 2D camera space: origin at the window centre, y up.
+            (Ok(t), Ok(w)) => {
+                let center = Vec2::new(w.width() * 0.5 + t.translation.x, w.height() * 0.5 - t.translation.y);
+                let extent = (COMPASS_RADIUS + 12.0) * t.scale.x * 2.0;
+                Some((center + Vec2::new(0.0, 10.0 * t.scale.x), Vec2::new(extent, extent + 20.0 * t.scale.x)))
+            }
+            _ => None,
+        },
+        None => None,
+    };
+    let Some((center, size)) = rect else {
+        *vis = Visibility::Hidden;
+        return;
+    };
+    let scale = ui_scale.0.max(0.01);
+    let pad = 6.0;
+    let top_left = (center - size * 0.5) / scale - Vec2::splat(pad);
+    style.left = Val::Px(top_left.x);
+    style.top = Val::Px(top_left.y);
+    style.width = Val::Px(size.x / scale + pad * 2.0);
+    style.height = Val::Px(size.y / scale + pad * 2.0);
+    border.0.set_alpha(0.6 + 0.4 * (time.elapsed_seconds() * 4.0).sin().abs());
+    *vis = Visibility::Inherited;
+}
@@ -0,0 +1,160 @@
+// Accessibility theme: colorblind-safe palettes and a high-contrast HUD mode.
+// `UiTheme` is the one place gameplay UI asks for its signal colors: the power-bar gradient,
+// the trajectory dots and the compass markers. The alternative palettes are built from the
+// Okabe-Ito set so their stops stay apart in lightness as well as hue (the default palette's
+// green -> red bar is the main offender for red-green color blindness).
+// High contrast puts a dark backdrop behind HUD text tagged `HighContrastText` and makes the
+// compass ring more opaque. Both options live in the settings panel's Accessibility group.
+
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorPalette {
+    #[default]
+    Default,
+    Deuteranopia, // red-green (green-weak)
+    Protanopia,   // red-green (red-weak)
+    Tritanopia,   // blue-yellow
+}
+
+impl ColorPalette {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Default => Self::Deuteranopia,
+            Self::Deuteranopia => Self::Protanopia,
+            Self::Protanopia => Self::Tritanopia,
+            Self::Tritanopia => Self::Default,
+        }
+    }
+
+    /// Localization key of the palette name.
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Self::Default => "theme.palette.default",
+            Self::Deuteranopia => "theme.palette.deuteranopia",
+            Self::Protanopia => "theme.palette.protanopia",
+            Self::Tritanopia => "theme.palette.tritanopia",
+        }
+    }
+}
+
+/// Colors of the compass ring and its markers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompassColors {
+    pub ring: Color,
+    pub target: Color,
+    pub landing: Color,
+    pub tee: Color,
+    pub bonus: Color,
+}
+
+#[derive(Resource, Debug, Clone, Default)]
+pub struct UiTheme {
+    pub palette: ColorPalette,
+    pub high_contrast: bool,
+}
+
+fn lerp_srgb(a: [f32; 3], b: [f32; 3], t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    Color::srgb(a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t, a[2] + (b[2] - a[2]) * t)
+}
+
+impl UiTheme {
+    /// Power-bar fill for `power` in 0..1 (three-stop gradient: low, mid, full).
+    pub fn power_color(&self, power: f32) -> Color {
+        let [low, mid, high] = match self.palette {
+            ColorPalette::Default => [[0.15, 0.60, 0.25], [0.70, 0.85, 0.10], [0.90, 0.20, 0.15]],
+            // Blue -> yellow -> vermillion
+            ColorPalette::Deuteranopia | ColorPalette::Protanopia => {
+                [[0.00, 0.45, 0.70], [0.94, 0.89, 0.26], [0.84, 0.37, 0.00]]
+            }
+            // Bluish green -> light pink -> deep red
+            ColorPalette::Tritanopia => [[0.00, 0.62, 0.45], [0.95, 0.75, 0.80], [0.80, 0.05, 0.20]],
+        };
+        if power < 0.5 {
+            lerp_srgb(low, mid, power / 0.5)
+        } else {
+            lerp_srgb(mid, high, (power - 0.5) / 0.5)
+        }
+    }
+
+    /// Base color of a trajectory dot; `fraction` runs 0 (first dot) .. 1 (last).
+    pub fn trajectory_color(&self, fraction: f32) -> Color {
+        let tint = 0.3 + fraction.clamp(0.0, 1.0) * 0.7;
+        let [r, g, b] = match self.palette {
+            ColorPalette::Default => [1.0, 0.85, 0.10],
+            ColorPalette::Deuteranopia | ColorPalette::Protanopia => [0.34, 0.71, 0.91], // sky blue
+            ColorPalette::Tritanopia => [0.95, 0.30, 0.50],                              // rose
+        };
+        // Dots near the ball are deeper, the far end of the arc lighter.
+        match self.palette {
+            ColorPalette::Default | ColorPalette::Tritanopia => Color::srgb(r, g * tint, b * tint),
+            ColorPalette::Deuteranopia | ColorPalette::Protanopia => Color::srgb(r * tint, g * tint, b),
+        }
+    }
+
+    /// Emissive glow of the trajectory dots (scaled by the charge intensity).
+    pub fn trajectory_emissive(&self) -> LinearRgba {
+        match self.palette {
+            ColorPalette::Default => LinearRgba::new(3.0, 2.0, 0.3, 1.0),
+            ColorPalette::Deuteranopia | ColorPalette::Protanopia => LinearRgba::new(0.6, 1.6, 3.0, 1.0),
+            ColorPalette::Tritanopia => LinearRgba::new(3.0, 0.8, 1.2, 1.0),
+        }
+    }
+
+    pub fn compass_colors(&self) -> CompassColors {
+        let ring = Color::srgba(1.0, 1.0, 1.0, if self.high_contrast { 0.25 } else { 0.07 });
+        match self.palette {
+            ColorPalette::Default => CompassColors {
+                ring,
+                target: Color::srgb(0.95, 0.2, 0.2),
+                landing: Color::srgb(0.85, 0.95, 1.0),
+                tee: Color::srgb(0.3, 0.9, 0.4),
+                bonus: Color::srgb(1.0, 0.8, 0.2),
+            },
+            ColorPalette::Deuteranopia | ColorPalette::Protanopia => CompassColors {
+                ring,
+                target: Color::srgb(0.84, 0.37, 0.0),
+                landing: Color::srgb(0.95, 0.95, 0.95),
+                tee: Color::srgb(0.0, 0.45, 0.70),
+                bonus: Color::srgb(0.94, 0.89, 0.26),
+            },
+            ColorPalette::Tritanopia => CompassColors {
+                ring,
+                target: Color::srgb(0.85, 0.10, 0.20),
+                landing: Color::srgb(0.95, 0.95, 0.95),
+                tee: Color::srgb(0.35, 0.80, 0.90),
+                bonus: Color::srgb(0.80, 0.47, 0.65),
+            },
+        }
+    }
+
+    /// Backdrop behind `HighContrastText` nodes.
+    pub fn text_backdrop(&self) -> Color {
+        if self.high_contrast { Color::srgba(0.0, 0.0, 0.0, 0.7) } else { Color::NONE }
+    }
+}
+
+/// HUD text that gets a backdrop in high-contrast mode.
+#[derive(Component)]
+pub struct HighContrastText;
+
+pub struct UiThemePlugin;
+impl Plugin for UiThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiTheme>().add_systems(Update, apply_text_contrast);
+    }
+}
+
+fn apply_text_contrast(
+    theme: Res<UiTheme>,
+    mut q: Query<(Ref<HighContrastText>, &mut BackgroundColor, &mut Style)>,
+) {
+    for (tag, mut bg, mut style) in &mut q {
+        if !(theme.is_changed() || tag.is_added()) {
+            continue;
+        }
+        bg.0 = theme.text_backdrop();
+        style.padding = if theme.high_contrast { UiRect::axes(Val::Px(6.0), Val::Px(2.0)) } else { UiRect::ZERO };
+    }
+}
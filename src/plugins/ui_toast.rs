@@ -0,0 +1,267 @@
+// Toast notifications.
+// Short banners ("Hole 3 / 5", "Shot 2", "New best!") stacked under the top edge of the screen.
+// Anything can `ToastQueue::push` one; the queue keeps at most `max_visible` on screen and holds
+// the rest back until a slot frees up, so a burst of events plays out in order instead of
+// overwriting each other. Each toast slides down / fades in, holds, then slides up / fades out.
+// Gameplay events (hole progress, shots, game over, felled trees) are turned into toasts here.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::plugins::game_state::Score;
+use crate::plugins::hud_layout::HudAnchor;
+use crate::plugins::app_state::AppState;
+use crate::plugins::particles::{GameOverEvent, ShotFiredEvent, TreeHitEvent};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastKind {
+    #[default]
+    Info,
+    Progress, // hole / shot counters
+    Success,  // records, big moments
+    Warning,  // penalties
+}
+
+impl ToastKind {
+    fn color(self) -> Color {
+        match self {
+            ToastKind::Info => Color::srgb(0.92, 0.92, 0.95),
+            ToastKind::Progress => Color::srgb(0.65, 0.85, 1.0),
+            ToastKind::Success => Color::srgb(1.0, 0.85, 0.3),
+            ToastKind::Warning => Color::srgb(1.0, 0.45, 0.35),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    pub text: String,
+    pub kind: ToastKind,
+    pub duration: f32, // s fully shown (excluding the in / out animation)
+}
+
+#[derive(Debug, Clone)]
+pub struct ActiveToast {
+    pub toast: Toast,
+    pub age: f32,
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct ToastConfig {
+    pub max_visible: usize,
+    pub max_pending: usize,   // oldest queued toasts are dropped beyond this
+    pub default_duration: f32,
+    pub anim_in: f32,         // s slide / fade in
+    pub anim_out: f32,        // s slide / fade out
+    pub slide: f32,           // px travelled while animating
+}
+impl Default for ToastConfig {
+    fn default() -> Self {
+        Self { max_visible: 3, max_pending: 8, default_duration: 1.8, anim_in: 0.25, anim_out: 0.35, slide: 18.0 }
+    }
+}
+
+impl ToastConfig {
+    /// 0 (hidden) .. 1 (fully shown) for a toast of `duration` at `age`.
+    pub fn envelope(&self, age: f32, duration: f32) -> f32 {
+        let fade_in = (age / self.anim_in.max(1e-3)).clamp(0.0, 1.0);
+        let fade_out = ((self.anim_in + duration + self.anim_out - age) / self.anim_out.max(1e-3)).clamp(0.0, 1.0);
+        fade_in.min(fade_out)
+    }
+
+    pub fn lifetime(&self, duration: f32) -> f32 {
+        self.anim_in + duration + self.anim_out
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct ToastQueue {
+    pending: VecDeque<Toast>,
+    active: Vec<ActiveToast>,
+}
+
+impl ToastQueue {
+    pub fn push(&mut self, text: impl Into<String>, kind: ToastKind) {
+        self.push_for(text, kind, 0.0);
+    }
+
+    /// Queue a toast shown for `duration` s (0 = `ToastConfig::default_duration`).
+    /// A toast identical to the last queued one is dropped.
+    pub fn push_for(&mut self, text: impl Into<String>, kind: ToastKind, duration: f32) {
+        let toast = Toast { text: text.into(), kind, duration };
+        if self.pending.back() == Some(&toast) {
+            return;
+        }
+        self.pending.push_back(toast);
+    }
+
+    pub fn clear(&mut self) {
+        self.pending.clear();
+        self.active.clear();
+    }
+
+    /// Age the visible toasts, retire finished ones and promote queued ones into free slots.
+    pub fn tick(&mut self, cfg: &ToastConfig, dt: f32) {
+        for t in &mut self.active {
+            t.age += dt;
+        }
+        self.active.retain(|t| t.age < cfg.lifetime(t.toast.duration));
+        while self.pending.len() > cfg.max_pending {
+            self.pending.pop_front();
+        }
+        while self.active.len() < cfg.max_visible {
+            let Some(mut toast) = self.pending.pop_front() else { break; };
+            if toast.duration <= 0.0 {
+                toast.duration = cfg.default_duration;
+            }
+            self.active.push(ActiveToast { toast, age: 0.0 });
+        }
+    }
+
+    pub fn visible(&self) -> &[ActiveToast] {
+        &self.active
+    }
+
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+#[derive(Component)]
+struct ToastSlot(usize);
+#[derive(Component)]
+struct ToastSlotText;
+
+pub struct UiToastPlugin;
+impl Plugin for UiToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ToastConfig>()
+            .init_resource::<ToastQueue>()
+            .add_systems(Startup, spawn_toast_slots)
+            .add_systems(Update, (toast_gameplay_events, tick_toasts, draw_toasts).chain());
+    }
+}
+
+fn spawn_toast_slots(mut commands: Commands, assets: Res<AssetServer>, cfg: Res<ToastConfig>) {
+    let font = assets.load("fonts/FiraSans-Bold.ttf");
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(64.0),
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(6.0),
+                    ..default()
+                },
+                ..default()
+            },
+            HudAnchor { top: Some(64.0), ..default() },
+            Name::new("Toasts"),
+        ))
+        .with_children(|root| {
+            for i in 0..cfg.max_visible {
+                root.spawn((
+                    NodeBundle {
+                        style: Style {
+                            padding: UiRect::axes(Val::Px(14.0), Val::Px(5.0)),
+                            ..default()
+                        },
+                        background_color: BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.0)),
+                        visibility: Visibility::Hidden,
+                        ..default()
+                    },
+                    ToastSlot(i),
+                ))
+                .with_children(|slot| {
+                    slot.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle { font: font.clone(), font_size: 22.0, color: Color::WHITE },
+                        ),
+                        ToastSlotText,
+                    ));
+                });
+            }
+        });
+}
+
+fn toast_gameplay_events(
+    score: Res<Score>,
+    phase: Option<Res<State<AppState>>>,
+    mut toasts: ResMut<ToastQueue>,
+    mut ev_shot: EventReader<ShotFiredEvent>,
+    mut ev_game_over: EventReader<GameOverEvent>,
+    mut ev_tree: EventReader<TreeHitEvent>,
+    mut last_hits: Local<Option<u32>>,
+    mut hole_shots: Local<u32>,
+) {
+    if phase.is_some_and(|p| !p.in_game()) {
+        // Menu: nothing to announce; the first hole is announced when play starts.
+        *last_hits = None;
+        ev_shot.clear();
+        ev_game_over.clear();
+        ev_tree.clear();
+        return;
+    }
+    // Hole progress: a new run (hits back to 0) or a completed hole.
+    if *last_hits != Some(score.hits) {
+        if score.hits == 0 || last_hits.is_some_and(|h| score.hits < h) {
+            toasts.clear();
+        }
+        *last_hits = Some(score.hits);
+        *hole_shots = 0;
+        if !score.game_over {
+            toasts.push(format!("Hole {} / {}", score.hits + 1, score.max_holes), ToastKind::Progress);
+        }
+    }
+    for _ in ev_shot.read() {
+        *hole_shots += 1;
+        toasts.push_for(format!("Shot {}", *hole_shots), ToastKind::Progress, 1.0);
+    }
+    for e in ev_tree.read() {
+        if e.knocked_down {
+            toasts.push("Timber!", ToastKind::Info);
+        }
+    }
+    if ev_game_over.read().count() > 0 {
+        if score.is_new_record() {
+            toasts.push_for("New best!", ToastKind::Success, 3.0);
+        } else {
+            toasts.push_for(format!("Finished in {:.2}s", score.final_time), ToastKind::Info, 3.0);
+        }
+    }
+}
+
+fn tick_toasts(time: Res<Time>, cfg: Res<ToastConfig>, mut toasts: ResMut<ToastQueue>) {
+    toasts.tick(&cfg, time.delta_seconds());
+}
+
+fn draw_toasts(
+    cfg: Res<ToastConfig>,
+    toasts: Res<ToastQueue>,
+    mut q_slots: Query<(&ToastSlot, &Children, &mut Style, &mut Visibility, &mut BackgroundColor)>,
+    mut q_text: Query<&mut Text, With<ToastSlotText>>,
+) {
+    for (slot, children, mut style, mut vis, mut bg) in &mut q_slots {
+        let Some(active) = toasts.visible().get(slot.0) else {
+            *vis = Visibility::Hidden;
+            continue;
+        };
+        let e = cfg.envelope(active.age, active.toast.duration);
+        *vis = Visibility::Inherited;
+        style.top = Val::Px(-(1.0 - e) * cfg.slide);
+        bg.0 = Color::srgba(0.05, 0.05, 0.08, 0.6 * e);
+        for &child in children.iter() {
+            if let Ok(mut text) = q_text.get_mut(child) {
+                if text.sections[0].value != active.toast.text {
+                    text.sections[0].value = active.toast.text.clone();
+                }
+                text.sections[0].style.color = active.toast.kind.color().with_alpha(e);
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+// Persisted player settings, stored in the player profile (profile.rs; older builds used a
+// separate settings.ron, which the profile migrates). Holds state that has to survive restarts
+// but isn't a performance knob: so far whether the tutorial has been completed or skipped, which
+// level was played last and the game mode picked in the menu. Unknown / missing fields fall back
+// to their defaults so older profiles keep loading as fields are added. Changes are written back
+// as soon as the resource is modified.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::plugins::game_mode::GameMode;
+
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UserSettings {
+    pub tutorial_completed: bool,
+    pub last_level: Option<String>, // manifest id of the level last played
+    pub game_mode: GameMode,        // rule variant picked in the main menu
+}
+
+impl UserSettings {
+    /// Legacy settings.ron contents.
+    pub fn from_ron(data: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str::<Self>(data)
+    }
+
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()).unwrap_or_default()
+    }
+}
@@ -3,18 +3,32 @@
 //  - Optional direct mesh/material spawning (bypasses full Scene hierarchy) for trees
 //    reducing entity count & potential draw calls (shared mesh/material = GPU instancing).
 //  - Approximate draw call debug system (unique (mesh, material, shadow state) among visible trees).
-//  - Config flags: use_instanced, debug_draw_calls, draw_call_log_interval.
+//  - Config flags: use_instanced, gpu_instancing, debug_draw_calls, draw_call_log_interval.
+//  - True GPU instancing (gpu_instancing): trees become transform-only entities drawn by
+//    tree_instancing.rs in one instanced draw per mesh variant.
 //  - Dual batching (scene vs pbr) to avoid per‑entity spawns.
+//  - Second scatter pass for props (boulders, stumps, mushrooms; vegetation_props.rs) with their
+//    own slope rules, spacing classes and optional ball collision.
+//  - Optional per-level density mask (vegetation_mask.rs) multiplies the noise density.
+//  - Wind sway: pbr trees use TreeWindMaterial (tree_wind.rs); GPU batches sway in their shader.
+//  - Placement cache (vegetation_cache.rs): accepted trees persist to disk per (seed, heightmap,
+//    config) hash, so later launches spawn chunks without re-running placement.
+//  - Exclusion zones (vegetation_exclusion.rs) keep the ball spawn, targets and level-defined
+//    areas clear; trees are removed around a target when it relocates.
 //
 // Existing optimizations retained:
 //  - Early rejection before expensive surface sampling
-//  - Progressive streaming spawn (frame‑budgeted)
-//  - Spatial hash spacing
+//  - Progressive streaming spawn (frame‑budgeted), per terrain chunk: each chunk owns its
+//    vegetation (children of the chunk entity), generated with a per-chunk seeded RNG.
+//    Chunks in the camera's view go first, nearest first (spawn_view.rs); the rest wait.
+//  - Spatial grid spacing (spatial_grid.rs); spawned trees indexed in `VegetationGrid`
 //  - Config resources (runtime tunable)
 //  - Preloaded scene handles
 //  - Batched entity creation (spawn_batch)
 //  - Distance culling with hysteresis + timed passes
 //  - Shadow LOD with hysteresis
+//  - Cluster culling: cull / shadow LOD decide per 64 m cell (`VegetationClusters`) and only
+//    visit individual trees in cells straddling a threshold
 //  - Adaptive performance tuner
 //
 // Added:
@@ -23,33 +37,68 @@
 //    This keeps trees present far away while gently shrinking out to hide.
 //
 // Future potential:
-//  - True GPU buffer-based instancing capturing original child local transforms
 //  - Billboard / impostor far LOD
-//  - Streaming unload + spatial partition for runtime memory reclaim
 //  - Parallel sampling via task pool
 //  - Per-instance shader driven alpha fade (would allow keeping scale w/o material duplication)
-//
-// NOTE: For determinism you could replace thread_rng with a seeded RNG from cfg.seed.
 
+use bevy::asset::UntypedAssetId;
 use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy::ecs::entity::Entities;
 use bevy::pbr::NotShadowCaster;
+use bevy::render::primitives::Frustum;
 use bevy::prelude::*;
+use bevy::transform::TransformSystem;
 use noise::{NoiseFn, Perlin};
-use rand::{thread_rng, Rng};
-use std::collections::{HashMap, HashSet};
-
-use crate::plugins::ball::Ball;
-use crate::plugins::terrain::TerrainSampler;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::plugins::ball::{ball_physics, Ball};
+use crate::plugins::grass::GrassConfig;
+use crate::plugins::birds::BirdConfig;
+use crate::plugins::level::LevelDef;
+use crate::plugins::app_state::gameplay_running;
+use crate::plugins::camera::OrbitCamera;
+use crate::plugins::core_sim::GameSet;
+use crate::plugins::spatial_grid::{distance_range_to_box, SpatialGrid};
+use crate::plugins::spawn_view::{next_by_priority, SpawnView};
+use crate::plugins::terrain::{TerrainChunkReady, TerrainSampler};
+use crate::plugins::tree_instancing::TreeInstanceOf;
+use crate::plugins::tree_wind::{tree_wind_material, TreeWindMaterial};
+use crate::plugins::vegetation_cache::{sync_vegetation_cache, VegetationCache};
+use crate::plugins::vegetation_exclusion::{
+    apply_level_exclusions, update_target_exclusions, VegetationExclusionConfig, VegetationExclusions,
+};
+use crate::plugins::vegetation_mask::VegetationDensityMask;
+use crate::plugins::vegetation_props::{
+    ball_prop_collision, index_props, Prop, PropAssets, PropGrid, PropKind, PropPlacement, PropScatterConfig,
+};
+use crate::plugins::vegetation_species::{VegetationSpecies, VegetationSpeciesList};
 
 pub struct VegetationPlugin;
 impl Plugin for VegetationPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(VegetationConfig::default())
+            .insert_resource(VegetationSpeciesList::load())
             .insert_resource(VegetationCullingConfig::default())
             .insert_resource(VegetationLodConfig::default())
             .insert_resource(VegetationFadeConfig::default())
             .insert_resource(VegetationPerfTuner::default())
             .insert_resource(VegetationMeshVariants::default())
+            .init_resource::<VegetationSpawnState>()
+            .init_resource::<VegetationGrid>()
+            .init_resource::<VegetationClusters>()
+            .init_resource::<VegetationCache>()
+            .init_resource::<VegetationExclusionConfig>()
+            .init_resource::<VegetationExclusions>()
+            .insert_resource(PropScatterConfig::default())
+            .init_resource::<PropAssets>()
+            .init_resource::<PropGrid>()
+            .add_systems(
+                PostUpdate,
+                (index_trees, index_props).after(TransformSystem::TransformPropagate),
+            )
+            .add_systems(FixedUpdate, ball_prop_collision.after(ball_physics).in_set(GameSet::Simulate).run_if(gameplay_running))
             .add_systems(Startup, prepare_vegetation)
             .insert_resource(VegetationCullingState {
                 timer: Timer::from_seconds(
@@ -74,10 +123,20 @@ impl Plugin for VegetationPlugin {
             .add_systems(
                 Update,
                 (
-                    extract_tree_mesh_variants.before(vegetation_expand_area),
-                    vegetation_expand_area.before(progressive_spawn_trees),
-                    progressive_spawn_trees,
-                    cull_trees.after(progressive_spawn_trees),
+                    apply_level_density_mask
+                        .run_if(resource_exists_and_changed::<LevelDef>)
+                        .before(spawn_chunk_vegetation),
+                    apply_level_exclusions
+                        .run_if(resource_exists_and_changed::<LevelDef>)
+                        .before(spawn_chunk_vegetation),
+                    update_target_exclusions.before(spawn_chunk_vegetation),
+                    sync_vegetation_cache
+                        .after(apply_level_density_mask)
+                        .before(spawn_chunk_vegetation),
+                    extract_tree_mesh_variants.before(spawn_chunk_vegetation),
+                    queue_chunk_vegetation.before(spawn_chunk_vegetation),
+                    spawn_chunk_vegetation,
+                    cull_trees.after(spawn_chunk_vegetation),
                     tree_distance_fade.after(cull_trees),
                     tree_lod_update.after(tree_distance_fade),
                     vegetation_perf_tuner.after(tree_lod_update),
@@ -90,7 +149,7 @@ impl Plugin for VegetationPlugin {
 #[derive(Component)]
 pub struct Tree;
 #[derive(Component)]
-struct TreeCulled(bool); // true if currently hidden
+pub(crate) struct TreeCulled(pub(crate) bool); // true if currently hidden
 
 #[derive(Component)]
 struct TreeLod {
@@ -109,9 +168,7 @@ pub struct VegetationConfig {
     pub base_density: f32,
     pub threshold: f32,
     pub max_instances: usize,
-    pub min_slope_normal_y: f32,
-    pub scale_min: f32,
-    pub scale_max: f32,
+    pub min_slope_normal_y: f32, // global slope cutoff; species may be stricter
     pub samples_per_frame: usize, // grid cells evaluated per frame
     pub batch_spawn_flush: usize, // flush batch when queued >= this
     // Minimum spacing (approx) between accepted trees per region (pseudo blue-noise)
@@ -121,7 +178,7 @@ pub struct VegetationConfig {
     // New clustering / variation controls
     pub patch_noise_freq: f64,   // low-frequency patchiness noise
     pub patch_contrast: f32,     // contrast applied to patch noise (higher = more binary clusters)
-    pub inner_cap: usize,        // max trees allowed in inner play radius
+    pub inner_cap: usize,        // max trees per chunk inside the inner play radius
     // Hero / variation controls
     pub hero_chance: f32,        // probability a tree becomes a hero (bigger)
     pub hero_scale_min_mul: f32, // min multiplier for hero scale
@@ -129,8 +186,14 @@ pub struct VegetationConfig {
     pub tilt_max_deg: f32,       // random tilt around X/Z to avoid uniform uprights
     // New flags
     pub use_instanced: bool,         // if true spawn single-mesh PbrBundle instead of entire Scene
+    pub gpu_instancing: bool,        // with use_instanced: draw via per-variant instance buffers (no tree shadows)
     pub debug_draw_calls: bool,      // enable approximate draw call logging
     pub draw_call_log_interval: f32, // seconds between debug logs
+    pub density_mask: Option<VegetationDensityMask>, // painted per level (LevelDef::vegetation_mask)
+    // Placement RNG: with `deterministic` every chunk is seeded from (terrain seed, seed, chunk), so
+    // the same forest grows every run (ghost replays, screenshot diffs). Off = new layout each load.
+    pub deterministic: bool,
+    pub seed: u64,
 }
 impl Default for VegetationConfig {
     fn default() -> Self {
@@ -141,8 +204,6 @@ impl Default for VegetationConfig {
             threshold: 0.50,
             max_instances: 8000,
             min_slope_normal_y: 0.70,
-            scale_min: 0.5,
-            scale_max: 2.0,
             samples_per_frame: 700,
             batch_spawn_flush: 256,
             min_spacing_inner: 16.0,
@@ -150,14 +211,18 @@ impl Default for VegetationConfig {
             min_spacing_rim: 8.0,
             patch_noise_freq: 0.010,
             patch_contrast: 1.7,
-            inner_cap: 140,
+            inner_cap: 40,
             hero_chance: 0.0,
             hero_scale_min_mul: 1.0,
             hero_scale_max_mul: 1.0,
             tilt_max_deg: 7.0,
             use_instanced: true,
+            gpu_instancing: true,
             debug_draw_calls: true,
             draw_call_log_interval: 2.0,
+            density_mask: None,
+            deterministic: true,
+            seed: 0,
         }
     }
 }
@@ -267,23 +332,23 @@ impl Default for VegetationPerfTuner {
     }
 }
 
-// Preloaded assets & shared noise
+// Preloaded assets
 #[derive(Resource)]
 struct VegetationAssets {
-    tree1: Handle<Scene>,
-    tree2: Handle<Scene>,
-    perlin: Perlin,
+    scenes: Vec<Handle<Scene>>, // indexed like VegetationSpeciesList::species
 }
 
-// Instanced mesh/material variants extracted from the scene glbs.
+// Instanced mesh/material variants extracted from the scene glbs, per species
+// (`None` until that species' template scene has been visited).
 #[derive(Resource, Default)]
 struct VegetationMeshVariants {
-    ready: bool,
-    variants: Vec<(Handle<Mesh>, Handle<StandardMaterial>)>,
+    variants: Vec<Option<Vec<(Handle<Mesh>, Handle<StandardMaterial>)>>>,
+    // Swaying copy of each variant material (only when TreeWindPlugin is present).
+    wind_materials: HashMap<AssetId<StandardMaterial>, Handle<TreeWindMaterial>>,
 }
 
 #[derive(Component)]
-struct TreeTemplate;
+struct TreeTemplate(usize); // species index
 
 // Debug state
 #[derive(Resource)]
@@ -293,89 +358,164 @@ struct VegetationDebugState {
     last_unique: usize,
 }
 
-// ---------------- Spatial Hash For Spacing Rejection ----------------
+// ---------------- Tree Index ----------------
 
-#[derive(Default)]
-struct SpacingGrid {
-    cell: f32,
-    cells: HashMap<(i32, i32), Vec<Vec2>>,
+/// World-wide grid of spawned trees (XZ), kept in sync as chunks stream in and out.
+/// Ball-vs-tree collision and other proximity queries use this instead of scanning every tree.
+#[derive(Resource)]
+pub struct VegetationGrid {
+    grid: SpatialGrid<Entity>,
+    positions: HashMap<Entity, Vec2>,
 }
 
-impl SpacingGrid {
-    fn new(cell: f32) -> Self {
-        Self {
-            cell,
-            cells: HashMap::new(),
+impl Default for VegetationGrid {
+    fn default() -> Self {
+        Self { grid: SpatialGrid::new(16.0), positions: HashMap::new() }
+    }
+}
+
+impl VegetationGrid {
+    pub fn len(&self) -> usize {
+        self.grid.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.grid.is_empty()
+    }
+
+    /// Trees whose trunk (XZ) lies within `radius` of `p`.
+    pub fn trees_within(&self, p: Vec2, radius: f32) -> Vec<(Entity, Vec2)> {
+        let mut out = Vec::new();
+        self.grid.for_each_within(p, radius, |q, e| out.push((*e, q)));
+        out
+    }
+
+    pub fn nearest(&self, p: Vec2, radius: f32) -> Option<(Entity, Vec2)> {
+        self.grid.nearest(p, radius).map(|(q, e)| (*e, q))
+    }
+
+    pub fn insert(&mut self, e: Entity, p: Vec2) {
+        self.remove(e);
+        self.grid.insert(p, e);
+        self.positions.insert(e, p);
+    }
+
+    pub fn remove(&mut self, e: Entity) {
+        if let Some(p) = self.positions.remove(&e) {
+            self.grid.remove_where(p, |v| *v == e);
         }
     }
+}
+
+// ---------------- Clusters ----------------
 
-    #[inline(always)]
-    fn key(&self, p: Vec2) -> (i32, i32) {
-        let inv = 1.0 / self.cell;
-        let x = (p.x * inv).floor() as i32;
-        let y = (p.y * inv).floor() as i32;
-        (x, y)
+// Trees bucketed into coarse XZ cells with their bounds. The cull and shadow LOD passes classify a
+// cell against their radius as a unit; `culled` / `shadows` cache the state last applied to every
+// tree of the cell (None = mixed, decided per tree).
+#[derive(Resource)]
+struct VegetationClusters {
+    cell: f32,
+    cells: HashMap<IVec2, TreeCluster>,
+    tree_cell: HashMap<Entity, IVec2>,
+}
+
+struct TreeCluster {
+    trees: Vec<Entity>,
+    min: Vec3,
+    max: Vec3,
+    culled: Option<bool>,
+    shadows: Option<bool>,
+}
+
+impl Default for VegetationClusters {
+    fn default() -> Self {
+        Self { cell: 64.0, cells: HashMap::new(), tree_cell: HashMap::new() }
     }
+}
 
-    #[inline(always)]
-    fn insert(&mut self, p: Vec2) {
-        let k = self.key(p);
-        self.cells.entry(k).or_default().push(p);
+impl VegetationClusters {
+    fn insert(&mut self, e: Entity, p: Vec3) {
+        let key = (p.xz() / self.cell).floor().as_ivec2();
+        let cluster = self.cells.entry(key).or_insert_with(|| TreeCluster {
+            trees: Vec::new(),
+            min: p,
+            max: p,
+            culled: None,
+            shadows: None,
+        });
+        cluster.trees.push(e);
+        cluster.min = cluster.min.min(p);
+        cluster.max = cluster.max.max(p);
+        // New member has default state; re-apply the unit state on the next pass.
+        cluster.culled = None;
+        cluster.shadows = None;
+        self.tree_cell.insert(e, key);
     }
 
-    fn too_close(&self, p: Vec2, spacing: f32) -> bool {
-        if spacing <= 0.0 {
-            return false;
+    // Bounds are not shrunk on removal (stay conservative until the cell empties).
+    fn remove(&mut self, e: Entity) {
+        let Some(key) = self.tree_cell.remove(&e) else { return; };
+        let Some(cluster) = self.cells.get_mut(&key) else { return; };
+        if let Some(i) = cluster.trees.iter().position(|t| *t == e) {
+            cluster.trees.swap_remove(i);
         }
-        let cell_range = ((spacing / self.cell).ceil() as i32).max(1);
-        let (kx, ky) = self.key(p);
-        let spacing2 = spacing * spacing;
-        for dy in -cell_range..=cell_range {
-            for dx in -cell_range..=cell_range {
-                if let Some(list) = self.cells.get(&(kx + dx, ky + dy)) {
-                    for &q in list {
-                        if q.distance_squared(p) < spacing2 {
-                            return true;
-                        }
-                    }
-                }
-            }
+        if cluster.trees.is_empty() {
+            self.cells.remove(&key);
         }
-        false
     }
 }
 
-// Progressive spawn state
-#[derive(Resource)]
+// Per-chunk spawn queue: terrain chunks announce themselves (TerrainChunkReady) and get their
+// vegetation as children, so it despawns with the chunk.
+#[derive(Resource, Default)]
 struct VegetationSpawnState {
-    points: Vec<Vec2>,
-    cursor: usize,
-    spawned: usize,
+    pending: VecDeque<(IVec2, Entity)>,
+    chunks_done: usize,
+    attempts: usize,
+    early_noise_rejects: usize,
+    slope_rejects: usize,
+    cache_hits: usize,
+}
+
+/// One tree chosen for a chunk: world transform, species and variant pick (0..1).
+#[derive(Clone, Debug)]
+pub struct TreePlacement {
+    pub transform: Transform,
+    pub species: usize,
+    pub variant_roll: f32,
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+struct PlacementStats {
     attempts: usize,
     early_noise_rejects: usize,
     slope_rejects: usize,
-    inner_spawned: usize,
-    finished: bool,
-    batch_scene: Vec<(SceneBundle, (Tree, TreeCulled, TreeLod, TreeBaseScale))>,
-    batch_pbr: Vec<(PbrBundle, (Tree, TreeCulled, TreeLod, TreeBaseScale))>,
-    spacing_grid: SpacingGrid,
-    half_extent: f32,
-    seen_cells: HashSet<(i32, i32)>,
 }
 
 // ---------------- Utility / Functional Stages ----------------
 
-fn generate_grid_points(half: f32, cell: f32) -> Vec<Vec2> {
-    let steps = ((half * 2.0) / cell).ceil() as i32;
-    let mut pts = Vec::with_capacity((steps * steps) as usize);
-    for j in -steps / 2..=steps / 2 {
-        for i in -steps / 2..=steps / 2 {
+/// Grid points (multiples of `cell`) inside the chunk's world-space square.
+fn chunk_grid_points(coord: IVec2, chunk_size: f32, cell: f32) -> Vec<Vec2> {
+    let (x0, z0) = (coord.x as f32 * chunk_size, coord.y as f32 * chunk_size);
+    let (i0, i1) = ((x0 / cell).ceil() as i32, ((x0 + chunk_size) / cell).ceil() as i32);
+    let (j0, j1) = ((z0 / cell).ceil() as i32, ((z0 + chunk_size) / cell).ceil() as i32);
+    let mut pts = Vec::with_capacity(((i1 - i0) * (j1 - j0)).max(0) as usize);
+    for j in j0..j1 {
+        for i in i0..i1 {
             pts.push(Vec2::new(i as f32 * cell, j as f32 * cell));
         }
     }
     pts
 }
 
+/// RNG seed for a chunk: same terrain seed + coord => same vegetation, whatever the load order.
+fn chunk_seed(terrain_seed: u32, seed: u64, coord: IVec2) -> u64 {
+    (coord.x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (coord.y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F)
+        ^ ((terrain_seed as u64) << 17)
+        ^ seed.wrapping_mul(0xD6E8_FEB8_6659_FD93)
+}
+
 #[inline(always)]
 fn jitter_point(mut base: Vec2, cell: f32, rng: &mut impl Rng) -> Vec2 {
     base.x += rng.gen_range(-0.45..0.45) * cell;
@@ -430,7 +570,7 @@ fn decide_spawn(density: f32, threshold: f32) -> bool {
 }
 
 #[inline(always)]
-fn build_transform(pos: Vec2, height: f32, rng: &mut impl Rng, cfg: &VegetationConfig) -> Transform {
+fn build_transform(pos: Vec2, height: f32, rng: &mut impl Rng, cfg: &VegetationConfig, species: &VegetationSpecies) -> Transform {
     // Base yaw
     let yaw = Quat::from_rotation_y(rng.gen_range(0.0..std::f32::consts::TAU));
 
@@ -440,15 +580,16 @@ fn build_transform(pos: Vec2, height: f32, rng: &mut impl Rng, cfg: &VegetationC
     let tilt_z = rng.gen_range(-tilt_max..tilt_max);
     let tilt = Quat::from_rotation_x(tilt_x) * Quat::from_rotation_z(tilt_z);
 
-    // Scale (clamped to natural size range 0.5x .. 2x)
-    let mut scale_base = rng.gen_range(cfg.scale_min..cfg.scale_max);
+    // Scale (clamped to the species' natural size range)
+    let (scale_min, scale_max) = (species.scale.0, species.scale.1.max(species.scale.0));
+    let mut scale_base = rng.gen_range(scale_min..=scale_max);
     if rng.gen_bool(cfg.hero_chance as f64) {
         scale_base *= rng.gen_range(cfg.hero_scale_min_mul..cfg.hero_scale_max_mul);
     }
-    scale_base = scale_base.clamp(cfg.scale_min, cfg.scale_max);
-    let sx = (scale_base * rng.gen_range(0.95..1.05)).clamp(cfg.scale_min, cfg.scale_max);
-    let sy = (scale_base * rng.gen_range(0.95..1.10)).clamp(cfg.scale_min, cfg.scale_max);
-    let sz = (scale_base * rng.gen_range(0.95..1.05)).clamp(cfg.scale_min, cfg.scale_max);
+    scale_base = scale_base.clamp(scale_min, scale_max);
+    let sx = (scale_base * rng.gen_range(0.95..1.05)).clamp(scale_min, scale_max);
+    let sy = (scale_base * rng.gen_range(0.95..1.10)).clamp(scale_min, scale_max);
+    let sz = (scale_base * rng.gen_range(0.95..1.05)).clamp(scale_min, scale_max);
     let scale = Vec3::new(sx, sy, sz);
 
     Transform {
@@ -458,27 +599,6 @@ fn build_transform(pos: Vec2, height: f32, rng: &mut impl Rng, cfg: &VegetationC
     }
 }
 
-#[inline(always)]
-fn random_tree_handle(rng: &mut impl Rng, a: &Handle<Scene>, b: &Handle<Scene>) -> Handle<Scene> {
-    if rng.gen_bool(0.5) {
-        a.clone()
-    } else {
-        b.clone()
-    }
-}
-
-#[inline(always)]
-fn random_variant<'a>(
-    rng: &mut impl Rng,
-    variants: &'a [(Handle<Mesh>, Handle<StandardMaterial>)],
-) -> Option<&'a (Handle<Mesh>, Handle<StandardMaterial>)> {
-    if variants.is_empty() {
-        None
-    } else {
-        Some(&variants[rng.gen_range(0..variants.len())])
-    }
-}
-
 // Region weighting strategy.
 // Returns (weight, region_inner_flag).
 fn region_weight(r_len: f32, play_r: f32, rim_start: f32, rim_peak: f32) -> (f32, bool) {
@@ -504,82 +624,40 @@ fn region_weight(r_len: f32, play_r: f32, rim_start: f32, rim_peak: f32) -> (f32
 fn prepare_vegetation(
     mut commands: Commands,
     assets: Res<AssetServer>,
-    sampler: Res<TerrainSampler>,
-    cfg: Res<VegetationConfig>,
+    species: Res<VegetationSpeciesList>,
+    mut variants: ResMut<VegetationMeshVariants>,
 ) {
-    // Cover full visible terrain radius for tree spawning instead of just half a chunk around origin.
-    let half = sampler.cfg.chunk_size * sampler.cfg.view_radius_chunks as f32;
-    let points = generate_grid_points(half, cfg.cell_size);
-    let perlin = Perlin::new(sampler.cfg.seed.wrapping_add(917_331));
-    let tree1 = assets.load("models/tree_1.glb#Scene0");
-    let tree2 = assets.load("models/tree_2.glb#Scene0");
-
-    // Spacing grid cell: half of smallest spacing for fine granularity
-    let spacing_cell =
-        (cfg.min_spacing_rim.min(cfg.min_spacing_slope).min(cfg.min_spacing_inner) * 0.5).max(1.0);
-
-    commands.insert_resource(VegetationAssets {
-        tree1: tree1.clone(),
-        tree2: tree2.clone(),
-        perlin,
-    });
-    // Build initial seen cell set
-    let mut seen_cells = HashSet::new();
-    let step_count = (half / cfg.cell_size).ceil() as i32;
-    for j in -step_count..=step_count {
-        for i in -step_count..=step_count {
-            seen_cells.insert((i, j));
-        }
+    let scenes: Vec<Handle<Scene>> = species.species.iter().map(|s| assets.load(s.model.clone())).collect();
+    variants.variants = vec![None; scenes.len()];
+    commands.insert_resource(VegetationAssets { scenes: scenes.clone() });
+
+    // Hidden template scenes (one per species) to extract mesh/material variants later.
+    for (i, (scene, s)) in scenes.into_iter().zip(&species.species).enumerate() {
+        commands.spawn((
+            SceneBundle {
+                scene,
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            TreeTemplate(i),
+            Name::new(format!("TreeTemplate:{}", s.name)),
+        ));
     }
-
-    commands.insert_resource(VegetationSpawnState {
-        points,
-        cursor: 0,
-        spawned: 0,
-        attempts: 0,
-        early_noise_rejects: 0,
-        slope_rejects: 0,
-        inner_spawned: 0,
-        finished: false,
-        batch_scene: Vec::with_capacity(cfg.batch_spawn_flush),
-        batch_pbr: Vec::with_capacity(cfg.batch_spawn_flush),
-        spacing_grid: SpacingGrid::new(spacing_cell),
-        half_extent: half,
-        seen_cells,
-    });
-
-    // Hidden template scenes to extract mesh/material variants later.
-    commands.spawn((
-        SceneBundle {
-            scene: tree1.clone(),
-            visibility: Visibility::Hidden,
-            ..default()
-        },
-        TreeTemplate,
-        Name::new("TreeTemplate1"),
-    ));
-    commands.spawn((
-        SceneBundle {
-            scene: tree2.clone(),
-            visibility: Visibility::Hidden,
-            ..default()
-        },
-        TreeTemplate,
-        Name::new("TreeTemplate2"),
-    ));
 }
 
 fn extract_tree_mesh_variants(
     mut commands: Commands,
     mut variants: ResMut<VegetationMeshVariants>,
-    q_templates: Query<Entity, With<TreeTemplate>>,
+    species: Res<VegetationSpeciesList>,
+    q_templates: Query<(Entity, &TreeTemplate)>,
     q_children: Query<&Children>,
     q_mesh_mats: Query<(&Handle<Mesh>, &Handle<StandardMaterial>)>,
+    materials: Res<Assets<StandardMaterial>>,
+    mut wind_materials: Option<ResMut<Assets<TreeWindMaterial>>>,
 ) {
-    if variants.ready {
+    if q_templates.is_empty() {
         return;
     }
-    let mut collected: Vec<(Handle<Mesh>, Handle<StandardMaterial>)> = Vec::new();
 
     fn visit(
         e: Entity,
@@ -597,103 +675,84 @@ fn extract_tree_mesh_variants(
         }
     }
 
-    for root in q_templates.iter() {
+    for (root, template) in q_templates.iter() {
+        let mut collected: Vec<(Handle<Mesh>, Handle<StandardMaterial>)> = Vec::new();
         visit(root, &q_children, &q_mesh_mats, &mut collected);
-    }
-
-    if !collected.is_empty() {
+        if collected.is_empty() {
+            continue; // scene not instantiated yet
+        }
         collected.truncate(4); // allow a few variants
-        variants.variants = collected;
-        variants.ready = true;
-        for root in q_templates.iter() {
-            commands.entity(root).despawn_recursive();
+        if let Some(wind_materials) = wind_materials.as_mut() {
+            for (_, mat) in &collected {
+                if let Some(base) = materials.get(mat) {
+                    let wind = wind_materials.add(tree_wind_material(base));
+                    variants.wind_materials.insert(mat.id(), wind);
+                }
+            }
         }
         info!(
-            "Vegetation instancing: extracted {} tree mesh variants",
-            variants.variants.len()
+            "Vegetation instancing: extracted {} mesh variants for '{}'",
+            collected.len(),
+            species.species.get(template.0).map_or("?", |s| s.name.as_str())
         );
+        if let Some(slot) = variants.variants.get_mut(template.0) {
+            *slot = Some(collected);
+        }
+        commands.entity(root).despawn_recursive();
     }
 }
 
-fn vegetation_expand_area(
-    sampler: Res<TerrainSampler>,
-    cfg: Res<VegetationConfig>,
-    q_ball: Query<&Transform, With<Ball>>,
-    mut state: ResMut<VegetationSpawnState>,
-) {
-    let Ok(ball_t) = q_ball.get_single() else { return; };
-    let pos = Vec2::new(ball_t.translation.x, ball_t.translation.z);
-    let dist = pos.length();
-    // Trigger expansion when player nears 60% of current extent
-    if dist < state.half_extent * 0.6 {
-        return;
-    }
-    // Target new half extent (add half a chunk margin)
-    let target = dist + sampler.cfg.chunk_size * 0.5;
-    if target <= state.half_extent + cfg.cell_size {
-        return;
-    }
-    let old_half = state.half_extent;
-    state.half_extent = target;
-
-    let new_steps = (state.half_extent / cfg.cell_size).ceil() as i32;
-    let old_steps = (old_half / cfg.cell_size).ceil() as i32;
-
-    for j in -new_steps..=new_steps {
-        for i in -new_steps..=new_steps {
-            // Skip cells already inside old square
-            if i.abs() <= old_steps && j.abs() <= old_steps {
-                continue;
-            }
-            let key = (i, j);
-            if state.seen_cells.contains(&key) {
-                continue;
-            }
-            state.seen_cells.insert(key);
-            state.points
-                .push(Vec2::new(i as f32 * cfg.cell_size, j as f32 * cfg.cell_size));
-        }
-    }
+// Levels may paint a density mask over the heightmap square (path relative to assets/).
+fn apply_level_density_mask(level: Res<LevelDef>, sampler: Res<TerrainSampler>, mut cfg: ResMut<VegetationConfig>) {
+    cfg.density_mask = level
+        .vegetation_mask
+        .as_ref()
+        .and_then(|path| VegetationDensityMask::load(&format!("assets/{path}"), sampler.cfg.heightmap_world_size));
+}
 
-    // Allow spawning loop to resume if it was previously marked finished
-    if state.finished && state.cursor < state.points.len() && state.spawned < cfg.max_instances {
-        state.finished = false;
+fn queue_chunk_vegetation(mut ev_ready: EventReader<TerrainChunkReady>, mut state: ResMut<VegetationSpawnState>) {
+    for ev in ev_ready.read() {
+        state.pending.push_back((ev.coord, ev.entity));
     }
-
-    info!(
-        "Vegetation area expanded: half_extent {:.1} (points now {})",
-        state.half_extent,
-        state.points.len()
-    );
 }
 
-fn progressive_spawn_trees(
-    mut commands: Commands,
-    sampler: Res<TerrainSampler>,
-    mut state: ResMut<VegetationSpawnState>,
-    assets: Res<VegetationAssets>,
-    variants: Res<VegetationMeshVariants>,
-    cfg: Res<VegetationConfig>,
-) {
-    if state.finished {
-        return;
-    }
-    const MIN_TREE_GROUND: f32 = 50.0;
+/// Tree placements for one terrain chunk (world-space transforms).
+/// With `cfg.deterministic` this depends only on the sampler (heights, biomes, seed), species
+/// list and config (incl. `cfg.seed`).
+pub fn chunk_tree_placements(
+    coord: IVec2,
+    sampler: &TerrainSampler,
+    species: &VegetationSpeciesList,
+    cfg: &VegetationConfig,
+) -> Vec<TreePlacement> {
+    chunk_tree_placements_with_stats(coord, sampler, species, cfg).0
+}
 
-    let mut rng = thread_rng();
-    let total_points = state.points.len();
-    let end = (state.cursor + cfg.samples_per_frame).min(total_points);
+fn chunk_tree_placements_with_stats(
+    coord: IVec2,
+    sampler: &TerrainSampler,
+    species: &VegetationSpeciesList,
+    cfg: &VegetationConfig,
+) -> (Vec<TreePlacement>, PlacementStats) {
+    // Noise fields stay seeded either way so density patches remain continuous across chunks.
+    let perlin = Perlin::new(sampler.cfg.seed.wrapping_add(917_331).wrapping_add(cfg.seed as u32));
+    let seed = if cfg.deterministic { chunk_seed(sampler.cfg.seed, cfg.seed, coord) } else { rand::random() };
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut stats = PlacementStats::default();
+    let mut out = Vec::new();
 
     let play_r = sampler.cfg.play_radius;
     let rim_start = sampler.cfg.rim_start;
     let rim_peak = sampler.cfg.rim_peak;
 
-    let use_pbr = cfg.use_instanced && variants.ready && !variants.variants.is_empty();
+    // Spacing grid cell: half of smallest spacing for fine granularity
+    let spacing_cell =
+        (cfg.min_spacing_rim.min(cfg.min_spacing_slope).min(cfg.min_spacing_inner) * 0.5).max(1.0);
+    let mut spacing_grid: SpatialGrid<()> = SpatialGrid::new(spacing_cell);
+    let mut inner_spawned = 0usize;
 
-    while state.cursor < end && state.spawned < cfg.max_instances {
-        let base = state.points[state.cursor];
-        state.cursor += 1;
-        state.attempts += 1;
+    for base in chunk_grid_points(coord, sampler.cfg.chunk_size, cfg.cell_size) {
+        stats.attempts += 1;
 
         // Jitter point
         let p = jitter_point(base, cfg.cell_size, &mut rng);
@@ -708,7 +767,7 @@ fn progressive_spawn_trees(
         let (weight, region_inner) = region_weight(r_len, play_r, rim_start, rim_peak);
 
         // Enforce sparse inner quota cap
-        if region_inner && state.inner_spawned >= cfg.inner_cap {
+        if region_inner && inner_spawned >= cfg.inner_cap {
             continue;
         }
 
@@ -717,42 +776,49 @@ fn progressive_spawn_trees(
             continue;
         }
 
-        // Noise layers
-        let n_val = noise_density(&assets.perlin, p, cfg.noise_freq);
+        // Noise layers (scaled by the painted mask, if the level has one)
+        let mask = cfg.density_mask.as_ref().map_or(1.0, |m| m.sample(p.x, p.y));
+        if mask <= 0.0 {
+            stats.early_noise_rejects += 1;
+            continue;
+        }
+        let n_val = noise_density(&perlin, p, cfg.noise_freq) * mask;
 
         // Low-frequency patch noise for clustering
-        let patch_raw =
-            assets
-                .perlin
-                .get([p.x as f64 * cfg.patch_noise_freq, p.y as f64 * cfg.patch_noise_freq]);
+        let patch_raw = perlin.get([p.x as f64 * cfg.patch_noise_freq, p.y as f64 * cfg.patch_noise_freq]);
         let patch_norm = (patch_raw as f32 * 0.5 + 0.5).clamp(0.0, 1.0);
         let centered = (patch_norm - 0.5) * cfg.patch_contrast;
         let patch_mod = (centered + 0.5).clamp(0.0, 1.0).powf(1.2); // emphasize extremes a bit
 
+        // Biome drives density (cheap grid lookup); species mix uses the blend weights below.
+        let biome = sampler.biome(p.x, p.y).params();
+
         // Quick preliminary test (approx density before slope & spacing)
-        if cfg.base_density * n_val * patch_mod * r_mask <= cfg.threshold {
-            state.early_noise_rejects += 1;
+        if cfg.base_density * n_val * patch_mod * r_mask * biome.vegetation_density <= cfg.threshold {
+            stats.early_noise_rejects += 1;
             continue;
         }
 
         // Surface sample (expensive)
-        let (h, n) = sample_surface(&sampler, p);
-        // Reject below minimum allowed ground elevation.
-        if h < MIN_TREE_GROUND {
-            continue;
-        }
+        let (h, n) = sample_surface(sampler, p);
         let s_mask = slope_mask(n, cfg.min_slope_normal_y);
         if s_mask <= 0.0 {
-            state.slope_rejects += 1;
+            stats.slope_rejects += 1;
             continue;
         }
 
         // Final density
-        let density = combine_density(cfg.base_density, n_val, r_mask, s_mask) * patch_mod;
+        let density = combine_density(cfg.base_density, n_val, r_mask, s_mask) * patch_mod * biome.vegetation_density;
         if !decide_spawn(density, cfg.threshold) {
             continue;
         }
 
+        // Species whose altitude band / slope tolerance accept this spot, weighted by biome.
+        let Some(species_idx) = species.pick(&mut rng, h, n.y, &sampler.biome_weights(p.x, p.y)) else {
+            continue;
+        };
+        let kind = &species.species[species_idx];
+
         // Region-specific minimum spacing
         let base_spacing = if r_len < play_r {
             cfg.min_spacing_inner
@@ -762,101 +828,295 @@ fn progressive_spawn_trees(
             cfg.min_spacing_rim
         };
         // Slightly reduce spacing inside dense patches so clusters feel fuller
-        let spacing = base_spacing * (0.75 + 0.25 * (1.0 - patch_mod));
+        let spacing = base_spacing * kind.spacing * (0.75 + 0.25 * (1.0 - patch_mod));
 
-        // Spatial hash rejection
-        if state.spacing_grid.too_close(p, spacing) {
+        // Spatial hash rejection (per chunk, so results don't depend on neighbours' load order)
+        if spacing_grid.too_close(p, spacing) {
             continue;
         }
 
-        let transform = build_transform(p, h, &mut rng, &cfg);
-        let base_scale = TreeBaseScale(transform.scale);
-
-        if use_pbr {
-            if let Some((mesh, material)) = random_variant(&mut rng, &variants.variants) {
-                state.batch_pbr.push((
-                    PbrBundle {
-                        mesh: mesh.clone(),
-                        material: material.clone(),
-                        transform,
-                        ..default()
-                    },
-                    (
-                        Tree,
-                        TreeCulled(false),
-                        TreeLod { shadows_on: true },
-                        base_scale,
-                    ),
-                ));
-            }
-        } else {
-            let handle = random_tree_handle(&mut rng, &assets.tree1, &assets.tree2);
-            state.batch_scene.push((
-                SceneBundle {
-                    scene: handle,
-                    transform,
-                    ..default()
-                },
-                (
-                    Tree,
-                    TreeCulled(false),
-                    TreeLod { shadows_on: true },
-                    base_scale,
-                ),
-            ));
-        }
+        let transform = build_transform(p, h, &mut rng, cfg, kind);
+        out.push(TreePlacement { transform, species: species_idx, variant_roll: rng.gen() });
 
         if region_inner {
-            state.inner_spawned += 1;
+            inner_spawned += 1;
+        }
+        spacing_grid.insert(p, ());
+    }
+    (out, stats)
+}
+
+/// Second scatter pass: props for one chunk (world-space), kept clear of the chunk's `trees`.
+/// Seeded like the tree pass (salted), so it is deterministic under the same conditions.
+pub fn chunk_prop_placements(
+    coord: IVec2,
+    sampler: &TerrainSampler,
+    props: &PropScatterConfig,
+    cfg: &VegetationConfig,
+    trees: &[TreePlacement],
+) -> Vec<PropPlacement> {
+    if !props.enabled || props.rules.is_empty() {
+        return Vec::new();
+    }
+    let perlin = Perlin::new(sampler.cfg.seed.wrapping_add(311_783).wrapping_add(cfg.seed as u32));
+    let seed = if cfg.deterministic {
+        chunk_seed(sampler.cfg.seed, cfg.seed ^ 0x5052_4F50, coord)
+    } else {
+        rand::random()
+    };
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut tree_grid: SpatialGrid<()> = SpatialGrid::new(props.tree_clearance.max(1.0));
+    for t in trees {
+        tree_grid.insert(t.transform.translation.xz(), ());
+    }
+    // One spacing class per rule.
+    let mut spacing: Vec<SpatialGrid<()>> =
+        props.rules.iter().map(|r| SpatialGrid::new((r.spacing * 0.5).max(1.0))).collect();
+    let mut out = Vec::new();
+
+    for base in chunk_grid_points(coord, sampler.cfg.chunk_size, props.cell_size) {
+        if out.len() >= props.max_per_chunk {
+            break;
         }
-        state.spacing_grid.insert(p);
-        state.spawned += 1;
-
-        if state.batch_scene.len() >= cfg.batch_spawn_flush {
-            let drained = std::mem::take(&mut state.batch_scene);
-            commands.spawn_batch(drained.into_iter().map(
-                |(bundle, comps)| (bundle, comps.0, comps.1, comps.2, comps.3),
-            ));
+        let p = jitter_point(base, props.cell_size, &mut rng);
+        let (roll, pick): (f32, f32) = (rng.gen(), rng.gen());
+
+        // Same cleared play area and painted mask as the trees; noise clumps props together.
+        let mask = radial_mask(p, sampler.cfg.play_radius)
+            * cfg.density_mask.as_ref().map_or(1.0, |m| m.sample(p.x, p.y));
+        let clump = noise_density(&perlin, p, props.noise_freq);
+        if roll >= props.density * mask * (0.5 + clump) {
+            continue;
+        }
+        if tree_grid.too_close(p, props.tree_clearance) {
+            continue;
         }
-        if state.batch_pbr.len() >= cfg.batch_spawn_flush {
-            let drained = std::mem::take(&mut state.batch_pbr);
-            commands.spawn_batch(drained.into_iter().map(
-                |(bundle, comps)| (bundle, comps.0, comps.1, comps.2, comps.3),
-            ));
+
+        let (h, n) = sample_surface(sampler, p);
+        let weights: Vec<f32> = props.rules.iter().map(|r| r.weight_at(h, n.y).max(0.0)).collect();
+        let total: f32 = weights.iter().sum();
+        if total <= 0.0 {
+            continue;
+        }
+        let mut r = pick * total;
+        let Some(i) = weights.iter().position(|w| {
+            let hit = *w > 0.0 && r < *w;
+            r -= w;
+            hit
+        }) else {
+            continue;
+        };
+        let rule = &props.rules[i];
+        if spacing[i].too_close(p, rule.spacing) {
+            continue;
         }
+        spacing[i].insert(p, ());
+
+        let scale = rng.gen_range(rule.scale.0..=rule.scale.1.max(rule.scale.0));
+        // Half-way between upright and the ground normal, random yaw, sunk a little.
+        let up = (n + Vec3::Y).normalize();
+        let rotation = Quat::from_rotation_arc(Vec3::Y, up) * Quat::from_rotation_y(rng.gen_range(0.0..std::f32::consts::TAU));
+        let transform = Transform {
+            translation: Vec3::new(p.x, h - 0.05 * scale, p.y),
+            rotation,
+            scale: Vec3::splat(scale),
+        };
+        out.push(PropPlacement { kind: rule.kind, rule: i, transform });
     }
+    out
+}
 
-    // Flush remainder
-    if !state.batch_scene.is_empty() {
-        let drained = std::mem::take(&mut state.batch_scene);
-        commands.spawn_batch(
-            drained
-                .into_iter()
-                .map(|(bundle, comps)| (bundle, comps.0, comps.1, comps.2, comps.3)),
-        );
+fn spawn_chunk_vegetation(
+    mut commands: Commands,
+    sampler: Res<TerrainSampler>,
+    mut state: ResMut<VegetationSpawnState>,
+    assets: Option<Res<VegetationAssets>>,
+    variants: Res<VegetationMeshVariants>,
+    species: Res<VegetationSpeciesList>,
+    cfg: Res<VegetationConfig>,
+    prop_cfg: Res<PropScatterConfig>,
+    prop_assets: Res<PropAssets>,
+    mut cache: ResMut<VegetationCache>,
+    exclusions: Res<VegetationExclusions>,
+    entities: &Entities,
+    q_trees: Query<(), With<Tree>>,
+    q_cam: Query<(&GlobalTransform, &Frustum), With<OrbitCamera>>,
+) {
+    // Placement samples the ground: wait for the decoded heightmap.
+    if state.pending.is_empty() || sampler.is_placeholder() {
+        return;
     }
-    if !state.batch_pbr.is_empty() {
-        let drained = std::mem::take(&mut state.batch_pbr);
-        commands.spawn_batch(
-            drained
-                .into_iter()
-                .map(|(bundle, comps)| (bundle, comps.0, comps.1, comps.2, comps.3)),
+    let view = SpawnView::from_camera(&q_cam);
+    let chunk_size = sampler.cfg.chunk_size;
+    // Bounding sphere of a chunk's vegetation: centre at ground level, trees fit inside one chunk size.
+    let chunk_priority = |coord: &IVec2| {
+        let Some(view) = &view else { return 0.0; };
+        let c = (coord.as_vec2() + Vec2::splat(0.5)) * chunk_size;
+        view.priority(Vec3::new(c.x, sampler.height(c.x, c.y), c.y), chunk_size)
+    };
+    let Some(assets) = assets else { return; };
+    let mut live = q_trees.iter().count();
+    // Per species: instanced meshes once its template has been extracted, scenes until then.
+    let pbr_variants = |i: usize| {
+        variants.variants.get(i).and_then(|v| v.as_deref()).filter(|v| cfg.use_instanced && !v.is_empty())
+    };
+
+    let mut budget = cfg.samples_per_frame;
+    while budget > 0 {
+        let next = next_by_priority(state.pending.iter(), |(coord, _)| chunk_priority(coord));
+        let Some((coord, chunk)) = next.and_then(|i| state.pending.remove(i)) else { break; };
+        if !entities.contains(chunk) {
+            continue; // unloaded (or replaced at another LOD) before its turn
+        }
+        if live >= cfg.max_instances {
+            // Full: the chunk waits in the queue until trees elsewhere despawn (unloaded ones go).
+            state.pending.push_back((coord, chunk));
+            state.pending.retain(|(_, e)| entities.contains(*e));
+            break;
+        }
+        // Cached chunks only pay for spawning (charged per tree against the sample budget).
+        let (placements, stats) = match cache.get(coord) {
+            Some(cached) => {
+                state.cache_hits += 1;
+                (cached.to_vec(), PlacementStats { attempts: cached.len(), ..default() })
+            }
+            None => {
+                let generated = chunk_tree_placements_with_stats(coord, &sampler, &species, &cfg);
+                cache.insert(coord, generated.0.clone());
+                generated
+            }
+        };
+        let mut props = chunk_prop_placements(coord, &sampler, &prop_cfg, &cfg, &placements);
+        // Zones move (targets relocate), so they filter after the cache rather than in placement.
+        let mut placements = placements;
+        if !exclusions.is_empty() {
+            placements.retain(|p| !exclusions.contains(p.transform.translation.xz()));
+            props.retain(|p| !exclusions.contains(p.transform.translation.xz()));
+        }
+        budget = budget.saturating_sub(stats.attempts.max(1));
+        state.attempts += stats.attempts;
+        state.early_noise_rejects += stats.early_noise_rejects;
+        state.slope_rejects += stats.slope_rejects;
+        state.chunks_done += 1;
+
+        // Children of the chunk: local transform relative to the chunk origin.
+        let origin = Vec3::new(coord.x as f32, 0.0, coord.y as f32) * sampler.cfg.chunk_size;
+        let mut scene_bundles = Vec::new();
+        let mut pbr_bundles = Vec::new();
+        let mut wind_bundles = Vec::new();
+        let mut gpu_bundles = Vec::new();
+        for placement in placements.into_iter().take(cfg.max_instances - live) {
+            let mut transform = placement.transform;
+            transform.translation -= origin;
+            let comps = (Tree, TreeCulled(false), TreeLod { shadows_on: true }, TreeBaseScale(transform.scale));
+            match pbr_variants(placement.species) {
+                Some(list) => {
+                    let i = ((placement.variant_roll * list.len() as f32) as usize).min(list.len() - 1);
+                    let (mesh, material) = list[i].clone();
+                    if cfg.gpu_instancing {
+                        gpu_bundles.push((SpatialBundle::from_transform(transform), TreeInstanceOf { mesh, material }, comps));
+                    } else {
+                        match variants.wind_materials.get(&material.id()) {
+                            Some(wind) => wind_bundles.push((
+                                MaterialMeshBundle { mesh, material: wind.clone(), transform, ..default() },
+                                comps,
+                            )),
+                            None => pbr_bundles.push((PbrBundle { mesh, material, transform, ..default() }, comps)),
+                        }
+                    }
+                }
+                None => scene_bundles.push((
+                    SceneBundle { scene: assets.scenes[placement.species].clone(), transform, ..default() },
+                    comps,
+                )),
+            }
+            live += 1;
+        }
+        let prop_bundles: Vec<_> = props
+            .into_iter()
+            .map(|placement| {
+                let mut transform = placement.transform;
+                transform.translation -= origin;
+                let rule = &prop_cfg.rules[placement.rule];
+                let k = placement.kind.index();
+                let bundle = PbrBundle {
+                    mesh: prop_assets.meshes[k].clone(),
+                    material: prop_assets.materials[k].clone(),
+                    transform,
+                    ..default()
+                };
+                let prop = Prop {
+                    kind: placement.kind,
+                    collision_radius: rule.collision_radius * transform.scale.x,
+                    collision_height: rule.collision_height * transform.scale.y,
+                };
+                (bundle, prop, placement.kind == PropKind::Mushroom)
+            })
+            .collect();
+        debug!(
+            "Vegetation chunk {:?}: {} trees, {} props ({} samples)",
+            coord,
+            scene_bundles.len() + pbr_bundles.len() + wind_bundles.len() + gpu_bundles.len(),
+            prop_bundles.len(),
+            stats.attempts
         );
+        // Applied as a world command so a chunk despawned earlier this frame is skipped safely.
+        commands.add(move |world: &mut World| {
+            let Some(mut chunk) = world.get_entity_mut(chunk) else { return; };
+            chunk.with_children(|p| {
+                for b in scene_bundles {
+                    p.spawn(b);
+                }
+                for b in pbr_bundles {
+                    p.spawn(b);
+                }
+                for b in wind_bundles {
+                    p.spawn(b);
+                }
+                for b in gpu_bundles {
+                    p.spawn(b);
+                }
+                for (bundle, prop, tiny) in prop_bundles {
+                    let mut e = p.spawn((bundle, prop));
+                    if tiny {
+                        e.insert(NotShadowCaster);
+                    }
+                }
+            });
+        });
     }
 
-    // Finish condition
-    if state.cursor >= total_points || state.spawned >= cfg.max_instances {
-        state.finished = true;
+    if state.pending.is_empty() {
         info!(
-            "Vegetation build complete: spawned {} (inner={}) / attempts {} (early_noise_rejects={}, slope_rejects={}, points={}) [instanced:{}]",
-            state.spawned,
-            state.inner_spawned,
+            "Vegetation streaming idle: {} trees live, {} chunks populated ({} cached) (attempts={}, early_noise_rejects={}, slope_rejects={}) [instanced:{}]",
+            live,
+            state.chunks_done,
+            state.cache_hits,
             state.attempts,
             state.early_noise_rejects,
             state.slope_rejects,
-            total_points,
-            use_pbr
+            cfg.use_instanced
         );
+        cache.save();
+    }
+}
+
+// Keep VegetationGrid / VegetationClusters in sync (after transform propagation so chunk children
+// have world positions).
+fn index_trees(
+    mut grid: ResMut<VegetationGrid>,
+    mut clusters: ResMut<VegetationClusters>,
+    q_added: Query<(Entity, &GlobalTransform), Added<Tree>>,
+    mut removed: RemovedComponents<Tree>,
+) {
+    for e in removed.read() {
+        grid.remove(e);
+        clusters.remove(e);
+    }
+    for (e, gt) in &q_added {
+        grid.insert(e, gt.translation().xz());
+        clusters.insert(e, gt.translation());
     }
 }
 
@@ -864,8 +1124,9 @@ fn cull_trees(
     time: Res<Time>,
     cfg: Res<VegetationCullingConfig>,
     mut state: ResMut<VegetationCullingState>,
+    mut clusters: ResMut<VegetationClusters>,
     q_ball: Query<&Transform, With<Ball>>,
-    mut q_trees: Query<(&mut Visibility, &Transform, &mut TreeCulled), With<Tree>>,
+    mut q_trees: Query<(&mut Visibility, &GlobalTransform, &mut TreeCulled), With<Tree>>,
 ) {
     if !cfg.enable_distance {
         return;
@@ -880,17 +1141,45 @@ fn cull_trees(
     let origin = ball_t.translation;
     let max_d = cfg.max_distance;
     let h = cfg.hysteresis;
-    let hide_r2 = (max_d + h).powi(2);
-    let show_r2 = (max_d - h).max(0.0).powi(2);
-
-    for (mut vis, t, mut culled) in &mut q_trees {
-        let d2 = (t.translation - origin).length_squared();
-        if !culled.0 && d2 > hide_r2 {
-            *vis = Visibility::Hidden;
-            culled.0 = true;
-        } else if culled.0 && d2 < show_r2 {
-            *vis = Visibility::Inherited;
-            culled.0 = false;
+    let hide_r = max_d + h;
+    let show_r = (max_d - h).max(0.0);
+    let hide_r2 = hide_r * hide_r;
+    let show_r2 = show_r * show_r;
+
+    for cluster in clusters.cells.values_mut() {
+        let (near, far) = distance_range_to_box(origin, cluster.min, cluster.max);
+        let unit = if near > hide_r {
+            Some(true)
+        } else if far < show_r {
+            Some(false)
+        } else {
+            None
+        };
+        if let Some(hidden) = unit {
+            if cluster.culled != Some(hidden) {
+                for &e in &cluster.trees {
+                    let Ok((mut vis, _, mut culled)) = q_trees.get_mut(e) else { continue; };
+                    if culled.0 != hidden {
+                        *vis = if hidden { Visibility::Hidden } else { Visibility::Inherited };
+                        culled.0 = hidden;
+                    }
+                }
+                cluster.culled = Some(hidden);
+            }
+            continue;
+        }
+        // Boundary cell: per-tree hysteresis.
+        cluster.culled = None;
+        for &e in &cluster.trees {
+            let Ok((mut vis, t, mut culled)) = q_trees.get_mut(e) else { continue; };
+            let d2 = (t.translation() - origin).length_squared();
+            if !culled.0 && d2 > hide_r2 {
+                *vis = Visibility::Hidden;
+                culled.0 = true;
+            } else if culled.0 && d2 < show_r2 {
+                *vis = Visibility::Inherited;
+                culled.0 = false;
+            }
         }
     }
 }
@@ -899,7 +1188,7 @@ fn cull_trees(
 fn tree_distance_fade(
     fade_cfg: Res<VegetationFadeConfig>,
     q_ball: Query<&Transform, (With<Ball>, Without<Tree>)>,
-    mut q_trees: Query<(&mut Transform, &TreeBaseScale, &mut Visibility, &TreeCulled), With<Tree>>,
+    mut q_trees: Query<(&mut Transform, &GlobalTransform, &TreeBaseScale, &mut Visibility, &TreeCulled), With<Tree>>,
 ) {
     if !fade_cfg.enable {
         return;
@@ -911,11 +1200,11 @@ fn tree_distance_fade(
     let end2 = fade_cfg.end * fade_cfg.end;
     let span = (fade_cfg.end - fade_cfg.start).max(1.0);
 
-    for (mut transform, base_scale, mut vis, culled) in &mut q_trees {
+    for (mut transform, global, base_scale, mut vis, culled) in &mut q_trees {
         if culled.0 {
             continue;
         }
-        let d_vec = transform.translation - origin;
+        let d_vec = global.translation() - origin;
         let d2 = d_vec.length_squared();
 
         if d2 <= start2 {
@@ -943,8 +1232,9 @@ fn tree_lod_update(
     time: Res<Time>,
     cfg: Res<VegetationLodConfig>,
     mut state: ResMut<VegetationLodState>,
+    mut clusters: ResMut<VegetationClusters>,
     q_ball: Query<&Transform, With<Ball>>,
-    mut q_trees: Query<(Entity, &Transform, &mut TreeLod, Option<&NotShadowCaster>), With<Tree>>,
+    mut q_trees: Query<(&GlobalTransform, &mut TreeLod, Option<&NotShadowCaster>), With<Tree>>,
     mut commands: Commands,
 ) {
     if !state.timer.tick(time.delta()).just_finished() {
@@ -961,32 +1251,66 @@ fn tree_lod_update(
     let enable_threshold = (cfg.shadows_full_on + cfg.hysteresis).powi(2);
     let disable_threshold = (cfg.shadows_full_off - cfg.hysteresis).powi(2);
 
-    for (e, t, mut lod, shadow_flag) in &mut q_trees {
-        let d2 = (t.translation - origin).length_squared();
+    for cluster in clusters.cells.values_mut() {
+        // Whole cell inside the hard "on" radius or beyond the hard "off" radius.
+        let (near, far) = distance_range_to_box(origin, cluster.min, cluster.max);
+        let unit = if far < cfg.shadows_full_on {
+            Some(true)
+        } else if near > cfg.shadows_full_off {
+            Some(false)
+        } else {
+            None
+        };
+        if let Some(on) = unit {
+            if cluster.shadows != Some(on) {
+                for &e in &cluster.trees {
+                    let Ok((_, mut lod, shadow_flag)) = q_trees.get_mut(e) else { continue; };
+                    lod.shadows_on = on;
+                    match (on, shadow_flag.is_some()) {
+                        (true, true) => {
+                            commands.entity(e).remove::<NotShadowCaster>();
+                        }
+                        (false, false) => {
+                            commands.entity(e).insert(NotShadowCaster);
+                        }
+                        _ => {}
+                    }
+                }
+                cluster.shadows = Some(on);
+            }
+            continue;
+        }
 
-        if lod.shadows_on {
-            if d2 > disable_threshold {
-                lod.shadows_on = false;
-                if shadow_flag.is_none() {
-                    commands.entity(e).insert(NotShadowCaster);
+        // Boundary cell: per-tree hysteresis.
+        cluster.shadows = None;
+        for &e in &cluster.trees {
+            let Ok((t, mut lod, shadow_flag)) = q_trees.get_mut(e) else { continue; };
+            let d2 = (t.translation() - origin).length_squared();
+
+            if lod.shadows_on {
+                if d2 > disable_threshold {
+                    lod.shadows_on = false;
+                    if shadow_flag.is_none() {
+                        commands.entity(e).insert(NotShadowCaster);
+                    }
+                }
+            } else if d2 < enable_threshold {
+                lod.shadows_on = true;
+                if shadow_flag.is_some() {
+                    commands.entity(e).remove::<NotShadowCaster>();
                 }
             }
-        } else if d2 < enable_threshold {
-            lod.shadows_on = true;
-            if shadow_flag.is_some() {
+
+            // Hard clamp extremes
+            if d2 > off_d2 && shadow_flag.is_none() {
+                commands.entity(e).insert(NotShadowCaster);
+                lod.shadows_on = false;
+            }
+            if d2 < on_d2 && shadow_flag.is_some() {
                 commands.entity(e).remove::<NotShadowCaster>();
+                lod.shadows_on = true;
             }
         }
-
-        // Hard clamp extremes
-        if d2 > off_d2 && shadow_flag.is_none() {
-            commands.entity(e).insert(NotShadowCaster);
-            lod.shadows_on = false;
-        }
-        if d2 < on_d2 && shadow_flag.is_some() {
-            commands.entity(e).remove::<NotShadowCaster>();
-            lod.shadows_on = true;
-        }
     }
 }
 
@@ -996,6 +1320,8 @@ fn vegetation_perf_tuner(
     mut tuner: ResMut<VegetationPerfTuner>,
     mut cull_cfg: ResMut<VegetationCullingConfig>,
     mut lod_cfg: ResMut<VegetationLodConfig>,
+    mut grass_cfg: Option<ResMut<GrassConfig>>,
+    mut bird_cfg: Option<ResMut<BirdConfig>>,
 ) {
     if !tuner.timer.tick(time.delta()).just_finished() {
         return;
@@ -1023,6 +1349,17 @@ fn vegetation_perf_tuner(
             lod_cfg.shadows_full_off =
                 (lod_cfg.shadows_full_off - tuner.adjust_step).max(tuner.min_shadow_off);
         }
+        // Grass is the cheapest layer to thin out.
+        if let Some(ref mut g) = grass_cfg {
+            if g.density_scale > g.min_density_scale {
+                g.density_scale = (g.density_scale - 0.1).max(g.min_density_scale);
+            }
+        }
+        if let Some(ref mut b) = bird_cfg {
+            if b.density_scale > b.min_density_scale {
+                b.density_scale = (b.density_scale - 0.1).max(b.min_density_scale);
+            }
+        }
     } else if ratio > tuner.high_band {
         // Relax
         if cull_cfg.enable_distance && cull_cfg.max_distance < tuner.default_cull {
@@ -1037,6 +1374,16 @@ fn vegetation_perf_tuner(
             lod_cfg.shadows_full_off = (lod_cfg.shadows_full_off + tuner.adjust_step)
                 .min(tuner.default_shadow_off.min(tuner.max_shadow_off));
         }
+        if let Some(ref mut g) = grass_cfg {
+            if g.density_scale < 1.0 {
+                g.density_scale = (g.density_scale + 0.05).min(1.0);
+            }
+        }
+        if let Some(ref mut b) = bird_cfg {
+            if b.density_scale < 1.0 {
+                b.density_scale = (b.density_scale + 0.05).min(1.0);
+            }
+        }
     } else {
         // Drift toward defaults
         if cull_cfg.enable_distance && (cull_cfg.max_distance - tuner.default_cull).abs() > 1.0 {
@@ -1068,6 +1415,11 @@ fn vegetation_draw_call_debug(
         (&Handle<Mesh>, &Handle<StandardMaterial>, Option<&NotShadowCaster>, &TreeCulled),
         With<Tree>,
     >,
+    q_tree_wind: Query<
+        (&Handle<Mesh>, &Handle<TreeWindMaterial>, Option<&NotShadowCaster>, &TreeCulled),
+        With<Tree>,
+    >,
+    q_tree_gpu: Query<(&TreeInstanceOf, &TreeCulled), With<Tree>>,
 ) {
     if !cfg.debug_draw_calls {
         return;
@@ -1077,21 +1429,36 @@ fn vegetation_draw_call_debug(
     }
 
     // Use Handle/AssetId hashing directly (no uuid() in Bevy 0.14).
-    let mut unique: HashSet<(Handle<Mesh>, Handle<StandardMaterial>, bool)> = HashSet::new();
+    let mut unique: HashSet<(AssetId<Mesh>, UntypedAssetId, bool)> = HashSet::new();
     let mut visible = 0usize;
     for (mesh, mat, shadow_flag, culled) in &q_tree_mesh {
         if culled.0 {
             continue;
         }
         visible += 1;
-        let key = (mesh.clone(), mat.clone(), shadow_flag.is_none());
+        let key = (mesh.id(), mat.id().untyped(), shadow_flag.is_none());
         unique.insert(key);
     }
+    for (mesh, mat, shadow_flag, culled) in &q_tree_wind {
+        if culled.0 {
+            continue;
+        }
+        visible += 1;
+        unique.insert((mesh.id(), mat.id().untyped(), shadow_flag.is_none()));
+    }
+    // GPU-instanced trees: one draw per (mesh, material) batch, never shadowed.
+    for (of, culled) in &q_tree_gpu {
+        if culled.0 {
+            continue;
+        }
+        visible += 1;
+        unique.insert((of.mesh.id(), of.material.id().untyped(), false));
+    }
 
     dbg_state.last_visible = visible;
     dbg_state.last_unique = unique.len();
     info!(
-        "Vegetation DrawCallDebug: visible_trees={} approx_unique_batches={} (instanced_mode={})",
-        visible, dbg_state.last_unique, cfg.use_instanced
+        "Vegetation DrawCallDebug: visible_trees={} approx_unique_batches={} (instanced_mode={}, gpu_instancing={})",
+        visible, dbg_state.last_unique, cfg.use_instanced, cfg.gpu_instancing
     );
 }
@@ -0,0 +1,303 @@
+// Vegetation placement cache.
+// Accepted tree placements are written to a small binary file once streaming goes idle and are
+// read back on the next launch, so chunks skip the placement pass (noise, surface sampling,
+// spacing) and only spawn. The file is keyed by a hash of everything placement depends on:
+// terrain seed / shape / heightmap bytes, the placement fields of VegetationConfig, the species
+// list and the density mask. A different key discards the file, so edits invalidate it.
+//  - Only deterministic placement is cached (`VegetationConfig::deterministic`).
+//  - Props are not cached; they are cheap and derive from the (cached) trees.
+//  - Native only: wasm has no filesystem, the cache stays disabled there.
+//  - The file lives in the data directory (see storage.rs).
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::plugins::storage::StoragePaths;
+use crate::plugins::terrain::TerrainSampler;
+use crate::plugins::vegetation::{TreePlacement, VegetationConfig};
+use crate::plugins::vegetation_species::VegetationSpeciesList;
+
+const MAGIC: &[u8; 4] = b"VGCH";
+// Bump when placement code changes in a way the key cannot see.
+const FORMAT_VERSION: u32 = 1;
+
+#[cfg(not(target_arch = "wasm32"))]
+const CACHE_FILE: &str = "vegetation_cache.bin";
+
+// FNV-1a: stable across builds and platforms (std's DefaultHasher is not guaranteed to be).
+struct KeyHasher(u64);
+
+impl KeyHasher {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+    fn bytes(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= *b as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    fn u64(&mut self, v: u64) {
+        self.bytes(&v.to_le_bytes());
+    }
+    fn f32(&mut self, v: f32) {
+        self.bytes(&v.to_bits().to_le_bytes());
+    }
+    fn str(&mut self, s: &str) {
+        self.u64(s.len() as u64);
+        self.bytes(s.as_bytes());
+    }
+}
+
+/// Hash of every input tree placement depends on. Equal keys => identical placements.
+pub fn vegetation_cache_key(sampler: &TerrainSampler, species: &VegetationSpeciesList, cfg: &VegetationConfig) -> u64 {
+    let mut h = KeyHasher::new();
+    h.u64(FORMAT_VERSION as u64);
+
+    let t = &sampler.cfg;
+    h.u64(t.seed as u64);
+    for v in [t.amplitude, t.chunk_size, t.play_radius, t.rim_start, t.rim_peak, t.heightmap_world_size, t.heightmap_max_height] {
+        h.f32(v);
+    }
+    let (w, hgt, data) = sampler.heightmap_data();
+    h.u64(((w as u64) << 32) | hgt as u64);
+    h.bytes(data);
+
+    for v in [
+        cfg.cell_size,
+        cfg.base_density,
+        cfg.threshold,
+        cfg.min_slope_normal_y,
+        cfg.min_spacing_inner,
+        cfg.min_spacing_slope,
+        cfg.min_spacing_rim,
+        cfg.patch_contrast,
+        cfg.hero_chance,
+        cfg.hero_scale_min_mul,
+        cfg.hero_scale_max_mul,
+        cfg.tilt_max_deg,
+    ] {
+        h.f32(v);
+    }
+    h.u64(cfg.noise_freq.to_bits());
+    h.u64(cfg.patch_noise_freq.to_bits());
+    h.u64(cfg.inner_cap as u64);
+    h.u64(cfg.seed);
+    match &cfg.density_mask {
+        Some(mask) => {
+            let (w, hgt, size, data) = mask.data();
+            h.u64(((w as u64) << 32) | hgt as u64);
+            h.f32(size);
+            h.bytes(data);
+        }
+        None => h.u64(0),
+    }
+
+    h.u64(species.species.len() as u64);
+    for s in &species.species {
+        h.str(&s.name);
+        h.str(&s.model);
+        let b = &s.biomes;
+        for v in [
+            s.weight, s.scale.0, s.scale.1, s.min_normal_y, s.altitude.0, s.altitude.1, s.spacing,
+            b.meadow, b.forest, b.highland, b.wetland,
+        ] {
+            h.f32(v);
+        }
+    }
+    h.0
+}
+
+/// Per-chunk tree placements for the current key, backed by `vegetation_cache.bin`.
+#[derive(Resource)]
+pub struct VegetationCache {
+    pub enabled: bool,
+    key: Option<u64>, // None = inactive (disabled or non-deterministic placement)
+    chunks: HashMap<IVec2, Vec<TreePlacement>>,
+    dirty: bool,      // holds chunks the file does not have yet
+    paths: StoragePaths,
+}
+
+impl FromWorld for VegetationCache {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            enabled: cfg!(not(target_arch = "wasm32")),
+            key: None,
+            chunks: HashMap::new(),
+            dirty: false,
+            paths: StoragePaths::in_world(world),
+        }
+    }
+}
+
+impl VegetationCache {
+    pub fn key(&self) -> Option<u64> {
+        self.key
+    }
+
+    pub fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    pub fn get(&self, coord: IVec2) -> Option<&[TreePlacement]> {
+        self.key?;
+        self.chunks.get(&coord).map(Vec::as_slice)
+    }
+
+    /// Remember a freshly generated chunk (no-op while inactive).
+    pub fn insert(&mut self, coord: IVec2, placements: Vec<TreePlacement>) {
+        if self.key.is_some() {
+            self.chunks.insert(coord, placements);
+            self.dirty = true;
+        }
+    }
+
+    /// Switch to `key`, loading the file if it was written for it. Returns true if the key changed.
+    pub fn set_key(&mut self, key: Option<u64>) -> bool {
+        if self.key == key {
+            return false;
+        }
+        self.key = key;
+        self.dirty = false;
+        self.chunks = key.and_then(|key| load_cache_file(&self.paths, key)).unwrap_or_default();
+        if key.is_some() && !self.chunks.is_empty() {
+            info!("Vegetation cache: loaded {} chunks", self.chunks.len());
+        }
+        true
+    }
+
+    /// Write the file if it is missing chunks.
+    pub fn save(&mut self) {
+        let Some(key) = self.key else { return; };
+        if !self.dirty {
+            return;
+        }
+        self.dirty = false;
+        save_cache_file(&self.paths, &encode_cache(key, &self.chunks));
+    }
+}
+
+/// Binary layout (little endian): magic, version u32, key u64, chunk count u32, then per chunk
+/// coord (i32, i32), tree count u32 and per tree translation 3×f32, rotation 4×f32, scale 3×f32,
+/// species u32, variant roll f32.
+pub fn encode_cache(key: u64, chunks: &HashMap<IVec2, Vec<TreePlacement>>) -> Vec<u8> {
+    let trees: usize = chunks.values().map(Vec::len).sum();
+    let mut out = Vec::with_capacity(20 + chunks.len() * 12 + trees * 48);
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    out.extend_from_slice(&key.to_le_bytes());
+    out.extend_from_slice(&(chunks.len() as u32).to_le_bytes());
+    for (coord, placements) in chunks {
+        out.extend_from_slice(&coord.x.to_le_bytes());
+        out.extend_from_slice(&coord.y.to_le_bytes());
+        out.extend_from_slice(&(placements.len() as u32).to_le_bytes());
+        for p in placements {
+            let t = &p.transform;
+            for v in t.translation.to_array().into_iter().chain(t.rotation.to_array()).chain(t.scale.to_array()) {
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+            out.extend_from_slice(&(p.species as u32).to_le_bytes());
+            out.extend_from_slice(&p.variant_roll.to_le_bytes());
+        }
+    }
+    out
+}
+
+/// Inverse of `encode_cache`; None if the data is malformed or written for another key.
+pub fn decode_cache(bytes: &[u8], key: u64) -> Option<HashMap<IVec2, Vec<TreePlacement>>> {
+    let mut r = Reader(bytes);
+    if r.take(4)? != MAGIC || r.u32()? != FORMAT_VERSION || r.u64()? != key {
+        return None;
+    }
+    let n_chunks = r.u32()? as usize;
+    let mut chunks = HashMap::with_capacity(n_chunks.min(4096));
+    for _ in 0..n_chunks {
+        let coord = IVec2::new(r.u32()? as i32, r.u32()? as i32);
+        let n = r.u32()? as usize;
+        let mut placements = Vec::with_capacity(n.min(4096));
+        for _ in 0..n {
+            let translation = Vec3::new(r.f32()?, r.f32()?, r.f32()?);
+            let rotation = Quat::from_xyzw(r.f32()?, r.f32()?, r.f32()?, r.f32()?);
+            let scale = Vec3::new(r.f32()?, r.f32()?, r.f32()?);
+            let species = r.u32()? as usize;
+            let variant_roll = r.f32()?;
+            placements.push(TreePlacement { transform: Transform { translation, rotation, scale }, species, variant_roll });
+        }
+        chunks.insert(coord, placements);
+    }
+    r.0.is_empty().then_some(chunks)
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.0.len() < n {
+            return None;
+        }
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        Some(head)
+    }
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4).map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+    }
+    fn u64(&mut self) -> Option<u64> {
+        self.take(8).map(|b| u64::from_le_bytes(b.try_into().unwrap()))
+    }
+    fn f32(&mut self) -> Option<f32> {
+        self.u32().map(f32::from_bits)
+    }
+}
+
+fn load_cache_file(paths: &StoragePaths, key: u64) -> Option<HashMap<IVec2, Vec<TreePlacement>>> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = (paths, key);
+        None
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let bytes = paths.load_bytes(CACHE_FILE)?;
+        let chunks = decode_cache(&bytes, key);
+        if chunks.is_none() {
+            info!("Vegetation cache: stale or unreadable, regenerating");
+        }
+        chunks
+    }
+}
+
+fn save_cache_file(paths: &StoragePaths, bytes: &[u8]) {
+    #[cfg(target_arch = "wasm32")]
+    let _ = (paths, bytes);
+    #[cfg(not(target_arch = "wasm32"))]
+    if paths.save_bytes(CACHE_FILE, bytes) {
+        info!("Vegetation cache: saved {} bytes", bytes.len());
+    }
+}
+
+// Recompute the key when anything it covers may have changed.
+pub(crate) fn sync_vegetation_cache(
+    sampler: Res<TerrainSampler>,
+    species: Res<VegetationSpeciesList>,
+    cfg: Res<VegetationConfig>,
+    mut cache: ResMut<VegetationCache>,
+) {
+    if sampler.is_placeholder() {
+        return; // keyed by the real heightmap once it has decoded
+    }
+    let wanted = cache.enabled && cfg.deterministic;
+    if !(sampler.is_changed() || species.is_changed() || cfg.is_changed()) && wanted == cache.key.is_some() {
+        return;
+    }
+    let key = wanted.then(|| vegetation_cache_key(&sampler, &species, &cfg));
+    cache.set_key(key);
+}
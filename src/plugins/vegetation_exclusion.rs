@@ -0,0 +1,179 @@
+// Vegetation exclusion zones around gameplay elements.
+// Trees and props are kept out of circles around the ball spawn and every target, plus any
+// zones the level lists (`vegetation_exclusions`: a single point is a circle, several points a
+// path such as a fairway spline). Zones filter placements at spawn time (after the placement
+// cache, so cached chunks stay valid) and, when a target relocates, vegetation already standing
+// inside its new zone is cleared so the target never hides inside a canopy. A target flying a
+// `TargetMotion` path keeps its whole route clear instead of a circle.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::plugins::level::LevelDef;
+use crate::plugins::target::{Target, TargetMotion};
+use crate::plugins::vegetation::{Tree, VegetationGrid};
+use crate::plugins::vegetation_props::Prop;
+
+#[derive(Resource, Clone)]
+pub struct VegetationExclusionConfig {
+    pub enabled: bool,
+    pub ball_spawn_radius: f32, // m around the level's ball spawn
+    pub target_radius: f32,     // m around each target (covers a large canopy)
+    pub relocate_epsilon: f32,  // target XZ moves below this are not a relocation
+}
+impl Default for VegetationExclusionConfig {
+    fn default() -> Self {
+        Self { enabled: true, ball_spawn_radius: 12.0, target_radius: 14.0, relocate_epsilon: 1.0 }
+    }
+}
+
+/// Level-defined zone (RON): one point = circle, several = path with `radius` half-width.
+#[derive(Debug, Deserialize, Clone)]
+pub struct VegetationExclusionDef {
+    pub points: Vec<(f32, f32)>, // XZ
+    pub radius: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExclusionZone {
+    Circle { center: Vec2, radius: f32 },
+    Path { points: Vec<Vec2>, radius: f32 },
+}
+
+impl ExclusionZone {
+    pub fn from_def(def: &VegetationExclusionDef) -> Option<Self> {
+        let points: Vec<Vec2> = def.points.iter().map(|&(x, z)| Vec2::new(x, z)).collect();
+        match points.len() {
+            0 => None,
+            1 => Some(Self::Circle { center: points[0], radius: def.radius }),
+            _ => Some(Self::Path { points, radius: def.radius }),
+        }
+    }
+
+    pub fn contains(&self, p: Vec2) -> bool {
+        match self {
+            Self::Circle { center, radius } => p.distance_squared(*center) <= radius * radius,
+            Self::Path { points, radius } => points
+                .windows(2)
+                .any(|w| distance_to_segment(p, w[0], w[1]) <= *radius),
+        }
+    }
+
+    /// Bounding circle (center, radius).
+    pub fn bounds(&self) -> (Vec2, f32) {
+        match self {
+            Self::Circle { center, radius } => (*center, *radius),
+            Self::Path { points, radius } => {
+                let (min, max) = points.iter().fold((Vec2::MAX, Vec2::MIN), |(lo, hi), p| (lo.min(*p), hi.max(*p)));
+                let center = (min + max) * 0.5;
+                (center, points.iter().map(|p| p.distance(center)).fold(0.0, f32::max) + radius)
+            }
+        }
+    }
+}
+
+fn distance_to_segment(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let len2 = ab.length_squared();
+    let t = if len2 > 0.0 { ((p - a).dot(ab) / len2).clamp(0.0, 1.0) } else { 0.0 };
+    p.distance(a + ab * t)
+}
+
+/// Active zones: static ones (ball spawn, level) plus one per target around its anchor.
+#[derive(Resource, Default, Clone)]
+pub struct VegetationExclusions {
+    pub zones: Vec<ExclusionZone>,
+    pub targets: Vec<Vec2>, // anchors the target zones were built for (relocation detection)
+    pub target_zones: Vec<ExclusionZone>,
+    pub target_radius: f32,
+}
+
+impl VegetationExclusions {
+    pub fn contains(&self, p: Vec2) -> bool {
+        self.target_zones.iter().chain(&self.zones).any(|z| z.contains(p))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.zones.is_empty() && self.target_zones.is_empty()
+    }
+}
+
+/// Zone kept clear for a target: circle at rest, its route (plus radius) when it flies a path.
+pub fn target_zone(position: Vec2, motion: Option<&TargetMotion>, radius: f32) -> ExclusionZone {
+    match motion {
+        Some(m) => ExclusionZone::Path { points: m.path.outline().into_iter().map(|o| m.anchor + o).collect(), radius },
+        None => ExclusionZone::Circle { center: position, radius },
+    }
+}
+
+pub(crate) fn apply_level_exclusions(
+    level: Res<LevelDef>,
+    cfg: Res<VegetationExclusionConfig>,
+    mut exclusions: ResMut<VegetationExclusions>,
+) {
+    exclusions.zones.clear();
+    if !cfg.enabled {
+        return;
+    }
+    exclusions.zones.push(ExclusionZone::Circle {
+        center: Vec2::new(level.ball.pos.x, level.ball.pos.z),
+        radius: cfg.ball_spawn_radius,
+    });
+    exclusions.zones.extend(level.vegetation_exclusions.iter().filter_map(ExclusionZone::from_def));
+}
+
+// Track target positions; on relocation clear trees / props standing in the new zone.
+pub(crate) fn update_target_exclusions(
+    mut commands: Commands,
+    cfg: Res<VegetationExclusionConfig>,
+    mut exclusions: ResMut<VegetationExclusions>,
+    mut grid: ResMut<VegetationGrid>,
+    q_targets: Query<(&Transform, Option<&TargetMotion>), With<Target>>,
+    q_trees: Query<(), With<Tree>>,
+    q_props: Query<(Entity, &GlobalTransform), With<Prop>>,
+) {
+    if !cfg.enabled {
+        if !exclusions.target_zones.is_empty() {
+            exclusions.targets.clear();
+            exclusions.target_zones.clear();
+        }
+        return;
+    }
+    // Moving targets are tracked by anchor, so patrolling is not a relocation.
+    let anchor = |(t, m): (&Transform, Option<&TargetMotion>)| m.map_or(t.translation.xz(), |m| m.anchor);
+    let current: Vec<Vec2> = q_targets.iter().map(anchor).collect();
+    let eps2 = cfg.relocate_epsilon * cfg.relocate_epsilon;
+    let moved: Vec<ExclusionZone> = q_targets
+        .iter()
+        .filter(|&(t, m)| !exclusions.targets.iter().any(|old| old.distance_squared(anchor((t, m))) <= eps2))
+        .map(|(t, m)| target_zone(t.translation.xz(), m, cfg.target_radius))
+        .collect();
+    if moved.is_empty() && current.len() == exclusions.targets.len() && exclusions.target_radius == cfg.target_radius {
+        return;
+    }
+    exclusions.targets = current;
+    exclusions.target_zones =
+        q_targets.iter().map(|(t, m)| target_zone(t.translation.xz(), m, cfg.target_radius)).collect();
+    exclusions.target_radius = cfg.target_radius;
+
+    let mut cleared = 0;
+    for zone in &moved {
+        let (center, reach) = zone.bounds();
+        for (e, p) in grid.trees_within(center, reach) {
+            if q_trees.contains(e) && zone.contains(p) {
+                grid.remove(e);
+                commands.entity(e).despawn_recursive();
+                cleared += 1;
+            }
+        }
+        for (e, gt) in &q_props {
+            if zone.contains(gt.translation().xz()) {
+                commands.entity(e).despawn_recursive();
+                cleared += 1;
+            }
+        }
+    }
+    if cleared > 0 {
+        debug!("Vegetation exclusion: cleared {cleared} trees/props around relocated target");
+    }
+}
@@ -0,0 +1,78 @@
+// Designer-painted vegetation density mask.
+// A grayscale PNG covering the same centered world square as the heightmap. Luminance scales the
+// vegetation noise density: black clears an area (fairways, greens), mid grey (128) leaves the
+// procedural result untouched, white doubles it (deliberate groves). Outside the image the
+// multiplier is 1. Levels opt in with `vegetation_mask` in their RON file.
+
+use bevy::prelude::*;
+use std::sync::Arc;
+
+/// Multiplier at full white.
+pub const MASK_MAX_MULTIPLIER: f32 = 2.0;
+
+#[derive(Clone, Debug)]
+pub struct VegetationDensityMask {
+    width: u32,
+    height: u32,
+    world_size: f32,
+    data: Arc<Vec<u8>>, // luminance, row-major, row 0 = -Z edge (same as the heightmap)
+}
+
+impl VegetationDensityMask {
+    pub fn from_luma(width: u32, height: u32, data: Vec<u8>, world_size: f32) -> Self {
+        assert_eq!(data.len(), (width * height) as usize, "density mask data size mismatch");
+        assert!(width >= 2 && height >= 2, "density mask must be at least 2x2");
+        Self { width, height, world_size, data: Arc::new(data) }
+    }
+
+    /// Load a mask image (any format the `image` crate decodes here; converted to luminance).
+    pub fn load(path: &str, world_size: f32) -> Option<Self> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            // No filesystem in the browser and level masks are not embedded yet.
+            warn!("Vegetation density mask {path} ignored on wasm");
+            let _ = world_size;
+            None
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let img = match image::open(path) {
+                Ok(img) => img.to_luma8(),
+                Err(e) => {
+                    error!("Failed to open vegetation density mask {path}: {e}");
+                    return None;
+                }
+            };
+            let (w, h) = img.dimensions();
+            if w < 2 || h < 2 {
+                error!("Vegetation density mask {path} is too small ({w} x {h})");
+                return None;
+            }
+            info!("Vegetation density mask loaded: {} ({} x {})", path, w, h);
+            Some(Self::from_luma(w, h, img.into_raw(), world_size))
+        }
+    }
+
+    /// Raw mask (width, height, world size, luminance bytes).
+    pub fn data(&self) -> (u32, u32, f32, &[u8]) {
+        (self.width, self.height, self.world_size, &self.data)
+    }
+
+    /// Density multiplier at world (x, z), bilinearly filtered.
+    pub fn sample(&self, x: f32, z: f32) -> f32 {
+        let nx = x / self.world_size + 0.5;
+        let nz = z / self.world_size + 0.5;
+        if !(0.0..=1.0).contains(&nx) || !(0.0..=1.0).contains(&nz) {
+            return 1.0;
+        }
+        let u = nx * (self.width - 1) as f32;
+        let v = nz * (self.height - 1) as f32;
+        let (x0, z0) = (u.floor() as u32, v.floor() as u32);
+        let (x1, z1) = ((x0 + 1).min(self.width - 1), (z0 + 1).min(self.height - 1));
+        let (tx, tz) = (u - x0 as f32, v - z0 as f32);
+        let at = |x: u32, z: u32| self.data[(z * self.width + x) as usize] as f32;
+        let a = at(x0, z0) + (at(x1, z0) - at(x0, z0)) * tx;
+        let b = at(x0, z1) + (at(x1, z1) - at(x0, z1)) * tx;
+        (a + (b - a) * tz) / 255.0 * MASK_MAX_MULTIPLIER
+    }
+}
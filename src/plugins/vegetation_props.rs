@@ -0,0 +1,272 @@
+// Non-tree props scattered by the vegetation plugin's second pass (boulders, stumps, mushrooms).
+// Placement lives next to the tree pass (`chunk_prop_placements` in vegetation.rs) so both share
+// the chunk seed, masks and terrain sampling; this module holds the prop rules, the procedural
+// meshes (no prop models ship yet) and the optional ball collision.
+//  - Rules use their own slope band: boulders favour steep ground where trees are rejected.
+//  - Each kind has its own spacing class and keeps clear of the chunk's trees.
+//  - Boulders (spheres) and stumps (short cylinders) can bounce the ball; mushrooms never do.
+
+use bevy::prelude::*;
+use bevy::render::mesh::{CylinderAnchor, VertexAttributeValues};
+use noise::{NoiseFn, Perlin};
+
+use crate::plugins::ball::{Ball, BallConditions, BallKinematic};
+use crate::plugins::spatial_grid::SpatialGrid;
+use crate::plugins::tree_impact::trunk_contact;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PropKind {
+    Boulder,
+    Stump,
+    Mushroom,
+}
+
+impl PropKind {
+    pub const ALL: [PropKind; 3] = [PropKind::Boulder, PropKind::Stump, PropKind::Mushroom];
+
+    pub fn index(self) -> usize {
+        match self {
+            PropKind::Boulder => 0,
+            PropKind::Stump => 1,
+            PropKind::Mushroom => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PropRule {
+    pub kind: PropKind,
+    pub weight: f32,
+    pub scale: (f32, f32),
+    pub normal_y: (f32, f32),  // accepted surface normal y band (lower = steeper)
+    pub prefer_steep: bool,    // weight grows with steepness instead of flatness
+    pub altitude: (f32, f32),
+    pub spacing: f32,          // minimum distance to props of the same kind (m)
+    pub collision_radius: f32, // at scale 1 (0 = ball passes through)
+    pub collision_height: f32, // stump cylinder height at scale 1
+}
+
+impl PropRule {
+    /// Relative pick weight for a sample (0 = rejected).
+    pub fn weight_at(&self, h: f32, normal_y: f32) -> f32 {
+        if h < self.altitude.0 || h > self.altitude.1 || normal_y < self.normal_y.0 || normal_y > self.normal_y.1 {
+            return 0.0;
+        }
+        let slope = if self.prefer_steep { (1.0 - normal_y) * 4.0 } else { normal_y };
+        self.weight * slope
+    }
+}
+
+#[derive(Resource, Clone)]
+pub struct PropScatterConfig {
+    pub enabled: bool,
+    pub cell_size: f32,        // scatter grid cell (m)
+    pub density: f32,          // acceptance probability before rule weights
+    pub noise_freq: f64,       // clumping noise
+    pub tree_clearance: f32,   // props keep this far from the chunk's trees
+    pub max_per_chunk: usize,
+    pub ball_collision: bool,
+    pub restitution: f32,
+    pub rules: Vec<PropRule>,
+}
+impl Default for PropScatterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            cell_size: 9.0,
+            density: 0.22,
+            noise_freq: 0.02,
+            tree_clearance: 2.5,
+            max_per_chunk: 24,
+            ball_collision: true,
+            restitution: 0.45,
+            rules: vec![
+                PropRule {
+                    kind: PropKind::Boulder,
+                    weight: 1.0,
+                    scale: (0.6, 2.2),
+                    normal_y: (0.40, 0.90),
+                    prefer_steep: true,
+                    altitude: (27.0, 400.0),
+                    spacing: 10.0,
+                    collision_radius: 0.85,
+                    collision_height: 0.0,
+                },
+                PropRule {
+                    kind: PropKind::Stump,
+                    weight: 0.35,
+                    scale: (0.7, 1.3),
+                    normal_y: (0.80, 1.0),
+                    prefer_steep: false,
+                    altitude: (50.0, 300.0),
+                    spacing: 14.0,
+                    collision_radius: 0.38,
+                    collision_height: 0.55,
+                },
+                PropRule {
+                    kind: PropKind::Mushroom,
+                    weight: 0.5,
+                    scale: (0.6, 1.2),
+                    normal_y: (0.85, 1.0),
+                    prefer_steep: false,
+                    altitude: (30.0, 220.0),
+                    spacing: 4.0,
+                    collision_radius: 0.0,
+                    collision_height: 0.0,
+                },
+            ],
+        }
+    }
+}
+
+/// World-space prop placement produced by the scatter pass.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropPlacement {
+    pub kind: PropKind,
+    pub rule: usize, // index into PropScatterConfig::rules
+    pub transform: Transform,
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Prop {
+    pub kind: PropKind,
+    pub collision_radius: f32, // world units (0 = no collision)
+    pub collision_height: f32,
+}
+
+// Shared meshes / materials, indexed by PropKind::index.
+#[derive(Resource)]
+pub(crate) struct PropAssets {
+    pub(crate) meshes: [Handle<Mesh>; 3],
+    pub(crate) materials: [Handle<StandardMaterial>; 3],
+}
+
+impl FromWorld for PropAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mut meshes = world.resource_mut::<Assets<Mesh>>();
+        let meshes = PropKind::ALL.map(|k| meshes.add(build_prop_mesh(k)));
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let mut mat = |c: Color, roughness: f32| {
+            materials.add(StandardMaterial { base_color: c, perceptual_roughness: roughness, ..default() })
+        };
+        let materials = [
+            mat(Color::srgb(0.46, 0.45, 0.42), 0.95),
+            mat(Color::srgb(0.42, 0.30, 0.19), 1.0),
+            mat(Color::WHITE, 0.7), // mushroom colours come from vertex colours
+        ];
+        Self { meshes, materials }
+    }
+}
+
+/// Procedural prop mesh, origin at the ground contact point.
+pub fn build_prop_mesh(kind: PropKind) -> Mesh {
+    match kind {
+        PropKind::Boulder => {
+            // Lumpy, flattened icosphere sunk slightly into the ground.
+            let mut mesh = Sphere::new(1.0).mesh().ico(2).expect("icosphere subdivisions");
+            let noise = Perlin::new(4_711);
+            if let Some(VertexAttributeValues::Float32x3(positions)) = mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION) {
+                for p in positions.iter_mut() {
+                    let v = Vec3::from(*p);
+                    let n = noise.get([v.x as f64 * 1.7, v.y as f64 * 1.7, v.z as f64 * 1.7]) as f32;
+                    let r = 1.0 + n * 0.28;
+                    *p = [v.x * r, v.y * r * 0.68 + 0.45, v.z * r];
+                }
+            }
+            mesh.remove_attribute(Mesh::ATTRIBUTE_NORMAL);
+            mesh.with_computed_smooth_normals()
+        }
+        PropKind::Stump => Cylinder::new(0.38, 0.55)
+            .mesh()
+            .resolution(10)
+            .anchor(CylinderAnchor::Bottom)
+            .build(),
+        PropKind::Mushroom => {
+            let with_color = |mut m: Mesh, c: [f32; 4]| {
+                let n = m.count_vertices();
+                m.insert_attribute(Mesh::ATTRIBUTE_COLOR, vec![c; n]);
+                m
+            };
+            let mut stem = with_color(
+                Cylinder::new(0.05, 0.22).mesh().resolution(8).anchor(CylinderAnchor::Bottom).build(),
+                [0.92, 0.90, 0.82, 1.0],
+            );
+            let cap = with_color(
+                Sphere::new(0.16)
+                    .mesh()
+                    .uv(10, 6)
+                    .transformed_by(Transform::from_xyz(0.0, 0.22, 0.0).with_scale(Vec3::new(1.0, 0.55, 1.0))),
+                [0.75, 0.12, 0.08, 1.0],
+            );
+            stem.merge(&cap);
+            stem
+        }
+    }
+}
+
+// Index of colliding props (separate from VegetationGrid so tree queries stay tree-only).
+#[derive(Resource)]
+pub(crate) struct PropGrid(SpatialGrid<Entity>);
+
+impl Default for PropGrid {
+    fn default() -> Self {
+        Self(SpatialGrid::new(16.0))
+    }
+}
+
+pub(crate) fn index_props(
+    mut grid: ResMut<PropGrid>,
+    q_added: Query<(Entity, &GlobalTransform, &Prop), Added<Prop>>,
+    mut removed: RemovedComponents<Prop>,
+    mut positions: Local<std::collections::HashMap<Entity, Vec2>>,
+) {
+    for e in removed.read() {
+        if let Some(p) = positions.remove(&e) {
+            grid.0.remove_where(p, |v| *v == e);
+        }
+    }
+    for (e, gt, prop) in &q_added {
+        if prop.collision_radius <= 0.0 {
+            continue;
+        }
+        let p = gt.translation().xz();
+        grid.0.insert(p, e);
+        positions.insert(e, p);
+    }
+}
+
+pub(crate) fn ball_prop_collision(
+    cfg: Res<PropScatterConfig>,
+    conditions: Res<BallConditions>,
+    grid: Res<PropGrid>,
+    mut q_ball: Query<(&mut Transform, &mut BallKinematic), With<Ball>>,
+    q_props: Query<(&GlobalTransform, &Prop), Without<Ball>>,
+) {
+    if !cfg.ball_collision || grid.0.is_empty() {
+        return;
+    }
+    let Ok((mut ball_t, mut kin)) = q_ball.get_single_mut() else { return; };
+    let mut near = Vec::new();
+    grid.0.for_each_within(ball_t.translation.xz(), 3.0 + kin.collider_radius, |_, e| near.push(*e));
+    for e in near {
+        let Ok((gt, prop)) = q_props.get(e) else { continue; };
+        let base = gt.translation();
+        let contact = match prop.kind {
+            PropKind::Boulder => {
+                // Sphere around roughly the middle of the (flattened, lifted) boulder mesh.
+                let c = base + Vec3::Y * prop.collision_radius * 0.5;
+                let d = ball_t.translation - c;
+                let reach = prop.collision_radius + kin.collider_radius;
+                let dist = d.length();
+                (dist < reach).then(|| (if dist > 1e-5 { d / dist } else { Vec3::Y }, reach - dist))
+            }
+            _ => trunk_contact(ball_t.translation, kin.collider_radius, base, prop.collision_radius, prop.collision_height),
+        };
+        let Some((n, depth)) = contact else { continue; };
+        ball_t.translation += n * depth;
+        let vn = kin.vel.dot(n);
+        if vn < 0.0 {
+            kin.vel -= (1.0 + cfg.restitution * conditions.bounce) * vn * n;
+        }
+    }
+}
@@ -0,0 +1,147 @@
+// Data-driven vegetation species registry.
+// Each species names a glTF scene plus its placement rules (scale range, slope tolerance,
+// altitude band, spacing, biome affinity). The list is read from assets/vegetation/species.ron
+// (embedded at compile time on wasm), so new bushes / rocks / flowers need no code edits.
+// Falls back to the two built-in trees when the file is missing or malformed.
+
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+
+use crate::plugins::biome::Biome;
+
+pub const SPECIES_FILE: &str = "assets/vegetation/species.ron";
+
+/// Relative spawn weight per biome (missing entries default to 1.0).
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct BiomeAffinity {
+    pub meadow: f32,
+    pub forest: f32,
+    pub highland: f32,
+    pub wetland: f32,
+}
+impl Default for BiomeAffinity {
+    fn default() -> Self {
+        Self { meadow: 1.0, forest: 1.0, highland: 1.0, wetland: 1.0 }
+    }
+}
+impl BiomeAffinity {
+    pub fn get(&self, biome: Biome) -> f32 {
+        match biome {
+            Biome::Meadow => self.meadow,
+            Biome::Forest => self.forest,
+            Biome::Highland => self.highland,
+            Biome::Wetland => self.wetland,
+        }
+    }
+
+    /// Affinity blended by biome weights (indexed by `Biome::index`).
+    pub fn blend(&self, weights: &[f32; 4]) -> f32 {
+        Biome::ALL.iter().map(|b| self.get(*b) * weights[b.index()]).sum()
+    }
+}
+
+fn one() -> f32 { 1.0 }
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct VegetationSpecies {
+    pub name: String,
+    pub model: String,            // scene path, e.g. "models/tree_1.glb#Scene0"
+    #[serde(default = "one")]
+    pub weight: f32,              // relative pick weight among accepted species
+    pub scale: (f32, f32),        // uniform scale range
+    pub min_normal_y: f32,        // slope tolerance (steeper ground rejects this species)
+    pub altitude: (f32, f32),     // terrain height band (m)
+    #[serde(default = "one")]
+    pub spacing: f32,             // multiplier on the region minimum spacing
+    #[serde(default)]
+    pub biomes: BiomeAffinity,
+}
+
+impl VegetationSpecies {
+    /// Placement rules for a sample at height `h` with surface normal y `normal_y`.
+    pub fn accepts(&self, h: f32, normal_y: f32) -> bool {
+        h >= self.altitude.0 && h <= self.altitude.1 && normal_y >= self.min_normal_y
+    }
+}
+
+/// Loaded species list (order = species index used by spawned instances).
+#[derive(Resource, Debug, Deserialize, Clone)]
+pub struct VegetationSpeciesList {
+    pub species: Vec<VegetationSpecies>,
+}
+
+impl Default for VegetationSpeciesList {
+    /// The two original trees; tree_2 dominates forests, tree_1 highlands and wetlands.
+    fn default() -> Self {
+        let tree = |name: &str, model: &str, biomes: BiomeAffinity| VegetationSpecies {
+            name: name.into(),
+            model: model.into(),
+            weight: 1.0,
+            scale: (0.5, 2.0),
+            min_normal_y: 0.70,
+            altitude: (50.0, f32::MAX),
+            spacing: 1.0,
+            biomes,
+        };
+        Self {
+            species: vec![
+                tree("broadleaf", "models/tree_1.glb#Scene0", BiomeAffinity { meadow: 0.5, forest: 0.2, highland: 0.8, wetland: 0.7 }),
+                tree("conifer", "models/tree_2.glb#Scene0", BiomeAffinity { meadow: 0.5, forest: 0.8, highland: 0.2, wetland: 0.3 }),
+            ],
+        }
+    }
+}
+
+impl VegetationSpeciesList {
+    pub fn from_ron(data: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str::<Self>(data)
+    }
+
+    /// Read the species file (embedded on wasm); built-in defaults on any error.
+    pub fn load() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        let data: Option<String> = Some(include_str!("../../assets/vegetation/species.ron").to_string());
+        #[cfg(not(target_arch = "wasm32"))]
+        let data = fs::read_to_string(SPECIES_FILE).ok();
+
+        let Some(data) = data else {
+            warn!("Failed to read {SPECIES_FILE}; using built-in vegetation species");
+            return Self::default();
+        };
+        match Self::from_ron(&data) {
+            Ok(list) if !list.species.is_empty() => list,
+            Ok(_) => {
+                warn!("{SPECIES_FILE} lists no species; using built-in vegetation species");
+                Self::default()
+            }
+            Err(e) => {
+                error!("Failed to parse {SPECIES_FILE}: {e}");
+                Self::default()
+            }
+        }
+    }
+
+    /// Weighted random pick among species whose rules accept the sample.
+    pub fn pick(&self, rng: &mut impl Rng, h: f32, normal_y: f32, biome_weights: &[f32; 4]) -> Option<usize> {
+        let weight = |s: &VegetationSpecies| {
+            if s.accepts(h, normal_y) { (s.weight * s.biomes.blend(biome_weights)).max(0.0) } else { 0.0 }
+        };
+        let total: f32 = self.species.iter().map(weight).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut r = rng.gen::<f32>() * total;
+        for (i, s) in self.species.iter().enumerate() {
+            let w = weight(s);
+            if w > 0.0 && r < w {
+                return Some(i);
+            }
+            r -= w;
+        }
+        self.species.iter().rposition(|s| weight(s) > 0.0)
+    }
+}
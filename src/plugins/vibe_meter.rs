@@ -0,0 +1,387 @@
+// Vibe meter: momentum that builds with good play.
+// Every shot is judged once it is over: one that scores fills the meter most, one that closes a
+// good share of the way to the focused target (TargetFocus) fills it by how much it closed, and a
+// dud (barely closer, or further off, e.g. back on the tee after water) drains it. A full meter
+// lets Z arm a one-shot vibe stroke (press again for the other one, then none):
+//   Perfect aim  - the shot heads straight at the target with the charge the autoplay bot would
+//                  pick (`AimPoint` + `ShotConfig::assist_power`), whenever the button is let go.
+//   Double power - the shot launches twice as hard (`ShotConfig::vibe_boost`, the preview follows).
+// Firing the stroke empties the meter. The meter is drawn as a ring around the power bar that
+// fills clockwise from its top-left corner, glows when full and takes the colour of an armed
+// stroke. A restarted round or a new level starts it empty.
+
+use bevy::prelude::*;
+
+use crate::plugins::app_state::{gameplay_running, AppState};
+use crate::plugins::autoplay::{plan_shot, SETTLED_SPEED};
+use crate::plugins::ball::{Ball, BallKinematic};
+use crate::plugins::camera::CameraMode;
+use crate::plugins::core_sim::GameSet;
+use crate::plugins::event_log::{EventLog, LogKind};
+use crate::plugins::game_state::{RestartGameEvent, Score, ShotConfig, ShotMode, ShotState};
+use crate::plugins::hud_layout::HudAnchor;
+use crate::plugins::level::LevelDef;
+use crate::plugins::localization::Localization;
+use crate::plugins::particles::{ShotFiredEvent, TargetHitEvent};
+use crate::plugins::shooting::{AimPoint, POWER_BAR_RIGHT, POWER_BAR_SIZE, POWER_BAR_TOP};
+use crate::plugins::spectate::InteractiveUi;
+use crate::plugins::target::{TargetFocus, TargetKind};
+use crate::plugins::terrain::TerrainSampler;
+use crate::plugins::ui_toast::{ToastKind, ToastQueue};
+
+pub const VIBE_KEY: KeyCode = KeyCode::KeyZ;
+
+const RING_THICKNESS: f32 = 3.0;
+const RING_GAP: f32 = 1.0; // px between the ring and the power bar
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VibeStroke {
+    PerfectAim,
+    DoublePower,
+}
+
+impl VibeStroke {
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::PerfectAim => "vibe.perfect_aim",
+            Self::DoublePower => "vibe.double_power",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Self::PerfectAim => Color::srgb(0.3, 0.9, 1.0),
+            Self::DoublePower => Color::srgb(1.0, 0.45, 0.15),
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct VibeConfig {
+    pub hit_gain: f32,      // meter share filled by a shot that scores
+    pub good_gain: f32,     // filled by an accurate shot, times the share of the way it closed
+    pub good_progress: f32, // share of the remaining distance an accurate shot closes
+    pub dud_progress: f32,  // a shot closing less than this is a dud
+    pub dud_loss: f32,      // meter share a dud drains
+    pub double_power: f32,  // launch speed multiplier of a double-power stroke
+}
+impl Default for VibeConfig {
+    fn default() -> Self {
+        Self { hit_gain: 0.4, good_gain: 0.3, good_progress: 0.5, dud_progress: 0.15, dud_loss: 0.25, double_power: 2.0 }
+    }
+}
+
+/// Change of the meter for a shot that started `before` m from the target and ended `after` m
+/// from it (`scored`: it hit the target).
+pub fn shot_vibe(before: f32, after: f32, scored: bool, cfg: &VibeConfig) -> f32 {
+    if scored {
+        return cfg.hit_gain;
+    }
+    let progress = if before > 1e-3 { (before - after) / before } else { 0.0 };
+    if progress >= cfg.good_progress {
+        cfg.good_gain * progress.min(1.0)
+    } else if progress < cfg.dud_progress {
+        -cfg.dud_loss
+    } else {
+        0.0
+    }
+}
+
+/// Fill (0..1) of the ring's four sides, clockwise from the top, for a meter at `level`.
+pub fn ring_segments(level: f32) -> [f32; 4] {
+    let sides = level.clamp(0.0, 1.0) * 4.0;
+    [0, 1, 2, 3].map(|i| (sides - i as f32).clamp(0.0, 1.0))
+}
+
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct VibeMeter {
+    pub level: f32, // 0..1
+    pub armed: Option<VibeStroke>,
+    pub pending: Option<f32>, // m to the target when the shot being judged was fired
+    pub scored: bool,         // that shot hit the target
+}
+
+impl VibeMeter {
+    pub fn is_full(&self) -> bool {
+        self.level >= 1.0
+    }
+
+    /// Add `delta` (clamped to 0..1); true when this fills the meter.
+    pub fn add(&mut self, delta: f32) -> bool {
+        let was_full = self.is_full();
+        self.level = (self.level + delta).clamp(0.0, 1.0);
+        !was_full && self.is_full()
+    }
+
+    /// Arm the next stroke (none -> perfect aim -> double power -> none); only with a full meter.
+    pub fn cycle_armed(&mut self) {
+        self.armed = match self.armed {
+            _ if !self.is_full() => None,
+            None => Some(VibeStroke::PerfectAim),
+            Some(VibeStroke::PerfectAim) => Some(VibeStroke::DoublePower),
+            Some(VibeStroke::DoublePower) => None,
+        };
+    }
+
+    /// A shot was fired: the armed stroke goes with it and empties the meter.
+    pub fn spend_armed(&mut self) -> Option<VibeStroke> {
+        let stroke = self.armed.take();
+        if stroke.is_some() {
+            self.level = 0.0;
+        }
+        stroke
+    }
+}
+
+#[derive(Component)]
+struct VibeRing;
+
+#[derive(Component)]
+struct VibeRingFill(usize); // side, clockwise from the top
+
+pub struct VibeMeterPlugin;
+impl Plugin for VibeMeterPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<VibeConfig>()
+            .init_resource::<VibeMeter>()
+            .add_systems(Startup, spawn_vibe_ring)
+            .add_systems(Update, (arm_vibe_stroke, steer_vibe_stroke).chain().in_set(GameSet::Input).run_if(gameplay_running))
+            .add_systems(Update, (reset_vibe, judge_shots.run_if(gameplay_running)).chain().in_set(GameSet::React))
+            .add_systems(Update, update_vibe_ring.in_set(GameSet::Present));
+    }
+}
+
+fn spawn_vibe_ring(mut commands: Commands) {
+    // Around the power bar, `RING_GAP` clear of it.
+    let margin = RING_THICKNESS + RING_GAP;
+    let (right, top) = (POWER_BAR_RIGHT - margin, POWER_BAR_TOP - margin);
+    let size = POWER_BAR_SIZE + Vec2::splat(2.0 * margin);
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(right),
+                    top: Val::Px(top),
+                    width: Val::Px(size.x),
+                    height: Val::Px(size.y),
+                    ..default()
+                },
+                ..default()
+            },
+            HudAnchor::top_right(right, top),
+            VibeRing,
+            InteractiveUi,
+        ))
+        .with_children(|ring| {
+            let thick = Val::Px(RING_THICKNESS);
+            let full = Val::Percent(100.0);
+            let (edge, auto) = (Val::Px(0.0), Val::Auto);
+            // (left, right, top, bottom; runs along the width; fills from the far end)
+            let sides = [
+                ((edge, auto, edge, auto), true, false),
+                ((auto, edge, edge, auto), false, false),
+                ((auto, edge, auto, edge), true, true),
+                ((edge, auto, auto, edge), false, true),
+            ];
+            for (i, ((left, right, top, bottom), across, reversed)) in sides.into_iter().enumerate() {
+                ring.spawn(NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left,
+                        right,
+                        top,
+                        bottom,
+                        width: if across { full } else { thick },
+                        height: if across { thick } else { full },
+                        flex_direction: if across { FlexDirection::Row } else { FlexDirection::Column },
+                        justify_content: if reversed { JustifyContent::FlexEnd } else { JustifyContent::FlexStart },
+                        ..default()
+                    },
+                    background_color: Color::srgba(0.08, 0.08, 0.10, 0.6).into(),
+                    ..default()
+                })
+                .with_children(|side| {
+                    side.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: if across { Val::Percent(0.0) } else { full },
+                                height: if across { full } else { Val::Percent(0.0) },
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        VibeRingFill(i),
+                    ));
+                });
+            }
+        });
+}
+
+fn arm_vibe_stroke(
+    keys: Res<ButtonInput<KeyCode>>,
+    (cfg, loc, score): (Res<VibeConfig>, Res<Localization>, Res<Score>),
+    mode: Res<CameraMode>,
+    mut meter: ResMut<VibeMeter>,
+    mut shot_cfg: ResMut<ShotConfig>,
+    mut aim: ResMut<AimPoint>,
+    mut toasts: ResMut<ToastQueue>,
+) {
+    if keys.just_pressed(VIBE_KEY) && *mode == CameraMode::Orbit && !score.game_over {
+        let was_aiming = meter.armed == Some(VibeStroke::PerfectAim);
+        meter.cycle_armed();
+        if was_aiming {
+            aim.pos = None;
+        }
+        let text = match meter.armed {
+            Some(stroke) => loc.tf("vibe.armed", &[("name", loc.t(stroke.key()).to_string())]),
+            None if !meter.is_full() => loc.t("vibe.not_full").to_string(),
+            None => loc.t("vibe.disarmed").to_string(),
+        };
+        toasts.push_for(text, ToastKind::Info, 2.0);
+    }
+    // Double power goes into the launch (and its preview) while armed.
+    let boost = if meter.armed == Some(VibeStroke::DoublePower) { cfg.double_power } else { 1.0 };
+    if shot_cfg.vibe_boost != boost {
+        shot_cfg.vibe_boost = boost;
+    }
+}
+
+// Perfect aim: point the shot at the focused target and pick its charge, like the autoplay bot.
+fn steer_vibe_stroke(
+    meter: Res<VibeMeter>,
+    (focus, sampler): (Res<TargetFocus>, Res<TerrainSampler>),
+    mut shot_cfg: ResMut<ShotConfig>,
+    mut aim: ResMut<AimPoint>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+    q_targets: Query<&Transform, Without<Ball>>,
+    mut planned: Local<Option<(Vec3, Vec3)>>, // ball / target the current plan is for
+) {
+    let target = focus.entity.and_then(|e| q_targets.get(e).ok()).map(|t| t.translation);
+    let (Some(VibeStroke::PerfectAim), Ok((ball_t, kin)), Some(target)) = (meter.armed, q_ball.get_single(), target) else {
+        *planned = None;
+        if shot_cfg.assist_power.is_some() {
+            shot_cfg.assist_power = None;
+        }
+        return;
+    };
+    let ball = ball_t.translation;
+    aim.pos = Some(target);
+    if planned.is_some_and(|(b, t)| b.distance_squared(ball) < 0.01 && t.distance_squared(target) < 0.01) {
+        return;
+    }
+    *planned = Some((ball, target));
+    // The plan assumes a plain launch; any other boost in play goes into the impulse.
+    let plain = ShotConfig { base_impulse: shot_cfg.base_impulse * shot_cfg.launch_boost(), ..*shot_cfg };
+    shot_cfg.assist_power = plan_shot(ball, target, kin.collider_radius, &plain, &sampler).map(|plan| plan.power);
+}
+
+fn reset_vibe(
+    level: Option<Res<LevelDef>>,
+    mut ev_restart: EventReader<RestartGameEvent>,
+    mut meter: ResMut<VibeMeter>,
+    mut shot_cfg: ResMut<ShotConfig>,
+) {
+    if ev_restart.read().count() > 0 || level.is_some_and(|l| l.is_changed()) {
+        *meter = VibeMeter::default();
+        shot_cfg.vibe_boost = 1.0;
+        shot_cfg.assist_power = None;
+    }
+}
+
+// Judge each shot once it is over (scored, the ball at rest, or the next shot played from where
+// it crept to) and spend an armed stroke.
+fn judge_shots(
+    (cfg, loc): (Res<VibeConfig>, Res<Localization>),
+    (mut meter, mut shot_cfg): (ResMut<VibeMeter>, ResMut<ShotConfig>),
+    (focus, state): (Res<TargetFocus>, Res<ShotState>),
+    mut ev_shot: EventReader<ShotFiredEvent>,
+    mut ev_hit: EventReader<TargetHitEvent>,
+    q_ball: Query<(&Transform, &BallKinematic), With<Ball>>,
+    q_targets: Query<&Transform, Without<Ball>>,
+    mut toasts: ResMut<ToastQueue>,
+    mut log: Option<ResMut<EventLog>>,
+) {
+    let target = focus.entity.and_then(|e| q_targets.get(e).ok()).map(|t| t.translation.xz());
+    if let Some(shot) = ev_shot.read().last() {
+        if let Some(before) = meter.pending {
+            let after = target.map_or(before, |t| t.distance(shot.pos.xz()));
+            settle_shot(&mut meter, shot_vibe(before, after, false, &cfg), &loc, &mut toasts);
+        }
+        if let Some(stroke) = meter.spend_armed() {
+            info!("VIBE stroke={stroke:?}");
+            shot_cfg.vibe_boost = 1.0;
+            shot_cfg.assist_power = None;
+            if let Some(log) = log.as_mut() {
+                log.push(LogKind::Info, loc.tf("log.vibe", &[("name", loc.t(stroke.key()).to_string())]));
+            }
+        }
+        meter.pending = target.map(|t| t.distance(shot.pos.xz()));
+        meter.scored = false;
+        return;
+    }
+    if ev_hit.read().any(|e| e.kind == TargetKind::Standard) && meter.pending.is_some() {
+        meter.scored = true;
+    }
+    let Some(before) = meter.pending else { return; };
+    let Ok((ball_t, kin)) = q_ball.get_single() else { return; };
+    let at_rest = kin.vel.length() < SETTLED_SPEED && state.mode == ShotMode::Idle;
+    if !meter.scored && !at_rest {
+        return;
+    }
+    let after = target.map_or(before, |t| t.distance(ball_t.translation.xz()));
+    let delta = shot_vibe(before, after, meter.scored, &cfg);
+    settle_shot(&mut meter, delta, &loc, &mut toasts);
+}
+
+fn settle_shot(meter: &mut VibeMeter, delta: f32, loc: &Localization, toasts: &mut ToastQueue) {
+    meter.pending = None;
+    let filled = meter.add(delta);
+    info!("VIBE shot delta={delta:+.2} level={:.2}", meter.level);
+    if filled {
+        toasts.push_for(loc.t("vibe.full").to_string(), ToastKind::Success, 3.0);
+    }
+}
+
+fn update_vibe_ring(
+    time: Res<Time>,
+    meter: Res<VibeMeter>,
+    phase: Option<Res<State<AppState>>>,
+    mut q_ring: Query<&mut Visibility, With<VibeRing>>,
+    mut q_fill: Query<(&mut Style, &mut BackgroundColor, &VibeRingFill)>,
+) {
+    let shown = phase.is_some_and(|p| p.get().round_in_view());
+    if let Ok(mut vis) = q_ring.get_single_mut() {
+        let want = if shown { Visibility::Inherited } else { Visibility::Hidden };
+        if *vis != want {
+            *vis = want;
+        }
+    }
+    if !shown {
+        return;
+    }
+    let fills = ring_segments(meter.level);
+    let color = match meter.armed {
+        Some(stroke) => stroke.color(),
+        // A full meter glows gold; filling up it shifts from violet to magenta.
+        None if meter.is_full() => {
+            let glow = 0.75 + 0.25 * (time.elapsed_seconds() * 5.0).sin();
+            Color::srgb(glow, 0.82 * glow, 0.3 * glow)
+        }
+        None => Color::srgb(0.55 + 0.4 * meter.level, 0.3, 0.95 - 0.25 * meter.level),
+    };
+    for (mut style, mut bg, fill) in &mut q_fill {
+        let pct = Val::Percent(fills[fill.0] * 100.0);
+        // Top and bottom grow along the width, the sides along the height.
+        if fill.0 % 2 == 0 {
+            if style.width != pct {
+                style.width = pct;
+            }
+        } else if style.height != pct {
+            style.height = pct;
+        }
+        if bg.0 != color {
+            bg.0 = color;
+        }
+    }
+}
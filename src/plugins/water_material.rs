@@ -0,0 +1,69 @@
+// Animated water surface: StandardMaterial lighting with the base color replaced by wave-tinted
+// deep / shallow colors and a fresnel rim (shaders/water.wgsl). Used by the rivers (rivers.rs).
+
+use bevy::pbr::{ExtendedMaterial, MaterialExtension, StandardMaterial};
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef, ShaderType};
+
+pub type WaterMaterial = ExtendedMaterial<StandardMaterial, WaterExtension>;
+
+/// Matches WGSL struct WaterMaterial in shaders/water.wgsl.
+#[derive(Clone, Copy, Debug, ShaderType)]
+pub struct WaterUniform {
+    pub time: f32,
+    pub wave_amp: f32,
+    pub wave_len: f32,   // m
+    pub wave_speed: f32,
+    pub fresnel_power: f32,
+    pub color_deep: Vec4,
+    pub color_shallow: Vec4,
+}
+
+impl Default for WaterUniform {
+    fn default() -> Self {
+        Self {
+            time: 0.0,
+            wave_amp: 0.25,
+            wave_len: 6.0,
+            wave_speed: 2.2,
+            fresnel_power: 4.0,
+            color_deep: Vec4::new(0.03, 0.16, 0.30, 1.0),
+            color_shallow: Vec4::new(0.12, 0.42, 0.52, 1.0),
+        }
+    }
+}
+
+#[derive(Asset, AsBindGroup, TypePath, Debug, Clone, Default)]
+pub struct WaterExtension {
+    #[uniform(100)]
+    pub params: WaterUniform,
+}
+
+impl MaterialExtension for WaterExtension {
+    fn fragment_shader() -> ShaderRef {
+        ShaderRef::Path("shaders/water.wgsl".into())
+    }
+}
+
+/// A glossy water material with the default wave parameters.
+pub fn water_material() -> WaterMaterial {
+    ExtendedMaterial {
+        base: StandardMaterial { perceptual_roughness: 0.08, reflectance: 0.6, ..default() },
+        extension: WaterExtension::default(),
+    }
+}
+
+pub struct WaterMaterialPlugin;
+impl Plugin for WaterMaterialPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(MaterialPlugin::<WaterMaterial>::default())
+            .add_systems(Update, animate_water);
+    }
+}
+
+fn animate_water(time: Res<Time>, mut materials: ResMut<Assets<WaterMaterial>>) {
+    let t = time.elapsed_seconds();
+    for (_, mat) in materials.iter_mut() {
+        mat.extension.params.time = t;
+    }
+}
@@ -0,0 +1,346 @@
+// Weather.
+// Levels opt in with `weather` in their RON file: the weather a round starts with and changes
+// on the run clock (`changes: [(at: 60.0, to: Rain)]`). Clear weather leaves everything as it
+// is; Wind raises the wind (wind.rs) and lets it push the ball in flight; Rain makes the ground
+// slower (more rolling friction), softens bounces off props and trees and dims the light a
+// little; a Storm does all of that harder, darkens the sky (day_night.rs) and throws lightning
+// with thunder. Changes blend in over a few seconds and are announced with a toast and an event
+// log line. Rain is drawn as streaks falling around the camera.
+
+use bevy::prelude::*;
+use rand::{thread_rng, Rng};
+use serde::Deserialize;
+
+use crate::plugins::app_state::AppState;
+use crate::plugins::ball::BallConditions;
+use crate::plugins::camera::OrbitCamera;
+use crate::plugins::core_sim::SimState;
+use crate::plugins::day_night::DayNightState;
+use crate::plugins::event_log::{EventLog, LogKind};
+use crate::plugins::game_state::{RestartGameEvent, Score};
+use crate::plugins::level::LevelDef;
+use crate::plugins::localization::Localization;
+use crate::plugins::particles::{ParticleQuality, ThunderEvent};
+use crate::plugins::ui_toast::{ToastKind, ToastQueue};
+use crate::plugins::wind::WindState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum WeatherKind {
+    #[default]
+    Clear,
+    Wind,
+    Rain,
+    Storm,
+}
+
+/// What the weather does to play and to the scene; blended during changes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeatherEffects {
+    pub friction: f32, // rolling friction multiplier
+    pub bounce: f32,   // bounce (restitution) multiplier
+    pub drift: f32,    // share of the wind that pushes the ball in flight
+    pub light: f32,    // lighting multiplier
+    pub rain: f32,     // rain density 0..1
+    pub thunder: f32,  // lightning likelihood 0..1
+}
+
+impl WeatherEffects {
+    pub fn lerp(self, to: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        Self {
+            friction: mix(self.friction, to.friction),
+            bounce: mix(self.bounce, to.bounce),
+            drift: mix(self.drift, to.drift),
+            light: mix(self.light, to.light),
+            rain: mix(self.rain, to.rain),
+            thunder: mix(self.thunder, to.thunder),
+        }
+    }
+}
+
+impl WeatherKind {
+    pub fn effects(self) -> WeatherEffects {
+        let (friction, bounce, drift, light, rain, thunder) = match self {
+            Self::Clear => (1.0, 1.0, 0.0, 1.0, 0.0, 0.0),
+            Self::Wind => (1.0, 1.0, 1.0, 0.95, 0.0, 0.0),
+            Self::Rain => (1.6, 0.5, 0.3, 0.75, 0.6, 0.0),
+            Self::Storm => (1.8, 0.4, 1.0, 0.45, 1.0, 1.0),
+        };
+        WeatherEffects { friction, bounce, drift, light, rain, thunder }
+    }
+
+    /// `WindState` base strength and gustiness for this weather.
+    pub fn wind(self) -> (f32, f32) {
+        match self {
+            Self::Clear => (0.6, 0.5),
+            Self::Wind => (1.6, 0.8),
+            Self::Rain => (0.9, 0.5),
+            Self::Storm => (2.4, 1.0),
+        }
+    }
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Clear => "weather.clear",
+            Self::Wind => "weather.wind",
+            Self::Rain => "weather.rain",
+            Self::Storm => "weather.storm",
+        }
+    }
+}
+
+/// Level-defined weather (RON).
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+pub struct WeatherDef {
+    pub start: WeatherKind,
+    pub changes: Vec<WeatherChange>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct WeatherChange {
+    pub at: f32, // run clock (s)
+    pub to: WeatherKind,
+}
+
+impl WeatherDef {
+    /// Weather at run time `time`: the latest change due by then, else the start weather.
+    pub fn kind_at(&self, time: f32) -> WeatherKind {
+        self.changes
+            .iter()
+            .filter(|c| c.at <= time)
+            .max_by(|a, b| a.at.total_cmp(&b.at))
+            .map_or(self.start, |c| c.to)
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct WeatherConfig {
+    pub transition: f32,             // s a change takes to blend in
+    pub drift_accel: f32,            // m/s² on the ball per unit of wind strength (at full drift)
+    pub thunder_interval: (f32, f32), // s between lightning strikes in a full storm
+    pub flash_boost: f32,            // extra light at the peak of a flash
+    pub flash_decay: f32,            // 1/s
+    pub rain_drops: usize,           // streaks at full rain (scaled by particle quality)
+    pub rain_box: Vec3,              // half extents of the box of rain around the camera
+    pub rain_speed: f32,             // m/s
+}
+impl Default for WeatherConfig {
+    fn default() -> Self {
+        Self {
+            transition: 8.0,
+            drift_accel: 0.9,
+            thunder_interval: (5.0, 14.0),
+            flash_boost: 1.6,
+            flash_decay: 4.0,
+            rain_drops: 700,
+            rain_box: Vec3::new(30.0, 18.0, 30.0),
+            rain_speed: 26.0,
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct WeatherState {
+    pub active: bool, // the current level has weather
+    pub kind: WeatherKind,
+    pub previous: WeatherKind,
+    pub blend: f32, // 0 = previous .. 1 = kind
+    pub effects: WeatherEffects,
+    pub flash: f32, // lightning flash 1 .. 0
+    thunder_in: f32,
+}
+impl Default for WeatherState {
+    fn default() -> Self {
+        Self {
+            active: false,
+            kind: WeatherKind::Clear,
+            previous: WeatherKind::Clear,
+            blend: 1.0,
+            effects: WeatherKind::Clear.effects(),
+            flash: 0.0,
+            thunder_in: 0.0,
+        }
+    }
+}
+
+impl WeatherState {
+    /// Switch to `kind` at once (new level or round).
+    pub fn snap(&mut self, kind: WeatherKind) {
+        self.kind = kind;
+        self.previous = kind;
+        self.blend = 1.0;
+        self.effects = kind.effects();
+    }
+
+    /// Start blending from the current weather towards `kind`.
+    pub fn change_to(&mut self, kind: WeatherKind) {
+        self.previous = self.kind;
+        self.kind = kind;
+        self.blend = 0.0;
+    }
+
+    /// Advance the blend by `dt` seconds of a `transition` seconds change.
+    pub fn step(&mut self, dt: f32, transition: f32) {
+        self.blend = if transition > 0.0 { (self.blend + dt / transition).min(1.0) } else { 1.0 };
+        self.effects = self.previous.effects().lerp(self.kind.effects(), self.blend);
+    }
+}
+
+#[derive(Component)]
+struct RainDrop {
+    offset: Vec3, // from the camera
+}
+
+pub struct WeatherPlugin;
+impl Plugin for WeatherPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WeatherConfig>()
+            .init_resource::<WeatherState>()
+            .add_systems(Startup, spawn_rain)
+            .add_systems(Update, (follow_schedule, apply_weather, lightning, update_rain).chain());
+    }
+}
+
+fn spawn_rain(
+    mut commands: Commands,
+    cfg: Res<WeatherConfig>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mesh = meshes.add(Cuboid::new(0.02, 0.9, 0.02));
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.75, 0.8, 0.9, 0.35),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    });
+    let mut rng = thread_rng();
+    let b = cfg.rain_box;
+    for _ in 0..cfg.rain_drops {
+        let offset = Vec3::new(rng.gen_range(-b.x..b.x), rng.gen_range(-b.y..b.y), rng.gen_range(-b.z..b.z));
+        commands.spawn((
+            PbrBundle { mesh: mesh.clone(), material: material.clone(), visibility: Visibility::Hidden, ..default() },
+            RainDrop { offset },
+        ));
+    }
+}
+
+fn follow_schedule(
+    (sim, score, level): (Res<SimState>, Res<Score>, Option<Res<LevelDef>>),
+    phase: Res<State<AppState>>,
+    loc: Res<Localization>,
+    mut state: ResMut<WeatherState>,
+    mut wind: ResMut<WindState>,
+    mut toasts: ResMut<ToastQueue>,
+    mut log: Option<ResMut<EventLog>>,
+    mut ev_restart: EventReader<RestartGameEvent>,
+) {
+    let Some(level) = level else { return; };
+    // A new level or a restarted round begins with its start weather straight away (the run
+    // clock may not have been reset yet this frame).
+    let fresh = ev_restart.read().count() > 0 || level.is_changed();
+    let time = if fresh { 0.0 } else { score.run_time(sim.elapsed_seconds) };
+    let target = level.weather.as_ref().map_or(WeatherKind::Clear, |w| w.kind_at(time));
+    if !fresh && (target == state.kind || !phase.get().in_game()) {
+        return;
+    }
+    // Levels without weather keep the wind their scripts set; leaving a weather level resets it.
+    if level.weather.is_some() || state.active {
+        (wind.base_strength, wind.gustiness) = target.wind();
+    }
+    state.active = level.weather.is_some();
+    if state.active {
+        info!("Weather: {:?}", target);
+    }
+    if fresh {
+        state.snap(target);
+    } else {
+        state.change_to(target);
+    }
+    if state.active && !(fresh && target == WeatherKind::Clear) {
+        let text = loc.t(target.key()).to_string();
+        let kind = if matches!(target, WeatherKind::Rain | WeatherKind::Storm) { ToastKind::Warning } else { ToastKind::Info };
+        toasts.push_for(text.clone(), kind, 4.0);
+        if let Some(log) = log.as_mut() {
+            log.push(LogKind::Info, text);
+        }
+    }
+}
+
+fn apply_weather(
+    time: Res<Time>,
+    (cfg, wind): (Res<WeatherConfig>, Res<WindState>),
+    mut state: ResMut<WeatherState>,
+    mut conditions: ResMut<BallConditions>,
+    mut day_night: ResMut<DayNightState>,
+) {
+    state.step(time.delta_seconds(), cfg.transition);
+    state.flash = (state.flash - cfg.flash_decay * time.delta_seconds()).max(0.0);
+    let fx = state.effects;
+    let push = wind.dir() * wind.strength * cfg.drift_accel * fx.drift;
+    let wanted = BallConditions { friction: fx.friction, bounce: fx.bounce, wind_push: Vec3::new(push.x, 0.0, push.y) };
+    if *conditions != wanted {
+        *conditions = wanted;
+    }
+    let light = fx.light * (1.0 + cfg.flash_boost * state.flash);
+    if day_night.weather_light != light {
+        day_night.weather_light = light;
+    }
+}
+
+fn lightning(
+    time: Res<Time>,
+    cfg: Res<WeatherConfig>,
+    phase: Res<State<AppState>>,
+    mut state: ResMut<WeatherState>,
+    mut ev_thunder: EventWriter<ThunderEvent>,
+) {
+    if state.effects.thunder <= 0.0 || !phase.get().round_in_view() {
+        return;
+    }
+    state.thunder_in -= time.delta_seconds() * state.effects.thunder;
+    if state.thunder_in > 0.0 {
+        return;
+    }
+    let mut rng = thread_rng();
+    let (lo, hi) = cfg.thunder_interval;
+    state.thunder_in = rng.gen_range(lo..hi);
+    let distance: f32 = rng.gen_range(0.0..1.0);
+    state.flash = state.flash.max(1.0 - 0.7 * distance);
+    ev_thunder.send(ThunderEvent { distance });
+}
+
+fn update_rain(
+    time: Res<Time>,
+    (cfg, wind, quality): (Res<WeatherConfig>, Res<WindState>, Res<ParticleQuality>),
+    state: Res<WeatherState>,
+    q_cam: Query<&GlobalTransform, With<OrbitCamera>>,
+    mut q_drops: Query<(&mut RainDrop, &mut Transform, &mut Visibility)>,
+) {
+    let wanted = quality.scaled((cfg.rain_drops as f32 * state.effects.rain) as usize);
+    let cam = q_cam.get_single().map(|t| t.translation()).ok();
+    let dt = time.delta_seconds();
+    let b = cfg.rain_box;
+    // Streaks lean with the wind.
+    let push = wind.dir() * wind.strength * 4.0;
+    let vel = Vec3::new(push.x, -cfg.rain_speed, push.y);
+    let tilt = Quat::from_rotation_arc(Vec3::NEG_Y, vel.normalize());
+    let mut rng = thread_rng();
+    for (i, (mut drop, mut t, mut vis)) in q_drops.iter_mut().enumerate() {
+        let Some(cam) = cam.filter(|_| i < wanted) else {
+            if *vis != Visibility::Hidden {
+                *vis = Visibility::Hidden;
+            }
+            continue;
+        };
+        drop.offset += vel * dt;
+        if drop.offset.y < -b.y {
+            drop.offset = Vec3::new(rng.gen_range(-b.x..b.x), b.y, rng.gen_range(-b.z..b.z));
+        }
+        t.translation = cam + drop.offset;
+        t.rotation = tilt;
+        *vis = Visibility::Visible;
+    }
+}
@@ -0,0 +1,54 @@
+// Global wind.
+// One `WindState` drives everything that moves in the wind (tree sway today). Direction and
+// base strength are plain fields so weather / level code can set them; `update_wind` layers
+// slow gusts on top and writes the result to `strength` every frame.
+
+use bevy::prelude::*;
+
+pub struct WindPlugin;
+impl Plugin for WindPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WindState>()
+            .add_systems(Update, update_wind);
+    }
+}
+
+#[derive(Resource, Clone, Debug)]
+pub struct WindState {
+    pub direction: Vec2,     // XZ heading the wind blows towards (normalized on use)
+    pub base_strength: f32,  // 0 = calm, 1 = strong breeze, >1 = storm
+    pub gustiness: f32,      // gust amplitude as a fraction of base_strength
+    pub gust_frequency: f32, // gust cycles per second (roughly)
+    pub strength: f32,       // current strength incl. gusts (written by update_wind)
+    pub time: f32,           // wind clock (s); shaders use it as their animation time
+}
+impl Default for WindState {
+    fn default() -> Self {
+        Self {
+            direction: Vec2::new(0.8, 0.6),
+            base_strength: 0.6,
+            gustiness: 0.5,
+            gust_frequency: 0.15,
+            strength: 0.6,
+            time: 0.0,
+        }
+    }
+}
+
+impl WindState {
+    /// Strength at wind time `t`: two incommensurate sines so gusts never repeat exactly.
+    pub fn strength_at(&self, t: f32) -> f32 {
+        let w = t * self.gust_frequency * std::f32::consts::TAU;
+        let gust = 0.65 * w.sin() + 0.35 * (w * 2.71 + 1.3).sin();
+        (self.base_strength * (1.0 + self.gustiness * gust)).max(0.0)
+    }
+
+    pub fn dir(&self) -> Vec2 {
+        self.direction.normalize_or_zero()
+    }
+}
+
+fn update_wind(time: Res<Time>, mut wind: ResMut<WindState>) {
+    wind.time += time.delta_seconds();
+    wind.strength = wind.strength_at(wind.time);
+}
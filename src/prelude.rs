@@ -7,29 +7,67 @@
 //! most commonly used building blocks.
 
 /// Core simulation / timing
-pub use crate::plugins::core_sim::{SimState, AutoConfig, AutoRuntime, LogState, CoreSimPlugin};
+pub use crate::plugins::core_sim::{SimState, SimRng, AutoConfig, AutoRuntime, LogState, CoreSimPlugin};
+pub use crate::plugins::headless::{HeadlessSim, HeadlessSimPlugin, SyntheticTerrain};
 
 /// Gameplay domain types
 pub use crate::plugins::ball::{Ball, BallKinematic, BallPlugin};
-pub use crate::plugins::target::{Target, TargetPlugin, TargetParams};
+pub use crate::plugins::difficulty::{DifficultyCurve, Difficulty};
+pub use crate::plugins::target::{Target, TargetPlugin, TargetParams, TargetInfo, TargetBob, TargetFocus, TargetKind, TargetMotion, TargetPath};
+pub use crate::plugins::target_relocation::RelocationConfig;
+pub use crate::plugins::target_proximity::{ProximityConfig, TargetProximity, TargetProximityPlugin};
+pub use crate::plugins::cup_target::{CupConfig, CupTargetPlugin, TargetStyle};
+pub use crate::plugins::special_targets::{SpecialTargetConfig, SpecialTargetsPlugin};
+pub use crate::plugins::gates::{Gate, GateDef, GatePlugin, GateProgress};
 pub use crate::plugins::shooting::ShootingPlugin;
 pub use crate::plugins::game_state::{GameStatePlugin, ShotState, ShotConfig, Score, ShotMode};
 pub use crate::plugins::level::{LevelPlugin, LevelDef};
+pub use crate::plugins::players::{LocalPlayers, PlayerScore, PlayersPlugin};
+pub use crate::plugins::ghost_race::{GhostRace, GhostRacePlugin, GhostTrack};
+pub use crate::plugins::challenge::{ActiveChallenge, ChallengeCode, ChallengePlugin};
+pub use crate::plugins::spectate::{InteractiveUi, SpectateMode, SpectatePlugin};
+#[cfg(feature = "net")]
+pub use crate::plugins::net::{NetMessage, NetPlugin, NetSession};
 
 /// World / environment
-pub use crate::plugins::terrain::{TerrainPlugin, TerrainSampler, TerrainConfig};
+pub use crate::plugins::terrain::{TerrainPlugin, TerrainSampler, TerrainConfig, TerrainHit, TerrainStamp};
+pub use crate::plugins::terrain_deform::{TerrainDeformPlugin, TerrainDeformations};
+pub use crate::plugins::biome::{Biome, BiomeMap};
 pub use crate::plugins::vegetation::{
-    VegetationPlugin, VegetationConfig, VegetationCullingConfig, VegetationLodConfig,
+    VegetationPlugin, VegetationConfig, VegetationCullingConfig, VegetationLodConfig, VegetationGrid,
 };
+pub use crate::plugins::vegetation_species::{VegetationSpecies, VegetationSpeciesList};
+pub use crate::plugins::vegetation_cache::VegetationCache;
+pub use crate::plugins::vegetation_exclusion::{VegetationExclusionConfig, VegetationExclusions};
+pub use crate::plugins::tree_instancing::TreeInstancingPlugin;
+pub use crate::plugins::tree_wind::{TreeWindPlugin, TreeWindConfig};
+pub use crate::plugins::wind::{WindPlugin, WindState};
+pub use crate::plugins::day_night::{DayNightConfig, DayNightPlugin, DayNightState};
+pub use crate::plugins::weather::{WeatherConfig, WeatherKind, WeatherPlugin, WeatherState};
+pub use crate::plugins::water_material::{WaterMaterial, WaterMaterialPlugin};
+pub use crate::plugins::rivers::{RiverConfig, Rivers, RiversPlugin};
+pub use crate::plugins::birds::{BirdConfig, BirdsPlugin};
+pub use crate::plugins::hole_in_one::{HoleInOneConfig, HoleInOnePlugin};
+pub use crate::plugins::trick_shots::{TrickConfig, TrickDetector, TrickShotsPlugin};
+pub use crate::plugins::powerups::{PowerupConfig, PowerupInventory, PowerupsPlugin};
+pub use crate::plugins::obstacles::{Health, Obstacle, ObstacleConfig, ObstacleKind, ObstaclesPlugin};
+pub use crate::plugins::vibe_meter::{VibeConfig, VibeMeter, VibeMeterPlugin, VibeStroke};
+pub use crate::plugins::tree_impact::{TreeImpactPlugin, TreeImpactConfig};
+pub use crate::plugins::grass::{GrassPlugin, GrassConfig};
 pub use crate::plugins::contour_material::ContourMaterialPlugin;
 pub use crate::plugins::terrain_material::TerrainMaterialPlugin;
 
 /// Presentation / UX
 pub use crate::plugins::hud::{HudPlugin, Hud};
-pub use crate::plugins::camera::CameraPlugin;
+pub use crate::plugins::minimap::MinimapPlugin;
+pub use crate::plugins::camera::{CameraMode, CameraPlugin};
 pub use crate::plugins::particles::ParticlePlugin;
 pub use crate::plugins::game_audio::GameAudioPlugin;
+pub use crate::plugins::app_state::{AppState, AppStatePlugin};
 pub use crate::plugins::main_menu::MainMenuPlugin;
+pub use crate::plugins::loading_state::LoadingStatePlugin;
+pub use crate::plugins::texture_pipeline::TexturePipelinePlugin;
+pub use crate::plugins::low_power::LowPowerPlugin;
 
 /// Optional utilities
 pub use crate::plugins::autoplay::AutoplayPlugin;
@@ -1,8 +1,17 @@
-use std::path::Path;
+// Screenshot capture (--screenshot / --screenshot-every).
+// The first frame and, once the run duration is reached, the last frame are saved to the
+// screenshots directory. With an interval, numbered frames (frame_0001.png, ...) are also saved
+// every that many simulated seconds into a subdirectory of their own per run (run_<unix time>),
+// optionally downscaled, so automated runs leave a visual timeline.
+
+use std::path::{Path, PathBuf};
 use std::fs;
 use bevy::prelude::*;
+use bevy::utils::SystemTime;
 use bevy::window::PrimaryWindow;
 use bevy::render::view::screenshot::ScreenshotManager;
+use image::imageops::FilterType;
+use image::RgbImage;
 
 use crate::plugins::core_sim::{SimState, AutoConfig};
 
@@ -12,9 +21,71 @@ pub struct ScreenshotConfig {
     pub first_frame_path: String,
     pub last_frame_path: String,
     pub legacy_last_run_path: String, // kept for backwards compatibility
+    pub interval: Option<f32>,          // simulated s between sequence frames
+    pub scale: f32,                     // sequence frame size factor (0..1]
+    pub sequence_dir: Option<PathBuf>,  // this run's sequence directory
 }
 impl ScreenshotConfig {
-    pub fn new(enabled: bool) -> Self { Self { enabled, first_frame_path: "screenshots/first_frame.png".into(), last_frame_path: "screenshots/last_frame.png".into(), legacy_last_run_path: "screenshots/last_run.png".into() } }
+    /// Screenshots go to `dir` (StoragePaths::screenshots_dir).
+    pub fn new(enabled: bool, dir: &Path) -> Self {
+        let path = |name: &str| dir.join(name).to_string_lossy().into_owned();
+        Self {
+            enabled,
+            first_frame_path: path("first_frame.png"),
+            last_frame_path: path("last_frame.png"),
+            legacy_last_run_path: path("last_run.png"),
+            interval: None,
+            scale: 1.0,
+            sequence_dir: None,
+        }
+    }
+
+    /// Also capture a frame every `interval` simulated seconds, scaled by `scale`.
+    pub fn with_sequence(mut self, interval: Option<f32>, scale: f32) -> Self {
+        self.interval = interval;
+        self.scale = scale.clamp(0.01, 1.0);
+        if interval.is_some() {
+            let secs = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).map_or(0, |d| d.as_secs());
+            let dir = Path::new(&self.first_frame_path).parent().unwrap_or(Path::new("."));
+            self.sequence_dir = Some(dir.join(run_dir_name(secs)));
+        }
+        self
+    }
+}
+
+/// Sequence directory for a run started at `unix_secs`.
+pub fn run_dir_name(unix_secs: u64) -> String {
+    format!("run_{unix_secs}")
+}
+
+/// File name of sequence frame `index` (from 1).
+pub fn sequence_frame_name(index: u32) -> String {
+    format!("frame_{index:04}.png")
+}
+
+/// `width` x `height` RGB pixels shrunk by `scale` (unchanged at 1); None if the sizes disagree.
+pub fn downscale(width: u32, height: u32, rgb: Vec<u8>, scale: f32) -> Option<RgbImage> {
+    let img = RgbImage::from_raw(width, height, rgb)?;
+    if scale >= 1.0 {
+        return Some(img);
+    }
+    let size = |n: u32| ((n as f32 * scale).round() as u32).max(1);
+    Some(image::imageops::resize(&img, size(width), size(height), FilterType::Triangle))
+}
+
+fn save_frame(img: Image, path: &Path, scale: f32) {
+    let rgb = match img.try_into_dynamic() {
+        Ok(dynamic) => dynamic.to_rgb8(),
+        Err(e) => {
+            warn!("SCREENSHOT frame conversion failed error={:?}", e);
+            return;
+        }
+    };
+    let (width, height) = (rgb.width(), rgb.height());
+    let Some(frame) = downscale(width, height, rgb.into_raw(), scale) else { return; };
+    if let Err(e) = frame.save(path) {
+        warn!("SCREENSHOT frame save failed path={} error={}", path.display(), e);
+    }
 }
 
 #[derive(Resource, Default)]
@@ -23,6 +94,8 @@ pub struct ScreenshotState {
     pub first_saved: bool,
     pub last_requested: bool,
     pub last_saved: bool,
+    pub sequence_frames: u32,
+    pub next_sequence_at: f32, // simulated s
 }
 
 pub struct ScreenshotPlugin;
@@ -31,7 +104,7 @@ impl Plugin for ScreenshotPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ScreenshotState>()
             .add_systems(Startup, cleanup_previous_screenshots)
-            .add_systems(Update, capture_screenshot);
+            .add_systems(Update, (capture_screenshot, capture_sequence_frame.after(capture_screenshot)));
     }
 }
 
@@ -101,3 +174,28 @@ fn capture_screenshot(
         }}
     }
 }
+
+fn capture_sequence_frame(
+    sim: Res<SimState>,
+    cfg: Option<Res<ScreenshotConfig>>,
+    mut state: ResMut<ScreenshotState>,
+    mut screenshot_manager: ResMut<ScreenshotManager>,
+    q_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    let Some(cfg) = cfg else { return; };
+    let (Some(interval), Some(dir)) = (cfg.interval, cfg.sequence_dir.as_ref()) else { return; };
+    if !cfg.enabled || sim.elapsed_seconds < state.next_sequence_at { return; }
+    let Ok(window_entity) = q_window.get_single() else { return; };
+    if state.sequence_frames == 0 {
+        if let Err(e) = fs::create_dir_all(dir) { warn!("SCREENSHOT dir create failed error={}", e); }
+    }
+    let path = dir.join(sequence_frame_name(state.sequence_frames + 1));
+    let scale = cfg.scale;
+    let request = screenshot_manager.take_screenshot(window_entity, move |img| save_frame(img, &path, scale));
+    // Only one screenshot per window and frame: if the first / last frame took it, try next frame.
+    if request.is_ok() {
+        state.sequence_frames += 1;
+        state.next_sequence_at = ((sim.elapsed_seconds / interval).floor() + 1.0) * interval;
+        info!("SCREENSHOT sequence frame={} dir={}", state.sequence_frames, dir.display());
+    }
+}
@@ -0,0 +1,347 @@
+// Visual regression checks (rendering).
+// `capture_reference_frame` runs an app through a fixed `Scenario`: seeded randomness, the camera
+// pinned to one pose and terrain streamed around it. Once chunk building has settled it captures
+// the window and exits. `check_against_reference` compares that frame with the stored reference
+// (tests/reference_frames/<name>.png) using a perceptual colour difference per pixel (YIQ distance,
+// as in pixelmatch), so shading noise passes and a scenario only fails when more than its share of
+// pixels visibly changed. On a failure the actual frame and a diff image are written next to the
+// reference. A missing reference, or VIBE_GOLF_BLESS=1, records the frame as the new reference.
+// Rendering needs a GPU and a window, so the scene tests are #[ignore]d; run them locally with
+// `cargo test --test visual_regression -- --ignored`.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use bevy::app::AppExit;
+use bevy::asset::{AssetMode, AssetPlugin};
+use bevy::prelude::*;
+use bevy::render::view::screenshot::ScreenshotManager;
+use bevy::window::{ExitCondition, PrimaryWindow, WindowResolution};
+use bevy::winit::{WakeUp, WinitPlugin};
+use image::{Rgb, RgbImage};
+
+use crate::launch_options::LaunchOptions;
+use crate::plugins::core_sim::{CoreSimPlugin, SimRng};
+use crate::plugins::storage::StoragePaths;
+use crate::plugins::terrain::{InProgressChunks, TerrainPlugin, TerrainSampler, TerrainStreamFocus};
+use crate::plugins::terrain_material::TerrainMaterialPlugin;
+use crate::plugins::terrain_presets::TerrainPreset;
+use crate::screenshot::downscale;
+
+/// Stored reference frames, relative to the crate root.
+pub const REFERENCE_DIR: &str = "tests/reference_frames";
+/// Set to 1 to overwrite the references with the captured frames.
+pub const BLESS_ENV: &str = "VIBE_GOLF_BLESS";
+/// Frames after which a capture gives up (terrain never settled, no frame delivered).
+const MAX_CAPTURE_FRAMES: u32 = 3000;
+
+/// One fixed view of the game to compare between builds.
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    pub name: String,
+    pub seed: u64,
+    pub camera: Transform,
+    pub size: (u32, u32),          // window size in physical pixels
+    pub warmup_frames: u32,        // rendered before waiting for terrain chunks
+    pub pixel_threshold: f32,      // colour difference (0..1) that counts a pixel as changed
+    pub max_changed_fraction: f32, // share of changed pixels that still passes
+}
+
+impl Scenario {
+    /// Camera at `eye` looking at `look_at`, with the default seed, size and thresholds.
+    pub fn new(name: &str, eye: Vec3, look_at: Vec3) -> Self {
+        Self {
+            name: name.to_string(),
+            seed: 0,
+            camera: Transform::from_translation(eye).looking_at(look_at, Vec3::Y),
+            size: (640, 360),
+            warmup_frames: 30,
+            pixel_threshold: 0.1,
+            max_changed_fraction: 0.005,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CaptureError {
+    NoWindow,
+    Timeout(u32), // frames waited
+    BadFrame,
+}
+
+impl fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoWindow => write!(f, "no primary window to capture"),
+            Self::Timeout(frames) => write!(f, "no frame captured after {frames} frames"),
+            Self::BadFrame => write!(f, "captured frame could not be converted to RGB"),
+        }
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+type FrameSlot = Arc<Mutex<Option<Result<RgbImage, CaptureError>>>>;
+
+#[derive(Resource)]
+struct ReferenceCapture {
+    scenario: Scenario,
+    frames: u32,
+    requested: bool,
+    slot: FrameSlot,
+}
+
+/// Run `app` (built but not yet run) through `scenario` and return the rendered frame. The app
+/// exits once the frame is captured.
+pub fn capture_reference_frame(app: &mut App, scenario: &Scenario) -> Result<RgbImage, CaptureError> {
+    let slot = FrameSlot::default();
+    app.insert_resource(SimRng::seeded(scenario.seed))
+        .insert_resource(ReferenceCapture { scenario: scenario.clone(), frames: 0, requested: false, slot: slot.clone() })
+        .add_systems(Update, drive_capture)
+        .add_systems(PostUpdate, pin_camera.before(TransformSystem::TransformPropagate));
+    app.run();
+    let result = slot.lock().map(|mut s| s.take()).unwrap_or(None);
+    result.unwrap_or(Err(CaptureError::Timeout(MAX_CAPTURE_FRAMES)))
+}
+
+// Warm up, wait for the terrain, then ask for the screenshot; exit once it has arrived.
+fn drive_capture(
+    mut capture: ResMut<ReferenceCapture>,
+    chunks: Option<Res<InProgressChunks>>,
+    sampler: Option<Res<TerrainSampler>>,
+    mut screenshots: ResMut<ScreenshotManager>,
+    mut q_window: Query<(Entity, &mut Window), With<PrimaryWindow>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let finish = |capture: &ReferenceCapture, result, exit: &mut EventWriter<AppExit>| {
+        if let Ok(mut slot) = capture.slot.lock() {
+            slot.get_or_insert(result);
+        }
+        exit.send(AppExit::Success);
+    };
+    if capture.slot.lock().is_ok_and(|s| s.is_some()) {
+        exit.send(AppExit::Success);
+        return;
+    }
+    capture.frames += 1;
+    if capture.frames > MAX_CAPTURE_FRAMES {
+        finish(&capture, Err(CaptureError::Timeout(MAX_CAPTURE_FRAMES)), &mut exit);
+        return;
+    }
+    let Ok((window_entity, mut window)) = q_window.get_single_mut() else {
+        finish(&capture, Err(CaptureError::NoWindow), &mut exit);
+        return;
+    };
+    let (width, height) = capture.scenario.size;
+    if capture.frames == 1 {
+        window.resolution = WindowResolution::new(width as f32, height as f32).with_scale_factor_override(1.0);
+    }
+    let terrain_busy = chunks.is_some_and(|c| !c.set.is_empty()) || sampler.is_some_and(|s| s.is_placeholder());
+    if capture.requested || capture.frames <= capture.scenario.warmup_frames || terrain_busy {
+        return;
+    }
+    let slot = capture.slot.clone();
+    let request = screenshots.take_screenshot(window_entity, move |img| {
+        let (w, h) = (img.width(), img.height());
+        let frame = img.try_into_dynamic().ok().and_then(|d| downscale(w, h, d.to_rgb8().into_raw(), 1.0));
+        if let Ok(mut slot) = slot.lock() {
+            *slot = Some(frame.ok_or(CaptureError::BadFrame));
+        }
+    });
+    capture.requested = request.is_ok();
+}
+
+// The scenario's camera wins over whatever moved it this frame; terrain streams around it.
+fn pin_camera(
+    capture: Res<ReferenceCapture>,
+    focus: Option<ResMut<TerrainStreamFocus>>,
+    mut q_cam: Query<&mut Transform, With<Camera3d>>,
+) {
+    for mut t in &mut q_cam {
+        *t = capture.scenario.camera;
+    }
+    if let Some(mut focus) = focus {
+        focus.pos = Some(capture.scenario.camera.translation);
+    }
+}
+
+/// Perceptual difference of two colours: 0 (same) to 1 (most distant pair). YIQ distance weighted
+/// like pixelmatch, so luminance changes count more than hue shifts.
+pub fn color_delta(a: [u8; 3], b: [u8; 3]) -> f32 {
+    let yiq = |c: [u8; 3]| {
+        let (r, g, b) = (c[0] as f32, c[1] as f32, c[2] as f32);
+        Vec3::new(
+            r * 0.298_895 + g * 0.586_622 + b * 0.114_482,
+            r * 0.595_978 - g * 0.274_176 - b * 0.321_802,
+            r * 0.211_470 - g * 0.522_617 + b * 0.311_147,
+        )
+    };
+    let d = yiq(a) - yiq(b);
+    ((0.5053 * d.x * d.x + 0.299 * d.y * d.y + 0.1957 * d.z * d.z) / 35215.0).sqrt()
+}
+
+/// Pixels that changed between two frames.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameDiff {
+    pub changed: u32,
+    pub pixels: u32,
+    pub max_delta: f32,
+}
+
+impl FrameDiff {
+    pub fn changed_fraction(&self) -> f32 {
+        if self.pixels == 0 { 0.0 } else { self.changed as f32 / self.pixels as f32 }
+    }
+}
+
+/// Compare `actual` with `reference`; pixels differing by more than `pixel_threshold` count as
+/// changed. None when the sizes differ.
+pub fn perceptual_diff(reference: &RgbImage, actual: &RgbImage, pixel_threshold: f32) -> Option<FrameDiff> {
+    if reference.dimensions() != actual.dimensions() {
+        return None;
+    }
+    let mut diff = FrameDiff { changed: 0, pixels: reference.width() * reference.height(), max_delta: 0.0 };
+    for (a, b) in reference.pixels().zip(actual.pixels()) {
+        let delta = color_delta(a.0, b.0);
+        diff.max_delta = diff.max_delta.max(delta);
+        if delta > pixel_threshold {
+            diff.changed += 1;
+        }
+    }
+    Some(diff)
+}
+
+/// The reference faded toward white with the changed pixels in red.
+pub fn diff_image(reference: &RgbImage, actual: &RgbImage, pixel_threshold: f32) -> RgbImage {
+    RgbImage::from_fn(reference.width(), reference.height(), |x, y| {
+        let a = reference.get_pixel(x, y).0;
+        match actual.get_pixel_checked(x, y) {
+            Some(b) if color_delta(a, b.0) <= pixel_threshold => {
+                let luma = (a[0] as f32 * 0.299 + a[1] as f32 * 0.587 + a[2] as f32 * 0.114) as u8;
+                let faded = 255 - (255 - luma) / 4;
+                Rgb([faded, faded, faded])
+            }
+            _ => Rgb([255, 0, 0]),
+        }
+    })
+}
+
+/// Outcome of comparing a frame with its reference.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReferenceCheck {
+    Created, // no reference yet (or blessing): the frame became the reference
+    Matched(FrameDiff),
+    Mismatched(FrameDiff),
+    SizeChanged { reference: (u32, u32), actual: (u32, u32) },
+}
+
+impl ReferenceCheck {
+    pub fn passed(&self) -> bool {
+        matches!(self, Self::Created | Self::Matched(_))
+    }
+}
+
+pub fn reference_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{name}.png"))
+}
+
+/// Where a failing frame (`actual`) and its diff image (`diff`) are written.
+pub fn failure_path(dir: &Path, name: &str, kind: &str) -> PathBuf {
+    dir.join(format!("{name}.{kind}.png"))
+}
+
+/// Whether VIBE_GOLF_BLESS asks for the references to be replaced.
+pub fn bless_requested() -> bool {
+    std::env::var(BLESS_ENV).is_ok_and(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+}
+
+/// Compare `frame` with the scenario's reference in `dir` (recording it when missing or `bless`).
+pub fn check_against_reference(frame: &RgbImage, dir: &Path, scenario: &Scenario, bless: bool) -> io::Result<ReferenceCheck> {
+    let path = reference_path(dir, &scenario.name);
+    let (actual_path, diff_path) = (failure_path(dir, &scenario.name, "actual"), failure_path(dir, &scenario.name, "diff"));
+    let save = |img: &RgbImage, path: &Path| img.save(path).map_err(io::Error::other);
+    if bless || !path.exists() {
+        fs::create_dir_all(dir)?;
+        save(frame, &path)?;
+        return Ok(ReferenceCheck::Created);
+    }
+    let reference = image::open(&path).map_err(io::Error::other)?.to_rgb8();
+    let check = match perceptual_diff(&reference, frame, scenario.pixel_threshold) {
+        Some(diff) if diff.changed_fraction() <= scenario.max_changed_fraction => ReferenceCheck::Matched(diff),
+        Some(diff) => ReferenceCheck::Mismatched(diff),
+        None => ReferenceCheck::SizeChanged { reference: reference.dimensions(), actual: frame.dimensions() },
+    };
+    if check.passed() {
+        let _ = fs::remove_file(&actual_path);
+        let _ = fs::remove_file(&diff_path);
+    } else {
+        save(frame, &actual_path)?;
+        save(&diff_image(&reference, frame, scenario.pixel_threshold), &diff_path)?;
+    }
+    Ok(check)
+}
+
+/// Test support: a window showing the terrain the way the game renders it (material, lighting,
+/// sky colour, High preset) with no gameplay, UI or saved settings involved.
+pub fn scene_app(scenario: &Scenario) -> App {
+    let (width, height) = scenario.size;
+    let window = Window {
+        title: format!("Vibe Golf visual check: {}", scenario.name),
+        resolution: WindowResolution::new(width as f32, height as f32).with_scale_factor_override(1.0),
+        resizable: false,
+        ..default()
+    };
+    // Test harness threads are not the main thread.
+    let mut winit = WinitPlugin::<WakeUp>::default();
+    winit.run_on_any_thread = true;
+    let mut app = App::new();
+    app.insert_resource(ClearColor(Color::srgb(0.52, 0.80, 0.92)))
+        .insert_resource(AmbientLight { color: Color::srgb(0.55, 0.55, 0.60), brightness: 800.0 })
+        .insert_resource(StoragePaths::new(std::env::temp_dir().join("vibe_golf_visual_regression")))
+        .insert_resource(LaunchOptions { quality: Some(TerrainPreset::High), seed: Some(scenario.seed), ..default() })
+        .add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin { primary_window: Some(window), exit_condition: ExitCondition::DontExit, ..default() })
+                .set(winit)
+                .set(AssetPlugin { mode: AssetMode::Unprocessed, file_path: "assets".into(), ..default() }),
+        )
+        .add_plugins((CoreSimPlugin, TerrainMaterialPlugin, TerrainPlugin))
+        .add_systems(Startup, spawn_scene_camera);
+    app
+}
+
+fn spawn_scene_camera(mut commands: Commands) {
+    commands.spawn(Camera3dBundle::default());
+    commands.spawn(DirectionalLightBundle {
+        directional_light: DirectionalLight { illuminance: 40_000.0, shadows_enabled: true, ..default() },
+        transform: Transform::from_xyz(30.0, 60.0, 30.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    });
+}
+
+/// Test support: render `scenario` in `scene_app`, compare it with its reference and panic with
+/// the diff details (and where the actual / diff images went) when it does not match.
+pub fn assert_matches_reference(scenario: &Scenario) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(REFERENCE_DIR);
+    let mut app = scene_app(scenario);
+    let frame = capture_reference_frame(&mut app, scenario).unwrap_or_else(|e| panic!("{}: {e}", scenario.name));
+    match check_against_reference(&frame, &dir, scenario, bless_requested()) {
+        Ok(ReferenceCheck::Created) => eprintln!("{}: reference recorded in {}", scenario.name, dir.display()),
+        Ok(ReferenceCheck::Matched(_)) => {}
+        Ok(ReferenceCheck::Mismatched(diff)) => panic!(
+            "{}: {:.2}% of pixels changed (limit {:.2}%, max delta {:.2}); see {}",
+            scenario.name,
+            diff.changed_fraction() * 100.0,
+            scenario.max_changed_fraction * 100.0,
+            diff.max_delta,
+            failure_path(&dir, &scenario.name, "diff").display()
+        ),
+        Ok(ReferenceCheck::SizeChanged { reference, actual }) => {
+            panic!("{}: frame is {actual:?}, reference {reference:?}", scenario.name)
+        }
+        Err(e) => panic!("{}: {e}", scenario.name),
+    }
+}
@@ -0,0 +1,35 @@
+use std::collections::HashSet;
+
+use vibe_golf::plugins::achievements::{earned_by_stats, is_quick_finish, Achievement};
+use vibe_golf::plugins::profile::{LifetimeStats, Profile};
+
+#[test]
+fn lifetime_stats_earn_their_milestones() {
+    assert!(earned_by_stats(&LifetimeStats::default()).is_empty());
+    let stats = LifetimeStats { holes_in_one: 1, longest_drive: 299.0, ..Default::default() };
+    assert_eq!(earned_by_stats(&stats), vec![Achievement::HoleInOne]);
+    let stats = LifetimeStats { longest_drive: 300.0, ..Default::default() };
+    assert_eq!(earned_by_stats(&stats), vec![Achievement::LongDrive]);
+}
+
+#[test]
+fn quick_finish_needs_under_two_minutes() {
+    assert!(is_quick_finish(119.9));
+    assert!(!is_quick_finish(120.0));
+}
+
+#[test]
+fn ids_are_unique() {
+    let ids: HashSet<_> = Achievement::ALL.iter().map(|a| a.id()).collect();
+    assert_eq!(ids.len(), Achievement::ALL.len());
+}
+
+#[test]
+fn unlocks_persist_in_the_profile() {
+    let mut profile = Profile::default();
+    assert!(profile.unlock(Achievement::MidAirHit.id()));
+    assert!(!profile.unlock(Achievement::MidAirHit.id()));
+    let loaded = Profile::from_json(&profile.to_json()).unwrap();
+    assert!(loaded.is_unlocked("mid_air_hit"));
+    assert!(!loaded.is_unlocked("hole_in_one"));
+}
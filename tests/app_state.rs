@@ -0,0 +1,40 @@
+// AppState: which states count as a live run, and the shared run conditions.
+use bevy::prelude::*;
+use vibe_golf::plugins::app_state::{gameplay_running, round_in_view, AppState, AppStatePlugin};
+
+#[derive(Resource, Default)]
+struct Runs {
+    gameplay: u32,
+    round: u32,
+}
+
+fn count_gameplay(mut runs: ResMut<Runs>) {
+    runs.gameplay += 1;
+}
+
+fn count_round(mut runs: ResMut<Runs>) {
+    runs.round += 1;
+}
+
+#[test]
+fn game_over_is_part_of_the_run_but_not_paused() {
+    assert!(AppState::GameOver.in_game() && AppState::GameOver.round_in_view());
+    assert!(AppState::Paused.in_game() && !AppState::Paused.round_in_view());
+    assert!(!AppState::Replay.in_game() && !AppState::Menu.round_in_view());
+    assert_eq!(AppState::default(), AppState::Loading);
+}
+
+#[test]
+fn run_conditions_follow_the_state() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AppStatePlugin))
+        .init_resource::<Runs>()
+        .add_systems(Update, (count_gameplay.run_if(gameplay_running), count_round.run_if(round_in_view)));
+    app.update(); // Loading
+    for state in [AppState::Playing, AppState::Paused, AppState::GameOver] {
+        app.world_mut().resource_mut::<NextState<AppState>>().set(state);
+        app.update();
+    }
+    let runs = app.world().resource::<Runs>();
+    assert_eq!((runs.gameplay, runs.round), (3, 2));
+}
@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::autoplay::{direct_power, launch_velocity, plan_shot, AutoplayPlugin, AutoplayReport};
+use vibe_golf::plugins::game_state::ShotConfig;
+use vibe_golf::plugins::headless::{HeadlessSim, SyntheticTerrain};
+use vibe_golf::plugins::landing_reticle::predict_landing;
+
+const GROUND: f32 = 100.0;
+
+#[test]
+fn direct_power_arc_passes_through_the_target() {
+    let cfg = ShotConfig::default();
+    let ball = Vec3::new(0.0, GROUND, 0.0);
+    let target = Vec3::new(40.0, GROUND + 5.0, 30.0);
+    let power = direct_power(ball, target, &cfg).expect("in range");
+    let heading = (target - ball).xz().normalize();
+    let v = launch_velocity(heading, power, &cfg);
+    // Height of the arc where it is horizontally over the target.
+    let t = 50.0 / v.xz().length();
+    let y = ball.y + v.y * t - 0.5 * 9.81 * t * t;
+    assert!((y - target.y).abs() < 0.01, "arc at {y}, target at {}", target.y);
+    // Far beyond full power: no direct shot.
+    assert!(direct_power(ball, Vec3::new(500.0, GROUND, 0.0), &cfg).is_none());
+}
+
+#[test]
+fn far_targets_get_the_closest_lay_up() {
+    let cfg = ShotConfig::default();
+    let sampler = SyntheticTerrain::Flat { height: GROUND }.sampler();
+    let ball = Vec3::new(0.0, GROUND + 0.5, 0.0);
+    let far = plan_shot(ball, Vec3::new(0.0, GROUND + 3.0, 500.0), 0.5, &cfg, &sampler).unwrap();
+    assert!(!far.direct);
+    assert_eq!(far.power, 1.0);
+    assert!((far.heading - Vec2::Y).length() < 1e-5);
+
+    let target = Vec3::new(60.0, GROUND, 0.0); // on the ground, below the launch height
+    let plan = plan_shot(ball, target, 0.5, &cfg, &sampler).unwrap();
+    let landing = predict_landing(ball, launch_velocity(plan.heading, plan.power, &cfg), 0.5, 20.0, |x, z| sampler.height(x, z));
+    assert!(landing.unwrap().xz().distance(target.xz()) < 3.0);
+}
+
+#[test]
+fn bot_completes_holes_on_flat_ground() {
+    let mut sim = HeadlessSim::flat(GROUND);
+    sim.app.add_plugins(AutoplayPlugin);
+    sim.score_mut().max_holes = 2;
+    sim.spawn_ball(Vec2::ZERO);
+    sim.spawn_target(0, Vec2::new(30.0, 70.0), 3.0, 4.5, 1);
+    sim.step_until(60 * 120, |s| s.score().game_over).expect("round finished");
+    let report = sim.world().resource::<AutoplayReport>();
+    assert_eq!(report.holes_completed(), 2);
+    assert!(report.finished);
+    assert_eq!(report.shots, sim.score().shots);
+    assert!(report.holes.iter().all(|h| h.shots >= 1));
+}
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::ball_cam::{integrate_tumble, update_heading, BallCamConfig};
+
+#[test]
+fn tumble_follows_the_spin() {
+    // Rolling toward +X spins about -Z: the view tips over forward.
+    let spin = Vec3::new(0.0, 0.0, -std::f32::consts::PI); // half a turn per second
+    let mut q = Quat::IDENTITY;
+    for _ in 0..60 {
+        q = integrate_tumble(q, spin, 1.0 / 60.0);
+    }
+    let expected = Quat::from_rotation_z(-std::f32::consts::PI);
+    assert!(q.angle_between(expected) < 1e-3, "{q:?}");
+    assert_eq!(integrate_tumble(q, Vec3::ZERO, 0.1), q.normalize());
+}
+
+#[test]
+fn stabilized_heading_turns_toward_travel_only_when_moving() {
+    let cfg = BallCamConfig::default();
+    let heading = Vec3::NEG_Z;
+    // Slow (resting / settling) ball: heading kept.
+    assert_eq!(update_heading(&cfg, heading, Vec3::new(0.2, 3.0, 0.0), 0.1), heading);
+    // Fast ball going +X: heading swings round and stays horizontal.
+    let mut h = heading;
+    for _ in 0..120 {
+        h = update_heading(&cfg, h, Vec3::new(20.0, 8.0, 0.0), 1.0 / 60.0);
+    }
+    assert!(h.distance(Vec3::X) < 0.01, "{h}");
+    assert!(h.y.abs() < 1e-6 && (h.length() - 1.0).abs() < 1e-5);
+}
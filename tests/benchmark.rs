@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::benchmark::{percentile, route_point, BenchmarkConfig, BenchmarkReport, SecondSample};
+
+#[test]
+fn percentiles_use_nearest_rank() {
+    let sorted: Vec<f32> = (1..=100).map(|i| i as f32).collect();
+    assert_eq!(percentile(&sorted, 50.0), 50.0);
+    assert_eq!(percentile(&sorted, 95.0), 95.0);
+    assert_eq!(percentile(&sorted, 100.0), 100.0);
+    assert_eq!(percentile(&sorted, 0.0), 1.0);
+    assert_eq!(percentile(&[], 50.0), 0.0);
+}
+
+#[test]
+fn route_is_a_closed_loop_inside_the_world() {
+    let cfg = BenchmarkConfig::default();
+    let size = 2000.0;
+    assert!(route_point(&cfg, size, 0.0).length() < 1e-3);
+    assert!(route_point(&cfg, size, cfg.duration).length() < 1e-2);
+    for i in 0..=120 {
+        let p = route_point(&cfg, size, cfg.duration * i as f32 / 120.0);
+        assert!(p.x.abs() <= size * 0.5 && p.y.abs() <= size * 0.5, "{p:?} leaves the island");
+    }
+    // Deterministic: the same time gives the same spot.
+    assert_eq!(route_point(&cfg, size, 12.5), route_point(&cfg, size, 12.5));
+}
+
+#[test]
+fn report_summarizes_frames_and_seconds() {
+    let second = |n: u32, chunks: u32| SecondSample {
+        second: n,
+        frames: 60,
+        avg_ms: 16.0,
+        p95_ms: 20.0,
+        max_ms: 25.0,
+        chunks_built: chunks,
+        loaded_chunks: 49,
+        entities: 1200,
+    };
+    let frames = vec![10.0; 90].into_iter().chain(vec![40.0; 10]).collect::<Vec<f32>>();
+    let report = BenchmarkReport::new("meadow", true, &frames, vec![second(1, 4), second(2, 3)]);
+    assert_eq!(report.frames, 100);
+    assert!((report.seconds - 1.3).abs() < 1e-4);
+    assert_eq!((report.frame_time.p50_ms, report.frame_time.p95_ms, report.frame_time.max_ms), (10.0, 40.0, 40.0));
+    assert_eq!(report.chunks_built, 7);
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+    assert_eq!(json["level"], "meadow");
+    assert_eq!(json["per_second"].as_array().unwrap().len(), 2);
+    let csv = report.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], SecondSample::CSV_HEADER);
+    assert_eq!(lines[2], "2,60,16.000,20.000,25.000,3,49,1200");
+}
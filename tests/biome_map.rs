@@ -0,0 +1,38 @@
+use vibe_golf::plugins::biome::{Biome, BiomeMap};
+
+const WORLD: f32 = 1000.0;
+const MAX_H: f32 = 200.0;
+
+// Height ramps from 0 m at the -X edge to 200 m at the +X edge.
+fn ramp(x: f32, _z: f32) -> f32 {
+    ((x / WORLD + 0.5) * MAX_H).clamp(0.0, MAX_H)
+}
+
+#[test]
+fn low_ground_is_wetland_and_peaks_are_highland() {
+    let map = BiomeMap::generate(64, WORLD, MAX_H, 1, ramp);
+    assert_eq!(map.biome(-490.0, 0.0), Biome::Wetland);
+    assert_eq!(map.biome(490.0, 0.0), Biome::Highland);
+    let mid = map.biome(-100.0, 0.0);
+    assert!(matches!(mid, Biome::Meadow | Biome::Forest), "mid slope was {:?}", mid);
+}
+
+#[test]
+fn weights_are_normalized_and_match_dominant_biome() {
+    let map = BiomeMap::generate(64, WORLD, MAX_H, 7, ramp);
+    for &(x, z) in &[(-490.0, -490.0), (-3.3, 12.7), (250.0, 100.0), (499.0, 499.0)] {
+        let w = map.weights(x, z);
+        let sum: f32 = w.iter().sum();
+        assert!((sum - 1.0).abs() < 1e-4, "weights sum {} at ({}, {})", sum, x, z);
+    }
+    // Cell center of a uniform region is fully that biome.
+    let w = map.weights(-490.0, 0.0);
+    assert!((w[Biome::Wetland.index()] - 1.0).abs() < 1e-4);
+}
+
+#[test]
+fn uniform_map_reports_single_biome() {
+    let map = BiomeMap::uniform(Biome::Forest);
+    assert_eq!(map.biome(123.0, -45.0), Biome::Forest);
+    assert_eq!(map.weights(0.0, 0.0)[Biome::Forest.index()], 1.0);
+}
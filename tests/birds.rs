@@ -0,0 +1,40 @@
+// Birds: the boid steering rules that keep a flock together and circling its home.
+use bevy::math::Vec3;
+use vibe_golf::plugins::birds::{steer, BirdConfig, Boid};
+
+fn lone(pos: Vec3, vel: Vec3) -> Vec3 {
+    steer(Boid { pos, vel }, &[], Vec3::ZERO, 30.0, &BirdConfig::default())
+}
+
+#[test]
+fn strays_are_pulled_back_to_the_circle() {
+    let far = Vec3::new(120.0, 0.0, 0.0);
+    assert!(lone(far, Vec3::ZERO).dot(-far) > 0.0, "pulled towards home");
+    let inside = Vec3::new(5.0, 0.0, 0.0);
+    assert!(lone(inside, Vec3::ZERO).x > 0.0, "pushed out to the circle");
+    // On the circle it is steered round (counter-clockwise from above), not inwards.
+    let on = lone(Vec3::new(30.0, 0.0, 0.0), Vec3::ZERO);
+    assert!(on.z > 0.0 && on.x.abs() < on.z);
+}
+
+#[test]
+fn close_neighbours_are_avoided_and_followed() {
+    let cfg = BirdConfig { homing: 0.0, cohesion: 0.0, ..BirdConfig::default() };
+    let me = Boid { pos: Vec3::ZERO, vel: Vec3::X };
+    let crowd = [Boid { pos: Vec3::new(0.5, 0.0, 0.0), vel: Vec3::X }];
+    assert!(steer(me, &crowd, Vec3::ZERO, 30.0, &cfg).x < 0.0, "moves away");
+
+    let cfg = BirdConfig { homing: 0.0, cohesion: 0.0, separation: 0.0, ..BirdConfig::default() };
+    let flock = [Boid { pos: Vec3::new(3.0, 0.0, 0.0), vel: Vec3::Z }, Boid { pos: Vec3::new(-3.0, 0.0, 0.0), vel: Vec3::Z }];
+    let acc = steer(me, &flock, Vec3::ZERO, 30.0, &cfg);
+    assert!(acc.z > 0.0 && acc.x < 0.0, "turns to the flock's heading");
+}
+
+#[test]
+fn far_birds_are_not_neighbours() {
+    let cfg = BirdConfig { homing: 0.0, ..BirdConfig::default() };
+    let me = Boid { pos: Vec3::ZERO, vel: Vec3::X };
+    let far = [Boid { pos: Vec3::new(cfg.neighbour_radius * 2.0, 0.0, 0.0), vel: Vec3::NEG_X }];
+    assert_eq!(steer(me, &far, Vec3::ZERO, 30.0, &cfg), Vec3::ZERO);
+    assert!(cfg.min_density_scale > 0.0 && cfg.min_density_scale < cfg.density_scale);
+}
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::camera_cinematic::{catmull_rom, intro_path, CinematicConfig};
+
+fn close(a: Vec3, b: Vec3) -> bool {
+    a.distance(b) < 1e-3
+}
+
+#[test]
+fn spline_passes_through_its_points_smoothly() {
+    let pts = [Vec3::ZERO, Vec3::new(10.0, 5.0, 0.0), Vec3::new(20.0, 0.0, 10.0), Vec3::new(30.0, 2.0, 10.0)];
+    assert!(close(catmull_rom(&pts, 0.0), pts[0]));
+    assert!(close(catmull_rom(&pts, 1.0 / 3.0), pts[1]));
+    assert!(close(catmull_rom(&pts, 2.0 / 3.0), pts[2]));
+    assert!(close(catmull_rom(&pts, 1.0), pts[3]));
+    // Out of range t clamps; no jumps between neighbouring samples.
+    assert!(close(catmull_rom(&pts, 1.5), pts[3]));
+    for i in 0..100 {
+        let (a, b) = (catmull_rom(&pts, i as f32 / 100.0), catmull_rom(&pts, (i + 1) as f32 / 100.0));
+        assert!(a.distance(b) < 1.5, "step {i}: {a} -> {b}");
+    }
+    assert_eq!(catmull_rom(&[Vec3::ONE], 0.7), Vec3::ONE);
+}
+
+#[test]
+fn intro_flies_from_behind_the_target_to_the_orbit_camera() {
+    let cfg = CinematicConfig::default();
+    let (target, ball, camera) = (Vec3::new(400.0, 90.0, 0.0), Vec3::new(0.0, 80.0, 0.0), Vec3::new(-20.0, 90.0, 0.0));
+    let path = intro_path(&cfg, target, ball, camera);
+    let start = path[0];
+    assert!(start.x > target.x, "starts on the far side of the target");
+    assert!(start.y > target.y);
+    assert!(close(*path.last().unwrap(), camera), "ends where the orbit camera is");
+    // The middle arcs up over the course.
+    let mid = catmull_rom(&path, 0.5);
+    assert!(mid.y > start.y.max(camera.y) + 50.0, "{mid}");
+}
@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::camera_obstruction::{ray_cylinder, smooth_cap, spherecast_terrain, CameraObstructionConfig};
+use vibe_golf::plugins::terrain::TerrainSampler;
+
+mod common;
+
+const RES: u32 = 128;
+const WORLD: f32 = 2000.0;
+
+// Flat ground at 80 m with a 120 m high, flat-topped hill across |x| < 100.
+fn hill() -> TerrainSampler {
+    common::world_sampler(WORLD, RES, |i, _| {
+        let x = (i as f32 / (RES - 1) as f32 - 0.5) * WORLD;
+        if x.abs() < 100.0 { 120 } else { 80 }
+    })
+}
+
+#[test]
+fn spherecast_catches_hills_a_thin_ray_grazes_over() {
+    let sampler = hill();
+    let origin = Vec3::new(-400.0, 120.4, 0.0);
+    // The centre ray clears the hilltop; the sphere's lower edge does not.
+    assert!(sampler.raycast_max(origin, Vec3::X, 600.0).is_none());
+    let d = spherecast_terrain(&sampler, origin, Vec3::X, 600.0, 0.6).expect("hill blocks the sphere");
+    assert!((250.0..=320.0).contains(&d), "hill edge is ~300 m away: {d}");
+    // Looking away from the hill nothing is hit.
+    assert!(spherecast_terrain(&sampler, origin, Vec3::NEG_X, 300.0, 0.6).is_none());
+}
+
+#[test]
+fn tree_cylinders_and_radius_smoothing() {
+    let base = Vec3::new(10.0, 0.0, 0.0);
+    let t = ray_cylinder(Vec3::new(0.0, 2.0, 0.0), Vec3::X, 50.0, base, 1.0, 9.0).expect("trunk in the way");
+    assert!((t - 9.0).abs() < 1e-3);
+    // Over the canopy, beside it, or beyond the camera: no hit.
+    assert!(ray_cylinder(Vec3::new(0.0, 12.0, 0.0), Vec3::X, 50.0, base, 1.0, 9.0).is_none());
+    assert!(ray_cylinder(Vec3::new(0.0, 2.0, 3.0), Vec3::X, 50.0, base, 1.0, 9.0).is_none());
+    assert!(ray_cylinder(Vec3::new(0.0, 2.0, 0.0), Vec3::X, 5.0, base, 1.0, 9.0).is_none());
+
+    // Pull-in is quick, easing back out is slow, and the cap never exceeds the orbit radius.
+    let cfg = CameraObstructionConfig::default();
+    let pulled = smooth_cap(30.0, 10.0, 30.0, &cfg, 0.1);
+    let released = smooth_cap(10.0, f32::INFINITY, 30.0, &cfg, 0.1);
+    assert!(30.0 - pulled > released - 10.0);
+    assert!(released > 10.0 && released < 30.0);
+    assert!(smooth_cap(f32::INFINITY, f32::INFINITY, 30.0, &cfg, 0.1) <= 30.0);
+}
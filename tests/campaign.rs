@@ -0,0 +1,61 @@
+use vibe_golf::plugins::campaign::{CampaignState, Medal, MedalTimes};
+use vibe_golf::plugins::level::LevelManifest;
+
+const MEDALS: MedalTimes = MedalTimes { gold: 100.0, silver: 200.0, bronze: 300.0 };
+
+fn manifest() -> LevelManifest {
+    LevelManifest::from_ron(
+        r#"(levels: [
+            (id: "a", name: "A", file: "levels/a.ron", heightmap: "h.png"),
+            (id: "b", name: "B", file: "levels/b.ron", heightmap: "h.png"),
+            (id: "c", name: "C", file: "levels/c.ron", heightmap: "h.png", requires: Some("a")),
+        ])"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn medal_thresholds_are_inclusive() {
+    assert_eq!(MEDALS.medal_for(100.0), Some(Medal::Gold));
+    assert_eq!(MEDALS.medal_for(150.0), Some(Medal::Silver));
+    assert_eq!(MEDALS.medal_for(300.0), Some(Medal::Bronze));
+    assert_eq!(MEDALS.medal_for(300.5), None);
+}
+
+#[test]
+fn only_better_medals_and_times_are_kept() {
+    let mut campaign = CampaignState::default();
+    let first = campaign.record_finish("a", 150.0, Some(&MEDALS));
+    assert!(first.first_completion);
+    assert_eq!(first.new_medal, Some(Medal::Silver));
+
+    let slower = campaign.record_finish("a", 250.0, Some(&MEDALS));
+    assert!(!slower.first_completion);
+    assert_eq!(slower.new_medal, None);
+    let progress = campaign.progress("a").unwrap();
+    assert_eq!(progress.medal, Some(Medal::Silver));
+    assert_eq!(progress.best_time, Some(150.0));
+
+    assert_eq!(campaign.record_finish("a", 90.0, Some(&MEDALS)).new_medal, Some(Medal::Gold));
+}
+
+#[test]
+fn levels_unlock_after_their_requirement() {
+    let manifest = manifest();
+    let mut campaign = CampaignState::default();
+    assert!(campaign.is_unlocked(&manifest, 0));
+    assert!(!campaign.is_unlocked(&manifest, 1));
+    assert!(!campaign.is_unlocked(&manifest, 2));
+
+    campaign.record_finish("a", 500.0, None);
+    assert!(campaign.is_unlocked(&manifest, 1));
+    assert!(campaign.is_unlocked(&manifest, 2), "c requires a, not b");
+}
+
+#[test]
+fn campaign_round_trips_through_ron() {
+    let mut campaign = CampaignState::default();
+    campaign.record_finish("a", 120.0, Some(&MEDALS));
+    assert_eq!(CampaignState::from_ron(&campaign.to_ron()).unwrap(), campaign);
+    assert_eq!(CampaignState::from_ron("()").unwrap(), CampaignState::default());
+}
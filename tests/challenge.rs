@@ -0,0 +1,36 @@
+// Challenge codes: the text format, which rounds a challenge applies to and the head-to-head line.
+use vibe_golf::plugins::challenge::{compare_records, ActiveChallenge, ChallengeCode};
+use vibe_golf::plugins::game_mode::GameMode;
+
+#[test]
+fn codes_round_trip_and_reject_garbage() {
+    let code = ChallengeCode { level: "canyon-run".into(), seed: 0x9F3A_21C4, mode: GameMode::StrokePlay, record: Some(7.0) };
+    assert_eq!(code.encode(), "VG1-9F3A21C4-S-700-canyon-run");
+    assert_eq!(ChallengeCode::parse(&code.encode()), Some(code.clone()));
+    // Typed in lower case with stray spaces; no record yet.
+    let fresh = ChallengeCode::parse("  vg1-9f3a21c4-t-0-level1 ").unwrap();
+    assert_eq!((fresh.seed, fresh.mode, fresh.record, fresh.level.as_str()), (0x9F3A_21C4, GameMode::TimeAttack, None, "level1"));
+    for bad in ["", "VG2-1-T-0-level1", "VG1-XYZ-T-0-level1", "VG1-1-Q-0-level1", "VG1-1-T-0-", "VG1-1-T-level1"] {
+        assert_eq!(ChallengeCode::parse(bad), None, "{bad}");
+    }
+}
+
+#[test]
+fn challenge_applies_to_its_level_and_rounds() {
+    let code = ChallengeCode::new("level1", GameMode::TimeAttack);
+    let challenge = ActiveChallenge(Some(code.clone()));
+    assert_eq!(challenge.course_seed("level1"), Some(u64::from(code.seed)));
+    assert_eq!(challenge.course_seed("canyon"), None);
+    assert_eq!(challenge.round_seed(&code.score_id()), Some(u64::from(code.seed)));
+    assert_eq!(challenge.round_seed("level1"), None);
+    assert_eq!(code.with_record(Some(48.5)).encode(), format!("VG1-{:08X}-T-4850-level1", code.seed));
+}
+
+#[test]
+fn head_to_head_lines() {
+    let secs = |v: f32| format!("{v:.2}s");
+    assert_eq!(compare_records(Some(48.0), Some(50.5), secs), "you 48.00s vs 50.50s - you win by 2.50s");
+    assert_eq!(compare_records(Some(52.0), Some(50.0), secs), "you 52.00s vs 50.00s - they win by 2.00s");
+    assert_eq!(compare_records(None, Some(50.0), secs), "50.00s to beat");
+    assert_eq!(compare_records(None, None, secs), "no result yet");
+}
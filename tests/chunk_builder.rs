@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::terrain::{ChunkBuilder, TerrainConfig, TerrainSampler};
+
+mod common;
+
+const RES: u32 = 65;
+
+fn fixture() -> TerrainSampler {
+    common::heightmap_sampler(RES, |x, z| ((x * 3 + z * 2) % 256) as u8)
+}
+
+#[test]
+fn incremental_build_matches_one_shot() {
+    let s = fixture();
+    let coord = IVec2::new(-1, 0);
+    let one_shot = ChunkBuilder::new(coord, 8, 20.0, true).build(&s);
+
+    let mut inc = ChunkBuilder::new(coord, 8, 20.0, true);
+    let mut steps = 0;
+    while !inc.step_rows(&s, 1) {
+        steps += 1;
+    }
+    // heights (9 rows) + vertices (9 rows) + indices (8 rows), last step returns true.
+    assert_eq!(steps + 1, 9 + 9 + 8);
+    let inc = inc.finish();
+
+    assert_eq!(one_shot.heights, inc.heights);
+    assert_eq!(common::positions(&one_shot.mesh), common::positions(&inc.mesh));
+    assert_eq!(one_shot.mesh.indices().unwrap().len(), 8 * 8 * 6);
+    assert_eq!((one_shot.min_h, one_shot.max_h), (inc.min_h, inc.max_h));
+}
+
+#[test]
+#[should_panic]
+fn finish_before_done_panics() {
+    let s = fixture();
+    let mut b = ChunkBuilder::new(IVec2::ZERO, 4, 10.0, false);
+    b.step_rows(&s, 2);
+    let _ = b.finish();
+}
+
+#[test]
+fn presets_round_trip_and_high_matches_default() {
+    use vibe_golf::plugins::terrain_presets::TerrainPreset;
+    for p in TerrainPreset::ALL {
+        assert_eq!(TerrainPreset::from_name(p.name()), Some(p));
+        let mut cfg = TerrainConfig::default();
+        p.apply(&mut cfg);
+        // Every LOD resolution must be a valid chunk grid and ordered coarse <= fine.
+        assert!(cfg.lod_far_resolution <= cfg.lod_mid_resolution && cfg.lod_mid_resolution <= cfg.resolution);
+        assert!(cfg.lod_mid_distance < cfg.lod_far_distance);
+    }
+    let mut cfg = TerrainConfig::default();
+    TerrainPreset::High.apply(&mut cfg);
+    let d = TerrainConfig::default();
+    assert_eq!((cfg.resolution, cfg.view_radius_chunks, cfg.collider_resolution), (d.resolution, d.view_radius_chunks, d.collider_resolution));
+    assert_eq!(TerrainPreset::Ultra.next(), TerrainPreset::Low);
+}
+
+#[test]
+fn only_preset_knobs_break_a_preset() {
+    use vibe_golf::plugins::terrain_presets::TerrainPreset;
+    let mut cfg = TerrainConfig::default();
+    TerrainPreset::Medium.apply(&mut cfg);
+    assert!(TerrainPreset::Medium.matches(&cfg));
+    assert!(!TerrainPreset::Low.matches(&cfg));
+    cfg.amplitude += 1.0;
+    assert!(TerrainPreset::Medium.matches(&cfg), "amplitude is not part of a preset");
+    cfg.view_radius_chunks += 1;
+    assert!(!TerrainPreset::Medium.matches(&cfg));
+}
@@ -0,0 +1,47 @@
+use vibe_golf::plugins::game_state::{Score, COMBO_TIMEOUT, MAX_COMBO_MULTIPLIER};
+
+fn score() -> Score {
+    Score { high_score_time: None, ..Default::default() }
+}
+
+#[test]
+fn single_stroke_hits_chain_until_a_miss() {
+    let mut s = score();
+    s.shots = 1;
+    assert_eq!(s.register_combo_hit(), 1, "first hole-in-one starts the chain");
+    s.shots = 2;
+    assert_eq!(s.register_combo_hit(), 2);
+    s.shots = 3;
+    assert_eq!(s.register_combo_hit(), 3);
+    assert_eq!((s.combo, s.best_combo), (3, 3));
+
+    // Two strokes for the next target: chain broken, no multiplier.
+    s.shots = 5;
+    assert_eq!(s.register_combo_hit(), 1);
+    assert_eq!(s.combo, 0);
+    assert_eq!(s.best_combo, 3);
+
+    for _ in 0..10 {
+        s.shots += 1;
+        s.register_combo_hit();
+    }
+    assert_eq!(s.combo_multiplier(), MAX_COMBO_MULTIPLIER);
+}
+
+#[test]
+fn combo_expires_after_the_timeout() {
+    let mut s = score();
+    s.shots = 1;
+    s.register_combo_hit();
+    s.shots = 2;
+    s.register_combo_hit();
+    s.tick_combo(COMBO_TIMEOUT - 1.0);
+    assert_eq!(s.combo, 2);
+    s.tick_combo(2.0);
+    assert_eq!((s.combo, s.combo_timer), (0, 0.0));
+
+    // Next single-stroke hit starts over.
+    s.shots = 3;
+    assert_eq!(s.register_combo_hit(), 1);
+    assert_eq!(s.combo, 1);
+}
@@ -0,0 +1,61 @@
+// Shared in-memory terrain fixtures and mesh helpers for the integration tests (`mod common;`).
+// Each test binary uses only some of them.
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use bevy::render::mesh::VertexAttributeValues;
+use vibe_golf::plugins::terrain::{TerrainConfig, TerrainSampler};
+
+fn sampler(cfg: TerrainConfig, size: u32, f: impl Fn(u32, u32) -> u8) -> TerrainSampler {
+    let mut red = Vec::with_capacity((size * size) as usize);
+    for z in 0..size {
+        for x in 0..size {
+            red.push(f(x, z));
+        }
+    }
+    TerrainSampler::from_heightmap_data(cfg, size, size, red)
+}
+
+/// `world` m square world, `size` x `size` pixels; the red value `f(x, z)` maps 1:1 to meters.
+pub fn world_sampler(world: f32, size: u32, f: impl Fn(u32, u32) -> u8) -> TerrainSampler {
+    let cfg = TerrainConfig {
+        heightmap_world_size: world,
+        heightmap_max_height: 255.0,
+        ..Default::default()
+    };
+    sampler(cfg, size, f)
+}
+
+/// 100 m square world, `size` x `size` pixels; the red value `f(x, z)` maps 1:1 to meters.
+pub fn heightmap_sampler(size: u32, f: impl Fn(u32, u32) -> u8) -> TerrainSampler {
+    world_sampler(100.0, size, f)
+}
+
+/// 2 km square of rolling meadow hills around 70 m (above the water line, inside the tree
+/// altitude band), 64 x 64 pixels. `hills` scales their height and steepness (1 = gentle);
+/// `bump` raises the corner pixel to make an otherwise identical heightmap.
+pub fn meadow_sampler(hills: f32, bump: u8) -> TerrainSampler {
+    let cfg = TerrainConfig {
+        heightmap_world_size: 2000.0,
+        heightmap_max_height: 200.0,
+        ..Default::default()
+    };
+    sampler(cfg, 64, |x, z| {
+        let h = 90.0 + 8.0 * hills * ((x as f32 * 0.3 * hills).sin() + (z as f32 * 0.2 * hills).cos());
+        let h = h as u8;
+        if x == 0 && z == 0 { h.wrapping_add(bump) } else { h }
+    })
+}
+
+/// 2 km square: the west half is sea (0 m), the east half land at 100 m.
+pub fn half_sea() -> TerrainSampler {
+    sampler(TerrainConfig::default(), 64, |x, _| if x < 32 { 0 } else { 128 })
+}
+
+/// Vertex positions of a terrain mesh.
+pub fn positions(mesh: &Mesh) -> Vec<[f32; 3]> {
+    match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(v)) => v.clone(),
+        _ => panic!("missing positions"),
+    }
+}
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::hud::compass_marker_offset;
+
+#[test]
+fn markers_inside_range_sit_inside_the_ring() {
+    // yaw 0: camera looks along -Z, so -Z is "up" on the compass and +X is to the right.
+    let ball = Vec3::new(10.0, 5.0, 10.0);
+    let ahead = compass_marker_offset(0.0, ball, ball + Vec3::new(0.0, 3.0, -50.0), 70.0, 100.0).unwrap();
+    assert!(ahead.x.abs() < 1e-4 && (ahead.y - 35.0).abs() < 1e-3);
+    let right = compass_marker_offset(0.0, ball, ball + Vec3::new(25.0, 0.0, 0.0), 70.0, 100.0).unwrap();
+    assert!((right.x - 17.5).abs() < 1e-3 && right.y.abs() < 1e-3);
+    assert!(compass_marker_offset(0.0, ball, ball + Vec3::Y * 4.0, 70.0, 100.0).is_none());
+}
+
+#[test]
+fn far_markers_clamp_to_the_ring_edge() {
+    let ball = Vec3::ZERO;
+    let behind = compass_marker_offset(0.0, ball, Vec3::new(0.0, 0.0, 900.0), 70.0, 100.0).unwrap();
+    assert!((behind.length() - 70.0).abs() < 1e-3 && behind.y < 0.0);
+    // Range 0 always pins to the edge (used for the focused target).
+    let near = compass_marker_offset(0.0, ball, Vec3::new(-1.0, 0.0, 0.0), 70.0, 0.0).unwrap();
+    assert!((near.length() - 70.0).abs() < 1e-3 && near.x < 0.0);
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::cup_target::{CupConfig, TargetStyle};
+use vibe_golf::plugins::terrain::{TerrainSampler, TerrainStamp};
+use vibe_golf::plugins::terrain_deform::{chunk_overlaps, TerrainDeformations};
+
+mod common;
+
+const RES: u32 = 65;
+
+// Ground rising 1 m per heightmap pixel along +x.
+fn slope() -> TerrainSampler {
+    common::heightmap_sampler(RES, |x, _| x as u8 + 40)
+}
+
+#[test]
+fn green_stamp_flattens_and_blends_back() {
+    let mut sampler = slope();
+    let center = Vec2::new(0.0, 0.0);
+    let before = |x: f32| slope().height(x, 0.0);
+    let level = before(0.0);
+    let stamp = CupConfig::default().green_stamp(center, level);
+    sampler.set_stamps(vec![stamp]);
+
+    for x in [-8.0, -3.0, 0.0, 5.0, 8.9] {
+        assert!((sampler.height(x, 0.0) - level).abs() < 1e-3, "flat green at x={x}");
+    }
+    let mid = stamp.radius + stamp.falloff * 0.5;
+    let h = sampler.height(mid, 0.0);
+    assert!(h > level && h < before(mid), "blend between green and slope");
+    let outside = stamp.reach() + 1.0;
+    assert_eq!(sampler.height(outside, 0.0), before(outside));
+
+    let mut deform = TerrainDeformations::default();
+    let id = deform.add(stamp);
+    let moved = TerrainStamp { center: Vec2::new(20.0, 0.0), ..stamp };
+    assert_eq!(deform.replace(id, moved), id);
+    assert_eq!(deform.stamps().copied().collect::<Vec<_>>(), vec![moved]);
+    assert_eq!(deform.remove(id), Some(moved));
+    assert!(deform.remove(id).is_none());
+
+    assert!(chunk_overlaps(IVec2::new(0, 0), 32.0, Vec2::new(-5.0, 10.0), 6.0));
+    assert!(!chunk_overlaps(IVec2::new(2, 0), 32.0, Vec2::new(-5.0, 10.0), 6.0));
+}
+
+#[test]
+fn cup_captures_only_a_slow_ball_in_the_hole() {
+    let cfg = CupConfig::default();
+    let cup = Vec2::new(10.0, 10.0);
+    let ground = 50.0;
+    assert!(cfg.captures(Vec3::new(10.5, 50.3, 10.2), 1.0, cup, ground));
+    assert!(!cfg.captures(Vec3::new(10.5, 50.3, 10.2), 8.0, cup, ground), "rolling too fast");
+    assert!(!cfg.captures(Vec3::new(10.5, 55.0, 10.2), 1.0, cup, ground), "flying over");
+    assert!(!cfg.captures(Vec3::new(13.0, 50.3, 10.0), 0.5, cup, ground), "on the green, not in the cup");
+
+    let styles: Vec<TargetStyle> = ron::from_str("[Floating, Cup]").unwrap();
+    assert_eq!(styles, vec![TargetStyle::Floating, TargetStyle::Cup]);
+    assert_eq!(TargetStyle::default(), TargetStyle::Floating);
+}
@@ -0,0 +1,48 @@
+use vibe_golf::plugins::daily::{generate_daily_course, DailyDate};
+use vibe_golf::plugins::level::LevelDef;
+use vibe_golf::plugins::level_validation::{validate_level, IssueSeverity};
+
+mod common;
+
+fn level1() -> LevelDef {
+    ron::from_str(&std::fs::read_to_string("assets/levels/level1.ron").unwrap()).unwrap()
+}
+
+#[test]
+fn unix_days_map_to_calendar_dates() {
+    assert_eq!(DailyDate::from_unix_days(0).to_string(), "1970-01-01");
+    assert_eq!(DailyDate::from_unix_days(11_017).to_string(), "2000-03-01");
+    let leap = DailyDate::from_unix_days(19_782);
+    assert_eq!((leap.year, leap.month, leap.day), (2024, 2, 29));
+    assert_eq!(leap.key(), "daily-2024-02-29");
+}
+
+#[test]
+fn seeds_are_stable_and_differ_per_day() {
+    let day = DailyDate { year: 2024, month: 2, day: 29 };
+    assert_eq!(day.seed(), DailyDate::from_unix_days(19_782).seed());
+    assert_ne!(day.seed(), DailyDate::from_unix_days(19_783).seed());
+}
+
+#[test]
+fn same_seed_same_playable_course() {
+    let sampler = common::half_sea();
+    let seed = DailyDate::from_unix_days(19_782).seed();
+    let (mut a, mut b) = (level1(), level1());
+    generate_daily_course(&mut a, seed, &sampler);
+    generate_daily_course(&mut b, seed, &sampler);
+
+    assert!(a.is_course());
+    assert_eq!(a.scoring.max_holes, a.holes.len() as u32);
+    for (ha, hb) in a.holes.iter().zip(&b.holes) {
+        assert_eq!((ha.tee.x, ha.tee.z, ha.target.x, ha.target.z, ha.par), (hb.tee.x, hb.tee.z, hb.target.x, hb.target.z, hb.par));
+        assert!(ha.tee.x > 0.0 && ha.target.x > 0.0, "holes stay on the dry east half");
+    }
+    assert_eq!(a.scripts.len(), b.scripts.len());
+    let errors: Vec<_> = validate_level(&a, &sampler).into_iter().filter(|i| i.severity == IssueSeverity::Error).collect();
+    assert!(errors.is_empty(), "{errors:?}");
+
+    let mut other = level1();
+    generate_daily_course(&mut other, DailyDate::from_unix_days(19_783).seed(), &sampler);
+    assert_ne!(other.holes[0].tee.x, a.holes[0].tee.x);
+}
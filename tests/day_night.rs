@@ -0,0 +1,39 @@
+// Day / night cycle: the sun's path, the lighting it gives and the level clock.
+use bevy::math::Vec3;
+use vibe_golf::plugins::day_night::{advance_hour, daylight, sun_direction, DayNightConfig, DayNightDef};
+use vibe_golf::plugins::level::LevelDef;
+
+#[test]
+fn sun_rises_east_peaks_at_noon_and_sets_west() {
+    assert!(sun_direction(6.0).y.abs() < 1e-5 && sun_direction(6.0).x > 0.9);
+    assert!(sun_direction(18.0).y.abs() < 1e-5 && sun_direction(18.0).x < -0.9);
+    assert!(sun_direction(12.0).y > 0.9);
+    assert!(sun_direction(0.0).y < -0.9);
+    assert_eq!((daylight(12.0), daylight(0.0), daylight(5.0)), (1.0, 0.0, 0.0));
+    assert!(daylight(7.0) > 0.0 && daylight(7.0) < 1.0);
+}
+
+#[test]
+fn night_is_moonlit_and_tinted() {
+    let cfg = DayNightConfig::default();
+    let noon = cfg.lighting(12.0);
+    assert_eq!(noon.illuminance, cfg.sun_lux);
+    assert!(noon.tint.abs_diff_eq(Vec3::ONE, 1e-6));
+    let midnight = cfg.lighting(0.0);
+    assert_eq!((midnight.illuminance, midnight.tint, midnight.light_color), (cfg.moon_lux, cfg.night_tint, cfg.sun_colors.2));
+    // The moon lights the course from above, never from under the ground.
+    assert!(midnight.light_dir.y > 0.9);
+    assert!(cfg.lighting(18.0).tint.x > cfg.lighting(18.0).tint.z, "sunset is warm");
+}
+
+#[test]
+fn clock_wraps_or_stands_still_and_levels_opt_in() {
+    assert!((advance_hour(23.0, 50.0, 600.0) - 1.0).abs() < 1e-4);
+    assert_eq!(advance_hour(19.5, 50.0, 0.0), 19.5);
+
+    let course = ron::from_str::<LevelDef>(&std::fs::read_to_string("assets/levels/course1.ron").unwrap()).unwrap();
+    assert_eq!(course.day_night, Some(DayNightDef { start_hour: 17.0, day_length: 600.0 }));
+    let level1 = ron::from_str::<LevelDef>(&std::fs::read_to_string("assets/levels/level1.ron").unwrap()).unwrap();
+    assert_eq!(level1.day_night, None);
+    assert_eq!(ron::from_str::<DayNightDef>("(start_hour: 21.0)").unwrap().day_length, 0.0);
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::debug_gizmos::{chunk_border_points, DebugGizmoLayers, DebugGizmos};
+
+#[test]
+fn layers_toggle_independently() {
+    let mut layers = DebugGizmoLayers::all();
+    assert!(DebugGizmoLayers::ALL.iter().all(|l| layers.contains(*l)));
+    layers.toggle(DebugGizmoLayers::CHUNK_BORDERS);
+    assert!(!layers.contains(DebugGizmoLayers::CHUNK_BORDERS));
+    assert!(layers.contains(DebugGizmoLayers::BALL_VELOCITY) && layers.contains(DebugGizmoLayers::CAMERA_SPRINGS));
+    layers.toggle(DebugGizmoLayers::CHUNK_BORDERS);
+    assert_eq!(layers, DebugGizmoLayers::all());
+    assert!(!DebugGizmoLayers::empty().contains(DebugGizmoLayers::TERRAIN_NORMAL));
+}
+
+#[test]
+fn nothing_shows_until_enabled() {
+    let mut gizmos = DebugGizmos::default();
+    assert!(!gizmos.shows(DebugGizmoLayers::TARGET_COLLIDERS));
+    gizmos.enabled = true;
+    assert!(gizmos.shows(DebugGizmoLayers::TARGET_COLLIDERS));
+    gizmos.layers = DebugGizmoLayers::empty();
+    assert!(!gizmos.shows(DebugGizmoLayers::TARGET_COLLIDERS));
+}
+
+#[test]
+fn chunk_border_is_a_closed_loop_on_the_ground() {
+    let points = chunk_border_points(IVec2::new(1, -1), 160.0, 4, |x, z| x * 0.01 + z * 0.02);
+    assert_eq!(points.len(), 17);
+    assert_eq!(points.first(), points.last());
+    assert_eq!(points[0].xz(), Vec2::new(160.0, -160.0));
+    assert_eq!(points[4].xz(), Vec2::new(320.0, -160.0));
+    assert_eq!(points[8].xz(), Vec2::new(320.0, 0.0));
+    assert!(points.iter().all(|p| (p.y - (p.x * 0.01 + p.z * 0.02)).abs() < 1e-4));
+}
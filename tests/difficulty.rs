@@ -0,0 +1,31 @@
+use vibe_golf::plugins::difficulty::DifficultyCurve;
+
+#[test]
+fn default_curve_is_flat() {
+    let curve = DifficultyCurve::default();
+    for hits in [0, 3, 50] {
+        let d = curve.at(hits);
+        assert_eq!(d.collider_scale, 1.0);
+        assert_eq!(d.distance, (500.0, 800.0));
+        assert_eq!(d.motion_speed, 1.0);
+    }
+}
+
+#[test]
+fn curve_ramps_to_its_end_values_and_holds() {
+    let curve: DifficultyCurve =
+        ron::from_str("(ramp_hits: 4, collider_scale_end: 0.6, distance_end: (700.0, 1100.0), motion_speed_end: 2.0)")
+            .unwrap();
+    assert_eq!(curve.exponent, 1.0, "omitted fields keep their defaults");
+
+    let mid = curve.at(2);
+    assert!((mid.collider_scale - 0.8).abs() < 1e-5);
+    assert!((mid.distance.0 - 600.0).abs() < 1e-3 && (mid.distance.1 - 950.0).abs() < 1e-3);
+    assert!((mid.motion_speed - 1.5).abs() < 1e-5);
+
+    assert_eq!(curve.at(4), curve.at(40));
+    assert!((curve.at(9).collider_scale - 0.6).abs() < 1e-5);
+
+    let eased = DifficultyCurve { exponent: 2.0, ..curve.clone() };
+    assert!(eased.at(2).collider_scale > mid.collider_scale, "exponent > 1 starts gentler");
+}
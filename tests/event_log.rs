@@ -0,0 +1,23 @@
+use vibe_golf::plugins::event_log::{format_timestamp, EventLog, LogKind};
+
+#[test]
+fn log_is_bounded_and_stamped() {
+    let mut log = EventLog::default();
+    log.capacity = 3;
+    for i in 0..5 {
+        log.now = i as f32;
+        log.push(LogKind::Shot, format!("shot {i}"));
+    }
+    assert_eq!(log.len(), 3);
+    let recent: Vec<_> = log.recent(2).collect();
+    assert_eq!(recent.len(), 2);
+    assert_eq!(recent[0].text, "shot 3");
+    assert_eq!(recent[1].time, 4.0);
+}
+
+#[test]
+fn timestamps_are_minutes_and_seconds() {
+    assert_eq!(format_timestamp(0.0), "00:00.0");
+    assert_eq!(format_timestamp(83.44), "01:23.4");
+    assert_eq!(format_timestamp(-1.0), "00:00.0");
+}
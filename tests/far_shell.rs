@@ -0,0 +1,57 @@
+// Far terrain shell: grid + skirt layout, the cut-out under loaded chunks, and sunk heights.
+use bevy::prelude::*;
+use vibe_golf::plugins::terrain::{build_far_shell_mesh, far_shell_indices, TerrainConfig};
+
+mod common;
+
+const RES: u32 = 65;
+
+#[test]
+fn shell_has_grid_and_skirt_vertices() {
+    let s = common::heightmap_sampler(RES, |_, _| 50);
+    let mesh = build_far_shell_mesh(&s, 10, 1.5);
+    let pos = common::positions(&mesh);
+    assert_eq!(pos.len(), 11 * 11 + 4 * 10);
+    // Nothing loaded: 100 grid quads plus 40 skirt quads, two triangles each.
+    assert_eq!(mesh.indices().unwrap().len(), (100 + 40) * 6);
+    // Skirt bases sit at y = 0 under the perimeter.
+    assert!(pos[121..].iter().all(|p| p[1] == 0.0));
+    assert_eq!((pos[121][0], pos[121][2]), (pos[0][0], pos[0][2]));
+}
+
+#[test]
+fn loaded_chunks_leave_a_hole() {
+    // 100 m world, 10 m quads; default 160 m chunks meet at the origin, so chunk (0, 0) covers
+    // the +x / +z quarter.
+    let chunk = TerrainConfig::default().chunk_size;
+    let all = far_shell_indices(10, 100.0, chunk, |_| false);
+    let holed = far_shell_indices(10, 100.0, chunk, |c| c == IVec2::ZERO);
+    assert_eq!(all.len() - holed.len(), 25 * 6);
+    let none = far_shell_indices(10, 100.0, chunk, |_| true);
+    assert_eq!(none.len(), 40 * 6, "only the skirt is left");
+
+    // No remaining grid triangle touches the interior of the covered quarter (vertex 6/6 is
+    // at (10, 10) m and only belongs to covered quads).
+    let row = 11;
+    assert!(!holed[..holed.len() - 40 * 6].contains(&(6 * row + 6)));
+    assert!(all.contains(&(6 * row + 6)));
+}
+
+#[test]
+fn shell_heights_stay_under_the_sampler() {
+    let s = common::heightmap_sampler(RES, |x, z| (x + z) as u8);
+    let drop = 1.5;
+    let mesh = build_far_shell_mesh(&s, 16, drop);
+    // Interior points: the border ones also sample beyond the heightmap edge.
+    let interior = |mesh: &Mesh| {
+        let pos = common::positions(mesh);
+        (1..16).flat_map(|j| (1..16).map(move |i| j * 17 + i)).map(|k| pos[k]).collect::<Vec<_>>()
+    };
+    for p in interior(&mesh) {
+        let h = s.height(p[0], p[2]);
+        assert!(p[1] <= h - drop + 1e-3, "shell at {p:?} above terrain {h}");
+        assert!(p[1] >= h - drop - 10.0, "shell at {p:?} far below terrain {h}");
+    }
+    let flat = build_far_shell_mesh(&common::heightmap_sampler(RES, |_, _| 50), 16, drop);
+    assert!(interior(&flat).iter().all(|p| (p[1] - (50.0 - drop)).abs() < 1e-3));
+}
@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::camera::{fly_direction, CameraMode};
+
+fn close(a: Vec3, b: Vec3) -> bool {
+    a.distance(b) < 1e-4
+}
+
+#[test]
+fn fly_input_follows_the_view() {
+    assert_eq!(CameraMode::default(), CameraMode::Orbit);
+    // Unrotated camera looks down -Z.
+    assert!(close(fly_direction(0.0, 0.0, Vec3::Z), Vec3::NEG_Z));
+    assert!(close(fly_direction(0.0, 0.0, Vec3::X), Vec3::X));
+    assert!(close(fly_direction(0.0, 0.0, Vec3::Y), Vec3::Y));
+    // Turned 90 degrees left: forward is -X.
+    assert!(close(fly_direction(std::f32::consts::FRAC_PI_2, 0.0, Vec3::Z), Vec3::NEG_X));
+    // Looking down: forward dives, up stays world up.
+    let dive = fly_direction(0.0, -std::f32::consts::FRAC_PI_4, Vec3::Z);
+    assert!(dive.y < -0.7 && dive.z < -0.7);
+    assert!(close(fly_direction(1.0, -0.5, Vec3::Y), Vec3::Y));
+}
+
+#[test]
+fn diagonal_input_is_normalized() {
+    let d = fly_direction(0.3, 0.2, Vec3::new(1.0, 1.0, 1.0));
+    assert!((d.length() - 1.0).abs() < 1e-4);
+    assert_eq!(fly_direction(0.3, 0.2, Vec3::ZERO), Vec3::ZERO);
+}
+
+#[test]
+fn align_yaw_faces_the_target() {
+    use vibe_golf::plugins::camera::{orbit_direction, yaw_behind, yaw_delta};
+    let ball = Vec3::new(10.0, 5.0, -3.0);
+    for target in [Vec3::new(60.0, 0.0, -3.0), Vec3::new(10.0, 9.0, 80.0), Vec3::new(-40.0, 2.0, -50.0)] {
+        let yaw = yaw_behind(ball, target).unwrap();
+        // The camera sits on the far side of the ball from the target.
+        let offset = orbit_direction(yaw, 0.0);
+        let to_target = (target - ball).with_y(0.0).normalize();
+        assert!(close(offset, -to_target), "{target}: {offset} vs {to_target}");
+    }
+    assert!(yaw_behind(ball, ball + Vec3::Y * 3.0).is_none());
+    // Shortest way round across the +-PI seam.
+    assert!((yaw_delta(3.0, -3.0) - (std::f32::consts::TAU - 6.0)).abs() < 1e-4);
+    assert!((yaw_delta(0.5, 0.2) + 0.3).abs() < 1e-4);
+    assert!((yaw_delta(0.0, 4.0 * std::f32::consts::TAU + 0.1) - 0.1).abs() < 1e-3);
+}
+
+#[test]
+fn fov_kick_scales_with_speed_and_power() {
+    use vibe_golf::plugins::camera::{fov_kick_deg, OrbitCameraConfig};
+    let cfg = OrbitCameraConfig::default();
+    assert_eq!(fov_kick_deg(&cfg, 0.0, 2.0), 0.0);
+    assert!((fov_kick_deg(&cfg, 500.0, 2.0) - cfg.fov_kick_deg).abs() < 1e-4, "capped at the configured kick");
+    let half_speed = fov_kick_deg(&cfg, cfg.fov_kick_full_speed * 0.5, 2.0);
+    assert!((half_speed - cfg.fov_kick_deg * 0.5).abs() < 1e-4);
+    assert!(fov_kick_deg(&cfg, 30.0, 0.25) < fov_kick_deg(&cfg, 30.0, 2.0));
+    let off = OrbitCameraConfig { fov_kick_deg: 0.0, ..Default::default() };
+    assert_eq!(fov_kick_deg(&off, 30.0, 2.0), 0.0);
+}
+
+#[test]
+fn smoothing_profiles_and_teleport_snap() {
+    use vibe_golf::plugins::camera::{CameraSmoothing, OrbitCameraConfig};
+    let mut cfg = OrbitCameraConfig::default();
+    assert_eq!((cfg.follow_spring, cfg.camera_spring), CameraSmoothing::Default.springs());
+    cfg.set_smoothing(CameraSmoothing::Tight);
+    let tight = cfg.camera_spring;
+    cfg.set_smoothing(CameraSmoothing::Cinematic);
+    assert!(tight > CameraSmoothing::Default.springs().1 && cfg.camera_spring < CameraSmoothing::Default.springs().1);
+    assert_eq!(CameraSmoothing::Tight.next().next().next(), CameraSmoothing::Tight);
+
+    // A flying ball moves well under a metre per frame; a restart jumps hundreds of metres.
+    assert!(!cfg.is_teleport(Vec3::ZERO, Vec3::new(0.6, 0.2, 0.0)));
+    assert!(cfg.is_teleport(Vec3::ZERO, Vec3::new(1000.0, 0.0, 0.0)));
+    cfg.snap_on_teleport = false;
+    assert!(!cfg.is_teleport(Vec3::ZERO, Vec3::new(1000.0, 0.0, 0.0)));
+}
+
+#[test]
+fn keyboard_orbit_steps_scale_with_time() {
+    use vibe_golf::plugins::camera::OrbitCameraConfig;
+    let cfg = OrbitCameraConfig::default();
+    let (yaw, pitch, radius) = cfg.keyboard_orbit_delta(1.0, -1.0, 1.0, 0.5);
+    assert!((yaw - cfg.key_yaw_speed * 0.5).abs() < 1e-6);
+    assert!((pitch + cfg.key_pitch_speed * 0.5).abs() < 1e-6);
+    assert!(radius < 0.0, "zooming in shrinks the radius");
+    assert_eq!(cfg.keyboard_orbit_delta(0.0, 0.0, 0.0, 0.5), (0.0, 0.0, 0.0));
+}
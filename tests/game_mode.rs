@@ -0,0 +1,46 @@
+use vibe_golf::plugins::game_mode::{hole_par, stroke_summary, GameMode, DEFAULT_PAR};
+use vibe_golf::plugins::game_state::Score;
+
+fn finished(mode: GameMode, final_time: f32, shots: u32, previous_best: Option<f32>) -> Score {
+    Score { mode, final_time, shots, game_over: true, high_score_time: None, previous_best_time: previous_best, ..Default::default() }
+}
+
+#[test]
+fn records_are_kept_per_mode_with_time_attack_on_the_plain_id() {
+    assert_eq!(GameMode::default(), GameMode::TimeAttack);
+    assert_eq!(GameMode::TimeAttack.record_key("level1"), "level1");
+    assert_eq!(GameMode::StrokePlay.record_key("level1"), "level1@strokes");
+    assert_eq!(GameMode::HardPar.record_key("daily-2026-10-17"), "daily-2026-10-17@hardpar");
+    assert_eq!(GameMode::TimeAttack.cycle(1), GameMode::StrokePlay);
+    assert_eq!(GameMode::TimeAttack.cycle(-1), GameMode::HardPar);
+}
+
+#[test]
+fn each_mode_ranks_rounds_by_its_own_record() {
+    // 120 s / 9 strokes against a best of 100 (seconds or strokes, depending on the mode).
+    let time = finished(GameMode::TimeAttack, 120.0, 9, Some(100.0));
+    assert_eq!(time.mode.rules().record(&time), 120.0);
+    assert!(!time.is_new_record());
+    let strokes = finished(GameMode::StrokePlay, 120.0, 9, Some(100.0));
+    assert_eq!(strokes.mode.rules().record(&strokes), 9.0);
+    assert!(strokes.is_new_record());
+    assert!(finished(GameMode::HardPar, 120.0, 9, None).is_new_record());
+}
+
+#[test]
+fn only_hard_par_loses_a_round_over_par() {
+    for mode in [GameMode::TimeAttack, GameMode::StrokePlay] {
+        assert!(!mode.rules().hole_failed(10, 3));
+    }
+    let hard = GameMode::HardPar.rules();
+    assert!(!hard.hole_failed(3, 3));
+    assert!(hard.hole_failed(4, 3));
+    assert_eq!(hole_par(None, 0), DEFAULT_PAR);
+}
+
+#[test]
+fn stroke_summary_compares_with_the_best() {
+    assert_eq!(stroke_summary(12, None), "Strokes: 12 | First finish - new best!");
+    assert_eq!(stroke_summary(9, Some(12.0)), "Strokes: 9 | New best! (-3)");
+    assert_eq!(stroke_summary(14, Some(12.0)), "Strokes: 14 | +2 vs best (12)");
+}
@@ -0,0 +1,15 @@
+use vibe_golf::plugins::game_over::{best_time_delta, game_over_summary};
+
+#[test]
+fn delta_is_measured_against_the_previous_best() {
+    assert_eq!(best_time_delta(80.0, None), None);
+    assert!((best_time_delta(80.0, Some(90.0)).unwrap() + 10.0).abs() < 1e-5);
+    assert!((best_time_delta(95.5, Some(90.0)).unwrap() - 5.5).abs() < 1e-5);
+}
+
+#[test]
+fn summary_reports_new_records_and_slower_runs() {
+    assert_eq!(game_over_summary(84.2, None), "Time: 84.20s | First finish - new best!");
+    assert_eq!(game_over_summary(84.2, Some(87.3)), "Time: 84.20s | New best! (-3.10s)");
+    assert_eq!(game_over_summary(90.0, Some(87.5)), "Time: 90.00s | +2.50s vs best (87.50s)");
+}
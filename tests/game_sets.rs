@@ -0,0 +1,33 @@
+// Gameplay system sets run in a fixed order regardless of registration order.
+use bevy::prelude::*;
+use vibe_golf::plugins::core_sim::{CoreSimPlugin, GameSet};
+
+#[derive(Resource, Default)]
+struct Ran(Vec<GameSet>);
+
+fn record(set: GameSet) -> impl FnMut(ResMut<Ran>) {
+    move |mut ran: ResMut<Ran>| ran.0.push(set)
+}
+
+#[test]
+fn update_runs_input_react_present_in_order() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, CoreSimPlugin)).init_resource::<Ran>();
+    // Registered backwards on purpose.
+    for set in [GameSet::Present, GameSet::React, GameSet::Simulate, GameSet::Input] {
+        app.add_systems(Update, record(set).in_set(set));
+    }
+    app.update();
+    assert_eq!(app.world().resource::<Ran>().0, vec![GameSet::Input, GameSet::Simulate, GameSet::React, GameSet::Present]);
+}
+
+#[test]
+fn fixed_step_simulates_before_reacting() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, CoreSimPlugin)).init_resource::<Ran>();
+    for set in [GameSet::React, GameSet::Simulate, GameSet::Input] {
+        app.add_systems(FixedUpdate, record(set).in_set(set));
+    }
+    app.world_mut().run_schedule(FixedUpdate);
+    assert_eq!(app.world().resource::<Ran>().0, vec![GameSet::Input, GameSet::Simulate, GameSet::React]);
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::gates::{segment_crosses_gate, torus_contact, GateDef, GateProgress};
+
+#[test]
+fn ball_path_must_cross_inside_the_ring() {
+    let (center, normal, radius) = (Vec3::new(0.0, 10.0, 0.0), Vec3::Z, 4.0);
+    // Straight through, both directions, even in one long tick.
+    assert!(segment_crosses_gate(Vec3::new(0.0, 10.0, -30.0), Vec3::new(1.0, 11.0, 25.0), center, normal, radius));
+    assert!(segment_crosses_gate(Vec3::new(0.0, 10.0, 1.0), Vec3::new(0.0, 10.0, -1.0), center, normal, radius));
+    // Crossing the plane outside the opening, or not crossing at all.
+    assert!(!segment_crosses_gate(Vec3::new(6.0, 10.0, -1.0), Vec3::new(6.0, 10.0, 1.0), center, normal, radius));
+    assert!(!segment_crosses_gate(Vec3::new(0.0, 10.0, -3.0), Vec3::new(0.0, 10.0, -1.0), center, normal, radius));
+
+    // Tube contact pushes out from the ring, not from the open middle.
+    let (n, depth) = torus_contact(Vec3::new(4.2, 10.0, 0.3), 0.2, center, normal, 4.0, 0.35).expect("touching the tube");
+    assert!(depth > 0.0 && n.z > 0.5);
+    assert!(torus_contact(Vec3::new(1.0, 10.0, 0.0), 0.2, center, normal, 4.0, 0.35).is_none());
+}
+
+#[test]
+fn progress_tracks_each_gate_once() {
+    assert!(GateProgress::default().complete(), "no gates: target always open");
+    let mut p = GateProgress::new(2);
+    assert!(!p.complete());
+    assert!(p.pass(1));
+    assert!(!p.pass(1));
+    assert!(!p.pass(5));
+    assert_eq!((p.count(), p.total()), (1, 2));
+    assert!(p.pass(0) && p.complete());
+    p.reset();
+    assert_eq!(p.count(), 0);
+
+    let def: GateDef = ron::from_str("(pos: (60.0, 260.0), yaw_deg: 30.0)").unwrap();
+    assert_eq!((def.height, def.radius, def.yaw_deg), (8.0, 4.0, 30.0));
+}
@@ -0,0 +1,52 @@
+// Ghost racing: the interchange format, ghost ball playback and the lead/behind delta.
+use bevy::prelude::*;
+use vibe_golf::plugins::game_mode::GameMode;
+use vibe_golf::plugins::ghost_race::{format_delta, GhostTrack};
+use vibe_golf::plugins::replay::{Replay, ReplayFrame};
+
+fn frame(tick: u64, ball: (f32, f32, f32)) -> ReplayFrame {
+    ReplayFrame { tick, ball, camera_pos: (0.0, 10.0, 0.0), camera_rot: (0.0, 0.0, 0.0, 1.0), targets: Vec::new() }
+}
+
+fn replay() -> Replay {
+    let mut replay = Replay::new("meadow", "meadow", GameMode::TimeAttack, 2);
+    replay.frames = vec![frame(60, (0.0, 0.0, 0.0)), frame(120, (6.0, 0.0, 0.0)), frame(126, (80.0, 0.0, 0.0))];
+    replay.hole_ticks = vec![120, 300];
+    replay.final_time = 4.0;
+    replay
+}
+
+#[test]
+fn replays_and_ghost_files_both_parse() {
+    let ghost = GhostTrack::from_replay(&replay(), "anna");
+    assert_eq!(ghost.hole_times, vec![1.0, 4.0]);
+    assert_eq!(ghost.path[1], (1.0, 6.0, 0.0, 0.0));
+
+    let parsed = GhostTrack::parse(&ghost.to_ron(), "file").unwrap();
+    assert_eq!(parsed, ghost);
+    // A plain replay file is raced too, named after the file it came from.
+    let from_replay = GhostTrack::parse(&replay().to_ron(), "bob").unwrap();
+    assert_eq!((from_replay.player.as_str(), from_replay.score_id.as_str()), ("bob", "meadow"));
+    assert!(GhostTrack::parse("not a ghost", "x").is_none());
+}
+
+#[test]
+fn ghost_ball_interpolates_but_jumps_across_cuts() {
+    let ghost = GhostTrack::from_replay(&replay(), "anna");
+    assert!((ghost.position_at(0.5).unwrap().x - 3.0).abs() < 1e-4);
+    // The next hole's start is a cut, not a 74 m glide.
+    assert_eq!(ghost.position_at(1.05).unwrap(), Vec3::new(6.0, 0.0, 0.0));
+    assert_eq!(ghost.position_at(30.0).unwrap(), Vec3::new(80.0, 0.0, 0.0));
+}
+
+#[test]
+fn delta_compares_hole_splits_and_overdue_holes() {
+    let ghost = GhostTrack::from_replay(&replay(), "anna");
+    assert_eq!(ghost.delta(&[], 0.5), None);
+    // Still on hole 1 after the ghost finished it.
+    assert_eq!(ghost.delta(&[], 1.5), Some(0.5));
+    // Finished hole 1 a quarter second ahead; the ghost is still on hole 2.
+    assert_eq!(ghost.delta(&[0.75], 2.0), Some(-0.25));
+    assert_eq!(ghost.delta(&[0.75], 5.0), Some(1.0));
+    assert_eq!((format_delta(-0.25), format_delta(1.0)), ("-0.25s".to_string(), "+1.00s".to_string()));
+}
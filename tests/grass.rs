@@ -0,0 +1,31 @@
+use vibe_golf::plugins::grass::{grass_tile_clumps, GrassConfig};
+
+#[test]
+fn full_density_inside_the_first_ring_and_none_past_the_radius() {
+    let cfg = GrassConfig::default();
+    let full = cfg.clumps_per_tile;
+    for dist in [0.0, 5.0, cfg.radius * 0.25 - 0.01] {
+        assert_eq!(grass_tile_clumps(&cfg, dist), full, "dist {dist}");
+    }
+    // Quadratic falloff per ring: (1 - ring / 4)^2.
+    assert_eq!(grass_tile_clumps(&cfg, cfg.radius * 0.25), (full as f32 * 0.5625).round() as u32);
+    assert_eq!(grass_tile_clumps(&cfg, cfg.radius * 0.5), (full as f32 * 0.25).round() as u32);
+    assert_eq!(grass_tile_clumps(&cfg, cfg.radius * 0.99), (full as f32 * 0.0625).round() as u32);
+    for dist in [cfg.radius, cfg.radius + 0.01, cfg.radius * 3.0] {
+        assert_eq!(grass_tile_clumps(&cfg, dist), 0, "dist {dist}");
+    }
+    // Counts only change at ring edges, so tiles do not respawn as the camera creeps.
+    assert_eq!(grass_tile_clumps(&cfg, cfg.radius * 0.3), grass_tile_clumps(&cfg, cfg.radius * 0.45));
+}
+
+#[test]
+fn density_scale_is_quantized_to_quarters() {
+    let at = |density_scale: f32| grass_tile_clumps(&GrassConfig { density_scale, ..Default::default() }, 0.0);
+    let full = GrassConfig::default().clumps_per_tile;
+    // Small tuner steps inside one quarter leave the tiles alone.
+    assert_eq!(at(0.55), at(0.6));
+    assert_eq!(at(0.6), (full as f32 * 0.5).round() as u32);
+    assert_eq!(at(0.74), (full as f32 * 0.75).round() as u32);
+    assert_eq!(at(1.0), full);
+    assert_eq!(at(0.1), 0);
+}
@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::headless::{HeadlessSim, HeadlessSimPlugin, SyntheticTerrain};
+
+const GROUND: f32 = 100.0;
+
+/// Carry (xz) of a shot from the origin along +x, up to its first bounce.
+fn carry(sim: &mut HeadlessSim, power: f32) -> f32 {
+    sim.spawn_ball(Vec2::ZERO);
+    sim.shoot(Vec2::X, power);
+    sim.step();
+    sim.step_until(600, |s| s.ball_grounded()).expect("ball lands");
+    sim.ball_position().xz().length()
+}
+
+#[test]
+fn flat_ground_carry_matches_projectile_range() {
+    let mut sim = HeadlessSim::flat(GROUND);
+    // Weakest shot: 18 m/s * 0.25 at 45 degrees.
+    let v0: f32 = 18.0 * 0.25;
+    let expected = v0 * v0 / 9.81;
+    let d = carry(&mut sim, 0.0);
+    assert!((d - expected).abs() < expected * 0.05, "carry {d} m, expected {expected} m");
+
+    let mut strong = HeadlessSim::flat(GROUND);
+    assert!(carry(&mut strong, 1.0) > d * 10.0);
+}
+
+#[test]
+fn same_shots_give_the_same_run() {
+    let run = || {
+        let mut sim = HeadlessSim::flat(GROUND);
+        sim.spawn_ball(Vec2::new(10.0, -20.0));
+        sim.shoot(Vec2::new(0.6, 0.8), 0.7);
+        let ticks = sim.run_until_rest(3000).expect("ball comes to rest");
+        (ticks, sim.ball_position(), sim.tick())
+    };
+    let (a, b) = (run(), run());
+    assert_eq!(a, b);
+    assert!((a.1.y - (GROUND + 0.5)).abs() < 0.01, "resting on the ground: {:?}", a.1);
+}
+
+#[test]
+fn mid_air_hit_scores_and_ends_a_one_hole_round() {
+    let mut sim = HeadlessSim::flat(GROUND);
+    sim.spawn_ball(Vec2::ZERO);
+    // Full power: 36 m/s at 45 degrees peaks ~33 m up, ~66 m out.
+    let target = sim.spawn_target(0, Vec2::new(66.0, 0.0), 33.0, 4.5, 3);
+    sim.shoot(Vec2::X, 1.0);
+    let hit = sim.step_until(600, |s| s.score().hits > 0);
+    assert!(hit.is_some(), "ball missed the target");
+    assert!(!sim.ball_grounded());
+    let score = sim.score();
+    assert_eq!((score.hits, score.points), (1, 3));
+    assert!(score.game_over, "max_holes is 1");
+    assert!(sim.target_position(target).is_some());
+}
+
+#[test]
+fn seeded_relocation_is_reproducible() {
+    let run = |seed: u64| {
+        let mut sim = HeadlessSim::new(HeadlessSimPlugin { terrain: SyntheticTerrain::Flat { height: GROUND }, seed });
+        sim.score_mut().max_holes = 5;
+        sim.spawn_ball(Vec2::ZERO);
+        let target = sim.spawn_target(0, Vec2::new(66.0, 0.0), 33.0, 4.5, 1);
+        sim.shoot(Vec2::X, 1.0);
+        sim.step_until(600, |s| s.score().hits > 0).expect("target hit");
+        assert!(!sim.score().game_over);
+        sim.target_position(target).unwrap()
+    };
+    let first = run(7);
+    assert_eq!(first, run(7));
+    assert_ne!(first.xz(), Vec2::new(66.0, 0.0), "target relocated");
+}
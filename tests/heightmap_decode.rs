@@ -0,0 +1,19 @@
+// Placeholder sampler used while the level heightmap decodes in the background.
+use vibe_golf::prelude::*;
+
+#[test]
+fn placeholder_is_flat_and_flagged() {
+    let s = TerrainSampler::placeholder(TerrainConfig::default());
+    assert!(s.is_placeholder());
+    let h = s.height(0.0, 0.0);
+    for (x, z) in [(250.0, -40.0), (-900.0, 900.0), (13.0, 600.0)] {
+        assert_eq!(s.height(x, z), h);
+    }
+    assert_eq!(s.heightmap_data().0, 1);
+}
+
+#[test]
+fn decoded_samplers_are_not_placeholders() {
+    let s = TerrainSampler::from_heightmap_data(TerrainConfig::default(), 2, 2, vec![0, 64, 128, 255]);
+    assert!(!s.is_placeholder());
+}
@@ -0,0 +1,17 @@
+use vibe_golf::plugins::game_state::DEFAULT_LEVEL_ID;
+use vibe_golf::plugins::profile::parse_high_scores;
+
+#[test]
+fn legacy_single_time_belongs_to_the_default_level() {
+    let times = parse_high_scores("123.5\n");
+    assert_eq!(times.get(DEFAULT_LEVEL_ID), Some(&123.5));
+    assert_eq!(times.len(), 1);
+}
+
+#[test]
+fn per_level_times_are_parsed() {
+    let times = parse_high_scores("level1 98.25\ncourse1 301\n\nbroken line here\n");
+    assert_eq!(times.get("level1"), Some(&98.25));
+    assert_eq!(times.get("course1"), Some(&301.0));
+    assert_eq!(times.len(), 2);
+}
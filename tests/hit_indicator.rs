@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::hit_indicator::{edge_point, needs_indicator, HitIndicatorConfig};
+
+#[test]
+fn edge_point_lands_on_the_inset_screen_border() {
+    let window = Vec2::new(800.0, 600.0);
+    assert_eq!(edge_point(Vec2::X, window, 40.0), Vec2::new(760.0, 300.0));
+    assert_eq!(edge_point(Vec2::new(0.0, -3.0), window, 40.0), Vec2::new(400.0, 40.0));
+    // Diagonal: the nearer (top / bottom) border is hit first on a wide screen.
+    let p = edge_point(Vec2::new(1.0, 1.0), window, 0.0);
+    assert!((p - Vec2::new(700.0, 600.0)).length() < 1e-3);
+}
+
+#[test]
+fn only_far_or_off_screen_hits_need_the_indicator() {
+    let cfg = HitIndicatorConfig::default();
+    let window = Vec2::new(800.0, 600.0);
+    assert!(!needs_indicator(&cfg, Some(Vec2::new(400.0, 300.0)), window, 30.0));
+    assert!(needs_indicator(&cfg, Some(Vec2::new(400.0, 300.0)), window, cfg.far_distance + 1.0));
+    assert!(needs_indicator(&cfg, Some(Vec2::new(-20.0, 300.0)), window, 30.0));
+    assert!(needs_indicator(&cfg, None, window, 30.0), "behind the camera");
+}
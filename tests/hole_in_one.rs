@@ -0,0 +1,46 @@
+// Hole in one: which hits count, and the recorded shot path the slow-motion replay plays back.
+use bevy::math::Vec3;
+use vibe_golf::plugins::game_state::Score;
+use vibe_golf::plugins::hole_in_one::{is_hole_in_one, ShotPath};
+use vibe_golf::plugins::target::TargetKind;
+
+#[test]
+fn only_first_stroke_standard_hits_count() {
+    assert!(is_hole_in_one(TargetKind::Standard, 1));
+    assert!(!is_hole_in_one(TargetKind::Standard, 2));
+    assert!(!is_hole_in_one(TargetKind::Standard, 0));
+    assert!(!is_hole_in_one(TargetKind::Decoy, 1));
+    assert!(!is_hole_in_one(TargetKind::Bonus, 1));
+
+    let score = Score { shots: 5, shots_at_last_hit: 4, ..Score::default() };
+    assert_eq!(score.hole_strokes(), 1);
+}
+
+#[test]
+fn path_is_interpolated_and_clamped() {
+    let mut path = ShotPath::default();
+    assert!(path.is_empty() && path.at(0.0).is_none());
+    path.push(10.0, Vec3::ZERO);
+    path.push(11.0, Vec3::new(10.0, 0.0, 0.0));
+    path.push(12.0, Vec3::new(10.0, 4.0, 0.0));
+    assert_eq!(path.duration(), 2.0);
+    assert_eq!(path.at(0.5), Some(Vec3::new(5.0, 0.0, 0.0)));
+    assert_eq!(path.at(1.5), Some(Vec3::new(10.0, 2.0, 0.0)));
+    assert_eq!(path.at(-1.0), Some(Vec3::ZERO));
+    assert_eq!(path.at(9.0), Some(Vec3::new(10.0, 4.0, 0.0)));
+}
+
+#[test]
+fn tail_keeps_the_end_of_the_flight() {
+    let mut path = ShotPath::default();
+    for i in 0..=10 {
+        path.push(i as f32, Vec3::new(i as f32, 0.0, 0.0));
+    }
+    let tail = path.tail(3.0);
+    assert_eq!(tail.len(), 4);
+    assert_eq!(tail.duration(), 3.0);
+    assert_eq!(tail.at(0.0), Some(Vec3::new(7.0, 0.0, 0.0)));
+    assert_eq!(tail.last(), path.last());
+    // A window longer than the flight keeps all of it.
+    assert_eq!(path.tail(60.0), path);
+}
@@ -0,0 +1,28 @@
+use bevy::math::Vec2;
+use vibe_golf::plugins::game_state::{HoleState, Score};
+
+#[test]
+fn return_to_tee_costs_one_stroke() {
+    let mut score = Score::default();
+    let mut hole = HoleState::default();
+    hole.start_run(Vec2::new(10.0, -5.0));
+    score.shots = 3;
+    hole.penalize_return(&mut score);
+    hole.penalize_return(&mut score);
+    assert_eq!(score.shots, 5);
+    assert_eq!(hole.penalty_strokes, 2);
+    assert_eq!(hole.tee, Vec2::new(10.0, -5.0));
+}
+
+#[test]
+fn checkpoint_moves_the_tee_and_clears_penalties() {
+    let mut score = Score::default();
+    let mut hole = HoleState::default();
+    hole.start_run(Vec2::ZERO);
+    hole.penalize_return(&mut score);
+    hole.checkpoint(1, Vec2::new(120.0, 40.0));
+    assert_eq!(hole, HoleState { tee: Vec2::new(120.0, 40.0), hole: 1, penalty_strokes: 0 });
+
+    hole.start_run(Vec2::new(3.0, 4.0));
+    assert_eq!(hole, HoleState { tee: Vec2::new(3.0, 4.0), hole: 0, penalty_strokes: 0 });
+}
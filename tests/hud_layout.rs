@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::hud_layout::{anchored_offset, effective_ui_scale, HudLayoutConfig};
+
+#[test]
+fn ui_scale_follows_window_size_and_user_scale() {
+    let mut cfg = HudLayoutConfig::default();
+    assert!((effective_ui_scale(&cfg, Vec2::new(1920.0, 1080.0)) - 1.0).abs() < 1e-5);
+    // 4k at scale factor 1: twice the reference in both directions.
+    assert!((effective_ui_scale(&cfg, Vec2::new(3840.0, 2160.0)) - 2.0).abs() < 1e-5);
+    // Ultrawide: the tighter axis wins.
+    assert!((effective_ui_scale(&cfg, Vec2::new(3440.0, 1080.0)) - 1.0).abs() < 1e-5);
+    // Phones bottom out at the clamp.
+    assert!((effective_ui_scale(&cfg, Vec2::new(390.0, 844.0)) - 0.6).abs() < 1e-5);
+
+    cfg.set_scale(1.5);
+    assert!((effective_ui_scale(&cfg, Vec2::new(3840.0, 2160.0)) - 3.0).abs() < 1e-5);
+    cfg.auto_scale = false;
+    assert!((effective_ui_scale(&cfg, Vec2::new(3840.0, 2160.0)) - 1.5).abs() < 1e-5);
+    cfg.set_scale(10.0);
+    assert_eq!(cfg.scale, cfg.scale_range.1);
+}
+
+#[test]
+fn safe_area_inset_stays_in_window_pixels_under_ui_scale() {
+    assert_eq!(anchored_offset(12.0, 0.0, 2.0), Val::Px(12.0));
+    // 40 window px of notch at 2x UI scale = 20 UI px (rendered as 40).
+    assert_eq!(anchored_offset(12.0, 40.0, 2.0), Val::Px(32.0));
+    assert_eq!(anchored_offset(8.0, 30.0, 0.5), Val::Px(68.0));
+}
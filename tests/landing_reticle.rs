@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::biome::Biome;
+use vibe_golf::plugins::landing_reticle::{predict_landing, slope_stretch, LandingSurface};
+
+#[test]
+fn flat_ground_landing_matches_ballistic_range() {
+    // 45 degrees at 20 m/s: range v^2 / g ~ 40.8 m.
+    let v = Vec3::new(1.0, 1.0, 0.0).normalize() * 20.0;
+    let hit = predict_landing(Vec3::new(0.0, 0.5, 0.0), v, 0.5, 10.0, |_, _| 0.0).unwrap();
+    assert!((hit.x - 40.8).abs() < 1.5, "{hit:?}");
+    assert_eq!(hit.y, 0.0);
+}
+
+#[test]
+fn rising_ground_shortens_the_flight() {
+    let v = Vec3::new(1.0, 1.0, 0.0).normalize() * 20.0;
+    let flat = predict_landing(Vec3::new(0.0, 0.5, 0.0), v, 0.5, 10.0, |_, _| 0.0).unwrap();
+    let hill = predict_landing(Vec3::new(0.0, 0.5, 0.0), v, 0.5, 10.0, |x, _| x * 0.3).unwrap();
+    assert!(hill.x < flat.x);
+    assert!(predict_landing(Vec3::Y, Vec3::Y * 5.0, 0.5, 10.0, |_, _| -1000.0).is_none());
+}
+
+#[test]
+fn surface_and_stretch() {
+    assert_eq!(LandingSurface::classify(10.0, Biome::Meadow), LandingSurface::Water);
+    assert_eq!(LandingSurface::classify(60.0, Biome::Forest), LandingSurface::Forest);
+    assert_eq!(slope_stretch(Vec3::Y, 2.5), 1.0);
+    assert_eq!(slope_stretch(Vec3::new(1.0, 0.1, 0.0).normalize(), 2.5), 2.5);
+}
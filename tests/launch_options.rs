@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use vibe_golf::launch_options::{parse_interval, parse_window_size, ArgError, LaunchOptions};
+use vibe_golf::plugins::terrain_presets::TerrainPreset;
+
+#[test]
+fn parses_every_option_in_both_forms() {
+    let options = LaunchOptions::parse([
+        "--level", "canyon", "--seed=42", "--autoplay", "--headless", "--window-size", "1280x720",
+        "--quality=ultra", "--low-power", "--ghost", "anna.ghost.ron", "--host=7777", "--spectate", "-runtime", "30", "--screenshot", "--benchmark", "--data-dir=saves",
+    ])
+    .unwrap();
+    assert_eq!(
+        options,
+        LaunchOptions {
+            level: Some("canyon".into()),
+            seed: Some(42),
+            autoplay: true,
+            benchmark: true,
+            headless: true,
+            window_size: Some((1280, 720)),
+            quality: Some(TerrainPreset::Ultra),
+            low_power: true,
+            ghost: Some("anna.ghost.ron".into()),
+            host: Some(7777),
+            join: None,
+            challenge: None,
+            spectate: true,
+            runtime: Some(30.0),
+            screenshot: true,
+            screenshot_every: None,
+            screenshot_scale: None,
+            data_dir: Some(PathBuf::from("saves")),
+            help: false,
+        }
+    );
+    assert_eq!(LaunchOptions::parse(Vec::<String>::new()).unwrap(), LaunchOptions::default());
+    assert!(LaunchOptions::parse(["-h"]).unwrap().help);
+}
+
+#[test]
+fn rejects_unknown_options_and_bad_values() {
+    assert_eq!(LaunchOptions::parse(["--no-such-flag"]), Err(ArgError::Unknown("--no-such-flag".into())));
+    assert_eq!(LaunchOptions::parse(["stray"]), Err(ArgError::Unknown("stray".into())));
+    assert_eq!(LaunchOptions::parse(["--level"]), Err(ArgError::MissingValue("level")));
+    assert_eq!(
+        LaunchOptions::parse(["--quality", "potato"]),
+        Err(ArgError::BadValue { option: "quality", value: "potato".into() })
+    );
+    assert!(matches!(LaunchOptions::parse(["--runtime", "-5"]), Err(ArgError::BadValue { .. })));
+    assert!(matches!(LaunchOptions::parse(["--seed", "abc"]), Err(ArgError::BadValue { .. })));
+    assert_eq!(LaunchOptions::parse(["--autoplay=yes"]), Err(ArgError::UnexpectedValue("autoplay")));
+}
+
+#[test]
+fn window_sizes() {
+    assert_eq!(parse_window_size("1920x1080"), Some((1920, 1080)));
+    assert_eq!(parse_window_size("800X600"), Some((800, 600)));
+    assert_eq!(parse_window_size("0x600"), None);
+    assert_eq!(parse_window_size("1920"), None);
+}
+
+#[test]
+fn screenshot_sequence_options() {
+    let options = LaunchOptions::parse(["--screenshot-every", "2s", "--screenshot-scale=0.5"]).unwrap();
+    assert_eq!((options.screenshot_every, options.screenshot_scale), (Some(2.0), Some(0.5)));
+    assert!(options.screenshots_enabled() && !options.screenshot);
+    assert_eq!(parse_interval("500ms"), Some(0.5));
+    assert_eq!(parse_interval("3"), Some(3.0));
+    assert_eq!(parse_interval("0s"), None);
+    assert!(matches!(LaunchOptions::parse(["--screenshot-scale", "2"]), Err(ArgError::BadValue { .. })));
+}
+
+#[test]
+fn data_dir_in_both_forms() {
+    let dir = |args: &[&str]| LaunchOptions::parse(args).unwrap().data_dir;
+    assert_eq!(dir(&["--data-dir", "/tmp/golf"]), Some(PathBuf::from("/tmp/golf")));
+    assert_eq!(dir(&["--runtime", "5", "--data-dir=saves"]), Some(PathBuf::from("saves")));
+    assert_eq!(dir(&["--screenshot"]), None);
+    assert_eq!(LaunchOptions::parse(["--data-dir"]), Err(ArgError::MissingValue("data-dir")));
+}
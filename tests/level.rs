@@ -0,0 +1,78 @@
+use bevy::math::Vec2;
+use vibe_golf::plugins::cup_target::TargetStyle;
+use vibe_golf::plugins::level::{HoleDef, LevelDef, LevelManifest};
+
+fn base_level() -> LevelDef {
+    let data = std::fs::read_to_string("assets/levels/level1.ron").unwrap();
+    ron::from_str::<LevelDef>(&data).unwrap()
+}
+
+fn course_level() -> LevelDef {
+    let mut level = base_level();
+    level.holes = ron::from_str::<Vec<HoleDef>>(
+        "[
+            (tee: (x: 10.0, z: 20.0), target: (x: 30.0, z: 40.0), par: 2),
+            (tee: (x: 50.0, z: 60.0), target: (x: 70.0, z: 80.0, style: Some(Cup)),
+                props: [(model: \"models/candy_1.glb#Scene0\", x: 1.0, z: 2.0)]),
+        ]",
+    )
+    .unwrap();
+    level
+}
+
+#[test]
+fn bundled_level_is_not_a_course() {
+    let level = base_level();
+    assert!(!level.is_course());
+    assert_eq!(level.tee(3), Vec2::new(level.ball.pos.x, level.ball.pos.z));
+}
+
+#[test]
+fn hole_defaults() {
+    let level = course_level();
+    assert_eq!(level.hole(0).unwrap().par, 2);
+    let second = level.hole(1).unwrap();
+    assert_eq!(second.par, 3);
+    assert_eq!(second.props.len(), 1);
+    assert_eq!(second.props[0].scale, 1.0);
+    assert!(level.hole(2).is_none());
+}
+
+#[test]
+fn course_replaces_spawn_target_and_hole_count() {
+    let mut level = course_level();
+    let exclusions = level.vegetation_exclusions.len();
+    level.apply_course();
+    assert_eq!((level.ball.pos.x, level.ball.pos.z), (10.0, 20.0));
+    assert_eq!((level.target.initial.x, level.target.initial.z), (30.0, 40.0));
+    assert_eq!(level.scoring.max_holes, 2);
+    assert_eq!(level.hole_styles, vec![level.target.style, TargetStyle::Cup]);
+    assert_eq!(level.tee(1), Vec2::new(50.0, 60.0));
+    assert_eq!(level.vegetation_exclusions.len(), exclusions + 1);
+}
+
+#[test]
+fn manifest_levels_load() {
+    let data = std::fs::read_to_string("assets/levels/manifest.ron").unwrap();
+    let manifest = LevelManifest::from_ron(&data).unwrap();
+    assert!(!manifest.levels.is_empty());
+    assert_eq!(manifest.index_of("level1"), Some(0));
+    for entry in &manifest.levels {
+        assert_eq!(manifest.index_of(&entry.id), manifest.levels.iter().position(|l| l.id == entry.id));
+        assert!(std::path::Path::new("assets").join(&entry.heightmap).exists(), "{}", entry.heightmap);
+        let level = std::fs::read_to_string(std::path::Path::new("assets").join(&entry.file)).unwrap();
+        ron::from_str::<LevelDef>(&level).unwrap_or_else(|e| panic!("{}: {e}", entry.file));
+    }
+}
+
+#[test]
+fn manifest_selection_wraps() {
+    let mut manifest = LevelManifest::fallback();
+    manifest.levels.push(manifest.levels[0].clone());
+    manifest.levels[1].id = "other".into();
+    manifest.cycle(-1);
+    assert_eq!(manifest.selected_entry().unwrap().id, "other");
+    manifest.cycle(1);
+    assert_eq!(manifest.selected, 0);
+    assert!(manifest.index_of("missing").is_none());
+}
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::level::PropDef;
+use vibe_golf::plugins::level_props::{bounce_velocity, prop_contact, PropCollider};
+
+#[test]
+fn prop_defs_parse_with_defaults() {
+    let props = ron::from_str::<Vec<PropDef>>(
+        "[
+            (model: \"models/candy_1.glb#Scene0\", x: 30.0, z: 40.0, scale: 5.0,
+                collider: Some(Cylinder(radius: 0.6, height: 2.0)), tag: Some(\"candy_tower\")),
+            (model: \"models/snowflake.glb#Scene0\", x: -20.0, z: 60.0, height: 12.0, roll_deg: 90.0),
+        ]",
+    )
+    .unwrap();
+    assert_eq!(props[0].collider.unwrap().scaled(props[0].scale), PropCollider::Cylinder { radius: 3.0, height: 10.0 });
+    assert_eq!(props[0].tag.as_deref(), Some("candy_tower"));
+    assert_eq!((props[1].scale, props[1].yaw_deg), (1.0, 0.0));
+    assert!(props[1].collider.is_none());
+}
+
+#[test]
+fn ball_rests_on_box_top_and_is_pushed_off_sides() {
+    let deck = PropCollider::Box { half_extents: (10.0, 0.5, 2.0) };
+    let pose = Transform::from_xyz(0.0, 5.0, 0.0);
+    let (n, depth) = prop_contact(Vec3::new(3.0, 5.8, 0.0), 0.5, deck, &pose).unwrap();
+    assert!(n.abs_diff_eq(Vec3::Y, 1e-5) && (depth - 0.2).abs() < 1e-4);
+    assert!(prop_contact(Vec3::new(3.0, 6.2, 0.0), 0.5, deck, &pose).is_none());
+
+    // Turned 90 degrees: the long side now runs along Z.
+    let turned = pose.with_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_2));
+    assert!(prop_contact(Vec3::new(0.0, 5.0, 8.0), 0.5, deck, &turned).is_some());
+    assert!(prop_contact(Vec3::new(8.0, 5.0, 0.0), 0.5, deck, &turned).is_none());
+}
+
+#[test]
+fn cylinder_side_and_cap() {
+    let post = PropCollider::Cylinder { radius: 1.0, height: 4.0 };
+    let pose = Transform::IDENTITY;
+    let (n, _) = prop_contact(Vec3::new(1.2, 2.0, 0.0), 0.5, post, &pose).unwrap();
+    assert!(n.abs_diff_eq(Vec3::X, 1e-5));
+    let (n, depth) = prop_contact(Vec3::new(0.2, 4.3, 0.0), 0.5, post, &pose).unwrap();
+    assert!(n.abs_diff_eq(Vec3::Y, 1e-5) && (depth - 0.2).abs() < 1e-4);
+    assert!(prop_contact(Vec3::new(0.0, 2.0, 3.0), 0.5, post, &pose).is_none());
+}
+
+#[test]
+fn half_pipe_holds_the_ball_inside_its_curve() {
+    let pipe = PropCollider::HalfPipe { radius: 4.0, length: 10.0 };
+    let pose = Transform::IDENTITY;
+    // Riding the bottom: pushed up.
+    let (n, depth) = prop_contact(Vec3::new(0.0, 0.3, 0.0), 0.5, pipe, &pose).unwrap();
+    assert!(n.abs_diff_eq(Vec3::Y, 1e-5) && (depth - 0.2).abs() < 1e-4);
+    // Up the wall on the +z side: pushed back towards the axis.
+    let (n, _) = prop_contact(Vec3::new(2.0, 4.0, 3.7), 0.5, pipe, &pose).unwrap();
+    assert!(n.abs_diff_eq(-Vec3::Z, 1e-5));
+    // Above the lips, past its ends and in the middle of the pipe: free.
+    assert!(prop_contact(Vec3::new(0.0, 9.0, 0.0), 0.5, pipe, &pose).is_none());
+    assert!(prop_contact(Vec3::new(6.0, 0.3, 0.0), 0.5, pipe, &pose).is_none());
+    assert!(prop_contact(Vec3::new(0.0, 3.0, 0.0), 0.5, pipe, &pose).is_none());
+
+    // A ramp is only the +z part of the curve.
+    let ramp = PropCollider::Ramp { radius: 4.0, width: 3.0, angle_deg: 60.0 };
+    assert!(prop_contact(Vec3::new(0.0, 1.0, 2.4), 0.5, ramp, &pose).is_some());
+    assert!(prop_contact(Vec3::new(0.0, 1.0, -2.4), 0.5, ramp, &pose).is_none());
+}
+
+#[test]
+fn bounce_pads_kick_and_restitution_scales() {
+    let n = Vec3::Y;
+    let vel = Vec3::new(3.0, -10.0, 0.0);
+    assert!(bounce_velocity(vel, n, 0.5, 0.0).abs_diff_eq(Vec3::new(3.0, 5.0, 0.0), 1e-5));
+    assert!(bounce_velocity(vel, n, 0.5, 8.0).abs_diff_eq(Vec3::new(3.0, 13.0, 0.0), 1e-5));
+    // Already leaving: untouched.
+    assert_eq!(bounce_velocity(Vec3::new(0.0, 2.0, 0.0), n, 0.5, 8.0), Vec3::new(0.0, 2.0, 0.0));
+
+    let pads = ron::from_str::<Vec<PropDef>>(
+        "[(x: 10.0, z: 5.0, collider: Some(Cylinder(radius: 2.0, height: 0.3)), restitution: Some(0.9), boost: 12.0)]",
+    )
+    .unwrap();
+    assert!(pads[0].model.is_empty());
+    assert_eq!((pads[0].restitution, pads[0].boost), (Some(0.9), 12.0));
+}
@@ -0,0 +1,56 @@
+use vibe_golf::plugins::level_script::{LevelScriptState, ScriptAction, ScriptRule, ScriptTrigger};
+
+fn rules() -> Vec<ScriptRule> {
+    ron::from_str(
+        r#"[
+            (when: After(60.0), then: [SpawnBonus, Message("Golden duck!")]),
+            (when: HoleStart(3), then: [SetWind(strength: 1.6, heading_deg: Some(90.0))]),
+            (when: Every(30.0), then: [AddTime(-1.0)]),
+            (when: Hit, then: [SetWind(strength: 0.2)]),
+        ]"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn rules_parse_from_ron() {
+    let rules = rules();
+    assert_eq!(rules[0].when, ScriptTrigger::After(60.0));
+    assert_eq!(rules[0].then[1], ScriptAction::Message("Golden duck!".into()));
+    assert_eq!(rules[3].then[0], ScriptAction::SetWind { strength: 0.2, heading_deg: None });
+}
+
+#[test]
+fn timers_fire_once_or_per_period() {
+    let rules = rules();
+    let mut state = LevelScriptState::default();
+    assert!(state.step(&rules, 10.0, 0, 0).is_empty());
+    assert_eq!(state.step(&rules, 31.0, 0, 0), vec![2]);
+    assert_eq!(state.step(&rules, 61.0, 0, 0), vec![0, 2]);
+    assert!(state.step(&rules, 70.0, 0, 0).is_empty(), "After fires only once");
+    // A frame hitch spanning two periods catches up.
+    assert_eq!(state.step(&rules, 125.0, 0, 0), vec![2, 2]);
+}
+
+#[test]
+fn hole_start_and_hits_follow_the_score() {
+    let rules = rules();
+    let mut state = LevelScriptState::default();
+    assert!(state.step(&rules, 1.0, 0, 0).is_empty());
+    assert_eq!(state.step(&rules, 2.0, 1, 1), vec![3]);
+    assert_eq!(state.step(&rules, 3.0, 2, 1), vec![1, 3], "hole 3 starts after the second hit");
+    assert!(state.step(&rules, 4.0, 2, 0).is_empty());
+
+    state.reset();
+    assert_eq!(state.step(&rules, 4.0, 2, 0), vec![1], "a new run fires hole rules again");
+}
+
+#[test]
+fn resumed_runs_skip_what_already_happened() {
+    let rules = rules();
+    let mut state = LevelScriptState::default();
+    state.skip_to(&rules, 95.0, 2);
+    // Hole 3 (hits = 2) is the hole being played: its start still fires, the past timers don't.
+    assert_eq!(state.step(&rules, 96.0, 2, 0), vec![1]);
+    assert_eq!(state.step(&rules, 121.0, 2, 0), vec![2]);
+}
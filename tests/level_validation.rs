@@ -0,0 +1,54 @@
+use vibe_golf::plugins::level::{HoleDef, LevelDef};
+use vibe_golf::plugins::level_validation::{asset_exists, max_shot_range, validate_level, IssueSeverity};
+use vibe_golf::plugins::terrain::{TerrainConfig, TerrainSampler};
+
+mod common;
+
+fn level(file: &str) -> LevelDef {
+    let data = std::fs::read_to_string(format!("assets/levels/{file}")).unwrap();
+    let mut def = ron::from_str::<LevelDef>(&data).unwrap();
+    def.apply_course();
+    def
+}
+
+#[test]
+fn shot_range_matches_projectile_formula() {
+    let def = level("level1.ron");
+    let v = def.shot.base_impulse * 2.0;
+    assert!((max_shot_range(&def.shot) - v * v / 9.81).abs() < 0.5);
+    assert!(asset_exists("models/ducky.glb#Scene0"));
+    assert!(!asset_exists("models/missing.glb"));
+}
+
+#[test]
+fn bad_course_reports_actionable_errors() {
+    let mut def = level("level1.ron");
+    def.holes = ron::from_str::<Vec<HoleDef>>(
+        "[
+            (tee: (x: 500.0, z: 0.0), target: (x: 520.0, z: 0.0), par: 2),
+            (tee: (x: 500.0, z: 0.0), target: (x: -500.0, z: 0.0), par: 1),
+            (tee: (x: 1500.0, z: 0.0), target: (x: 600.0, z: 0.0),
+                props: [(model: \"models/missing.glb#Scene0\", x: 0.0, z: 0.0)]),
+        ]",
+    )
+    .unwrap();
+    def.apply_course();
+    let issues = validate_level(&def, &common::half_sea());
+    let about = |subject: &str| issues.iter().filter(|i| i.subject == subject).count();
+    assert_eq!(about("hole 1"), 0);
+    assert_eq!(about("hole 1 tee") + about("hole 1 target"), 0);
+    assert_eq!(about("hole 2 target"), 1, "under water: {issues:?}");
+    assert_eq!(about("hole 2"), 1, "out of reach in par 1: {issues:?}");
+    assert_eq!(about("hole 3 tee"), 1, "outside the terrain: {issues:?}");
+    assert_eq!(about("hole 3 prop 1"), 1, "missing asset: {issues:?}");
+    assert!(issues.iter().all(|i| i.severity == IssueSeverity::Error));
+}
+
+#[test]
+fn bundled_levels_have_no_errors() {
+    let sampler = TerrainSampler::new(TerrainConfig::default());
+    for file in ["level1.ron", "course1.ron"] {
+        let issues = validate_level(&level(file), &sampler);
+        assert!(issues.iter().all(|i| i.severity != IssueSeverity::Error), "{file}: {issues:?}");
+    }
+}
@@ -0,0 +1,34 @@
+use bevy::asset::{AssetLoadError, LoadState};
+use vibe_golf::plugins::level::LevelDef;
+use vibe_golf::plugins::loading_state::{preload_paths, LoadProgress};
+use vibe_golf::plugins::vegetation_species::VegetationSpeciesList;
+
+#[test]
+fn progress_counts_failures_as_done() {
+    let failed = LoadState::Failed(Box::new(AssetLoadError::CannotLoadIgnoredAsset { path: "missing.glb".into() }));
+    let progress = LoadProgress::from_states([LoadState::Loaded, LoadState::Loading, failed, LoadState::NotLoaded]);
+    assert_eq!(progress, LoadProgress { loaded: 1, failed: 1, total: 4 });
+    assert!((progress.fraction() - 0.5).abs() < 1e-6);
+    assert!(!progress.finished());
+
+    let done = LoadProgress::from_states([LoadState::Loaded, LoadState::Loaded]);
+    assert!(done.finished() && done.fraction() == 1.0);
+    assert!(LoadProgress::default().finished());
+}
+
+#[test]
+fn preload_covers_level_species_and_particles_once() {
+    let data = std::fs::read_to_string("assets/levels/level1.ron").unwrap();
+    let level = ron::from_str::<LevelDef>(&data).unwrap();
+    let species = VegetationSpeciesList::default();
+    let (scenes, images) = preload_paths(Some(&level), Some(&species));
+    assert!(scenes.contains(&level.ball.model));
+    assert!(scenes.contains(&level.target.model));
+    assert!(species.species.iter().all(|s| scenes.contains(&s.model)));
+    assert!(scenes.iter().any(|p| p.contains("snowflake")));
+    assert_eq!(images, vec![level.sky.texture.clone()]);
+    let mut unique = scenes.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), scenes.len());
+}
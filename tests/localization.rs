@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use vibe_golf::plugins::localization::{fill_placeholders, needs_fallback_font, LanguageFile, Localization, LANGUAGES};
+
+fn lang(name: &str, pairs: &[(&str, &str)]) -> LanguageFile {
+    LanguageFile {
+        name: name.to_string(),
+        font: None,
+        strings: pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect::<HashMap<_, _>>(),
+    }
+}
+
+#[test]
+fn lookup_falls_back_to_english_then_key() {
+    let mut loc = Localization::from_languages(vec![
+        ("en".into(), lang("English", &[("menu.play", "Play"), ("menu.quit", "Quit")])),
+        ("de".into(), lang("Deutsch", &[("menu.play", "Spielen")])),
+    ]);
+    assert_eq!(loc.t("menu.play"), "Play");
+    assert!(loc.set_language("de"));
+    assert_eq!(loc.t("menu.play"), "Spielen");
+    assert_eq!(loc.t("menu.quit"), "Quit");
+    assert_eq!(loc.t("missing.key"), "missing.key");
+    loc.cycle();
+    assert_eq!(loc.code(), "en");
+}
+
+#[test]
+fn placeholders_are_filled() {
+    let s = fill_placeholders("Hole: {hole}/{holes} ({hole})", &[("hole", "2".into()), ("holes", "5".into())]);
+    assert_eq!(s, "Hole: 2/5 (2)");
+}
+
+#[test]
+fn shipped_language_files_cover_english_keys() {
+    let read = |code: &str| {
+        let data = std::fs::read_to_string(format!("assets/i18n/{code}.ron")).unwrap();
+        LanguageFile::from_ron(&data).unwrap()
+    };
+    let en = read("en");
+    for code in LANGUAGES {
+        let file = read(code);
+        for key in en.strings.keys() {
+            assert!(file.strings.contains_key(key), "{code} is missing {key}");
+        }
+        assert!(file.font.is_some() || !file.strings.values().any(|s| needs_fallback_font(s)), "{code} needs a font");
+    }
+}
+
+#[test]
+fn non_latin_text_needs_fallback_font() {
+    assert!(!needs_fallback_font("Schläge: 3 | Ø 2.5 ¡Sí!"));
+    assert!(needs_fallback_font("ゴルフ"));
+    assert!(needs_fallback_font("Гольф"));
+}
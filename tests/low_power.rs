@@ -0,0 +1,24 @@
+use std::time::Duration;
+
+use vibe_golf::plugins::low_power::{FrameLimiter, LowPowerMode};
+use vibe_golf::plugins::particles::ParticleQuality;
+
+#[test]
+fn frame_limiter_waits_out_the_rest_of_the_frame() {
+    let capped = FrameLimiter { max_fps: Some(50.0) };
+    assert_eq!(capped.wait_for(Duration::from_millis(5)).as_millis(), 15);
+    assert_eq!(capped.wait_for(Duration::from_millis(40)), Duration::ZERO);
+    assert_eq!(FrameLimiter::default().wait_for(Duration::ZERO), Duration::ZERO);
+    assert_eq!(FrameLimiter { max_fps: Some(0.0) }.wait_for(Duration::ZERO), Duration::ZERO);
+}
+
+#[test]
+fn particle_budget_scales_but_keeps_effects_visible() {
+    let low = ParticleQuality { scale: 0.35 };
+    assert_eq!(low.scaled(300), 105);
+    assert_eq!(low.scaled(1), 1);
+    assert_eq!(low.scaled(0), 0);
+    assert_eq!(ParticleQuality::default().scaled(220), 220);
+    assert!(LowPowerMode::reduced_motion(Some(&LowPowerMode { enabled: true })));
+    assert!(!LowPowerMode::reduced_motion(None));
+}
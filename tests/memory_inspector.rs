@@ -0,0 +1,44 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use vibe_golf::plugins::memory_inspector::{
+    format_bytes, mesh_bytes, steady_growth, AssetUsage, MemoryReport, GROWTH_THRESHOLD, HISTORY_SAMPLES,
+};
+
+#[test]
+fn mesh_bytes_counts_attributes_and_indices() {
+    let mesh = Mesh::from(Cuboid::default());
+    // 24 vertices of position + normal + uv (32 bytes), 36 u32 indices.
+    assert_eq!(mesh_bytes(&mesh), 24 * 32 + 36 * 4);
+    let empty = Mesh::new(bevy::render::mesh::PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+    assert_eq!(mesh_bytes(&empty), 0);
+}
+
+#[test]
+fn bytes_are_formatted_with_units() {
+    assert_eq!(format_bytes(512), "512 B");
+    assert_eq!(format_bytes(1536), "1.5 KB");
+    assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MB");
+}
+
+#[test]
+fn only_steady_growth_is_flagged() {
+    let history = |v: Vec<usize>| v.into_iter().collect::<VecDeque<_>>();
+    let rising: Vec<usize> = (0..HISTORY_SAMPLES).map(|i| 100 + i * 5).collect();
+    assert!(steady_growth(&history(rising.clone()), GROWTH_THRESHOLD));
+    assert!(!steady_growth(&history(rising[..HISTORY_SAMPLES - 1].to_vec()), GROWTH_THRESHOLD), "not enough samples");
+    let mut dip = rising.clone();
+    dip[4] = 90;
+    assert!(!steady_growth(&history(dip), GROWTH_THRESHOLD), "streaming up and down is normal");
+    assert!(!steady_growth(&history(vec![100; HISTORY_SAMPLES]), GROWTH_THRESHOLD));
+
+    let mut usage = AssetUsage::default();
+    for n in rising.iter().chain(rising.iter().map(|n| n + 50).collect::<Vec<_>>().iter()) {
+        usage.record(*n, n * 10);
+    }
+    assert_eq!(usage.history.len(), HISTORY_SAMPLES);
+    assert!(usage.growing());
+    let report = MemoryReport { meshes: usage, ..default() };
+    assert!(report.lines()[1].contains("GROWING"), "{:?}", report.lines());
+}
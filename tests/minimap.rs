@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::minimap::{map_to_world, world_to_map};
+
+const WORLD: f32 = 2000.0;
+
+#[test]
+fn world_and_map_positions_round_trip() {
+    assert_eq!(world_to_map(WORLD, Vec2::ZERO), Some(Vec2::splat(0.5)));
+    assert_eq!(world_to_map(WORLD, Vec2::new(-1000.0, 1000.0)), Some(Vec2::new(0.0, 1.0)));
+    for xz in [Vec2::new(-640.0, 250.0), Vec2::new(999.0, -999.0), Vec2::new(12.5, 0.0)] {
+        let uv = world_to_map(WORLD, xz).unwrap();
+        assert!(map_to_world(WORLD, uv).distance(xz) < 1e-3, "{xz}");
+    }
+    for uv in [Vec2::ZERO, Vec2::new(0.25, 0.8), Vec2::ONE] {
+        assert!(world_to_map(WORLD, map_to_world(WORLD, uv)).unwrap().distance(uv) < 1e-6, "{uv}");
+    }
+}
+
+#[test]
+fn positions_off_the_heightmap_have_no_map_spot() {
+    assert_eq!(world_to_map(WORLD, Vec2::new(1000.5, 0.0)), None);
+    assert_eq!(world_to_map(WORLD, Vec2::new(0.0, -1001.0)), None);
+    assert_eq!(world_to_map(WORLD, Vec2::splat(5000.0)), None);
+}
@@ -0,0 +1,76 @@
+// Networked multiplayer (feature `net`): the wire format, host hole decisions and a loopback session.
+#![cfg(feature = "net")]
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use vibe_golf::plugins::net::{HoleReferee, NetLink, NetMessage, HOST_ID};
+
+#[test]
+fn messages_round_trip_as_json() {
+    let messages = [
+        NetMessage::Hello { level: "level1".into() },
+        NetMessage::Ball { id: 2, pos: (1.0, 2.5, -3.0), hole: 1 },
+        NetMessage::Targets { hole: 0, positions: vec![(10.0, 5.0, 20.0)] },
+        NetMessage::Awarded { hole: 3, winner: 1 },
+    ];
+    for msg in messages {
+        assert_eq!(NetMessage::decode(&msg.encode()), Some(msg));
+    }
+    assert_eq!(NetMessage::decode("{\"Nope\":{}}"), None);
+}
+
+#[test]
+fn first_valid_claim_wins_the_hole() {
+    let mut referee = HoleReferee::default();
+    referee.record_targets(0, vec![Vec3::new(100.0, 5.0, 0.0)]);
+    // Nowhere near the host's target: rejected, the hole stays open.
+    assert!(!referee.claim(0, 1, Vec3::ZERO));
+    assert!(referee.claim(0, 2, Vec3::new(95.0, 4.0, 0.0)));
+    assert!(!referee.claim(0, HOST_ID, Vec3::new(100.0, 5.0, 0.0)));
+    assert_eq!((referee.wins(1), referee.wins(2), referee.wins(HOST_ID)), (0, 1, 0));
+}
+
+#[test]
+fn claims_ahead_of_the_host_wait_for_its_targets() {
+    let mut referee = HoleReferee::default();
+    // Hole 1 has no targets on the host yet: nothing is awarded, the claims are held.
+    assert!(!referee.claim(1, 1, Vec3::ZERO));
+    assert!(!referee.claim(1, 2, Vec3::new(48.0, 0.0, 0.0)));
+    assert!(!referee.claim(1, 2, Vec3::new(50.0, 0.0, 0.0))); // one held claim per peer
+    assert!(referee.winners.is_empty());
+    // The host gets there: the first held claim near its target wins, the far one does not.
+    assert_eq!(referee.record_targets(1, vec![Vec3::new(50.0, 0.0, 0.0)]), Some(2));
+    assert_eq!((referee.wins(1), referee.wins(2)), (0, 1));
+    assert!(!referee.claim(1, 1, Vec3::new(50.0, 0.0, 0.0)));
+    // Nothing held for a hole: recording its targets awards nothing.
+    assert_eq!(referee.record_targets(2, vec![Vec3::ZERO]), None);
+    // A claim for a far-off hole that is nowhere near the host's layout never wins.
+    assert!(!referee.claim(7, 1, Vec3::splat(900.0)));
+    assert_eq!(referee.record_targets(7, vec![Vec3::ZERO]), None);
+    assert_eq!(referee.wins(1), 0);
+}
+
+fn wait_for(link: &NetLink, received: &mut Vec<(u8, NetMessage)>, count: usize) {
+    let start = Instant::now();
+    while received.len() < count && start.elapsed() < Duration::from_secs(5) {
+        received.extend(link.drain());
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn host_and_joiner_exchange_messages() {
+    let (host, joiner) = (NetLink::default(), NetLink::default());
+    host.host(47_917).unwrap();
+    joiner.join("ws://127.0.0.1:47917");
+    joiner.send_to(HOST_ID, &NetMessage::Hello { level: "level1".into() });
+
+    let mut at_host = Vec::new();
+    wait_for(&host, &mut at_host, 1);
+    assert_eq!(at_host, vec![(1, NetMessage::Hello { level: "level1".into() })]);
+
+    host.broadcast(&NetMessage::Welcome { id: 1, level: "level1".into() }, None);
+    let mut at_joiner = Vec::new();
+    wait_for(&joiner, &mut at_joiner, 1);
+    assert_eq!(at_joiner, vec![(HOST_ID, NetMessage::Welcome { id: 1, level: "level1".into() })]);
+}
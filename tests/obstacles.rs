@@ -0,0 +1,40 @@
+// Breakable obstacles: what a hit does to them and where a target guard stands.
+use bevy::math::Vec2;
+use vibe_golf::plugins::obstacles::{guard_layout, impact_damage, Health, ObstacleConfig, ObstacleKind};
+
+#[test]
+fn soft_knocks_do_no_damage() {
+    let cfg = ObstacleConfig::default();
+    assert_eq!(impact_damage(cfg.min_damage_speed * 0.5, &cfg), 0.0);
+    assert_eq!(impact_damage(-10.0, &cfg), 0.0, "moving away");
+    assert_eq!(impact_damage(20.0, &cfg), 20.0);
+}
+
+#[test]
+fn breaks_once_when_hit_points_run_out() {
+    let mut health = Health::new(ObstacleKind::Crate.hit_points());
+    assert!(!health.damage(18.0));
+    assert!((health.fraction() - 0.4).abs() < 1e-5);
+    assert!(health.damage(18.0), "second good hit breaks a crate");
+    assert_eq!(health.hp, 0.0);
+    assert!(!health.damage(18.0), "already broken");
+    assert!(ObstacleKind::Ice.hit_points() < ObstacleKind::Crate.hit_points(), "ice is brittle");
+}
+
+#[test]
+fn guard_stands_across_the_line_in_front_of_the_target() {
+    let tee = Vec2::new(0.0, 0.0);
+    let target = Vec2::new(100.0, 0.0);
+    let row = guard_layout(tee, target, 14.0, 3, 4.0);
+    assert_eq!(row.len(), 3);
+    let xs: Vec<f32> = row.iter().map(|(p, _)| p.x).collect();
+    assert!(xs.iter().all(|x| (x - 86.0).abs() < 1e-4), "14 m short of the target: {xs:?}");
+    let zs: Vec<f32> = row.iter().map(|(p, _)| p.y).collect();
+    assert!((zs[0] + zs[2]).abs() < 1e-4 && zs[1].abs() < 1e-4, "centred on the line: {zs:?}");
+    assert!((zs[0] - zs[2]).abs() > 7.9, "side by side: {zs:?}");
+
+    // Never closer to the tee than half way, and nothing for a tee on the target.
+    let short = guard_layout(Vec2::new(90.0, 0.0), target, 14.0, 1, 4.0);
+    assert!((short[0].0.x - 95.0).abs() < 1e-4);
+    assert!(guard_layout(target, target, 14.0, 3, 4.0).is_empty());
+}
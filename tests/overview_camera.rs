@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::overview_camera::{frame_overview, OverviewCameraConfig, OverviewFraming};
+
+// Is `p` inside the view frustum of a camera at `t` (vertical fov / aspect from `cfg`)?
+fn in_view(cfg: &OverviewCameraConfig, t: &Transform, p: Vec3) -> bool {
+    let local = t.compute_matrix().inverse().transform_point3(p);
+    let depth = -local.z;
+    let half_y = (cfg.fov_deg.to_radians() * 0.5).tan();
+    depth > 0.0 && (local.x / depth).abs() <= half_y * cfg.aspect() && (local.y / depth).abs() <= half_y
+}
+
+#[test]
+fn both_framings_keep_ball_and_target_in_view() {
+    let cases = [
+        (Vec3::new(0.0, 80.0, 0.0), Vec3::new(600.0, 95.0, 0.0)),
+        (Vec3::new(-120.0, 60.0, 300.0), Vec3::new(250.0, 140.0, -180.0)),
+        (Vec3::new(10.0, 50.0, 10.0), Vec3::new(14.0, 52.0, 7.0)),
+    ];
+    for framing in [OverviewFraming::TopDown, OverviewFraming::Wide] {
+        let cfg = OverviewCameraConfig { framing, ..Default::default() };
+        for (ball, target) in cases {
+            let t = frame_overview(&cfg, ball, target);
+            assert!(in_view(&cfg, &t, ball), "{framing:?}: ball {ball} off screen");
+            assert!(in_view(&cfg, &t, target), "{framing:?}: target {target} off screen");
+            assert!(t.translation.y > ball.y.max(target.y), "{framing:?}: camera above both");
+        }
+    }
+}
+
+#[test]
+fn top_down_runs_the_shot_across_the_wide_axis() {
+    let cfg = OverviewCameraConfig::default();
+    let (ball, target) = (Vec3::new(0.0, 70.0, 0.0), Vec3::new(300.0, 70.0, 400.0));
+    let t = frame_overview(&cfg, ball, target);
+    assert!(t.forward().dot(Vec3::NEG_Y) > 0.999, "looks straight down");
+    let along = (target - ball).normalize();
+    assert!(t.right().dot(along).abs() > 0.999, "ball-target line is horizontal on screen");
+    // Longer shots pull the camera further out.
+    let far = frame_overview(&cfg, ball, ball + (target - ball) * 2.0);
+    assert!(far.translation.y - 70.0 > (t.translation.y - 70.0) * 1.9);
+    assert_eq!(cfg.framing.next().next(), cfg.framing);
+}
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::state::state::StateTransition;
+use vibe_golf::plugins::app_state::AppState;
+use vibe_golf::plugins::pause_menu::escape_transition;
+use vibe_golf::prelude::*;
+
+#[test]
+fn escape_pauses_a_live_run_and_resumes_a_paused_one() {
+    assert_eq!(escape_transition(AppState::Playing, true), AppState::Paused);
+    assert_eq!(escape_transition(AppState::Paused, true), AppState::Playing);
+    assert_eq!(escape_transition(AppState::Paused, false), AppState::Playing);
+    // Game over / photo mode: nothing to pause; the menu ignores Escape.
+    assert_eq!(escape_transition(AppState::Playing, false), AppState::Playing);
+    assert_eq!(escape_transition(AppState::Menu, true), AppState::Menu);
+    assert!(AppState::Paused.in_game() && !AppState::Menu.in_game());
+}
+
+#[test]
+fn simulation_tick_stops_while_paused() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin)).add_plugins(CoreSimPlugin).insert_state(AppState::Playing);
+    for _ in 0..3 { app.world_mut().run_schedule(FixedUpdate); }
+    app.world_mut().resource_mut::<NextState<AppState>>().set(AppState::Paused);
+    app.world_mut().run_schedule(StateTransition);
+    for _ in 0..5 { app.world_mut().run_schedule(FixedUpdate); }
+    assert_eq!(app.world().resource::<SimState>().tick, 3);
+    app.world_mut().resource_mut::<NextState<AppState>>().set(AppState::Playing);
+    app.world_mut().run_schedule(StateTransition);
+    app.world_mut().run_schedule(FixedUpdate);
+    assert_eq!(app.world().resource::<SimState>().tick, 4);
+}
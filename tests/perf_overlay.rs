@@ -0,0 +1,19 @@
+use vibe_golf::plugins::perf_overlay::{frame_time_color, FrameTimeHistory};
+
+#[test]
+fn history_keeps_the_latest_frames() {
+    let mut h = FrameTimeHistory { cap: 3, ..Default::default() };
+    assert_eq!(h.stats(), (0.0, 0.0));
+    for ms in [10.0, 20.0, 30.0, 40.0] {
+        h.push(ms);
+    }
+    assert_eq!(h.samples.iter().copied().collect::<Vec<_>>(), vec![20.0, 30.0, 40.0]);
+    assert_eq!(h.stats(), (30.0, 40.0));
+}
+
+#[test]
+fn bars_are_colored_by_frame_budget() {
+    assert_eq!(frame_time_color(16.6), frame_time_color(5.0));
+    assert_ne!(frame_time_color(25.0), frame_time_color(16.0));
+    assert_ne!(frame_time_color(50.0), frame_time_color(25.0));
+}
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::photo_mode::{photo_filename, photo_rotation};
+
+#[test]
+fn photo_filenames_are_utc_timestamps() {
+    assert_eq!(photo_filename(0), "photo_19700101_000000.png");
+    // 2000-02-29 12:34:56 UTC (leap day).
+    assert_eq!(photo_filename(951_827_696), "photo_20000229_123456.png");
+    // 2026-10-16 23:59:59 UTC.
+    assert_eq!(photo_filename(1_792_195_199), "photo_20261016_235959.png");
+}
+
+#[test]
+fn roll_turns_the_horizon_but_keeps_the_view_direction() {
+    let level = photo_rotation(0.7, -0.2, 0.0);
+    let rolled = photo_rotation(0.7, -0.2, 0.5);
+    assert!((level * Vec3::NEG_Z).distance(rolled * Vec3::NEG_Z) < 1e-5);
+    let (up_a, up_b) = (level * Vec3::Y, rolled * Vec3::Y);
+    assert!((up_a.angle_between(up_b) - 0.5).abs() < 1e-4);
+}
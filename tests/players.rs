@@ -0,0 +1,42 @@
+// Hot-seat turns: stroke / hole bookkeeping, parked balls and the final ranking.
+use bevy::prelude::*;
+use vibe_golf::prelude::*;
+
+#[test]
+fn turns_rotate_and_park_each_ball_where_it_stopped() {
+    let mut players = LocalPlayers::new(3);
+    assert!(players.hot_seat() && !LocalPlayers::new(1).hot_seat());
+    players.credit_stroke();
+    assert_eq!(players.end_turn(Vec2::new(5.0, 1.0)), None); // player 2 starts on the tee
+    players.credit_stroke();
+    players.end_turn(Vec2::new(7.0, 2.0));
+    players.credit_stroke();
+    // Back to player 1, at their own ball.
+    assert_eq!(players.end_turn(Vec2::new(9.0, 3.0)), Some(Vec2::new(5.0, 1.0)));
+    assert_eq!(players.active, 0);
+    assert!(players.players.iter().all(|p| p.strokes == 1));
+    assert_eq!(LocalPlayers::new(9).players.len(), 4);
+    assert_eq!(LocalPlayers::new(4).cycled_count(), 1);
+}
+
+#[test]
+fn winning_a_hole_sends_everybody_to_the_new_tee() {
+    let mut players = LocalPlayers::new(2);
+    players.credit_stroke();
+    players.end_turn(Vec2::new(3.0, 3.0));
+    players.credit_stroke();
+    players.credit_hole(2);
+    assert_eq!(players.end_turn(Vec2::new(8.0, 8.0)), None);
+    assert!(players.players.iter().all(|p| p.ball.is_none()));
+    assert_eq!((players.players[1].holes, players.players[1].points), (1, 2));
+}
+
+#[test]
+fn standings_rank_holes_then_points_then_strokes() {
+    let mut players = LocalPlayers::new(3);
+    players.players[0] = PlayerScore { strokes: 5, holes: 1, points: 1, ball: None };
+    players.players[1] = PlayerScore { strokes: 4, holes: 1, points: 1, ball: None };
+    players.players[2] = PlayerScore { strokes: 9, holes: 2, points: 2, ball: None };
+    assert_eq!(players.standings(), vec![2, 1, 0]);
+    assert!(players.summary().starts_with("Winner: Player 3 | P3 2 holes, 9 strokes | P2 1 hole"));
+}
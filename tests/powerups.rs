@@ -0,0 +1,52 @@
+// Powerups: the inventory's arming cycle and spending, and where procedural pickups go.
+use bevy::math::Vec2;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use vibe_golf::plugins::powerups::{scatter_spots, PowerupInventory, PowerupKind};
+
+#[test]
+fn inventory_holds_up_to_capacity() {
+    let mut inv = PowerupInventory::default();
+    assert!(inv.add(PowerupKind::Magnet, 2));
+    assert!(inv.add(PowerupKind::Sticky, 2));
+    assert!(!inv.add(PowerupKind::ExtraPower, 2), "full");
+    assert_eq!(inv.items, vec![PowerupKind::Magnet, PowerupKind::Sticky]);
+}
+
+#[test]
+fn arming_cycles_and_the_shot_spends_it() {
+    let mut inv = PowerupInventory::default();
+    inv.cycle_armed();
+    assert_eq!(inv.armed, None, "nothing to arm");
+    inv.add(PowerupKind::ExtraPower, 3);
+    inv.add(PowerupKind::BulletTime, 3);
+    inv.cycle_armed();
+    assert_eq!(inv.armed_kind(), Some(PowerupKind::ExtraPower));
+    inv.cycle_armed();
+    assert_eq!(inv.armed_kind(), Some(PowerupKind::BulletTime));
+    inv.cycle_armed();
+    assert_eq!(inv.armed_kind(), None, "past the last one: none");
+
+    // A shot without an armed powerup spends nothing.
+    assert_eq!(inv.spend_armed(), None);
+    assert_eq!(inv.items.len(), 2);
+
+    inv.cycle_armed();
+    inv.cycle_armed();
+    assert_eq!(inv.spend_armed(), Some(PowerupKind::BulletTime));
+    assert_eq!(inv.items, vec![PowerupKind::ExtraPower]);
+    assert_eq!((inv.armed, inv.active), (None, Some(PowerupKind::BulletTime)));
+}
+
+#[test]
+fn scattered_spots_lie_along_the_hole() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let (from, to) = (Vec2::ZERO, Vec2::new(200.0, 0.0));
+    let spots = scatter_spots(from, to, 4, 30.0, &mut rng);
+    assert_eq!(spots.len(), 4);
+    for p in &spots {
+        assert!(p.x >= 40.0 && p.x <= 160.0, "middle of the way: {p}");
+        assert!(p.y.abs() <= 30.0, "within the spread: {p}");
+    }
+    assert!(spots.windows(2).all(|w| w[0].x < w[1].x), "in order from the tee");
+}
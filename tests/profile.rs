@@ -0,0 +1,53 @@
+use bevy::prelude::Vec3;
+use vibe_golf::plugins::game_mode::GameMode;
+use vibe_golf::plugins::game_state::{update_high_score, Score};
+use vibe_golf::plugins::profile::{Profile, PROFILE_VERSION};
+
+#[test]
+fn legacy_files_migrate_into_the_profile() {
+    let settings = "(tutorial_completed: true, last_level: Some(\"course1\"))";
+    let profile = Profile::from_legacy(Some("level1 98.5\ncourse1 301\n"), Some(settings));
+    assert_eq!(profile.version, PROFILE_VERSION);
+    assert_eq!(profile.best("course1"), Some(301.0));
+    assert!(profile.settings.tutorial_completed);
+    assert_eq!(profile.settings.last_level.as_deref(), Some("course1"));
+    // Unreadable legacy settings fall back to defaults rather than losing the times.
+    let partial = Profile::from_legacy(Some("77"), Some("not ron"));
+    assert_eq!(partial.best("level1"), Some(77.0));
+    assert!(!partial.settings.tutorial_completed);
+}
+
+#[test]
+fn json_round_trips_and_tolerates_old_versions() {
+    let mut profile = Profile::default();
+    profile.record_best("level1@strokes", 14.0);
+    profile.stats.shots = 321;
+    profile.stats.record_drive(Vec3::ZERO, Vec3::new(30.0, 5.0, 40.0));
+    let loaded = Profile::from_json(&profile.to_json()).unwrap();
+    assert_eq!(loaded, profile);
+    assert_eq!(loaded.stats.longest_drive, 50.0);
+
+    let old = Profile::from_json(r#"{ "version": 0, "best": { "level1": 99.0 } }"#).unwrap();
+    assert_eq!(old.version, PROFILE_VERSION);
+    assert_eq!(old.best("level1"), Some(99.0));
+    assert_eq!(old.stats.shots, 0);
+}
+
+#[test]
+fn records_only_improve() {
+    let mut profile = Profile::default();
+    assert!(profile.record_best("level1", 120.0));
+    assert!(!profile.record_best("level1", 130.0));
+    assert!(profile.record_best("level1", 110.0));
+    assert_eq!(profile.best("level1"), Some(110.0));
+}
+
+#[test]
+fn finished_rounds_update_the_mode_record() {
+    let mut profile = Profile::default();
+    let mut score = Score { mode: GameMode::StrokePlay, shots: 11, final_time: 200.0, high_score_time: None, ..Default::default() };
+    update_high_score(&mut score, &mut profile);
+    assert_eq!(profile.best("level1@strokes"), Some(11.0));
+    assert_eq!(profile.best("level1"), None);
+    assert_eq!(score.high_score_time, Some(11.0));
+}
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::game_mode::GameMode;
+use vibe_golf::plugins::level::LevelManifest;
+use vibe_golf::plugins::replay::{Replay, ReplayFrame, ReplayShot};
+
+fn frame(tick: u64, ball: (f32, f32, f32), target: (f32, f32, f32)) -> ReplayFrame {
+    ReplayFrame { tick, ball, camera_pos: (0.0, 10.0, 0.0), camera_rot: (0.0, 0.0, 0.0, 1.0), targets: vec![target] }
+}
+
+fn replay() -> Replay {
+    let mut replay = Replay::new("a", "a", GameMode::StrokePlay, 3);
+    replay.frames = vec![
+        frame(60, (0.0, 0.0, 0.0), (100.0, 5.0, 0.0)),
+        frame(66, (6.0, 0.0, 0.0), (100.0, 5.0, 0.0)),
+        // Hole 1 finished at tick 66: the target relocated 200 m away.
+        frame(72, (8.0, 0.0, 0.0), (-100.0, 5.0, 0.0)),
+        frame(660, (8.0, 0.0, 0.0), (-100.0, 5.0, 0.0)),
+    ];
+    replay.shots = vec![ReplayShot { tick: 60, pos: (0.0, 0.0, 0.0), power: 0.5 }];
+    replay.hole_ticks = vec![66, 600];
+    replay
+}
+
+#[test]
+fn samples_interpolate_motion_but_not_cuts() {
+    let replay = replay();
+    assert_eq!(replay.duration(), 10.0);
+    let s = replay.sample(0.05).unwrap();
+    assert!((s.ball.x - 3.0).abs() < 1e-4);
+    // Halfway through the relocation the target is still at its old spot.
+    let s = replay.sample(0.15).unwrap();
+    assert_eq!(s.targets[0], Vec3::new(100.0, 5.0, 0.0));
+    assert_eq!(replay.sample(0.2).unwrap().targets[0], Vec3::new(-100.0, 5.0, 0.0));
+    assert!(Replay::new("a", "a", GameMode::TimeAttack, 1).sample(0.0).is_none());
+}
+
+#[test]
+fn hole_skipping_follows_finished_holes() {
+    let replay = replay();
+    assert_eq!(replay.hole_at(0.0), 1);
+    assert_eq!(replay.hole_at(0.1), 2);
+    assert_eq!(replay.hole_at(9.5), 3);
+    assert_eq!(replay.next_hole_time(0.0), Some(0.1));
+    assert_eq!(replay.next_hole_time(5.0), Some(9.0));
+    assert_eq!(replay.next_hole_time(9.5), None);
+    // Back to the current hole's start, or the one before right after a hole began.
+    assert_eq!(replay.previous_hole_time(5.0), 0.1);
+    assert_eq!(replay.previous_hole_time(0.5), 0.0);
+    assert_eq!(replay.shots_at(5.0), 1);
+}
+
+#[test]
+fn replays_round_trip_and_need_their_level() {
+    let replay = replay();
+    assert_eq!(Replay::from_ron(&replay.to_ron()).unwrap(), replay);
+    let manifest = LevelManifest::from_ron(
+        r#"(levels: [
+            (id: "b", name: "B", file: "levels/b.ron", heightmap: "h.png"),
+            (id: "a", name: "A", file: "levels/a.ron", heightmap: "h.png"),
+        ])"#,
+    )
+    .unwrap();
+    assert_eq!(replay.level_index(&manifest), Some(1));
+    let other = Replay { level: "gone".into(), ..replay.clone() };
+    assert_eq!(other.level_index(&manifest), None);
+}
@@ -0,0 +1,47 @@
+// Rivers: sources on the high ground, downhill tracing, waterfalls and the hazard area.
+use bevy::math::{Vec2, Vec3};
+use vibe_golf::plugins::level::LevelDef;
+use vibe_golf::plugins::rivers::{pick_sources, trace_downhill, River, RiverDef};
+
+// A cone peaking at the origin (100 m), dropping 0.5 m per m outwards.
+fn cone(x: f32, z: f32) -> f32 {
+    100.0 - 0.5 * Vec2::new(x, z).length()
+}
+
+#[test]
+fn sources_are_high_and_spread_out() {
+    let sources = pick_sources(cone, 200.0, 50.0, 3);
+    assert_eq!(sources.len(), 3);
+    assert!(sources[0].length() < 1e-3, "the peak comes first");
+    assert!(sources.iter().enumerate().all(|(i, a)| sources[i + 1..].iter().all(|b| a.distance(*b) >= 50.0)));
+}
+
+#[test]
+fn water_runs_downhill_into_the_lake_or_stops() {
+    let path = trace_downhill(cone, Vec2::new(10.0, 0.0), 4.0, 500, 25.0, |_| false);
+    assert!(path.windows(2).all(|w| w[1].y < w[0].y));
+    assert!(path.last().unwrap().y < 25.0, "ends in the lake");
+    assert!(path.last().unwrap().x > 150.0, "straight down the slope");
+    let blocked = trace_downhill(cone, Vec2::new(10.0, 0.0), 4.0, 500, 25.0, |p| p.x > 50.0);
+    assert!(blocked.last().unwrap().x <= 50.0);
+    // A flat plain is a hollow: nowhere to go.
+    assert_eq!(trace_downhill(|_, _| 40.0, Vec2::ZERO, 4.0, 500, 25.0, |_| false).len(), 1);
+}
+
+#[test]
+fn waterfalls_hazard_area_and_level_opt_in() {
+    let river = River {
+        points: vec![Vec3::new(0.0, 50.0, 0.0), Vec3::new(4.0, 49.0, 0.0), Vec3::new(8.0, 40.0, 0.0), Vec3::new(12.0, 39.5, 0.0)],
+        width: 4.0,
+    };
+    assert_eq!(river.waterfalls(0.9), vec![1]);
+    assert!(river.contains(Vec2::new(6.0, 1.9)));
+    assert!(!river.contains(Vec2::new(6.0, 2.5)));
+    assert!(!river.contains(Vec2::new(15.0, 0.0)));
+
+    let course = ron::from_str::<LevelDef>(&std::fs::read_to_string("assets/levels/course1.ron").unwrap()).unwrap();
+    assert_eq!(course.rivers.map(|r| r.count), Some(4));
+    let level1 = ron::from_str::<LevelDef>(&std::fs::read_to_string("assets/levels/level1.ron").unwrap()).unwrap();
+    assert_eq!(level1.rivers, None);
+    assert_eq!(ron::from_str::<RiverDef>("(width: 6.0)").unwrap(), RiverDef { width: 6.0, ..RiverDef::default() });
+}
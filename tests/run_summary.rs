@@ -0,0 +1,51 @@
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use vibe_golf::plugins::core_sim::{AutoConfig, CoreSimPlugin};
+use vibe_golf::plugins::game_state::Score;
+use vibe_golf::plugins::run_summary::{LodCounts, RunSummaryPlugin, ScoreSummary, Timings, RUN_SUMMARY_JSON};
+use vibe_golf::plugins::storage::StoragePaths;
+
+#[test]
+fn lod_counts_and_timings() {
+    let lod = LodCounts::from_resolutions([96, 96, 48, 24, 24, 24, 12]);
+    assert_eq!(lod, LodCounts { res96: 2, res48: 1, res24: 3, other: 1 });
+    let t = Timings::new(30.0, 1800, 12.5, 750);
+    assert!((t.avg_fps - 60.0).abs() < 1e-4);
+    assert_eq!(Timings::new(0.0, 0, 0.0, 0).avg_fps, 0.0);
+}
+
+fn run(exit_enabled: bool, name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("vibe_golf_run_summary_{name}_{}", std::process::id()));
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .insert_resource(AutoConfig { exit_enabled, ..default() })
+        .insert_resource(StoragePaths::new(&dir))
+        .insert_resource(Score { level_id: "island".into(), shots: 4, hits: 2, max_holes: 3, ..default() })
+        .add_plugins((CoreSimPlugin, RunSummaryPlugin));
+    app.update();
+    app.world_mut().send_event(AppExit::Success);
+    app.update();
+    dir
+}
+
+#[test]
+fn summary_is_written_on_exit_with_runtime() {
+    let dir = run(true, "on");
+    let json = std::fs::read_to_string(dir.join(RUN_SUMMARY_JSON)).expect("run_summary.json written");
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["score"]["level"], "island");
+    assert_eq!(value["score"]["shots"], 4);
+    assert_eq!(value["terrain"]["chunks"], 0);
+    assert!(value["timings"]["frames"].as_u64().unwrap() >= 1);
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let dir = run(false, "off");
+    assert!(!dir.join(RUN_SUMMARY_JSON).exists(), "interactive runs write no summary");
+}
+
+#[test]
+fn score_summary_copies_the_round() {
+    let score = Score { points: 7, game_over: true, final_time: 42.5, ..default() };
+    let s = ScoreSummary::from_score(&score);
+    assert_eq!((s.points, s.game_over, s.final_time), (7, true, 42.5));
+}
@@ -0,0 +1,62 @@
+use vibe_golf::plugins::game_mode::GameMode;
+use vibe_golf::plugins::game_state::Score;
+use vibe_golf::plugins::level::LevelManifest;
+use vibe_golf::plugins::save_game::{RoundSave, SavedTarget};
+
+fn save(level: &str, score_id: &str) -> RoundSave {
+    RoundSave {
+        level: level.into(),
+        score_id: score_id.into(),
+        elapsed_seconds: 1234.5,
+        hits: 3,
+        points: 7,
+        shots: 11,
+        time_adjustment: -8.0,
+        combo: 2,
+        combo_timer: 12.0,
+        best_combo: 2,
+        shots_at_last_hit: 10,
+        ball_pos: (10.0, 80.0, -20.0),
+        ball_vel: (1.0, 0.0, 2.0),
+        tee: (5.0, -15.0),
+        penalty_strokes: 1,
+        targets: vec![SavedTarget { index: 0, x: 300.0, z: 120.0, base_height: 4.8 }],
+        mode: GameMode::HardPar,
+    }
+}
+
+fn manifest() -> LevelManifest {
+    LevelManifest::from_ron(
+        r#"(levels: [
+            (id: "a", name: "A", file: "levels/a.ron", heightmap: "h.png"),
+            (id: "daily", name: "Daily", file: "levels/a.ron", heightmap: "h.png", daily: true),
+        ])"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn round_save_round_trips_through_ron() {
+    let s = save("a", "a");
+    assert_eq!(RoundSave::from_ron(&s.to_ron()).unwrap(), s);
+}
+
+#[test]
+fn restoring_brings_back_the_counters() {
+    let mut score = Score { high_score_time: None, game_over: true, max_holes: 5, ..Default::default() };
+    save("a", "a").restore_score(&mut score);
+    assert_eq!((score.hits, score.points, score.shots, score.combo), (3, 7, 11, 2));
+    assert_eq!(score.time_adjustment, -8.0);
+    assert!(!score.game_over);
+    assert_eq!(score.max_holes, 5, "the hole limit comes from the level");
+}
+
+#[test]
+fn only_listed_levels_and_today_resume() {
+    let manifest = manifest();
+    assert_eq!(save("a", "a").resume_index(&manifest), Some(0));
+    assert_eq!(save("gone", "gone").resume_index(&manifest), None);
+    let today = manifest.levels[1].score_id();
+    assert_eq!(save("daily", &today).resume_index(&manifest), Some(1));
+    assert_eq!(save("daily", "daily-2000-01-01").resume_index(&manifest), None, "yesterday's layout is gone");
+}
@@ -0,0 +1,40 @@
+use vibe_golf::plugins::scoreboard::HoleHistory;
+
+#[test]
+fn holes_record_strokes_time_and_distance_since_the_last_hole() {
+    let mut h = HoleHistory::default();
+    h.add_travel(120.0);
+    h.add_travel(30.5);
+    let first = h.complete_hole(3, 42.0, 10);
+    assert_eq!((first.hole, first.strokes, first.points), (1, 3, 10));
+    assert!((first.time - 42.0).abs() < 1e-5 && (first.distance - 150.5).abs() < 1e-4);
+
+    h.add_travel(80.0);
+    let second = h.complete_hole(4, 50.0, 25);
+    assert_eq!((second.hole, second.strokes), (2, 1));
+    assert!((second.time - 8.0).abs() < 1e-5 && (second.distance - 80.0).abs() < 1e-4);
+
+    let total = h.totals();
+    assert_eq!((total.hole, total.strokes, total.points), (2, 4, 35));
+    assert!((total.time - 50.0).abs() < 1e-4);
+
+    h.reset();
+    assert!(h.holes.is_empty());
+    assert_eq!(h.complete_hole(2, 5.0, 1).strokes, 2);
+}
+
+#[test]
+fn table_has_a_row_per_hole_and_a_total() {
+    let mut h = HoleHistory::default();
+    let empty = h.table_columns();
+    assert_eq!(empty[0], "Hole\n-");
+    h.add_travel(99.6);
+    h.complete_hole(2, 12.34, 10);
+    h.complete_hole(5, 20.0, 20);
+    let cols = h.table_columns();
+    assert_eq!(cols[0], "Hole\n1\n2\nTotal");
+    assert_eq!(cols[1], "Strokes\n2\n3\n5");
+    assert_eq!(cols[2], "Time\n12.3s\n7.7s\n20.0s");
+    assert_eq!(cols[3], "Distance\n100m\n0m\n100m");
+    assert_eq!(cols[4], "Points\n10\n20\n30");
+}
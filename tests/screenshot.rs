@@ -0,0 +1,28 @@
+use std::path::Path;
+
+use vibe_golf::screenshot::{downscale, run_dir_name, sequence_frame_name, ScreenshotConfig};
+
+#[test]
+fn sequence_frames_are_numbered_in_a_run_directory() {
+    assert_eq!(sequence_frame_name(1), "frame_0001.png");
+    assert_eq!(sequence_frame_name(120), "frame_0120.png");
+    assert_eq!(run_dir_name(1_700_000_000), "run_1700000000");
+
+    let plain = ScreenshotConfig::new(true, Path::new("shots"));
+    assert!(plain.interval.is_none() && plain.sequence_dir.is_none());
+    let cfg = ScreenshotConfig::new(true, Path::new("shots")).with_sequence(Some(2.0), 0.5);
+    let dir = cfg.sequence_dir.unwrap();
+    assert_eq!(dir.parent(), Some(Path::new("shots")));
+    assert!(dir.file_name().unwrap().to_string_lossy().starts_with("run_"));
+}
+
+#[test]
+fn downscale_shrinks_frames() {
+    let (w, h) = (64, 32);
+    let rgb = vec![200u8; (w * h * 3) as usize];
+    let half = downscale(w, h, rgb.clone(), 0.5).unwrap();
+    assert_eq!(half.dimensions(), (32, 16));
+    assert_eq!(half.get_pixel(5, 5).0, [200, 200, 200]);
+    assert_eq!(downscale(w, h, rgb.clone(), 1.0).unwrap().dimensions(), (64, 32));
+    assert!(downscale(w, h + 1, rgb, 0.5).is_none());
+}
@@ -0,0 +1,52 @@
+use bevy::pbr::{CascadeShadowConfig, DirectionalLightShadowMap};
+use bevy::prelude::*;
+use vibe_golf::plugins::shadow_settings::{ShadowSettings, ShadowSettingsPlugin, MAX_SHADOW_DISTANCE};
+use vibe_golf::plugins::terrain_presets::{ActiveTerrainPreset, TerrainPreset};
+
+#[test]
+fn presets_scale_shadow_cost() {
+    let s: Vec<ShadowSettings> = TerrainPreset::ALL.into_iter().map(ShadowSettings::for_preset).collect();
+    assert!(!s[0].enabled, "Low turns the sun's shadows off");
+    assert!(s[1..].iter().all(|p| p.enabled));
+    assert!(s.windows(2).all(|w| w[0].map_resolution <= w[1].map_resolution && w[0].max_distance < w[1].max_distance));
+    // High is what the game rendered before the settings existed (Bevy's defaults).
+    let high = ShadowSettings::for_preset(TerrainPreset::High);
+    assert_eq!((high.map_resolution, high.max_distance, high.cascades), (2048, 1000.0, 4));
+    assert_eq!(ShadowSettings::default(), high);
+}
+
+#[test]
+fn menu_adjustments_stay_in_range() {
+    let mut s = ShadowSettings::default();
+    s.set_max_distance(1e6);
+    assert_eq!(s.max_distance, MAX_SHADOW_DISTANCE);
+    s.set_cascades(0);
+    assert_eq!(s.cascades, 1);
+    s.set_cascades(9);
+    assert_eq!(s.cascades, 4);
+    assert_eq!(s.next_resolution(), 4096);
+    assert_eq!(ShadowSettings::for_preset(TerrainPreset::Ultra).next_resolution(), 512, "4096 wraps to the smallest size");
+    assert_eq!(s.cascade_config().bounds.len(), 4);
+    s.cascades = 1;
+    assert_eq!(s.cascade_config().bounds, vec![s.max_distance]);
+}
+
+#[test]
+fn level_light_follows_the_selected_preset() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .init_resource::<DirectionalLightShadowMap>()
+        .insert_resource(ActiveTerrainPreset(Some(TerrainPreset::Medium)))
+        .add_plugins(ShadowSettingsPlugin);
+    let light = app.world_mut().spawn((DirectionalLight { shadows_enabled: true, ..default() }, CascadeShadowConfig::default())).id();
+    app.update();
+    let medium = ShadowSettings::for_preset(TerrainPreset::Medium);
+    assert_eq!(*app.world().resource::<ShadowSettings>(), medium);
+    assert_eq!(app.world().resource::<DirectionalLightShadowMap>().size, medium.map_resolution);
+    assert_eq!(app.world().get::<CascadeShadowConfig>(light).unwrap().bounds.len(), medium.cascades);
+
+    app.world_mut().resource_mut::<ActiveTerrainPreset>().0 = Some(TerrainPreset::Low);
+    app.update();
+    assert!(!app.world().get::<DirectionalLight>(light).unwrap().shadows_enabled);
+    assert_eq!(app.world().get::<CascadeShadowConfig>(light).unwrap().bounds.len(), 1);
+}
@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::spatial_grid::{distance_range_to_box, SpatialGrid};
+
+#[test]
+fn radius_queries_match_brute_force() {
+    let mut grid = SpatialGrid::new(5.0);
+    let pts: Vec<Vec2> = (0..400)
+        .map(|i| Vec2::new(((i * 37) % 101) as f32 - 50.0, ((i * 53) % 97) as f32 - 48.0))
+        .collect();
+    for (i, p) in pts.iter().enumerate() {
+        grid.insert(*p, i);
+    }
+    assert_eq!(grid.len(), pts.len());
+
+    for &(c, r) in &[(Vec2::ZERO, 7.5), (Vec2::new(-40.0, 30.0), 12.0), (Vec2::new(13.3, -2.1), 2.0)] {
+        let mut found = Vec::new();
+        grid.for_each_within(c, r, |_, i| found.push(*i));
+        found.sort();
+        let expected: Vec<usize> = (0..pts.len()).filter(|&i| pts[i].distance_squared(c) < r * r).collect();
+        assert_eq!(found, expected);
+        assert_eq!(grid.too_close(c, r), !expected.is_empty());
+
+        let nearest = grid.nearest(c, r).map(|(_, i)| *i);
+        let brute = expected.iter().copied().min_by(|&a, &b| pts[a].distance(c).total_cmp(&pts[b].distance(c)));
+        assert_eq!(nearest.map(|i| pts[i].distance(c)), brute.map(|i| pts[i].distance(c)));
+    }
+}
+
+#[test]
+fn remove_where_drops_only_the_matching_entry() {
+    let mut grid = SpatialGrid::new(4.0);
+    let p = Vec2::new(1.0, 1.0);
+    grid.insert(p, 'a');
+    grid.insert(p, 'b');
+    assert_eq!(grid.remove_where(p, |v| *v == 'a'), Some('a'));
+    assert_eq!(grid.remove_where(p, |v| *v == 'a'), None);
+    assert_eq!(grid.len(), 1);
+    assert_eq!(grid.nearest(p, 1.0).map(|(_, v)| *v), Some('b'));
+    assert!(!grid.too_close(Vec2::new(20.0, 20.0), 3.0));
+}
+
+#[test]
+fn box_distance_range_bounds_every_point() {
+    let (min, max) = (Vec3::new(0.0, 40.0, 0.0), Vec3::new(64.0, 55.0, 64.0));
+    for p in [Vec3::new(10.0, 50.0, 10.0), Vec3::new(-30.0, 60.0, 100.0), Vec3::new(200.0, 0.0, 32.0)] {
+        let (near, far) = distance_range_to_box(p, min, max);
+        assert!(near <= far);
+        for i in 0..=8 {
+            for j in 0..=8 {
+                for k in 0..=2 {
+                    let q = min + (max - min) * Vec3::new(i as f32 / 8.0, k as f32 / 2.0, j as f32 / 8.0);
+                    let d = q.distance(p);
+                    assert!(d >= near - 1e-3 && d <= far + 1e-3);
+                }
+            }
+        }
+    }
+    assert_eq!(distance_range_to_box(Vec3::new(10.0, 50.0, 10.0), min, max).0, 0.0);
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use bevy::render::primitives::Frustum;
+use vibe_golf::plugins::spawn_view::{next_by_priority, SpawnView, DEFERRED_PENALTY};
+
+// Camera at the origin looking down -Z.
+fn forward_view() -> SpawnView {
+    let proj = Mat4::perspective_rh(60f32.to_radians(), 16.0 / 9.0, 0.1, 3000.0);
+    let view = Mat4::look_at_rh(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+    SpawnView::new(Vec3::ZERO, Some(Frustum::from_clip_from_world(&(proj * view))))
+}
+
+#[test]
+fn sees_frustum_and_near_radius() {
+    let view = forward_view();
+    assert!(view.sees(Vec3::new(0.0, 0.0, -500.0), 10.0));
+    assert!(!view.sees(Vec3::new(0.0, 0.0, 500.0), 10.0));
+    // Behind the camera but close: still counts, so turning around shows no holes.
+    assert!(view.sees(Vec3::new(0.0, 0.0, 30.0), 10.0));
+    // Without a frustum everything is visible.
+    assert!(SpawnView::new(Vec3::ZERO, None).sees(Vec3::new(0.0, 0.0, 500.0), 1.0));
+}
+
+#[test]
+fn visible_work_sorts_before_deferred() {
+    let view = forward_view();
+    let far_ahead = view.priority(Vec3::new(0.0, 0.0, -1500.0), 32.0);
+    let behind = view.priority(Vec3::new(0.0, 0.0, 200.0), 32.0);
+    assert!(far_ahead < behind);
+    assert!(behind >= DEFERRED_PENALTY);
+    assert!(view.priority(Vec3::new(0.0, 0.0, -100.0), 32.0) < far_ahead);
+}
+
+#[test]
+fn next_by_priority_keeps_fifo_on_ties() {
+    assert_eq!(next_by_priority([3.0, 1.0, 2.0, 1.0], |k| *k), Some(1));
+    assert_eq!(next_by_priority([0.0, 0.0, 0.0], |k| *k), Some(0));
+    assert_eq!(next_by_priority(Vec::<f32>::new(), |k| *k), None);
+}
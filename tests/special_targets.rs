@@ -0,0 +1,30 @@
+use vibe_golf::plugins::game_state::Score;
+use vibe_golf::plugins::special_targets::SpecialTargetConfig;
+
+#[test]
+fn run_time_applies_penalties_and_bonuses() {
+    let mut score = Score::default();
+    assert_eq!(score.run_time(42.0), 42.0);
+
+    score.time_adjustment += 5.0; // decoy
+    assert_eq!(score.run_time(42.0), 47.0);
+
+    score.time_adjustment -= 8.0 * 2.0; // two golden targets
+    assert_eq!(score.run_time(42.0), 31.0);
+    assert_eq!(score.run_time(3.0), 0.0, "bonuses never push the clock below zero");
+}
+
+#[test]
+fn special_targets_parse_partial_ron() {
+    let cfg: SpecialTargetConfig =
+        ron::from_str("(decoys: [(10.0, -20.0)], bonus_enabled: false, decoy_penalty_seconds: 3.0)").unwrap();
+    assert_eq!(cfg.decoys, vec![(10.0, -20.0)]);
+    assert!(!cfg.bonus_enabled);
+    assert_eq!(cfg.decoy_penalty_seconds, 3.0);
+    let defaults = SpecialTargetConfig::default();
+    assert_eq!(cfg.bonus_seconds, defaults.bonus_seconds);
+    assert_eq!(cfg.decoy_model, defaults.decoy_model);
+
+    let empty: SpecialTargetConfig = ron::from_str("()").unwrap();
+    assert!(empty.decoys.is_empty() && empty.bonus_enabled);
+}
@@ -0,0 +1,30 @@
+// Spectator overlay: the camera cut order and the last-shot summary.
+use bevy::prelude::*;
+use vibe_golf::plugins::spectate::{ShotStats, ShotTracker, SpectateView};
+
+#[test]
+fn cuts_pick_the_ball_cam_only_in_flight() {
+    assert_eq!(SpectateView::Chase.next(true), SpectateView::Wide);
+    assert_eq!(SpectateView::Wide.next(true), SpectateView::BallCam);
+    assert_eq!(SpectateView::Wide.next(false), SpectateView::Flyby);
+    assert_eq!(SpectateView::BallCam.next(false), SpectateView::Chase);
+    assert_eq!(SpectateView::Flyby.next(true), SpectateView::Chase);
+}
+
+#[test]
+fn shot_summary_measures_carry_apex_and_time() {
+    let mut shot = ShotTracker::new(Vec3::new(0.0, 10.0, 0.0), 1.25);
+    for (i, y) in [14.0, 31.5, 22.0, 9.0].into_iter().enumerate() {
+        shot.sample(Vec3::new(i as f32 * 20.0, y, 0.0), 0.5);
+    }
+    let stats = shot.finish(Vec3::new(90.0, 8.0, 120.0));
+    assert_eq!(stats, ShotStats { distance: 150.0, apex: 21.5, time: 2.0, power: 1.25 });
+}
+
+#[test]
+fn downhill_shots_have_no_negative_apex() {
+    let mut shot = ShotTracker::new(Vec3::new(5.0, 40.0, 5.0), 0.5);
+    shot.sample(Vec3::new(5.0, 30.0, 25.0), 1.0);
+    let stats = shot.finish(Vec3::new(5.0, 20.0, 45.0));
+    assert_eq!((stats.apex, stats.distance), (0.0, 40.0));
+}
@@ -0,0 +1,39 @@
+use std::path::PathBuf;
+
+use vibe_golf::plugins::storage::{platform_data_dir, StoragePaths};
+
+fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+    move |name| vars.iter().find(|(k, _)| *k == name).map(|(_, v)| v.to_string())
+}
+
+#[test]
+fn platform_data_dirs_follow_os_conventions() {
+    let home = [("HOME", "/home/ann")];
+    assert_eq!(platform_data_dir("linux", env(&home)), Some(PathBuf::from("/home/ann/.local/share/vibe_golf")));
+    let xdg = [("HOME", "/home/ann"), ("XDG_DATA_HOME", "/data")];
+    assert_eq!(platform_data_dir("linux", env(&xdg)), Some(PathBuf::from("/data/vibe_golf")));
+    assert_eq!(
+        platform_data_dir("macos", env(&home)),
+        Some(PathBuf::from("/home/ann/Library/Application Support/vibe_golf"))
+    );
+    assert_eq!(platform_data_dir("windows", env(&[("APPDATA", "C:/Users/ann/AppData/Roaming")])), Some(PathBuf::from("C:/Users/ann/AppData/Roaming/vibe_golf")));
+    // Nothing to go on: callers fall back to the working directory.
+    assert_eq!(platform_data_dir("linux", env(&[("HOME", "")])), None);
+}
+
+#[test]
+fn an_explicit_data_dir_wins() {
+    assert_eq!(StoragePaths::resolve(Some("saves".into())).file("profile.json"), PathBuf::from("saves/profile.json"));
+}
+
+#[test]
+fn texts_round_trip_in_a_fresh_data_dir() {
+    let dir = std::env::temp_dir().join(format!("vibe_golf_storage_{}", std::process::id()));
+    let paths = StoragePaths::new(dir.join("nested"));
+    assert_eq!(paths.load_text("profile.json"), None);
+    paths.save_text("profile.json", "{}");
+    assert_eq!(paths.load_text("profile.json").as_deref(), Some("{}"));
+    paths.remove_text("profile.json");
+    assert_eq!(paths.load_text("profile.json"), None);
+    let _ = std::fs::remove_dir_all(dir);
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::hud::compass_distance;
+use vibe_golf::plugins::target_label::TargetLabelConfig;
+
+#[test]
+fn label_distance_matches_compass_ground_distance() {
+    let ball = Vec3::new(0.0, 2.0, 0.0);
+    let target = Vec3::new(30.0, 45.0, 40.0);
+    assert!((compass_distance(ball, target) - 50.0).abs() < 1e-4, "height is ignored");
+}
+
+#[test]
+fn label_fades_near_target_and_at_screen_centre() {
+    let cfg = TargetLabelConfig::default();
+    let full = cfg.alpha(500.0, 1.0);
+    assert!((full - cfg.max_alpha).abs() < 1e-5);
+    assert_eq!(cfg.alpha(cfg.near_fade.0, 1.0), 0.0);
+    assert_eq!(cfg.alpha(500.0, 0.0), 0.0);
+    let mid = cfg.alpha((cfg.near_fade.0 + cfg.near_fade.1) * 0.5, 1.0);
+    assert!(mid > 0.0 && mid < full);
+    assert!(cfg.alpha(500.0, 0.15) < cfg.alpha(500.0, 0.3));
+}
@@ -0,0 +1,20 @@
+use vibe_golf::plugins::target_proximity::ProximityConfig;
+
+#[test]
+fn heat_is_log_scaled_between_near_and_far() {
+    let cfg = ProximityConfig { near: 10.0, far: 1000.0, ..Default::default() };
+    assert_eq!(cfg.heat(0.0), 1.0);
+    assert_eq!(cfg.heat(10.0), 1.0);
+    assert!((cfg.heat(100.0) - 0.5).abs() < 1e-4, "halfway on a log scale");
+    assert_eq!(cfg.heat(1000.0), 0.0);
+    assert_eq!(cfg.heat(5000.0), 0.0);
+    assert!(cfg.heat(50.0) > cfg.heat(300.0));
+}
+
+#[test]
+fn hotter_means_faster_pulse_and_beeps() {
+    let cfg = ProximityConfig::default();
+    assert!(cfg.pulse_hz(1.0) > cfg.pulse_hz(0.0));
+    assert!(cfg.beep_interval(1.0) < cfg.beep_interval(0.0));
+    assert_eq!(cfg.beep_interval(0.0), cfg.beep_interval.0);
+}
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use vibe_golf::plugins::target_relocation::{find_relocation, RelocationConfig};
+use vibe_golf::plugins::terrain::TerrainSampler;
+
+mod common;
+
+const RES: u32 = 128;
+const WORLD: f32 = 2000.0;
+
+// Two flat plateaus (west: x < -150, east: x > 150) split by a 300 m sea channel, a steep ridge
+// on the west plateau at z > 500, and sea around the outside.
+fn islands() -> TerrainSampler {
+    common::world_sampler(WORLD, RES, |i, j| {
+        let x = (i as f32 / (RES - 1) as f32 - 0.5) * WORLD;
+        let z = (j as f32 / (RES - 1) as f32 - 0.5) * WORLD;
+        if x.abs() > 850.0 || z.abs() > 850.0 || x.abs() < 150.0 {
+            5
+        } else if x < 0.0 && z > 500.0 {
+            if (j % 2) == 0 { 200 } else { 60 }
+        } else {
+            80
+        }
+    })
+}
+
+#[test]
+fn strict_spots_are_on_land_reachable_and_in_bounds() {
+    let sampler = islands();
+    let cfg = RelocationConfig::default();
+    let ball = Vec3::new(-500.0, 81.0, -300.0);
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..30 {
+        let (p, ground, pass) =
+            find_relocation(&cfg, &sampler, Vec2::new(-500.0, -300.0), ball, (300.0, 600.0), 5.0, &mut rng)
+                .expect("the west plateau has room");
+        assert_eq!(pass, 0);
+        assert!(ground >= cfg.min_ground);
+        assert!(p.x.abs() <= WORLD * 0.5 - cfg.edge_margin && p.y.abs() <= WORLD * 0.5 - cfg.edge_margin);
+        assert!(p.x < -150.0, "not across the channel: {p}");
+        assert!(cfg.landing_slope_deg(&sampler, p) <= cfg.max_slope_deg, "not on the ridge: {p}");
+    }
+}
+
+#[test]
+fn checks_measure_sea_slope_and_sight() {
+    let sampler = islands();
+    let cfg = RelocationConfig::default();
+    let gap = cfg.longest_water_gap(&sampler, Vec2::new(-500.0, 0.0), Vec2::new(500.0, 0.0));
+    assert!((250.0..=360.0).contains(&gap), "channel is ~300 m: {gap}");
+    assert_eq!(cfg.longest_water_gap(&sampler, Vec2::new(-500.0, 0.0), Vec2::new(-300.0, 0.0)), 0.0);
+
+    assert!(cfg.landing_slope_deg(&sampler, Vec2::new(-500.0, 0.0)) < 1.0);
+    assert!(cfg.landing_slope_deg(&sampler, Vec2::new(-500.0, 700.0)) > cfg.max_slope_deg);
+
+    let eye = Vec3::new(-500.0, 85.0, 300.0);
+    assert!(cfg.line_of_sight(&sampler, eye, Vec3::new(-300.0, 85.0, 300.0)));
+    assert!(!cfg.line_of_sight(&sampler, eye, Vec3::new(-500.0, 85.0, 800.0)), "ridge blocks the view");
+
+    // Ball stranded west, target around the east plateau: only the loosest pass allows the crossing.
+    let mut rng = StdRng::seed_from_u64(3);
+    let (p, _, pass) = find_relocation(&cfg, &sampler, Vec2::new(450.0, 0.0), Vec3::new(-200.0, 81.0, 0.0), (100.0, 150.0), 5.0, &mut rng)
+        .expect("east plateau is land");
+    assert!(pass == 3 && p.x > 150.0, "{p} pass {pass}");
+}
@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::headless::HeadlessSim;
+use vibe_golf::plugins::target::{choose_focus, TargetBob, TargetFloat, TargetFocusMode, TargetInfo, TargetMotion, TargetPath};
+
+#[test]
+fn focus_picks_nearest_or_highest_value() {
+    let mut world = World::new();
+    let [near, far_valuable, far_cheap] = [(); 3].map(|_| world.spawn_empty().id());
+    let targets = [
+        (near, Vec3::new(10.0, 0.0, 0.0), 1),
+        (far_valuable, Vec3::new(0.0, 50.0, 300.0), 5),
+        (far_cheap, Vec3::new(-200.0, 0.0, 0.0), 1),
+    ];
+
+    assert_eq!(choose_focus(TargetFocusMode::Nearest, Vec3::ZERO, targets), Some(near));
+    assert_eq!(choose_focus(TargetFocusMode::HighestValue, Vec3::ZERO, targets), Some(far_valuable));
+    // Height is ignored: distance is measured on the ground plane.
+    assert_eq!(choose_focus(TargetFocusMode::Nearest, Vec3::new(0.0, 0.0, 290.0), targets), Some(far_valuable));
+    assert_eq!(choose_focus(TargetFocusMode::Nearest, Vec3::ZERO, []), None);
+}
+
+#[test]
+fn highest_value_ties_go_to_the_nearest() {
+    let mut world = World::new();
+    let [a, b] = [(); 2].map(|_| world.spawn_empty().id());
+    let targets = [(a, Vec3::new(100.0, 0.0, 0.0), 3), (b, Vec3::new(-20.0, 0.0, 0.0), 3)];
+    assert_eq!(choose_focus(TargetFocusMode::HighestValue, Vec3::ZERO, targets), Some(b));
+}
+
+#[test]
+fn flight_paths_start_at_the_anchor_and_loop() {
+    let patrol = TargetPath::Waypoints { points: vec![(0.0, 0.0), (100.0, 0.0), (100.0, 50.0)], speed: 10.0 };
+    assert_eq!(patrol.offset_at(0.0), Vec2::ZERO);
+    assert!(patrol.offset_at(5.0).distance(Vec2::new(50.0, 0.0)) < 1e-3);
+    assert!(patrol.offset_at(12.5).distance(Vec2::new(100.0, 25.0)) < 1e-3);
+    // Loop length 100 + 50 + ~111.8: wraps back to the start.
+    let lap = (100.0 + 50.0 + Vec2::new(100.0, 50.0).length()) / 10.0;
+    assert!(patrol.offset_at(lap + 5.0).distance(Vec2::new(50.0, 0.0)) < 1e-2);
+
+    let eight = TargetPath::FigureEight { radius: 40.0, period: 8.0 };
+    assert!(eight.offset_at(0.0).length() < 1e-4);
+    assert!(eight.offset_at(4.0).length() < 1e-3, "crosses the anchor mid-cycle");
+    assert!((eight.offset_at(2.0).x - 40.0).abs() < 1e-3);
+
+    let orbit = TargetPath::Orbit { radius: 25.0, period: 6.0 };
+    for t in [0.0, 1.3, 4.9] {
+        assert!((orbit.offset_at(t).length() - 25.0).abs() < 1e-3);
+    }
+    assert!(orbit.offset_at(0.0).distance(orbit.offset_at(6.0)) < 1e-3);
+
+    let motion = TargetMotion::new(orbit, Vec2::new(10.0, -5.0), 70.0);
+    assert!(motion.position().distance(Vec2::new(35.0, -5.0)) < 1e-3);
+}
+
+#[test]
+fn flight_paths_parse_from_level_ron() {
+    let patrol: TargetPath = ron::from_str("Waypoints(points: [(0.0, 0.0), (120.0, 0.0)], speed: 6.0)").unwrap();
+    assert_eq!(patrol, TargetPath::Waypoints { points: vec![(0.0, 0.0), (120.0, 0.0)], speed: 6.0 });
+    let orbit: TargetPath = ron::from_str("Orbit(radius: 25.0, period: 20.0)").unwrap();
+    assert_eq!(orbit, TargetPath::Orbit { radius: 25.0, period: 20.0 });
+}
+
+#[test]
+fn relocated_extra_targets_keep_their_own_float() {
+    let mut sim = HeadlessSim::flat(100.0);
+    sim.score_mut().max_holes = 5;
+    sim.spawn_ball(Vec2::ZERO);
+    // A second target (index 1) that bobs and spins unlike the primary's TargetParams (still).
+    let bob = TargetBob { amplitude: 0.5, bob_freq: 1.5, rot_speed: 2.0 };
+    let target = sim.spawn_target(1, Vec2::new(66.0, 0.0), 33.0, 4.5, 2);
+    sim.world_mut().get_mut::<TargetInfo>(target).unwrap().bob = bob;
+    bob.apply(&mut sim.world_mut().get_mut::<TargetFloat>(target).unwrap());
+
+    sim.shoot(Vec2::X, 1.0);
+    sim.step_until(600, |s| s.score().hits > 0).expect("target hit");
+    assert_ne!(sim.target_position(target).unwrap().xz(), Vec2::new(66.0, 0.0), "target relocated");
+    let float = sim.world().get::<TargetFloat>(target).unwrap();
+    assert_eq!((float.amplitude, float.bounce_freq, float.rot_speed), (0.5, 1.5, 2.0));
+    assert_eq!(float.base_height, 33.0);
+}
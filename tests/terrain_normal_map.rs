@@ -0,0 +1,30 @@
+use vibe_golf::plugins::terrain::bake_normal_map;
+
+mod common;
+
+const RES: u32 = 65;
+
+fn decode(image: &bevy::prelude::Image, i: u32, j: u32) -> [f32; 3] {
+    let w = image.texture_descriptor.size.width;
+    let o = ((j * w + i) * 4) as usize;
+    let d = |b: u8| b as f32 / 255.0 * 2.0 - 1.0;
+    [d(image.data[o]), d(image.data[o + 1]), d(image.data[o + 2])]
+}
+
+#[test]
+fn flat_terrain_bakes_up_normals() {
+    let s = common::heightmap_sampler(RES, |_, _| 40);
+    let img = bake_normal_map(&s, 32);
+    assert_eq!(img.texture_descriptor.size.width, 32);
+    let n = decode(&img, 16, 16);
+    assert!(n[0].abs() < 0.01 && (n[1] - 1.0).abs() < 0.01 && n[2].abs() < 0.01, "{:?}", n);
+}
+
+#[test]
+fn ramp_tilts_normal_away_from_rising_side() {
+    // Height rises toward +X -> normal leans toward -X.
+    let s = common::heightmap_sampler(RES, |x, _| (x * 2) as u8);
+    let img = bake_normal_map(&s, 32);
+    let n = decode(&img, 16, 16);
+    assert!(n[0] < -0.3 && n[1] > 0.3 && n[2].abs() < 0.02, "{:?}", n);
+}
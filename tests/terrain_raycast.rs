@@ -0,0 +1,52 @@
+// Terrain raycast checks against small in-memory heightmap fixtures.
+use bevy::prelude::*;
+
+mod common;
+
+const SIZE: u32 = 65;
+
+#[test]
+fn vertical_ray_hits_flat_ground() {
+    let s = common::heightmap_sampler(SIZE, |_, _| 100);
+    let hit = s.raycast(Vec3::new(0.0, 200.0, 0.0), Vec3::NEG_Y).expect("expected hit");
+    assert!((hit.position.y - 100.0).abs() < 0.05, "hit y={}", hit.position.y);
+    assert!((hit.distance - 100.0).abs() < 0.05, "distance={}", hit.distance);
+    assert!(hit.normal.y > 0.99);
+}
+
+#[test]
+fn oblique_ray_hits_flat_ground() {
+    let s = common::heightmap_sampler(SIZE, |_, _| 100);
+    let hit = s.raycast(Vec3::new(-20.0, 150.0, 0.0), Vec3::new(1.0, -1.0, 0.0)).expect("expected hit");
+    assert!((hit.position.x - 30.0).abs() < 0.1, "hit x={}", hit.position.x);
+    assert!((hit.distance - 50.0 * 2f32.sqrt()).abs() < 0.1);
+}
+
+#[test]
+fn upward_ray_misses() {
+    let s = common::heightmap_sampler(SIZE, |_, _| 100);
+    assert!(s.raycast(Vec3::new(0.0, 120.0, 0.0), Vec3::new(0.3, 1.0, 0.0)).is_none());
+}
+
+#[test]
+fn horizontal_ray_hits_ramp() {
+    // Height rises linearly along +X: h(x) = (x / 100 + 0.5) * 255.
+    let s = common::heightmap_sampler(SIZE, |x, _| ((x as f32 / (SIZE - 1) as f32) * 255.0).round() as u8);
+    let hit = s.raycast(Vec3::new(-50.0, 150.0, 0.0), Vec3::X).expect("expected hit");
+    let expected_x = (150.0 / 255.0 - 0.5) * 100.0;
+    assert!((hit.position.x - expected_x).abs() < 0.5, "hit x={} expected={}", hit.position.x, expected_x);
+    assert!(hit.normal.x < 0.0, "ramp normal should face -X");
+}
+
+#[test]
+fn origin_below_surface_is_immediate_hit() {
+    let s = common::heightmap_sampler(SIZE, |_, _| 100);
+    let hit = s.raycast(Vec3::new(0.0, 50.0, 0.0), Vec3::X).expect("expected hit");
+    assert_eq!(hit.distance, 0.0);
+}
+
+#[test]
+fn max_distance_limits_search() {
+    let s = common::heightmap_sampler(SIZE, |_, _| 100);
+    assert!(s.raycast_max(Vec3::new(0.0, 200.0, 0.0), Vec3::NEG_Y, 50.0).is_none());
+}
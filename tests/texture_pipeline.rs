@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::render::texture::CompressedImageFormats;
+use vibe_golf::plugins::texture_pipeline::{generate_mips, BlockFormat, CompressedTextures};
+
+#[test]
+fn format_follows_gpu_support() {
+    assert_eq!(BlockFormat::pick(CompressedImageFormats::all()), Some(BlockFormat::Bc));
+    assert_eq!(BlockFormat::pick(CompressedImageFormats::ASTC_LDR | CompressedImageFormats::ETC2), Some(BlockFormat::Astc));
+    assert_eq!(BlockFormat::pick(CompressedImageFormats::ETC2), Some(BlockFormat::Etc2));
+    assert_eq!(BlockFormat::pick(CompressedImageFormats::NONE), None);
+}
+
+#[test]
+fn unlisted_or_unsupported_textures_use_the_original() {
+    let list = CompressedTextures::from_ron(r#"(textures: { "skymap/sky_1k.hdr": [Bc] })"#).unwrap();
+    assert_eq!(list.resolve("skymap/sky_1k.hdr", Some(BlockFormat::Bc)), "skymap/compressed/sky_1k.bc.ktx2");
+    assert_eq!(list.resolve("skymap/sky_1k.hdr", Some(BlockFormat::Etc2)), "skymap/sky_1k.hdr");
+    assert_eq!(list.resolve("skymap/sky_1k.hdr", None), "skymap/sky_1k.hdr");
+    assert_eq!(list.resolve("models/tree.png", Some(BlockFormat::Bc)), "models/tree.png");
+    let shipped = std::fs::read_to_string("assets/textures/compressed.ron").unwrap();
+    assert!(CompressedTextures::from_ron(&shipped).is_ok());
+}
+
+#[test]
+fn mip_chain_averages_down_to_one_texel() {
+    let size = Extent3d { width: 4, height: 2, depth_or_array_layers: 1 };
+    let texels: Vec<u8> = (0..8u8).flat_map(|i| [i * 30, 0, 255, 255]).collect();
+    let mut image = Image::new(size, TextureDimension::D2, texels, TextureFormat::Rgba8Unorm, RenderAssetUsages::default());
+    assert!(generate_mips(&mut image));
+    assert_eq!(image.texture_descriptor.mip_level_count, 3);
+    // 4x2 + 2x1 + 1x1 texels
+    assert_eq!(image.data.len(), (8 + 2 + 1) * 4);
+    // Level 1 averages 2x2 blocks: red of texels 0,1,4,5 = (0+30+120+150)/4 = 75
+    assert_eq!(&image.data[32..36], &[75, 0, 255, 255]);
+    assert!(!generate_mips(&mut image), "already mipped");
+}
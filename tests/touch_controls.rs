@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::hud_layout::SafeAreaInsets;
+use vibe_golf::plugins::touch_controls::{control_at, control_rects, stick_vector, TouchControl, TouchControlsConfig};
+
+#[test]
+fn controls_hit_test_where_they_are_drawn() {
+    let cfg = TouchControlsConfig::default();
+    let window = Vec2::new(800.0, 600.0);
+    let rects = control_rects(&cfg, window, 1.0, SafeAreaInsets::default());
+    let rect = |c| rects.iter().find(|(k, _)| *k == c).unwrap().1;
+
+    let fire = rect(TouchControl::Fire);
+    assert!((fire.max.x - (800.0 - cfg.margin)).abs() < 1e-3);
+    assert_eq!(control_at(&rects, fire.center()), Some(TouchControl::Fire));
+    assert_eq!(control_at(&rects, rect(TouchControl::NudgeLeft).center()), Some(TouchControl::NudgeLeft));
+    assert!(rect(TouchControl::NudgeLeft).center().x < rect(TouchControl::NudgeRight).center().x);
+    assert_eq!(control_at(&rects, rect(TouchControl::Joystick).center()), Some(TouchControl::Joystick));
+    assert_eq!(control_at(&rects, Vec2::new(400.0, 100.0)), None, "free screen");
+
+    // No two controls overlap.
+    for (i, (_, a)) in rects.iter().enumerate() {
+        for (_, b) in rects.iter().skip(i + 1) {
+            assert!(a.intersect(*b).is_empty());
+        }
+    }
+}
+
+#[test]
+fn rects_follow_ui_scale_and_safe_area() {
+    let cfg = TouchControlsConfig::default();
+    let safe = SafeAreaInsets { right: 40.0, bottom: 20.0, ..Default::default() };
+    let rects = control_rects(&cfg, Vec2::new(1600.0, 900.0), 2.0, safe);
+    let fire = rects.iter().find(|(k, _)| *k == TouchControl::Fire).unwrap().1;
+    assert!((fire.width() - cfg.fire_size * 2.0).abs() < 1e-3);
+    assert!((fire.max.x - (1600.0 - 40.0 - cfg.margin * 2.0)).abs() < 1e-3);
+    assert!(fire.max.y <= 900.0 - 20.0);
+}
+
+#[test]
+fn stick_has_dead_zone_and_up_is_positive() {
+    let r = Rect::from_center_size(Vec2::new(100.0, 100.0), Vec2::splat(100.0));
+    assert_eq!(stick_vector(r, Vec2::new(105.0, 100.0), 0.15), Vec2::ZERO);
+    let up = stick_vector(r, Vec2::new(100.0, 50.0), 0.15);
+    assert!(up.x.abs() < 1e-5 && (up.y - 1.0).abs() < 1e-5);
+    let far = stick_vector(r, Vec2::new(400.0, 100.0), 0.15);
+    assert!((far.length() - 1.0).abs() < 1e-5);
+}
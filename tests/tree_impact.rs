@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::tree_impact::{trunk_contact, TreeWobble};
+
+#[test]
+fn trunk_contact_pushes_ball_out_horizontally() {
+    let base = Vec3::new(10.0, 50.0, -4.0);
+    // Touching the trunk from +X at knee height.
+    let (n, depth) = trunk_contact(Vec3::new(10.5, 51.0, -4.0), 0.25, base, 0.35, 4.0).expect("contact");
+    assert!((n - Vec3::X).length() < 1e-5);
+    assert!((depth - 0.1).abs() < 1e-4);
+    // Above the trunk, below the ground, or out of reach: no contact.
+    assert!(trunk_contact(Vec3::new(10.5, 55.0, -4.0), 0.25, base, 0.35, 4.0).is_none());
+    assert!(trunk_contact(Vec3::new(10.5, 49.0, -4.0), 0.25, base, 0.35, 4.0).is_none());
+    assert!(trunk_contact(Vec3::new(10.7, 51.0, -4.0), 0.25, base, 0.35, 4.0).is_none());
+}
+
+#[test]
+fn wobble_spring_settles_back_to_rest() {
+    let rest = Quat::from_rotation_y(0.8);
+    let mut w = TreeWobble { axis: Vec3::X, angle: 0.0, ang_vel: 2.0, rest };
+    let mut peak = 0.0f32;
+    let mut steps = 0;
+    while w.step(1.0 / 60.0, 30.0, 3.5, 0.45) {
+        peak = peak.max(w.angle.abs());
+        steps += 1;
+        assert!(steps < 60 * 20, "spring never settled");
+    }
+    assert!(peak > 0.1 && peak <= 0.45);
+    assert!(w.rotation().angle_between(rest) < 0.01);
+}
@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use bevy::render::view::VisibilityPlugin;
+use vibe_golf::plugins::tree_instancing::{TreeInstanceBatch, TreeInstanceData, TreeInstanceOf, TreeInstancingPlugin};
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), TransformPlugin, HierarchyPlugin, VisibilityPlugin))
+        .init_asset::<Mesh>()
+        .init_asset::<Image>()
+        .init_asset::<StandardMaterial>()
+        .add_plugins(TreeInstancingPlugin);
+    app
+}
+
+fn batch_sizes(app: &mut App) -> Vec<usize> {
+    let mut q = app.world_mut().query::<&TreeInstanceBatch>();
+    let mut sizes: Vec<usize> = q.iter(app.world()).map(|b| b.len()).collect();
+    sizes.sort();
+    sizes
+}
+
+#[test]
+fn instance_data_matches_shader_layout() {
+    // 4 matrix columns + tint, read as five Float32x4 vertex attributes.
+    assert_eq!(std::mem::size_of::<TreeInstanceData>(), 80);
+    let m = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+    let d = TreeInstanceData::new(m, LinearRgba::RED);
+    assert_eq!(d.model[3], [1.0, 2.0, 3.0, 1.0]);
+    assert_eq!(d.color, [1.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn trees_are_grouped_per_variant_and_hidden_trees_dropped() {
+    let mut app = app();
+    let (mesh_a, mesh_b, mat) = {
+        let world = app.world_mut();
+        let a = world.resource_mut::<Assets<Mesh>>().add(Cuboid::default());
+        let b = world.resource_mut::<Assets<Mesh>>().add(Sphere::default());
+        let m = world.resource_mut::<Assets<StandardMaterial>>().add(StandardMaterial::default());
+        (a, b, m)
+    };
+    let of = |mesh: &Handle<Mesh>| TreeInstanceOf { mesh: mesh.clone(), material: mat.clone() };
+    for i in 0..3 {
+        app.world_mut().spawn((SpatialBundle::from_transform(Transform::from_xyz(i as f32, 0.0, 0.0)), of(&mesh_a)));
+    }
+    let hidden = app
+        .world_mut()
+        .spawn((SpatialBundle { visibility: Visibility::Hidden, ..default() }, of(&mesh_b)))
+        .id();
+    app.world_mut().spawn((SpatialBundle::default(), of(&mesh_b)));
+
+    app.update();
+    app.update();
+    assert_eq!(batch_sizes(&mut app), vec![1, 3]);
+
+    *app.world_mut().get_mut::<Visibility>(hidden).unwrap() = Visibility::Inherited;
+    app.update();
+    assert_eq!(batch_sizes(&mut app), vec![2, 3]);
+}
@@ -0,0 +1,42 @@
+// Trick shots: what a shot collects, what it pays out and how each mode scales the bonus.
+use vibe_golf::plugins::game_mode::GameMode;
+use vibe_golf::plugins::trick_shots::{is_skimming, trick_bonus, TrickConfig, TrickDetector, TrickKind};
+
+#[test]
+fn tricks_are_cashed_in_once_per_shot() {
+    let cfg = TrickConfig::default();
+    let mut detector = TrickDetector::default();
+    detector.track_shot(1);
+    detector.tree_bank = true;
+    detector.water_skip = true;
+    assert_eq!(detector.cash_in(Some(1.0), &cfg), vec![TrickKind::TreeBank, TrickKind::WaterSkip, TrickKind::ApexHit]);
+    // Spent: a second hit with the same shot only gets its own apex.
+    assert!(detector.cash_in(Some(0.0), &cfg).is_empty());
+
+    detector.tree_bank = true;
+    detector.track_shot(1);
+    assert!(detector.tree_bank, "same shot keeps its tricks");
+    detector.track_shot(2);
+    assert_eq!(detector, TrickDetector { shot: 2, ..TrickDetector::default() });
+    assert!(detector.cash_in(None, &cfg).is_empty(), "a bob that doesn't count is no apex");
+}
+
+#[test]
+fn skimming_needs_low_and_fast() {
+    let cfg = TrickConfig::default();
+    assert!(is_skimming(1.0, 20.0, &cfg));
+    assert!(!is_skimming(cfg.skim_height + 1.0, 20.0, &cfg), "too high");
+    assert!(!is_skimming(1.0, cfg.skim_speed * 0.5, &cfg), "too slow");
+    assert!(!is_skimming(-0.5, 20.0, &cfg), "in the water");
+}
+
+#[test]
+fn bonus_follows_the_mode() {
+    let cfg = TrickConfig::default();
+    let tricks = [TrickKind::TreeBank, TrickKind::ApexHit];
+    let full = cfg.tree_bank_bonus + cfg.apex_bonus;
+    assert_eq!(trick_bonus(&tricks, &cfg, GameMode::TimeAttack.rules()), full);
+    assert_eq!(trick_bonus(&tricks, &cfg, GameMode::HardPar.rules()), full);
+    assert_eq!(trick_bonus(&tricks, &cfg, GameMode::StrokePlay.rules()), 0.0);
+    assert_eq!(trick_bonus(&[], &cfg, GameMode::TimeAttack.rules()), 0.0);
+}
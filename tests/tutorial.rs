@@ -0,0 +1,48 @@
+use vibe_golf::plugins::game_mode::GameMode;
+use vibe_golf::plugins::tutorial::{TutorialRun, TutorialScript, TutorialSignals};
+use vibe_golf::plugins::user_settings::UserSettings;
+
+fn script() -> TutorialScript {
+    TutorialScript::from_ron(
+        "(min_step_time: 0.5, steps: [
+            (text: \"a\", wait: OrbitCamera(1.0)),
+            (text: \"b\", wait: ChargeShot(0.5)),
+            (text: \"c\", highlight: Some(PowerGauge), wait: FireShot),
+        ])",
+    )
+    .unwrap()
+}
+
+#[test]
+fn steps_wait_for_their_action_and_min_time() {
+    let script = script();
+    let mut run = TutorialRun::default();
+    run.start();
+    let turn = TutorialSignals { yaw_turned: 0.6, ..Default::default() };
+    assert!(!run.update(&script, turn, 0.1));
+    assert!(!run.update(&script, turn, 0.1)); // turned 1.2 rad but only 0.2 s shown
+    assert_eq!(run.step, 0);
+    run.update(&script, TutorialSignals::default(), 0.4);
+    assert_eq!(run.step, 1);
+
+    // Releasing early still counts as having charged.
+    let fired = TutorialSignals { shot_fired: true, ..Default::default() };
+    run.update(&script, fired, 0.6);
+    assert_eq!(run.step, 2);
+    assert!(run.update(&script, fired, 0.6));
+    assert!(!run.active);
+}
+
+#[test]
+fn shipped_tutorial_parses() {
+    let data = std::fs::read_to_string("assets/tutorial.ron").unwrap();
+    let script = TutorialScript::from_ron(&data).unwrap();
+    assert!(!script.steps.is_empty());
+}
+
+#[test]
+fn settings_round_trip_and_tolerate_missing_fields() {
+    let s = UserSettings { tutorial_completed: true, game_mode: GameMode::StrokePlay, ..Default::default() };
+    assert_eq!(UserSettings::from_ron(&s.to_ron()).unwrap(), s);
+    assert_eq!(UserSettings::from_ron("()").unwrap(), UserSettings::default());
+}
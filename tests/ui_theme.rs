@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::ui_theme::{ColorPalette, UiTheme};
+
+fn close(a: Color, b: Srgba) -> bool {
+    let a = a.to_srgba();
+    (a.red - b.red).abs() < 1e-5 && (a.green - b.green).abs() < 1e-5 && (a.blue - b.blue).abs() < 1e-5
+}
+
+fn luminance(c: Color) -> f32 {
+    let l = c.to_linear();
+    0.2126 * l.red + 0.7152 * l.green + 0.0722 * l.blue
+}
+
+#[test]
+fn default_palette_keeps_original_power_gradient() {
+    let theme = UiTheme::default();
+    assert!(close(theme.power_color(0.0), Srgba::rgb(0.15, 0.60, 0.25)));
+    assert!(close(theme.power_color(1.0), Srgba::rgb(0.90, 0.20, 0.15)));
+    assert!(close(theme.trajectory_color(1.0), Srgba::rgb(1.0, 0.85, 0.10)));
+}
+
+#[test]
+fn colorblind_palettes_separate_power_stops_by_lightness() {
+    for palette in [ColorPalette::Deuteranopia, ColorPalette::Protanopia, ColorPalette::Tritanopia] {
+        let theme = UiTheme { palette, high_contrast: false };
+        let (low, mid, high) = (luminance(theme.power_color(0.0)), luminance(theme.power_color(0.5)), luminance(theme.power_color(1.0)));
+        assert!(mid - low > 0.2 && mid - high > 0.2, "{palette:?}: {low} {mid} {high}");
+    }
+}
+
+#[test]
+fn palette_cycle_and_contrast_ring() {
+    let mut p = ColorPalette::Default;
+    for _ in 0..4 {
+        p = p.next();
+    }
+    assert_eq!(p, ColorPalette::Default);
+    let hc = UiTheme { palette: p, high_contrast: true };
+    assert!(hc.compass_colors().ring.alpha() > UiTheme::default().compass_colors().ring.alpha());
+    assert_eq!(UiTheme::default().text_backdrop(), Color::NONE);
+}
@@ -0,0 +1,45 @@
+use vibe_golf::plugins::ui_toast::{ToastConfig, ToastKind, ToastQueue};
+
+#[test]
+fn toasts_queue_beyond_the_visible_slots() {
+    let cfg = ToastConfig { max_visible: 2, ..Default::default() };
+    let mut q = ToastQueue::default();
+    q.push("Hole 1 / 3", ToastKind::Progress);
+    q.push("Shot 1", ToastKind::Progress);
+    q.push("New best!", ToastKind::Success);
+    q.tick(&cfg, 0.0);
+    assert_eq!(q.visible().len(), 2);
+    assert_eq!(q.visible()[0].toast.text, "Hole 1 / 3");
+    assert_eq!(q.visible()[0].toast.duration, cfg.default_duration);
+    assert_eq!(q.pending(), 1);
+
+    // Once the first two have played out the queued one takes a slot.
+    q.tick(&cfg, cfg.lifetime(cfg.default_duration) + 0.01);
+    q.tick(&cfg, 0.0);
+    assert_eq!(q.visible().len(), 1);
+    assert_eq!(q.visible()[0].toast.text, "New best!");
+    assert_eq!(q.pending(), 0);
+}
+
+#[test]
+fn duplicate_toasts_collapse_and_old_ones_are_dropped() {
+    let cfg = ToastConfig { max_visible: 1, max_pending: 2, ..Default::default() };
+    let mut q = ToastQueue::default();
+    q.push("Timber!", ToastKind::Info);
+    q.push("Timber!", ToastKind::Info);
+    assert_eq!(q.pending(), 1);
+    q.push("a", ToastKind::Info);
+    q.push("b", ToastKind::Info);
+    q.tick(&cfg, 0.0);
+    assert_eq!(q.visible()[0].toast.text, "a", "oldest pending toast was dropped");
+}
+
+#[test]
+fn envelope_animates_in_holds_and_out() {
+    let cfg = ToastConfig::default();
+    assert_eq!(cfg.envelope(0.0, 2.0), 0.0);
+    assert!((cfg.envelope(cfg.anim_in * 0.5, 2.0) - 0.5).abs() < 1e-5);
+    assert_eq!(cfg.envelope(cfg.anim_in + 1.0, 2.0), 1.0);
+    assert!(cfg.envelope(cfg.anim_in + 2.0 + cfg.anim_out * 0.5, 2.0) < 1.0);
+    assert_eq!(cfg.envelope(cfg.lifetime(2.0), 2.0), 0.0);
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use vibe_golf::plugins::vegetation::{chunk_tree_placements, VegetationConfig};
+use vibe_golf::plugins::vegetation_cache::{decode_cache, encode_cache, vegetation_cache_key};
+use vibe_golf::plugins::vegetation_species::VegetationSpeciesList;
+
+mod common;
+
+#[test]
+fn cache_roundtrips_placements_for_its_key_only() {
+    let sampler = common::meadow_sampler(1.0, 0);
+    let species = VegetationSpeciesList::default();
+    let cfg = VegetationConfig::default();
+    let key = vegetation_cache_key(&sampler, &species, &cfg);
+
+    let mut chunks = HashMap::new();
+    for coord in [IVec2::new(2, -1), IVec2::new(-3, 2), IVec2::new(0, 0)] {
+        chunks.insert(coord, chunk_tree_placements(coord, &sampler, &species, &cfg));
+    }
+    assert!(chunks.values().any(|p| !p.is_empty()));
+
+    let bytes = encode_cache(key, &chunks);
+    let decoded = decode_cache(&bytes, key).expect("decodes with its own key");
+    assert_eq!(decoded.len(), chunks.len());
+    for (coord, placements) in &chunks {
+        let got = &decoded[coord];
+        assert_eq!(got.len(), placements.len());
+        for (a, b) in got.iter().zip(placements) {
+            assert_eq!(a.transform, b.transform);
+            assert_eq!(a.species, b.species);
+            assert_eq!(a.variant_roll, b.variant_roll);
+        }
+    }
+
+    assert!(decode_cache(&bytes, key ^ 1).is_none(), "another key must not load");
+    assert!(decode_cache(&bytes[..bytes.len() - 3], key).is_none(), "truncated file must not load");
+}
+
+#[test]
+fn key_tracks_seed_heightmap_and_config() {
+    let sampler = common::meadow_sampler(1.0, 0);
+    let species = VegetationSpeciesList::default();
+    let cfg = VegetationConfig::default();
+    let key = vegetation_cache_key(&sampler, &species, &cfg);
+
+    assert_eq!(key, vegetation_cache_key(&common::meadow_sampler(1.0, 0), &species, &cfg));
+    assert_ne!(key, vegetation_cache_key(&common::meadow_sampler(1.0, 1), &species, &cfg));
+    assert_ne!(key, vegetation_cache_key(&sampler, &species, &VegetationConfig { seed: 7, ..Default::default() }));
+    assert_ne!(key, vegetation_cache_key(&sampler, &species, &VegetationConfig { threshold: 0.55, ..Default::default() }));
+
+    // Spawn-only settings do not invalidate placements.
+    let spawn_only = VegetationConfig { use_instanced: false, max_instances: 10, ..Default::default() };
+    assert_eq!(key, vegetation_cache_key(&sampler, &species, &spawn_only));
+
+    let mut fewer = species.clone();
+    fewer.species.pop();
+    assert_ne!(key, vegetation_cache_key(&sampler, &fewer, &cfg));
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::vegetation::{chunk_tree_placements, VegetationConfig};
+use vibe_golf::plugins::vegetation_species::VegetationSpeciesList;
+
+mod common;
+
+#[test]
+fn chunk_placements_are_deterministic_and_inside_the_chunk() {
+    let sampler = common::meadow_sampler(1.0, 0);
+    let species = VegetationSpeciesList::default();
+    let cfg = VegetationConfig::default();
+    let coord = IVec2::new(2, -1);
+
+    let a = chunk_tree_placements(coord, &sampler, &species, &cfg);
+    let b = chunk_tree_placements(coord, &sampler, &species, &cfg);
+    assert!(!a.is_empty(), "expected trees on the fixture hills");
+    assert_eq!(a.len(), b.len());
+    for (pa, pb) in a.iter().zip(&b) {
+        assert_eq!(pa.transform, pb.transform);
+        assert_eq!(pa.species, pb.species);
+    }
+
+    // Grid points lie inside the chunk; jitter adds at most half a cell.
+    let size = sampler.cfg.chunk_size;
+    let margin = cfg.cell_size * 0.5;
+    let (x0, z0) = (coord.x as f32 * size, coord.y as f32 * size);
+    for p in &a {
+        let t = p.transform.translation;
+        assert!(t.x >= x0 - margin && t.x <= x0 + size + margin, "x {} outside chunk", t.x);
+        assert!(t.z >= z0 - margin && t.z <= z0 + size + margin, "z {} outside chunk", t.z);
+    }
+
+    // Another chunk gets a different layout.
+    let other = chunk_tree_placements(IVec2::new(-3, 2), &sampler, &species, &cfg);
+    assert!(other.iter().all(|p| p.transform.translation.x < -2.0 * size));
+}
+
+#[test]
+fn seed_changes_the_forest_and_can_be_disabled() {
+    let sampler = common::meadow_sampler(1.0, 0);
+    let species = VegetationSpeciesList::default();
+    let coord = IVec2::new(2, -1);
+    let positions = |cfg: &VegetationConfig| -> Vec<Vec3> {
+        chunk_tree_placements(coord, &sampler, &species, cfg).iter().map(|p| p.transform.translation).collect()
+    };
+
+    let seeded = VegetationConfig { seed: 42, ..Default::default() };
+    assert_eq!(positions(&seeded), positions(&seeded));
+    assert_ne!(positions(&seeded), positions(&VegetationConfig { seed: 43, ..Default::default() }));
+
+    // Unseeded runs draw a fresh layout every call (overwhelmingly likely to differ).
+    let random = VegetationConfig { deterministic: false, ..Default::default() };
+    let runs: Vec<Vec<Vec3>> = (0..3).map(|_| positions(&random)).collect();
+    assert!(runs.windows(2).any(|w| w[0] != w[1]));
+}
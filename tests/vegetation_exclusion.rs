@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::target::{TargetMotion, TargetPath};
+use vibe_golf::plugins::vegetation_exclusion::{
+    target_zone, ExclusionZone, VegetationExclusionDef, VegetationExclusions,
+};
+
+#[test]
+fn level_defs_become_circles_and_paths() {
+    let circle = ExclusionZone::from_def(&VegetationExclusionDef { points: vec![(10.0, 0.0)], radius: 5.0 }).unwrap();
+    assert!(circle.contains(Vec2::new(14.0, 0.0)));
+    assert!(!circle.contains(Vec2::new(16.0, 0.0)));
+
+    let path = ExclusionZone::from_def(&VegetationExclusionDef {
+        points: vec![(0.0, 0.0), (0.0, 100.0), (50.0, 100.0)],
+        radius: 6.0,
+    })
+    .unwrap();
+    assert!(path.contains(Vec2::new(5.0, 50.0)), "beside the first segment");
+    assert!(path.contains(Vec2::new(25.0, 95.0)), "beside the second segment");
+    assert!(!path.contains(Vec2::new(25.0, 50.0)), "inside the bend, away from both segments");
+    assert!(!path.contains(Vec2::new(0.0, -7.0)), "past the path's start");
+
+    assert!(ExclusionZone::from_def(&VegetationExclusionDef { points: vec![], radius: 3.0 }).is_none());
+}
+
+#[test]
+fn exclusions_cover_static_zones_and_targets() {
+    let mut ex = VegetationExclusions::default();
+    assert!(ex.is_empty());
+    assert!(!ex.contains(Vec2::ZERO));
+
+    ex.zones.push(ExclusionZone::Circle { center: Vec2::ZERO, radius: 12.0 });
+    ex.target_zones.push(target_zone(Vec2::new(0.0, 80.0), None, 14.0));
+    assert!(ex.contains(Vec2::new(3.0, 3.0)));
+    assert!(ex.contains(Vec2::new(10.0, 80.0)));
+    assert!(!ex.contains(Vec2::new(0.0, 40.0)));
+
+    // Relocated target: the old spot is free again, the new one is kept clear.
+    ex.target_zones[0] = target_zone(Vec2::new(600.0, 80.0), None, 14.0);
+    assert!(!ex.contains(Vec2::new(10.0, 80.0)));
+    assert!(ex.contains(Vec2::new(595.0, 85.0)));
+}
+
+#[test]
+fn moving_targets_keep_their_whole_route_clear() {
+    let motion = TargetMotion::new(TargetPath::Orbit { radius: 50.0, period: 10.0 }, Vec2::new(100.0, 0.0), 60.0);
+    let zone = target_zone(Vec2::new(150.0, 0.0), Some(&motion), 10.0);
+    assert!(zone.contains(Vec2::new(50.0, 0.0)), "far side of the orbit");
+    assert!(zone.contains(Vec2::new(100.0, 55.0)));
+    assert!(!zone.contains(Vec2::new(100.0, 0.0)), "orbit centre stays wooded");
+    let (center, reach) = zone.bounds();
+    assert!(center.distance(Vec2::new(100.0, 0.0)) < 1.0);
+    assert!((reach - 60.0).abs() < 1.0);
+}
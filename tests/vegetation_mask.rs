@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::vegetation::{chunk_tree_placements, VegetationConfig};
+use vibe_golf::plugins::vegetation_mask::{VegetationDensityMask, MASK_MAX_MULTIPLIER};
+use vibe_golf::plugins::vegetation_species::VegetationSpeciesList;
+
+mod common;
+
+#[test]
+fn mask_samples_luminance_over_the_world_square() {
+    // Left column black, right column white; rows identical.
+    let mask = VegetationDensityMask::from_luma(2, 2, vec![0, 255, 0, 255], 100.0);
+    assert_eq!(mask.sample(-50.0, 0.0), 0.0);
+    assert!((mask.sample(50.0, 10.0) - MASK_MAX_MULTIPLIER).abs() < 1e-5);
+    assert!((mask.sample(0.0, -20.0) - MASK_MAX_MULTIPLIER * 0.5).abs() < 1e-5);
+    // Outside the painted square the procedural density is untouched.
+    assert_eq!(mask.sample(80.0, 0.0), 1.0);
+}
+
+#[test]
+fn black_mask_clears_a_chunk() {
+    let sampler = common::meadow_sampler(1.0, 0);
+    let species = VegetationSpeciesList::default();
+    let coord = IVec2::new(2, -1);
+    let cfg = VegetationConfig::default();
+    assert!(!chunk_tree_placements(coord, &sampler, &species, &cfg).is_empty());
+
+    let cleared = VegetationConfig {
+        density_mask: Some(VegetationDensityMask::from_luma(2, 2, vec![0; 4], 2000.0)),
+        ..Default::default()
+    };
+    assert!(chunk_tree_placements(coord, &sampler, &species, &cleared).is_empty());
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::vegetation::{chunk_prop_placements, chunk_tree_placements, VegetationConfig};
+use vibe_golf::plugins::vegetation_props::{build_prop_mesh, PropKind, PropScatterConfig};
+use vibe_golf::plugins::vegetation_species::VegetationSpeciesList;
+
+mod common;
+
+#[test]
+fn props_follow_their_rules_and_keep_clear_of_trees() {
+    // Steeper hills so both slope classes occur.
+    let sampler = common::meadow_sampler(2.5, 0);
+    let species = VegetationSpeciesList::default();
+    let cfg = VegetationConfig::default();
+    let props = PropScatterConfig { density: 1.0, max_per_chunk: 200, ..Default::default() };
+
+    let mut total = 0;
+    for coord in [IVec2::new(2, -1), IVec2::new(-3, 2), IVec2::new(1, 3)] {
+        let trees = chunk_tree_placements(coord, &sampler, &species, &cfg);
+        let placed = chunk_prop_placements(coord, &sampler, &props, &cfg, &trees);
+        assert_eq!(placed, chunk_prop_placements(coord, &sampler, &props, &cfg, &trees));
+        total += placed.len();
+
+        for (i, p) in placed.iter().enumerate() {
+            let xz = p.transform.translation.xz();
+            let rule = &props.rules[p.rule];
+            assert_eq!(rule.kind, p.kind);
+            assert!(rule.weight_at(sampler.height(xz.x, xz.y), sampler.normal(xz.x, xz.y).y) > 0.0);
+            for t in &trees {
+                assert!(t.transform.translation.xz().distance(xz) >= props.tree_clearance);
+            }
+            for q in &placed[..i] {
+                if q.kind == p.kind {
+                    assert!(q.transform.translation.xz().distance(xz) >= rule.spacing);
+                }
+            }
+        }
+    }
+    assert!(total > 0, "expected some props on the fixture");
+
+    let disabled = PropScatterConfig { enabled: false, ..Default::default() };
+    assert!(chunk_prop_placements(IVec2::new(2, -1), &sampler, &disabled, &cfg, &[]).is_empty());
+}
+
+#[test]
+fn prop_meshes_sit_on_the_ground() {
+    for kind in PropKind::ALL {
+        let mesh = build_prop_mesh(kind);
+        assert!(mesh.attribute(Mesh::ATTRIBUTE_NORMAL).is_some());
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).and_then(|a| a.as_float3()).expect("positions");
+        let min_y = positions.iter().map(|p| p[1]).fold(f32::MAX, f32::min);
+        assert!((-0.3..=0.01).contains(&min_y), "{kind:?} min y {min_y}");
+    }
+}
@@ -0,0 +1,43 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use vibe_golf::plugins::biome::Biome;
+use vibe_golf::plugins::vegetation_species::{VegetationSpeciesList, SPECIES_FILE};
+
+const MEADOW: [f32; 4] = [1.0, 0.0, 0.0, 0.0];
+
+#[test]
+fn species_file_parses_and_references_existing_models() {
+    let data = std::fs::read_to_string(SPECIES_FILE).expect("species file");
+    let list = VegetationSpeciesList::from_ron(&data).expect("valid RON");
+    assert!(!list.species.is_empty());
+    for s in &list.species {
+        let file = s.model.split('#').next().unwrap();
+        assert!(std::path::Path::new("assets").join(file).exists(), "missing model {}", s.model);
+        assert!(s.scale.0 > 0.0 && s.scale.0 <= s.scale.1, "bad scale range for {}", s.name);
+        assert!(s.altitude.0 <= s.altitude.1, "bad altitude band for {}", s.name);
+    }
+}
+
+#[test]
+fn pick_respects_altitude_slope_and_biome_rules() {
+    let list = VegetationSpeciesList::from_ron(
+        r#"(species: [
+            (name: "low", model: "m.glb", scale: (1.0, 1.0), min_normal_y: 0.9, altitude: (0.0, 100.0)),
+            (name: "high", model: "m.glb", scale: (1.0, 1.0), min_normal_y: 0.5, altitude: (100.0, 200.0),
+             biomes: (meadow: 0.0)),
+        ])"#,
+    )
+    .unwrap();
+    assert_eq!(list.species[0].spacing, 1.0);
+    assert_eq!(list.species[1].biomes.get(Biome::Forest), 1.0);
+
+    let mut rng = StdRng::seed_from_u64(3);
+    for _ in 0..50 {
+        assert_eq!(list.pick(&mut rng, 50.0, 0.95, &MEADOW), Some(0));
+    }
+    // Too steep for "low"; "high" is out of its band.
+    assert_eq!(list.pick(&mut rng, 50.0, 0.6, &MEADOW), None);
+    // "high" accepts the spot but has zero meadow affinity.
+    assert_eq!(list.pick(&mut rng, 150.0, 0.6, &MEADOW), None);
+    assert_eq!(list.pick(&mut rng, 150.0, 0.6, &[0.0, 1.0, 0.0, 0.0]), Some(1));
+}
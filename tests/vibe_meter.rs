@@ -0,0 +1,42 @@
+// Vibe meter: how shots move it, when a stroke can be armed and how the ring fills.
+use vibe_golf::plugins::vibe_meter::{ring_segments, shot_vibe, VibeConfig, VibeMeter, VibeStroke};
+
+#[test]
+fn good_shots_fill_and_duds_drain() {
+    let cfg = VibeConfig::default();
+    assert_eq!(shot_vibe(100.0, 300.0, true, &cfg), cfg.hit_gain, "scoring beats where the ball ended");
+    assert!((shot_vibe(100.0, 20.0, false, &cfg) - cfg.good_gain * 0.8).abs() < 1e-5);
+    assert_eq!(shot_vibe(100.0, 70.0, false, &cfg), 0.0, "some way closer: no change");
+    assert_eq!(shot_vibe(100.0, 95.0, false, &cfg), -cfg.dud_loss);
+    assert_eq!(shot_vibe(100.0, 140.0, false, &cfg), -cfg.dud_loss, "further off");
+}
+
+#[test]
+fn strokes_need_a_full_meter_and_empty_it() {
+    let mut meter = VibeMeter::default();
+    meter.cycle_armed();
+    assert_eq!(meter.armed, None, "not full");
+    assert!(!meter.add(0.7));
+    assert!(meter.add(0.7), "filled");
+    assert!(!meter.add(0.2), "already full");
+    assert_eq!(meter.level, 1.0);
+
+    meter.cycle_armed();
+    assert_eq!(meter.armed, Some(VibeStroke::PerfectAim));
+    meter.cycle_armed();
+    assert_eq!(meter.armed, Some(VibeStroke::DoublePower));
+    assert_eq!(meter.spend_armed(), Some(VibeStroke::DoublePower));
+    assert_eq!((meter.level, meter.armed), (0.0, None));
+
+    meter.add(-0.5);
+    assert_eq!(meter.level, 0.0, "never below empty");
+    assert_eq!(meter.spend_armed(), None);
+}
+
+#[test]
+fn ring_fills_clockwise_side_by_side() {
+    assert_eq!(ring_segments(0.0), [0.0; 4]);
+    assert_eq!(ring_segments(0.375), [1.0, 0.5, 0.0, 0.0]);
+    assert_eq!(ring_segments(1.0), [1.0; 4]);
+    assert_eq!(ring_segments(2.0), [1.0; 4]);
+}
@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+use image::{Rgb, RgbImage};
+use vibe_golf::visual_regression::{
+    assert_matches_reference, check_against_reference, color_delta, failure_path, perceptual_diff, ReferenceCheck,
+    Scenario,
+};
+
+fn gradient(shift: u8) -> RgbImage {
+    RgbImage::from_fn(40, 20, |x, y| Rgb([(x * 6) as u8 + shift, (y * 12) as u8, 128]))
+}
+
+#[test]
+fn color_delta_weights_luminance() {
+    assert_eq!(color_delta([10, 20, 30], [10, 20, 30]), 0.0);
+    let black_white = color_delta([0, 0, 0], [255, 255, 255]);
+    assert!(black_white > 0.95 && black_white <= 1.0, "{black_white}");
+    // A few levels of shading noise stay well under the default threshold.
+    assert!(color_delta([100, 120, 90], [102, 121, 90]) < 0.02);
+    assert!(color_delta([200, 200, 200], [200, 60, 60]) > 0.1);
+}
+
+#[test]
+fn perceptual_diff_counts_changed_pixels() {
+    let reference = gradient(0);
+    let noisy = gradient(2);
+    let diff = perceptual_diff(&reference, &noisy, 0.1).unwrap();
+    assert_eq!(diff.changed, 0);
+    assert_eq!(diff.pixels, 800);
+
+    let mut broken = reference.clone();
+    for x in 0..10 {
+        broken.put_pixel(x, 0, Rgb([255, 0, 255]));
+    }
+    let diff = perceptual_diff(&reference, &broken, 0.1).unwrap();
+    assert_eq!(diff.changed, 10);
+    assert!((diff.changed_fraction() - 10.0 / 800.0).abs() < 1e-6);
+    assert!(perceptual_diff(&reference, &RgbImage::new(20, 20), 0.1).is_none());
+}
+
+#[test]
+fn reference_is_recorded_then_compared() {
+    let dir = std::env::temp_dir().join(format!("vibe_golf_visual_{}", std::process::id()));
+    let scenario = Scenario::new("gradient", Vec3::ZERO, Vec3::X);
+    let reference = gradient(0);
+    assert_eq!(check_against_reference(&reference, &dir, &scenario, false).unwrap(), ReferenceCheck::Created);
+    assert!(check_against_reference(&gradient(1), &dir, &scenario, false).unwrap().passed());
+
+    let mut broken = reference.clone();
+    for y in 0..20 {
+        broken.put_pixel(5, y, Rgb([0, 0, 0]));
+    }
+    let check = check_against_reference(&broken, &dir, &scenario, false).unwrap();
+    assert!(matches!(check, ReferenceCheck::Mismatched(d) if d.changed == 20), "{check:?}");
+    assert!(failure_path(&dir, "gradient", "diff").exists());
+    assert!(failure_path(&dir, "gradient", "actual").exists());
+
+    // Blessing accepts the new frame; the next check passes and clears the failure images.
+    assert_eq!(check_against_reference(&broken, &dir, &scenario, true).unwrap(), ReferenceCheck::Created);
+    assert!(check_against_reference(&broken, &dir, &scenario, false).unwrap().passed());
+    assert!(!failure_path(&dir, "gradient", "diff").exists());
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+#[ignore = "renders with the GPU; run locally with --ignored"]
+fn island_overview_matches_reference() {
+    let mut scenario = Scenario::new("island_overview", Vec3::new(0.0, 420.0, 700.0), Vec3::new(0.0, 40.0, 0.0));
+    scenario.seed = 1;
+    assert_matches_reference(&scenario);
+}
@@ -0,0 +1,39 @@
+// Weather: the per-level schedule, what each kind does and how changes blend in.
+use vibe_golf::plugins::level::LevelDef;
+use vibe_golf::plugins::weather::{WeatherDef, WeatherKind, WeatherState};
+
+#[test]
+fn schedule_follows_the_run_clock() {
+    let def = ron::from_str::<WeatherDef>("(start: Wind, changes: [(at: 90.0, to: Storm), (at: 30.0, to: Rain)])").unwrap();
+    assert_eq!(def.kind_at(0.0), WeatherKind::Wind);
+    assert_eq!(def.kind_at(30.0), WeatherKind::Rain);
+    assert_eq!(def.kind_at(200.0), WeatherKind::Storm);
+    assert_eq!(WeatherDef::default().kind_at(500.0), WeatherKind::Clear);
+}
+
+#[test]
+fn clear_is_neutral_and_rain_slows_the_ball() {
+    let clear = WeatherKind::Clear.effects();
+    assert_eq!((clear.friction, clear.bounce, clear.drift, clear.light), (1.0, 1.0, 0.0, 1.0));
+    let rain = WeatherKind::Rain.effects();
+    assert!(rain.friction > 1.0 && rain.bounce < 1.0 && rain.rain > 0.0);
+    let storm = WeatherKind::Storm.effects();
+    assert!(storm.light < rain.light && storm.thunder > 0.0);
+
+    let mut state = WeatherState::default();
+    state.change_to(WeatherKind::Rain);
+    state.step(4.0, 8.0);
+    assert!((state.effects.friction - (clear.friction + rain.friction) / 2.0).abs() < 1e-5);
+    state.step(10.0, 8.0);
+    assert_eq!(state.effects, rain);
+}
+
+#[test]
+fn levels_opt_in() {
+    let course = ron::from_str::<LevelDef>(&std::fs::read_to_string("assets/levels/course1.ron").unwrap()).unwrap();
+    let weather = course.weather.expect("course1 has weather");
+    assert_eq!(weather.start, WeatherKind::Clear);
+    assert_eq!(weather.kind_at(1000.0), WeatherKind::Storm);
+    let level1 = ron::from_str::<LevelDef>(&std::fs::read_to_string("assets/levels/level1.ron").unwrap()).unwrap();
+    assert_eq!(level1.weather, None);
+}
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+use vibe_golf::plugins::tree_wind::{TreeWindConfig, TreeWindUniform};
+use vibe_golf::plugins::wind::WindState;
+
+#[test]
+fn gusts_stay_within_gustiness_band() {
+    let wind = WindState { base_strength: 0.8, gustiness: 0.5, ..default() };
+    let (mut lo, mut hi) = (f32::MAX, f32::MIN);
+    for i in 0..6000 {
+        let s = wind.strength_at(i as f32 * 0.05);
+        lo = lo.min(s);
+        hi = hi.max(s);
+    }
+    assert!(lo >= 0.8 * 0.5 - 1e-4 && hi <= 0.8 * 1.5 + 1e-4);
+    assert!(hi - lo > 0.4, "gusts should actually vary ({lo}..{hi})");
+
+    let calm = WindState { base_strength: 0.0, ..default() };
+    assert_eq!(calm.strength_at(12.3), 0.0);
+}
+
+#[test]
+fn disabled_tree_wind_has_zero_strength() {
+    let wind = WindState { strength: 0.9, time: 4.0, direction: Vec2::new(0.0, 2.0), ..default() };
+    let on = TreeWindUniform::new(&wind, &TreeWindConfig::default());
+    assert_eq!(on.wind.truncate().truncate(), Vec2::Y);
+    assert_eq!(on.wind.z, 0.9);
+    assert_eq!(on.params.x, 4.0);
+    let off = TreeWindUniform::new(&wind, &TreeWindConfig { enabled: false, ..default() });
+    assert_eq!(off.wind.z, 0.0);
+}